@@ -35,7 +35,8 @@ impl ServiceSchemas {
 
     pub async fn schema_json(&self, thread_id: &str) -> AgentResult<String> {
         let ledger = Arc::clone(&self.profile).inject_ledger();
-        Ok(ledger.get_schema(thread_id, None).await?)
+        let schema = ledger.get_schema(thread_id, None).await?;
+        Ok(serde_json::to_string(&schema)?)
     }
 
     pub fn find_by_name_and_version(&self, name: &str, version: &str) -> AgentResult<Vec<String>> {
@@ -3,8 +3,6 @@
 pub mod integration_tests {
     use std::sync::Arc;
 
-    use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
-
     use crate::common::test_utils::create_and_store_credential;
     use crate::utils::constants::TAILS_DIR;
     use crate::utils::devsetup::{init_holder_setup_in_indy_context, SetupProfile};
@@ -14,10 +12,19 @@ pub mod integration_tests {
     #[ignore]
     async fn test_pool_returns_error_if_proof_request_is_malformed() {
         SetupProfile::run(|setup| async move {
-            let proof_req = "{";
+            let proof_req = serde_json::from_value(json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "address1_1": json!({}),
+               }),
+            }))
+            .unwrap();
             let anoncreds = Arc::clone(&setup.profile).inject_anoncreds();
+            // the requested attribute has neither `name` nor `names`
             let result = anoncreds.prover_get_credentials_for_proof_req(&proof_req).await;
-            assert_eq!(result.unwrap_err().kind(), AriesVcxCoreErrorKind::InvalidProofRequest);
+            assert!(result.is_err());
         })
         .await;
     }
@@ -26,7 +33,7 @@ pub mod integration_tests {
     #[ignore]
     async fn test_pool_prover_get_credentials() {
         SetupProfile::run(|setup| async move {
-            let proof_req = json!({
+            let proof_req = serde_json::from_value(json!({
                "nonce":"123432421212",
                "name":"proof_req_1",
                "version":"0.1",
@@ -39,20 +46,16 @@ pub mod integration_tests {
                    }),
                }),
                "requested_predicates": json!({}),
-            })
-            .to_string();
+            }))
+            .unwrap();
 
             let anoncreds = Arc::clone(&setup.profile).inject_anoncreds();
-            let _result = anoncreds
+            let result = anoncreds
                 .prover_get_credentials_for_proof_req(&proof_req)
                 .await
                 .unwrap();
-
-            let result_malformed_json = anoncreds.prover_get_credentials_for_proof_req("{}").await.unwrap_err();
-            assert_eq!(
-                result_malformed_json.kind(),
-                AriesVcxCoreErrorKind::InvalidAttributesStructure
-            );
+            assert!(result.attrs.contains_key("address1_1"));
+            assert!(result.attrs.contains_key("zip_2"));
         })
         .await;
     }
@@ -73,11 +76,11 @@ pub mod integration_tests {
 
             let ledger = Arc::clone(&holder_setup.profile).inject_ledger();
 
-            let (_, first_rev_reg_delta, first_timestamp) =
-                ledger.get_rev_reg_delta_json(&rev_reg_id, None, None).await.unwrap();
+            let (first_rev_reg_delta, first_timestamp) =
+                ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
 
-            let (_, test_same_delta, test_same_timestamp) =
-                ledger.get_rev_reg_delta_json(&rev_reg_id, None, None).await.unwrap();
+            let (test_same_delta, test_same_timestamp) =
+                ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
 
             assert_eq!(first_rev_reg_delta, test_same_delta);
             assert_eq!(first_timestamp, test_same_timestamp);
@@ -99,8 +102,8 @@ pub mod integration_tests {
                 .unwrap();
 
             // Delta should change after revocation
-            let (_, second_rev_reg_delta, _) = ledger
-                .get_rev_reg_delta_json(&rev_reg_id, Some(first_timestamp + 1), None)
+            let (second_rev_reg_delta, _) = ledger
+                .get_rev_reg_delta(&rev_reg_id, Some(first_timestamp + 1), None)
                 .await
                 .unwrap();
 
@@ -1,33 +1,15 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
 use time::OffsetDateTime;
 
 use crate::core::profile::profile::Profile;
 use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
 
-use super::primitives::revocation_registry_delta::RevocationRegistryDelta;
-
 pub mod encoding;
 
-#[derive(Serialize, Deserialize)]
-struct ProverCredential {
-    referent: String,
-    attrs: HashMap<String, String>,
-    schema_id: String,
-    cred_def_id: String,
-    rev_reg_id: Option<String>,
-    cred_rev_id: Option<String>,
-}
-
 pub async fn get_cred_rev_id(profile: &Arc<dyn Profile>, cred_id: &str) -> VcxResult<String> {
     let anoncreds = Arc::clone(profile).inject_anoncreds();
-    let cred_json = anoncreds.prover_get_credential(cred_id).await?;
-    let prover_cred = serde_json::from_str::<ProverCredential>(&cred_json).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!("Failed to deserialize anoncreds credential: {}", err),
-        )
-    })?;
+    let prover_cred = anoncreds.prover_get_credential(cred_id).await?;
     prover_cred.cred_rev_id.ok_or(AriesVcxError::from_msg(
         AriesVcxErrorKind::InvalidRevocationDetails,
         "Credenial revocation id missing on credential - is this credential revokable?",
@@ -37,7 +19,8 @@ pub async fn get_cred_rev_id(profile: &Arc<dyn Profile>, cred_id: &str) -> VcxRe
 pub async fn is_cred_revoked(profile: &Arc<dyn Profile>, rev_reg_id: &str, rev_id: &str) -> VcxResult<bool> {
     let from = None;
     let to = Some(OffsetDateTime::now_utc().unix_timestamp() as u64 + 100);
-    let rev_reg_delta = RevocationRegistryDelta::create_from_ledger(profile, rev_reg_id, from, to).await?;
+    let ledger = Arc::clone(profile).inject_ledger();
+    let (rev_reg_delta, _) = ledger.get_rev_reg_delta(rev_reg_id, from, to).await?;
     Ok(rev_reg_delta.revoked().iter().any(|s| s.to_string().eq(rev_id)))
 }
 
@@ -71,8 +54,7 @@ mod integration_tests {
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
 
-            let cred_json = anoncreds.prover_get_credential(&cred_id).await.unwrap();
-            let prover_cred = serde_json::from_str::<ProverCredential>(&cred_json).unwrap();
+            let prover_cred = anoncreds.prover_get_credential(&cred_id).await.unwrap();
 
             assert_eq!(prover_cred.schema_id, schema_id);
             assert_eq!(prover_cred.cred_def_id, cred_def_id);
@@ -43,33 +43,11 @@ pub async fn rotate_verkey(profile: &Arc<dyn Profile>, did: &str) -> VcxResult<(
 pub async fn get_verkey_from_ledger(profile: &Arc<dyn Profile>, did: &str) -> VcxResult<String> {
     let ledger = Arc::clone(profile).inject_ledger();
 
-    let nym_response: String = ledger.get_nym(did).await?;
-    let nym_json: Value = serde_json::from_str(&nym_response).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!("Cannot deserialize {:?} into Value, err: {:?}", nym_response, err),
-        )
-    })?;
-    let nym_data: String = nym_json["result"]["data"]
-        .as_str()
-        .ok_or(AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!("Cannot deserialize {:?} into String", nym_json["result"]["data"]),
-        ))?
-        .to_string();
-    let nym_data: Value = serde_json::from_str(&nym_data).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!("Cannot deserialize {:?} into Value, err: {:?}", nym_data, err),
-        )
-    })?;
-    Ok(nym_data["verkey"]
-        .as_str()
-        .ok_or(AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!("Cannot deserialize {:?} into String", nym_data["verkey"]),
-        ))?
-        .to_string())
+    let nym_response = ledger.get_nym(did).await?;
+    nym_response.verkey.ok_or(AriesVcxError::from_msg(
+        AriesVcxErrorKind::LedgerItemNotFound,
+        format!("No verkey found on the ledger for DID {}", did),
+    ))
 }
 
 #[cfg(test)]
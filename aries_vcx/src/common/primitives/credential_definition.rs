@@ -1,5 +1,7 @@
+use aries_vcx_core::anoncreds::types::CredentialDefinitionConfig;
 use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
 use aries_vcx_core::ledger::base_ledger::BaseLedger;
+use aries_vcx_core::ledger::types::{CredentialDefinition, Schema};
 
 use crate::core::profile::profile::Profile;
 use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
@@ -107,7 +109,7 @@ async fn _try_get_cred_def_from_ledger(
     ledger: &Arc<dyn BaseLedger>,
     issuer_did: &str,
     cred_def_id: &str,
-) -> VcxResult<Option<String>> {
+) -> VcxResult<Option<CredentialDefinition>> {
     if indy_mocks_enabled() {
         return Ok(None);
     }
@@ -141,7 +143,24 @@ impl CredentialDef {
             tag,
         } = config;
         let ledger = Arc::clone(profile).inject_ledger();
-        let schema_json = ledger.get_schema(&schema_id, Some(&issuer_did)).await?;
+        let schema = ledger.get_schema(&schema_id, Some(&issuer_did)).await?;
+        // generating the cred def is expensive, so fail early if it was already published
+        if let Some(seq_no) = schema.seq_no {
+            let cred_def_id = format!("{}:3:CL:{}:{}", issuer_did, seq_no, tag);
+            if _try_get_cred_def_from_ledger(&ledger, &issuer_did, &cred_def_id)
+                .await?
+                .is_some()
+            {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::CredDefAlreadyCreated,
+                    format!(
+                        "Credential definition with id {} already exists on the ledger",
+                        cred_def_id
+                    ),
+                ));
+            }
+        }
+        let schema_json = json!(schema).to_string();
         let (cred_def_id, cred_def_json) =
             generate_cred_def(profile, &issuer_did, &schema_json, &tag, None, Some(support_revocation)).await?;
         Ok(Self {
@@ -171,12 +190,13 @@ impl CredentialDef {
             self.id
         );
         let ledger = Arc::clone(profile).inject_ledger();
-        if let Some(ledger_cred_def_json) = _try_get_cred_def_from_ledger(&ledger, &self.issuer_did, &self.id).await? {
+        if let Some(ledger_cred_def) = _try_get_cred_def_from_ledger(&ledger, &self.issuer_did, &self.id).await? {
             return Err(AriesVcxError::from_msg(
                 AriesVcxErrorKind::CredDefAlreadyCreated,
                 format!(
                     "Credential definition with id {} already exists on the ledger: {}",
-                    self.id, ledger_cred_def_json
+                    self.id,
+                    json!(ledger_cred_def)
                 ),
             ));
         }
@@ -262,13 +282,21 @@ pub async fn generate_cred_def(
         return Ok((CRED_DEF_ID.to_string(), CRED_DEF_JSON.to_string()));
     }
 
-    let config_json = json!({"support_revocation": support_revocation.unwrap_or(false)}).to_string();
+    let schema: Schema = serde_json::from_str(schema_json).map_err(|err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidSchema,
+            format!("Cannot deserialize schema: {}", err),
+        )
+    })?;
+    let config = CredentialDefinitionConfig {
+        support_revocation: support_revocation.unwrap_or(false),
+    };
 
     let anoncreds = Arc::clone(profile).inject_anoncreds();
-    anoncreds
-        .issuer_create_and_store_credential_def(issuer_did, schema_json, tag, sig_type, &config_json)
-        .await
-        .map_err(|err| err.into())
+    let (cred_def_id, cred_def) = anoncreds
+        .issuer_create_and_store_credential_def(issuer_did, &schema, tag, sig_type, &config)
+        .await?;
+    Ok((cred_def_id, serde_json::to_string(&cred_def)?))
 }
 
 #[cfg(test)]
@@ -290,7 +318,7 @@ pub mod integration_tests {
                 create_and_write_test_schema(&setup.profile, &setup.institution_did, DEFAULT_SCHEMA_ATTRS).await;
 
             let ledger = Arc::clone(&setup.profile).inject_ledger();
-            let schema_json = ledger.get_schema(&schema_id, None).await.unwrap();
+            let schema_json = json!(ledger.get_schema(&schema_id, None).await.unwrap()).to_string();
 
             let (_, cred_def_json) = generate_cred_def(
                 &setup.profile,
@@ -318,7 +346,7 @@ pub mod integration_tests {
             let (schema_id, _) =
                 create_and_write_test_schema(&setup.profile, &setup.institution_did, DEFAULT_SCHEMA_ATTRS).await;
             let ledger = Arc::clone(&setup.profile).inject_ledger();
-            let schema_json = ledger.get_schema(&schema_id, None).await.unwrap();
+            let schema_json = json!(ledger.get_schema(&schema_id, None).await.unwrap()).to_string();
 
             let (cred_def_id, cred_def_json) = generate_cred_def(
                 &setup.profile,
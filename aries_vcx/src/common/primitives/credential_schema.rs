@@ -91,11 +91,11 @@ impl Schema {
         schema_id: &str,
     ) -> VcxResult<Self> {
         let ledger = Arc::clone(profile).inject_ledger();
-        let schema_json = ledger.get_schema(schema_id, None).await?;
-        let schema_data: SchemaData = serde_json::from_str(&schema_json).map_err(|err| {
+        let schema = ledger.get_schema(schema_id, None).await?;
+        let schema_json = serde_json::to_string(&schema).map_err(|err| {
             AriesVcxError::from_msg(
-                AriesVcxErrorKind::InvalidJson,
-                format!("Cannot deserialize schema: {}", err),
+                AriesVcxErrorKind::SerializationError,
+                format!("Cannot serialize schema: {}", err),
             )
         })?;
 
@@ -103,9 +103,9 @@ impl Schema {
             source_id: source_id.to_string(),
             schema_id: schema_id.to_string(),
             schema_json,
-            name: schema_data.name,
-            version: schema_data.version,
-            data: schema_data.attr_names,
+            name: schema.name,
+            version: schema.version,
+            data: schema.attr_names,
             submitter_did: "".to_string(),
             state: PublicEntityStateType::Published,
         })
@@ -165,7 +165,13 @@ impl Schema {
             Ok(self.schema_json.clone())
         } else {
             let ledger = Arc::clone(profile).inject_ledger();
-            Ok(ledger.get_schema(&self.schema_id, None).await?)
+            let schema = ledger.get_schema(&self.schema_id, None).await?;
+            serde_json::to_string(&schema).map_err(|err| {
+                AriesVcxError::from_msg(
+                    AriesVcxErrorKind::SerializationError,
+                    format!("Cannot serialize schema: {}", err),
+                )
+            })
         }
     }
 
@@ -48,30 +48,32 @@ pub mod integration_tests {
 
     #[tokio::test]
     #[ignore]
-    async fn test_pool_get_rev_reg_def_json() {
+    async fn test_pool_get_rev_reg_def() {
         SetupProfile::run_indy(|setup| async move {
             let attrs = r#"["address1","address2","city","state","zip"]"#;
             let (_, _, _, _, rev_reg_id, _, _) =
                 create_and_store_credential_def(&setup.profile, &setup.institution_did, attrs).await;
 
             let ledger = Arc::clone(&setup.profile).inject_ledger();
-            let _json = ledger.get_rev_reg_def_json(&rev_reg_id).await.unwrap();
+            let rev_reg_def = ledger.get_rev_reg_def(&rev_reg_id).await.unwrap();
+
+            assert_eq!(rev_reg_def.id, rev_reg_id);
         })
         .await;
     }
 
     #[tokio::test]
     #[ignore]
-    async fn test_pool_get_rev_reg_delta_json() {
+    async fn test_pool_get_rev_reg_delta() {
         SetupProfile::run_indy(|setup| async move {
             let attrs = r#"["address1","address2","city","state","zip"]"#;
             let (_, _, _, _, rev_reg_id, _, _) =
                 create_and_store_credential_def(&setup.profile, &setup.institution_did, attrs).await;
 
             let ledger = Arc::clone(&setup.profile).inject_ledger();
-            let (id, _delta, _timestamp) = ledger.get_rev_reg_delta_json(&rev_reg_id, None, None).await.unwrap();
+            let (delta, _timestamp) = ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
 
-            assert_eq!(id, rev_reg_id);
+            assert!(!delta.value.accum.is_empty());
         })
         .await;
     }
@@ -107,7 +109,7 @@ pub mod integration_tests {
             let cred_def = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
 
             assert_eq!(
-                serde_json::from_str::<serde_json::Value>(&cred_def).unwrap(),
+                serde_json::to_value(&cred_def).unwrap(),
                 serde_json::from_str::<serde_json::Value>(&cred_def_json).unwrap()
             );
         })
@@ -125,7 +127,7 @@ pub mod integration_tests {
             let rc = ledger.get_schema(&schema_id, None).await;
 
             let retrieved_schema = rc.unwrap();
-            assert!(retrieved_schema.contains(&schema_id));
+            assert_eq!(retrieved_schema.id, schema_id);
         })
         .await;
     }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
+pub use aries_vcx_core::ledger::types::{RevocationRegistryDefinition, RevocationRegistryDefinitionValue};
 
 use crate::core::profile::profile::Profile;
 use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
@@ -200,7 +201,7 @@ impl RevocationRegistry {
 
         if let Some(delta) = anoncreds.get_rev_reg_delta(&self.rev_reg_id).await? {
             ledger
-                .publish_rev_reg_delta(&self.rev_reg_id, &delta, submitter_did)
+                .publish_rev_reg_delta(&self.rev_reg_id, &serde_json::to_string(&delta)?, submitter_did)
                 .await?;
 
             info!(
@@ -231,26 +232,6 @@ impl RevocationRegistry {
     }
 }
 
-#[derive(Clone, Deserialize, Debug, Serialize, PartialEq, Eq, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct RevocationRegistryDefinitionValue {
-    pub issuance_type: String,
-    pub max_cred_num: u32,
-    pub public_keys: serde_json::Value,
-    pub tails_hash: String,
-    pub tails_location: String,
-}
-
-#[derive(Clone, Deserialize, Debug, Serialize, PartialEq, Eq, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct RevocationRegistryDefinition {
-    pub id: String,
-    pub revoc_def_type: String,
-    pub tag: String,
-    pub cred_def_id: String,
-    pub value: RevocationRegistryDefinitionValue,
-    pub ver: String,
-}
 pub async fn generate_rev_reg(
     profile: &Arc<dyn Profile>,
     issuer_did: &str,
@@ -278,22 +259,9 @@ pub async fn generate_rev_reg(
 
     let anoncreds = Arc::clone(profile).inject_anoncreds();
 
-    let (rev_reg_id, rev_reg_def_json, rev_reg_entry_json) = anoncreds
+    let (rev_reg_id, rev_reg_def, rev_reg_entry) = anoncreds
         .issuer_create_and_store_revoc_reg(issuer_did, cred_def_id, tails_dir, max_creds, tag)
         .await?;
 
-    let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(&rev_reg_def_json).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!(
-                "Failed to deserialize rev_reg_def: {:?}, error: {:?}",
-                rev_reg_def_json, err
-            ),
-        )
-    })?;
-
-    Ok((rev_reg_id, rev_reg_def, rev_reg_entry_json))
+    Ok((rev_reg_id, rev_reg_def, serde_json::to_string(&rev_reg_entry)?))
 }
-
-// consider impl revoke_credential_local in a generic (non-vdrtools) fashion
-// consider impl publish_local_revocations in a generic (non-vdrtools) fashion
@@ -1,8 +1,13 @@
+#![allow(deprecated)]
+
 use std::sync::Arc;
 
+use aries_vcx_core::ledger::types::RevocationRegistryDelta as LedgerRevocationRegistryDelta;
+
 use crate::core::profile::profile::Profile;
 use crate::errors::error::prelude::*;
 
+#[deprecated(note = "use `BaseLedger::get_rev_reg_delta`, which returns the typed revocation registry delta")]
 #[derive(Clone, Deserialize, Debug, Serialize, Default)]
 pub struct RevocationRegistryDelta {
     value: RevocationRegistryDeltaValue,
@@ -10,6 +15,7 @@ pub struct RevocationRegistryDelta {
     version: String,
 }
 
+#[deprecated(note = "use `aries_vcx_core::ledger::types::RevocationRegistryDeltaValue`")]
 #[derive(Clone, Deserialize, Debug, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct RevocationRegistryDeltaValue {
@@ -31,6 +37,20 @@ impl RevocationRegistryDeltaValue {
     }
 }
 
+impl From<LedgerRevocationRegistryDelta> for RevocationRegistryDelta {
+    fn from(delta: LedgerRevocationRegistryDelta) -> Self {
+        RevocationRegistryDelta {
+            value: RevocationRegistryDeltaValue {
+                prev_accum: delta.value.prev_accum,
+                accum: delta.value.accum,
+                issued: delta.value.issued,
+                revoked: delta.value.revoked,
+            },
+            version: delta.ver,
+        }
+    }
+}
+
 impl RevocationRegistryDelta {
     pub async fn create_from_ledger(
         profile: &Arc<dyn Profile>,
@@ -39,13 +59,8 @@ impl RevocationRegistryDelta {
         to: Option<u64>,
     ) -> VcxResult<Self> {
         let ledger = Arc::clone(profile).inject_ledger();
-        let (_, rev_reg_delta_json, _) = ledger.get_rev_reg_delta_json(rev_reg_id, from, to).await?;
-        serde_json::from_str(&rev_reg_delta_json).map_err(|err| {
-            AriesVcxError::from_msg(
-                AriesVcxErrorKind::SerializationError,
-                format!("Failed to deserialize rev_reg_delta_json from ledger, err: {}", err),
-            )
-        })
+        let (rev_reg_delta, _) = ledger.get_rev_reg_delta(rev_reg_id, from, to).await?;
+        Ok(rev_reg_delta.into())
     }
 
     pub fn issued(&self) -> &[u32] {
@@ -57,6 +72,32 @@ impl RevocationRegistryDelta {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub mod unit_tests {
+    use aries_vcx_core::ledger::types::RevocationRegistryDeltaValue as LedgerRevocationRegistryDeltaValue;
+
+    use super::*;
+
+    #[test]
+    fn test_rev_reg_delta_from_ledger_delta() {
+        let delta: RevocationRegistryDelta = LedgerRevocationRegistryDelta {
+            ver: "1.0".to_string(),
+            value: LedgerRevocationRegistryDeltaValue {
+                prev_accum: None,
+                accum: "accum".to_string(),
+                issued: vec![1, 2],
+                revoked: vec![3],
+            },
+        }
+        .into();
+
+        assert_eq!(delta.issued(), &[1, 2]);
+        assert_eq!(delta.revoked(), &[3]);
+        assert_eq!(serde_json::to_value(&delta).unwrap()["ver"], "1.0");
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub mod integration_tests {
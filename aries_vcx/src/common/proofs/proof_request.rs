@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::vec::Vec;
 
+use aries_vcx_core::anoncreds::types::ProofRequest;
 use serde_json;
 
 use crate::core::profile::profile::Profile;
@@ -146,6 +147,20 @@ impl Default for ProofRequestData {
     }
 }
 
+impl From<ProofRequestData> for ProofRequest {
+    fn from(data: ProofRequestData) -> Self {
+        Self {
+            nonce: data.nonce,
+            name: data.name,
+            version: data.data_version,
+            requested_attributes: data.requested_attributes,
+            requested_predicates: data.requested_predicates,
+            non_revoked: data.non_revoked,
+            ver: None,
+        }
+    }
+}
+
 pub type PresentationRequestData = ProofRequestData;
 
 #[allow(clippy::unwrap_used)]
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use crate::common::proofs::proof_request::ProofRequestData;
 use crate::common::proofs::prover::prover_internal::{
-    build_cred_defs_json_prover, build_requested_credentials_json, build_rev_states_json, build_schemas_json_prover,
+    build_cred_defs_prover, build_requested_credentials, build_rev_states, build_schemas_prover,
     credential_def_identifiers,
 };
 use crate::core::profile::profile::Profile;
@@ -40,22 +40,22 @@ pub async fn generate_indy_proof(
 
     let mut credentials_identifiers = credential_def_identifiers(credentials, &proof_request)?;
 
-    let revoc_states_json = build_rev_states_json(profile, &mut credentials_identifiers).await?;
+    let revoc_states = build_rev_states(profile, &mut credentials_identifiers).await?;
     let requested_credentials =
-        build_requested_credentials_json(&credentials_identifiers, self_attested_attrs, &proof_request)?;
+        build_requested_credentials(&credentials_identifiers, self_attested_attrs, &proof_request)?;
 
-    let schemas_json = build_schemas_json_prover(profile, &credentials_identifiers).await?;
-    let credential_defs_json = build_cred_defs_json_prover(profile, &credentials_identifiers).await?;
+    let schemas = build_schemas_prover(profile, &credentials_identifiers).await?;
+    let credential_defs = build_cred_defs_prover(profile, &credentials_identifiers).await?;
 
     let proof = anoncreds
         .prover_create_proof(
-            proof_req_data_json,
+            &proof_request.into(),
             &requested_credentials,
             settings::DEFAULT_LINK_SECRET_ALIAS,
-            &schemas_json,
-            &credential_defs_json,
-            Some(&revoc_states_json),
+            &schemas,
+            &credential_defs,
+            Some(&revoc_states),
         )
         .await?;
-    Ok(proof)
+    Ok(serde_json::to_string(&proof)?)
 }
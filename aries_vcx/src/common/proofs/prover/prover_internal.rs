@@ -1,6 +1,10 @@
 use std::{collections::HashMap, sync::Arc};
 
-use aries_vcx_core::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind};
+use aries_vcx_core::anoncreds::types::{
+    RequestedAttribute, RequestedCredentials, RequestedPredicate, RevocationStates,
+};
+use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
+use aries_vcx_core::ledger::types::{CredentialDefinition, Schema};
 use serde_json::Value;
 
 use crate::errors::error::prelude::*;
@@ -23,50 +27,43 @@ pub struct CredInfoProver {
     pub revealed: Option<bool>,
 }
 
-pub async fn build_schemas_json_prover(
+pub async fn build_schemas_prover(
     profile: &Arc<dyn Profile>,
     credentials_identifiers: &Vec<CredInfoProver>,
-) -> VcxResult<String> {
+) -> VcxResult<HashMap<String, Schema>> {
     trace!(
-        "build_schemas_json_prover >>> credentials_identifiers: {:?}",
+        "build_schemas_prover >>> credentials_identifiers: {:?}",
         credentials_identifiers
     );
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut rtn: Value = json!({});
+    let mut rtn = HashMap::new();
 
     for cred_info in credentials_identifiers {
-        if rtn.get(&cred_info.schema_id).is_none() {
-            let schema_json = ledger
+        if !rtn.contains_key(&cred_info.schema_id) {
+            let schema = ledger
                 .get_schema(&cred_info.schema_id, None)
                 .await
                 .map_err(|err| err.map(AriesVcxCoreErrorKind::InvalidSchema, "Cannot get schema"))?;
 
-            let schema_json = serde_json::from_str(&schema_json).map_err(|err| {
-                AriesVcxCoreError::from_msg(
-                    AriesVcxCoreErrorKind::InvalidSchema,
-                    format!("Cannot deserialize schema: {}", err),
-                )
-            })?;
-
-            rtn[cred_info.schema_id.to_owned()] = schema_json;
+            rtn.insert(cred_info.schema_id.to_owned(), schema);
         }
     }
-    Ok(rtn.to_string())
+    Ok(rtn)
 }
 
-pub async fn build_cred_defs_json_prover(
+pub async fn build_cred_defs_prover(
     profile: &Arc<dyn Profile>,
     credentials_identifiers: &Vec<CredInfoProver>,
-) -> VcxResult<String> {
+) -> VcxResult<HashMap<String, CredentialDefinition>> {
     trace!(
-        "build_cred_defs_json_prover >>> credentials_identifiers: {:?}",
+        "build_cred_defs_prover >>> credentials_identifiers: {:?}",
         credentials_identifiers
     );
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut rtn: Value = json!({});
+    let mut rtn = HashMap::new();
 
     for cred_info in credentials_identifiers {
-        if rtn.get(&cred_info.cred_def_id).is_none() {
+        if !rtn.contains_key(&cred_info.cred_def_id) {
             let credential_def = ledger.get_cred_def(&cred_info.cred_def_id, None).await.map_err(|err| {
                 err.map(
                     AriesVcxCoreErrorKind::InvalidProofCredentialData,
@@ -74,17 +71,10 @@ pub async fn build_cred_defs_json_prover(
                 )
             })?;
 
-            let credential_def = serde_json::from_str(&credential_def).map_err(|err| {
-                AriesVcxError::from_msg(
-                    AriesVcxErrorKind::InvalidProofCredentialData,
-                    format!("Cannot deserialize credential definition: {}", err),
-                )
-            })?;
-
-            rtn[cred_info.cred_def_id.to_owned()] = credential_def;
+            rtn.insert(cred_info.cred_def_id.to_owned(), credential_def);
         }
     }
-    Ok(rtn.to_string())
+    Ok(rtn)
 }
 
 pub fn credential_def_identifiers(credentials: &str, proof_req: &ProofRequestData) -> VcxResult<Vec<CredInfoProver>> {
@@ -159,24 +149,24 @@ fn _get_revocation_interval(attr_name: &str, proof_req: &ProofRequestData) -> Vc
     }
 }
 
-pub async fn build_rev_states_json(
+pub async fn build_rev_states(
     profile: &Arc<dyn Profile>,
     credentials_identifiers: &mut Vec<CredInfoProver>,
-) -> VcxResult<String> {
+) -> VcxResult<RevocationStates> {
     trace!(
-        "build_rev_states_json >> credentials_identifiers: {:?}",
+        "build_rev_states >> credentials_identifiers: {:?}",
         credentials_identifiers
     );
     let ledger = Arc::clone(profile).inject_ledger();
     let anoncreds = Arc::clone(profile).inject_anoncreds();
-    let mut rtn: Value = json!({});
+    let mut rtn: RevocationStates = HashMap::new();
     let mut timestamps: HashMap<String, u64> = HashMap::new();
 
     for cred_info in credentials_identifiers.iter_mut() {
         if let (Some(rev_reg_id), Some(cred_rev_id), Some(tails_file)) =
             (&cred_info.rev_reg_id, &cred_info.cred_rev_id, &cred_info.tails_file)
         {
-            if rtn.get(rev_reg_id).is_none() {
+            if !rtn.contains_key(rev_reg_id) {
                 // Does this make sense in case cred_info's for same rev_reg_ids have different revocation intervals
                 let (from, to) = if let Some(ref interval) = cred_info.revocation_interval {
                     (interval.from, interval.to)
@@ -184,31 +174,17 @@ pub async fn build_rev_states_json(
                     (None, None)
                 };
 
-                let rev_reg_def_json = ledger.get_rev_reg_def_json(rev_reg_id).await?;
+                let rev_reg_def = ledger.get_rev_reg_def(rev_reg_id).await?;
 
-                let (rev_reg_id, rev_reg_delta_json, timestamp) =
-                    ledger.get_rev_reg_delta_json(rev_reg_id, from, to).await?;
+                let (rev_reg_delta, timestamp) = ledger.get_rev_reg_delta(rev_reg_id, from, to).await?;
 
-                let rev_state_json = anoncreds
-                    .create_revocation_state(
-                        tails_file,
-                        &rev_reg_def_json,
-                        &rev_reg_delta_json,
-                        timestamp,
-                        cred_rev_id,
-                    )
+                let rev_state = anoncreds
+                    .create_revocation_state(tails_file, &rev_reg_def, &rev_reg_delta, timestamp, cred_rev_id)
                     .await?;
 
-                let rev_state_json: Value = serde_json::from_str(&rev_state_json).map_err(|err| {
-                    AriesVcxError::from_msg(
-                        AriesVcxErrorKind::InvalidJson,
-                        format!("Cannot deserialize RevocationState: {}", err),
-                    )
-                })?;
-
                 // TODO: proover should be able to create multiple states of same revocation policy for different timestamps
                 // see ticket IS-1108
-                rtn[rev_reg_id.to_string()] = json!({ timestamp.to_string(): rev_state_json });
+                rtn.insert(rev_reg_id.to_string(), HashMap::from([(timestamp, rev_state)]));
                 cred_info.timestamp = Some(timestamp);
 
                 // Cache timestamp for future attributes that have the same rev_reg_id
@@ -223,61 +199,63 @@ pub async fn build_rev_states_json(
         }
     }
 
-    Ok(rtn.to_string())
+    Ok(rtn)
 }
 
-pub fn build_requested_credentials_json(
+pub fn build_requested_credentials(
     credentials_identifiers: &Vec<CredInfoProver>,
     self_attested_attrs: &str,
     proof_req: &ProofRequestData,
-) -> VcxResult<String> {
+) -> VcxResult<RequestedCredentials> {
     trace!(
-        "build_requested_credentials_json >> credentials_identifiers: {:?}, self_attested_attrs: {:?}, proof_req: {:?}",
+        "build_requested_credentials >> credentials_identifiers: {:?}, self_attested_attrs: {:?}, proof_req: {:?}",
         credentials_identifiers,
         self_attested_attrs,
         proof_req
     );
-    let mut rtn: Value = json!({
-          "self_attested_attributes":{},
-          "requested_attributes":{},
-          "requested_predicates":{}
-    });
-    // do same for predicates and self_attested
-    if let Value::Object(ref mut map) = rtn["requested_attributes"] {
-        for cred_info in credentials_identifiers {
-            if proof_req.requested_attributes.get(&cred_info.requested_attr).is_some() {
-                let insert_val = json!({"cred_id": cred_info.referent, "revealed": cred_info.revealed.unwrap_or(true), "timestamp": cred_info.timestamp});
-                map.insert(cred_info.requested_attr.to_owned(), insert_val);
-            }
+    let mut rtn = RequestedCredentials::default();
+
+    for cred_info in credentials_identifiers {
+        if proof_req.requested_attributes.get(&cred_info.requested_attr).is_some() {
+            rtn.requested_attributes.insert(
+                cred_info.requested_attr.to_owned(),
+                RequestedAttribute {
+                    cred_id: cred_info.referent.to_owned(),
+                    timestamp: cred_info.timestamp,
+                    revealed: cred_info.revealed.unwrap_or(true),
+                },
+            );
         }
     }
 
-    if let Value::Object(ref mut map) = rtn["requested_predicates"] {
-        for cred_info in credentials_identifiers {
-            if proof_req.requested_predicates.get(&cred_info.requested_attr).is_some() {
-                let insert_val = json!({"cred_id": cred_info.referent, "timestamp": cred_info.timestamp});
-                map.insert(cred_info.requested_attr.to_owned(), insert_val);
-            }
+    for cred_info in credentials_identifiers {
+        if proof_req.requested_predicates.get(&cred_info.requested_attr).is_some() {
+            rtn.requested_predicates.insert(
+                cred_info.requested_attr.to_owned(),
+                RequestedPredicate {
+                    cred_id: cred_info.referent.to_owned(),
+                    timestamp: cred_info.timestamp,
+                },
+            );
         }
     }
 
     // handle if the attribute is not revealed
-    let self_attested_attrs: Value = serde_json::from_str(self_attested_attrs).map_err(|err| {
+    rtn.self_attested_attributes = serde_json::from_str(self_attested_attrs).map_err(|err| {
         AriesVcxError::from_msg(
             AriesVcxErrorKind::InvalidJson,
             format!("Cannot deserialize self attested attributes: {}", err),
         )
     })?;
-    rtn["self_attested_attributes"] = self_attested_attrs;
 
-    Ok(rtn.to_string())
+    Ok(rtn)
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub mod pool_tests {
 
-    use crate::common::proofs::prover::prover_internal::{build_rev_states_json, CredInfoProver};
+    use crate::common::proofs::prover::prover_internal::{build_rev_states, CredInfoProver};
     use crate::utils::constants::{CRED_DEF_ID, CRED_REV_ID, LICENCE_CRED_ID, SCHEMA_ID, TAILS_DIR};
     use crate::utils::devsetup::SetupProfile;
     use crate::utils::get_temp_dir_path;
@@ -287,12 +265,10 @@ pub mod pool_tests {
     async fn test_pool_build_rev_states_json_empty() {
         SetupProfile::run(|_setup| async move {
             // empty vector
-            assert_eq!(
-                build_rev_states_json(&_setup.profile, Vec::new().as_mut())
-                    .await
-                    .unwrap(),
-                "{}".to_string()
-            );
+            assert!(build_rev_states(&_setup.profile, Vec::new().as_mut())
+                .await
+                .unwrap()
+                .is_empty());
 
             // no rev_reg_id
             let cred1 = CredInfoProver {
@@ -307,12 +283,10 @@ pub mod pool_tests {
                 timestamp: None,
                 revealed: None,
             };
-            assert_eq!(
-                build_rev_states_json(&_setup.profile, vec![cred1].as_mut())
-                    .await
-                    .unwrap(),
-                "{}".to_string()
-            );
+            assert!(build_rev_states(&_setup.profile, vec![cred1].as_mut())
+                .await
+                .unwrap()
+                .is_empty());
         })
         .await;
     }
@@ -382,9 +356,11 @@ pub mod unit_tests {
         };
         let creds = vec![cred1, cred2];
 
-        let credential_def = build_cred_defs_json_prover(&mock_profile(), &creds).await.unwrap();
-        assert!(credential_def.len() > 0);
-        assert!(credential_def.contains(r#""id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:47:tag1","schemaId":"47""#));
+        let credential_defs = build_cred_defs_prover(&mock_profile(), &creds).await.unwrap();
+        assert_eq!(credential_defs.len(), 2);
+        let credential_def = &credential_defs[CRED_DEF_ID];
+        assert_eq!(credential_def.id, "V4SGRU86Z58d6TV7PBUe6f:3:CL:47:tag1");
+        assert_eq!(credential_def.schema_id, "47");
     }
 
     #[tokio::test]
@@ -403,7 +379,7 @@ pub mod unit_tests {
                 timestamp: None,
                 revealed: None,
             }];
-            let err_kind = build_cred_defs_json_prover(&profile, &credential_ids)
+            let err_kind = build_cred_defs_prover(&profile, &credential_ids)
                 .await
                 .unwrap_err()
                 .kind();
@@ -430,7 +406,7 @@ pub mod unit_tests {
             }];
 
             assert_eq!(
-                build_schemas_json_prover(&profile, &credential_ids)
+                build_schemas_prover(&profile, &credential_ids)
                     .await
                     .unwrap_err()
                     .kind(),
@@ -444,10 +420,10 @@ pub mod unit_tests {
     async fn test_find_schemas() {
         let _setup = SetupMocks::init();
 
-        assert_eq!(
-            build_schemas_json_prover(&mock_profile(), &Vec::new()).await.unwrap(),
-            "{}".to_string()
-        );
+        assert!(build_schemas_prover(&mock_profile(), &Vec::new())
+            .await
+            .unwrap()
+            .is_empty());
 
         let cred1 = CredInfoProver {
             requested_attr: "height_1".to_string(),
@@ -475,9 +451,11 @@ pub mod unit_tests {
         };
         let creds = vec![cred1, cred2];
 
-        let schemas = build_schemas_json_prover(&mock_profile(), &creds).await.unwrap();
-        assert!(schemas.len() > 0);
-        assert!(schemas.contains(r#""id":"2hoqvcwupRTUNkXn6ArYzs:2:test-licence:4.4.4","name":"test-licence""#));
+        let schemas = build_schemas_prover(&mock_profile(), &creds).await.unwrap();
+        assert_eq!(schemas.len(), 2);
+        let schema = &schemas[SCHEMA_ID];
+        assert_eq!(schema.id, "2hoqvcwupRTUNkXn6ArYzs:2:test-licence:4.4.4");
+        assert_eq!(schema.name, "test-licence");
     }
 
     #[test]
@@ -734,17 +712,31 @@ pub mod unit_tests {
         })
         .to_string();
 
-        let test: Value = json!({
-              "self_attested_attributes":{
-                  "self_attested_attr_3": "my self attested 1",
-                  "self_attested_attr_4": "my self attested 2",
-              },
-              "requested_attributes":{
-                  "height_1": {"cred_id": LICENCE_CRED_ID, "revealed": true, "timestamp": 800},
-                  "zip_2": {"cred_id": ADDRESS_CRED_ID, "revealed": false, "timestamp": 800},
-              },
-              "requested_predicates":{}
-        });
+        let test = RequestedCredentials {
+            self_attested_attributes: HashMap::from([
+                ("self_attested_attr_3".to_string(), "my self attested 1".to_string()),
+                ("self_attested_attr_4".to_string(), "my self attested 2".to_string()),
+            ]),
+            requested_attributes: HashMap::from([
+                (
+                    "height_1".to_string(),
+                    RequestedAttribute {
+                        cred_id: LICENCE_CRED_ID.to_string(),
+                        timestamp: Some(800),
+                        revealed: true,
+                    },
+                ),
+                (
+                    "zip_2".to_string(),
+                    RequestedAttribute {
+                        cred_id: ADDRESS_CRED_ID.to_string(),
+                        timestamp: Some(800),
+                        revealed: false,
+                    },
+                ),
+            ]),
+            requested_predicates: HashMap::new(),
+        };
 
         let proof_req = json!({
             "nonce": "123432421212",
@@ -761,12 +753,12 @@ pub mod unit_tests {
             "non_revoked": {"from": 098, "to": 123}
         });
         let proof_req: ProofRequestData = serde_json::from_value(proof_req).unwrap();
-        let requested_credential = build_requested_credentials_json(&creds, &self_attested_attrs, &proof_req).unwrap();
-        assert_eq!(test.to_string(), requested_credential);
+        let requested_credential = build_requested_credentials(&creds, &self_attested_attrs, &proof_req).unwrap();
+        assert_eq!(test, requested_credential);
     }
 
     #[tokio::test]
-    async fn test_build_rev_states_json() {
+    async fn test_build_rev_states() {
         let _setup = SetupMocks::init();
 
         let cred1 = CredInfoProver {
@@ -782,11 +774,9 @@ pub mod unit_tests {
             revealed: None,
         };
         let mut cred_info = vec![cred1];
-        let states = build_rev_states_json(&mock_profile(), cred_info.as_mut())
-            .await
-            .unwrap();
-        let rev_state_json: Value = serde_json::from_str(REV_STATE_JSON).unwrap();
-        let expected = json!({REV_REG_ID: {"1": rev_state_json}}).to_string();
+        let states = build_rev_states(&mock_profile(), cred_info.as_mut()).await.unwrap();
+        let rev_state = serde_json::from_str(REV_STATE_JSON).unwrap();
+        let expected = HashMap::from([(REV_REG_ID.to_string(), HashMap::from([(1, rev_state)]))]);
         assert_eq!(states, expected);
         assert!(cred_info[0].timestamp.is_some());
     }
@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
+use aries_vcx_core::anoncreds::types::{Proof, ProofRequest};
+
 use crate::common::proofs::verifier::verifier_internal::{
-    build_cred_defs_json_verifier, build_rev_reg_defs_json, build_rev_reg_json, build_schemas_json_verifier,
-    get_credential_info, validate_proof_revealed_attributes,
+    build_cred_defs_verifier, build_rev_reg_defs, build_rev_regs, build_schemas_verifier, get_credential_info,
+    validate_proof_revealed_attributes,
 };
 use crate::core::profile::profile::Profile;
 use crate::errors::error::prelude::*;
@@ -18,38 +20,39 @@ pub async fn validate_indy_proof(
     }
 
     let anoncreds = Arc::clone(profile).inject_anoncreds();
-    validate_proof_revealed_attributes(proof_json)?;
+    let proof: Proof = serde_json::from_str(proof_json).map_err(|err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("Cannot deserialize libndy proof: {}", err),
+        )
+    })?;
+    let proof_req: ProofRequest = serde_json::from_str(proof_req_json).map_err(|err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("Cannot deserialize proof request: {}", err),
+        )
+    })?;
+    validate_proof_revealed_attributes(&proof)?;
 
-    let credential_data = get_credential_info(proof_json)?;
+    let credential_data = get_credential_info(&proof);
 
-    let credential_defs_json = build_cred_defs_json_verifier(profile, &credential_data)
-        .await
-        .unwrap_or(json!({}).to_string());
-    let schemas_json = build_schemas_json_verifier(profile, &credential_data)
-        .await
-        .unwrap_or(json!({}).to_string());
-    let rev_reg_defs_json = build_rev_reg_defs_json(profile, &credential_data)
+    let credential_defs = build_cred_defs_verifier(profile, &credential_data)
         .await
-        .unwrap_or(json!({}).to_string());
-    let rev_regs_json = build_rev_reg_json(profile, &credential_data)
+        .unwrap_or_default();
+    let schemas = build_schemas_verifier(profile, &credential_data)
         .await
-        .unwrap_or(json!({}).to_string());
+        .unwrap_or_default();
+    let rev_reg_defs = build_rev_reg_defs(profile, &credential_data).await.unwrap_or_default();
+    let rev_regs = build_rev_regs(profile, &credential_data).await.unwrap_or_default();
 
-    debug!("*******\n{}\n********", credential_defs_json);
-    debug!("*******\n{}\n********", schemas_json);
+    debug!("*******\n{:?}\n********", credential_defs);
+    debug!("*******\n{:?}\n********", schemas);
     debug!("*******\n{}\n********", proof_json);
     debug!("*******\n{}\n********", proof_req_json);
-    debug!("*******\n{}\n********", rev_reg_defs_json);
-    debug!("*******\n{}\n********", rev_regs_json);
+    debug!("*******\n{:?}\n********", rev_reg_defs);
+    debug!("*******\n{:?}\n********", rev_regs);
     anoncreds
-        .verifier_verify_proof(
-            proof_req_json,
-            proof_json,
-            &schemas_json,
-            &credential_defs_json,
-            &rev_reg_defs_json,
-            &rev_regs_json,
-        )
+        .verifier_verify_proof(&proof_req, &proof, &schemas, &credential_defs, &rev_reg_defs, &rev_regs)
         .await
         .map_err(|err| err.into())
 }
@@ -57,6 +60,10 @@ pub async fn validate_indy_proof(
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub mod unit_tests {
+    use std::collections::HashMap;
+
+    use aries_vcx_core::anoncreds::types::RequestedCredentials;
+
     use crate::common::proofs::proof_request::ProofRequestData;
     use crate::common::test_utils::create_and_store_nonrevocable_credential;
     use crate::utils;
@@ -93,28 +100,30 @@ pub mod unit_tests {
                 .set_not_revoked_interval(revocation_details)
                 .unwrap();
 
-            let proof_req_json = serde_json::to_string(&proof_req_json).unwrap();
+            let proof_req: ProofRequest = proof_req_json.into();
+            let proof_req_json = serde_json::to_string(&proof_req).unwrap();
+
+            let requested_credentials = RequestedCredentials {
+                self_attested_attributes: HashMap::from([
+                    ("attribute_0".to_string(), "my_self_attested_address".to_string()),
+                    ("attribute_1".to_string(), "my_self_attested_zip".to_string()),
+                ]),
+                ..Default::default()
+            };
 
             let anoncreds = Arc::clone(&setup.profile).inject_anoncreds();
-            let prover_proof_json = anoncreds
+            let prover_proof = anoncreds
                 .prover_create_proof(
-                    &proof_req_json,
-                    &json!({
-                      "self_attested_attributes":{
-                         "attribute_0": "my_self_attested_address",
-                         "attribute_1": "my_self_attested_zip"
-                      },
-                      "requested_attributes":{},
-                      "requested_predicates":{}
-                    })
-                    .to_string(),
+                    &proof_req,
+                    &requested_credentials,
                     "main",
-                    &json!({}).to_string(),
-                    &json!({}).to_string(),
+                    &HashMap::new(),
+                    &HashMap::new(),
                     None,
                 )
                 .await
                 .unwrap();
+            let prover_proof_json = serde_json::to_string(&prover_proof).unwrap();
 
             assert_eq!(
                 validate_indy_proof(&setup.profile, &prover_proof_json, &proof_req_json.to_string())
@@ -160,7 +169,8 @@ pub mod unit_tests {
                 .set_not_revoked_interval(revocation_details)
                 .unwrap();
 
-            let proof_req_json = serde_json::to_string(&proof_req_json).unwrap();
+            let proof_req: ProofRequest = proof_req_json.into();
+            let proof_req_json = serde_json::to_string(&proof_req).unwrap();
 
             let (schema_id, schema_json, cred_def_id, cred_def_json, _offer, _req, _req_meta, cred_id) =
                 create_and_store_nonrevocable_credential(
@@ -170,31 +180,26 @@ pub mod unit_tests {
                     utils::constants::DEFAULT_SCHEMA_ATTRS,
                 )
                 .await;
-            let cred_def_json: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-            let schema_json: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            let schemas = HashMap::from([(schema_id, serde_json::from_str(&schema_json).unwrap())]);
+            let cred_defs = HashMap::from([(cred_def_id, serde_json::from_str(&cred_def_json).unwrap())]);
+            let requested_credentials: RequestedCredentials = serde_json::from_value(json!({
+                "self_attested_attributes":{
+                   "attribute_2": "my_self_attested_val"
+                },
+                "requested_attributes":{
+                   "attribute_0": {"cred_id": cred_id, "revealed": true},
+                   "attribute_1": {"cred_id": cred_id, "revealed": true}
+                },
+                "requested_predicates":{}
+            }))
+            .unwrap();
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
-            let prover_proof_json = anoncreds
-                .prover_create_proof(
-                    &proof_req_json,
-                    &json!({
-                        "self_attested_attributes":{
-                           "attribute_2": "my_self_attested_val"
-                        },
-                        "requested_attributes":{
-                           "attribute_0": {"cred_id": cred_id, "revealed": true},
-                           "attribute_1": {"cred_id": cred_id, "revealed": true}
-                        },
-                        "requested_predicates":{}
-                    })
-                    .to_string(),
-                    "main",
-                    &json!({ schema_id: schema_json }).to_string(),
-                    &json!({ cred_def_id: cred_def_json }).to_string(),
-                    None,
-                )
+            let prover_proof = anoncreds
+                .prover_create_proof(&proof_req, &requested_credentials, "main", &schemas, &cred_defs, None)
                 .await
                 .unwrap();
+            let prover_proof_json = serde_json::to_string(&prover_proof).unwrap();
             assert_eq!(
                 validate_indy_proof(&holder_setup.profile, &prover_proof_json, &proof_req_json)
                     .await
@@ -250,7 +255,8 @@ pub mod unit_tests {
                 .set_not_revoked_interval(revocation_details)
                 .unwrap();
 
-            let proof_req_json = serde_json::to_string(&proof_req_json).unwrap();
+            let proof_req: ProofRequest = proof_req_json.into();
+            let proof_req_json = serde_json::to_string(&proof_req).unwrap();
 
             let (schema_id, schema_json, cred_def_id, cred_def_json, _offer, _req, _req_meta, cred_id) =
                 create_and_store_nonrevocable_credential(
@@ -260,31 +266,26 @@ pub mod unit_tests {
                     utils::constants::DEFAULT_SCHEMA_ATTRS,
                 )
                 .await;
-            let cred_def_json: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-            let schema_json: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            let schemas = HashMap::from([(schema_id, serde_json::from_str(&schema_json).unwrap())]);
+            let cred_defs = HashMap::from([(cred_def_id, serde_json::from_str(&cred_def_json).unwrap())]);
+            let requested_credentials: RequestedCredentials = serde_json::from_value(json!({
+                "self_attested_attributes":{
+                   "attribute_2": "my_self_attested_val"
+                },
+                "requested_attributes":{
+                   "attribute_0": {"cred_id": cred_id, "revealed": true},
+                   "attribute_1": {"cred_id": cred_id, "revealed": true}
+                },
+                "requested_predicates":{}
+            }))
+            .unwrap();
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
-            let prover_proof_json = anoncreds
-                .prover_create_proof(
-                    &proof_req_json,
-                    &json!({
-                        "self_attested_attributes":{
-                           "attribute_2": "my_self_attested_val"
-                        },
-                        "requested_attributes":{
-                           "attribute_0": {"cred_id": cred_id, "revealed": true},
-                           "attribute_1": {"cred_id": cred_id, "revealed": true}
-                        },
-                        "requested_predicates":{}
-                    })
-                    .to_string(),
-                    "main",
-                    &json!({ schema_id: schema_json }).to_string(),
-                    &json!({ cred_def_id: cred_def_json }).to_string(),
-                    None,
-                )
+            let prover_proof = anoncreds
+                .prover_create_proof(&proof_req, &requested_credentials, "main", &schemas, &cred_defs, None)
                 .await
                 .unwrap();
+            let prover_proof_json = serde_json::to_string(&prover_proof).unwrap();
             assert_eq!(
                 validate_indy_proof(&holder_setup.profile, &prover_proof_json, &proof_req_json)
                     .await
@@ -326,6 +327,7 @@ pub mod unit_tests {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 pub mod integration_tests {
+    use std::collections::HashMap;
     use std::sync::Arc;
 
     use crate::common::test_utils::{create_indy_proof, create_proof_with_predicate};
@@ -342,7 +344,14 @@ pub mod integration_tests {
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
             let proof_validation = anoncreds
-                .verifier_verify_proof(&proof_req, &proof, &schemas, &cred_defs, "{}", "{}")
+                .verifier_verify_proof(
+                    &proof_req,
+                    &proof,
+                    &schemas,
+                    &cred_defs,
+                    &HashMap::new(),
+                    &HashMap::new(),
+                )
                 .await
                 .unwrap();
 
@@ -362,7 +371,14 @@ pub mod integration_tests {
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
             let proof_validation = anoncreds
-                .verifier_verify_proof(&proof_req, &proof, &schemas, &cred_defs, "{}", "{}")
+                .verifier_verify_proof(
+                    &proof_req,
+                    &proof,
+                    &schemas,
+                    &cred_defs,
+                    &HashMap::new(),
+                    &HashMap::new(),
+                )
                 .await
                 .unwrap();
 
@@ -382,7 +398,14 @@ pub mod integration_tests {
 
             let anoncreds = Arc::clone(&holder_setup.profile).inject_anoncreds();
             anoncreds
-                .verifier_verify_proof(&proof_req, &proof, &schemas, &cred_defs, "{}", "{}")
+                .verifier_verify_proof(
+                    &proof_req,
+                    &proof,
+                    &schemas,
+                    &cred_defs,
+                    &HashMap::new(),
+                    &HashMap::new(),
+                )
                 .await
                 .unwrap_err();
         })
@@ -1,8 +1,10 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use aries_vcx_core::anoncreds::types::{Proof, RevocationRegistries, RevocationRegistry};
 use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
+use aries_vcx_core::ledger::types::{CredentialDefinition, RevocationRegistryDefinition, Schema};
 use serde_json;
-use serde_json::Value;
 
 use crate::core::profile::profile::Profile;
 use crate::errors::error::prelude::*;
@@ -17,77 +19,33 @@ pub struct CredInfoVerifier {
     pub timestamp: Option<u64>,
 }
 
-pub fn get_credential_info(proof: &str) -> VcxResult<Vec<CredInfoVerifier>> {
-    let mut rtn = Vec::new();
-
-    let credentials: Value = serde_json::from_str(proof).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::InvalidJson,
-            format!("Cannot deserialize libndy proof: {}", err),
-        )
-    })?;
-
-    if let Value::Array(ref identifiers) = credentials["identifiers"] {
-        for identifier in identifiers {
-            if let (Some(schema_id), Some(cred_def_id)) =
-                (identifier["schema_id"].as_str(), identifier["cred_def_id"].as_str())
-            {
-                let rev_reg_id = identifier["rev_reg_id"].as_str().map(|x| x.to_string());
-
-                let timestamp = identifier["timestamp"].as_u64();
-                rtn.push(CredInfoVerifier {
-                    schema_id: schema_id.to_string(),
-                    cred_def_id: cred_def_id.to_string(),
-                    rev_reg_id,
-                    timestamp,
-                });
-            } else {
-                return Err(AriesVcxError::from_msg(
-                    AriesVcxErrorKind::InvalidProofCredentialData,
-                    "Cannot get identifiers",
-                ));
-            }
-        }
-    }
-
-    Ok(rtn)
+pub fn get_credential_info(proof: &Proof) -> Vec<CredInfoVerifier> {
+    proof
+        .identifiers
+        .iter()
+        .map(|identifier| CredInfoVerifier {
+            schema_id: identifier.schema_id.to_owned(),
+            cred_def_id: identifier.cred_def_id.to_owned(),
+            rev_reg_id: identifier.rev_reg_id.to_owned(),
+            timestamp: identifier.timestamp,
+        })
+        .collect()
 }
 
-pub fn validate_proof_revealed_attributes(proof_json: &str) -> VcxResult<()> {
+pub fn validate_proof_revealed_attributes(proof: &Proof) -> VcxResult<()> {
     if settings::indy_mocks_enabled() {
         return Ok(());
     }
 
-    let proof: Value = serde_json::from_str(proof_json).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::InvalidJson,
-            format!("Cannot deserialize libndy proof: {}", err),
-        )
-    })?;
-
-    let revealed_attrs = match proof["requested_proof"]["revealed_attrs"].as_object() {
-        Some(revealed_attrs) => revealed_attrs,
-        None => return Ok(()),
-    };
-
-    for (attr1_referent, info) in revealed_attrs.iter() {
-        let raw = info["raw"].as_str().ok_or(AriesVcxError::from_msg(
-            AriesVcxErrorKind::InvalidProof,
-            format!("Cannot get raw value for \"{}\" attribute", attr1_referent),
-        ))?;
-        let encoded_ = info["encoded"].as_str().ok_or(AriesVcxError::from_msg(
-            AriesVcxErrorKind::InvalidProof,
-            format!("Cannot get encoded value for \"{}\" attribute", attr1_referent),
-        ))?;
-
-        let expected_encoded = encode(raw)?;
+    for (attr1_referent, info) in proof.requested_proof.revealed_attrs.iter() {
+        let expected_encoded = encode(&info.raw)?;
 
-        if expected_encoded != *encoded_ {
+        if expected_encoded != info.encoded {
             return Err(AriesVcxError::from_msg(
                 AriesVcxErrorKind::InvalidProof,
                 format!(
-                    "Encoded values are different. Expected: {}. From Proof: {}",
-                    expected_encoded, encoded_
+                    "Encoded values of \"{}\" attribute are different. Expected: {}. From Proof: {}",
+                    attr1_referent, expected_encoded, info.encoded
                 ),
             ));
         }
@@ -96,70 +54,56 @@ pub fn validate_proof_revealed_attributes(proof_json: &str) -> VcxResult<()> {
     Ok(())
 }
 
-pub async fn build_cred_defs_json_verifier(
+pub async fn build_cred_defs_verifier(
     profile: &Arc<dyn Profile>,
     credential_data: &[CredInfoVerifier],
-) -> VcxResult<String> {
-    debug!("building credential_def_json for proof validation");
+) -> VcxResult<HashMap<String, CredentialDefinition>> {
+    debug!("building credential definitions for proof validation");
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut credential_json = json!({});
+    let mut credential_defs = HashMap::new();
 
     for cred_info in credential_data.iter() {
-        if credential_json.get(&cred_info.cred_def_id).is_none() {
+        if !credential_defs.contains_key(&cred_info.cred_def_id) {
             let cred_def_id = &cred_info.cred_def_id;
             let credential_def = ledger.get_cred_def(cred_def_id, None).await?;
-
-            let credential_def = serde_json::from_str(&credential_def).map_err(|err| {
-                AriesVcxError::from_msg(
-                    AriesVcxErrorKind::InvalidProofCredentialData,
-                    format!("Cannot deserialize credential definition: {}", err),
-                )
-            })?;
-
-            credential_json[cred_def_id] = credential_def;
+            credential_defs.insert(cred_def_id.to_owned(), credential_def);
         }
     }
 
-    Ok(credential_json.to_string())
+    Ok(credential_defs)
 }
 
-pub async fn build_schemas_json_verifier(
+pub async fn build_schemas_verifier(
     profile: &Arc<dyn Profile>,
     credential_data: &[CredInfoVerifier],
-) -> VcxResult<String> {
-    debug!("building schemas json for proof validation");
+) -> VcxResult<HashMap<String, Schema>> {
+    debug!("building schemas for proof validation");
 
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut schemas_json = json!({});
+    let mut schemas = HashMap::new();
 
     for cred_info in credential_data.iter() {
-        if schemas_json.get(&cred_info.schema_id).is_none() {
+        if !schemas.contains_key(&cred_info.schema_id) {
             let schema_id = &cred_info.schema_id;
-            let schema_json = ledger
+            let schema = ledger
                 .get_schema(schema_id, None)
                 .await
                 .map_err(|err| err.map(AriesVcxCoreErrorKind::InvalidSchema, "Cannot get schema"))?;
-            let schema_val = serde_json::from_str(&schema_json).map_err(|err| {
-                AriesVcxError::from_msg(
-                    AriesVcxErrorKind::InvalidSchema,
-                    format!("Cannot deserialize schema: {}", err),
-                )
-            })?;
-            schemas_json[schema_id] = schema_val;
+            schemas.insert(schema_id.to_owned(), schema);
         }
     }
 
-    Ok(schemas_json.to_string())
+    Ok(schemas)
 }
 
-pub async fn build_rev_reg_defs_json(
+pub async fn build_rev_reg_defs(
     profile: &Arc<dyn Profile>,
     credential_data: &[CredInfoVerifier],
-) -> VcxResult<String> {
-    debug!("building rev_reg_def_json for proof validation");
+) -> VcxResult<HashMap<String, RevocationRegistryDefinition>> {
+    debug!("building revocation registry definitions for proof validation");
 
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut rev_reg_defs_json = json!({});
+    let mut rev_reg_defs = HashMap::new();
 
     for cred_info in credential_data.iter() {
         let rev_reg_id = cred_info.rev_reg_id.as_ref().ok_or(AriesVcxError::from_msg(
@@ -167,24 +111,23 @@ pub async fn build_rev_reg_defs_json(
             format!("Missing rev_reg_id in the record {:?}", cred_info),
         ))?;
 
-        if rev_reg_defs_json.get(rev_reg_id).is_none() {
-            let json = ledger.get_rev_reg_def_json(rev_reg_id).await?;
-            let rev_reg_def_json = serde_json::from_str(&json).or(Err(AriesVcxError::from_msg(
-                AriesVcxErrorKind::InvalidJson,
-                format!("Failed to deserialize as json rev_reg_def: {}", json),
-            )))?;
-            rev_reg_defs_json[rev_reg_id] = rev_reg_def_json;
+        if !rev_reg_defs.contains_key(rev_reg_id) {
+            let rev_reg_def = ledger.get_rev_reg_def(rev_reg_id).await?;
+            rev_reg_defs.insert(rev_reg_id.to_owned(), rev_reg_def);
         }
     }
 
-    Ok(rev_reg_defs_json.to_string())
+    Ok(rev_reg_defs)
 }
 
-pub async fn build_rev_reg_json(profile: &Arc<dyn Profile>, credential_data: &[CredInfoVerifier]) -> VcxResult<String> {
-    debug!("building rev_reg_json for proof validation");
+pub async fn build_rev_regs(
+    profile: &Arc<dyn Profile>,
+    credential_data: &[CredInfoVerifier],
+) -> VcxResult<RevocationRegistries> {
+    debug!("building revocation registries for proof validation");
 
     let ledger = Arc::clone(profile).inject_ledger();
-    let mut rev_regs_json = json!({});
+    let mut rev_regs: RevocationRegistries = HashMap::new();
 
     for cred_info in credential_data.iter() {
         let rev_reg_id = cred_info.rev_reg_id.as_ref().ok_or(AriesVcxError::from_msg(
@@ -197,18 +140,17 @@ pub async fn build_rev_reg_json(profile: &Arc<dyn Profile>, credential_data: &[C
             format!("Revocation timestamp is missing on record {:?}", cred_info),
         ))?;
 
-        if rev_regs_json.get(rev_reg_id).is_none() {
+        if !rev_regs.contains_key(rev_reg_id) {
             let (id, rev_reg_json, timestamp) = ledger.get_rev_reg(rev_reg_id, timestamp.to_owned()).await?;
-            let rev_reg_json: Value = serde_json::from_str(&rev_reg_json).or(Err(AriesVcxError::from_msg(
+            let rev_reg: RevocationRegistry = serde_json::from_str(&rev_reg_json).or(Err(AriesVcxError::from_msg(
                 AriesVcxErrorKind::InvalidJson,
                 format!("Failed to deserialize as json: {}", rev_reg_json),
             )))?;
-            let rev_reg_json = json!({ timestamp.to_string(): rev_reg_json });
-            rev_regs_json[id] = rev_reg_json;
+            rev_regs.insert(id, HashMap::from([(timestamp, rev_reg)]));
         }
     }
 
-    Ok(rev_regs_json.to_string())
+    Ok(rev_regs)
 }
 
 #[cfg(test)]
@@ -221,7 +163,7 @@ pub mod unit_tests {
     use super::*;
 
     #[tokio::test]
-    async fn test_build_cred_defs_json_verifier_with_multiple_credentials() {
+    async fn test_build_cred_defs_verifier_with_multiple_credentials() {
         let _setup = SetupMocks::init();
 
         let cred1 = CredInfoVerifier {
@@ -237,17 +179,14 @@ pub mod unit_tests {
             timestamp: None,
         };
         let credentials = vec![cred1, cred2];
-        let credential_json = build_cred_defs_json_verifier(&mock_profile(), &credentials)
-            .await
-            .unwrap();
+        let credential_defs = build_cred_defs_verifier(&mock_profile(), &credentials).await.unwrap();
 
-        let json: Value = serde_json::from_str(CRED_DEF_JSON).unwrap();
-        let expected = json!({ CRED_DEF_ID: json }).to_string();
-        assert_eq!(credential_json, expected);
+        let expected = HashMap::from([(CRED_DEF_ID.to_string(), serde_json::from_str(CRED_DEF_JSON).unwrap())]);
+        assert_eq!(credential_defs, expected);
     }
 
     #[tokio::test]
-    async fn test_build_schemas_json_verifier_with_multiple_schemas() {
+    async fn test_build_schemas_verifier_with_multiple_schemas() {
         let _setup = SetupMocks::init();
 
         let cred1 = CredInfoVerifier {
@@ -263,17 +202,14 @@ pub mod unit_tests {
             timestamp: None,
         };
         let credentials = vec![cred1, cred2];
-        let schema_json = build_schemas_json_verifier(&mock_profile(), &credentials)
-            .await
-            .unwrap();
+        let schemas = build_schemas_verifier(&mock_profile(), &credentials).await.unwrap();
 
-        let json: Value = serde_json::from_str(SCHEMA_JSON).unwrap();
-        let expected = json!({ SCHEMA_ID: json }).to_string();
-        assert_eq!(schema_json, expected);
+        let expected = HashMap::from([(SCHEMA_ID.to_string(), serde_json::from_str(SCHEMA_JSON).unwrap())]);
+        assert_eq!(schemas, expected);
     }
 
     #[tokio::test]
-    async fn test_build_rev_reg_defs_json() {
+    async fn test_build_rev_reg_defs() {
         let _setup = SetupMocks::init();
 
         let cred1 = CredInfoVerifier {
@@ -289,15 +225,14 @@ pub mod unit_tests {
             timestamp: None,
         };
         let credentials = vec![cred1, cred2];
-        let rev_reg_defs_json = build_rev_reg_defs_json(&mock_profile(), &credentials).await.unwrap();
+        let rev_reg_defs = build_rev_reg_defs(&mock_profile(), &credentials).await.unwrap();
 
-        let json: Value = serde_json::from_str(&rev_def_json()).unwrap();
-        let expected = json!({ REV_REG_ID: json }).to_string();
-        assert_eq!(rev_reg_defs_json, expected);
+        let expected = HashMap::from([(REV_REG_ID.to_string(), serde_json::from_str(&rev_def_json()).unwrap())]);
+        assert_eq!(rev_reg_defs, expected);
     }
 
     #[tokio::test]
-    async fn test_build_rev_reg_json() {
+    async fn test_build_rev_regs() {
         let _setup = SetupMocks::init();
 
         let cred1 = CredInfoVerifier {
@@ -313,10 +248,12 @@ pub mod unit_tests {
             timestamp: Some(2),
         };
         let credentials = vec![cred1, cred2];
-        let rev_reg_json = build_rev_reg_json(&mock_profile(), &credentials).await.unwrap();
+        let rev_regs = build_rev_regs(&mock_profile(), &credentials).await.unwrap();
 
-        let json: Value = serde_json::from_str(REV_REG_JSON).unwrap();
-        let expected = json!({REV_REG_ID:{"1":json}}).to_string();
-        assert_eq!(rev_reg_json, expected);
+        let expected = HashMap::from([(
+            REV_REG_ID.to_string(),
+            HashMap::from([(1, serde_json::from_str(REV_REG_JSON).unwrap())]),
+        )]);
+        assert_eq!(rev_regs, expected);
     }
 }
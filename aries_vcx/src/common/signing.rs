@@ -2,8 +2,14 @@ use std::sync::Arc;
 
 use aries_vcx_core::wallet::base_wallet::BaseWallet;
 use base64;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::decorators::attachment::{Attachment, AttachmentData, AttachmentType};
+use messages::misc::MimeType;
 use messages::msg_fields::protocols::connection::response::{ConnectionSignature, ResponseContent};
 use messages::msg_fields::protocols::connection::ConnectionData;
+use messages::msg_fields::protocols::question_answer::answer::{AnswerContent, ResponseSignature};
+use messages::msg_fields::protocols::question_answer::question::QuestionContent;
+use serde_json::Value;
 use time;
 
 use crate::errors::error::prelude::*;
@@ -78,6 +84,196 @@ pub async fn decode_signed_connection_response(
     Ok(connection)
 }
 
+fn question_answer_data(question: &QuestionContent, response: &str) -> String {
+    format!("{}{}{}", question.question_text, response, question.nonce)
+}
+
+/// Signs `response` to `question` with `key`, producing the `response~sig` of the answer.
+/// The signed data is the question text, the response and the nonce of the question.
+pub async fn sign_question_answer(
+    wallet: &Arc<dyn BaseWallet>,
+    key: &str,
+    question: &QuestionContent,
+    response: &str,
+) -> VcxResult<ResponseSignature> {
+    let data = question_answer_data(question, response);
+    let (signature, sig_data) = get_signature_data(wallet, data, key).await?;
+
+    let sig_data = base64::encode_config(&sig_data, base64::URL_SAFE);
+    let signature = base64::encode_config(&signature, base64::URL_SAFE);
+
+    Ok(ResponseSignature::new(signature, sig_data, key.to_string()))
+}
+
+/// Verifies that the `response~sig` of `answer` was made by `their_vk` over the response of
+/// `answer` to `question`.
+pub async fn verify_signed_question_answer(
+    wallet: &Arc<dyn BaseWallet>,
+    question: &QuestionContent,
+    answer: &AnswerContent,
+    their_vk: &str,
+) -> VcxResult<()> {
+    let response_sig = answer
+        .response_sig
+        .as_ref()
+        .ok_or_else(|| AriesVcxError::from_msg(AriesVcxErrorKind::InvalidJson, "Answer is not signed"))?;
+
+    let decode_err = |err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("Cannot decode Answer signature: {:?}", err),
+        )
+    };
+    let signature = base64::decode_config(response_sig.signature.as_bytes(), base64::URL_SAFE).map_err(decode_err)?;
+    let sig_data = base64::decode_config(response_sig.sig_data.as_bytes(), base64::URL_SAFE).map_err(decode_err)?;
+
+    if response_sig.signer != their_vk {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "Signer declared in Answer signature is not matching the expected signer",
+        ));
+    }
+
+    if !wallet.verify(their_vk, &sig_data, &signature).await? {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "Answer signature is invalid",
+        ));
+    }
+
+    let expected_data = question_answer_data(question, &answer.response);
+    if sig_data.get(8..) != Some(expected_data.as_bytes()) {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "Answer signature was not made over the question and response",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates a `did_doc~attach` attachment of the provided [`AriesDidDoc`] and signs it
+/// with `key` through a detached compact JWS, as used by the DID Exchange protocol.
+pub async fn sign_did_doc_attachment(
+    wallet: &Arc<dyn BaseWallet>,
+    key: &str,
+    did_doc: &AriesDidDoc,
+) -> VcxResult<Attachment> {
+    let payload = base64::encode_config(json!(did_doc).to_string().as_bytes(), base64::URL_SAFE_NO_PAD);
+
+    let public_key = bs58::decode(key).into_vec().map_err(|err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidVerkey,
+            format!("Cannot decode signing key {key}: {err}"),
+        )
+    })?;
+
+    let header = json!({
+        "alg": "EdDSA",
+        "jwk": {
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "x": base64::encode_config(&public_key, base64::URL_SAFE_NO_PAD)
+        }
+    });
+    let protected = base64::encode_config(header.to_string().as_bytes(), base64::URL_SAFE_NO_PAD);
+
+    let signing_input = format!("{protected}.{payload}");
+    let signature = wallet.sign(key, signing_input.as_bytes()).await?;
+    let signature = base64::encode_config(&signature, base64::URL_SAFE_NO_PAD);
+
+    let mut attach_data = AttachmentData::new(AttachmentType::Base64(payload));
+    attach_data.jws = Some(format!("{protected}..{signature}"));
+
+    let mut attachment = Attachment::new(attach_data);
+    attachment.mime_type = Some(MimeType::Json);
+
+    Ok(attachment)
+}
+
+/// Verifies the JWS of a `did_doc~attach` attachment and returns the enclosed [`AriesDidDoc`].
+///
+/// If `their_vk` is provided, the attachment must have been signed with that key.
+/// Otherwise, it must have been signed with one of the enclosed [`AriesDidDoc`]'s recipient keys.
+pub async fn decode_signed_did_doc_attachment(
+    wallet: &Arc<dyn BaseWallet>,
+    attachment: &Attachment,
+    their_vk: Option<&str>,
+) -> VcxResult<AriesDidDoc> {
+    let AttachmentType::Base64(payload) = &attachment.data.content else {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("DidDoc attachment is not base64 encoded: {attachment:?}"),
+        ));
+    };
+
+    let jws =
+        attachment.data.jws.as_deref().ok_or_else(|| {
+            AriesVcxError::from_msg(AriesVcxErrorKind::InvalidJson, "DidDoc attachment is not signed")
+        })?;
+
+    let decode_err = |err| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("Cannot decode DidDoc attachment: {:?}", err),
+        )
+    };
+
+    let (protected, signature) = match jws.split('.').collect::<Vec<_>>()[..] {
+        [protected, "", signature] => (protected, signature),
+        _ => {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidJson,
+                format!("DidDoc attachment JWS is not a detached compact JWS: {jws}"),
+            ))
+        }
+    };
+
+    let header = base64::decode_config(protected, base64::URL_SAFE_NO_PAD).map_err(decode_err)?;
+    let header: Value = serde_json::from_slice(&header)?;
+    let signer = header["jwk"]["x"]
+        .as_str()
+        .map(|x| base64::decode_config(x, base64::URL_SAFE_NO_PAD))
+        .transpose()
+        .map_err(decode_err)?
+        .map(|x| bs58::encode(x).into_string())
+        .ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidJson,
+                "DidDoc attachment JWS does not declare its signing key",
+            )
+        })?;
+
+    let payload = payload.trim_end_matches('=');
+    let did_doc = base64::decode_config(payload, base64::URL_SAFE_NO_PAD).map_err(decode_err)?;
+    let did_doc: AriesDidDoc = serde_json::from_slice(&did_doc)
+        .map_err(|err| AriesVcxError::from_msg(AriesVcxErrorKind::InvalidJson, err.to_string()))?;
+
+    let is_expected_signer = match their_vk {
+        Some(their_vk) => signer == their_vk,
+        None => did_doc.recipient_keys()?.contains(&signer),
+    };
+
+    if !is_expected_signer {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "Signer declared in DidDoc attachment is not matching the expected signer",
+        ));
+    }
+
+    let signature = base64::decode_config(signature, base64::URL_SAFE_NO_PAD).map_err(decode_err)?;
+    let signing_input = format!("{protected}.{payload}");
+
+    if !wallet.verify(&signer, signing_input.as_bytes(), &signature).await? {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "DidDoc attachment signature is invalid",
+        ));
+    }
+
+    Ok(did_doc)
+}
+
 pub async fn unpack_message_to_string(wallet: &Arc<dyn BaseWallet>, msg: &[u8]) -> VcxResult<String> {
     if settings::indy_mocks_enabled() {
         return Ok(String::new());
@@ -158,3 +354,140 @@ pub async fn unpack_message_to_string(wallet: &Arc<dyn BaseWallet>, msg: &[u8])
 //         .await;
 //     }
 // }
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod did_doc_attachment_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+
+    use super::*;
+
+    fn _did_doc(verkey: &str) -> AriesDidDoc {
+        let mut did_doc = AriesDidDoc::default();
+        did_doc.set_id("test_did".to_owned());
+        did_doc.set_service_endpoint("http://localhost:8080".parse().unwrap());
+        did_doc.set_recipient_keys(vec![verkey.to_owned()]);
+        did_doc
+    }
+
+    #[tokio::test]
+    async fn test_did_doc_attachment_roundtrip() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let did_doc = _did_doc(&verkey);
+
+        let attachment = sign_did_doc_attachment(&wallet, &verkey, &did_doc).await.unwrap();
+
+        let decoded = decode_signed_did_doc_attachment(&wallet, &attachment, Some(&verkey))
+            .await
+            .unwrap();
+        assert_eq!(decoded, did_doc);
+
+        let decoded = decode_signed_did_doc_attachment(&wallet, &attachment, None)
+            .await
+            .unwrap();
+        assert_eq!(decoded, did_doc);
+    }
+
+    #[tokio::test]
+    async fn test_did_doc_attachment_fails_for_other_signer() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let (_, other_verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let did_doc = _did_doc(&verkey);
+
+        let attachment = sign_did_doc_attachment(&wallet, &verkey, &did_doc).await.unwrap();
+
+        decode_signed_did_doc_attachment(&wallet, &attachment, Some(&other_verkey))
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_did_doc_attachment_fails_for_tampered_payload() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let did_doc = _did_doc(&verkey);
+
+        let mut attachment = sign_did_doc_attachment(&wallet, &verkey, &did_doc).await.unwrap();
+
+        let mut tampered_doc = did_doc.clone();
+        tampered_doc.set_id("other_did".to_owned());
+        let payload = base64::encode_config(json!(tampered_doc).to_string().as_bytes(), base64::URL_SAFE_NO_PAD);
+        attachment.data.content = AttachmentType::Base64(payload);
+
+        decode_signed_did_doc_attachment(&wallet, &attachment, Some(&verkey))
+            .await
+            .unwrap_err();
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod question_answer_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use messages::msg_fields::protocols::question_answer::question::QuestionResponse;
+
+    use super::*;
+
+    fn _question() -> QuestionContent {
+        QuestionContent::new(
+            "Are you on the phone with Faber Bank?".to_owned(),
+            "test_nonce".to_owned(),
+            vec![
+                QuestionResponse::new("Yes".to_owned()),
+                QuestionResponse::new("No".to_owned()),
+            ],
+        )
+    }
+
+    async fn _signed_answer(wallet: &Arc<dyn BaseWallet>, key: &str, response: &str) -> AnswerContent {
+        let mut answer = AnswerContent::new(response.to_owned());
+        answer.response_sig = Some(sign_question_answer(wallet, key, &_question(), response).await.unwrap());
+        answer
+    }
+
+    #[tokio::test]
+    async fn test_question_answer_signature_roundtrip() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let answer = _signed_answer(&wallet, &verkey, "Yes").await;
+        verify_signed_question_answer(&wallet, &_question(), &answer, &verkey)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_question_answer_signature_fails_for_other_signer() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let (_, other_verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let answer = _signed_answer(&wallet, &verkey, "Yes").await;
+        verify_signed_question_answer(&wallet, &_question(), &answer, &other_verkey)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_question_answer_signature_fails_for_other_response_or_nonce() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let mut answer = _signed_answer(&wallet, &verkey, "Yes").await;
+        answer.response = "No".to_owned();
+        verify_signed_question_answer(&wallet, &_question(), &answer, &verkey)
+            .await
+            .unwrap_err();
+
+        let answer = _signed_answer(&wallet, &verkey, "Yes").await;
+        let mut question = _question();
+        question.nonce = "other_nonce".to_owned();
+        verify_signed_question_answer(&wallet, &question, &answer, &verkey)
+            .await
+            .unwrap_err();
+    }
+}
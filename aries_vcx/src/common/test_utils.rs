@@ -1,9 +1,12 @@
 #![allow(clippy::unwrap_used)]
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+use aries_vcx_core::anoncreds::types::{Proof, ProofRequest, RequestedCredentials};
+use aries_vcx_core::ledger::types::{CredentialDefinition, Schema};
 use aries_vcx_core::PoolHandle;
 use aries_vcx_core::WalletHandle;
 
@@ -66,7 +69,7 @@ pub async fn create_and_store_nonrevocable_credential_def(
     tokio::time::sleep(Duration::from_millis(1000)).await;
 
     let ledger = Arc::clone(profile).inject_ledger();
-    let cred_def_json = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
+    let cred_def_json = serde_json::to_string(&ledger.get_cred_def(&cred_def_id, None).await.unwrap()).unwrap();
     (schema_id, schema_json, cred_def_id, cred_def_json, cred_def)
 }
 
@@ -116,7 +119,7 @@ pub async fn create_and_store_credential_def(
     let cred_def_id = cred_def.get_cred_def_id();
     tokio::time::sleep(Duration::from_millis(1000)).await;
     let ledger = Arc::clone(profile).inject_ledger();
-    let cred_def_json = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
+    let cred_def_json = serde_json::to_string(&ledger.get_cred_def(&cred_def_id, None).await.unwrap()).unwrap();
     (
         schema_id,
         schema_json,
@@ -141,12 +144,17 @@ pub async fn create_credential_req(
         .await
         .unwrap();
     let master_secret_name = settings::DEFAULT_LINK_SECRET_ALIAS;
+    let cred_def = serde_json::from_str(cred_def_json).unwrap();
     let (req, req_meta) = Arc::clone(holder)
         .inject_anoncreds()
-        .prover_create_credential_req(&did, &offer, cred_def_json, master_secret_name)
+        .prover_create_credential_req(&did, &offer, &cred_def, master_secret_name)
         .await
         .unwrap();
-    (offer, req, req_meta)
+    (
+        serde_json::to_string(&offer).unwrap(),
+        serde_json::to_string(&req).unwrap(),
+        serde_json::to_string(&req_meta).unwrap(),
+    )
 }
 
 // todo: extract create_and_store_credential_def into caller functions
@@ -177,16 +185,16 @@ pub async fn create_and_store_credential(
 
     /* create cred */
     let credential_data = r#"{"address1": ["123 Main St"], "address2": ["Suite 3"], "city": ["Draper"], "state": ["UT"], "zip": ["84000"]}"#;
-    let encoded_attributes = encode_attributes(&credential_data).unwrap();
+    let encoded_attributes = serde_json::from_str(&encode_attributes(&credential_data).unwrap()).unwrap();
     let ledger = Arc::clone(issuer).inject_ledger();
-    let rev_def_json = ledger.get_rev_reg_def_json(&rev_reg_id).await.unwrap();
+    let rev_reg_def = ledger.get_rev_reg_def(&rev_reg_id).await.unwrap();
     let tails_file = get_temp_dir_path(TAILS_DIR).to_str().unwrap().to_string();
 
-    let (cred, cred_rev_id, _) = Arc::clone(issuer)
+    let issued = Arc::clone(issuer)
         .inject_anoncreds()
         .issuer_create_credential(
-            &offer,
-            &req,
+            &serde_json::from_str(&offer).unwrap(),
+            &serde_json::from_str(&req).unwrap(),
             &encoded_attributes,
             Some(rev_reg_id.clone()),
             Some(tails_file.clone()),
@@ -196,7 +204,13 @@ pub async fn create_and_store_credential(
     /* store cred */
     let cred_id = Arc::clone(holder)
         .inject_anoncreds()
-        .prover_store_credential(None, &req_meta, &cred, &cred_def_json, Some(&rev_def_json))
+        .prover_store_credential(
+            None,
+            &serde_json::from_str(&req_meta).unwrap(),
+            &issued.credential,
+            &serde_json::from_str(&cred_def_json).unwrap(),
+            Some(&rev_reg_def),
+        )
         .await
         .unwrap();
     (
@@ -209,7 +223,7 @@ pub async fn create_and_store_credential(
         req_meta,
         cred_id,
         rev_reg_id,
-        cred_rev_id.unwrap(),
+        issued.cred_rev_id.unwrap(),
         tails_file,
         rev_reg,
     )
@@ -229,17 +243,29 @@ pub async fn create_and_store_nonrevocable_credential(
 
     /* create cred */
     let credential_data = r#"{"address1": ["123 Main St"], "address2": ["Suite 3"], "city": ["Draper"], "state": ["UT"], "zip": ["84000"]}"#;
-    let encoded_attributes = encode_attributes(&credential_data).unwrap();
+    let encoded_attributes = serde_json::from_str(&encode_attributes(&credential_data).unwrap()).unwrap();
 
-    let (cred, _, _) = Arc::clone(issuer)
+    let issued = Arc::clone(issuer)
         .inject_anoncreds()
-        .issuer_create_credential(&offer, &req, &encoded_attributes, None, None)
+        .issuer_create_credential(
+            &serde_json::from_str(&offer).unwrap(),
+            &serde_json::from_str(&req).unwrap(),
+            &encoded_attributes,
+            None,
+            None,
+        )
         .await
         .unwrap();
     /* store cred */
     let cred_id = Arc::clone(holder)
         .inject_anoncreds()
-        .prover_store_credential(None, &req_meta, &cred, &cred_def_json, None)
+        .prover_store_credential(
+            None,
+            &serde_json::from_str(&req_meta).unwrap(),
+            &issued.credential,
+            &serde_json::from_str(&cred_def_json).unwrap(),
+            None,
+        )
         .await
         .unwrap();
     (
@@ -259,10 +285,15 @@ pub async fn create_indy_proof(
     issuer: &Arc<dyn Profile>,
     prover: &Arc<dyn Profile>,
     did: &str,
-) -> (String, String, String, String) {
+) -> (
+    HashMap<String, Schema>,
+    HashMap<String, CredentialDefinition>,
+    ProofRequest,
+    Proof,
+) {
     let (schema_id, schema_json, cred_def_id, cred_def_json, _offer, _req, _req_meta, cred_id) =
         create_and_store_nonrevocable_credential(issuer, prover, &did, DEFAULT_SCHEMA_ATTRS).await;
-    let proof_req = json!({
+    let proof_req: ProofRequest = serde_json::from_value(json!({
        "nonce":"123432421212",
        "name":"proof_req_1",
        "version":"0.1",
@@ -280,9 +311,9 @@ pub async fn create_indy_proof(
            }),
        }),
        "requested_predicates": json!({}),
-    })
-    .to_string();
-    let requested_credentials_json = json!({
+    }))
+    .unwrap();
+    let requested_credentials: RequestedCredentials = serde_json::from_value(json!({
           "self_attested_attributes":{
              "self_attest_3": "my_self_attested_val"
           },
@@ -291,20 +322,11 @@ pub async fn create_indy_proof(
              "zip_2": {"cred_id": cred_id, "revealed": true}
             },
           "requested_predicates":{}
-    })
-    .to_string();
-
-    let schema_json: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
-    let schemas = json!({
-        schema_id: schema_json,
-    })
-    .to_string();
+    }))
+    .unwrap();
 
-    let cred_def_json: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-    let cred_defs = json!({
-        cred_def_id: cred_def_json,
-    })
-    .to_string();
+    let schemas = HashMap::from([(schema_id, serde_json::from_str(&schema_json).unwrap())]);
+    let cred_defs = HashMap::from([(cred_def_id, serde_json::from_str(&cred_def_json).unwrap())]);
 
     let anoncreds = Arc::clone(prover).inject_anoncreds();
 
@@ -314,14 +336,7 @@ pub async fn create_indy_proof(
         .unwrap();
 
     let proof = anoncreds
-        .prover_create_proof(
-            &proof_req,
-            &requested_credentials_json,
-            "main",
-            &schemas,
-            &cred_defs,
-            None,
-        )
+        .prover_create_proof(&proof_req, &requested_credentials, "main", &schemas, &cred_defs, None)
         .await
         .unwrap();
     (schemas, cred_defs, proof_req, proof)
@@ -332,11 +347,16 @@ pub async fn create_proof_with_predicate(
     prover: &Arc<dyn Profile>,
     did: &str,
     include_predicate_cred: bool,
-) -> (String, String, String, String) {
+) -> (
+    HashMap<String, Schema>,
+    HashMap<String, CredentialDefinition>,
+    ProofRequest,
+    Proof,
+) {
     let (schema_id, schema_json, cred_def_id, cred_def_json, _offer, _req, _req_meta, cred_id) =
         create_and_store_nonrevocable_credential(issuer, prover, &did, DEFAULT_SCHEMA_ATTRS).await;
 
-    let proof_req = json!({
+    let proof_req: ProofRequest = serde_json::from_value(json!({
        "nonce":"123432421212",
        "name":"proof_req_1",
        "version":"0.1",
@@ -352,8 +372,8 @@ pub async fn create_proof_with_predicate(
        "requested_predicates": json!({
            "zip_3": {"name":"zip", "p_type":">=", "p_value":18}
        }),
-    })
-    .to_string();
+    }))
+    .unwrap();
 
     let requested_credentials_json;
     if include_predicate_cred {
@@ -367,8 +387,7 @@ pub async fn create_proof_with_predicate(
           "requested_predicates":{
               "zip_3": {"cred_id": cred_id}
           }
-        })
-        .to_string();
+        });
     } else {
         requested_credentials_json = json!({
           "self_attested_attributes":{
@@ -379,21 +398,12 @@ pub async fn create_proof_with_predicate(
             },
           "requested_predicates":{
           }
-        })
-        .to_string();
+        });
     }
+    let requested_credentials: RequestedCredentials = serde_json::from_value(requested_credentials_json).unwrap();
 
-    let schema_json: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
-    let schemas = json!({
-        schema_id: schema_json,
-    })
-    .to_string();
-
-    let cred_def_json: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-    let cred_defs = json!({
-        cred_def_id: cred_def_json,
-    })
-    .to_string();
+    let schemas = HashMap::from([(schema_id, serde_json::from_str(&schema_json).unwrap())]);
+    let cred_defs = HashMap::from([(cred_def_id, serde_json::from_str(&cred_def_json).unwrap())]);
 
     let anoncreds = Arc::clone(prover).inject_anoncreds();
 
@@ -403,14 +413,7 @@ pub async fn create_proof_with_predicate(
         .unwrap();
 
     let proof = anoncreds
-        .prover_create_proof(
-            &proof_req,
-            &requested_credentials_json,
-            "main",
-            &schemas,
-            &cred_defs,
-            None,
-        )
+        .prover_create_proof(&proof_req, &requested_credentials, "main", &schemas, &cred_defs, None)
         .await
         .unwrap();
     (schemas, cred_defs, proof_req, proof)
@@ -4,6 +4,7 @@ use aries_vcx_core::anoncreds::base_anoncreds::BaseAnonCreds;
 use aries_vcx_core::anoncreds::credx_anoncreds::IndyCredxAnonCreds;
 use aries_vcx_core::ledger::base_ledger::BaseLedger;
 use aries_vcx_core::ledger::indy_vdr_ledger::{IndyVdrLedger, IndyVdrLedgerPool, LedgerPoolConfig};
+use aries_vcx_core::ledger::request_submitter::RequestSubmitter;
 use aries_vcx_core::wallet::base_wallet::BaseWallet;
 
 use crate::errors::error::VcxResult;
@@ -21,13 +22,21 @@ pub struct ModularLibsProfile {
 impl ModularLibsProfile {
     pub fn new(wallet: Arc<dyn BaseWallet>, ledger_pool_config: LedgerPoolConfig) -> VcxResult<Self> {
         let ledger_pool = Arc::new(IndyVdrLedgerPool::new(ledger_pool_config)?);
-        let ledger = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), ledger_pool));
+        Ok(Self::new_with_request_submitter(wallet, ledger_pool))
+    }
+
+    // e.g. with an `InMemoryLedger` submitter, to run ledger dependant flows without a pool
+    pub fn new_with_request_submitter<T>(wallet: Arc<dyn BaseWallet>, request_submitter: Arc<T>) -> Self
+    where
+        T: RequestSubmitter + 'static,
+    {
+        let ledger = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), request_submitter));
         let anoncreds = Arc::new(IndyCredxAnonCreds::new(Arc::clone(&wallet)));
-        Ok(ModularLibsProfile {
+        ModularLibsProfile {
             wallet,
             ledger,
             anoncreds,
-        })
+        }
     }
 }
 
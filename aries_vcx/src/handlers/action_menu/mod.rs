@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use messages::msg_fields::protocols::action_menu::menu::{Menu, MenuContent, MenuOption};
+use messages::msg_fields::protocols::action_menu::perform::Perform;
+use messages::msg_fields::protocols::action_menu::problem_report::ActionMenuProblemReport;
+
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
+use crate::protocols::action_menu::{
+    build_menu, build_menu_request, build_perform, build_problem_report, get_menu_thread_id,
+};
+use crate::protocols::SendClosure;
+
+use super::util::{matches_opt_thread_id, matches_thread_id};
+
+fn find_enabled_option<'a>(menu: &'a MenuContent, name: &str) -> VcxResult<&'a MenuOption> {
+    let option = menu.options.iter().find(|o| o.name == name).ok_or_else(|| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidOption,
+            format!("Menu has no option named {}", name),
+        )
+    })?;
+    if option.disabled {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidOption,
+            format!("Menu option {} is disabled", name),
+        ));
+    }
+    Ok(option)
+}
+
+/// The party requesting a menu and performing one of its options.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ActionMenuRequester {
+    menu: Option<Menu>,
+    problem_report: Option<ActionMenuProblemReport>,
+}
+
+impl ActionMenuRequester {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The last menu received from the responder.
+    pub fn get_menu(&self) -> Option<&Menu> {
+        self.menu.as_ref()
+    }
+
+    pub fn get_problem_report(&self) -> Option<&ActionMenuProblemReport> {
+        self.problem_report.as_ref()
+    }
+
+    pub async fn send_menu_request(&self, send_message: SendClosure) -> VcxResult<()> {
+        send_message(build_menu_request().into()).await
+    }
+
+    /// Stores the received menu, replacing any previous one.
+    pub fn handle_menu(&mut self, menu: Menu) -> VcxResult<()> {
+        self.menu = Some(menu);
+        self.problem_report = None;
+        Ok(())
+    }
+
+    /// Selects the option `name` of the current menu, with `params` holding the values of
+    /// its form, if it has any.
+    pub async fn send_perform(
+        &self,
+        name: &str,
+        params: HashMap<String, String>,
+        send_message: SendClosure,
+    ) -> VcxResult<()> {
+        let menu = self
+            .menu
+            .as_ref()
+            .ok_or_else(|| AriesVcxError::from_msg(AriesVcxErrorKind::NotReady, "No menu has been received yet"))?;
+
+        let option = find_enabled_option(&menu.content, name)?;
+        if let Some(form) = option.form.as_ref() {
+            if let Some(missing) = form
+                .params
+                .iter()
+                .find(|param| param.required && !params.contains_key(&param.name))
+            {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidInput,
+                    format!("Missing required parameter {} of menu option {}", missing.name, name),
+                ));
+            }
+        }
+
+        let perform = build_perform(get_menu_thread_id(menu), name.to_owned(), params);
+        send_message(perform.into()).await
+    }
+
+    pub fn handle_problem_report(&mut self, problem_report: ActionMenuProblemReport) -> VcxResult<()> {
+        if let Some(menu) = self.menu.as_ref() {
+            if !matches_opt_thread_id!(problem_report, get_menu_thread_id(menu)) {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::NotReady,
+                    "Thread ID mismatch",
+                ));
+            }
+        }
+        self.problem_report = Some(problem_report);
+        Ok(())
+    }
+}
+
+/// The party offering a menu and receiving the option the requester selected.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionMenuResponder {
+    menu: MenuContent,
+    sent_menu: Option<Menu>,
+    perform: Option<Perform>,
+}
+
+impl ActionMenuResponder {
+    pub fn build(menu: MenuContent) -> Self {
+        Self {
+            menu,
+            sent_menu: None,
+            perform: None,
+        }
+    }
+
+    pub fn get_menu_content(&self) -> &MenuContent {
+        &self.menu
+    }
+
+    /// The last menu sent to the requester.
+    pub fn get_sent_menu(&self) -> Option<&Menu> {
+        self.sent_menu.as_ref()
+    }
+
+    /// The option selected by the requester from the last sent menu.
+    pub fn get_perform(&self) -> Option<&Perform> {
+        self.perform.as_ref()
+    }
+
+    /// Replaces the menu offered by the responder, e.g. to show an `errormsg` after a failed
+    /// `perform`. The new menu is sent on the next call to [`ActionMenuResponder::send_menu`].
+    pub fn set_menu(&mut self, menu: MenuContent) {
+        self.menu = menu;
+    }
+
+    pub async fn send_menu(&mut self, send_message: SendClosure) -> VcxResult<()> {
+        let menu = build_menu(self.menu.clone());
+        send_message(menu.clone().into()).await?;
+        self.sent_menu = Some(menu);
+        self.perform = None;
+        Ok(())
+    }
+
+    pub async fn handle_menu_request(&mut self, send_message: SendClosure) -> VcxResult<()> {
+        self.send_menu(send_message).await
+    }
+
+    pub fn handle_perform(&mut self, perform: Perform) -> VcxResult<()> {
+        let menu = self
+            .sent_menu
+            .as_ref()
+            .ok_or_else(|| AriesVcxError::from_msg(AriesVcxErrorKind::NotReady, "No menu has been sent yet"))?;
+        if !matches_thread_id!(perform, get_menu_thread_id(menu)) {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Thread ID mismatch",
+            ));
+        }
+        find_enabled_option(&menu.content, &perform.content.name)?;
+        self.perform = Some(perform);
+        Ok(())
+    }
+
+    pub async fn send_problem_report(&self, code: String, send_message: SendClosure) -> VcxResult<()> {
+        let menu = self
+            .sent_menu
+            .as_ref()
+            .ok_or_else(|| AriesVcxError::from_msg(AriesVcxErrorKind::NotReady, "No menu has been sent yet"))?;
+        send_message(build_problem_report(get_menu_thread_id(menu), code).into()).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_fields::protocols::action_menu::menu::{MenuForm, MenuFormParam};
+    use messages::AriesMessage;
+
+    use super::*;
+
+    pub fn _send_message() -> SendClosure {
+        Box::new(|_: AriesMessage| Box::pin(async { VcxResult::Ok(()) }))
+    }
+
+    fn _menu_content() -> MenuContent {
+        let mut search = MenuOption::new(
+            "search".to_owned(),
+            "Search attendees".to_owned(),
+            "Find other attendees".to_owned(),
+        );
+        let mut param = MenuFormParam::new("query".to_owned(), "Attendee name".to_owned());
+        param.required = true;
+        search.form = Some(MenuForm::new(vec![param]));
+
+        let mut disabled = MenuOption::new("disabled".to_owned(), "Disabled".to_owned(), "Disabled".to_owned());
+        disabled.disabled = true;
+
+        MenuContent::new("Welcome".to_owned(), "Main menu".to_owned(), vec![search, disabled])
+    }
+
+    async fn _requester_with_menu(responder: &mut ActionMenuResponder) -> ActionMenuRequester {
+        responder.send_menu(_send_message()).await.unwrap();
+        let mut requester = ActionMenuRequester::new();
+        requester
+            .handle_menu(responder.get_sent_menu().unwrap().clone())
+            .unwrap();
+        requester
+    }
+
+    #[tokio::test]
+    async fn test_menu_perform() {
+        let mut responder = ActionMenuResponder::build(_menu_content());
+        let requester = _requester_with_menu(&mut responder).await;
+
+        let params = HashMap::from([("query".to_owned(), "Alice".to_owned())]);
+        requester
+            .send_perform("search", params.clone(), _send_message())
+            .await
+            .unwrap();
+
+        let menu = responder.get_sent_menu().unwrap();
+        let perform = build_perform(get_menu_thread_id(menu), "search".to_owned(), params);
+        responder.handle_perform(perform).unwrap();
+        assert_eq!("search", responder.get_perform().unwrap().content.name);
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_to_perform_unknown_or_disabled_option() {
+        let mut responder = ActionMenuResponder::build(_menu_content());
+        let requester = _requester_with_menu(&mut responder).await;
+
+        requester
+            .send_perform("unknown", HashMap::new(), _send_message())
+            .await
+            .unwrap_err();
+        requester
+            .send_perform("disabled", HashMap::new(), _send_message())
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_to_perform_without_required_param() {
+        let mut responder = ActionMenuResponder::build(_menu_content());
+        let requester = _requester_with_menu(&mut responder).await;
+
+        requester
+            .send_perform("search", HashMap::new(), _send_message())
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_on_thread_id_mismatch() {
+        let mut responder = ActionMenuResponder::build(_menu_content());
+        responder.send_menu(_send_message()).await.unwrap();
+
+        let params = HashMap::from([("query".to_owned(), "Alice".to_owned())]);
+        let perform = build_perform("other_thread", "search".to_owned(), params);
+        responder.handle_perform(perform).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_to_perform_without_menu() {
+        let requester = ActionMenuRequester::new();
+        requester
+            .send_perform("search", HashMap::new(), _send_message())
+            .await
+            .unwrap_err();
+    }
+}
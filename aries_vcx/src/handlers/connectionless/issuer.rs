@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use messages::decorators::service::Service;
+use messages::msg_fields::protocols::cred_issuance::ack::AckCredential;
+use messages::msg_fields::protocols::cred_issuance::request_credential::RequestCredential;
+use messages::AriesMessage;
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::issuance::issuer::Issuer;
+use crate::transport::Transport;
+
+use super::{send_message_to_service, service_key, with_service};
+
+/// An issuer offering a credential without a connection, e.g. through a QR code.
+///
+/// The credential offer carries our `~service` decorator, telling the holder where to send the
+/// credential request. The holder attaches its own `~service` to the request, which is used to
+/// deliver the credential.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConnectionlessIssuer {
+    issuer: Issuer,
+    service: Service,
+    holder_service: Option<Service>,
+}
+
+impl ConnectionlessIssuer {
+    /// Wraps `issuer`, whose credential offer must already be built, replying to the holder
+    /// from `service`. See [`super::create_service`].
+    pub fn new(issuer: Issuer, service: Service) -> Self {
+        Self {
+            issuer,
+            service,
+            holder_service: None,
+        }
+    }
+
+    pub fn get_issuer(&self) -> &Issuer {
+        &self.issuer
+    }
+
+    pub fn get_service(&self) -> &Service {
+        &self.service
+    }
+
+    /// The `~service` the holder attached to its credential request, once received.
+    pub fn get_holder_service(&self) -> Option<&Service> {
+        self.holder_service.as_ref()
+    }
+
+    /// The credential offer to hand over to the holder, decorated with our `~service`.
+    pub fn get_credential_offer_msg(&self) -> VcxResult<AriesMessage> {
+        Ok(with_service(
+            self.issuer.get_credential_offer_msg()?,
+            self.service.clone(),
+        ))
+    }
+
+    pub fn mark_credential_offer_msg_sent(&mut self) -> VcxResult<()> {
+        self.issuer.mark_credential_offer_msg_sent()
+    }
+
+    /// Accepts a credential request carrying the `~service` the credential is to be sent to.
+    pub fn process_credential_request(&mut self, request: RequestCredential) -> VcxResult<()> {
+        let holder_service = request.decorators.service.clone().ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Credential request has no ~service decorator to reply to",
+            )
+        })?;
+        self.issuer.process_credential_request(request)?;
+        self.holder_service = Some(holder_service);
+        Ok(())
+    }
+
+    pub fn process_credential_ack(&mut self, ack: AckCredential) -> VcxResult<()> {
+        self.issuer.process_credential_ack(ack)
+    }
+
+    /// Issues the requested credential and sends it to the `~service` of the holder.
+    pub async fn send_credential<T>(&mut self, profile: &Arc<dyn Profile>, transport: Arc<T>) -> VcxResult<()>
+    where
+        T: Transport + ?Sized + 'static,
+    {
+        let holder_service = self.holder_service.clone().ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "No credential request has been received yet",
+            )
+        })?;
+        let send_message = send_message_to_service(
+            profile.inject_wallet(),
+            transport,
+            service_key(&self.service)?,
+            holder_service,
+            Some(self.service.clone()),
+        );
+        self.issuer.send_credential(profile, send_message).await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_fields::protocols::cred_issuance::request_credential::{
+        RequestCredentialContent, RequestCredentialDecorators,
+    };
+
+    use super::*;
+
+    fn _issuer() -> ConnectionlessIssuer {
+        let service = Service::new(
+            vec!["test_verkey".to_owned()],
+            vec![],
+            "https://issuer.org/endpoint".parse().unwrap(),
+        );
+        ConnectionlessIssuer::new(Issuer::create("test_source_id").unwrap(), service)
+    }
+
+    #[test]
+    fn test_should_fail_to_process_request_without_service() {
+        let mut issuer = _issuer();
+        let request = RequestCredential::with_decorators(
+            "test_id".to_owned(),
+            RequestCredentialContent::new(vec![]),
+            RequestCredentialDecorators::default(),
+        );
+        issuer.process_credential_request(request).unwrap_err();
+        assert!(issuer.get_holder_service().is_none());
+    }
+}
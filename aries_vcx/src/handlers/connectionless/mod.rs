@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::decorators::service::Service;
+use messages::msg_fields::protocols::cred_issuance::CredentialIssuance;
+use messages::msg_fields::protocols::present_proof::PresentProof;
+use messages::AriesMessage;
+use url::Url;
+
+use crate::errors::error::prelude::*;
+use crate::protocols::SendClosure;
+use crate::transport::Transport;
+use crate::utils::encryption_envelope::EncryptionEnvelope;
+
+pub mod issuer;
+pub mod verifier;
+
+/// Creates the `~service` block of a connectionless exchange, backed by a new ephemeral key.
+/// Replies sent to the service are packed for this key, and so are the messages we send.
+pub async fn create_service(
+    wallet: &Arc<dyn BaseWallet>,
+    service_endpoint: Url,
+    routing_keys: Vec<String>,
+) -> VcxResult<Service> {
+    let (_, verkey) = wallet.create_and_store_my_did(None, None).await?;
+    Ok(Service::new(vec![verkey], routing_keys, service_endpoint))
+}
+
+/// Attaches `service` to `message` as its `~service` decorator. Messages which are not
+/// exchanged without a connection cannot carry the decorator and are returned unchanged.
+pub fn with_service(message: AriesMessage, service: Service) -> AriesMessage {
+    match message {
+        AriesMessage::CredentialIssuance(CredentialIssuance::OfferCredential(mut msg)) => {
+            msg.decorators.service = Some(service);
+            msg.into()
+        }
+        AriesMessage::CredentialIssuance(CredentialIssuance::RequestCredential(mut msg)) => {
+            msg.decorators.service = Some(service);
+            msg.into()
+        }
+        AriesMessage::PresentProof(PresentProof::RequestPresentation(mut msg)) => {
+            msg.decorators.service = Some(service);
+            msg.into()
+        }
+        AriesMessage::PresentProof(PresentProof::Presentation(mut msg)) => {
+            msg.decorators.service = Some(service);
+            msg.into()
+        }
+        msg => msg,
+    }
+}
+
+/// Builds a [`SendClosure`] delivering messages to `their_service`, packed with our `sender_vk`.
+/// If `our_service` is provided, it is attached to the sent messages so the other party can
+/// reply to them.
+pub fn send_message_to_service<T>(
+    wallet: Arc<dyn BaseWallet>,
+    transport: Arc<T>,
+    sender_vk: String,
+    their_service: Service,
+    our_service: Option<Service>,
+) -> SendClosure
+where
+    T: Transport + ?Sized + 'static,
+{
+    Box::new(move |message: AriesMessage| {
+        Box::pin(async move {
+            let message = match our_service {
+                Some(service) => with_service(message, service),
+                None => message,
+            };
+            let EncryptionEnvelope(packed) =
+                EncryptionEnvelope::create(&wallet, &message, Some(&sender_vk), &service_did_doc(&their_service))
+                    .await?;
+            transport.send_message(packed, their_service.service_endpoint).await
+        })
+    })
+}
+
+fn service_did_doc(service: &Service) -> AriesDidDoc {
+    let mut did_doc = AriesDidDoc::default();
+    did_doc.set_service_endpoint(service.service_endpoint.clone());
+    did_doc.set_recipient_keys(service.recipient_keys.clone());
+    did_doc.set_routing_keys(service.routing_keys.clone());
+    did_doc
+}
+
+fn service_key(service: &Service) -> VcxResult<String> {
+    service.recipient_keys.first().cloned().ok_or_else(|| {
+        AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "No recipient key found in ~service decorator",
+        )
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use std::sync::Mutex;
+
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use async_trait::async_trait;
+    use messages::decorators::thread::Thread;
+    use messages::msg_fields::protocols::notification::ack::{AckDecorators, AckStatus};
+    use messages::msg_fields::protocols::present_proof::ack::{AckPresentation, AckPresentationContent};
+    use messages::msg_fields::protocols::present_proof::request::{
+        RequestPresentation, RequestPresentationContent, RequestPresentationDecorators,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockTransport {
+        sent: Mutex<Vec<(Vec<u8>, Url)>>,
+    }
+
+    #[async_trait]
+    impl Transport for MockTransport {
+        async fn send_message(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<()> {
+            self.sent.lock().unwrap().push((msg, service_endpoint));
+            Ok(())
+        }
+    }
+
+    async fn _services(wallet: &Arc<dyn BaseWallet>) -> (Service, Service) {
+        let ours = create_service(wallet, "https://verifier.org/endpoint".parse().unwrap(), vec![])
+            .await
+            .unwrap();
+        let theirs = create_service(wallet, "https://prover.org/endpoint".parse().unwrap(), vec![])
+            .await
+            .unwrap();
+        (ours, theirs)
+    }
+
+    fn _request() -> AriesMessage {
+        let content = RequestPresentationContent::new(vec![]);
+        RequestPresentation::with_decorators("test_id".to_owned(), content, RequestPresentationDecorators::default())
+            .into()
+    }
+
+    #[tokio::test]
+    async fn test_send_message_to_service() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let transport = Arc::new(MockTransport::default());
+        let (ours, theirs) = _services(&wallet).await;
+        let sender_vk = service_key(&ours).unwrap();
+
+        let send_message = send_message_to_service(
+            wallet.clone(),
+            transport.clone(),
+            sender_vk.clone(),
+            theirs.clone(),
+            Some(ours.clone()),
+        );
+        send_message(_request()).await.unwrap();
+
+        let (packed, endpoint) = transport.sent.lock().unwrap().pop().unwrap();
+        assert_eq!(theirs.service_endpoint, endpoint);
+        let message = EncryptionEnvelope::auth_unpack(&wallet, packed, &sender_vk)
+            .await
+            .unwrap();
+        assert_eq!(with_service(_request(), ours), message);
+    }
+
+    #[tokio::test]
+    async fn test_with_service_leaves_other_messages_unchanged() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (ours, _) = _services(&wallet).await;
+
+        let decorators = AckDecorators::new(Thread::new("test_thread_id".to_owned()));
+        let content = AckPresentationContent::new(AckStatus::Ok);
+        let ack: AriesMessage = AckPresentation::with_decorators("test_id".to_owned(), content, decorators).into();
+        assert_eq!(ack.clone(), with_service(ack, ours));
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use messages::decorators::service::Service;
+use messages::msg_fields::protocols::present_proof::present::Presentation;
+use messages::msg_fields::protocols::present_proof::request::RequestPresentation;
+use messages::AriesMessage;
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::proof_presentation::verifier::Verifier;
+use crate::protocols::SendClosure;
+use crate::transport::Transport;
+
+use super::{send_message_to_service, service_key};
+
+/// A verifier requesting a presentation without a connection, e.g. through a QR code.
+///
+/// The presentation request carries our `~service` decorator, telling the prover where to send
+/// the presentation. If the prover attaches its own `~service` to the presentation, it is used
+/// to send back the ack.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ConnectionlessVerifier {
+    verifier: Verifier,
+    service: Service,
+}
+
+impl ConnectionlessVerifier {
+    /// Wraps `verifier`, whose presentation request must already be set, replying to the prover
+    /// from `service`. See [`super::create_service`].
+    pub fn new(verifier: Verifier, service: Service) -> Self {
+        Self { verifier, service }
+    }
+
+    pub fn get_verifier(&self) -> &Verifier {
+        &self.verifier
+    }
+
+    pub fn get_service(&self) -> &Service {
+        &self.service
+    }
+
+    /// The presentation request to hand over to the prover, decorated with our `~service`.
+    pub fn get_presentation_request_msg(&self) -> VcxResult<RequestPresentation> {
+        let mut request = self.verifier.get_presentation_request_msg()?;
+        request.decorators.service = Some(self.service.clone());
+        Ok(request)
+    }
+
+    pub fn mark_presentation_request_msg_sent(&mut self) -> VcxResult<()> {
+        self.verifier.mark_presentation_request_msg_sent()
+    }
+
+    /// Verifies `presentation` and sends the ack to the `~service` the prover attached to it.
+    /// Provers which attach no `~service` do not expect an ack, so none is sent.
+    pub async fn verify_presentation<T>(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        transport: Arc<T>,
+        presentation: Presentation,
+    ) -> VcxResult<()>
+    where
+        T: Transport + ?Sized + 'static,
+    {
+        let send_message: SendClosure = match presentation.decorators.service.clone() {
+            Some(their_service) => send_message_to_service(
+                profile.inject_wallet(),
+                transport,
+                service_key(&self.service)?,
+                their_service,
+                None,
+            ),
+            None => Box::new(|message: AriesMessage| {
+                Box::pin(async move {
+                    debug!("Presentation has no ~service decorator, not sending {:?}", message);
+                    Ok(())
+                })
+            }),
+        };
+        self.verifier
+            .verify_presentation(profile, presentation, send_message)
+            .await
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use messages::decorators::attachment::{Attachment, AttachmentData, AttachmentType};
+    use messages::decorators::thread::Thread;
+    use messages::msg_fields::protocols::present_proof::present::{PresentationContent, PresentationDecorators};
+    use url::Url;
+
+    use crate::common::proofs::proof_request::PresentationRequestData;
+    use crate::common::test_utils::mock_profile;
+    use crate::protocols::proof_presentation::verifier::state_machine::VerifierState;
+    use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+    use crate::utils::devsetup::SetupMocks;
+    use crate::utils::mockdata::mock_settings::MockBuilder;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockTransport {
+        sent: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Transport for MockTransport {
+        async fn send_message(&self, _msg: Vec<u8>, _service_endpoint: Url) -> VcxResult<()> {
+            self.sent.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn _service() -> Service {
+        Service::new(
+            vec!["test_verkey".to_owned()],
+            vec![],
+            "https://verifier.org/endpoint".parse().unwrap(),
+        )
+    }
+
+    fn _verifier() -> ConnectionlessVerifier {
+        let verifier =
+            Verifier::create_from_request("test_source_id".to_owned(), &PresentationRequestData::default()).unwrap();
+        ConnectionlessVerifier::new(verifier, _service())
+    }
+
+    #[test]
+    fn test_presentation_request_has_service() {
+        let verifier = _verifier();
+        let request = verifier.get_presentation_request_msg().unwrap();
+        assert_eq!(Some(_service()), request.decorators.service);
+    }
+
+    #[tokio::test]
+    async fn test_verify_presentation_without_service() {
+        let _setup = SetupMocks::init();
+        let _mock_builder = MockBuilder::init().set_mock_result_for_validate_indy_proof(Ok(true));
+        let mut verifier = _verifier();
+        verifier.mark_presentation_request_msg_sent().unwrap();
+
+        let thread_id = verifier.get_verifier().get_thread_id().unwrap();
+        let attachment = Attachment::new(AttachmentData::new(AttachmentType::Base64(base64::encode("{}"))));
+        let presentation = Presentation::with_decorators(
+            "test_id".to_owned(),
+            PresentationContent::new(vec![attachment]),
+            PresentationDecorators::new(Thread::new(thread_id)),
+        );
+        let transport = Arc::new(MockTransport::default());
+        verifier
+            .verify_presentation(&mock_profile(), transport.clone(), presentation)
+            .await
+            .unwrap();
+
+        assert_eq!(VerifierState::Finished, verifier.get_verifier().get_state());
+        assert_eq!(
+            PresentationVerificationStatus::Valid,
+            verifier.get_verifier().get_verification_status()
+        );
+        // There is nowhere to send the ack to
+        assert_eq!(0, transport.sent.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,287 @@
+use diddoc::aries::service::AriesService;
+use messages::msg_fields::protocols::coordinate_mediation::keylist::Keylist;
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update::{
+    KeylistUpdateItem, KeylistUpdateItemAction,
+};
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update_response::KeylistUpdateResponse;
+use messages::msg_fields::protocols::coordinate_mediation::mediate_deny::MediateDeny;
+use messages::msg_fields::protocols::coordinate_mediation::mediate_grant::MediateGrant;
+use messages::msg_fields::protocols::coordinate_mediation::CoordinateMediation;
+use messages::AriesMessage;
+use url::Url;
+
+use crate::errors::error::prelude::*;
+use crate::protocols::coordinate_mediation::recipient::state_machine::{MediationRecipientSM, MediationRecipientState};
+use crate::protocols::SendClosure;
+
+/// Obtains routing from a mediator and registers recipient keys with it.
+///
+/// Once mediation is granted, connections built with `InviterConnection::create_mediated_invitation`,
+/// `InviteeConnection::send_mediated_request` and `InviterConnection::handle_mediated_request`
+/// hand out DID docs routed through the mediator. Out-of-band invitations can use
+/// `OutOfBandSender::append_mediated_service`. Every recipient key used in such a DID doc
+/// or service must be registered with [`MediationRecipient::add_recipient_key`] beforehand,
+/// otherwise the mediator will not accept messages forwarded to it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediationRecipient {
+    recipient_sm: MediationRecipientSM,
+}
+
+impl MediationRecipient {
+    pub fn build() -> Self {
+        Self {
+            recipient_sm: MediationRecipientSM::create(),
+        }
+    }
+
+    pub fn get_state(&self) -> MediationRecipientState {
+        self.recipient_sm.get_state()
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        self.recipient_sm.get_thread_id()
+    }
+
+    pub fn routing_endpoint(&self) -> VcxResult<Url> {
+        self.recipient_sm.routing_endpoint()
+    }
+
+    pub fn routing_keys(&self) -> VcxResult<Vec<String>> {
+        self.recipient_sm.routing_keys()
+    }
+
+    pub fn recipient_keys(&self) -> VcxResult<Vec<String>> {
+        self.recipient_sm.recipient_keys()
+    }
+
+    /// Returns the mediator endpoint and routing keys to put in a DID doc or invitation using
+    /// `recipient_key`, once the key is registered with the mediator.
+    pub fn routing_for(&self, recipient_key: &str) -> VcxResult<(Url, Vec<String>)> {
+        if !self.recipient_keys()?.iter().any(|key| key == recipient_key) {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                format!("Recipient key {recipient_key} is not registered with the mediator"),
+            ));
+        }
+        Ok((self.routing_endpoint()?, self.routing_keys()?))
+    }
+
+    /// Builds a service routed through the mediator, suitable for out-of-band invitations.
+    pub fn to_aries_service(&self, recipient_keys: Vec<String>) -> VcxResult<AriesService> {
+        Ok(AriesService::create()
+            .set_service_endpoint(self.routing_endpoint()?)
+            .set_routing_keys(self.routing_keys()?)
+            .set_recipient_keys(recipient_keys))
+    }
+
+    pub async fn send_mediation_request(self, send_message: SendClosure) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.send_request(send_message).await?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub fn handle_mediation_grant(self, grant: MediateGrant) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.handle_grant(grant)?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub fn handle_mediation_deny(self, deny: MediateDeny) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.handle_deny(deny)?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub async fn add_recipient_key(self, recipient_key: String, send_message: SendClosure) -> VcxResult<Self> {
+        let update = KeylistUpdateItem::new(recipient_key, KeylistUpdateItemAction::Add);
+        self.send_keylist_update(vec![update], send_message).await
+    }
+
+    pub async fn remove_recipient_key(self, recipient_key: String, send_message: SendClosure) -> VcxResult<Self> {
+        let update = KeylistUpdateItem::new(recipient_key, KeylistUpdateItemAction::Remove);
+        self.send_keylist_update(vec![update], send_message).await
+    }
+
+    pub async fn send_keylist_update(
+        self,
+        updates: Vec<KeylistUpdateItem>,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.send_keylist_update(updates, send_message).await?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub fn handle_keylist_update_response(self, response: KeylistUpdateResponse) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.handle_keylist_update_response(response)?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub async fn send_keylist_query(self, send_message: SendClosure) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.send_keylist_query(send_message).await?;
+        Ok(Self { recipient_sm })
+    }
+
+    pub fn handle_keylist(self, keylist: Keylist) -> VcxResult<Self> {
+        let recipient_sm = self.recipient_sm.handle_keylist(keylist)?;
+        Ok(Self { recipient_sm })
+    }
+
+    /// Dispatches a message received from the mediator to the matching handler.
+    pub fn handle_message(self, message: AriesMessage) -> VcxResult<Self> {
+        match message {
+            AriesMessage::CoordinateMediation(CoordinateMediation::MediateGrant(msg)) => {
+                self.handle_mediation_grant(msg)
+            }
+            AriesMessage::CoordinateMediation(CoordinateMediation::MediateDeny(msg)) => self.handle_mediation_deny(msg),
+            AriesMessage::CoordinateMediation(CoordinateMediation::KeylistUpdateResponse(msg)) => {
+                self.handle_keylist_update_response(msg)
+            }
+            AriesMessage::CoordinateMediation(CoordinateMediation::Keylist(msg)) => self.handle_keylist(msg),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidMessageFormat,
+                format!("Message {:?} is not handled by the mediation recipient", message),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_fields::protocols::out_of_band::invitation::OobService;
+
+    use crate::handlers::out_of_band::sender::OutOfBandSender;
+    use crate::protocols::coordinate_mediation::recipient::state_machine::test_utils::_mediate_grant;
+    use crate::protocols::coordinate_mediation::test_utils::{
+        _recipient_key, _routing_endpoint, _routing_keys, _send_message,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mediated_service_in_oob_invitation() {
+        let recipient = MediationRecipient::build();
+        assert!(recipient.to_aries_service(vec![_recipient_key()]).is_err());
+
+        let recipient = recipient.send_mediation_request(_send_message()).await.unwrap();
+        let thread_id = recipient.get_thread_id().unwrap();
+        let recipient = recipient.handle_message(_mediate_grant(&thread_id).into()).unwrap();
+        assert_eq!(MediationRecipientState::Granted, recipient.get_state());
+
+        let oob = OutOfBandSender::create()
+            .append_mediated_service(&recipient, _recipient_key())
+            .unwrap();
+
+        let OobService::AriesService(service) = &oob.get_services()[0] else {
+            panic!("expected an inline service");
+        };
+        assert_eq!(_routing_endpoint(), service.service_endpoint);
+        assert_eq!(_routing_keys(), service.routing_keys);
+        assert_eq!(vec![_recipient_key()], service.recipient_keys);
+    }
+
+    #[cfg(feature = "modular_libs")]
+    mod connection {
+        use std::sync::{Arc, Mutex};
+
+        use aries_vcx_core::ledger::request_submitter::in_memory_ledger::InMemoryLedger;
+        use aries_vcx_core::wallet::base_wallet::BaseWallet;
+        use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+        use async_trait::async_trait;
+        use messages::msg_fields::protocols::connection::invitation::Invitation;
+        use messages::msg_fields::protocols::connection::Connection as ConnectionMsg;
+
+        use crate::core::profile::modular_libs_profile::ModularLibsProfile;
+        use crate::core::profile::profile::Profile;
+        use crate::handlers::util::AnyInvitation;
+        use crate::protocols::connection::pairwise_info::PairwiseInfo;
+        use crate::protocols::connection::Connection;
+        use crate::protocols::coordinate_mediation::recipient::state_machine::test_utils::_keylist;
+        use crate::protocols::coordinate_mediation::test_utils::{_capture_message, _sent_id};
+        use crate::transport::Transport;
+        use crate::utils::encryption_envelope::EncryptionEnvelope;
+
+        use super::*;
+
+        #[derive(Default)]
+        struct MockTransport {
+            sent: Mutex<Vec<(Vec<u8>, Url)>>,
+        }
+
+        #[async_trait]
+        impl Transport for MockTransport {
+            async fn send_message(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<()> {
+                self.sent.lock().unwrap().push((msg, service_endpoint));
+                Ok(())
+            }
+        }
+
+        async fn _granted_recipient(recipient_keys: Vec<String>) -> MediationRecipient {
+            let recipient = MediationRecipient::build()
+                .send_mediation_request(_send_message())
+                .await
+                .unwrap();
+            let thread_id = recipient.get_thread_id().unwrap();
+            let sent = Arc::new(Mutex::new(None));
+            let recipient = recipient
+                .handle_message(_mediate_grant(&thread_id).into())
+                .unwrap()
+                .send_keylist_query(_capture_message(sent.clone()))
+                .await
+                .unwrap();
+            recipient
+                .handle_keylist(_keylist(&_sent_id(&sent), recipient_keys))
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_mediated_connection_did_docs() {
+            // Both parties keep their keys in the same in-memory wallet
+            let profile: Arc<dyn Profile> = Arc::new(ModularLibsProfile::new_with_request_submitter(
+                Arc::new(InMemoryWallet::new()),
+                Arc::new(InMemoryLedger::new()),
+            ));
+            let wallet: Arc<dyn BaseWallet> = profile.inject_wallet();
+            let inviter_info = PairwiseInfo::create(&wallet).await.unwrap();
+            let invitee_info = PairwiseInfo::create(&wallet).await.unwrap();
+
+            // Keys which are not registered with the mediator can not be routed through it
+            let recipient = _granted_recipient(vec![]).await;
+            assert!(Connection::new_inviter("inviter".to_owned(), inviter_info.clone())
+                .create_mediated_invitation(&recipient)
+                .is_err());
+
+            let recipient = _granted_recipient(vec![inviter_info.pw_vk.clone(), invitee_info.pw_vk.clone()]).await;
+            let inviter = Connection::new_inviter("inviter".to_owned(), inviter_info.clone())
+                .create_mediated_invitation(&recipient)
+                .unwrap();
+            let AnyInvitation::Con(Invitation::Pairwise(invitation)) = inviter.get_invitation() else {
+                panic!("expected a pairwise invitation");
+            };
+            assert_eq!(_routing_endpoint(), invitation.content.service_endpoint);
+            assert_eq!(_routing_keys(), invitation.content.routing_keys);
+            assert_eq!(vec![inviter_info.pw_vk.clone()], invitation.content.recipient_keys);
+
+            // The invitee gets an unmediated invitation, so that the request is packed for the inviter only
+            let invitation = Connection::new_inviter("inviter".to_owned(), inviter_info)
+                .create_invitation(vec![], "https://inviter.org/endpoint".parse().unwrap())
+                .get_invitation()
+                .clone();
+            let transport = MockTransport::default();
+            Connection::new_invitee("invitee".to_owned(), invitee_info.clone())
+                .accept_invitation(&profile, invitation)
+                .await
+                .unwrap()
+                .send_mediated_request(&wallet, &recipient, &transport)
+                .await
+                .unwrap();
+
+            let (packed, _) = transport.sent.lock().unwrap().pop().unwrap();
+            let (message, _) = EncryptionEnvelope::anon_unpack(&wallet, packed).await.unwrap();
+            let AriesMessage::Connection(ConnectionMsg::Request(request)) = message else {
+                panic!("expected a connection request");
+            };
+            let did_doc = request.content.connection.did_doc;
+            assert_eq!(Some(_routing_endpoint()), did_doc.get_endpoint());
+            assert_eq!(_routing_keys(), did_doc.routing_keys());
+            assert_eq!(vec![invitee_info.pw_vk], did_doc.recipient_keys().unwrap());
+        }
+    }
+}
@@ -16,6 +16,7 @@ use crate::handlers::connection::mediated_connection::MediatedConnection;
 use crate::handlers::revocation_notification::receiver::RevocationNotificationReceiver;
 use crate::protocols::issuance::actions::CredentialIssuanceAction;
 use crate::protocols::issuance::holder::state_machine::{HolderSM, HolderState};
+use crate::protocols::issuance::CredentialNegotiationRound;
 use crate::protocols::SendClosure;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -142,6 +143,10 @@ impl Holder {
         self.holder_sm.get_thread_id()
     }
 
+    pub fn get_negotiation_history(&self) -> &[CredentialNegotiationRound] {
+        self.holder_sm.get_negotiation_history()
+    }
+
     pub async fn is_revokable(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
         self.holder_sm.is_revokable(profile).await
     }
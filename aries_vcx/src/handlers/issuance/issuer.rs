@@ -18,6 +18,7 @@ use crate::handlers::revocation_notification::sender::RevocationNotificationSend
 use crate::handlers::util::OfferInfo;
 use crate::protocols::issuance::actions::CredentialIssuanceAction;
 use crate::protocols::issuance::issuer::state_machine::{IssuerSM, IssuerState, RevocationInfoV1};
+use crate::protocols::issuance::CredentialNegotiationRound;
 use crate::protocols::revocation_notification::sender::state_machine::SenderConfigBuilder;
 use crate::protocols::SendClosure;
 
@@ -39,6 +40,12 @@ fn _build_credential_preview(credential_json: &str) -> VcxResult<CredentialPrevi
         secret!(credential_json)
     );
 
+    Ok(CredentialPreview::new(build_credential_attributes(credential_json)?))
+}
+
+/// Parses the credential values, given either as a `{name: value}` object or as a list of
+/// `{"name", "value"}` objects, into the attributes of a credential preview.
+pub(crate) fn build_credential_attributes(credential_json: &str) -> VcxResult<Vec<CredentialAttr>> {
     let cred_values: serde_json::Value = serde_json::from_str(credential_json).map_err(|err| {
         AriesVcxError::from_msg(
             AriesVcxErrorKind::InvalidJson,
@@ -50,7 +57,7 @@ fn _build_credential_preview(credential_json: &str) -> VcxResult<CredentialPrevi
     })?;
 
     // todo: should throw err if cred_values is not serde_json::Value::Array or serde_json::Value::Object
-    let mut credential_preview = CredentialPreview::new(Vec::new());
+    let mut attributes = Vec::new();
 
     match cred_values {
         serde_json::Value::Array(cred_values) => {
@@ -81,7 +88,7 @@ fn _build_credential_preview(credential_json: &str) -> VcxResult<CredentialPrevi
                 );
 
                 attr.mime_type = Some(MimeType::Plain);
-                credential_preview.attributes.push(attr);
+                attributes.push(attr);
             }
         }
         serde_json::Value::Object(values_map) => {
@@ -100,12 +107,12 @@ fn _build_credential_preview(credential_json: &str) -> VcxResult<CredentialPrevi
                 );
 
                 attr.mime_type = Some(MimeType::Plain);
-                credential_preview.attributes.push(attr);
+                attributes.push(attr);
             }
         }
         _ => {}
     };
-    Ok(credential_preview)
+    Ok(attributes)
 }
 
 impl Issuer {
@@ -138,7 +145,7 @@ impl Issuer {
             .issuer_create_credential_offer(&offer_info.cred_def_id)
             .await?;
         self.issuer_sm = self.issuer_sm.clone().build_credential_offer_msg(
-            &libindy_cred_offer,
+            &serde_json::to_string(&libindy_cred_offer)?,
             credential_preview,
             comment,
             &offer_info,
@@ -271,6 +278,10 @@ impl Issuer {
         self.issuer_sm.thread_id()
     }
 
+    pub fn get_negotiation_history(&self) -> &[CredentialNegotiationRound] {
+        self.issuer_sm.get_negotiation_history()
+    }
+
     pub fn get_proposal(&self) -> VcxResult<ProposeCredential> {
         self.issuer_sm.get_proposal()
     }
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use agency_client::agency_client::AgencyClient;
+use messages::msg_fields::protocols::cred_issuance_v2::ack::AckCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::propose_credential::ProposeCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::RequestCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::CredentialPreviewV2;
+use messages::AriesMessage;
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::connection::mediated_connection::MediatedConnection;
+use crate::handlers::issuance::issuer::build_credential_attributes;
+use crate::handlers::util::OfferInfo;
+use crate::protocols::issuance::issuer::state_machine::{IssuerState, RevocationInfoV1};
+use crate::protocols::issuance_v2::actions::CredentialIssuanceV2Action;
+use crate::protocols::issuance_v2::issuer::state_machine::IssuerV2SM;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IssuerV2 {
+    issuer_sm: IssuerV2SM,
+}
+
+impl IssuerV2 {
+    pub fn create(source_id: &str) -> VcxResult<IssuerV2> {
+        trace!("IssuerV2::create >>> source_id: {:?}", source_id);
+        let issuer_sm = IssuerV2SM::new(source_id);
+        Ok(IssuerV2 { issuer_sm })
+    }
+
+    pub fn create_from_proposal(source_id: &str, credential_proposal: &ProposeCredentialV2) -> VcxResult<IssuerV2> {
+        trace!(
+            "IssuerV2::create_from_proposal >>> source_id: {:?}, credential_proposal: {:?}",
+            source_id,
+            credential_proposal
+        );
+        let issuer_sm = IssuerV2SM::from_proposal(source_id, credential_proposal);
+        Ok(IssuerV2 { issuer_sm })
+    }
+
+    pub async fn build_credential_offer_msg(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        offer_info: OfferInfo,
+        comment: Option<String>,
+    ) -> VcxResult<()> {
+        let anoncreds = Arc::clone(profile).inject_anoncreds();
+        let credential_preview = CredentialPreviewV2::new(build_credential_attributes(&offer_info.credential_json)?);
+        let libindy_cred_offer = anoncreds
+            .issuer_create_credential_offer(&offer_info.cred_def_id)
+            .await?;
+        self.issuer_sm = self.issuer_sm.clone().build_credential_offer_msg(
+            &serde_json::to_string(&libindy_cred_offer)?,
+            credential_preview,
+            comment,
+            &offer_info,
+        )?;
+        Ok(())
+    }
+
+    pub fn get_credential_offer_msg(&self) -> VcxResult<AriesMessage> {
+        let offer = self.issuer_sm.get_credential_offer_msg()?;
+        Ok(offer.into())
+    }
+
+    pub fn mark_credential_offer_msg_sent(&mut self) -> VcxResult<()> {
+        self.issuer_sm = self.issuer_sm.clone().mark_credential_offer_msg_sent()?;
+        Ok(())
+    }
+
+    pub async fn send_credential_offer(&mut self, send_message: SendClosure) -> VcxResult<()> {
+        self.issuer_sm = self.issuer_sm.clone().send_credential_offer(send_message).await?;
+        Ok(())
+    }
+
+    pub fn process_credential_request(&mut self, request: RequestCredentialV2) -> VcxResult<()> {
+        self.issuer_sm = self.issuer_sm.clone().receive_request(request)?;
+        Ok(())
+    }
+
+    pub fn process_credential_ack(&mut self, ack: AckCredentialV2) -> VcxResult<()> {
+        self.issuer_sm = self.issuer_sm.clone().receive_ack(ack)?;
+        Ok(())
+    }
+
+    pub async fn send_credential(&mut self, profile: &Arc<dyn Profile>, send_message: SendClosure) -> VcxResult<()> {
+        self.issuer_sm = self.issuer_sm.clone().send_credential(profile, send_message).await?;
+        Ok(())
+    }
+
+    pub fn get_state(&self) -> IssuerState {
+        self.issuer_sm.get_state()
+    }
+
+    pub fn get_source_id(&self) -> VcxResult<String> {
+        Ok(self.issuer_sm.get_source_id())
+    }
+
+    pub fn is_terminal_state(&self) -> bool {
+        self.issuer_sm.is_terminal_state()
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        self.issuer_sm.find_message_to_handle(messages)
+    }
+
+    pub async fn revoke_credential_local(&self, profile: &Arc<dyn Profile>) -> VcxResult<()> {
+        let revocation_info: RevocationInfoV1 = self.issuer_sm.get_revocation_info().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "Credential is not revocable, no revocation info has been found.",
+        ))?;
+        if let (Some(cred_rev_id), Some(rev_reg_id), Some(tails_file)) = (
+            revocation_info.cred_rev_id,
+            revocation_info.rev_reg_id,
+            revocation_info.tails_file,
+        ) {
+            let anoncreds = Arc::clone(profile).inject_anoncreds();
+            anoncreds
+                .revoke_credential_local(&tails_file, &rev_reg_id, &cred_rev_id)
+                .await?;
+        } else {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Revocation info is not complete, cannot revoke credential.",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn get_rev_reg_id(&self) -> VcxResult<String> {
+        self.issuer_sm.get_rev_reg_id()
+    }
+
+    pub fn get_rev_id(&self) -> VcxResult<String> {
+        self.issuer_sm.get_rev_id()
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        self.issuer_sm.thread_id()
+    }
+
+    pub fn get_proposal(&self) -> VcxResult<ProposeCredentialV2> {
+        self.issuer_sm.get_proposal()
+    }
+
+    pub fn get_credential_status(&self) -> VcxResult<u32> {
+        Ok(self.issuer_sm.credential_status())
+    }
+
+    pub fn is_revokable(&self) -> bool {
+        self.issuer_sm.is_revokable()
+    }
+
+    pub async fn is_revoked(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
+        self.issuer_sm.is_revoked(profile).await
+    }
+
+    pub async fn step(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        message: CredentialIssuanceV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<()> {
+        self.issuer_sm = self
+            .issuer_sm
+            .clone()
+            .handle_message(profile, message, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_state(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        agency_client: &AgencyClient,
+        connection: &MediatedConnection,
+    ) -> VcxResult<IssuerState> {
+        trace!("IssuerV2::update_state >>>");
+        if self.is_terminal_state() {
+            return Ok(self.get_state());
+        }
+        let send_message = connection.send_message_closure(profile).await?;
+        let messages = connection.get_messages(agency_client).await?;
+        if let Some((uid, msg)) = self.find_message_to_handle(messages) {
+            self.step(profile, msg.into(), Some(send_message)).await?;
+            connection.update_message_status(&uid, agency_client).await?;
+        }
+        Ok(self.get_state())
+    }
+}
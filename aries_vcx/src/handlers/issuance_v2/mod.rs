@@ -0,0 +1,130 @@
+pub mod holder;
+pub mod issuer;
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use messages::msg_fields::protocols::cred_issuance_v2::CredentialIssuanceV2;
+    use messages::AriesMessage;
+
+    use crate::core::profile::profile::Profile;
+    use crate::errors::error::VcxResult;
+    use crate::handlers::issuance_v2::holder::HolderV2;
+    use crate::handlers::issuance_v2::issuer::IssuerV2;
+    use crate::handlers::util::OfferInfo;
+    use crate::protocols::issuance::holder::state_machine::HolderState;
+    use crate::protocols::issuance::issuer::state_machine::IssuerState;
+    use crate::protocols::SendClosure;
+    use crate::utils::devsetup::SetupMocks;
+    use crate::utils::mockdata::profile::mock_profile::MockProfile;
+
+    fn _send_message(sent: &Arc<Mutex<Vec<AriesMessage>>>) -> SendClosure {
+        let sent = Arc::clone(sent);
+        Box::new(move |message: AriesMessage| {
+            sent.lock().unwrap().push(message);
+            Box::pin(async { VcxResult::Ok(()) })
+        })
+    }
+
+    fn _last_sent(sent: &Arc<Mutex<Vec<AriesMessage>>>) -> AriesMessage {
+        sent.lock().unwrap().pop().unwrap()
+    }
+
+    fn _offer_info() -> OfferInfo {
+        OfferInfo::new(
+            json!({"name": "alice", "age": "25"}).to_string(),
+            "V4SGRU86Z58d6TV7PBUe6f:3:CL:1281:tag1".to_string(),
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_exchange_credential_v2() {
+        let _setup = SetupMocks::init();
+        let profile: Arc<dyn Profile> = Arc::new(MockProfile);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let mut issuer = IssuerV2::create("issuer").unwrap();
+        issuer
+            .build_credential_offer_msg(&profile, _offer_info(), Some("comment".into()))
+            .await
+            .unwrap();
+        issuer.send_credential_offer(_send_message(&sent)).await.unwrap();
+        assert_eq!(IssuerState::OfferSent, issuer.get_state());
+
+        let AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::OfferCredential(offer)) = _last_sent(&sent) else {
+            panic!("Expected a credential offer");
+        };
+        let mut holder = HolderV2::create_from_offer("holder", offer).unwrap();
+        assert_eq!(HolderState::OfferReceived, holder.get_state());
+        assert_eq!(issuer.get_thread_id().unwrap(), holder.get_thread_id().unwrap());
+        let attributes: serde_json::Value = serde_json::from_str(&holder.get_attributes().unwrap()).unwrap();
+        assert_eq!(json!({"name": "alice", "age": "25"}), attributes);
+
+        holder
+            .send_request(&profile, "pw_did".to_string(), _send_message(&sent))
+            .await
+            .unwrap();
+        assert_eq!(HolderState::RequestSent, holder.get_state());
+
+        let (_, request) = issuer
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        issuer
+            .step(&profile, request.into(), Some(_send_message(&sent)))
+            .await
+            .unwrap();
+        assert_eq!(IssuerState::RequestReceived, issuer.get_state());
+
+        issuer.send_credential(&profile, _send_message(&sent)).await.unwrap();
+        assert_eq!(IssuerState::CredentialSent, issuer.get_state());
+
+        let (_, credential) = holder
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        holder
+            .step(&profile, credential.into(), Some(_send_message(&sent)))
+            .await
+            .unwrap();
+        assert_eq!(HolderState::Finished, holder.get_state());
+        assert_eq!("cred_id", holder.get_cred_id().unwrap());
+
+        let (_, ack) = issuer
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        issuer.step(&profile, ack.into(), None).await.unwrap();
+        assert_eq!(IssuerState::Finished, issuer.get_state());
+    }
+
+    #[tokio::test]
+    async fn test_holder_declines_offer_v2() {
+        let _setup = SetupMocks::init();
+        let profile: Arc<dyn Profile> = Arc::new(MockProfile);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let mut issuer = IssuerV2::create("issuer").unwrap();
+        issuer
+            .build_credential_offer_msg(&profile, _offer_info(), None)
+            .await
+            .unwrap();
+        issuer.send_credential_offer(_send_message(&sent)).await.unwrap();
+
+        let AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::OfferCredential(offer)) = _last_sent(&sent) else {
+            panic!("Expected a credential offer");
+        };
+        let mut holder = HolderV2::create_from_offer("holder", offer).unwrap();
+        holder
+            .decline_offer(Some("not interested"), _send_message(&sent))
+            .await
+            .unwrap();
+        assert_eq!(HolderState::Failed, holder.get_state());
+
+        let (_, problem_report) = issuer
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        issuer.step(&profile, problem_report.into(), None).await.unwrap();
+        assert_eq!(IssuerState::Failed, issuer.get_state());
+    }
+}
@@ -15,22 +15,43 @@ use messages::msg_fields::protocols::out_of_band::OutOfBand;
 use messages::msg_fields::protocols::present_proof::present::Presentation;
 use messages::msg_fields::protocols::present_proof::request::RequestPresentation;
 use messages::msg_fields::protocols::present_proof::PresentProof;
+use messages::msg_types::Protocol;
 use messages::AriesMessage;
 use serde::Deserialize;
 use serde_json::Value;
+use shared_vcx::maybe_known::MaybeKnown;
 
 use crate::common::ledger::transactions::resolve_service;
 use crate::core::profile::profile::Profile;
 use crate::errors::error::prelude::*;
 use crate::handlers::connection::mediated_connection::MediatedConnection;
 use crate::handlers::util::{AnyInvitation, AttachmentId};
+use crate::protocols::connection::initiation_type::Invitee;
+use crate::protocols::connection::pairwise_info::PairwiseInfo;
 use crate::protocols::connection::GenericConnection;
+use crate::protocols::did_exchange::requester::states::invited::Invited;
+use crate::protocols::did_exchange::requester::DidExchangeRequester;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct OutOfBandReceiver {
     pub oob: Invitation,
 }
 
+/// Handshake protocols a connection can be built with from an out-of-band invitation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandshakeProtocol {
+    Connections,
+    DidExchange,
+}
+
+/// Connection built from an out-of-band invitation, using the
+/// handshake protocol selected from the invitation's `handshake_protocols`.
+#[derive(Clone)]
+pub enum OobConnection {
+    Connection(MediatedConnection),
+    DidExchange(DidExchangeRequester<Invited>),
+}
+
 impl OutOfBandReceiver {
     pub fn create_from_a2a_msg(msg: &AriesMessage) -> VcxResult<Self> {
         trace!("OutOfBandReceiver::create_from_a2a_msg >>> msg: {:?}", msg);
@@ -254,28 +275,100 @@ impl OutOfBandReceiver {
         Ok(None)
     }
 
+    /// Returns the first handshake protocol listed in the invitation which is also in `supported`.
+    /// The `handshake_protocols` are listed in the inviter's order of preference.
+    fn select_handshake_protocol(&self, supported: &[HandshakeProtocol]) -> Option<HandshakeProtocol> {
+        self.oob
+            .content
+            .handshake_protocols
+            .iter()
+            .flatten()
+            .filter_map(|protocol| match protocol {
+                MaybeKnown::Known(Protocol::ConnectionType(_)) => Some(HandshakeProtocol::Connections),
+                MaybeKnown::Known(Protocol::DidExchangeType(_)) => Some(HandshakeProtocol::DidExchange),
+                _ => None,
+            })
+            .find(|protocol| supported.contains(protocol))
+    }
+
+    /// Builds a connection with the first handshake protocol listed in the invitation, picking
+    /// DID Exchange when the inviter prefers it. Invitations listing no handshake protocol fall back
+    /// to connections/1.0.
     pub async fn build_connection(
         &self,
         profile: &Arc<dyn Profile>,
         agency_client: &AgencyClient,
         did_doc: AriesDidDoc,
         autohop_enabled: bool,
-    ) -> VcxResult<MediatedConnection> {
+    ) -> VcxResult<OobConnection> {
         trace!(
             "OutOfBandReceiver::build_connection >>> autohop_enabled: {}",
             autohop_enabled
         );
-        MediatedConnection::create_with_invite(
-            &self.oob.id,
+        self.build_connection_with_protocols(
             profile,
             agency_client,
-            AnyInvitation::Oob(self.oob.clone()),
             did_doc,
             autohop_enabled,
+            &[HandshakeProtocol::Connections, HandshakeProtocol::DidExchange],
         )
         .await
     }
 
+    /// Same as [`Self::build_connection`], restricted to the handshake protocols in `supported`,
+    /// for callers which can only drive some of them.
+    pub async fn build_connection_with_protocols(
+        &self,
+        profile: &Arc<dyn Profile>,
+        agency_client: &AgencyClient,
+        did_doc: AriesDidDoc,
+        autohop_enabled: bool,
+        supported: &[HandshakeProtocol],
+    ) -> VcxResult<OobConnection> {
+        trace!(
+            "OutOfBandReceiver::build_connection_with_protocols >>> autohop_enabled: {}, supported: {:?}",
+            autohop_enabled,
+            supported
+        );
+        let no_handshake_protocols = self
+            .oob
+            .content
+            .handshake_protocols
+            .as_ref()
+            .map_or(true, Vec::is_empty);
+        let protocol = match self.select_handshake_protocol(supported) {
+            Some(protocol) => protocol,
+            None if no_handshake_protocols && supported.contains(&HandshakeProtocol::Connections) => {
+                HandshakeProtocol::Connections
+            }
+            None => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::ActionNotSupported,
+                    format!("None of the supported handshake protocols {supported:?} is listed in the invitation"),
+                ))
+            }
+        };
+
+        match protocol {
+            HandshakeProtocol::Connections => MediatedConnection::create_with_invite(
+                &self.oob.id,
+                profile,
+                agency_client,
+                AnyInvitation::Oob(self.oob.clone()),
+                did_doc,
+                autohop_enabled,
+            )
+            .await
+            .map(OobConnection::Connection),
+            HandshakeProtocol::DidExchange => {
+                let pairwise_info = PairwiseInfo::create(&profile.inject_wallet()).await?;
+                let state = Invited::new(did_doc, self.oob.id.clone());
+                let requester = DidExchangeRequester::from_parts(self.oob.id.clone(), pairwise_info, Invitee, state);
+                Ok(OobConnection::DidExchange(requester))
+            }
+        }
+    }
+
     pub fn to_aries_message(&self) -> AriesMessage {
         self.oob.clone().into()
     }
@@ -290,3 +383,86 @@ impl OutOfBandReceiver {
         })
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_types::protocols::connection::ConnectionTypeV1;
+    use messages::msg_types::protocols::did_exchange::DidExchangeTypeV1;
+
+    use crate::handlers::out_of_band::sender::OutOfBandSender;
+    use crate::utils::mockdata::profile::mock_profile::MockProfile;
+
+    use super::*;
+
+    fn _receiver(protocols: Vec<Protocol>) -> OutOfBandReceiver {
+        let mut sender = OutOfBandSender::create();
+        for protocol in protocols {
+            sender = sender.append_handshake_protocol(protocol).unwrap();
+        }
+        OutOfBandReceiver { oob: sender.oob }
+    }
+
+    #[test]
+    fn test_select_handshake_protocol_in_invitation_order() {
+        let receiver = _receiver(vec![
+            DidExchangeTypeV1::new_v1_0().into(),
+            ConnectionTypeV1::new_v1_0().into(),
+        ]);
+
+        let both = [HandshakeProtocol::Connections, HandshakeProtocol::DidExchange];
+        assert_eq!(
+            receiver.select_handshake_protocol(&both),
+            Some(HandshakeProtocol::DidExchange)
+        );
+        assert_eq!(
+            receiver.select_handshake_protocol(&[HandshakeProtocol::Connections]),
+            Some(HandshakeProtocol::Connections)
+        );
+    }
+
+    #[test]
+    fn test_select_handshake_protocol_unsupported() {
+        let receiver = _receiver(vec![DidExchangeTypeV1::new_v1_0().into()]);
+        assert_eq!(
+            receiver.select_handshake_protocol(&[HandshakeProtocol::Connections]),
+            None
+        );
+
+        let receiver = _receiver(vec![]);
+        assert_eq!(
+            receiver.select_handshake_protocol(&[HandshakeProtocol::Connections]),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_connection_picks_did_exchange() {
+        let profile: Arc<dyn Profile> = Arc::new(MockProfile);
+        let receiver = _receiver(vec![
+            DidExchangeTypeV1::new_v1_0().into(),
+            ConnectionTypeV1::new_v1_0().into(),
+        ]);
+
+        let connection = receiver
+            .build_connection(&profile, &AgencyClient::new(), AriesDidDoc::default(), false)
+            .await
+            .unwrap();
+        match connection {
+            OobConnection::DidExchange(requester) => assert_eq!(requester.thread_id(), receiver.oob.id),
+            OobConnection::Connection(_) => panic!("Expected a DID exchange handshake"),
+        }
+
+        let connection = receiver
+            .build_connection_with_protocols(
+                &profile,
+                &AgencyClient::new(),
+                AriesDidDoc::default(),
+                false,
+                &[HandshakeProtocol::DidExchange],
+            )
+            .await
+            .unwrap();
+        assert!(matches!(connection, OobConnection::DidExchange(_)));
+    }
+}
@@ -15,7 +15,10 @@ use uuid::Uuid;
 
 use crate::{
     errors::error::prelude::*,
-    handlers::util::{make_attach_from_str, AttachmentId},
+    handlers::{
+        coordinate_mediation::recipient::MediationRecipient,
+        util::{make_attach_from_str, AttachmentId},
+    },
 };
 
 #[derive(Debug, PartialEq, Clone)]
@@ -54,6 +57,13 @@ impl OutOfBandSender {
         self
     }
 
+    /// Appends an inline service routed through the mediator `recipient` was granted mediation by.
+    /// The `recipient_key` is expected to be registered with the mediator.
+    pub fn append_mediated_service(self, recipient: &MediationRecipient, recipient_key: String) -> VcxResult<Self> {
+        let service = recipient.to_aries_service(vec![recipient_key])?;
+        Ok(self.append_service(&OobService::AriesService(service)))
+    }
+
     pub fn get_services(&self) -> Vec<OobService> {
         self.oob.content.services.clone()
     }
@@ -64,7 +74,7 @@ impl OutOfBandSender {
 
     pub fn append_handshake_protocol(mut self, protocol: Protocol) -> VcxResult<Self> {
         let new_protocol = match protocol {
-            Protocol::ConnectionType(_) => MaybeKnown::Known(protocol),
+            Protocol::ConnectionType(_) | Protocol::DidExchangeType(_) => MaybeKnown::Known(protocol),
             _ => {
                 return Err(AriesVcxError::from_msg(
                     AriesVcxErrorKind::ActionNotSupported,
@@ -0,0 +1,346 @@
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::decorators::attachment::AttachmentType;
+use messages::decorators::transport::{ReturnRoute, Transport as TransportDecorator};
+use messages::msg_fields::protocols::pickup::delivery::Delivery;
+use messages::msg_fields::protocols::pickup::delivery_request::{DeliveryRequest, DeliveryRequestContent};
+use messages::msg_fields::protocols::pickup::live_delivery_change::{LiveDeliveryChange, LiveDeliveryChangeContent};
+use messages::msg_fields::protocols::pickup::messages_received::{MessagesReceived, MessagesReceivedContent};
+use messages::msg_fields::protocols::pickup::status::Status;
+use messages::msg_fields::protocols::pickup::status_request::{StatusRequest, StatusRequestContent};
+use messages::msg_fields::protocols::pickup::{Pickup, PickupDecorators};
+use messages::AriesMessage;
+use uuid::Uuid;
+
+use crate::errors::error::prelude::*;
+use crate::transport::Transport;
+use crate::utils::encryption_envelope::EncryptionEnvelope;
+
+/// A message delivered by the mediator, already unpacked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickedUpMessage {
+    /// Id the mediator assigned to the queued message, used to acknowledge it.
+    pub id: String,
+    pub message: AriesMessage,
+    pub sender_vk: Option<String>,
+}
+
+/// A delivered message which could not be unpacked.
+#[derive(Debug)]
+pub struct FailedMessage {
+    /// Id the mediator assigned to the queued message, if the attachment carried one.
+    pub id: Option<String>,
+    pub error: AriesVcxError,
+}
+
+/// The outcome of a delivery. A message failing to unpack does not prevent the others
+/// from being returned.
+#[derive(Debug, Default)]
+pub struct DeliveredMessages {
+    pub messages: Vec<PickedUpMessage>,
+    pub failures: Vec<FailedMessage>,
+}
+
+/// Client for the [message pickup 2.0](<https://github.com/hyperledger/aries-rfcs/blob/main/features/0685-pickup-v2/README.md>)
+/// protocol, retrieving the messages a mediator queued for us.
+///
+/// Every request is sent with `return_route` set to `all`, so the used [`Transport`] must
+/// implement [`Transport::send_message_with_response`].
+pub struct PickupClient<'a, T> {
+    wallet: &'a Arc<dyn BaseWallet>,
+    transport: &'a T,
+    sender_vk: String,
+    mediator_did_doc: AriesDidDoc,
+}
+
+impl<'a, T> PickupClient<'a, T>
+where
+    T: Transport,
+{
+    /// Creates a client talking to the mediator described by `mediator_did_doc`,
+    /// authenticating requests with our `sender_vk`.
+    pub fn new(
+        wallet: &'a Arc<dyn BaseWallet>,
+        transport: &'a T,
+        sender_vk: String,
+        mediator_did_doc: AriesDidDoc,
+    ) -> Self {
+        Self {
+            wallet,
+            transport,
+            sender_vk,
+            mediator_did_doc,
+        }
+    }
+
+    pub async fn get_status(&self, recipient_key: Option<String>) -> VcxResult<Status> {
+        let content = StatusRequestContent { recipient_key };
+        let request = StatusRequest::with_decorators(Uuid::new_v4().to_string(), content, return_route_decorators());
+
+        match self.send_and_receive(request.into()).await? {
+            AriesMessage::Pickup(Pickup::Status(status)) => Ok(status),
+            msg => Err(unexpected_response(msg)),
+        }
+    }
+
+    /// Requests up to `limit` queued messages. The messages stay queued at the mediator
+    /// until they are acknowledged through [`PickupClient::mark_messages_received`].
+    pub async fn get_messages(&self, limit: u32, recipient_key: Option<String>) -> VcxResult<DeliveredMessages> {
+        let mut content = DeliveryRequestContent::new(limit);
+        content.recipient_key = recipient_key;
+        let request = DeliveryRequest::with_decorators(Uuid::new_v4().to_string(), content, return_route_decorators());
+
+        match self.send_and_receive(request.into()).await? {
+            AriesMessage::Pickup(Pickup::Delivery(delivery)) => Ok(unpack_delivery(self.wallet, delivery).await),
+            // The mediator answers with a status message when there is nothing to deliver.
+            AriesMessage::Pickup(Pickup::Status(status)) if status.content.message_count == 0 => {
+                Ok(DeliveredMessages::default())
+            }
+            msg => Err(unexpected_response(msg)),
+        }
+    }
+
+    /// Acknowledges the given messages, letting the mediator remove them from its queue.
+    pub async fn mark_messages_received(&self, message_ids: Vec<String>) -> VcxResult<Status> {
+        let content = MessagesReceivedContent::new(message_ids);
+        let request = MessagesReceived::with_decorators(Uuid::new_v4().to_string(), content, return_route_decorators());
+
+        match self.send_and_receive(request.into()).await? {
+            AriesMessage::Pickup(Pickup::Status(status)) => Ok(status),
+            msg => Err(unexpected_response(msg)),
+        }
+    }
+
+    /// Retrieves up to `limit` queued messages and acknowledges the ones which could be unpacked.
+    ///
+    /// Messages failing to unpack are reported in [`DeliveredMessages::failures`] and stay queued
+    /// at the mediator, it is up to the caller to retry or acknowledge them.
+    pub async fn pickup_messages(&self, limit: u32) -> VcxResult<DeliveredMessages> {
+        let delivered = self.get_messages(limit, None).await?;
+        if !delivered.messages.is_empty() {
+            self.mark_messages_received(delivered.messages.iter().map(|m| m.id.clone()).collect())
+                .await?;
+        }
+        Ok(delivered)
+    }
+
+    /// Asks the mediator to switch live delivery on or off. Live delivery only makes
+    /// sense over transports keeping the session open, such as websockets.
+    pub async fn set_live_delivery(&self, live_delivery: bool) -> VcxResult<Status> {
+        let content = LiveDeliveryChangeContent::new(live_delivery);
+        let request =
+            LiveDeliveryChange::with_decorators(Uuid::new_v4().to_string(), content, return_route_decorators());
+
+        match self.send_and_receive(request.into()).await? {
+            AriesMessage::Pickup(Pickup::Status(status)) => Ok(status),
+            msg => Err(unexpected_response(msg)),
+        }
+    }
+
+    async fn send_and_receive(&self, message: AriesMessage) -> VcxResult<AriesMessage> {
+        let endpoint = self.mediator_did_doc.get_endpoint().ok_or_else(|| {
+            AriesVcxError::from_msg(AriesVcxErrorKind::InvalidUrl, "No endpoint found in mediator DIDDoc")
+        })?;
+        let mediator_vk = self
+            .mediator_did_doc
+            .recipient_keys()?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "No recipient key found in mediator DIDDoc",
+                )
+            })?;
+
+        let EncryptionEnvelope(packed) =
+            EncryptionEnvelope::create(self.wallet, &message, Some(&self.sender_vk), &self.mediator_did_doc).await?;
+        let response = self.transport.send_message_with_response(packed, endpoint).await?;
+
+        EncryptionEnvelope::auth_unpack(self.wallet, response, &mediator_vk).await
+    }
+}
+
+fn return_route_decorators() -> PickupDecorators {
+    let mut decorators = PickupDecorators::default();
+    decorators.transport = Some(TransportDecorator::new(ReturnRoute::All));
+    decorators
+}
+
+fn unexpected_response(msg: AriesMessage) -> AriesVcxError {
+    AriesVcxError::from_msg(
+        AriesVcxErrorKind::InvalidMessageFormat,
+        format!("Unexpected response from mediator: {:?}", msg),
+    )
+}
+
+async fn unpack_delivery(wallet: &Arc<dyn BaseWallet>, delivery: Delivery) -> DeliveredMessages {
+    let mut delivered = DeliveredMessages::default();
+
+    for attachment in delivery.content.attach {
+        let id = match attachment.id {
+            Some(id) => id,
+            None => {
+                delivered.failures.push(FailedMessage {
+                    id: None,
+                    error: AriesVcxError::from_msg(
+                        AriesVcxErrorKind::InvalidMessageFormat,
+                        "Delivered attachment is missing its message id",
+                    ),
+                });
+                continue;
+            }
+        };
+        match unpack_attachment(wallet, &id, attachment.data.content).await {
+            Ok((message, sender_vk)) => delivered.messages.push(PickedUpMessage { id, message, sender_vk }),
+            Err(error) => {
+                warn!("Failed to unpack delivered message {}: {}", id, error);
+                delivered.failures.push(FailedMessage { id: Some(id), error });
+            }
+        }
+    }
+
+    delivered
+}
+
+async fn unpack_attachment(
+    wallet: &Arc<dyn BaseWallet>,
+    id: &str,
+    content: AttachmentType,
+) -> VcxResult<(AriesMessage, Option<String>)> {
+    let packed = match content {
+        AttachmentType::Base64(encoded) => base64::decode(&encoded)
+            .or_else(|_| base64::decode_config(&encoded, base64::URL_SAFE))
+            .map_err(|err| {
+                AriesVcxError::from_msg(
+                    AriesVcxErrorKind::SerializationError,
+                    format!("Delivered message {} is not valid base64: {}", id, err),
+                )
+            })?,
+        AttachmentType::Json(value) => value.to_string().into_bytes(),
+        AttachmentType::Links(_) => {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::ActionNotSupported,
+                format!("Delivered message {} is only available as links", id),
+            ))
+        }
+    };
+
+    EncryptionEnvelope::anon_unpack(wallet, packed).await
+}
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use messages::decorators::attachment::{Attachment, AttachmentData};
+    use messages::msg_fields::protocols::pickup::delivery::DeliveryContent;
+    use messages::msg_fields::protocols::trust_ping::ping::{Ping, PingContent, PingDecorators};
+
+    use super::*;
+
+    async fn pack_for(
+        wallet: &Arc<dyn BaseWallet>,
+        sender_vk: &str,
+        recipient_vk: &str,
+        msg: &AriesMessage,
+    ) -> Vec<u8> {
+        wallet
+            .pack_message(
+                Some(sender_vk),
+                &json!([recipient_vk]).to_string(),
+                json!(msg).to_string().as_bytes(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unpack_delivery() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, our_vk) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let (_, their_vk) = wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let ping: AriesMessage = Ping::with_decorators(
+            Uuid::new_v4().to_string(),
+            PingContent::default(),
+            PingDecorators::default(),
+        )
+        .into();
+        let packed = pack_for(&wallet, &their_vk, &our_vk, &ping).await;
+
+        let mut attachment = Attachment::new(AttachmentData::new(AttachmentType::Base64(base64::encode(packed))));
+        attachment.id = Some("queued_message_id".to_owned());
+        let delivery = Delivery::with_decorators(
+            Uuid::new_v4().to_string(),
+            DeliveryContent::new(vec![attachment]),
+            PickupDecorators::default(),
+        );
+
+        let delivered = unpack_delivery(&wallet, delivery).await;
+        assert_eq!(
+            vec![PickedUpMessage {
+                id: "queued_message_id".to_owned(),
+                message: ping,
+                sender_vk: Some(their_vk),
+            }],
+            delivered.messages
+        );
+        assert!(delivered.failures.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unpack_delivery_reports_failures() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, our_vk) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let (_, their_vk) = wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let ping: AriesMessage = Ping::with_decorators(
+            Uuid::new_v4().to_string(),
+            PingContent::default(),
+            PingDecorators::default(),
+        )
+        .into();
+        let packed = pack_for(&wallet, &their_vk, &our_vk, &ping).await;
+
+        let mut broken = Attachment::new(AttachmentData::new(AttachmentType::Base64("not base64!".to_owned())));
+        broken.id = Some("broken_message_id".to_owned());
+        let mut valid = Attachment::new(AttachmentData::new(AttachmentType::Base64(base64::encode(packed))));
+        valid.id = Some("valid_message_id".to_owned());
+        let delivery = Delivery::with_decorators(
+            Uuid::new_v4().to_string(),
+            DeliveryContent::new(vec![broken, valid]),
+            PickupDecorators::default(),
+        );
+
+        // The broken message does not prevent the valid one from being unpacked
+        let delivered = unpack_delivery(&wallet, delivery).await;
+        assert_eq!(1, delivered.messages.len());
+        assert_eq!("valid_message_id", delivered.messages[0].id);
+        assert_eq!(1, delivered.failures.len());
+        assert_eq!(Some("broken_message_id".to_owned()), delivered.failures[0].id);
+        assert_eq!(
+            AriesVcxErrorKind::SerializationError,
+            delivered.failures[0].error.kind()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unpack_delivery_reports_missing_message_id() {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let attachment = Attachment::new(AttachmentData::new(AttachmentType::Base64(String::new())));
+        let delivery = Delivery::with_decorators(
+            Uuid::new_v4().to_string(),
+            DeliveryContent::new(vec![attachment]),
+            PickupDecorators::default(),
+        );
+
+        let delivered = unpack_delivery(&wallet, delivery).await;
+        assert!(delivered.messages.is_empty());
+        assert_eq!(1, delivered.failures.len());
+        assert_eq!(None, delivered.failures[0].id);
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use agency_client::agency_client::AgencyClient;
+use aries_vcx_core::anoncreds::types::ProofRequest;
 use messages::msg_fields::protocols::present_proof::ack::AckPresentation;
 use messages::msg_fields::protocols::present_proof::present::Presentation;
 use messages::msg_fields::protocols::present_proof::propose::PresentationPreview;
@@ -15,6 +16,7 @@ use crate::handlers::connection::mediated_connection::MediatedConnection;
 use crate::handlers::util::{get_attach_as_string, PresentationProposalData};
 use crate::protocols::proof_presentation::prover::messages::ProverMessages;
 use crate::protocols::proof_presentation::prover::state_machine::{ProverSM, ProverState};
+use crate::protocols::proof_presentation::PresentationNegotiationRound;
 use crate::protocols::SendClosure;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -51,12 +53,18 @@ impl Prover {
 
     pub async fn retrieve_credentials(&self, profile: &Arc<dyn Profile>) -> VcxResult<String> {
         trace!("Prover::retrieve_credentials >>>");
-        let presentation_request = self.presentation_request_data()?;
+        let presentation_request: ProofRequest =
+            serde_json::from_str(&self.presentation_request_data()?).map_err(|err| {
+                AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidProofRequest,
+                    format!("Cannot deserialize proof request: {}", err),
+                )
+            })?;
         let anoncreds = Arc::clone(profile).inject_anoncreds();
-        anoncreds
+        let retrieved_credentials = anoncreds
             .prover_get_credentials_for_proof_req(&presentation_request)
-            .await
-            .map_err(|err| err.into())
+            .await?;
+        Ok(serde_json::to_string(&retrieved_credentials)?)
     }
 
     pub async fn generate_presentation(
@@ -168,6 +176,10 @@ impl Prover {
         self.prover_sm.get_thread_id()
     }
 
+    pub fn get_negotiation_history(&self) -> &[PresentationNegotiationRound] {
+        self.prover_sm.get_negotiation_history()
+    }
+
     pub async fn step(
         &mut self,
         profile: &Arc<dyn Profile>,
@@ -16,6 +16,7 @@ use crate::handlers::util::get_attach_as_string;
 use crate::protocols::proof_presentation::verifier::messages::VerifierMessages;
 use crate::protocols::proof_presentation::verifier::state_machine::{VerifierSM, VerifierState};
 use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+use crate::protocols::proof_presentation::PresentationNegotiationRound;
 use crate::protocols::SendClosure;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
@@ -159,6 +160,10 @@ impl Verifier {
         Ok(self.verifier_sm.thread_id())
     }
 
+    pub fn get_negotiation_history(&self) -> &[PresentationNegotiationRound] {
+        self.verifier_sm.get_negotiation_history()
+    }
+
     pub async fn step(
         &mut self,
         profile: &Arc<dyn Profile>,
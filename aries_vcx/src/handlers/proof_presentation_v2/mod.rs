@@ -0,0 +1,119 @@
+pub mod prover;
+pub mod verifier;
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use messages::msg_fields::protocols::present_proof_v2::PresentProofV2;
+    use messages::AriesMessage;
+
+    use crate::common::proofs::proof_request::PresentationRequestData;
+    use crate::core::profile::profile::Profile;
+    use crate::errors::error::VcxResult;
+    use crate::handlers::proof_presentation_v2::prover::ProverV2;
+    use crate::handlers::proof_presentation_v2::verifier::VerifierV2;
+    use crate::protocols::proof_presentation::prover::state_machine::ProverState;
+    use crate::protocols::proof_presentation::verifier::state_machine::VerifierState;
+    use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+    use crate::protocols::SendClosure;
+    use crate::utils::devsetup::SetupMocks;
+    use crate::utils::mockdata::mock_settings::MockBuilder;
+    use crate::utils::mockdata::profile::mock_profile::MockProfile;
+
+    fn _send_message(sent: &Arc<Mutex<Vec<AriesMessage>>>) -> SendClosure {
+        let sent = Arc::clone(sent);
+        Box::new(move |message: AriesMessage| {
+            sent.lock().unwrap().push(message);
+            Box::pin(async { VcxResult::Ok(()) })
+        })
+    }
+
+    fn _last_sent(sent: &Arc<Mutex<Vec<AriesMessage>>>) -> AriesMessage {
+        sent.lock().unwrap().pop().unwrap()
+    }
+
+    async fn _prover_from_sent_request(sent: &Arc<Mutex<Vec<AriesMessage>>>) -> (VerifierV2, ProverV2) {
+        let request_data = PresentationRequestData {
+            name: "proof".to_string(),
+            ..PresentationRequestData::default()
+        };
+        let mut verifier = VerifierV2::create_from_request("verifier", &request_data, None).unwrap();
+        verifier.send_presentation_request(_send_message(sent)).await.unwrap();
+        assert_eq!(VerifierState::PresentationRequestSent, verifier.get_state());
+
+        let AriesMessage::PresentProofV2(PresentProofV2::RequestPresentation(request)) = _last_sent(sent) else {
+            panic!("Expected a presentation request");
+        };
+        let prover = ProverV2::create_from_request("prover", request).unwrap();
+        assert_eq!(ProverState::PresentationRequestReceived, prover.get_state());
+        assert_eq!(verifier.get_thread_id().unwrap(), prover.get_thread_id().unwrap());
+        assert_eq!(
+            verifier.get_presentation_request_attachment().unwrap(),
+            prover.presentation_request_data().unwrap()
+        );
+        (verifier, prover)
+    }
+
+    #[tokio::test]
+    async fn test_exchange_presentation_v2() {
+        let _setup = SetupMocks::init();
+        let _mock_builder = MockBuilder::init()
+            .set_mock_generate_indy_proof(r#"{"proof": "mocked"}"#)
+            .set_mock_result_for_validate_indy_proof(Ok(true));
+        let profile: Arc<dyn Profile> = Arc::new(MockProfile);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let (mut verifier, mut prover) = _prover_from_sent_request(&sent).await;
+        prover
+            .generate_presentation(&profile, "{}".to_string(), "{}".to_string())
+            .await
+            .unwrap();
+        assert_eq!(ProverState::PresentationPrepared, prover.get_state());
+        prover.send_presentation(_send_message(&sent)).await.unwrap();
+        assert_eq!(ProverState::PresentationSent, prover.get_state());
+
+        let (_, presentation) = verifier
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        verifier
+            .step(&profile, presentation.into(), Some(_send_message(&sent)))
+            .await
+            .unwrap();
+        assert_eq!(VerifierState::Finished, verifier.get_state());
+        assert_eq!(
+            PresentationVerificationStatus::Valid,
+            verifier.get_verification_status()
+        );
+        assert_eq!(
+            r#"{"proof": "mocked"}"#,
+            verifier.get_presentation_attachment().unwrap()
+        );
+
+        let (_, ack) = prover
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        prover.step(&profile, ack.into(), None).await.unwrap();
+        assert_eq!(ProverState::Finished, prover.get_state());
+    }
+
+    #[tokio::test]
+    async fn test_prover_declines_request_v2() {
+        let _setup = SetupMocks::init();
+        let profile: Arc<dyn Profile> = Arc::new(MockProfile);
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let (mut verifier, mut prover) = _prover_from_sent_request(&sent).await;
+        prover
+            .decline_presentation_request("not interested".to_string(), _send_message(&sent))
+            .await
+            .unwrap();
+        assert_eq!(ProverState::Failed, prover.get_state());
+
+        let (_, problem_report) = verifier
+            .find_message_to_handle([("uid".to_string(), _last_sent(&sent))].into())
+            .unwrap();
+        verifier.step(&profile, problem_report.into(), None).await.unwrap();
+        assert_eq!(VerifierState::Failed, verifier.get_state());
+    }
+}
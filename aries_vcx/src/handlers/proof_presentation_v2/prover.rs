@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use agency_client::agency_client::AgencyClient;
+use aries_vcx_core::anoncreds::types::ProofRequest;
+use messages::msg_fields::protocols::present_proof_v2::ack::AckPresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::present::PresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::RequestPresentationV2;
+use messages::AriesMessage;
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::connection::mediated_connection::MediatedConnection;
+use crate::protocols::proof_presentation::prover::state_machine::ProverState;
+use crate::protocols::proof_presentation_v2::actions::PresentProofV2Action;
+use crate::protocols::proof_presentation_v2::prover::state_machine::ProverV2SM;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProverV2 {
+    prover_sm: ProverV2SM,
+}
+
+impl ProverV2 {
+    pub fn create(source_id: &str) -> VcxResult<ProverV2> {
+        trace!("ProverV2::create >>> source_id: {}", source_id);
+        Ok(ProverV2 {
+            prover_sm: ProverV2SM::new(source_id.to_string()),
+        })
+    }
+
+    pub fn create_from_request(source_id: &str, presentation_request: RequestPresentationV2) -> VcxResult<ProverV2> {
+        trace!(
+            "ProverV2::create_from_request >>> source_id: {}, presentation_request: {:?}",
+            source_id,
+            presentation_request
+        );
+        Ok(ProverV2 {
+            prover_sm: ProverV2SM::from_request(presentation_request, source_id.to_string()),
+        })
+    }
+
+    pub fn get_state(&self) -> ProverState {
+        self.prover_sm.get_state()
+    }
+
+    pub fn presentation_status(&self) -> u32 {
+        self.prover_sm.get_presentation_status()
+    }
+
+    /// Returns the `hlindy/proof-req@v2.0` proof request.
+    pub fn presentation_request_data(&self) -> VcxResult<String> {
+        self.prover_sm.get_presentation_request_attachment()
+    }
+
+    pub fn get_presentation_request(&self) -> VcxResult<RequestPresentationV2> {
+        Ok(self.prover_sm.get_presentation_request()?.to_owned())
+    }
+
+    pub async fn retrieve_credentials(&self, profile: &Arc<dyn Profile>) -> VcxResult<String> {
+        trace!("ProverV2::retrieve_credentials >>>");
+        let presentation_request: ProofRequest =
+            serde_json::from_str(&self.presentation_request_data()?).map_err(|err| {
+                AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidProofRequest,
+                    format!("Cannot deserialize proof request: {}", err),
+                )
+            })?;
+        let anoncreds = Arc::clone(profile).inject_anoncreds();
+        let retrieved_credentials = anoncreds
+            .prover_get_credentials_for_proof_req(&presentation_request)
+            .await?;
+        Ok(serde_json::to_string(&retrieved_credentials)?)
+    }
+
+    pub async fn generate_presentation(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        credentials: String,
+        self_attested_attrs: String,
+    ) -> VcxResult<()> {
+        trace!(
+            "ProverV2::generate_presentation >>> credentials: {}, self_attested_attrs: {:?}",
+            credentials,
+            self_attested_attrs
+        );
+        self.prover_sm = self
+            .prover_sm
+            .clone()
+            .generate_presentation(profile, credentials, self_attested_attrs)
+            .await?;
+        Ok(())
+    }
+
+    pub fn get_presentation_msg(&self) -> VcxResult<PresentationV2> {
+        Ok(self.prover_sm.get_presentation_msg()?.to_owned())
+    }
+
+    pub async fn send_proposal(&mut self, proposal: ProposePresentationV2, send_message: SendClosure) -> VcxResult<()> {
+        trace!("ProverV2::send_proposal >>>");
+        self.prover_sm = self
+            .prover_sm
+            .clone()
+            .send_presentation_proposal(proposal, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn send_presentation(&mut self, send_message: SendClosure) -> VcxResult<()> {
+        trace!("ProverV2::send_presentation >>>");
+        self.prover_sm = self.prover_sm.clone().send_presentation(send_message).await?;
+        Ok(())
+    }
+
+    pub async fn decline_presentation_request(&mut self, reason: String, send_message: SendClosure) -> VcxResult<()> {
+        trace!("ProverV2::decline_presentation_request >>> reason: {:?}", reason);
+        self.prover_sm = self
+            .prover_sm
+            .clone()
+            .decline_presentation_request(reason, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub fn process_presentation_ack(&mut self, ack: AckPresentationV2) -> VcxResult<()> {
+        trace!("ProverV2::process_presentation_ack >>>");
+        self.prover_sm = self.prover_sm.clone().receive_presentation_ack(ack)?;
+        Ok(())
+    }
+
+    pub fn progressable_by_message(&self) -> bool {
+        self.prover_sm.progressable_by_message()
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        self.prover_sm.find_message_to_handle(messages)
+    }
+
+    pub fn get_source_id(&self) -> String {
+        self.prover_sm.source_id()
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        self.prover_sm.get_thread_id()
+    }
+
+    pub async fn step(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        message: PresentProofV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<()> {
+        self.prover_sm = self
+            .prover_sm
+            .clone()
+            .handle_message(profile, message, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_state(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        agency_client: &AgencyClient,
+        connection: &MediatedConnection,
+    ) -> VcxResult<ProverState> {
+        trace!("ProverV2::update_state >>>");
+        if !self.progressable_by_message() {
+            return Ok(self.get_state());
+        }
+        let send_message = connection.send_message_closure(profile).await?;
+
+        let messages = connection.get_messages(agency_client).await?;
+        if let Some((uid, msg)) = self.find_message_to_handle(messages) {
+            self.step(profile, msg.into(), Some(send_message)).await?;
+            connection.update_message_status(&uid, agency_client).await?;
+        }
+        Ok(self.get_state())
+    }
+}
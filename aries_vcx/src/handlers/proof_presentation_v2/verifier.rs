@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use agency_client::agency_client::AgencyClient;
+use messages::msg_fields::protocols::present_proof_v2::present::{PresentationAttachmentFormatType, PresentationV2};
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::{
+    RequestPresentationAttachmentFormatType, RequestPresentationV2,
+};
+use messages::AriesMessage;
+
+use crate::common::proofs::proof_request::PresentationRequestData;
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::connection::mediated_connection::MediatedConnection;
+use crate::protocols::common::get_attach_for_format;
+use crate::protocols::proof_presentation::verifier::state_machine::VerifierState;
+use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+use crate::protocols::proof_presentation_v2::actions::PresentProofV2Action;
+use crate::protocols::proof_presentation_v2::verifier::state_machine::VerifierV2SM;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerifierV2 {
+    verifier_sm: VerifierV2SM,
+}
+
+impl VerifierV2 {
+    pub fn create(source_id: &str) -> VcxResult<Self> {
+        trace!("VerifierV2::create >>> source_id: {:?}", source_id);
+        Ok(Self {
+            verifier_sm: VerifierV2SM::new(source_id),
+        })
+    }
+
+    pub fn create_from_request(
+        source_id: &str,
+        presentation_request: &PresentationRequestData,
+        comment: Option<String>,
+    ) -> VcxResult<Self> {
+        trace!(
+            "VerifierV2::create_from_request >>> source_id: {:?}, presentation_request: {:?}",
+            source_id,
+            presentation_request
+        );
+        let verifier_sm = VerifierV2SM::from_request(source_id, presentation_request, comment)?;
+        Ok(Self { verifier_sm })
+    }
+
+    pub fn create_from_proposal(source_id: &str, presentation_proposal: &ProposePresentationV2) -> VcxResult<Self> {
+        trace!(
+            "VerifierV2::create_from_proposal >>> source_id: {:?}, presentation_proposal: {:?}",
+            source_id,
+            presentation_proposal
+        );
+        Ok(Self {
+            verifier_sm: VerifierV2SM::from_proposal(source_id, presentation_proposal),
+        })
+    }
+
+    pub fn get_source_id(&self) -> String {
+        self.verifier_sm.source_id()
+    }
+
+    pub fn get_state(&self) -> VerifierState {
+        self.verifier_sm.get_state()
+    }
+
+    pub fn set_request(
+        &mut self,
+        presentation_request_data: PresentationRequestData,
+        comment: Option<String>,
+    ) -> VcxResult<()> {
+        trace!(
+            "VerifierV2::set_request >>> presentation_request_data: {:?}, comment: {:?}",
+            presentation_request_data,
+            comment
+        );
+        self.verifier_sm = self
+            .verifier_sm
+            .clone()
+            .set_request(&presentation_request_data, comment)?;
+        Ok(())
+    }
+
+    pub async fn send_presentation_request(&mut self, send_message: SendClosure) -> VcxResult<()> {
+        if self.verifier_sm.get_state() == VerifierState::PresentationRequestSet {
+            let request = self.verifier_sm.presentation_request_msg()?.into();
+            send_message(request).await?;
+            self.verifier_sm = self.verifier_sm.clone().mark_presentation_request_msg_sent()?;
+        }
+        Ok(())
+    }
+
+    pub fn mark_presentation_request_msg_sent(&mut self) -> VcxResult<()> {
+        trace!("VerifierV2::mark_presentation_request_msg_sent >>>");
+        self.verifier_sm = self.verifier_sm.clone().mark_presentation_request_msg_sent()?;
+        Ok(())
+    }
+
+    pub async fn verify_presentation(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        presentation: PresentationV2,
+        send_message: SendClosure,
+    ) -> VcxResult<()> {
+        trace!("VerifierV2::verify_presentation >>>");
+        self.verifier_sm = self
+            .verifier_sm
+            .clone()
+            .verify_presentation(profile, presentation, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn decline_presentation_proposal(&mut self, reason: String, send_message: SendClosure) -> VcxResult<()> {
+        trace!("VerifierV2::decline_presentation_proposal >>> reason: {:?}", reason);
+        self.verifier_sm = self
+            .verifier_sm
+            .clone()
+            .reject_presentation_proposal(reason, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub fn get_presentation_request_msg(&self) -> VcxResult<RequestPresentationV2> {
+        self.verifier_sm.presentation_request_msg()
+    }
+
+    /// Returns the `hlindy/proof-req@v2.0` proof request.
+    pub fn get_presentation_request_attachment(&self) -> VcxResult<String> {
+        let request = self.verifier_sm.presentation_request_msg()?;
+        get_attach_for_format(
+            &request.content.formats,
+            &request.content.request_presentations_attach,
+            RequestPresentationAttachmentFormatType::HyperledgerIndyProofRequest2_0,
+        )
+    }
+
+    pub fn get_presentation_msg(&self) -> VcxResult<PresentationV2> {
+        self.verifier_sm.get_presentation_msg()
+    }
+
+    /// Returns the `hlindy/proof@v2.0` proof.
+    pub fn get_presentation_attachment(&self) -> VcxResult<String> {
+        let presentation = self.verifier_sm.get_presentation_msg()?;
+        get_attach_for_format(
+            &presentation.content.formats,
+            &presentation.content.presentations_attach,
+            PresentationAttachmentFormatType::HyperledgerIndyProof2_0,
+        )
+    }
+
+    pub fn get_verification_status(&self) -> PresentationVerificationStatus {
+        self.verifier_sm.get_verification_status()
+    }
+
+    pub fn get_presentation_proposal(&self) -> VcxResult<ProposePresentationV2> {
+        self.verifier_sm.presentation_proposal()
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        Ok(self.verifier_sm.thread_id())
+    }
+
+    pub fn progressable_by_message(&self) -> bool {
+        self.verifier_sm.progressable_by_message()
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        self.verifier_sm.find_message_to_handle(messages)
+    }
+
+    pub async fn step(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        message: PresentProofV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<()> {
+        self.verifier_sm = self
+            .verifier_sm
+            .clone()
+            .handle_message(profile, message, send_message)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_state(
+        &mut self,
+        profile: &Arc<dyn Profile>,
+        agency_client: &AgencyClient,
+        connection: &MediatedConnection,
+    ) -> VcxResult<VerifierState> {
+        trace!("VerifierV2::update_state >>>");
+        if !self.progressable_by_message() {
+            return Ok(self.get_state());
+        }
+        let send_message = connection.send_message_closure(profile).await?;
+
+        let messages = connection.get_messages(agency_client).await?;
+        if let Some((uid, msg)) = self.find_message_to_handle(messages) {
+            self.step(profile, msg.into(), Some(send_message)).await?;
+            connection.update_message_status(&uid, agency_client).await?;
+        }
+        Ok(self.get_state())
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use chrono::{DateTime, Utc};
+use messages::msg_fields::protocols::question_answer::answer::{Answer, AnswerContent};
+use messages::msg_fields::protocols::question_answer::question::{Question, QuestionContent};
+
+use crate::common::signing::{sign_question_answer, verify_signed_question_answer};
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
+use crate::protocols::question_answer::{build_answer, build_question, get_question_thread_id};
+use crate::protocols::SendClosure;
+
+use super::util::matches_thread_id;
+
+fn validate_not_expired(question: &Question) -> VcxResult<()> {
+    if question.decorators.is_expired_at(Utc::now()) {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            format!("Question {} has expired", question.id),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_response(question: &QuestionContent, response: &str) -> VcxResult<()> {
+    if !question.valid_responses.iter().any(|r| r.text == response) {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidOption,
+            format!("{} is not a valid response to the question", response),
+        ));
+    }
+    Ok(())
+}
+
+/// The party asking a question and receiving the answer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Questioner {
+    question: Question,
+    answer: Option<Answer>,
+}
+
+impl Questioner {
+    /// Builds a question which must be answered before `expires_time`, if provided.
+    pub fn build(content: QuestionContent, expires_time: Option<DateTime<Utc>>) -> Self {
+        Self {
+            question: build_question(content, expires_time),
+            answer: None,
+        }
+    }
+
+    pub fn get_question(&self) -> &Question {
+        &self.question
+    }
+
+    /// The answer received for the question, once its signature has been verified.
+    pub fn get_answer(&self) -> Option<&Answer> {
+        self.answer.as_ref()
+    }
+
+    pub fn get_thread_id(&self) -> &str {
+        get_question_thread_id(&self.question)
+    }
+
+    pub async fn send_question(&self, send_message: SendClosure) -> VcxResult<()> {
+        send_message(self.question.clone().into()).await
+    }
+
+    /// Accepts `answer` if it is a valid response received before the question expired.
+    /// A signed answer must be signed by `their_vk`, the pairwise verkey of the responder on
+    /// the connection, and an unsigned one is rejected if the question requires a signature.
+    pub async fn handle_answer(
+        &mut self,
+        wallet: &Arc<dyn BaseWallet>,
+        their_vk: &str,
+        answer: Answer,
+    ) -> VcxResult<()> {
+        if !matches_thread_id!(answer, self.get_thread_id()) {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Thread ID mismatch",
+            ));
+        }
+        validate_not_expired(&self.question)?;
+        validate_response(&self.question.content, &answer.content.response)?;
+
+        if answer.content.response_sig.is_some() || self.question.content.signature_required {
+            verify_signed_question_answer(wallet, &self.question.content, &answer.content, their_vk).await?;
+        }
+
+        self.answer = Some(answer);
+        Ok(())
+    }
+}
+
+/// The party answering a received question.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QuestionResponder {
+    question: Question,
+    answer: Option<Answer>,
+}
+
+impl QuestionResponder {
+    pub fn from_question(question: Question) -> Self {
+        Self { question, answer: None }
+    }
+
+    pub fn get_question(&self) -> &Question {
+        &self.question
+    }
+
+    /// The answer sent to the question, if any.
+    pub fn get_answer(&self) -> Option<&Answer> {
+        self.answer.as_ref()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.question.decorators.is_expired_at(Utc::now())
+    }
+
+    /// Answers the question with `response`, which must be one of its valid responses.
+    /// The answer is signed with `my_vk`, the pairwise verkey of the responder on the connection.
+    pub async fn send_answer(
+        &mut self,
+        wallet: &Arc<dyn BaseWallet>,
+        my_vk: &str,
+        response: &str,
+        send_message: SendClosure,
+    ) -> VcxResult<()> {
+        validate_not_expired(&self.question)?;
+        validate_response(&self.question.content, response)?;
+
+        let mut content = AnswerContent::new(response.to_owned());
+        content.response_sig = Some(sign_question_answer(wallet, my_vk, &self.question.content, response).await?);
+
+        let answer = build_answer(get_question_thread_id(&self.question), content);
+        send_message(answer.clone().into()).await?;
+        self.answer = Some(answer);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use chrono::Duration;
+    use messages::msg_fields::protocols::question_answer::question::QuestionResponse;
+    use messages::AriesMessage;
+
+    use super::*;
+
+    pub fn _send_message() -> SendClosure {
+        Box::new(|_: AriesMessage| Box::pin(async { VcxResult::Ok(()) }))
+    }
+
+    fn _question_content(signature_required: bool) -> QuestionContent {
+        let mut content = QuestionContent::new(
+            "Are you on the phone with Faber Bank?".to_owned(),
+            "test_nonce".to_owned(),
+            vec![
+                QuestionResponse::new("Yes".to_owned()),
+                QuestionResponse::new("No".to_owned()),
+            ],
+        );
+        content.signature_required = signature_required;
+        content
+    }
+
+    async fn _wallet_with_key() -> (Arc<dyn BaseWallet>, String) {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        (wallet, verkey)
+    }
+
+    #[tokio::test]
+    async fn test_signed_question_answer() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let mut questioner = Questioner::build(_question_content(true), Some(Utc::now() + Duration::hours(1)));
+        questioner.send_question(_send_message()).await.unwrap();
+
+        let mut responder = QuestionResponder::from_question(questioner.get_question().clone());
+        responder
+            .send_answer(&wallet, &verkey, "Yes", _send_message())
+            .await
+            .unwrap();
+
+        let answer = responder.get_answer().unwrap().clone();
+        questioner.handle_answer(&wallet, &verkey, answer).await.unwrap();
+        assert_eq!("Yes", questioner.get_answer().unwrap().content.response);
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_to_answer_with_invalid_response() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let questioner = Questioner::build(_question_content(false), None);
+
+        let mut responder = QuestionResponder::from_question(questioner.get_question().clone());
+        responder
+            .send_answer(&wallet, &verkey, "Maybe", _send_message())
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_to_answer_expired_question() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let questioner = Questioner::build(_question_content(false), Some(Utc::now() - Duration::minutes(1)));
+
+        let mut responder = QuestionResponder::from_question(questioner.get_question().clone());
+        assert!(responder.is_expired());
+        responder
+            .send_answer(&wallet, &verkey, "Yes", _send_message())
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_on_answer_signed_by_other_key() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let (_, other_verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let mut questioner = Questioner::build(_question_content(true), None);
+
+        let mut responder = QuestionResponder::from_question(questioner.get_question().clone());
+        responder
+            .send_answer(&wallet, &other_verkey, "Yes", _send_message())
+            .await
+            .unwrap();
+
+        let answer = responder.get_answer().unwrap().clone();
+        questioner.handle_answer(&wallet, &verkey, answer).await.unwrap_err();
+        assert!(questioner.get_answer().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_on_unsigned_answer_to_question_requiring_signature() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let mut questioner = Questioner::build(_question_content(true), None);
+
+        let answer = build_answer(questioner.get_thread_id(), AnswerContent::new("Yes".to_owned()));
+        questioner.handle_answer(&wallet, &verkey, answer).await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_should_fail_on_thread_id_mismatch() {
+        let (wallet, verkey) = _wallet_with_key().await;
+        let mut questioner = Questioner::build(_question_content(false), None);
+
+        let answer = build_answer("other_thread", AnswerContent::new("Yes".to_owned()));
+        questioner.handle_answer(&wallet, &verkey, answer).await.unwrap_err();
+    }
+}
@@ -1,14 +1,21 @@
 use messages::{
     msg_fields::protocols::{
+        action_menu::ActionMenu,
         connection::{invitation::Invitation, Connection},
+        coordinate_mediation::CoordinateMediation,
         cred_issuance::CredentialIssuance,
+        cred_issuance_v2::CredentialIssuanceV2,
+        did_exchange::DidExchange,
         discover_features::DiscoverFeatures,
         notification::Notification,
         out_of_band::{invitation::Invitation as OobInvitation, OutOfBand},
+        pickup::Pickup,
         present_proof::{
             propose::{Predicate, PresentationAttr},
             PresentProof,
         },
+        present_proof_v2::PresentProofV2,
+        question_answer::QuestionAnswer,
         report_problem::ProblemReport,
         revocation::Revocation,
         trust_ping::TrustPing,
@@ -78,6 +85,10 @@ pub(crate) use matches_thread_id;
 
 pub fn verify_thread_id(thread_id: &str, message: &AriesMessage) -> VcxResult<()> {
     let is_match = match message {
+        AriesMessage::ActionMenu(ActionMenu::Menu(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::ActionMenu(ActionMenu::MenuRequest(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::ActionMenu(ActionMenu::Perform(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::ActionMenu(ActionMenu::ProblemReport(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::BasicMessage(msg) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::Connection(Connection::Invitation(Invitation::Public(msg))) => msg.id == thread_id,
         AriesMessage::Connection(Connection::Invitation(Invitation::Pairwise(msg))) => msg.id == thread_id,
@@ -85,6 +96,21 @@ pub fn verify_thread_id(thread_id: &str, message: &AriesMessage) -> VcxResult<()
         AriesMessage::Connection(Connection::ProblemReport(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::Connection(Connection::Request(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::Connection(Connection::Response(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::CoordinateMediation(CoordinateMediation::MediateRequest(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CoordinateMediation(CoordinateMediation::MediateDeny(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::CoordinateMediation(CoordinateMediation::MediateGrant(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::CoordinateMediation(CoordinateMediation::KeylistUpdate(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CoordinateMediation(CoordinateMediation::KeylistUpdateResponse(msg)) => {
+            matches_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CoordinateMediation(CoordinateMediation::KeylistQuery(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CoordinateMediation(CoordinateMediation::Keylist(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::CredentialIssuance(CredentialIssuance::Ack(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::CredentialIssuance(CredentialIssuance::IssueCredential(msg)) => {
             matches_thread_id!(msg, thread_id)
@@ -101,6 +127,26 @@ pub fn verify_thread_id(thread_id: &str, message: &AriesMessage) -> VcxResult<()
         AriesMessage::CredentialIssuance(CredentialIssuance::ProblemReport(msg)) => {
             matches_opt_thread_id!(msg, thread_id)
         }
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::Ack(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::IssueCredential(msg)) => {
+            matches_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::OfferCredential(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProposeCredential(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::RequestCredential(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProblemReport(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::DidExchange(DidExchange::Request(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::DidExchange(DidExchange::Response(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::DidExchange(DidExchange::Complete(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::DidExchange(DidExchange::ProblemReport(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::DiscoverFeatures(DiscoverFeatures::Query(msg)) => msg.id == thread_id,
         AriesMessage::DiscoverFeatures(DiscoverFeatures::Disclose(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::Notification(Notification::Ack(msg)) => matches_thread_id!(msg, thread_id),
@@ -108,11 +154,28 @@ pub fn verify_thread_id(thread_id: &str, message: &AriesMessage) -> VcxResult<()
         AriesMessage::OutOfBand(OutOfBand::Invitation(msg)) => msg.id == thread_id,
         AriesMessage::OutOfBand(OutOfBand::HandshakeReuse(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::OutOfBand(OutOfBand::HandshakeReuseAccepted(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::StatusRequest(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::Status(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::DeliveryRequest(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::Delivery(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::MessagesReceived(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::Pickup(Pickup::LiveDeliveryChange(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::PresentProof(PresentProof::Ack(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::PresentProof(PresentProof::Presentation(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::PresentProof(PresentProof::ProposePresentation(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::PresentProof(PresentProof::RequestPresentation(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::PresentProof(PresentProof::ProblemReport(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::PresentProofV2(PresentProofV2::Ack(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::PresentProofV2(PresentProofV2::Presentation(msg)) => matches_thread_id!(msg, thread_id),
+        AriesMessage::PresentProofV2(PresentProofV2::ProposePresentation(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::PresentProofV2(PresentProofV2::RequestPresentation(msg)) => {
+            matches_opt_thread_id!(msg, thread_id)
+        }
+        AriesMessage::PresentProofV2(PresentProofV2::ProblemReport(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::QuestionAnswer(QuestionAnswer::Question(msg)) => matches_opt_thread_id!(msg, thread_id),
+        AriesMessage::QuestionAnswer(QuestionAnswer::Answer(msg)) => matches_thread_id!(msg, thread_id),
         AriesMessage::ReportProblem(msg) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::Revocation(Revocation::Revoke(msg)) => matches_opt_thread_id!(msg, thread_id),
         AriesMessage::Revocation(Revocation::Ack(msg)) => matches_thread_id!(msg, thread_id),
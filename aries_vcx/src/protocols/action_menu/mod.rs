@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use ::uuid::Uuid;
+use chrono::Utc;
+use messages::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::{
+        action_menu::{
+            menu::{Menu, MenuContent, MenuDecorators},
+            menu_request::{MenuRequest, MenuRequestContent, MenuRequestDecorators},
+            perform::{Perform, PerformContent, PerformDecorators},
+            problem_report::{ActionMenuProblemReport, ActionMenuProblemReportContent},
+        },
+        report_problem::ProblemReportDecorators,
+    },
+};
+
+fn out_timing() -> Timing {
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    timing
+}
+
+pub fn build_menu_request() -> MenuRequest {
+    let mut decorators = MenuRequestDecorators::default();
+    decorators.timing = Some(out_timing());
+
+    MenuRequest::with_decorators(Uuid::new_v4().to_string(), MenuRequestContent::default(), decorators)
+}
+
+pub fn build_menu(content: MenuContent) -> Menu {
+    let mut decorators = MenuDecorators::default();
+    decorators.timing = Some(out_timing());
+
+    Menu::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+pub fn build_perform(thread_id: &str, name: String, params: HashMap<String, String>) -> Perform {
+    let mut content = PerformContent::new(name);
+    content.params = params;
+
+    let mut decorators = PerformDecorators::new(Thread::new(thread_id.to_owned()));
+    decorators.timing = Some(out_timing());
+
+    Perform::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+pub fn build_problem_report(thread_id: &str, code: String) -> ActionMenuProblemReport {
+    let mut decorators = ProblemReportDecorators::default();
+    decorators.thread = Some(Thread::new(thread_id.to_owned()));
+    decorators.timing = Some(out_timing());
+
+    ActionMenuProblemReport::with_decorators(
+        Uuid::new_v4().to_string(),
+        ActionMenuProblemReportContent::new(code),
+        decorators,
+    )
+}
+
+/// Returns the thread of a menu, which is the thread `perform` messages and problem reports
+/// about the menu are sent on.
+pub fn get_menu_thread_id(menu: &Menu) -> &str {
+    menu.decorators
+        .thread
+        .as_ref()
+        .map(|t| t.thid.as_str())
+        .unwrap_or(menu.id.as_str())
+}
@@ -1,9 +1,16 @@
 use messages::{
-    decorators::thread::Thread,
-    msg_fields::protocols::report_problem::{ProblemReport, ProblemReportContent, ProblemReportDecorators},
+    decorators::{attachment::Attachment, thread::Thread},
+    msg_fields::protocols::{
+        common::attachment_format_id::AttachmentFormatSpecifier,
+        report_problem::{ProblemReport, ProblemReportContent, ProblemReportDecorators},
+    },
 };
+use shared_vcx::maybe_known::MaybeKnown;
 use uuid::Uuid;
 
+use crate::errors::error::prelude::*;
+use crate::handlers::util::get_attach_as_string;
+
 pub fn build_problem_report_msg(comment: Option<String>, thread_id: &str) -> ProblemReport {
     let id = Uuid::new_v4().to_string();
     let content = ProblemReportContent::new(comment.unwrap_or_default());
@@ -14,6 +21,42 @@ pub fn build_problem_report_msg(comment: Option<String>, thread_id: &str) -> Pro
     ProblemReport::with_decorators(id, content, decorators)
 }
 
+/// Returns the content of the attachment which the `formats` of a message bind to `format`.
+///
+/// Only the `hlindy` formats are currently processed by the 2.0 state machines, messages using
+/// other formats (such as `ld_proof`) are answered with a problem report.
+pub(crate) fn get_attach_for_format<F>(
+    formats: &[AttachmentFormatSpecifier<F>],
+    attachments: &[Attachment],
+    format: F,
+) -> VcxResult<String>
+where
+    F: PartialEq + std::fmt::Debug,
+{
+    let attach_id = formats
+        .iter()
+        .find(|specifier| matches!(&specifier.format, MaybeKnown::Known(known) if *known == format))
+        .map(|specifier| specifier.attach_id.as_str())
+        .ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidMessageFormat,
+                format!("Message has no attachment of the supported format {:?}", format),
+            )
+        })?;
+
+    let attachment = attachments
+        .iter()
+        .find(|attachment| attachment.id.as_deref() == Some(attach_id))
+        .ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidMessageFormat,
+                format!("Attachment {} listed in the message formats was not found", attach_id),
+            )
+        })?;
+
+    Ok(get_attach_as_string!(std::slice::from_ref(attachment)))
+}
+
 // #[cfg(test)]
 // mod test {
 //     use crate::protocols::common::build_problem_report_msg;
@@ -526,3 +526,108 @@ mod connection_serde_tests {
     generate_test!(inviter_connection_responded, make_inviter_responded);
     generate_test!(inviter_connection_complete, make_inviter_completed);
 }
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+mod connection_in_memory_wallet_tests {
+    #![allow(clippy::unwrap_used)]
+
+    use aries_vcx_core::ledger::request_submitter::in_memory_ledger::InMemoryLedger;
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use async_trait::async_trait;
+    use messages::decorators::thread::Thread;
+    use messages::msg_fields::protocols::connection::invitation::{
+        Invitation, PairwiseInvitation, PairwiseInvitationContent, PwInvitationDecorators,
+    };
+    use messages::msg_fields::protocols::connection::response::{Response, ResponseContent, ResponseDecorators};
+    use messages::msg_fields::protocols::connection::ConnectionData;
+    use url::Url;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::common::signing::sign_connection_response;
+    use crate::core::profile::modular_libs_profile::ModularLibsProfile;
+    use crate::core::profile::profile::Profile;
+    use crate::protocols::connection::{invitee::InviteeConnection, Connection};
+
+    const SERVICE_ENDPOINT: &str = "https://localhost:8080";
+
+    struct MockTransport;
+
+    #[async_trait]
+    impl Transport for MockTransport {
+        async fn send_message(&self, _msg: Vec<u8>, _service_endpoint: Url) -> VcxResult<()> {
+            Ok(())
+        }
+    }
+
+    fn make_in_memory_profile() -> Arc<dyn Profile> {
+        Arc::new(ModularLibsProfile::new_with_request_submitter(
+            Arc::new(InMemoryWallet::new()),
+            Arc::new(InMemoryLedger::new()),
+        ))
+    }
+
+    async fn make_invitee_requested(
+        profile: &Arc<dyn Profile>,
+        inviter_vk: &str,
+    ) -> InviteeConnection<InviteeRequested> {
+        let wallet = profile.inject_wallet();
+        let pairwise_info = PairwiseInfo::create(&wallet).await.unwrap();
+        let content = PairwiseInvitationContent::new(
+            String::new(),
+            vec![inviter_vk.to_owned()],
+            Vec::new(),
+            SERVICE_ENDPOINT.parse().unwrap(),
+        );
+        let pw_invite =
+            PairwiseInvitation::with_decorators(Uuid::new_v4().to_string(), content, PwInvitationDecorators::default());
+        let invitation = AnyInvitation::Con(Invitation::Pairwise(pw_invite));
+
+        Connection::new_invitee("connection_in_memory_wallet_tests".to_owned(), pairwise_info)
+            .accept_invitation(profile, invitation)
+            .await
+            .unwrap()
+            .send_request(&wallet, SERVICE_ENDPOINT.parse().unwrap(), vec![], &MockTransport)
+            .await
+            .unwrap()
+    }
+
+    async fn make_response(wallet: &Arc<dyn BaseWallet>, signer_vk: &str, did_vk: &str, thread_id: &str) -> Response {
+        let mut con_data = ConnectionData::new(did_vk.to_owned(), AriesDidDoc::default());
+        con_data.did_doc.id = did_vk.to_owned();
+        con_data.did_doc.set_recipient_keys(vec![did_vk.to_owned()]);
+        con_data.did_doc.set_routing_keys(Vec::new());
+
+        let sig_data = sign_connection_response(wallet, signer_vk, &con_data).await.unwrap();
+        let decorators = ResponseDecorators::new(Thread::new(thread_id.to_owned()));
+        Response::with_decorators(Uuid::new_v4().to_string(), ResponseContent::new(sig_data), decorators)
+    }
+
+    #[tokio::test]
+    async fn test_invitee_accepts_response_signed_by_inviter() {
+        let profile = make_in_memory_profile();
+        let wallet = profile.inject_wallet();
+        let inviter_vk = PairwiseInfo::create(&wallet).await.unwrap().pw_vk;
+
+        let con = make_invitee_requested(&profile, &inviter_vk).await;
+        let response = make_response(&wallet, &inviter_vk, &inviter_vk, con.thread_id()).await;
+        let con = con.handle_response(&wallet, response, &MockTransport).await.unwrap();
+
+        assert_eq!(con.their_did_doc().id, inviter_vk);
+        assert_eq!(con.remote_vk().unwrap(), inviter_vk);
+    }
+
+    #[tokio::test]
+    async fn test_invitee_rejects_response_signed_by_other_key() {
+        let profile = make_in_memory_profile();
+        let wallet = profile.inject_wallet();
+        let inviter_vk = PairwiseInfo::create(&wallet).await.unwrap().pw_vk;
+        let other_vk = PairwiseInfo::create(&wallet).await.unwrap().pw_vk;
+
+        let con = make_invitee_requested(&profile, &inviter_vk).await;
+        let response = make_response(&wallet, &other_vk, &other_vk, con.thread_id()).await;
+
+        assert!(con.handle_response(&wallet, response, &MockTransport).await.is_err());
+    }
+}
@@ -24,7 +24,10 @@ use crate::{
     common::ledger::transactions::into_did_doc,
     core::profile::profile::Profile,
     errors::error::VcxResult,
-    handlers::util::{matches_thread_id, AnyInvitation},
+    handlers::{
+        coordinate_mediation::recipient::MediationRecipient,
+        util::{matches_thread_id, AnyInvitation},
+    },
     protocols::connection::trait_bounds::ThreadId,
     transport::Transport,
 };
@@ -152,6 +155,27 @@ impl InviteeConnection<Invited> {
             initiation_type: Invitee,
         })
     }
+
+    /// Sends a [`Request`] whose DID doc routes through the mediator `mediation_recipient` was
+    /// granted mediation by, and transitions to [`InviteeConnection<Requested>`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if our pairwise verkey is not registered with the mediator,
+    /// or if sending the request fails.
+    pub async fn send_mediated_request<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        mediation_recipient: &MediationRecipient,
+        transport: &T,
+    ) -> VcxResult<InviteeConnection<Requested>>
+    where
+        T: Transport,
+    {
+        let (service_endpoint, routing_keys) = mediation_recipient.routing_for(&self.pairwise_info.pw_vk)?;
+        self.send_request(wallet, service_endpoint, routing_keys, transport)
+            .await
+    }
 }
 
 impl InviteeConnection<Requested> {
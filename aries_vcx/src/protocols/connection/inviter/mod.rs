@@ -16,6 +16,7 @@ use messages::msg_fields::protocols::connection::ConnectionData;
 use messages::AriesMessage;
 use url::Url;
 
+use crate::handlers::coordinate_mediation::recipient::MediationRecipient;
 use crate::handlers::util::{verify_thread_id, AnyInvitation};
 use crate::protocols::connection::trait_bounds::ThreadId;
 use crate::transport::Transport;
@@ -67,6 +68,20 @@ impl InviterConnection<Initial> {
         }
     }
 
+    /// Generates a pairwise [`Invitation`] routed through the mediator `mediation_recipient` was
+    /// granted mediation by, and transitions to [`InviterConnection<Invited>`].
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if our pairwise verkey is not registered with the mediator.
+    pub fn create_mediated_invitation(
+        self,
+        mediation_recipient: &MediationRecipient,
+    ) -> VcxResult<InviterConnection<Invited>> {
+        let (service_endpoint, routing_keys) = mediation_recipient.routing_for(&self.pairwise_info.pw_vk)?;
+        Ok(self.create_invitation(routing_keys, service_endpoint))
+    }
+
     /// This is implemented for retro-fitting the previous implementation
     /// where a [`Request`] could get processed directly from the initial state.
     ///
@@ -219,6 +234,32 @@ impl InviterConnection<Invited> {
         })
     }
 
+    /// Processes a [`Request`] like [`InviterConnection<Invited>::handle_request`], with a response
+    /// DidDoc routed through the mediator `mediation_recipient` was granted mediation by.
+    ///
+    /// The verkey of the newly generated [`PairwiseInfo`], available through
+    /// `pairwise_info()` on the returned connection, must be registered with the mediator
+    /// before the response is sent.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if mediation was not granted, or if `handle_request` fails.
+    pub async fn handle_mediated_request<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        request: Request,
+        mediation_recipient: &MediationRecipient,
+        transport: &T,
+    ) -> VcxResult<InviterConnection<Requested>>
+    where
+        T: Transport,
+    {
+        let service_endpoint = mediation_recipient.routing_endpoint()?;
+        let routing_keys = mediation_recipient.routing_keys()?;
+        self.handle_request(wallet, request, service_endpoint, routing_keys, transport)
+            .await
+    }
+
     /// Returns the [`Invitation`] generated by this inviter.
     ///
     /// NOTE: Calling [`InviterConnection<Initial>::into_invited()`]
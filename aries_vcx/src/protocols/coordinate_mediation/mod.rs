@@ -0,0 +1,41 @@
+pub mod recipient;
+
+#[allow(clippy::unwrap_used)]
+pub mod test_utils {
+    use std::sync::{Arc, Mutex};
+
+    use messages::AriesMessage;
+    use url::Url;
+
+    use crate::errors::error::VcxResult;
+    use crate::protocols::SendClosure;
+
+    pub fn _send_message() -> SendClosure {
+        Box::new(|_: AriesMessage| Box::pin(async { VcxResult::Ok(()) }))
+    }
+
+    pub fn _routing_endpoint() -> Url {
+        "https://mediator.example.org/didcomm".parse().unwrap()
+    }
+
+    pub fn _routing_keys() -> Vec<String> {
+        vec!["3LYuxJBJkngDbvJj4zjx13DBUdZ2P96eNybwd2n9L9AU".to_owned()]
+    }
+
+    pub fn _recipient_key() -> String {
+        "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL".to_owned()
+    }
+
+    // Keeps the last message sent, so that its id can be used as the thread id of the reply
+    pub fn _capture_message(sent: Arc<Mutex<Option<AriesMessage>>>) -> SendClosure {
+        Box::new(move |msg: AriesMessage| {
+            *sent.lock().unwrap() = Some(msg);
+            Box::pin(async { VcxResult::Ok(()) })
+        })
+    }
+
+    pub fn _sent_id(sent: &Arc<Mutex<Option<AriesMessage>>>) -> String {
+        let msg = sent.lock().unwrap().take().unwrap();
+        serde_json::to_value(msg).unwrap()["@id"].as_str().unwrap().to_owned()
+    }
+}
@@ -0,0 +1,428 @@
+use messages::msg_fields::protocols::coordinate_mediation::keylist::Keylist;
+use messages::msg_fields::protocols::coordinate_mediation::keylist_query::{
+    KeylistQuery, KeylistQueryContent, KeylistQueryDecorators,
+};
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update::{
+    KeylistUpdate, KeylistUpdateContent, KeylistUpdateDecorators, KeylistUpdateItem,
+};
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update_response::KeylistUpdateResponse;
+use messages::msg_fields::protocols::coordinate_mediation::mediate_deny::MediateDeny;
+use messages::msg_fields::protocols::coordinate_mediation::mediate_grant::MediateGrant;
+use messages::msg_fields::protocols::coordinate_mediation::mediate_request::{
+    MediateRequest, MediateRequestContent, MediateRequestDecorators,
+};
+use url::Url;
+use uuid::Uuid;
+
+use crate::errors::error::prelude::*;
+use crate::handlers::util::verify_thread_id;
+use crate::protocols::coordinate_mediation::recipient::states::denied::DeniedState;
+use crate::protocols::coordinate_mediation::recipient::states::granted::GrantedState;
+use crate::protocols::coordinate_mediation::recipient::states::initial::InitialState;
+use crate::protocols::coordinate_mediation::recipient::states::requested::RequestedState;
+use crate::protocols::SendClosure;
+
+/// Client side of the [coordinate mediation](<https://github.com/hyperledger/aries-rfcs/blob/main/features/0211-route-coordination/README.md>)
+/// protocol, used by an agent to obtain routing from a mediator and to keep
+/// the mediator informed about the recipient keys it should route messages for.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MediationRecipientSM {
+    state: RecipientFullState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RecipientFullState {
+    Initial(InitialState),
+    Requested(RequestedState),
+    Granted(GrantedState),
+    Denied(DeniedState),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MediationRecipientState {
+    Initial,
+    Requested,
+    Granted,
+    Denied,
+}
+
+impl MediationRecipientSM {
+    pub fn create() -> Self {
+        Self {
+            state: RecipientFullState::Initial(InitialState::new()),
+        }
+    }
+
+    pub fn get_state(&self) -> MediationRecipientState {
+        match self.state {
+            RecipientFullState::Initial(_) => MediationRecipientState::Initial,
+            RecipientFullState::Requested(_) => MediationRecipientState::Requested,
+            RecipientFullState::Granted(_) => MediationRecipientState::Granted,
+            RecipientFullState::Denied(_) => MediationRecipientState::Denied,
+        }
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        match &self.state {
+            RecipientFullState::Requested(state) => Ok(state.get_thread_id()),
+            RecipientFullState::Granted(state) => Ok(state.get_thread_id()),
+            RecipientFullState::Denied(state) => Ok(state.get_thread_id()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Thread ID not yet known in this state",
+            )),
+        }
+    }
+
+    pub fn routing_endpoint(&self) -> VcxResult<Url> {
+        match &self.state {
+            RecipientFullState::Granted(state) => Ok(state.routing_endpoint()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Routing endpoint is only known once mediation is granted",
+            )),
+        }
+    }
+
+    pub fn routing_keys(&self) -> VcxResult<Vec<String>> {
+        match &self.state {
+            RecipientFullState::Granted(state) => Ok(state.routing_keys()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Routing keys are only known once mediation is granted",
+            )),
+        }
+    }
+
+    pub fn recipient_keys(&self) -> VcxResult<Vec<String>> {
+        match &self.state {
+            RecipientFullState::Granted(state) => Ok(state.recipient_keys()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Recipient keys are only known once mediation is granted",
+            )),
+        }
+    }
+
+    pub async fn send_request(self, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Initial(_) | RecipientFullState::Denied(_) => {
+                let id = Uuid::new_v4().to_string();
+                let request = MediateRequest::with_decorators(
+                    id,
+                    MediateRequestContent::default(),
+                    MediateRequestDecorators::default(),
+                );
+                send_message(request.clone().into()).await?;
+                RecipientFullState::Requested(RequestedState::new(request))
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Mediation was already requested",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    pub fn handle_grant(self, grant: MediateGrant) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Requested(state) => {
+                verify_thread_id(&state.get_thread_id(), &grant.clone().into())?;
+                RecipientFullState::Granted(GrantedState::new(grant))
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Mediation grant not expected in this state",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    pub fn handle_deny(self, deny: MediateDeny) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Requested(state) => {
+                verify_thread_id(&state.get_thread_id(), &deny.clone().into())?;
+                RecipientFullState::Denied(DeniedState::new(deny))
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Mediation deny not expected in this state",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    pub async fn send_keylist_update(
+        self,
+        updates: Vec<KeylistUpdateItem>,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Granted(mut state) => {
+                let id = Uuid::new_v4().to_string();
+                let update = KeylistUpdate::with_decorators(
+                    id,
+                    KeylistUpdateContent::new(updates),
+                    KeylistUpdateDecorators::default(),
+                );
+                send_message(update.clone().into()).await?;
+                state.add_pending_update(update);
+                RecipientFullState::Granted(state)
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Keylist can only be updated once mediation is granted",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    pub fn handle_keylist_update_response(self, response: KeylistUpdateResponse) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Granted(mut state) => {
+                if state.take_pending_update(&response.decorators.thread.thid).is_none() {
+                    return Err(AriesVcxError::from_msg(
+                        AriesVcxErrorKind::InvalidJson,
+                        format!(
+                            "Cannot handle message {:?}: thread id does not match any pending keylist update",
+                            response
+                        ),
+                    ));
+                }
+                state.apply_update_results(&response.content.updated);
+                RecipientFullState::Granted(state)
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Keylist update response not expected in this state",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    pub async fn send_keylist_query(self, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Granted(mut state) => {
+                let id = Uuid::new_v4().to_string();
+                let query = KeylistQuery::with_decorators(
+                    id,
+                    KeylistQueryContent::default(),
+                    KeylistQueryDecorators::default(),
+                );
+                send_message(query.clone().into()).await?;
+                state.set_pending_query(query);
+                RecipientFullState::Granted(state)
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Keylist can only be queried once mediation is granted",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+
+    /// Replaces the locally tracked recipient keys with the ones reported by the mediator.
+    pub fn handle_keylist(self, keylist: Keylist) -> VcxResult<Self> {
+        let state = match self.state {
+            RecipientFullState::Granted(mut state) => {
+                let query = state.take_pending_query().ok_or_else(|| {
+                    AriesVcxError::from_msg(AriesVcxErrorKind::InvalidState, "No keylist query was sent")
+                })?;
+                verify_thread_id(&query.id, &keylist.clone().into())?;
+                state.set_recipient_keys(keylist.content.keys.into_iter().map(|k| k.recipient_key).collect());
+                RecipientFullState::Granted(state)
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Keylist not expected in this state",
+                ));
+            }
+        };
+        Ok(Self { state })
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+pub mod test_utils {
+    use messages::decorators::thread::Thread;
+    use messages::msg_fields::protocols::coordinate_mediation::keylist::{
+        KeylistContent, KeylistDecorators, KeylistItem,
+    };
+    use messages::msg_fields::protocols::coordinate_mediation::keylist_update::KeylistUpdateItemAction;
+    use messages::msg_fields::protocols::coordinate_mediation::keylist_update_response::{
+        KeylistUpdateItemResult, KeylistUpdateResponseContent, KeylistUpdateResponseDecorators,
+        KeylistUpdateResponseItem,
+    };
+    use messages::msg_fields::protocols::coordinate_mediation::mediate_deny::{
+        MediateDenyContent, MediateDenyDecorators,
+    };
+    use messages::msg_fields::protocols::coordinate_mediation::mediate_grant::{
+        MediateGrantContent, MediateGrantDecorators,
+    };
+
+    use crate::protocols::coordinate_mediation::test_utils::{_recipient_key, _routing_endpoint, _routing_keys};
+
+    use super::*;
+
+    pub fn _recipient() -> MediationRecipientSM {
+        MediationRecipientSM::create()
+    }
+
+    pub fn _mediate_grant(thread_id: &str) -> MediateGrant {
+        let content = MediateGrantContent::new(_routing_endpoint(), _routing_keys());
+        let decorators = MediateGrantDecorators::new(Thread::new(thread_id.to_owned()));
+        MediateGrant::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+    }
+
+    pub fn _mediate_deny(thread_id: &str) -> MediateDeny {
+        let decorators = MediateDenyDecorators::new(Thread::new(thread_id.to_owned()));
+        MediateDeny::with_decorators(Uuid::new_v4().to_string(), MediateDenyContent::default(), decorators)
+    }
+
+    pub fn _keylist_update_response(thread_id: &str, result: KeylistUpdateItemResult) -> KeylistUpdateResponse {
+        let item = KeylistUpdateResponseItem::new(_recipient_key(), KeylistUpdateItemAction::Add, result);
+        let content = KeylistUpdateResponseContent::new(vec![item]);
+        let decorators = KeylistUpdateResponseDecorators::new(Thread::new(thread_id.to_owned()));
+        KeylistUpdateResponse::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+    }
+
+    pub fn _keylist(thread_id: &str, recipient_keys: Vec<String>) -> Keylist {
+        let content = KeylistContent::new(recipient_keys.into_iter().map(KeylistItem::new).collect());
+        let decorators = KeylistDecorators::new(Thread::new(thread_id.to_owned()));
+        Keylist::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub mod unit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use messages::msg_fields::protocols::coordinate_mediation::keylist_update::KeylistUpdateItemAction;
+    use messages::msg_fields::protocols::coordinate_mediation::keylist_update_response::KeylistUpdateItemResult;
+
+    use crate::protocols::coordinate_mediation::recipient::state_machine::test_utils::*;
+    use crate::protocols::coordinate_mediation::test_utils::{
+        _capture_message, _recipient_key, _routing_endpoint, _routing_keys, _send_message, _sent_id,
+    };
+
+    use super::*;
+
+    async fn _to_granted_state() -> MediationRecipientSM {
+        let sm = _recipient().send_request(_send_message()).await.unwrap();
+        let thread_id = sm.get_thread_id().unwrap();
+        sm.handle_grant(_mediate_grant(&thread_id)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mediation_granted() {
+        let sm = _to_granted_state().await;
+        assert_eq!(MediationRecipientState::Granted, sm.get_state());
+        assert_eq!(_routing_endpoint(), sm.routing_endpoint().unwrap());
+        assert_eq!(_routing_keys(), sm.routing_keys().unwrap());
+        assert!(sm.recipient_keys().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mediation_denied_can_be_requested_again() {
+        let sm = _recipient().send_request(_send_message()).await.unwrap();
+        let thread_id = sm.get_thread_id().unwrap();
+        let sm = sm.handle_deny(_mediate_deny(&thread_id)).unwrap();
+        assert_eq!(MediationRecipientState::Denied, sm.get_state());
+        assert!(sm.routing_keys().is_err());
+
+        let sm = sm.send_request(_send_message()).await.unwrap();
+        assert_eq!(MediationRecipientState::Requested, sm.get_state());
+    }
+
+    #[tokio::test]
+    async fn test_grant_fails_incorrect_thread_id() {
+        let sm = _recipient().send_request(_send_message()).await.unwrap();
+        assert!(sm.handle_grant(_mediate_grant("wrong_thread_id")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_grant_fails_before_request() {
+        assert!(_recipient().handle_grant(_mediate_grant("thread_id")).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_keylist_update_tracks_recipient_keys() {
+        let sent = Arc::new(Mutex::new(None));
+        let update = KeylistUpdateItem::new(_recipient_key(), KeylistUpdateItemAction::Add);
+        let sm = _to_granted_state()
+            .await
+            .send_keylist_update(vec![update], _capture_message(sent.clone()))
+            .await
+            .unwrap();
+        let update_id = _sent_id(&sent);
+
+        assert!(sm
+            .clone()
+            .handle_keylist_update_response(_keylist_update_response(
+                "wrong_thread_id",
+                KeylistUpdateItemResult::Success
+            ))
+            .is_err());
+
+        let sm = sm
+            .handle_keylist_update_response(_keylist_update_response(&update_id, KeylistUpdateItemResult::Success))
+            .unwrap();
+        assert_eq!(vec![_recipient_key()], sm.recipient_keys().unwrap());
+
+        // the same update cannot be answered twice
+        assert!(sm
+            .handle_keylist_update_response(_keylist_update_response(&update_id, KeylistUpdateItemResult::Success))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_keylist_update_ignores_failed_results() {
+        let sent = Arc::new(Mutex::new(None));
+        let update = KeylistUpdateItem::new(_recipient_key(), KeylistUpdateItemAction::Add);
+        let sm = _to_granted_state()
+            .await
+            .send_keylist_update(vec![update], _capture_message(sent.clone()))
+            .await
+            .unwrap();
+        let update_id = _sent_id(&sent);
+
+        let sm = sm
+            .handle_keylist_update_response(_keylist_update_response(
+                &update_id,
+                KeylistUpdateItemResult::ServerError,
+            ))
+            .unwrap();
+        assert!(sm.recipient_keys().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_keylist_query_replaces_recipient_keys() {
+        let sent = Arc::new(Mutex::new(None));
+        let sm = _to_granted_state()
+            .await
+            .send_keylist_query(_capture_message(sent.clone()))
+            .await
+            .unwrap();
+        let query_id = _sent_id(&sent);
+
+        let sm = sm.handle_keylist(_keylist(&query_id, vec![_recipient_key()])).unwrap();
+        assert_eq!(vec![_recipient_key()], sm.recipient_keys().unwrap());
+
+        // no query is pending anymore
+        assert!(sm.handle_keylist(_keylist(&query_id, vec![])).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use messages::msg_fields::protocols::coordinate_mediation::mediate_deny::MediateDeny;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeniedState {
+    deny: MediateDeny,
+}
+
+impl DeniedState {
+    pub fn new(deny: MediateDeny) -> Self {
+        Self { deny }
+    }
+
+    pub fn get_thread_id(&self) -> String {
+        self.deny.decorators.thread.thid.clone()
+    }
+}
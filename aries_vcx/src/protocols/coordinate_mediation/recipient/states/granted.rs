@@ -0,0 +1,87 @@
+use messages::msg_fields::protocols::coordinate_mediation::{
+    keylist_query::KeylistQuery,
+    keylist_update::{KeylistUpdate, KeylistUpdateItemAction},
+    keylist_update_response::{KeylistUpdateItemResult, KeylistUpdateResponseItem},
+    mediate_grant::MediateGrant,
+};
+use url::Url;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GrantedState {
+    grant: MediateGrant,
+    recipient_keys: Vec<String>,
+    pending_updates: Vec<KeylistUpdate>,
+    pending_query: Option<KeylistQuery>,
+}
+
+impl GrantedState {
+    pub fn new(grant: MediateGrant) -> Self {
+        Self {
+            grant,
+            recipient_keys: Vec::new(),
+            pending_updates: Vec::new(),
+            pending_query: None,
+        }
+    }
+
+    pub fn get_thread_id(&self) -> String {
+        self.grant.decorators.thread.thid.clone()
+    }
+
+    pub fn routing_endpoint(&self) -> Url {
+        self.grant.content.endpoint.clone()
+    }
+
+    pub fn routing_keys(&self) -> Vec<String> {
+        self.grant.content.routing_keys.clone()
+    }
+
+    pub fn recipient_keys(&self) -> Vec<String> {
+        self.recipient_keys.clone()
+    }
+
+    pub fn add_pending_update(&mut self, update: KeylistUpdate) {
+        self.pending_updates.push(update);
+    }
+
+    /// Removes and returns the pending keylist update the given thread id refers to.
+    pub fn take_pending_update(&mut self, thread_id: &str) -> Option<KeylistUpdate> {
+        let pos = self.pending_updates.iter().position(|u| u.id == thread_id)?;
+        Some(self.pending_updates.remove(pos))
+    }
+
+    /// Applies the outcome of a keylist update to the locally tracked recipient keys.
+    /// Updates which resulted in `no_change` are applied as well, as they mean
+    /// the mediator state already matches the requested one.
+    pub fn apply_update_results(&mut self, updated: &[KeylistUpdateResponseItem]) {
+        for item in updated {
+            if !matches!(
+                item.result,
+                KeylistUpdateItemResult::Success | KeylistUpdateItemResult::NoChange
+            ) {
+                continue;
+            }
+
+            match item.action {
+                KeylistUpdateItemAction::Add => {
+                    if !self.recipient_keys.contains(&item.recipient_key) {
+                        self.recipient_keys.push(item.recipient_key.clone());
+                    }
+                }
+                KeylistUpdateItemAction::Remove => self.recipient_keys.retain(|k| k != &item.recipient_key),
+            }
+        }
+    }
+
+    pub fn set_pending_query(&mut self, query: KeylistQuery) {
+        self.pending_query = Some(query);
+    }
+
+    pub fn take_pending_query(&mut self) -> Option<KeylistQuery> {
+        self.pending_query.take()
+    }
+
+    pub fn set_recipient_keys(&mut self, recipient_keys: Vec<String>) {
+        self.recipient_keys = recipient_keys;
+    }
+}
@@ -0,0 +1,4 @@
+pub(super) mod denied;
+pub(super) mod granted;
+pub(super) mod initial;
+pub(super) mod requested;
@@ -0,0 +1,21 @@
+use messages::msg_fields::protocols::coordinate_mediation::mediate_request::MediateRequest;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RequestedState {
+    request: MediateRequest,
+}
+
+impl RequestedState {
+    pub fn new(request: MediateRequest) -> Self {
+        Self { request }
+    }
+
+    pub fn get_thread_id(&self) -> String {
+        self.request
+            .decorators
+            .thread
+            .as_ref()
+            .map(|t| t.thid.clone())
+            .unwrap_or(self.request.id.clone())
+    }
+}
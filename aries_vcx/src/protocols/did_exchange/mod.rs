@@ -0,0 +1,345 @@
+pub mod requester;
+pub mod responder;
+
+use std::{error::Error, sync::Arc};
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use chrono::Utc;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::did_exchange::problem_report::{
+        ProblemCode, ProblemReport, ProblemReportContent, ProblemReportDecorators,
+    },
+    AriesMessage,
+};
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+    errors::error::VcxResult,
+    protocols::connection::{
+        pairwise_info::PairwiseInfo,
+        trait_bounds::{TheirDidDoc, ThreadId},
+        wrap_and_send_msg,
+    },
+    transport::Transport,
+};
+
+/// A state machine for progressing through the [DID exchange protocol](https://github.com/hyperledger/aries-rfcs/blob/main/features/0023-did-exchange/README.md).
+///
+/// It shares the initiation types with [`crate::protocols::connection::Connection`],
+/// the requester being the invitee and the responder being the inviter.
+/// Once the exchange completes, it turns into a completed
+/// [`crate::protocols::connection::Connection`].
+#[derive(Clone, Debug)]
+pub struct DidExchange<I, S> {
+    source_id: String,
+    pairwise_info: PairwiseInfo,
+    initiation_type: I,
+    state: S,
+}
+
+impl<I, S> DidExchange<I, S> {
+    pub fn from_parts(source_id: String, pairwise_info: PairwiseInfo, initiation_type: I, state: S) -> Self {
+        Self {
+            source_id,
+            pairwise_info,
+            initiation_type,
+            state,
+        }
+    }
+
+    pub fn into_parts(self) -> (String, PairwiseInfo, I, S) {
+        let Self {
+            source_id,
+            pairwise_info,
+            initiation_type,
+            state,
+        } = self;
+        (source_id, pairwise_info, initiation_type, state)
+    }
+
+    pub fn pairwise_info(&self) -> &PairwiseInfo {
+        &self.pairwise_info
+    }
+
+    pub fn source_id(&self) -> &str {
+        &self.source_id
+    }
+}
+
+impl<I, S> DidExchange<I, S>
+where
+    S: ThreadId,
+{
+    pub fn thread_id(&self) -> &str {
+        self.state.thread_id()
+    }
+}
+
+impl<I, S> DidExchange<I, S>
+where
+    S: TheirDidDoc,
+{
+    pub fn their_did_doc(&self) -> &AriesDidDoc {
+        self.state.their_did_doc()
+    }
+
+    pub async fn send_message<T>(
+        &self,
+        wallet: &Arc<dyn BaseWallet>,
+        message: &AriesMessage,
+        transport: &T,
+    ) -> VcxResult<()>
+    where
+        T: Transport,
+    {
+        let sender_verkey = &self.pairwise_info().pw_vk;
+        let did_doc = self.their_did_doc();
+        wrap_and_send_msg(wallet, message, sender_verkey, did_doc, transport).await
+    }
+}
+
+impl<I, S> DidExchange<I, S> {
+    fn create_problem_report<E>(
+        &self,
+        err: &E,
+        problem_code: ProblemCode,
+        thread_id: &str,
+        pthid: Option<&str>,
+    ) -> ProblemReport
+    where
+        E: Error,
+    {
+        let mut content = ProblemReportContent::default();
+        content.problem_code = Some(problem_code);
+        content.explain = Some(err.to_string());
+
+        let mut thread = Thread::new(thread_id.to_owned());
+        thread.pthid = pthid.map(ToOwned::to_owned);
+
+        let mut decorators = ProblemReportDecorators::new(thread);
+        let mut timing = Timing::default();
+        timing.out_time = Some(Utc::now());
+        decorators.timing = Some(timing);
+
+        ProblemReport::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+    }
+
+    async fn send_problem_report<E, T>(
+        &self,
+        wallet: &Arc<dyn BaseWallet>,
+        err: &E,
+        problem_code: ProblemCode,
+        thread: (&str, Option<&str>),
+        did_doc: &AriesDidDoc,
+        transport: &T,
+    ) where
+        E: Error,
+        T: Transport,
+    {
+        let (thread_id, pthid) = thread;
+        let sender_verkey = &self.pairwise_info().pw_vk;
+        let problem_report = self.create_problem_report(err, problem_code, thread_id, pthid);
+        let res = wrap_and_send_msg(wallet, &problem_report.into(), sender_verkey, did_doc, transport).await;
+
+        if let Err(e) = res {
+            trace!("Error encountered when sending ProblemReport: {}", e);
+        } else {
+            info!("Error report sent!");
+        }
+    }
+}
+
+/// Builds the [`AriesDidDoc`] that a party shares with its counterparty
+/// during the exchange, based on its (new) [`PairwiseInfo`].
+fn build_our_did_doc(pairwise_info: &PairwiseInfo, service_endpoint: Url, routing_keys: Vec<String>) -> AriesDidDoc {
+    let mut did_doc = AriesDidDoc::default();
+    did_doc.set_id(pairwise_info.pw_did.clone());
+    did_doc.set_service_endpoint(service_endpoint);
+    did_doc.set_routing_keys(routing_keys);
+    did_doc.set_recipient_keys(vec![pairwise_info.pw_vk.clone()]);
+    did_doc
+}
+
+#[cfg(test)]
+#[cfg(feature = "modular_libs")]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use async_trait::async_trait;
+    use messages::msg_fields::protocols::did_exchange::{
+        complete::{Complete, CompleteContent, CompleteDecorators},
+        request::{Request, RequestContent, RequestDecorators},
+    };
+
+    use super::{
+        requester::{states::invited::Invited, DidExchangeRequester},
+        responder::DidExchangeResponder,
+        *,
+    };
+    use crate::{common::signing::sign_did_doc_attachment, protocols::connection::initiation_type::Invitee};
+
+    struct MockTransport;
+
+    #[async_trait]
+    impl Transport for MockTransport {
+        async fn send_message(&self, _msg: Vec<u8>, _service_endpoint: Url) -> VcxResult<()> {
+            Ok(())
+        }
+    }
+
+    const INVITATION_ID: &str = "test_invitation_id";
+    const SERVICE_ENDPOINT: &str = "https://localhost:8080";
+
+    fn make_wallet() -> Arc<dyn BaseWallet> {
+        Arc::new(InMemoryWallet::new())
+    }
+
+    async fn make_request(
+        wallet: &Arc<dyn BaseWallet>,
+        requester: &DidExchangeRequester<requester::states::requested::Requested>,
+    ) -> Request {
+        let pairwise_info = requester.pairwise_info();
+        let did_doc = build_our_did_doc(pairwise_info, SERVICE_ENDPOINT.parse().unwrap(), vec![]);
+
+        let mut content = RequestContent::new("test_label".to_owned(), pairwise_info.pw_did.clone());
+        content.did_doc = Some(
+            sign_did_doc_attachment(wallet, &pairwise_info.pw_vk, &did_doc)
+                .await
+                .unwrap(),
+        );
+
+        let mut thread = Thread::new(requester.thread_id().to_owned());
+        thread.pthid = Some(INVITATION_ID.to_owned());
+
+        Request::with_decorators(
+            requester.thread_id().to_owned(),
+            content,
+            RequestDecorators::new(thread),
+        )
+    }
+
+    async fn make_parties(
+        wallet: &Arc<dyn BaseWallet>,
+    ) -> (
+        DidExchangeRequester<requester::states::requested::Requested>,
+        DidExchangeResponder<responder::states::invited::Invited>,
+    ) {
+        let invitation_info = PairwiseInfo::create(wallet).await.unwrap();
+        let invitation_did_doc = build_our_did_doc(&invitation_info, SERVICE_ENDPOINT.parse().unwrap(), vec![]);
+        let responder =
+            DidExchangeResponder::new_responder("responder".to_owned(), invitation_info, INVITATION_ID.to_owned());
+
+        let requester = DidExchangeRequester::from_parts(
+            "requester".to_owned(),
+            PairwiseInfo::create(wallet).await.unwrap(),
+            Invitee,
+            Invited::new(invitation_did_doc, INVITATION_ID.to_owned()),
+        )
+        .send_request(wallet, SERVICE_ENDPOINT.parse().unwrap(), vec![], &MockTransport)
+        .await
+        .unwrap();
+
+        (requester, responder)
+    }
+
+    #[tokio::test]
+    async fn test_did_exchange_completes() {
+        let wallet = make_wallet();
+        let (requester, responder) = make_parties(&wallet).await;
+
+        let request = make_request(&wallet, &requester).await;
+        let responder = responder
+            .handle_request(
+                &wallet,
+                request,
+                SERVICE_ENDPOINT.parse().unwrap(),
+                vec![],
+                &MockTransport,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            responder.their_did_doc().recipient_keys().unwrap(),
+            vec![requester.pairwise_info().pw_vk.clone()]
+        );
+
+        let response = responder.state.signed_response.clone();
+        let responder = responder.send_response(&wallet, &MockTransport).await.unwrap();
+
+        let requester = requester
+            .handle_response(&wallet, response, &MockTransport)
+            .await
+            .unwrap();
+        assert_eq!(
+            requester.their_did_doc().recipient_keys().unwrap(),
+            vec![responder.pairwise_info().pw_vk.clone()]
+        );
+
+        let requester = requester.send_complete(&wallet, &MockTransport).await.unwrap();
+
+        let mut thread = Thread::new(requester.thread_id().to_owned());
+        thread.pthid = Some(INVITATION_ID.to_owned());
+        let complete = Complete::with_decorators(
+            Uuid::new_v4().to_string(),
+            CompleteContent::default(),
+            CompleteDecorators::new(thread),
+        );
+        let responder = responder.handle_complete(complete).unwrap();
+
+        assert_eq!(requester.thread_id(), responder.thread_id());
+        assert_eq!(requester.remote_vk().unwrap(), responder.pairwise_info().pw_vk);
+        assert_eq!(responder.remote_vk().unwrap(), requester.pairwise_info().pw_vk);
+    }
+
+    #[tokio::test]
+    async fn test_did_exchange_rejects_response_not_signed_by_invitation_key() {
+        let wallet = make_wallet();
+        let (requester, responder) = make_parties(&wallet).await;
+
+        let request = make_request(&wallet, &requester).await;
+        let (source_id, _, initiation_type, state) = responder.into_parts();
+
+        // Pretend the responder answers with a key other than the invitation one.
+        let other_info = PairwiseInfo::create(&wallet).await.unwrap();
+        let responder = DidExchange::from_parts(source_id, other_info, initiation_type, state)
+            .handle_request(
+                &wallet,
+                request,
+                SERVICE_ENDPOINT.parse().unwrap(),
+                vec![],
+                &MockTransport,
+            )
+            .await
+            .unwrap();
+
+        let response = responder.state.signed_response.clone();
+
+        requester
+            .handle_response(&wallet, response, &MockTransport)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_did_exchange_rejects_request_for_other_invitation() {
+        let wallet = make_wallet();
+        let (requester, responder) = make_parties(&wallet).await;
+
+        let mut request = make_request(&wallet, &requester).await;
+        request.decorators.thread.pthid = Some("other_invitation_id".to_owned());
+
+        responder
+            .handle_request(
+                &wallet,
+                request,
+                SERVICE_ENDPOINT.parse().unwrap(),
+                vec![],
+                &MockTransport,
+            )
+            .await
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,286 @@
+pub mod states;
+
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use chrono::Utc;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::{
+        did_exchange::{
+            complete::{Complete, CompleteContent, CompleteDecorators},
+            problem_report::ProblemCode,
+            request::{Request, RequestContent, RequestDecorators},
+            response::Response,
+        },
+        out_of_band::invitation::Invitation as OobInvitation,
+    },
+};
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+    common::{
+        ledger::transactions::{get_service, into_did_doc},
+        signing::{decode_signed_did_doc_attachment, sign_did_doc_attachment},
+    },
+    core::profile::profile::Profile,
+    errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult},
+    handlers::util::{matches_thread_id, AnyInvitation},
+    protocols::connection::{
+        initiation_type::Invitee, invitee::states::completed::Completed, pairwise_info::PairwiseInfo,
+        trait_bounds::BootstrapDidDoc, Connection,
+    },
+    transport::Transport,
+};
+
+use self::states::{initial::Initial, invited::Invited, requested::Requested, responded::Responded};
+
+use super::{build_our_did_doc, DidExchange};
+
+/// Convenience alias
+pub type DidExchangeRequester<S> = DidExchange<Invitee, S>;
+
+impl DidExchangeRequester<Initial> {
+    /// Creates a new [`DidExchangeRequester<Initial>`].
+    pub fn new_requester(source_id: String, pairwise_info: PairwiseInfo) -> Self {
+        Self {
+            source_id,
+            pairwise_info,
+            initiation_type: Invitee,
+            state: Initial,
+        }
+    }
+
+    /// Accepts an out-of-band [`OobInvitation`] and transitions to [`DidExchangeRequester<Invited>`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if a DidDoc could not be resolved from the [`OobInvitation`].
+    pub async fn accept_invitation(
+        self,
+        profile: &Arc<dyn Profile>,
+        invitation: OobInvitation,
+    ) -> VcxResult<DidExchangeRequester<Invited>> {
+        trace!("DidExchange::accept_invitation >>> invitation: {:?}", &invitation);
+
+        let invitation_id = invitation.id.clone();
+        let did_doc = into_did_doc(profile, &AnyInvitation::Oob(invitation)).await?;
+
+        Ok(DidExchange {
+            source_id: self.source_id,
+            pairwise_info: self.pairwise_info,
+            initiation_type: self.initiation_type,
+            state: Invited::new(did_doc, invitation_id),
+        })
+    }
+
+    /// Accepts an implicit invitation, in the form of the responder's public DID,
+    /// and transitions to [`DidExchangeRequester<Invited>`].
+    ///
+    /// The public DID is used as the parent thread ID of the exchange.
+    ///
+    /// # Errors
+    ///
+    /// Will error out if the service of the public DID could not be resolved from the ledger.
+    pub async fn accept_public_did(
+        self,
+        profile: &Arc<dyn Profile>,
+        public_did: String,
+    ) -> VcxResult<DidExchangeRequester<Invited>> {
+        trace!("DidExchange::accept_public_did >>> public_did: {}", public_did);
+
+        let service = get_service(profile, &public_did).await?;
+
+        let mut did_doc = AriesDidDoc::default();
+        did_doc.set_id(public_did.clone());
+        did_doc.set_service_endpoint(service.service_endpoint);
+        did_doc.set_recipient_keys(service.recipient_keys);
+        did_doc.set_routing_keys(service.routing_keys);
+
+        Ok(DidExchange {
+            source_id: self.source_id,
+            pairwise_info: self.pairwise_info,
+            initiation_type: self.initiation_type,
+            state: Invited::new(did_doc, public_did),
+        })
+    }
+}
+
+impl DidExchangeRequester<Invited> {
+    /// Sends a [`Request`] with our signed DidDoc attached to the responder
+    /// and transitions to [`DidExchangeRequester<Requested>`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if signing the DidDoc or sending the request fails.
+    pub async fn send_request<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        service_endpoint: Url,
+        routing_keys: Vec<String>,
+        transport: &T,
+    ) -> VcxResult<DidExchangeRequester<Requested>>
+    where
+        T: Transport,
+    {
+        trace!("DidExchange::send_request");
+
+        let did_doc = build_our_did_doc(&self.pairwise_info, service_endpoint, routing_keys);
+        let did_doc_attach = sign_did_doc_attachment(wallet, &self.pairwise_info.pw_vk, &did_doc).await?;
+
+        let mut content = RequestContent::new(self.source_id.clone(), self.pairwise_info.pw_did.clone());
+        content.did_doc = Some(did_doc_attach);
+
+        // The request ID is used as the thread ID, while the invitation ID
+        // (or the public DID, for implicit invitations) is the parent thread ID.
+        //
+        // Multiple requesters can use the same invitation, hence the common
+        // parent thread ID and different thread IDs (request IDs are unique).
+        let id = Uuid::new_v4().to_string();
+        let mut thread = Thread::new(id.clone());
+        thread.pthid = Some(self.state.invitation_id.clone());
+
+        let mut decorators = RequestDecorators::new(thread);
+        let mut timing = Timing::default();
+        timing.out_time = Some(Utc::now());
+        decorators.timing = Some(timing);
+
+        let request = Request::with_decorators(id.clone(), content, decorators);
+
+        self.send_message(wallet, &request.into(), transport).await?;
+
+        Ok(DidExchange {
+            state: Requested::new(self.state.did_doc, id, self.state.invitation_id),
+            source_id: self.source_id,
+            pairwise_info: self.pairwise_info,
+            initiation_type: self.initiation_type,
+        })
+    }
+}
+
+impl DidExchangeRequester<Requested> {
+    /// Processes a [`Response`] from the responder and transitions to [`DidExchangeRequester<Responded>`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if:
+    ///     * the thread ID of the response does not match the exchange thread ID
+    ///     * no recipient verkeys are present in the invitation DidDoc
+    ///     * the response carries no DidDoc attachment
+    ///     * the DidDoc attachment is not signed with the invitation key
+    pub async fn handle_response<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        response: Response,
+        transport: &T,
+    ) -> VcxResult<DidExchangeRequester<Responded>>
+    where
+        T: Transport,
+    {
+        if !matches_thread_id!(response, self.state.thread_id.as_str()) {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidJson,
+                format!(
+                    "Cannot handle message {:?}: thread id does not match, expected {:?}",
+                    response, self.state.thread_id
+                ),
+            ));
+        };
+
+        let keys = &self.state.did_doc.recipient_keys()?;
+        let their_vk = keys.first().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "Cannot handle response: remote verkey not found",
+        ))?;
+
+        let res = match response.content.did_doc.as_ref() {
+            Some(attach) => decode_signed_did_doc_attachment(wallet, attach, Some(their_vk)).await,
+            None => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidJson,
+                "Cannot handle response: DidDoc attachment not found",
+            )),
+        };
+
+        let did_doc = match res {
+            Ok(did_doc) => did_doc,
+            Err(err) => {
+                error!("Response DidDoc validation failed! Sending ProblemReport...");
+
+                self.send_problem_report(
+                    wallet,
+                    &err,
+                    ProblemCode::ResponseNotAccepted,
+                    (&self.state.thread_id, Some(self.state.invitation_id.as_str())),
+                    &self.state.did_doc,
+                    transport,
+                )
+                .await;
+
+                return Err(err);
+            }
+        };
+
+        let state = Responded::new(
+            did_doc,
+            self.state.did_doc,
+            self.state.thread_id,
+            self.state.invitation_id,
+        );
+
+        Ok(DidExchange {
+            state,
+            source_id: self.source_id,
+            pairwise_info: self.pairwise_info,
+            initiation_type: self.initiation_type,
+        })
+    }
+}
+
+impl DidExchangeRequester<Responded> {
+    /// Sends a [`Complete`] message to the responder and transitions
+    /// to a completed [`Connection`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if sending the message fails.
+    pub async fn send_complete<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        transport: &T,
+    ) -> VcxResult<Connection<Invitee, Completed>>
+    where
+        T: Transport,
+    {
+        let mut thread = Thread::new(self.state.thread_id.clone());
+        thread.pthid = Some(self.state.invitation_id.clone());
+
+        let mut decorators = CompleteDecorators::new(thread);
+        let mut timing = Timing::default();
+        timing.out_time = Some(Utc::now());
+        decorators.timing = Some(timing);
+
+        let msg = Complete::with_decorators(Uuid::new_v4().to_string(), CompleteContent::default(), decorators);
+
+        self.send_message(wallet, &msg.into(), transport).await?;
+
+        let state = Completed::new(
+            self.state.did_doc,
+            self.state.bootstrap_did_doc,
+            self.state.thread_id,
+            None,
+        );
+
+        Ok(Connection::from_parts(
+            self.source_id,
+            self.pairwise_info,
+            self.initiation_type,
+            state,
+        ))
+    }
+
+    pub fn bootstrap_did_doc(&self) -> &AriesDidDoc {
+        self.state.bootstrap_did_doc()
+    }
+}
@@ -0,0 +1,29 @@
+use diddoc::aries::diddoc::AriesDidDoc;
+
+use crate::protocols::connection::trait_bounds::{TheirDidDoc, ThreadId};
+
+/// State of a requester that accepted an invitation, either an explicit
+/// out-of-band one or an implicit one in the form of a public DID.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Invited {
+    pub(crate) did_doc: AriesDidDoc,
+    pub(crate) invitation_id: String,
+}
+
+impl Invited {
+    pub fn new(did_doc: AriesDidDoc, invitation_id: String) -> Self {
+        Self { did_doc, invitation_id }
+    }
+}
+
+impl TheirDidDoc for Invited {
+    fn their_did_doc(&self) -> &AriesDidDoc {
+        &self.did_doc
+    }
+}
+
+impl ThreadId for Invited {
+    fn thread_id(&self) -> &str {
+        &self.invitation_id
+    }
+}
@@ -0,0 +1,4 @@
+pub mod initial;
+pub mod invited;
+pub mod requested;
+pub mod responded;
@@ -0,0 +1,201 @@
+pub mod states;
+
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use chrono::Utc;
+use messages::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::did_exchange::{
+        complete::Complete,
+        problem_report::ProblemCode,
+        request::Request,
+        response::{Response, ResponseContent, ResponseDecorators},
+    },
+};
+use url::Url;
+use uuid::Uuid;
+
+use crate::{
+    common::signing::{decode_signed_did_doc_attachment, sign_did_doc_attachment},
+    errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult},
+    handlers::util::verify_thread_id,
+    protocols::connection::{
+        initiation_type::Inviter, inviter::states::completed::Completed, pairwise_info::PairwiseInfo, Connection,
+    },
+    transport::Transport,
+};
+
+use self::states::{invited::Invited, requested::Requested, responded::Responded};
+
+use super::{build_our_did_doc, DidExchange};
+
+/// Convenience alias
+pub type DidExchangeResponder<S> = DidExchange<Inviter, S>;
+
+impl DidExchangeResponder<Invited> {
+    /// Creates a new [`DidExchangeResponder<Invited>`] that awaits requests for
+    /// the invitation identified by `invitation_id`.
+    ///
+    /// The `pairwise_info` must contain the key the invitation was issued with,
+    /// as it is used to sign the DidDoc sent in the [`Response`]. For implicit invitations,
+    /// `invitation_id` is the public DID and `pairwise_info` holds the public DID and its verkey.
+    ///
+    /// Like with [`crate::protocols::connection::inviter::InviterConnection`], the advised
+    /// way of handling multiple requesters is to clone the [`DidExchangeResponder<Invited>`]
+    /// for every [`Request`] received for the invitation.
+    pub fn new_responder(source_id: String, pairwise_info: PairwiseInfo, invitation_id: String) -> Self {
+        Self {
+            source_id,
+            pairwise_info,
+            initiation_type: Inviter,
+            state: Invited::new(invitation_id),
+        }
+    }
+
+    /// Processes a [`Request`] and transitions to [`DidExchangeResponder<Requested>`].
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if either:
+    ///     * the [`Request`]'s parent thread ID does not match the invitation ID
+    ///     * the [`Request`] carries no DidDoc attachment or its signature is invalid
+    ///     * the [`Request`]'s DidDoc is not valid
+    ///     * generating new [`PairwiseInfo`] or signing our DidDoc fails
+    pub async fn handle_request<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        request: Request,
+        new_service_endpoint: Url,
+        new_routing_keys: Vec<String>,
+        transport: &T,
+    ) -> VcxResult<DidExchangeResponder<Requested>>
+    where
+        T: Transport,
+    {
+        trace!(
+            "DidExchange::handle_request >>> request: {:?}, service_endpoint: {}, routing_keys: {:?}",
+            request,
+            new_service_endpoint,
+            new_routing_keys,
+        );
+
+        verify_thread_id(&self.state.invitation_id, &request.clone().into())?;
+
+        let did_doc = match request.content.did_doc.as_ref() {
+            Some(attach) => decode_signed_did_doc_attachment(wallet, attach, None).await?,
+            None => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidJson,
+                    "Cannot handle request: DidDoc attachment not found",
+                ))
+            }
+        };
+
+        let thread_id = request.decorators.thread.thid;
+        let pthid = request.decorators.thread.pthid;
+
+        // If the request's DidDoc validation fails, we generate and send a ProblemReport.
+        // We then return early with the provided error.
+        if let Err(err) = did_doc.validate() {
+            error!("Request DidDoc validation failed! Sending ProblemReport...");
+
+            self.send_problem_report(
+                wallet,
+                &err,
+                ProblemCode::RequestNotAccepted,
+                (&thread_id, pthid.as_deref()),
+                &did_doc,
+                transport,
+            )
+            .await;
+
+            Err(err)?;
+        }
+
+        // Generate new pairwise info that will be used from this point on
+        // and incorporate that into the response, signed with the invitation key.
+        let new_pairwise_info = PairwiseInfo::create(wallet).await?;
+        let our_did_doc = build_our_did_doc(&new_pairwise_info, new_service_endpoint, new_routing_keys);
+        let did_doc_attach = sign_did_doc_attachment(wallet, &self.pairwise_info.pw_vk, &our_did_doc).await?;
+
+        let mut content = ResponseContent::new(new_pairwise_info.pw_did.clone());
+        content.did_doc = Some(did_doc_attach);
+
+        let mut thread = Thread::new(thread_id);
+        thread.pthid = pthid;
+
+        let mut decorators = ResponseDecorators::new(thread);
+        let mut timing = Timing::default();
+        timing.out_time = Some(Utc::now());
+        decorators.timing = Some(timing);
+
+        let response = Response::with_decorators(Uuid::new_v4().to_string(), content, decorators);
+
+        Ok(DidExchange {
+            source_id: self.source_id,
+            pairwise_info: new_pairwise_info,
+            initiation_type: self.initiation_type,
+            state: Requested::new(response, did_doc),
+        })
+    }
+
+    /// Returns the ID of the invitation this responder awaits requests for.
+    pub fn invitation_id(&self) -> &str {
+        &self.state.invitation_id
+    }
+}
+
+impl DidExchangeResponder<Requested> {
+    /// Sends a [`Response`] to the requester and transitions to [`DidExchangeResponder<Responded>`].
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if sending the response fails.
+    pub async fn send_response<T>(
+        self,
+        wallet: &Arc<dyn BaseWallet>,
+        transport: &T,
+    ) -> VcxResult<DidExchangeResponder<Responded>>
+    where
+        T: Transport,
+    {
+        trace!(
+            "DidExchange::send_response >>> signed_response: {:?}",
+            &self.state.signed_response
+        );
+
+        let thread_id = self.state.signed_response.decorators.thread.thid.clone();
+
+        self.send_message(wallet, &self.state.signed_response.clone().into(), transport)
+            .await?;
+
+        Ok(DidExchange {
+            state: Responded::new(self.state.did_doc, thread_id),
+            source_id: self.source_id,
+            pairwise_info: self.pairwise_info,
+            initiation_type: self.initiation_type,
+        })
+    }
+}
+
+impl DidExchangeResponder<Responded> {
+    /// Processes the requester's [`Complete`] message and transitions to a completed [`Connection`].
+    ///
+    /// # Errors
+    ///
+    /// Will error out if the message's thread ID does not match
+    /// the ID of the thread context used in this exchange.
+    pub fn handle_complete(self, complete: Complete) -> VcxResult<Connection<Inviter, Completed>> {
+        verify_thread_id(&self.state.thread_id, &complete.into())?;
+
+        let state = Completed::new(self.state.did_doc, self.state.thread_id, None);
+
+        Ok(Connection::from_parts(
+            self.source_id,
+            self.pairwise_info,
+            self.initiation_type,
+            state,
+        ))
+    }
+}
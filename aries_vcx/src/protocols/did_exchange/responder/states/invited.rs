@@ -0,0 +1,22 @@
+use crate::protocols::connection::trait_bounds::ThreadId;
+
+/// State of a responder waiting for requests to one of its invitations.
+///
+/// The invitation ID is either the ID of an out-of-band invitation or,
+/// in the case of implicit invitations, the responder's public DID.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Invited {
+    pub(crate) invitation_id: String,
+}
+
+impl Invited {
+    pub fn new(invitation_id: String) -> Self {
+        Self { invitation_id }
+    }
+}
+
+impl ThreadId for Invited {
+    fn thread_id(&self) -> &str {
+        &self.invitation_id
+    }
+}
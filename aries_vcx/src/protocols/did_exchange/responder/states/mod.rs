@@ -0,0 +1,3 @@
+pub mod invited;
+pub mod requested;
+pub mod responded;
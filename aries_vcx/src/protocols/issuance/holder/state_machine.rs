@@ -33,7 +33,7 @@ use crate::protocols::issuance::holder::states::initial::InitialHolderState;
 use crate::protocols::issuance::holder::states::offer_received::OfferReceivedState;
 use crate::protocols::issuance::holder::states::proposal_sent::ProposalSentState;
 use crate::protocols::issuance::holder::states::request_sent::RequestSentState;
-use crate::protocols::issuance::verify_thread_id;
+use crate::protocols::issuance::{verify_thread_id, CredentialNegotiationRound};
 use crate::protocols::SendClosure;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -60,6 +60,8 @@ pub struct HolderSM {
     state: HolderFullState,
     source_id: String,
     thread_id: String,
+    #[serde(default)]
+    negotiation_history: Vec<CredentialNegotiationRound>,
 }
 
 impl fmt::Display for HolderFullState {
@@ -112,17 +114,28 @@ impl HolderSM {
             thread_id: Uuid::new_v4().to_string(),
             state: HolderFullState::Initial(InitialHolderState::new()),
             source_id,
+            negotiation_history: Vec::new(),
         }
     }
 
     pub fn from_offer(offer: OfferCredential, source_id: String) -> Self {
+        let thread_id = match offer.decorators.thread {
+            Some(ref thread) => thread.thid.clone(),
+            None => offer.id.clone(),
+        };
         HolderSM {
-            thread_id: offer.id.clone(),
+            thread_id,
+            negotiation_history: vec![CredentialNegotiationRound::Offer(offer.clone())],
             state: HolderFullState::OfferReceived(OfferReceivedState::new(offer)),
             source_id,
         }
     }
 
+    /// Returns the proposals and offers exchanged on the thread so far, oldest first.
+    pub fn get_negotiation_history(&self) -> &[CredentialNegotiationRound] {
+        &self.negotiation_history
+    }
+
     pub fn get_source_id(&self) -> String {
         self.source_id.clone()
     }
@@ -245,17 +258,21 @@ impl HolderSM {
             &self.thread_id,
             &CredentialIssuanceAction::CredentialProposalSend(proposal_data.clone()),
         )?;
+        let mut negotiation_history = self.negotiation_history;
         let state = match self.state {
             HolderFullState::Initial(_) => {
                 let mut proposal = proposal_data;
                 proposal.id = self.thread_id.clone();
                 send_message(proposal.clone().into()).await?;
+                negotiation_history.push(CredentialNegotiationRound::Proposal(proposal.clone()));
                 HolderFullState::ProposalSent(ProposalSentState::new(proposal))
             }
             HolderFullState::OfferReceived(_) => {
                 let mut proposal = proposal_data;
-                proposal.id = self.thread_id.clone();
+                proposal.id = Uuid::new_v4().to_string();
+                proposal.decorators.thread = Some(Thread::new(self.thread_id.clone()));
                 send_message(proposal.clone().into()).await?;
+                negotiation_history.push(CredentialNegotiationRound::Proposal(proposal.clone()));
                 HolderFullState::ProposalSent(ProposalSentState::new(proposal))
             }
             s => {
@@ -263,7 +280,11 @@ impl HolderSM {
                 s
             }
         };
-        Ok(Self { state, ..self })
+        Ok(Self {
+            state,
+            negotiation_history,
+            ..self
+        })
     }
 
     pub fn receive_offer(self, offer: OfferCredential) -> VcxResult<Self> {
@@ -271,14 +292,22 @@ impl HolderSM {
             &self.thread_id,
             &CredentialIssuanceAction::CredentialOffer(offer.clone()),
         )?;
+        let mut negotiation_history = self.negotiation_history;
         let state = match self.state {
-            HolderFullState::ProposalSent(_) => HolderFullState::OfferReceived(OfferReceivedState::new(offer)),
+            HolderFullState::ProposalSent(_) => {
+                negotiation_history.push(CredentialNegotiationRound::Offer(offer.clone()));
+                HolderFullState::OfferReceived(OfferReceivedState::new(offer))
+            }
             s => {
                 warn!("Unable to receive credential offer in state {}", s);
                 s
             }
         };
-        Ok(Self { state, ..self })
+        Ok(Self {
+            state,
+            negotiation_history,
+            ..self
+        })
     }
 
     pub async fn send_request(
@@ -578,15 +607,22 @@ async fn _store_credential(
         cred_def_json
     );
 
+    let credential_json = get_attach_as_string!(&credential.content.credentials_attach);
+    store_credential(profile, &credential_json, req_meta, cred_def_json).await
+}
+
+pub(crate) async fn store_credential(
+    profile: &Arc<dyn Profile>,
+    credential_json: &str,
+    req_meta: &str,
+    cred_def_json: &str,
+) -> VcxResult<(String, Option<String>)> {
     let ledger = Arc::clone(profile).inject_ledger();
     let anoncreds = Arc::clone(profile).inject_anoncreds();
 
-    let credential_json = get_attach_as_string!(&credential.content.credentials_attach);
-
-    let rev_reg_id = _parse_rev_reg_id_from_credential(&credential_json)?;
-    let rev_reg_def_json = if let Some(rev_reg_id) = rev_reg_id {
-        let json = ledger.get_rev_reg_def_json(&rev_reg_id).await?;
-        Some(json)
+    let rev_reg_id = _parse_rev_reg_id_from_credential(credential_json)?;
+    let rev_reg_def = if let Some(rev_reg_id) = rev_reg_id {
+        Some(ledger.get_rev_reg_def(&rev_reg_id).await?)
     } else {
         None
     };
@@ -594,12 +630,13 @@ async fn _store_credential(
     let cred_id = anoncreds
         .prover_store_credential(
             None,
-            req_meta,
-            &credential_json,
-            cred_def_json,
-            rev_reg_def_json.as_deref(),
+            &serde_json::from_str(req_meta)?,
+            &serde_json::from_str(credential_json)?,
+            &serde_json::from_str(cred_def_json)?,
+            rev_reg_def.as_ref(),
         )
         .await?;
+    let rev_reg_def_json = rev_reg_def.map(|rev_reg_def| json!(rev_reg_def).to_string());
     Ok((cred_id, rev_reg_def_json))
 }
 
@@ -621,15 +658,20 @@ pub async fn create_credential_request(
 ) -> VcxResult<(String, String, String, String)> {
     let ledger = Arc::clone(profile).inject_ledger();
     let anoncreds = Arc::clone(profile).inject_anoncreds();
-    let cred_def_json = ledger.get_cred_def(cred_def_id, None).await?;
+    let cred_def = ledger.get_cred_def(cred_def_id, None).await?;
+    let cred_offer = serde_json::from_str(cred_offer)?;
 
     let master_secret_id = settings::DEFAULT_LINK_SECRET_ALIAS;
-    anoncreds
-        .prover_create_credential_req(prover_did, cred_offer, &cred_def_json, master_secret_id)
+    let (req, req_meta) = anoncreds
+        .prover_create_credential_req(prover_did, &cred_offer, &cred_def, master_secret_id)
         .await
-        .map_err(|err| err.extend("Cannot create credential request"))
-        .map(|(s1, s2)| (s1, s2, cred_def_id.to_string(), cred_def_json))
-        .map_err(|err| err.into())
+        .map_err(|err| err.extend("Cannot create credential request"))?;
+    Ok((
+        serde_json::to_string(&req)?,
+        serde_json::to_string(&req_meta)?,
+        cred_def_id.to_string(),
+        json!(cred_def).to_string(),
+    ))
 }
 
 async fn _make_credential_request(
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::sync::Arc;
 
+use aries_vcx_core::anoncreds::types::{CredentialOffer, CredentialRequest, CredentialValues};
+
 use crate::core::profile::profile::Profile;
 use crate::handlers::util::{
     get_attach_as_string, make_attach_from_str, matches_opt_thread_id, matches_thread_id, AttachmentId, OfferInfo,
@@ -38,7 +40,7 @@ use crate::protocols::issuance::issuer::states::offer_sent::OfferSentState;
 use crate::protocols::issuance::issuer::states::offer_set::OfferSetState;
 use crate::protocols::issuance::issuer::states::proposal_received::ProposalReceivedState;
 use crate::protocols::issuance::issuer::states::requested_received::RequestReceivedState;
-use crate::protocols::issuance::verify_thread_id;
+use crate::protocols::issuance::{verify_thread_id, CredentialNegotiationRound};
 use crate::protocols::SendClosure;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -97,6 +99,8 @@ pub struct IssuerSM {
     source_id: String,
     thread_id: String,
     state: IssuerFullState,
+    #[serde(default)]
+    negotiation_history: Vec<CredentialNegotiationRound>,
 }
 
 fn build_credential_message(libindy_credential: String) -> VcxResult<IssueCredential> {
@@ -115,13 +119,19 @@ fn build_credential_message(libindy_credential: String) -> VcxResult<IssueCreden
     Ok(IssueCredential::with_decorators(id, content, decorators))
 }
 
+/// The first offer of a thread starts it, so its id is the thread id. Counter-offers answering a
+/// proposal get their own id and refer to the thread instead.
 fn build_credential_offer(
     thread_id: &str,
+    starts_thread: bool,
     credential_offer: &str,
     credential_preview: CredentialPreview,
     comment: Option<String>,
 ) -> VcxResult<OfferCredential> {
-    let id = thread_id.to_owned();
+    let id = match starts_thread {
+        true => thread_id.to_owned(),
+        false => Uuid::new_v4().to_string(),
+    };
 
     let mut content = OfferCredentialContent::new(
         credential_preview,
@@ -133,6 +143,9 @@ fn build_credential_offer(
     content.comment = comment;
 
     let mut decorators = OfferCredentialDecorators::default();
+    if !starts_thread {
+        decorators.thread = Some(Thread::new(thread_id.to_owned()));
+    }
     let mut timing = Timing::default();
     timing.out_time = Some(Utc::now());
     decorators.timing = Some(timing);
@@ -146,6 +159,7 @@ impl IssuerSM {
             source_id: source_id.to_string(),
             thread_id: Uuid::new_v4().to_string(),
             state: IssuerFullState::Initial(InitialIssuerState {}),
+            negotiation_history: Vec::new(),
         }
     }
 
@@ -154,6 +168,7 @@ impl IssuerSM {
             thread_id: credential_proposal.id.clone(),
             source_id: source_id.to_string(),
             state: IssuerFullState::ProposalReceived(ProposalReceivedState::new(credential_proposal.clone(), None)),
+            negotiation_history: vec![CredentialNegotiationRound::Proposal(credential_proposal.clone())],
         }
     }
 
@@ -166,6 +181,7 @@ impl IssuerSM {
             source_id,
             thread_id,
             state,
+            negotiation_history: Vec::new(),
         }
     }
 
@@ -344,6 +360,11 @@ impl IssuerSM {
         }
     }
 
+    /// Returns the proposals and offers exchanged on the thread so far, oldest first.
+    pub fn get_negotiation_history(&self) -> &[CredentialNegotiationRound] {
+        &self.negotiation_history
+    }
+
     pub fn build_credential_offer_msg(
         self,
         credential_offer: &str,
@@ -351,14 +372,15 @@ impl IssuerSM {
         comment: Option<String>,
         offer_info: &OfferInfo,
     ) -> VcxResult<Self> {
-        let Self {
-            state,
-            source_id,
-            thread_id,
-        } = self;
-        let state = match state {
+        let state = match self.state {
             IssuerFullState::Initial(_) | IssuerFullState::OfferSet(_) | IssuerFullState::ProposalReceived(_) => {
-                let cred_offer_msg = build_credential_offer(&thread_id, credential_offer, credential_preview, comment)?;
+                let cred_offer_msg = build_credential_offer(
+                    &self.thread_id,
+                    self.negotiation_history.is_empty(),
+                    credential_offer,
+                    credential_preview,
+                    comment,
+                )?;
                 IssuerFullState::OfferSet(OfferSetState::new(
                     cred_offer_msg,
                     &offer_info.credential_json,
@@ -367,14 +389,14 @@ impl IssuerSM {
                     offer_info.tails_file.clone(),
                 ))
             }
-            _ => {
+            state => {
                 return Err(AriesVcxError::from_msg(
                     AriesVcxErrorKind::InvalidState,
                     format!("Can not set_offer in current state {}.", state),
                 ));
             }
         };
-        Ok(Self::step(source_id, thread_id, state))
+        Ok(Self { state, ..self })
     }
 
     pub fn get_credential_offer_msg(&self) -> VcxResult<OfferCredential> {
@@ -389,22 +411,25 @@ impl IssuerSM {
     }
 
     pub fn mark_credential_offer_msg_sent(self) -> VcxResult<Self> {
-        let Self {
-            state,
-            source_id,
-            thread_id,
-        } = self;
-        let state = match state {
-            IssuerFullState::OfferSet(state) => IssuerFullState::OfferSent(state.into()),
+        let mut negotiation_history = self.negotiation_history;
+        let state = match self.state {
+            IssuerFullState::OfferSet(state) => {
+                negotiation_history.push(CredentialNegotiationRound::Offer(state.offer.clone()));
+                IssuerFullState::OfferSent(state.into())
+            }
             IssuerFullState::OfferSent(state) => IssuerFullState::OfferSent(state),
-            _ => {
+            state => {
                 return Err(AriesVcxError::from_msg(
                     AriesVcxErrorKind::InvalidState,
                     format!("Can not mark_as_offer_sent in current state {}.", state),
                 ))
             }
         };
-        Ok(Self::step(source_id, thread_id, state))
+        Ok(Self {
+            state,
+            negotiation_history,
+            ..self
+        })
     }
 
     pub fn receive_proposal(self, proposal: ProposeCredential) -> VcxResult<Self> {
@@ -412,9 +437,11 @@ impl IssuerSM {
             &self.thread_id,
             &CredentialIssuanceAction::CredentialProposal(proposal.clone()),
         )?;
+        let mut negotiation_history = self.negotiation_history;
         let (state, thread_id) = match self.state {
             IssuerFullState::Initial(_) => {
                 let thread_id = proposal.id.to_string();
+                negotiation_history.push(CredentialNegotiationRound::Proposal(proposal.clone()));
                 let state = IssuerFullState::ProposalReceived(ProposalReceivedState::new(proposal, None));
                 (state, thread_id)
             }
@@ -423,6 +450,7 @@ impl IssuerSM {
                     &self.thread_id,
                     &CredentialIssuanceAction::CredentialProposal(proposal.clone()),
                 )?;
+                negotiation_history.push(CredentialNegotiationRound::Proposal(proposal.clone()));
                 let state = IssuerFullState::ProposalReceived(ProposalReceivedState::new(proposal, None));
                 (state, self.thread_id.clone())
             }
@@ -434,6 +462,7 @@ impl IssuerSM {
         Ok(Self {
             state,
             thread_id,
+            negotiation_history,
             ..self
         })
     }
@@ -599,12 +628,14 @@ async fn _create_credential(
 
     let request = get_attach_as_string!(&request.content.requests_attach);
 
-    let cred_data = encode_attributes(cred_data)?;
-    let (libindy_credential, cred_rev_id, _) = anoncreds
+    let offer: CredentialOffer = serde_json::from_str(&offer)?;
+    let request: CredentialRequest = serde_json::from_str(&request)?;
+    let cred_data: CredentialValues = serde_json::from_str(&encode_attributes(cred_data)?)?;
+    let issued = anoncreds
         .issuer_create_credential(&offer, &request, &cred_data, rev_reg_id.clone(), tails_file.clone())
         .await?;
-    let credential = build_credential_message(libindy_credential)?;
-    Ok((credential, cred_rev_id))
+    let credential = build_credential_message(serde_json::to_string(&issued.credential)?)?;
+    Ok((credential, issued.cred_rev_id))
 }
 
 // #[cfg(test)]
@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+use messages::msg_fields::protocols::cred_issuance::offer_credential::OfferCredential;
+use messages::msg_fields::protocols::cred_issuance::propose_credential::ProposeCredential;
+
 use crate::core::profile::profile::Profile;
 use crate::errors::error::prelude::*;
 use crate::global::settings;
@@ -9,6 +12,14 @@ pub mod actions;
 pub mod holder;
 pub mod issuer;
 
+/// A proposal or offer exchanged while negotiating the credential to issue. Holder and issuer
+/// may go back and forth on the same thread until the holder accepts an offer by requesting it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CredentialNegotiationRound {
+    Proposal(ProposeCredential),
+    Offer(OfferCredential),
+}
+
 pub fn verify_thread_id(thread_id: &str, message: &CredentialIssuanceAction) -> VcxResult<()> {
     if !settings::indy_mocks_enabled() && !message.thread_id_matches(thread_id) {
         return Err(AriesVcxError::from_msg(
@@ -24,20 +35,88 @@ pub fn verify_thread_id(thread_id: &str, message: &CredentialIssuanceAction) ->
 
 pub async fn is_cred_def_revokable(profile: &Arc<dyn Profile>, cred_def_id: &str) -> VcxResult<bool> {
     let ledger = Arc::clone(profile).inject_ledger();
-    let cred_def_json = ledger.get_cred_def(cred_def_id, None).await.map_err(|err| {
+    let cred_def = ledger.get_cred_def(cred_def_id, None).await.map_err(|err| {
         AriesVcxError::from_msg(
             AriesVcxErrorKind::InvalidLedgerResponse,
             format!("Failed to obtain credential definition from ledger or cache: {}", err),
         )
     })?;
-    let parsed_cred_def: serde_json::Value = serde_json::from_str(&cred_def_json).map_err(|err| {
-        AriesVcxError::from_msg(
-            AriesVcxErrorKind::SerializationError,
-            format!(
-                "Failed deserialize credential definition json {}\nError: {}",
-                cred_def_json, err
-            ),
+    Ok(!cred_def.value["revocation"].is_null())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_fields::protocols::cred_issuance::propose_credential::{
+        ProposeCredentialContent, ProposeCredentialDecorators,
+    };
+    use messages::msg_fields::protocols::cred_issuance::CredentialPreview;
+    use messages::AriesMessage;
+    use uuid::Uuid;
+
+    use crate::handlers::util::OfferInfo;
+    use crate::protocols::issuance::holder::state_machine::{HolderSM, HolderState};
+    use crate::protocols::issuance::issuer::state_machine::{IssuerSM, IssuerState};
+    use crate::protocols::SendClosure;
+
+    use super::*;
+
+    pub fn _send_message() -> SendClosure {
+        Box::new(|_: AriesMessage| Box::pin(async { VcxResult::Ok(()) }))
+    }
+
+    fn _proposal(cred_def_id: &str) -> ProposeCredential {
+        let content = ProposeCredentialContent::new(
+            CredentialPreview::new(vec![]),
+            "schema_id".to_owned(),
+            cred_def_id.to_owned(),
+        );
+        ProposeCredential::with_decorators(
+            Uuid::new_v4().to_string(),
+            content,
+            ProposeCredentialDecorators::default(),
         )
-    })?;
-    Ok(!parsed_cred_def["value"]["revocation"].is_null())
+    }
+
+    fn _send_offer(issuer: IssuerSM, cred_def_id: &str) -> IssuerSM {
+        let offer_info = OfferInfo::new("{}".to_owned(), cred_def_id.to_owned(), None, None);
+        issuer
+            .build_credential_offer_msg("{}", CredentialPreview::new(vec![]), None, &offer_info)
+            .unwrap()
+            .mark_credential_offer_msg_sent()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_credential_negotiation_loop() {
+        let holder = HolderSM::new("holder".to_owned())
+            .send_proposal(_proposal("cred_def_1"), _send_message())
+            .await
+            .unwrap();
+        let issuer = IssuerSM::from_proposal("issuer", &holder.get_proposal().unwrap());
+        let issuer = _send_offer(issuer, "cred_def_2");
+        let holder = holder
+            .receive_offer(issuer.get_credential_offer_msg().unwrap())
+            .unwrap();
+
+        let holder = holder
+            .send_proposal(_proposal("cred_def_3"), _send_message())
+            .await
+            .unwrap();
+        let issuer = issuer.receive_proposal(holder.get_proposal().unwrap()).unwrap();
+        assert_eq!(IssuerState::ProposalReceived, issuer.get_state());
+
+        let issuer = _send_offer(issuer, "cred_def_4");
+        let offer = issuer.get_credential_offer_msg().unwrap();
+        assert_eq!(
+            holder.get_thread_id().unwrap(),
+            offer.decorators.thread.as_ref().unwrap().thid
+        );
+        let holder = holder.receive_offer(offer).unwrap();
+        assert_eq!(HolderState::OfferReceived, holder.get_state());
+
+        assert_eq!(holder.get_thread_id().unwrap(), issuer.thread_id().unwrap());
+        assert_eq!(4, holder.get_negotiation_history().len());
+        assert_eq!(holder.get_negotiation_history(), issuer.get_negotiation_history());
+    }
 }
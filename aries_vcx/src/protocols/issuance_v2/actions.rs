@@ -0,0 +1,94 @@
+use messages::msg_fields::protocols::cred_issuance_v2::ack::{AckCredentialV2, AckCredentialV2Content};
+use messages::msg_fields::protocols::cred_issuance_v2::issue_credential::IssueCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::OfferCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::propose_credential::ProposeCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::RequestCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::CredentialIssuanceV2;
+use messages::msg_fields::protocols::notification::Notification;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+use messages::msg_parts::MsgParts;
+use messages::AriesMessage;
+
+use crate::handlers::util::{matches_opt_thread_id, matches_thread_id};
+
+type OptionalComment = Option<String>;
+
+#[derive(Debug, Clone)]
+pub enum CredentialIssuanceV2Action {
+    CredentialSend(),
+    CredentialProposalSend(ProposeCredentialV2),
+    CredentialProposal(ProposeCredentialV2),
+    CredentialOffer(OfferCredentialV2),
+    CredentialOfferReject(OptionalComment),
+    CredentialRequestSend(String),
+    CredentialRequest(RequestCredentialV2),
+    Credential(IssueCredentialV2),
+    CredentialAck(AckCredentialV2),
+    ProblemReport(ProblemReport),
+    Unknown,
+}
+
+impl CredentialIssuanceV2Action {
+    pub fn thread_id_matches(&self, thread_id: &str) -> bool {
+        match self {
+            Self::CredentialOffer(msg) => matches_opt_thread_id!(msg, thread_id),
+            Self::CredentialProposal(msg) => matches_opt_thread_id!(msg, thread_id),
+            Self::CredentialRequest(msg) => matches_opt_thread_id!(msg, thread_id),
+            Self::Credential(msg) => matches_thread_id!(msg, thread_id),
+            Self::CredentialAck(msg) => matches_thread_id!(msg, thread_id),
+            Self::ProblemReport(msg) => matches_opt_thread_id!(msg, thread_id),
+            _ => true,
+        }
+    }
+}
+
+impl From<AriesMessage> for CredentialIssuanceV2Action {
+    fn from(msg: AriesMessage) -> Self {
+        match msg {
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProposeCredential(proposal)) => {
+                CredentialIssuanceV2Action::CredentialProposal(proposal)
+            }
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::OfferCredential(offer)) => {
+                CredentialIssuanceV2Action::CredentialOffer(offer)
+            }
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::RequestCredential(request)) => {
+                CredentialIssuanceV2Action::CredentialRequest(request)
+            }
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::IssueCredential(credential)) => {
+                CredentialIssuanceV2Action::Credential(credential)
+            }
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::Ack(ack)) => {
+                CredentialIssuanceV2Action::CredentialAck(ack)
+            }
+            AriesMessage::Notification(Notification::Ack(ack)) => {
+                let MsgParts {
+                    id,
+                    content,
+                    decorators,
+                } = ack;
+                let ack = AckCredentialV2::with_decorators(id, AckCredentialV2Content(content), decorators);
+                CredentialIssuanceV2Action::CredentialAck(ack)
+            }
+            AriesMessage::ReportProblem(report) => CredentialIssuanceV2Action::ProblemReport(report),
+            AriesMessage::Notification(Notification::ProblemReport(report)) => {
+                let MsgParts {
+                    id,
+                    content,
+                    decorators,
+                } = report;
+                let report = ProblemReport::with_decorators(id, content.0, decorators);
+                CredentialIssuanceV2Action::ProblemReport(report)
+            }
+            AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProblemReport(report)) => {
+                let MsgParts {
+                    id,
+                    content,
+                    decorators,
+                } = report;
+                let report = ProblemReport::with_decorators(id, content.0, decorators);
+                CredentialIssuanceV2Action::ProblemReport(report)
+            }
+            _ => CredentialIssuanceV2Action::Unknown,
+        }
+    }
+}
@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Utc;
+use messages::decorators::thread::Thread;
+use messages::decorators::timing::Timing;
+use messages::msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier;
+use messages::msg_fields::protocols::cred_issuance_v2::ack::{AckCredentialV2, AckCredentialV2Content};
+use messages::msg_fields::protocols::cred_issuance_v2::issue_credential::{
+    IssueCredentialAttachmentFormatType, IssueCredentialV2,
+};
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::OfferCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::propose_credential::ProposeCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::{
+    RequestCredentialAttachmentFormatType, RequestCredentialV2, RequestCredentialV2Content,
+    RequestCredentialV2Decorators,
+};
+use messages::msg_fields::protocols::cred_issuance_v2::CredentialIssuanceV2;
+use messages::msg_fields::protocols::notification::ack::{AckDecorators, AckStatus};
+use messages::msg_fields::protocols::notification::Notification;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+use messages::AriesMessage;
+use shared_vcx::maybe_known::MaybeKnown;
+use uuid::Uuid;
+
+use crate::common::credentials::{get_cred_rev_id, is_cred_revoked};
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::util::{make_attach_from_str, matches_opt_thread_id, matches_thread_id, Status};
+use crate::protocols::common::{build_problem_report_msg, get_attach_for_format};
+use crate::protocols::issuance::holder::state_machine::{
+    create_credential_request, parse_cred_def_id_from_cred_offer, store_credential, HolderState,
+};
+use crate::protocols::issuance_v2::actions::CredentialIssuanceV2Action;
+use crate::protocols::issuance_v2::holder::states::finished::FinishedHolderState;
+use crate::protocols::issuance_v2::holder::states::initial::InitialHolderState;
+use crate::protocols::issuance_v2::holder::states::offer_received::OfferReceivedState;
+use crate::protocols::issuance_v2::holder::states::proposal_sent::ProposalSentState;
+use crate::protocols::issuance_v2::holder::states::request_sent::RequestSentState;
+use crate::protocols::issuance_v2::verify_thread_id;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum HolderV2FullState {
+    Initial(InitialHolderState),
+    ProposalSent(ProposalSentState),
+    OfferReceived(OfferReceivedState),
+    RequestSent(RequestSentState),
+    Finished(FinishedHolderState),
+}
+
+/// Holder side of the issue credential 2.0 protocol, supporting the `hlindy` attachment formats.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HolderV2SM {
+    state: HolderV2FullState,
+    source_id: String,
+    thread_id: String,
+}
+
+impl fmt::Display for HolderV2FullState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            HolderV2FullState::Initial(_) => f.write_str("Initial"),
+            HolderV2FullState::ProposalSent(_) => f.write_str("ProposalSent"),
+            HolderV2FullState::OfferReceived(_) => f.write_str("OfferReceived"),
+            HolderV2FullState::RequestSent(_) => f.write_str("RequestSent"),
+            HolderV2FullState::Finished(_) => f.write_str("Finished"),
+        }
+    }
+}
+
+fn build_credential_request_msg(credential_request_attach: String, thread_id: &str) -> RequestCredentialV2 {
+    let attach_id = Uuid::new_v4().to_string();
+    let formats = vec![AttachmentFormatSpecifier::new(
+        attach_id.clone(),
+        MaybeKnown::Known(RequestCredentialAttachmentFormatType::HyperledgerIndyCredentialRequest2_0),
+    )];
+    let content = RequestCredentialV2Content::new(
+        formats,
+        vec![make_attach_from_str!(&credential_request_attach, attach_id)],
+    );
+
+    let mut decorators = RequestCredentialV2Decorators::default();
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.thread = Some(Thread::new(thread_id.to_owned()));
+    decorators.timing = Some(timing);
+
+    RequestCredentialV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+fn build_credential_ack(thread_id: &str) -> AckCredentialV2 {
+    let content = AckCredentialV2Content::new(AckStatus::Ok);
+    let mut decorators = AckDecorators::new(Thread::new(thread_id.to_owned()));
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.timing = Some(timing);
+
+    AckCredentialV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+impl HolderV2SM {
+    pub fn new(source_id: String) -> Self {
+        HolderV2SM {
+            thread_id: Uuid::new_v4().to_string(),
+            state: HolderV2FullState::Initial(InitialHolderState::new()),
+            source_id,
+        }
+    }
+
+    pub fn from_offer(offer: OfferCredentialV2, source_id: String) -> Self {
+        HolderV2SM {
+            thread_id: offer
+                .decorators
+                .thread
+                .as_ref()
+                .map(|thread| thread.thid.clone())
+                .unwrap_or_else(|| offer.id.clone()),
+            state: HolderV2FullState::OfferReceived(OfferReceivedState::new(offer)),
+            source_id,
+        }
+    }
+
+    pub fn get_source_id(&self) -> String {
+        self.source_id.clone()
+    }
+
+    pub fn get_state(&self) -> HolderState {
+        match self.state {
+            HolderV2FullState::Initial(_) => HolderState::Initial,
+            HolderV2FullState::ProposalSent(_) => HolderState::ProposalSent,
+            HolderV2FullState::OfferReceived(_) => HolderState::OfferReceived,
+            HolderV2FullState::RequestSent(_) => HolderState::RequestSent,
+            HolderV2FullState::Finished(ref status) => match status.status {
+                Status::Success => HolderState::Finished,
+                _ => HolderState::Failed,
+            },
+        }
+    }
+
+    pub fn get_proposal(&self) -> VcxResult<ProposeCredentialV2> {
+        match &self.state {
+            HolderV2FullState::ProposalSent(state) => Ok(state.credential_proposal.clone()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Proposal not available in this state",
+            )),
+        }
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        trace!(
+            "HolderV2::find_message_to_handle >>> messages: {:?}, state: {:?}",
+            messages,
+            self.state
+        );
+        for (uid, message) in messages {
+            match self.state {
+                HolderV2FullState::ProposalSent(_) => {
+                    if let AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::OfferCredential(offer)) = &message {
+                        if matches_opt_thread_id!(offer, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                }
+                HolderV2FullState::RequestSent(_) => match &message {
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::IssueCredential(credential)) => {
+                        if matches_thread_id!(credential, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProblemReport(problem_report)) => {
+                        if matches_opt_thread_id!(problem_report, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(problem_report) => {
+                        if matches_opt_thread_id!(problem_report, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            };
+        }
+        None
+    }
+
+    pub async fn handle_message(
+        self,
+        profile: &Arc<dyn Profile>,
+        cim: CredentialIssuanceV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<HolderV2SM> {
+        trace!("HolderV2::handle_message >>> cim: {:?}, state: {:?}", cim, self.state);
+        verify_thread_id(&self.thread_id, &cim)?;
+        let send_message = || {
+            send_message.ok_or(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Attempted to call undefined send_message callback",
+            ))
+        };
+        let holder_sm = match cim {
+            CredentialIssuanceV2Action::CredentialProposalSend(proposal) => {
+                self.send_proposal(proposal, send_message()?).await?
+            }
+            CredentialIssuanceV2Action::CredentialOffer(offer) => self.receive_offer(offer)?,
+            CredentialIssuanceV2Action::CredentialRequestSend(my_pw_did) => {
+                self.send_request(profile, my_pw_did, send_message()?).await?
+            }
+            CredentialIssuanceV2Action::CredentialOfferReject(comment) => {
+                self.decline_offer(comment, send_message()?).await?
+            }
+            CredentialIssuanceV2Action::Credential(credential) => {
+                self.receive_credential(profile, credential, send_message()?).await?
+            }
+            CredentialIssuanceV2Action::ProblemReport(problem_report) => self.receive_problem_report(problem_report)?,
+            _ => self,
+        };
+        Ok(holder_sm)
+    }
+
+    pub async fn send_proposal(self, proposal: ProposeCredentialV2, send_message: SendClosure) -> VcxResult<Self> {
+        let mut proposal = proposal;
+        let state = match self.state {
+            HolderV2FullState::Initial(_) => {
+                proposal.id = self.thread_id.clone();
+                send_message(proposal.clone().into()).await?;
+                HolderV2FullState::ProposalSent(ProposalSentState::new(proposal))
+            }
+            HolderV2FullState::OfferReceived(_) => {
+                proposal.decorators.thread = Some(Thread::new(self.thread_id.clone()));
+                send_message(proposal.clone().into()).await?;
+                HolderV2FullState::ProposalSent(ProposalSentState::new(proposal))
+            }
+            s => {
+                warn!("Unable to send credential proposal in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_offer(self, offer: OfferCredentialV2) -> VcxResult<Self> {
+        verify_thread_id(
+            &self.thread_id,
+            &CredentialIssuanceV2Action::CredentialOffer(offer.clone()),
+        )?;
+        let state = match self.state {
+            HolderV2FullState::ProposalSent(_) => HolderV2FullState::OfferReceived(OfferReceivedState::new(offer)),
+            s => {
+                warn!("Unable to receive credential offer in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn send_request(
+        self,
+        profile: &Arc<dyn Profile>,
+        my_pw_did: String,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            HolderV2FullState::OfferReceived(state_data) => {
+                match _make_credential_request(profile, &self.thread_id, &my_pw_did, &state_data).await {
+                    Ok((cred_request, req_meta, cred_def_json)) => {
+                        send_message(cred_request.into()).await?;
+                        HolderV2FullState::RequestSent((state_data, req_meta, cred_def_json).into())
+                    }
+                    Err(err) => {
+                        let problem_report = build_problem_report_msg(Some(err.to_string()), &self.thread_id);
+                        error!(
+                            "Failed to create credential request, sending problem report: {:?}",
+                            problem_report
+                        );
+                        send_message(problem_report.clone().into()).await?;
+                        HolderV2FullState::Finished(problem_report.into())
+                    }
+                }
+            }
+            s => {
+                warn!("Unable to send credential request in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn decline_offer(self, comment: Option<String>, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            HolderV2FullState::OfferReceived(_) => {
+                let problem_report = build_problem_report_msg(comment, &self.thread_id);
+                send_message(problem_report.clone().into()).await?;
+                HolderV2FullState::Finished(problem_report.into())
+            }
+            s => {
+                warn!("Unable to decline credential offer in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn receive_credential(
+        self,
+        profile: &Arc<dyn Profile>,
+        credential: IssueCredentialV2,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            HolderV2FullState::RequestSent(state_data) => {
+                match _store_credential(profile, &credential, &state_data).await {
+                    Ok((cred_id, rev_reg_def_json)) => {
+                        if credential.decorators.please_ack.is_some() {
+                            let ack = build_credential_ack(&self.thread_id);
+                            send_message(ack.into()).await?;
+                        }
+                        HolderV2FullState::Finished((state_data, cred_id, credential, rev_reg_def_json).into())
+                    }
+                    Err(err) => {
+                        let problem_report = build_problem_report_msg(Some(err.to_string()), &self.thread_id);
+                        error!(
+                            "Failed to process or save received credential, sending problem report: {:?}",
+                            problem_report
+                        );
+                        send_message(problem_report.clone().into()).await?;
+                        HolderV2FullState::Finished(problem_report.into())
+                    }
+                }
+            }
+            s => {
+                warn!("Unable to receive credential in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_problem_report(self, problem_report: ProblemReport) -> VcxResult<Self> {
+        let state = match self.state {
+            HolderV2FullState::ProposalSent(_) | HolderV2FullState::RequestSent(_) => {
+                HolderV2FullState::Finished(problem_report.into())
+            }
+            s => {
+                warn!("Unable to receive problem report in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn credential_status(&self) -> u32 {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => state.status.code(),
+            _ => Status::Undefined.code(),
+        }
+    }
+
+    pub fn is_terminal_state(&self) -> bool {
+        matches!(self.state, HolderV2FullState::Finished(_))
+    }
+
+    pub fn get_credential(&self) -> VcxResult<(String, AriesMessage)> {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => {
+                let cred_id = state.get_cred_id()?;
+                let credential = state.credential.clone().ok_or(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Cannot get credential: Credential not found",
+                ))?;
+                Ok((cred_id, credential.into()))
+            }
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Cannot get credential: Credential Issuance is not finished yet",
+            )),
+        }
+    }
+
+    pub fn get_attributes(&self) -> VcxResult<String> {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => state.get_attributes(),
+            HolderV2FullState::OfferReceived(ref state) => state.get_attributes(),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Cannot get credential attributes: credential offer or credential must be receieved first",
+            )),
+        }
+    }
+
+    pub fn get_attachment(&self) -> VcxResult<String> {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => state.get_attachment(),
+            HolderV2FullState::OfferReceived(ref state) => state.get_attachment(),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Cannot get credential attachment: credential offer or credential must be receieved first",
+            )),
+        }
+    }
+
+    pub fn get_rev_reg_id(&self) -> VcxResult<String> {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => state.get_rev_reg_id(),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Cannot get rev reg id: credential exchange not finished yet",
+            )),
+        }
+    }
+
+    pub fn get_cred_id(&self) -> VcxResult<String> {
+        match self.state {
+            HolderV2FullState::Finished(ref state) => state.get_cred_id(),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Cannot get credential id: credential exchange not finished yet",
+            )),
+        }
+    }
+
+    pub fn get_offer(&self) -> VcxResult<OfferCredentialV2> {
+        match self.state {
+            HolderV2FullState::OfferReceived(ref state) => Ok(state.offer.clone()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Credential offer can only be obtained from OfferReceived state",
+            )),
+        }
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        Ok(self.thread_id.clone())
+    }
+
+    pub async fn is_revokable(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
+        match self.state {
+            HolderV2FullState::Initial(ref state) => state.is_revokable(),
+            HolderV2FullState::ProposalSent(_) => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Revocation information not available before a credential offer is received",
+            )),
+            HolderV2FullState::OfferReceived(ref state) => state.is_revokable(profile).await,
+            HolderV2FullState::RequestSent(ref state) => state.is_revokable(),
+            HolderV2FullState::Finished(ref state) => state.is_revokable(),
+        }
+    }
+
+    pub async fn is_revoked(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
+        if self.is_revokable(profile).await? {
+            let rev_reg_id = self.get_rev_reg_id()?;
+            let cred_id = self.get_cred_id()?;
+            let rev_id = get_cred_rev_id(profile, &cred_id).await?;
+            is_cred_revoked(profile, &rev_reg_id, &rev_id).await
+        } else {
+            Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Unable to check revocation status - this credential is not revokable",
+            ))
+        }
+    }
+
+    pub async fn delete_credential(&self, profile: &Arc<dyn Profile>) -> VcxResult<()> {
+        trace!("HolderV2::delete_credential");
+
+        let cred_id = self.get_cred_id()?;
+        Arc::clone(profile)
+            .inject_anoncreds()
+            .prover_delete_credential(&cred_id)
+            .await
+            .map_err(|err| err.into())
+    }
+}
+
+async fn _store_credential(
+    profile: &Arc<dyn Profile>,
+    credential: &IssueCredentialV2,
+    state: &RequestSentState,
+) -> VcxResult<(String, Option<String>)> {
+    let credential_json = get_attach_for_format(
+        &credential.content.formats,
+        &credential.content.credentials_attach,
+        IssueCredentialAttachmentFormatType::HyperledgerIndyCredential2_0,
+    )?;
+    store_credential(profile, &credential_json, &state.req_meta, &state.cred_def_json).await
+}
+
+async fn _make_credential_request(
+    profile: &Arc<dyn Profile>,
+    thread_id: &str,
+    my_pw_did: &str,
+    state: &OfferReceivedState,
+) -> VcxResult<(RequestCredentialV2, String, String)> {
+    let cred_offer = state.get_attachment()?;
+    trace!("HolderV2::_make_credential_request >>> cred_offer: {}", cred_offer);
+
+    let cred_def_id = parse_cred_def_id_from_cred_offer(&cred_offer)?;
+    let (req, req_meta, _cred_def_id, cred_def_json) =
+        create_credential_request(profile, &cred_def_id, my_pw_did, &cred_offer).await?;
+    Ok((build_credential_request_msg(req, thread_id), req_meta, cred_def_json))
+}
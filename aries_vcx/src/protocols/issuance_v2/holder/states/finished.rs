@@ -0,0 +1,100 @@
+use messages::msg_fields::protocols::cred_issuance_v2::issue_credential::{
+    IssueCredentialAttachmentFormatType, IssueCredentialV2,
+};
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::errors::error::prelude::*;
+use crate::handlers::util::{CredentialData, Status};
+use crate::protocols::common::get_attach_for_format;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FinishedHolderState {
+    pub cred_id: Option<String>,
+    pub credential: Option<IssueCredentialV2>,
+    pub status: Status,
+    pub rev_reg_def_json: Option<String>,
+}
+
+impl FinishedHolderState {
+    pub fn get_attributes(&self) -> VcxResult<String> {
+        let attach = self.get_attachment()?;
+        let cred_data: CredentialData = serde_json::from_str(&attach).map_err(|err| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidJson,
+                format!("Cannot deserialize {:?}, into CredentialData, err: {:?}", attach, err),
+            )
+        })?;
+
+        let values = cred_data.values.as_object().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("Cannot convert {:?} into object", attach),
+        ))?;
+        let mut new_map = serde_json::map::Map::new();
+        for (key, value) in values {
+            let val = value["raw"]
+                .as_str()
+                .ok_or(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidJson,
+                    "Missing raw encoding on credential value",
+                ))?
+                .into();
+            new_map.insert(key.clone(), val);
+        }
+        Ok(serde_json::Value::Object(new_map).to_string())
+    }
+
+    /// Returns the `hlindy/cred@v2.0` credential.
+    pub fn get_attachment(&self) -> VcxResult<String> {
+        let credential = self.credential.as_ref().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "No credential found",
+        ))?;
+
+        get_attach_for_format(
+            &credential.content.formats,
+            &credential.content.credentials_attach,
+            IssueCredentialAttachmentFormatType::HyperledgerIndyCredential2_0,
+        )
+    }
+
+    pub fn get_rev_reg_id(&self) -> VcxResult<String> {
+        let rev_reg_def_json = self.rev_reg_def_json.as_ref().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "No revocation registry definition found - is this credential revokable?",
+        ))?;
+        let rev_reg_def: serde_json::Value = serde_json::from_str(rev_reg_def_json).map_err(|err| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::SerializationError,
+                format!("Cannot deserialize {:?} into Value, err: {:?}", rev_reg_def_json, err),
+            )
+        })?;
+        let rev_reg_def_id = rev_reg_def["id"].as_str().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!("The field 'id' not found on rev_reg_def_json: {:?}", rev_reg_def_json),
+        ))?;
+        Ok(rev_reg_def_id.to_string())
+    }
+
+    pub fn get_cred_id(&self) -> VcxResult<String> {
+        self.cred_id.clone().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            "The field 'cred_id' not found on FinishedHolderState".to_string(),
+        ))
+    }
+
+    pub fn is_revokable(&self) -> VcxResult<bool> {
+        Ok(self.rev_reg_def_json.is_some())
+    }
+}
+
+impl From<ProblemReport> for FinishedHolderState {
+    fn from(problem_report: ProblemReport) -> Self {
+        trace!("SM is now in Finished state");
+        FinishedHolderState {
+            cred_id: None,
+            credential: None,
+            status: Status::Failed(problem_report),
+            rev_reg_def_json: None,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::{
+    OfferCredentialAttachmentFormatType, OfferCredentialV2,
+};
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::protocols::common::get_attach_for_format;
+use crate::protocols::issuance::holder::state_machine::parse_cred_def_id_from_cred_offer;
+use crate::protocols::issuance::is_cred_def_revokable;
+use crate::protocols::issuance_v2::holder::states::request_sent::RequestSentState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OfferReceivedState {
+    pub offer: OfferCredentialV2,
+}
+
+impl From<(OfferReceivedState, String, String)> for RequestSentState {
+    fn from((_state, req_meta, cred_def_json): (OfferReceivedState, String, String)) -> Self {
+        trace!("SM is now in RequestSent state");
+        RequestSentState {
+            req_meta,
+            cred_def_json,
+        }
+    }
+}
+
+impl OfferReceivedState {
+    pub fn new(offer: OfferCredentialV2) -> Self {
+        OfferReceivedState { offer }
+    }
+
+    pub fn get_attributes(&self) -> VcxResult<String> {
+        let mut new_map = serde_json::map::Map::new();
+        self.offer
+            .content
+            .credential_preview
+            .attributes
+            .iter()
+            .for_each(|attribute| {
+                new_map.insert(
+                    attribute.name.clone(),
+                    serde_json::Value::String(attribute.value.clone()),
+                );
+            });
+        Ok(serde_json::Value::Object(new_map).to_string())
+    }
+
+    pub async fn is_revokable(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
+        let offer = self.get_attachment()?;
+        let cred_def_id = parse_cred_def_id_from_cred_offer(&offer)?;
+        is_cred_def_revokable(profile, &cred_def_id).await
+    }
+
+    /// Returns the `hlindy/cred-abstract@v2.0` credential offer.
+    pub fn get_attachment(&self) -> VcxResult<String> {
+        get_attach_for_format(
+            &self.offer.content.formats,
+            &self.offer.content.offers_attach,
+            OfferCredentialAttachmentFormatType::HyperledgerIndyCredentialAbstract2_0,
+        )
+    }
+}
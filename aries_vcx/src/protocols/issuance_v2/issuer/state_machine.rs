@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use aries_vcx_core::anoncreds::types::{CredentialOffer, CredentialRequest, CredentialValues};
+use chrono::Utc;
+use messages::decorators::please_ack::PleaseAck;
+use messages::decorators::thread::Thread;
+use messages::decorators::timing::Timing;
+use messages::msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier;
+use messages::msg_fields::protocols::cred_issuance_v2::ack::AckCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::issue_credential::{
+    IssueCredentialAttachmentFormatType, IssueCredentialV2, IssueCredentialV2Content, IssueCredentialV2Decorators,
+};
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::{
+    OfferCredentialAttachmentFormatType, OfferCredentialV2, OfferCredentialV2Content, OfferCredentialV2Decorators,
+};
+use messages::msg_fields::protocols::cred_issuance_v2::propose_credential::ProposeCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::{
+    RequestCredentialAttachmentFormatType, RequestCredentialV2,
+};
+use messages::msg_fields::protocols::cred_issuance_v2::{CredentialIssuanceV2, CredentialPreviewV2};
+use messages::msg_fields::protocols::notification::Notification;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+use messages::AriesMessage;
+use shared_vcx::maybe_known::MaybeKnown;
+use uuid::Uuid;
+
+use crate::common::credentials::encoding::encode_attributes;
+use crate::common::credentials::is_cred_revoked;
+use crate::core::profile::profile::Profile;
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
+use crate::handlers::util::{make_attach_from_str, matches_opt_thread_id, matches_thread_id, OfferInfo, Status};
+use crate::protocols::common::{build_problem_report_msg, get_attach_for_format};
+use crate::protocols::issuance::issuer::state_machine::{IssuerState, RevocationInfoV1};
+use crate::protocols::issuance_v2::actions::CredentialIssuanceV2Action;
+use crate::protocols::issuance_v2::issuer::states::credential_sent::CredentialSentState;
+use crate::protocols::issuance_v2::issuer::states::finished::FinishedState;
+use crate::protocols::issuance_v2::issuer::states::initial::InitialIssuerState;
+use crate::protocols::issuance_v2::issuer::states::offer_sent::OfferSentState;
+use crate::protocols::issuance_v2::issuer::states::offer_set::OfferSetState;
+use crate::protocols::issuance_v2::issuer::states::proposal_received::ProposalReceivedState;
+use crate::protocols::issuance_v2::issuer::states::requested_received::RequestReceivedState;
+use crate::protocols::issuance_v2::verify_thread_id;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum IssuerV2FullState {
+    Initial(InitialIssuerState),
+    OfferSet(OfferSetState),
+    ProposalReceived(ProposalReceivedState),
+    OfferSent(OfferSentState),
+    RequestReceived(RequestReceivedState),
+    CredentialSent(CredentialSentState),
+    Finished(FinishedState),
+}
+
+impl Display for IssuerV2FullState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+        match *self {
+            IssuerV2FullState::Initial(_) => f.write_str("Initial"),
+            IssuerV2FullState::OfferSet(_) => f.write_str("OfferSet"),
+            IssuerV2FullState::ProposalReceived(_) => f.write_str("ProposalReceived"),
+            IssuerV2FullState::OfferSent(_) => f.write_str("OfferSent"),
+            IssuerV2FullState::RequestReceived(_) => f.write_str("RequestReceived"),
+            IssuerV2FullState::CredentialSent(_) => f.write_str("CredentialSent"),
+            IssuerV2FullState::Finished(_) => f.write_str("Finished"),
+        }
+    }
+}
+
+impl Default for IssuerV2FullState {
+    fn default() -> Self {
+        Self::Initial(InitialIssuerState::default())
+    }
+}
+
+/// Issuer side of the issue credential 2.0 protocol, supporting the `hlindy` attachment formats.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct IssuerV2SM {
+    source_id: String,
+    thread_id: String,
+    state: IssuerV2FullState,
+}
+
+fn build_credential_message(libindy_credential: String, thread_id: &str) -> IssueCredentialV2 {
+    let attach_id = Uuid::new_v4().to_string();
+    let formats = vec![AttachmentFormatSpecifier::new(
+        attach_id.clone(),
+        MaybeKnown::Known(IssueCredentialAttachmentFormatType::HyperledgerIndyCredential2_0),
+    )];
+    let content = IssueCredentialV2Content::new(formats, vec![make_attach_from_str!(&libindy_credential, attach_id)]);
+
+    let mut decorators = IssueCredentialV2Decorators::new(Thread::new(thread_id.to_owned()));
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.timing = Some(timing);
+    decorators.please_ack = Some(PleaseAck::new(vec![]));
+
+    IssueCredentialV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+fn build_credential_offer(
+    thread_id: &str,
+    credential_offer: &str,
+    credential_preview: CredentialPreviewV2,
+    comment: Option<String>,
+) -> OfferCredentialV2 {
+    let attach_id = Uuid::new_v4().to_string();
+    let formats = vec![AttachmentFormatSpecifier::new(
+        attach_id.clone(),
+        MaybeKnown::Known(OfferCredentialAttachmentFormatType::HyperledgerIndyCredentialAbstract2_0),
+    )];
+    let mut content = OfferCredentialV2Content::new(
+        credential_preview,
+        formats,
+        vec![make_attach_from_str!(&credential_offer, attach_id)],
+    );
+    content.comment = comment;
+
+    let mut decorators = OfferCredentialV2Decorators::default();
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.thread = Some(Thread::new(thread_id.to_owned()));
+    decorators.timing = Some(timing);
+
+    OfferCredentialV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+impl IssuerV2SM {
+    pub fn new(source_id: &str) -> Self {
+        Self {
+            source_id: source_id.to_string(),
+            thread_id: Uuid::new_v4().to_string(),
+            state: IssuerV2FullState::Initial(InitialIssuerState {}),
+        }
+    }
+
+    pub fn from_proposal(source_id: &str, credential_proposal: &ProposeCredentialV2) -> Self {
+        Self {
+            thread_id: credential_proposal.id.clone(),
+            source_id: source_id.to_string(),
+            state: IssuerV2FullState::ProposalReceived(ProposalReceivedState::new(credential_proposal.clone())),
+        }
+    }
+
+    pub fn get_source_id(&self) -> String {
+        self.source_id.clone()
+    }
+
+    pub fn get_revocation_info(&self) -> Option<RevocationInfoV1> {
+        match &self.state {
+            IssuerV2FullState::CredentialSent(state) => state.revocation_info_v1.clone(),
+            IssuerV2FullState::Finished(state) => state.revocation_info_v1.clone(),
+            _ => None,
+        }
+    }
+
+    pub fn get_rev_id(&self) -> VcxResult<String> {
+        self.get_revocation_info()
+            .and_then(|revocation_info| revocation_info.cred_rev_id)
+            .ok_or(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "No revocation info found - is this credential revokable?",
+            ))
+    }
+
+    pub fn get_rev_reg_id(&self) -> VcxResult<String> {
+        let rev_reg_id = match &self.state {
+            IssuerV2FullState::Initial(_) | IssuerV2FullState::ProposalReceived(_) => None,
+            IssuerV2FullState::OfferSet(state) => state.offer_info.rev_reg_id.clone(),
+            IssuerV2FullState::OfferSent(state) => state.offer_info.rev_reg_id.clone(),
+            IssuerV2FullState::RequestReceived(state) => state.offer_info.rev_reg_id.clone(),
+            IssuerV2FullState::CredentialSent(_) | IssuerV2FullState::Finished(_) => self
+                .get_revocation_info()
+                .and_then(|revocation_info| revocation_info.rev_reg_id),
+        };
+        rev_reg_id.ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "No revocation registry id found - is this credential revokable?",
+        ))
+    }
+
+    pub fn is_revokable(&self) -> bool {
+        self.get_revocation_info()
+            .map(|revocation_info| revocation_info.cred_rev_id.is_some())
+            .unwrap_or(false)
+    }
+
+    pub async fn is_revoked(&self, profile: &Arc<dyn Profile>) -> VcxResult<bool> {
+        if self.is_revokable() {
+            let rev_reg_id = self.get_rev_reg_id()?;
+            let rev_id = self.get_rev_id()?;
+            is_cred_revoked(profile, &rev_reg_id, &rev_id).await
+        } else {
+            Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Unable to check revocation status - this credential is not revokable",
+            ))
+        }
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        trace!(
+            "IssuerV2SM::find_message_to_handle >>> messages: {:?}, state: {:?}",
+            messages,
+            self.state
+        );
+
+        for (uid, message) in messages {
+            match self.state {
+                IssuerV2FullState::Initial(_) => {
+                    if let AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProposeCredential(_)) = &message {
+                        return Some((uid, message));
+                    }
+                }
+                IssuerV2FullState::OfferSent(_) => match &message {
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::RequestCredential(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProposeCredential(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(msg) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                IssuerV2FullState::CredentialSent(_) => match &message {
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::Ack(msg)) => {
+                        if matches_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::Ack(msg)) => {
+                        if matches_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::CredentialIssuanceV2(CredentialIssuanceV2::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(msg) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            };
+        }
+
+        None
+    }
+
+    pub fn get_state(&self) -> IssuerState {
+        match self.state {
+            IssuerV2FullState::Initial(_) => IssuerState::Initial,
+            IssuerV2FullState::ProposalReceived(_) => IssuerState::ProposalReceived,
+            IssuerV2FullState::OfferSet(_) => IssuerState::OfferSet,
+            IssuerV2FullState::OfferSent(_) => IssuerState::OfferSent,
+            IssuerV2FullState::RequestReceived(_) => IssuerState::RequestReceived,
+            IssuerV2FullState::CredentialSent(_) => IssuerState::CredentialSent,
+            IssuerV2FullState::Finished(ref status) => match status.status {
+                Status::Success => IssuerState::Finished,
+                _ => IssuerState::Failed,
+            },
+        }
+    }
+
+    pub fn get_proposal(&self) -> VcxResult<ProposeCredentialV2> {
+        match &self.state {
+            IssuerV2FullState::ProposalReceived(state) => Ok(state.credential_proposal.clone()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Proposal is only available in ProposalReceived state",
+            )),
+        }
+    }
+
+    pub fn build_credential_offer_msg(
+        self,
+        credential_offer: &str,
+        credential_preview: CredentialPreviewV2,
+        comment: Option<String>,
+        offer_info: &OfferInfo,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            IssuerV2FullState::Initial(_) | IssuerV2FullState::OfferSet(_) | IssuerV2FullState::ProposalReceived(_) => {
+                let offer = build_credential_offer(&self.thread_id, credential_offer, credential_preview, comment);
+                IssuerV2FullState::OfferSet(OfferSetState::new(offer, offer_info.clone()))
+            }
+            state => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    format!("Can not set_offer in current state {}.", state),
+                ));
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn get_credential_offer_msg(&self) -> VcxResult<OfferCredentialV2> {
+        match &self.state {
+            IssuerV2FullState::OfferSet(state) => Ok(state.offer.clone()),
+            IssuerV2FullState::OfferSent(state) => Ok(state.offer.clone()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                format!("Can not get_credential_offer in current state {}.", self.state),
+            )),
+        }
+    }
+
+    pub fn mark_credential_offer_msg_sent(self) -> VcxResult<Self> {
+        let state = match self.state {
+            IssuerV2FullState::OfferSet(state) => IssuerV2FullState::OfferSent(state.into()),
+            IssuerV2FullState::OfferSent(state) => IssuerV2FullState::OfferSent(state),
+            state => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    format!("Can not mark_as_offer_sent in current state {}.", state),
+                ))
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_proposal(self, proposal: ProposeCredentialV2) -> VcxResult<Self> {
+        let (state, thread_id) = match self.state {
+            IssuerV2FullState::Initial(_) => {
+                let thread_id = proposal.id.clone();
+                let state = IssuerV2FullState::ProposalReceived(ProposalReceivedState::new(proposal));
+                (state, thread_id)
+            }
+            IssuerV2FullState::OfferSent(_) => {
+                verify_thread_id(
+                    &self.thread_id,
+                    &CredentialIssuanceV2Action::CredentialProposal(proposal.clone()),
+                )?;
+                let state = IssuerV2FullState::ProposalReceived(ProposalReceivedState::new(proposal));
+                (state, self.thread_id.clone())
+            }
+            s => {
+                warn!("Unable to receive credential proposal in state {}", s);
+                (s, self.thread_id.clone())
+            }
+        };
+        Ok(Self {
+            state,
+            thread_id,
+            ..self
+        })
+    }
+
+    pub async fn send_credential_offer(self, send_message: SendClosure) -> VcxResult<Self> {
+        match self.state {
+            IssuerV2FullState::OfferSet(ref state_data) => {
+                send_message(state_data.offer.clone().into()).await?;
+                self.mark_credential_offer_msg_sent()
+            }
+            _ => Err(AriesVcxError::from_msg(AriesVcxErrorKind::NotReady, "Invalid action")),
+        }
+    }
+
+    pub fn receive_request(self, request: RequestCredentialV2) -> VcxResult<Self> {
+        verify_thread_id(
+            &self.thread_id,
+            &CredentialIssuanceV2Action::CredentialRequest(request.clone()),
+        )?;
+        let state = match self.state {
+            IssuerV2FullState::OfferSent(state_data) => {
+                IssuerV2FullState::RequestReceived((state_data, request).into())
+            }
+            s => {
+                warn!("Unable to receive credential request in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn send_credential(self, profile: &Arc<dyn Profile>, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            IssuerV2FullState::RequestReceived(state_data) => {
+                match _create_credential(profile, &state_data, &self.thread_id).await {
+                    Ok((credential_msg, cred_rev_id)) => {
+                        send_message(credential_msg.into()).await?;
+                        IssuerV2FullState::CredentialSent((state_data, cred_rev_id).into())
+                    }
+                    Err(err) => {
+                        let problem_report = build_problem_report_msg(Some(err.to_string()), &self.thread_id);
+                        error!(
+                            "Failed to create credential, sending problem report {:?}",
+                            problem_report
+                        );
+                        send_message(problem_report.clone().into()).await?;
+                        IssuerV2FullState::Finished((state_data, problem_report).into())
+                    }
+                }
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(AriesVcxErrorKind::NotReady, "Invalid action"));
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_ack(self, ack: AckCredentialV2) -> VcxResult<Self> {
+        verify_thread_id(&self.thread_id, &CredentialIssuanceV2Action::CredentialAck(ack))?;
+        let state = match self.state {
+            IssuerV2FullState::CredentialSent(state_data) => IssuerV2FullState::Finished(state_data.into()),
+            s => {
+                warn!("Unable to receive credential ack in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_problem_report(self, problem_report: ProblemReport) -> VcxResult<Self> {
+        verify_thread_id(
+            &self.thread_id,
+            &CredentialIssuanceV2Action::ProblemReport(problem_report.clone()),
+        )?;
+        let state = match self.state {
+            IssuerV2FullState::OfferSent(state_data) => {
+                IssuerV2FullState::Finished((state_data, problem_report).into())
+            }
+            IssuerV2FullState::CredentialSent(state_data) => IssuerV2FullState::Finished(FinishedState {
+                revocation_info_v1: state_data.revocation_info_v1,
+                status: Status::Failed(problem_report),
+            }),
+            s => {
+                warn!("Unable to receive problem report in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn handle_message(
+        self,
+        profile: &Arc<dyn Profile>,
+        cim: CredentialIssuanceV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<Self> {
+        trace!("IssuerV2SM::handle_message >>> cim: {:?}, state: {:?}", cim, self.state);
+        verify_thread_id(&self.thread_id, &cim)?;
+        let issuer_sm = match cim {
+            CredentialIssuanceV2Action::CredentialProposal(proposal) => self.receive_proposal(proposal)?,
+            CredentialIssuanceV2Action::CredentialRequest(request) => self.receive_request(request)?,
+            CredentialIssuanceV2Action::CredentialSend() => {
+                let send_message = send_message.ok_or(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Attempted to call undefined send_message callback",
+                ))?;
+                self.send_credential(profile, send_message).await?
+            }
+            CredentialIssuanceV2Action::CredentialAck(ack) => self.receive_ack(ack)?,
+            CredentialIssuanceV2Action::ProblemReport(problem_report) => self.receive_problem_report(problem_report)?,
+            _ => self,
+        };
+        Ok(issuer_sm)
+    }
+
+    pub fn credential_status(&self) -> u32 {
+        match self.state {
+            IssuerV2FullState::Finished(ref state) => state.status.code(),
+            _ => Status::Undefined.code(),
+        }
+    }
+
+    pub fn is_terminal_state(&self) -> bool {
+        matches!(self.state, IssuerV2FullState::Finished(_))
+    }
+
+    pub fn thread_id(&self) -> VcxResult<String> {
+        Ok(self.thread_id.clone())
+    }
+}
+
+async fn _create_credential(
+    profile: &Arc<dyn Profile>,
+    state: &RequestReceivedState,
+    thread_id: &str,
+) -> VcxResult<(IssueCredentialV2, Option<String>)> {
+    let anoncreds = Arc::clone(profile).inject_anoncreds();
+
+    let offer = get_attach_for_format(
+        &state.offer.content.formats,
+        &state.offer.content.offers_attach,
+        OfferCredentialAttachmentFormatType::HyperledgerIndyCredentialAbstract2_0,
+    )?;
+    let request = get_attach_for_format(
+        &state.request.content.formats,
+        &state.request.content.requests_attach,
+        RequestCredentialAttachmentFormatType::HyperledgerIndyCredentialRequest2_0,
+    )?;
+
+    let offer: CredentialOffer = serde_json::from_str(&offer)?;
+    let request: CredentialRequest = serde_json::from_str(&request)?;
+    let cred_data: CredentialValues = serde_json::from_str(&encode_attributes(&state.offer_info.credential_json)?)?;
+    let issued = anoncreds
+        .issuer_create_credential(
+            &offer,
+            &request,
+            &cred_data,
+            state.offer_info.rev_reg_id.clone(),
+            state.offer_info.tails_file.clone(),
+        )
+        .await?;
+    let credential = build_credential_message(serde_json::to_string(&issued.credential)?, thread_id);
+    Ok((credential, issued.cred_rev_id))
+}
@@ -0,0 +1,7 @@
+pub(super) mod credential_sent;
+pub(super) mod finished;
+pub(super) mod initial;
+pub(super) mod offer_sent;
+pub(super) mod offer_set;
+pub(super) mod proposal_received;
+pub(super) mod requested_received;
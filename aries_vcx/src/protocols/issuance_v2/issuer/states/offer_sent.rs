@@ -0,0 +1,39 @@
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::OfferCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::RequestCredentialV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::handlers::util::{OfferInfo, Status};
+use crate::protocols::issuance::issuer::state_machine::RevocationInfoV1;
+use crate::protocols::issuance_v2::issuer::states::finished::FinishedState;
+use crate::protocols::issuance_v2::issuer::states::requested_received::RequestReceivedState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OfferSentState {
+    pub offer: OfferCredentialV2,
+    pub offer_info: OfferInfo,
+}
+
+impl From<(OfferSentState, RequestCredentialV2)> for RequestReceivedState {
+    fn from((state, request): (OfferSentState, RequestCredentialV2)) -> Self {
+        trace!("SM is now in RequestReceived state");
+        RequestReceivedState {
+            offer: state.offer,
+            offer_info: state.offer_info,
+            request,
+        }
+    }
+}
+
+impl From<(OfferSentState, ProblemReport)> for FinishedState {
+    fn from((state, err): (OfferSentState, ProblemReport)) -> Self {
+        trace!("SM is now in Finished state");
+        FinishedState {
+            revocation_info_v1: Some(RevocationInfoV1 {
+                cred_rev_id: None,
+                rev_reg_id: state.offer_info.rev_reg_id,
+                tails_file: state.offer_info.tails_file,
+            }),
+            status: Status::Failed(err),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::OfferCredentialV2;
+
+use crate::handlers::util::OfferInfo;
+use crate::protocols::issuance_v2::issuer::states::offer_sent::OfferSentState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OfferSetState {
+    pub offer: OfferCredentialV2,
+    pub offer_info: OfferInfo,
+}
+
+impl OfferSetState {
+    pub fn new(offer: OfferCredentialV2, offer_info: OfferInfo) -> Self {
+        OfferSetState { offer, offer_info }
+    }
+}
+
+impl From<OfferSetState> for OfferSentState {
+    fn from(state: OfferSetState) -> Self {
+        trace!("SM is now in OfferSent state");
+        OfferSentState {
+            offer: state.offer,
+            offer_info: state.offer_info,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use messages::msg_fields::protocols::cred_issuance_v2::offer_credential::OfferCredentialV2;
+use messages::msg_fields::protocols::cred_issuance_v2::request_credential::RequestCredentialV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::handlers::util::{OfferInfo, Status};
+use crate::protocols::issuance::issuer::state_machine::RevocationInfoV1;
+use crate::protocols::issuance_v2::issuer::states::credential_sent::CredentialSentState;
+use crate::protocols::issuance_v2::issuer::states::finished::FinishedState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestReceivedState {
+    pub offer: OfferCredentialV2,
+    pub offer_info: OfferInfo,
+    pub request: RequestCredentialV2,
+}
+
+impl From<(RequestReceivedState, Option<String>)> for CredentialSentState {
+    fn from((state, cred_rev_id): (RequestReceivedState, Option<String>)) -> Self {
+        trace!("SM is now in CredentialSent state");
+        CredentialSentState {
+            revocation_info_v1: Some(RevocationInfoV1 {
+                cred_rev_id,
+                rev_reg_id: state.offer_info.rev_reg_id,
+                tails_file: state.offer_info.tails_file,
+            }),
+        }
+    }
+}
+
+impl From<(RequestReceivedState, ProblemReport)> for FinishedState {
+    fn from((state, err): (RequestReceivedState, ProblemReport)) -> Self {
+        trace!("SM is now in Finished state");
+        FinishedState {
+            revocation_info_v1: Some(RevocationInfoV1 {
+                cred_rev_id: None,
+                rev_reg_id: state.offer_info.rev_reg_id,
+                tails_file: state.offer_info.tails_file,
+            }),
+            status: Status::Failed(err),
+        }
+    }
+}
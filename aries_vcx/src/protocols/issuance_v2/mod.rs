@@ -0,0 +1,20 @@
+use crate::errors::error::prelude::*;
+use crate::global::settings;
+use crate::protocols::issuance_v2::actions::CredentialIssuanceV2Action;
+
+pub mod actions;
+pub mod holder;
+pub mod issuer;
+
+pub fn verify_thread_id(thread_id: &str, message: &CredentialIssuanceV2Action) -> VcxResult<()> {
+    if !settings::indy_mocks_enabled() && !message.thread_id_matches(thread_id) {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!(
+                "Cannot handle message {:?}: thread id does not match, expected {:?}",
+                message, thread_id
+            ),
+        ));
+    };
+    Ok(())
+}
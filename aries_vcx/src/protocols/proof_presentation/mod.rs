@@ -1,2 +1,81 @@
+use messages::msg_fields::protocols::present_proof::propose::ProposePresentation;
+use messages::msg_fields::protocols::present_proof::request::RequestPresentation;
+
 pub mod prover;
 pub mod verifier;
+
+/// A proposal or request exchanged while negotiating the presentation to make. Prover and verifier
+/// may go back and forth on the same thread until the prover answers a request with a presentation.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PresentationNegotiationRound {
+    Proposal(ProposePresentation),
+    Request(RequestPresentation),
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use messages::msg_fields::protocols::present_proof::propose::PresentationPreview;
+    use messages::AriesMessage;
+
+    use crate::common::proofs::proof_request::PresentationRequestData;
+    use crate::common::test_utils::mock_profile;
+    use crate::errors::error::VcxResult;
+    use crate::protocols::proof_presentation::prover::messages::ProverMessages;
+    use crate::protocols::proof_presentation::prover::state_machine::{ProverSM, ProverState};
+    use crate::protocols::proof_presentation::verifier::state_machine::{VerifierSM, VerifierState};
+    use crate::protocols::SendClosure;
+
+    use super::*;
+
+    pub fn _send_message() -> SendClosure {
+        Box::new(|_: AriesMessage| Box::pin(async { VcxResult::Ok(()) }))
+    }
+
+    fn _last_proposal(prover: &ProverSM) -> ProposePresentation {
+        match prover.get_negotiation_history().last() {
+            Some(PresentationNegotiationRound::Proposal(proposal)) => proposal.clone(),
+            round => panic!("Expected a proposal, got {:?}", round),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_presentation_negotiation_loop() {
+        let verifier = VerifierSM::from_request("verifier", &PresentationRequestData::default())
+            .unwrap()
+            .mark_presentation_request_msg_sent()
+            .unwrap();
+        let prover = ProverSM::from_request(verifier.presentation_request_msg().unwrap(), "prover".to_owned());
+
+        let prover = prover
+            .negotiate_presentation(PresentationPreview::new(vec![], vec![]), _send_message())
+            .await
+            .unwrap();
+        assert_eq!(ProverState::PresentationProposalSent, prover.get_state());
+
+        let verifier = verifier
+            .receive_presentation_proposal(_last_proposal(&prover))
+            .unwrap()
+            .set_request(&PresentationRequestData::default(), None)
+            .unwrap()
+            .mark_presentation_request_msg_sent()
+            .unwrap();
+        assert_eq!(VerifierState::PresentationRequestSent, verifier.get_state());
+
+        let request = verifier.presentation_request_msg().unwrap();
+        assert_eq!(verifier.thread_id(), request.decorators.thread.as_ref().unwrap().thid);
+        let prover = prover
+            .step(
+                &mock_profile(),
+                ProverMessages::PresentationRequestReceived(request),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ProverState::PresentationRequestReceived, prover.get_state());
+
+        assert_eq!(verifier.thread_id(), prover.get_thread_id().unwrap());
+        assert_eq!(3, prover.get_negotiation_history().len());
+        assert_eq!(prover.get_negotiation_history(), verifier.get_negotiation_history());
+    }
+}
@@ -35,6 +35,7 @@ impl ProverMessages {
         match self {
             Self::SetPresentation(msg) => matches_thread_id!(msg, thread_id),
             Self::PresentationRejectReceived(msg) => matches_opt_thread_id!(msg, thread_id),
+            Self::PresentationRequestReceived(msg) => matches_opt_thread_id!(msg, thread_id),
             Self::PresentationAckReceived(msg) => matches_thread_id!(msg, thread_id),
             _ => true,
         }
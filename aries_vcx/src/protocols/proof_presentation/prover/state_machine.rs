@@ -17,6 +17,7 @@ use crate::protocols::proof_presentation::prover::states::presentation_proposal_
 use crate::protocols::proof_presentation::prover::states::presentation_request_received::PresentationRequestReceived;
 use crate::protocols::proof_presentation::prover::states::presentation_sent::PresentationSentState;
 use crate::protocols::proof_presentation::prover::verify_thread_id;
+use crate::protocols::proof_presentation::PresentationNegotiationRound;
 use crate::protocols::SendClosure;
 
 use chrono::Utc;
@@ -43,6 +44,8 @@ pub struct ProverSM {
     source_id: String,
     thread_id: String,
     state: ProverFullState,
+    #[serde(default)]
+    negotiation_history: Vec<PresentationNegotiationRound>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -109,22 +112,34 @@ impl ProverSM {
             source_id,
             thread_id: Uuid::new_v4().to_string(),
             state: ProverFullState::Initial(InitialProverState {}),
+            negotiation_history: Vec::new(),
         }
     }
 
     pub fn from_request(presentation_request: RequestPresentation, source_id: String) -> ProverSM {
+        let thread_id = match presentation_request.decorators.thread {
+            Some(ref thread) => thread.thid.clone(),
+            None => presentation_request.id.clone(),
+        };
         ProverSM {
             source_id,
-            thread_id: presentation_request.id.clone(),
+            thread_id,
+            negotiation_history: vec![PresentationNegotiationRound::Request(presentation_request.clone())],
             state: ProverFullState::PresentationRequestReceived(PresentationRequestReceived { presentation_request }),
         }
     }
 
+    /// Returns the proposals and requests exchanged on the thread so far, oldest first.
+    pub fn get_negotiation_history(&self) -> &[PresentationNegotiationRound] {
+        &self.negotiation_history
+    }
+
     pub async fn send_presentation_proposal(
         self,
         proposal_data: PresentationProposalData,
         send_message: SendClosure,
     ) -> VcxResult<Self> {
+        let mut negotiation_history = self.negotiation_history;
         let state = match self.state {
             ProverFullState::Initial(_) => {
                 let id = self.thread_id.clone();
@@ -137,6 +152,7 @@ impl ProverSM {
                 let proposal = ProposePresentation::with_decorators(id, content, decorators);
 
                 send_message(proposal.clone().into()).await?;
+                negotiation_history.push(PresentationNegotiationRound::Proposal(proposal.clone()));
                 ProverFullState::PresentationProposalSent(PresentationProposalSent::new(proposal))
             }
             ProverFullState::PresentationRequestReceived(_) => {
@@ -151,6 +167,7 @@ impl ProverSM {
                 let proposal = ProposePresentation::with_decorators(id, content, decorators);
 
                 send_message(proposal.clone().into()).await?;
+                negotiation_history.push(PresentationNegotiationRound::Proposal(proposal.clone()));
                 ProverFullState::PresentationProposalSent(PresentationProposalSent::new(proposal))
             }
             s => {
@@ -158,7 +175,11 @@ impl ProverSM {
                 s
             }
         };
-        Ok(Self { state, ..self })
+        Ok(Self {
+            state,
+            negotiation_history,
+            ..self
+        })
     }
 
     pub async fn decline_presentation_request(self, reason: String, send_message: SendClosure) -> VcxResult<Self> {
@@ -186,21 +207,24 @@ impl ProverSM {
         presentation_preview: PresentationPreview,
         send_message: SendClosure,
     ) -> VcxResult<Self> {
+        let mut negotiation_history = self.negotiation_history;
         let state = match self.state {
-            ProverFullState::PresentationRequestReceived(state) => {
-                Self::_handle_presentation_proposal(send_message, presentation_preview, &self.thread_id).await?;
-                ProverFullState::Finished(state.into())
-            }
-            ProverFullState::PresentationPrepared(state) => {
-                Self::_handle_presentation_proposal(send_message, presentation_preview, &self.thread_id).await?;
-                ProverFullState::Finished(state.into())
+            ProverFullState::PresentationRequestReceived(_) | ProverFullState::PresentationPrepared(_) => {
+                let proposal =
+                    Self::_handle_presentation_proposal(send_message, presentation_preview, &self.thread_id).await?;
+                negotiation_history.push(PresentationNegotiationRound::Proposal(proposal.clone()));
+                ProverFullState::PresentationProposalSent(PresentationProposalSent::new(proposal))
             }
             s => {
                 warn!("Unable to send handle presentation proposal in state {}", s);
                 s
             }
         };
-        Ok(Self { state, ..self })
+        Ok(Self {
+            state,
+            negotiation_history,
+            ..self
+        })
     }
 
     pub async fn generate_presentation(
@@ -362,9 +386,15 @@ impl ProverSM {
             ProverFullState::PresentationProposalSent(_) => {
                 match message {
                     ProverMessages::PresentationRequestReceived(request) => {
+                        let mut negotiation_history = self.negotiation_history;
+                        negotiation_history.push(PresentationNegotiationRound::Request(request.clone()));
                         let state =
                             ProverFullState::PresentationRequestReceived(PresentationRequestReceived::new(request));
-                        ProverSM { state, ..self }
+                        ProverSM {
+                            state,
+                            negotiation_history,
+                            ..self
+                        }
                     }
                     // TODO: Perhaps use a different message type?
                     ProverMessages::PresentationRejectReceived(problem_report) => {
@@ -488,7 +518,7 @@ impl ProverSM {
         send_message: SendClosure,
         preview: PresentationPreview,
         thread_id: &str,
-    ) -> VcxResult<()> {
+    ) -> VcxResult<ProposePresentation> {
         let id = Uuid::new_v4().to_string();
         let content = ProposePresentationContent::new(preview);
         let mut decorators = ProposePresentationDecorators::default();
@@ -499,7 +529,8 @@ impl ProverSM {
         decorators.timing = Some(timing);
 
         let proposal = ProposePresentation::with_decorators(id, content, decorators);
-        send_message(proposal.into()).await
+        send_message(proposal.clone().into()).await?;
+        Ok(proposal)
     }
 
     pub fn source_id(&self) -> String {
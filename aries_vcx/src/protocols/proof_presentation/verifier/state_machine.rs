@@ -15,6 +15,7 @@ use crate::protocols::proof_presentation::verifier::states::presentation_request
 use crate::protocols::proof_presentation::verifier::states::presentation_request_set::PresentationRequestSetState;
 use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
 use crate::protocols::proof_presentation::verifier::verify_thread_id;
+use crate::protocols::proof_presentation::PresentationNegotiationRound;
 use crate::protocols::SendClosure;
 use chrono::Utc;
 use messages::decorators::thread::Thread;
@@ -39,6 +40,8 @@ pub struct VerifierSM {
     source_id: String,
     thread_id: String,
     state: VerifierFullState,
+    #[serde(default)]
+    negotiation_history: Vec<PresentationNegotiationRound>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -88,12 +91,18 @@ fn build_verification_ack(thread_id: &str) -> AckPresentation {
     AckPresentation::with_decorators(Uuid::new_v4().to_string(), content, decorators)
 }
 
+/// The first request of a thread starts it, so its id is the thread id. Counter-requests answering
+/// a proposal get their own id and refer to the thread instead.
 fn build_starting_presentation_request(
     thread_id: &str,
+    starts_thread: bool,
     request_data: &PresentationRequestData,
     comment: Option<String>,
 ) -> VcxResult<RequestPresentation> {
-    let id = thread_id.to_owned();
+    let id = match starts_thread {
+        true => thread_id.to_owned(),
+        false => Uuid::new_v4().to_string(),
+    };
 
     let mut content = RequestPresentationContent::new(vec![make_attach_from_str!(
         &json!(request_data).to_string(),
@@ -102,6 +111,9 @@ fn build_starting_presentation_request(
     content.comment = comment;
 
     let mut decorators = RequestPresentationDecorators::default();
+    if !starts_thread {
+        decorators.thread = Some(Thread::new(thread_id.to_owned()));
+    }
     let mut timing = Timing::default();
     timing.out_time = Some(Utc::now());
     decorators.timing = Some(timing);
@@ -115,6 +127,7 @@ impl VerifierSM {
             thread_id: String::new(),
             source_id: source_id.to_string(),
             state: VerifierFullState::Initial(InitialVerifierState {}),
+            negotiation_history: Vec::new(),
         }
     }
 
@@ -124,31 +137,44 @@ impl VerifierSM {
             source_id: source_id.to_string(),
             thread_id: Uuid::new_v4().to_string(),
             state: VerifierFullState::Initial(InitialVerifierState {}),
+            negotiation_history: Vec::new(),
         };
         sm.set_request(presentation_request_data, None)
     }
 
     pub fn from_proposal(source_id: &str, presentation_proposal: &ProposePresentation) -> Self {
+        let thread_id = match presentation_proposal.decorators.thread {
+            Some(ref thread) => thread.thid.clone(),
+            None => presentation_proposal.id.clone(),
+        };
         Self {
             source_id: source_id.to_string(),
-            thread_id: presentation_proposal.id.clone(),
+            thread_id,
             state: VerifierFullState::PresentationProposalReceived(PresentationProposalReceivedState::new(
                 presentation_proposal.clone(),
             )),
+            negotiation_history: vec![PresentationNegotiationRound::Proposal(presentation_proposal.clone())],
         }
     }
 
+    /// Returns the proposals and requests exchanged on the thread so far, oldest first.
+    pub fn get_negotiation_history(&self) -> &[PresentationNegotiationRound] {
+        &self.negotiation_history
+    }
+
     pub fn receive_presentation_proposal(self, proposal: ProposePresentation) -> VcxResult<Self> {
         verify_thread_id(
             &self.thread_id,
             &VerifierMessages::PresentationProposalReceived(proposal.clone()),
         )?;
+        let mut negotiation_history = self.negotiation_history;
         let (state, thread_id) = match self.state {
             VerifierFullState::Initial(_) => {
                 let thread_id = match proposal.decorators.thread {
                     Some(ref thread) => thread.thid.clone(),
                     None => proposal.id.clone(),
                 };
+                negotiation_history.push(PresentationNegotiationRound::Proposal(proposal.clone()));
                 (
                     VerifierFullState::PresentationProposalReceived(PresentationProposalReceivedState::new(proposal)),
                     thread_id,
@@ -159,6 +185,7 @@ impl VerifierSM {
                     &self.thread_id,
                     &VerifierMessages::PresentationProposalReceived(proposal.clone()),
                 )?;
+                negotiation_history.push(PresentationNegotiationRound::Proposal(proposal.clone()));
                 (
                     VerifierFullState::PresentationProposalReceived(PresentationProposalReceivedState::new(proposal)),
                     self.thread_id.clone(),
@@ -172,6 +199,7 @@ impl VerifierSM {
         Ok(Self {
             state,
             thread_id,
+            negotiation_history,
             ..self
         })
     }
@@ -330,16 +358,16 @@ impl VerifierSM {
     }
 
     pub fn set_request(self, request_data: &PresentationRequestData, comment: Option<String>) -> VcxResult<Self> {
-        let Self {
-            source_id,
-            thread_id,
-            state,
-        } = self;
-        let state = match state {
+        let state = match self.state {
             VerifierFullState::Initial(_)
             | VerifierFullState::PresentationRequestSet(_)
             | VerifierFullState::PresentationProposalReceived(_) => {
-                let presentation_request = build_starting_presentation_request(&thread_id, request_data, comment)?;
+                let presentation_request = build_starting_presentation_request(
+                    &self.thread_id,
+                    self.negotiation_history.is_empty(),
+                    request_data,
+                    comment,
+                )?;
                 VerifierFullState::PresentationRequestSet(PresentationRequestSetState::new(presentation_request))
             }
             _ => {
@@ -349,21 +377,16 @@ impl VerifierSM {
                 ));
             }
         };
-        Ok(Self {
-            source_id,
-            state,
-            thread_id,
-        })
+        Ok(Self { state, ..self })
     }
 
     pub fn mark_presentation_request_msg_sent(self) -> VcxResult<Self> {
-        let Self {
-            state,
-            source_id,
-            thread_id,
-        } = self;
-        let state = match state {
+        let mut negotiation_history = self.negotiation_history;
+        let state = match self.state {
             VerifierFullState::PresentationRequestSet(state) => {
+                negotiation_history.push(PresentationNegotiationRound::Request(
+                    state.presentation_request.clone(),
+                ));
                 VerifierFullState::PresentationRequestSent(state.into())
             }
             VerifierFullState::PresentationRequestSent(state) => VerifierFullState::PresentationRequestSent(state),
@@ -375,9 +398,9 @@ impl VerifierSM {
             }
         };
         Ok(Self {
-            source_id,
-            thread_id,
             state,
+            negotiation_history,
+            ..self
         })
     }
 
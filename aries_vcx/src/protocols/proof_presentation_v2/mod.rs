@@ -0,0 +1,20 @@
+use crate::errors::error::prelude::*;
+use crate::global::settings;
+use crate::protocols::proof_presentation_v2::actions::PresentProofV2Action;
+
+pub mod actions;
+pub mod prover;
+pub mod verifier;
+
+pub fn verify_thread_id(thread_id: &str, message: &PresentProofV2Action) -> VcxResult<()> {
+    if !settings::indy_mocks_enabled() && !message.thread_id_matches(thread_id) {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidJson,
+            format!(
+                "Cannot handle message {:?}: thread id does not match, expected {:?}",
+                message, thread_id
+            ),
+        ));
+    };
+    Ok(())
+}
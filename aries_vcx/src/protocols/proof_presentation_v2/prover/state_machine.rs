@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Utc;
+use messages::decorators::thread::Thread;
+use messages::decorators::timing::Timing;
+use messages::msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier;
+use messages::msg_fields::protocols::notification::Notification;
+use messages::msg_fields::protocols::present_proof_v2::ack::AckPresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::present::{
+    PresentationAttachmentFormatType, PresentationV2, PresentationV2Content, PresentationV2Decorators,
+};
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::{
+    RequestPresentationAttachmentFormatType, RequestPresentationV2,
+};
+use messages::msg_fields::protocols::present_proof_v2::PresentProofV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+use messages::AriesMessage;
+use shared_vcx::maybe_known::MaybeKnown;
+use uuid::Uuid;
+
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::util::{make_attach_from_str, matches_opt_thread_id, matches_thread_id, Status};
+use crate::protocols::common::{build_problem_report_msg, get_attach_for_format};
+use crate::protocols::proof_presentation::prover::state_machine::ProverState;
+use crate::protocols::proof_presentation_v2::actions::PresentProofV2Action;
+use crate::protocols::proof_presentation_v2::prover::states::finished::FinishedProverState;
+use crate::protocols::proof_presentation_v2::prover::states::initial::InitialProverState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_preparation_failed::PresentationPreparationFailedState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_prepared::PresentationPreparedState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_proposal_sent::PresentationProposalSentState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_request_received::PresentationRequestReceivedState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_sent::PresentationSentState;
+use crate::protocols::proof_presentation_v2::verify_thread_id;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ProverV2FullState {
+    Initial(InitialProverState),
+    PresentationProposalSent(PresentationProposalSentState),
+    PresentationRequestReceived(PresentationRequestReceivedState),
+    PresentationPrepared(PresentationPreparedState),
+    PresentationPreparationFailed(PresentationPreparationFailedState),
+    PresentationSent(PresentationSentState),
+    Finished(FinishedProverState),
+}
+
+/// Prover side of the present proof 2.0 protocol, supporting the `hlindy` attachment formats.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProverV2SM {
+    source_id: String,
+    thread_id: String,
+    state: ProverV2FullState,
+}
+
+impl fmt::Display for ProverV2FullState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ProverV2FullState::Initial(_) => f.write_str("Initial"),
+            ProverV2FullState::PresentationProposalSent(_) => f.write_str("PresentationProposalSent"),
+            ProverV2FullState::PresentationRequestReceived(_) => f.write_str("PresentationRequestReceived"),
+            ProverV2FullState::PresentationPrepared(_) => f.write_str("PresentationPrepared"),
+            ProverV2FullState::PresentationPreparationFailed(_) => f.write_str("PresentationPreparationFailed"),
+            ProverV2FullState::PresentationSent(_) => f.write_str("PresentationSent"),
+            ProverV2FullState::Finished(_) => f.write_str("Finished"),
+        }
+    }
+}
+
+fn build_presentation_msg(thread_id: &str, presentation_attach: String) -> PresentationV2 {
+    let attach_id = Uuid::new_v4().to_string();
+    let formats = vec![AttachmentFormatSpecifier::new(
+        attach_id.clone(),
+        MaybeKnown::Known(PresentationAttachmentFormatType::HyperledgerIndyProof2_0),
+    )];
+    let content = PresentationV2Content::new(formats, vec![make_attach_from_str!(&presentation_attach, attach_id)]);
+
+    let mut decorators = PresentationV2Decorators::new(Thread::new(thread_id.to_owned()));
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.timing = Some(timing);
+
+    PresentationV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+impl ProverV2SM {
+    pub fn new(source_id: String) -> Self {
+        ProverV2SM {
+            source_id,
+            thread_id: Uuid::new_v4().to_string(),
+            state: ProverV2FullState::Initial(InitialProverState::default()),
+        }
+    }
+
+    pub fn from_request(presentation_request: RequestPresentationV2, source_id: String) -> Self {
+        ProverV2SM {
+            source_id,
+            thread_id: presentation_request
+                .decorators
+                .thread
+                .as_ref()
+                .map(|thread| thread.thid.clone())
+                .unwrap_or_else(|| presentation_request.id.clone()),
+            state: ProverV2FullState::PresentationRequestReceived(PresentationRequestReceivedState::new(
+                presentation_request,
+            )),
+        }
+    }
+
+    pub fn source_id(&self) -> String {
+        self.source_id.clone()
+    }
+
+    pub fn get_thread_id(&self) -> VcxResult<String> {
+        Ok(self.thread_id.clone())
+    }
+
+    pub fn get_state(&self) -> ProverState {
+        match self.state {
+            ProverV2FullState::Initial(_) => ProverState::Initial,
+            ProverV2FullState::PresentationProposalSent(_) => ProverState::PresentationProposalSent,
+            ProverV2FullState::PresentationRequestReceived(_) => ProverState::PresentationRequestReceived,
+            ProverV2FullState::PresentationPrepared(_) => ProverState::PresentationPrepared,
+            ProverV2FullState::PresentationPreparationFailed(_) => ProverState::PresentationPreparationFailed,
+            ProverV2FullState::PresentationSent(_) => ProverState::PresentationSent,
+            ProverV2FullState::Finished(ref status) => match status.status {
+                Status::Success => ProverState::Finished,
+                _ => ProverState::Failed,
+            },
+        }
+    }
+
+    pub fn progressable_by_message(&self) -> bool {
+        matches!(
+            self.state,
+            ProverV2FullState::PresentationProposalSent(_) | ProverV2FullState::PresentationSent(_)
+        )
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        trace!(
+            "ProverV2::find_message_to_handle >>> messages: {:?}, state: {:?}",
+            messages,
+            self.state
+        );
+        for (uid, message) in messages {
+            match self.state {
+                ProverV2FullState::PresentationProposalSent(_) => match &message {
+                    AriesMessage::PresentProofV2(PresentProofV2::RequestPresentation(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::PresentProofV2(PresentProofV2::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(msg) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                ProverV2FullState::PresentationSent(_) => match &message {
+                    AriesMessage::PresentProofV2(PresentProofV2::Ack(msg)) => {
+                        if matches_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::Ack(msg)) => {
+                        if matches_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::PresentProofV2(PresentProofV2::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(msg) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            };
+        }
+        None
+    }
+
+    pub async fn handle_message(
+        self,
+        profile: &Arc<dyn Profile>,
+        action: PresentProofV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<Self> {
+        trace!(
+            "ProverV2::handle_message >>> action: {:?}, state: {:?}",
+            action,
+            self.state
+        );
+        verify_thread_id(&self.thread_id, &action)?;
+        let send_message = || {
+            send_message.ok_or(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Attempted to call undefined send_message callback",
+            ))
+        };
+        let prover_sm = match action {
+            PresentProofV2Action::PresentationProposalSend(proposal) => {
+                self.send_presentation_proposal(proposal, send_message()?).await?
+            }
+            PresentProofV2Action::PresentationRequest(request) => self.receive_presentation_request(request)?,
+            PresentProofV2Action::PreparePresentation((credentials, self_attested_attrs)) => {
+                self.generate_presentation(profile, credentials, self_attested_attrs)
+                    .await?
+            }
+            PresentProofV2Action::SendPresentation => self.send_presentation(send_message()?).await?,
+            PresentProofV2Action::PresentationRequestReject(reason) => {
+                self.decline_presentation_request(reason, send_message()?).await?
+            }
+            PresentProofV2Action::PresentationAck(ack) => self.receive_presentation_ack(ack)?,
+            PresentProofV2Action::ProblemReport(problem_report) => self.receive_problem_report(problem_report)?,
+            _ => self,
+        };
+        Ok(prover_sm)
+    }
+
+    pub async fn send_presentation_proposal(
+        self,
+        proposal: ProposePresentationV2,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        let mut proposal = proposal;
+        let state = match self.state {
+            ProverV2FullState::Initial(_) => {
+                proposal.id = self.thread_id.clone();
+                send_message(proposal.clone().into()).await?;
+                ProverV2FullState::PresentationProposalSent(PresentationProposalSentState::new(proposal))
+            }
+            ProverV2FullState::PresentationRequestReceived(_) => {
+                proposal.decorators.thread = Some(Thread::new(self.thread_id.clone()));
+                send_message(proposal.clone().into()).await?;
+                ProverV2FullState::PresentationProposalSent(PresentationProposalSentState::new(proposal))
+            }
+            s => {
+                warn!("Unable to send presentation proposal in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_presentation_request(self, presentation_request: RequestPresentationV2) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationProposalSent(_) => ProverV2FullState::PresentationRequestReceived(
+                PresentationRequestReceivedState::new(presentation_request),
+            ),
+            s => {
+                warn!("Unable to receive presentation request in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn generate_presentation(
+        self,
+        profile: &Arc<dyn Profile>,
+        credentials: String,
+        self_attested_attrs: String,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationRequestReceived(state) => {
+                match state
+                    .build_presentation(profile, &credentials, &self_attested_attrs)
+                    .await
+                {
+                    Ok(presentation) => {
+                        let presentation = build_presentation_msg(&self.thread_id, presentation);
+                        ProverV2FullState::PresentationPrepared((state, presentation).into())
+                    }
+                    Err(err) => {
+                        let problem_report = build_problem_report_msg(Some(err.to_string()), &self.thread_id);
+                        error!(
+                            "Failed to build presentation, sending problem report: {:?}",
+                            problem_report
+                        );
+                        ProverV2FullState::PresentationPreparationFailed((state, problem_report).into())
+                    }
+                }
+            }
+            s => {
+                warn!("Unable to generate presentation in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn send_presentation(self, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationPrepared(state) => {
+                send_message(state.presentation.clone().into()).await?;
+                ProverV2FullState::PresentationSent(state.into())
+            }
+            ProverV2FullState::PresentationPreparationFailed(state) => {
+                send_message(state.problem_report.clone().into()).await?;
+                ProverV2FullState::Finished(state.into())
+            }
+            s => {
+                warn!("Unable to send presentation in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn decline_presentation_request(self, reason: String, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationRequestReceived(state) => {
+                let problem_report = build_problem_report_msg(Some(reason), &self.thread_id);
+                send_message(problem_report.clone().into()).await?;
+                ProverV2FullState::Finished((state, problem_report).into())
+            }
+            ProverV2FullState::PresentationPrepared(state) => {
+                let problem_report = build_problem_report_msg(Some(reason), &self.thread_id);
+                send_message(problem_report.clone().into()).await?;
+                ProverV2FullState::Finished((state, problem_report).into())
+            }
+            ProverV2FullState::PresentationSent(_) => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::ActionNotSupported,
+                    "Presentation is already sent",
+                ));
+            }
+            s => {
+                warn!("Unable to decline presentation request in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_presentation_ack(self, ack: AckPresentationV2) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationSent(state) => ProverV2FullState::Finished((state, ack).into()),
+            s => {
+                warn!("Unable to process presentation ack in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_problem_report(self, problem_report: ProblemReport) -> VcxResult<Self> {
+        let state = match self.state {
+            ProverV2FullState::PresentationProposalSent(_) => {
+                ProverV2FullState::Finished(FinishedProverState::declined(problem_report))
+            }
+            ProverV2FullState::PresentationSent(state) => ProverV2FullState::Finished((state, problem_report).into()),
+            s => {
+                warn!("Unable to receive problem report in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn get_presentation_status(&self) -> u32 {
+        match self.state {
+            ProverV2FullState::Finished(ref state) => state.status.code(),
+            _ => Status::Undefined.code(),
+        }
+    }
+
+    pub fn get_presentation_request(&self) -> VcxResult<&RequestPresentationV2> {
+        let presentation_request = match self.state {
+            ProverV2FullState::PresentationRequestReceived(ref state) => Some(&state.presentation_request),
+            ProverV2FullState::PresentationPrepared(ref state) => Some(&state.presentation_request),
+            ProverV2FullState::PresentationPreparationFailed(ref state) => Some(&state.presentation_request),
+            ProverV2FullState::PresentationSent(ref state) => Some(&state.presentation_request),
+            ProverV2FullState::Finished(ref state) => state.presentation_request.as_ref(),
+            ProverV2FullState::Initial(_) | ProverV2FullState::PresentationProposalSent(_) => None,
+        };
+        presentation_request.ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::NotReady,
+            "Presentation request is not available",
+        ))
+    }
+
+    /// Returns the `hlindy/proof-req@v2.0` proof request.
+    pub fn get_presentation_request_attachment(&self) -> VcxResult<String> {
+        let presentation_request = self.get_presentation_request()?;
+        get_attach_for_format(
+            &presentation_request.content.formats,
+            &presentation_request.content.request_presentations_attach,
+            RequestPresentationAttachmentFormatType::HyperledgerIndyProofRequest2_0,
+        )
+    }
+
+    pub fn get_presentation_msg(&self) -> VcxResult<&PresentationV2> {
+        let presentation = match self.state {
+            ProverV2FullState::PresentationPrepared(ref state) => Some(&state.presentation),
+            ProverV2FullState::PresentationSent(ref state) => Some(&state.presentation),
+            ProverV2FullState::Finished(ref state) => state.presentation.as_ref(),
+            _ => None,
+        };
+        presentation.ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::NotReady,
+            "Presentation is not created yet",
+        ))
+    }
+}
@@ -0,0 +1,2 @@
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InitialProverState {}
@@ -0,0 +1,7 @@
+pub(super) mod finished;
+pub(super) mod initial;
+pub(super) mod presentation_preparation_failed;
+pub(super) mod presentation_prepared;
+pub(super) mod presentation_proposal_sent;
+pub(super) mod presentation_request_received;
+pub(super) mod presentation_sent;
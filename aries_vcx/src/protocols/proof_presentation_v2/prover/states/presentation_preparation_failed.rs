@@ -0,0 +1,22 @@
+use messages::msg_fields::protocols::present_proof_v2::request::RequestPresentationV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::handlers::util::Status;
+use crate::protocols::proof_presentation_v2::prover::states::finished::FinishedProverState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationPreparationFailedState {
+    pub presentation_request: RequestPresentationV2,
+    pub problem_report: ProblemReport,
+}
+
+impl From<PresentationPreparationFailedState> for FinishedProverState {
+    fn from(state: PresentationPreparationFailedState) -> Self {
+        trace!("transit state from PresentationPreparationFailedState to FinishedProverState");
+        FinishedProverState {
+            presentation_request: Some(state.presentation_request),
+            presentation: None,
+            status: Status::Failed(state.problem_report),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use messages::msg_fields::protocols::present_proof_v2::present::PresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::RequestPresentationV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::handlers::util::Status;
+use crate::protocols::proof_presentation_v2::prover::states::finished::FinishedProverState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_sent::PresentationSentState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationPreparedState {
+    pub presentation_request: RequestPresentationV2,
+    pub presentation: PresentationV2,
+}
+
+impl From<PresentationPreparedState> for PresentationSentState {
+    fn from(state: PresentationPreparedState) -> Self {
+        trace!("transit state from PresentationPreparedState to PresentationSentState");
+        PresentationSentState {
+            presentation_request: state.presentation_request,
+            presentation: state.presentation,
+        }
+    }
+}
+
+impl From<(PresentationPreparedState, ProblemReport)> for FinishedProverState {
+    fn from((state, problem_report): (PresentationPreparedState, ProblemReport)) -> Self {
+        trace!("transit state from PresentationPreparedState to FinishedProverState");
+        FinishedProverState {
+            presentation_request: Some(state.presentation_request),
+            presentation: None,
+            status: Status::Declined(problem_report),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationProposalSentState {
+    pub proposal: ProposePresentationV2,
+}
+
+impl PresentationProposalSentState {
+    pub fn new(proposal: ProposePresentationV2) -> Self {
+        Self { proposal }
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use messages::msg_fields::protocols::present_proof_v2::present::PresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::{
+    RequestPresentationAttachmentFormatType, RequestPresentationV2,
+};
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::common::proofs::prover::prover::generate_indy_proof;
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::util::Status;
+use crate::protocols::common::get_attach_for_format;
+use crate::protocols::proof_presentation_v2::prover::states::finished::FinishedProverState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_preparation_failed::PresentationPreparationFailedState;
+use crate::protocols::proof_presentation_v2::prover::states::presentation_prepared::PresentationPreparedState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationRequestReceivedState {
+    pub presentation_request: RequestPresentationV2,
+}
+
+impl PresentationRequestReceivedState {
+    pub fn new(presentation_request: RequestPresentationV2) -> Self {
+        Self { presentation_request }
+    }
+
+    /// Returns the `hlindy/proof-req@v2.0` proof request.
+    pub fn get_attachment(&self) -> VcxResult<String> {
+        get_attach_for_format(
+            &self.presentation_request.content.formats,
+            &self.presentation_request.content.request_presentations_attach,
+            RequestPresentationAttachmentFormatType::HyperledgerIndyProofRequest2_0,
+        )
+    }
+
+    pub async fn build_presentation(
+        &self,
+        profile: &Arc<dyn Profile>,
+        credentials: &str,
+        self_attested_attrs: &str,
+    ) -> VcxResult<String> {
+        let proof_req_data_json = self.get_attachment()?;
+        generate_indy_proof(profile, credentials, self_attested_attrs, &proof_req_data_json).await
+    }
+}
+
+impl From<(PresentationRequestReceivedState, PresentationV2)> for PresentationPreparedState {
+    fn from((state, presentation): (PresentationRequestReceivedState, PresentationV2)) -> Self {
+        trace!("transit state from PresentationRequestReceivedState to PresentationPreparedState");
+        PresentationPreparedState {
+            presentation_request: state.presentation_request,
+            presentation,
+        }
+    }
+}
+
+impl From<(PresentationRequestReceivedState, ProblemReport)> for PresentationPreparationFailedState {
+    fn from((state, problem_report): (PresentationRequestReceivedState, ProblemReport)) -> Self {
+        trace!("transit state from PresentationRequestReceivedState to PresentationPreparationFailedState");
+        PresentationPreparationFailedState {
+            presentation_request: state.presentation_request,
+            problem_report,
+        }
+    }
+}
+
+impl From<(PresentationRequestReceivedState, ProblemReport)> for FinishedProverState {
+    fn from((state, problem_report): (PresentationRequestReceivedState, ProblemReport)) -> Self {
+        trace!("transit state from PresentationRequestReceivedState to FinishedProverState");
+        FinishedProverState {
+            presentation_request: Some(state.presentation_request),
+            presentation: None,
+            status: Status::Declined(problem_report),
+        }
+    }
+}
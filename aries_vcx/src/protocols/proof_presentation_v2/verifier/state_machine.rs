@@ -0,0 +1,448 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::Utc;
+use messages::decorators::thread::Thread;
+use messages::decorators::timing::Timing;
+use messages::msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier;
+use messages::msg_fields::protocols::notification::ack::{AckDecorators, AckStatus};
+use messages::msg_fields::protocols::notification::Notification;
+use messages::msg_fields::protocols::present_proof_v2::ack::{AckPresentationV2, AckPresentationV2Content};
+use messages::msg_fields::protocols::present_proof_v2::present::PresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::problem_report::{
+    PresentProofV2ProblemReport, PresentProofV2ProblemReportContent,
+};
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+use messages::msg_fields::protocols::present_proof_v2::request::{
+    RequestPresentationAttachmentFormatType, RequestPresentationV2, RequestPresentationV2Content,
+    RequestPresentationV2Decorators,
+};
+use messages::msg_fields::protocols::present_proof_v2::PresentProofV2;
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+use messages::msg_parts::MsgParts;
+use messages::AriesMessage;
+use shared_vcx::maybe_known::MaybeKnown;
+use uuid::Uuid;
+
+use crate::common::proofs::proof_request::PresentationRequestData;
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::util::{make_attach_from_str, matches_opt_thread_id, matches_thread_id, Status};
+use crate::protocols::common::build_problem_report_msg;
+use crate::protocols::proof_presentation::verifier::state_machine::VerifierState;
+use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+use crate::protocols::proof_presentation_v2::actions::PresentProofV2Action;
+use crate::protocols::proof_presentation_v2::verifier::states::finished::FinishedVerifierState;
+use crate::protocols::proof_presentation_v2::verifier::states::initial::InitialVerifierState;
+use crate::protocols::proof_presentation_v2::verifier::states::presentation_proposal_received::PresentationProposalReceivedState;
+use crate::protocols::proof_presentation_v2::verifier::states::presentation_request_sent::PresentationRequestSentState;
+use crate::protocols::proof_presentation_v2::verifier::states::presentation_request_set::PresentationRequestSetState;
+use crate::protocols::proof_presentation_v2::verify_thread_id;
+use crate::protocols::SendClosure;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum VerifierV2FullState {
+    Initial(InitialVerifierState),
+    PresentationProposalReceived(PresentationProposalReceivedState),
+    PresentationRequestSet(PresentationRequestSetState),
+    PresentationRequestSent(PresentationRequestSentState),
+    Finished(FinishedVerifierState),
+}
+
+/// Verifier side of the present proof 2.0 protocol, supporting the `hlindy` attachment formats.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerifierV2SM {
+    source_id: String,
+    thread_id: String,
+    state: VerifierV2FullState,
+}
+
+impl fmt::Display for VerifierV2FullState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            VerifierV2FullState::Initial(_) => f.write_str("Initial"),
+            VerifierV2FullState::PresentationProposalReceived(_) => f.write_str("PresentationProposalReceived"),
+            VerifierV2FullState::PresentationRequestSet(_) => f.write_str("PresentationRequestSet"),
+            VerifierV2FullState::PresentationRequestSent(_) => f.write_str("PresentationRequestSent"),
+            VerifierV2FullState::Finished(_) => f.write_str("Finished"),
+        }
+    }
+}
+
+fn build_verification_ack(thread_id: &str) -> AckPresentationV2 {
+    let content = AckPresentationV2Content::new(AckStatus::Ok);
+    let mut decorators = AckDecorators::new(Thread::new(thread_id.to_owned()));
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.timing = Some(timing);
+
+    AckPresentationV2::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+fn build_presentation_request(
+    id: String,
+    thread: Option<Thread>,
+    request_data: &PresentationRequestData,
+    comment: Option<String>,
+) -> RequestPresentationV2 {
+    let attach_id = Uuid::new_v4().to_string();
+    let formats = vec![AttachmentFormatSpecifier::new(
+        attach_id.clone(),
+        MaybeKnown::Known(RequestPresentationAttachmentFormatType::HyperledgerIndyProofRequest2_0),
+    )];
+    let mut content = RequestPresentationV2Content::new(
+        formats,
+        vec![make_attach_from_str!(&json!(request_data).to_string(), attach_id)],
+    );
+    content.comment = comment;
+    content.will_confirm = Some(true);
+
+    let mut decorators = RequestPresentationV2Decorators::default();
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    decorators.thread = thread;
+    decorators.timing = Some(timing);
+
+    RequestPresentationV2::with_decorators(id, content, decorators)
+}
+
+impl VerifierV2SM {
+    pub fn new(source_id: &str) -> Self {
+        Self {
+            source_id: source_id.to_string(),
+            thread_id: Uuid::new_v4().to_string(),
+            state: VerifierV2FullState::Initial(InitialVerifierState::default()),
+        }
+    }
+
+    pub fn from_request(
+        source_id: &str,
+        presentation_request_data: &PresentationRequestData,
+        comment: Option<String>,
+    ) -> VcxResult<Self> {
+        Self::new(source_id).set_request(presentation_request_data, comment)
+    }
+
+    pub fn from_proposal(source_id: &str, presentation_proposal: &ProposePresentationV2) -> Self {
+        Self {
+            source_id: source_id.to_string(),
+            thread_id: presentation_proposal
+                .decorators
+                .thread
+                .as_ref()
+                .map(|thread| thread.thid.clone())
+                .unwrap_or_else(|| presentation_proposal.id.clone()),
+            state: VerifierV2FullState::PresentationProposalReceived(PresentationProposalReceivedState::new(
+                presentation_proposal.clone(),
+            )),
+        }
+    }
+
+    pub fn source_id(&self) -> String {
+        self.source_id.clone()
+    }
+
+    pub fn thread_id(&self) -> String {
+        self.thread_id.clone()
+    }
+
+    pub fn get_state(&self) -> VerifierState {
+        match self.state {
+            VerifierV2FullState::Initial(_) => VerifierState::Initial,
+            VerifierV2FullState::PresentationProposalReceived(_) => VerifierState::PresentationProposalReceived,
+            VerifierV2FullState::PresentationRequestSet(_) => VerifierState::PresentationRequestSet,
+            VerifierV2FullState::PresentationRequestSent(_) => VerifierState::PresentationRequestSent,
+            VerifierV2FullState::Finished(ref status) => match status.status {
+                Status::Success => VerifierState::Finished,
+                _ => VerifierState::Failed,
+            },
+        }
+    }
+
+    pub fn progressable_by_message(&self) -> bool {
+        matches!(
+            self.state,
+            VerifierV2FullState::Initial(_) | VerifierV2FullState::PresentationRequestSent(_)
+        )
+    }
+
+    pub fn find_message_to_handle(&self, messages: HashMap<String, AriesMessage>) -> Option<(String, AriesMessage)> {
+        trace!(
+            "VerifierV2::find_message_to_handle >>> messages: {:?}, state: {:?}",
+            messages,
+            self.state
+        );
+        for (uid, message) in messages {
+            match self.state {
+                VerifierV2FullState::Initial(_) => {
+                    if let AriesMessage::PresentProofV2(PresentProofV2::ProposePresentation(_)) = &message {
+                        return Some((uid, message));
+                    }
+                }
+                VerifierV2FullState::PresentationRequestSent(_) => match &message {
+                    AriesMessage::PresentProofV2(PresentProofV2::Presentation(msg)) => {
+                        if matches_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::PresentProofV2(PresentProofV2::ProposePresentation(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::PresentProofV2(PresentProofV2::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::ReportProblem(msg) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    AriesMessage::Notification(Notification::ProblemReport(msg)) => {
+                        if matches_opt_thread_id!(msg, self.thread_id.as_str()) {
+                            return Some((uid, message));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            };
+        }
+        None
+    }
+
+    pub async fn handle_message(
+        self,
+        profile: &Arc<dyn Profile>,
+        action: PresentProofV2Action,
+        send_message: Option<SendClosure>,
+    ) -> VcxResult<Self> {
+        trace!(
+            "VerifierV2::handle_message >>> action: {:?}, state: {:?}",
+            action,
+            self.state
+        );
+        let send_message = || {
+            send_message.ok_or(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Attempted to call undefined send_message callback",
+            ))
+        };
+        let verifier_sm = match action {
+            PresentProofV2Action::PresentationProposal(proposal) => self.receive_presentation_proposal(proposal)?,
+            PresentProofV2Action::Presentation(presentation) => {
+                self.verify_presentation(profile, presentation, send_message()?).await?
+            }
+            PresentProofV2Action::ProblemReport(problem_report) => self.receive_problem_report(problem_report)?,
+            _ => self,
+        };
+        Ok(verifier_sm)
+    }
+
+    pub fn receive_presentation_proposal(self, proposal: ProposePresentationV2) -> VcxResult<Self> {
+        let (state, thread_id) = match self.state {
+            VerifierV2FullState::Initial(_) => {
+                let thread_id = proposal
+                    .decorators
+                    .thread
+                    .as_ref()
+                    .map(|thread| thread.thid.clone())
+                    .unwrap_or_else(|| proposal.id.clone());
+                (
+                    VerifierV2FullState::PresentationProposalReceived(PresentationProposalReceivedState::new(proposal)),
+                    thread_id,
+                )
+            }
+            VerifierV2FullState::PresentationRequestSent(_) => {
+                verify_thread_id(
+                    &self.thread_id,
+                    &PresentProofV2Action::PresentationProposal(proposal.clone()),
+                )?;
+                (
+                    VerifierV2FullState::PresentationProposalReceived(PresentationProposalReceivedState::new(proposal)),
+                    self.thread_id.clone(),
+                )
+            }
+            s => {
+                warn!("Unable to receive presentation proposal in state {}", s);
+                (s, self.thread_id.clone())
+            }
+        };
+        Ok(Self {
+            state,
+            thread_id,
+            ..self
+        })
+    }
+
+    pub fn set_request(self, request_data: &PresentationRequestData, comment: Option<String>) -> VcxResult<Self> {
+        let state = match self.state {
+            VerifierV2FullState::Initial(_) | VerifierV2FullState::PresentationRequestSet(_) => {
+                let request = build_presentation_request(self.thread_id.clone(), None, request_data, comment);
+                VerifierV2FullState::PresentationRequestSet(PresentationRequestSetState::new(request))
+            }
+            VerifierV2FullState::PresentationProposalReceived(_) => {
+                let request = build_presentation_request(
+                    Uuid::new_v4().to_string(),
+                    Some(Thread::new(self.thread_id.clone())),
+                    request_data,
+                    comment,
+                );
+                VerifierV2FullState::PresentationRequestSet(PresentationRequestSetState::new(request))
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Cannot set presentation request in this state",
+                ));
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn mark_presentation_request_msg_sent(self) -> VcxResult<Self> {
+        let state = match self.state {
+            VerifierV2FullState::PresentationRequestSet(state) => {
+                VerifierV2FullState::PresentationRequestSent(state.into())
+            }
+            VerifierV2FullState::PresentationRequestSent(state) => VerifierV2FullState::PresentationRequestSent(state),
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::InvalidState,
+                    "Can not mark_presentation_request_msg_sent in current state.",
+                ));
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn reject_presentation_proposal(self, reason: String, send_message: SendClosure) -> VcxResult<Self> {
+        let state = match self.state {
+            VerifierV2FullState::PresentationProposalReceived(_) => {
+                let problem_report = build_problem_report_msg(Some(reason), &self.thread_id);
+                send_message(problem_report.clone().into()).await?;
+                VerifierV2FullState::Finished(FinishedVerifierState::declined(problem_report))
+            }
+            s => {
+                warn!("Unable to reject presentation proposal in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub async fn verify_presentation(
+        self,
+        profile: &Arc<dyn Profile>,
+        presentation: PresentationV2,
+        send_message: SendClosure,
+    ) -> VcxResult<Self> {
+        verify_thread_id(
+            &self.thread_id,
+            &PresentProofV2Action::Presentation(presentation.clone()),
+        )?;
+        let state = match self.state {
+            VerifierV2FullState::PresentationRequestSent(state) => {
+                let (state, message) = match state.verify_presentation(profile, &presentation).await {
+                    Ok(()) => {
+                        let state = VerifierV2FullState::Finished(
+                            (state, presentation, PresentationVerificationStatus::Valid).into(),
+                        );
+                        (state, build_verification_ack(&self.thread_id).into())
+                    }
+                    Err(err) => {
+                        let problem_report = build_problem_report_msg(Some(err.to_string()), &self.thread_id);
+                        let state = match err.kind() {
+                            AriesVcxErrorKind::InvalidProof => VerifierV2FullState::Finished(
+                                (state, presentation, PresentationVerificationStatus::Invalid).into(),
+                            ),
+                            _ => VerifierV2FullState::Finished((state, problem_report.clone()).into()),
+                        };
+
+                        let MsgParts {
+                            id,
+                            content,
+                            decorators,
+                        } = problem_report;
+                        let problem_report = PresentProofV2ProblemReport::with_decorators(
+                            id,
+                            PresentProofV2ProblemReportContent(content),
+                            decorators,
+                        );
+                        (state, AriesMessage::from(problem_report))
+                    }
+                };
+                send_message(message).await?;
+                state
+            }
+            s => {
+                warn!("Unable to verify presentation in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn receive_problem_report(self, problem_report: ProblemReport) -> VcxResult<Self> {
+        verify_thread_id(
+            &self.thread_id,
+            &PresentProofV2Action::ProblemReport(problem_report.clone()),
+        )?;
+        let state = match self.state {
+            VerifierV2FullState::PresentationRequestSent(state) => {
+                VerifierV2FullState::Finished((state, problem_report).into())
+            }
+            s => {
+                warn!("Unable to receive problem report in state {}", s);
+                s
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
+    pub fn get_verification_status(&self) -> PresentationVerificationStatus {
+        match self.state {
+            VerifierV2FullState::Finished(ref state) => state.verification_status.clone(),
+            _ => PresentationVerificationStatus::Unavailable,
+        }
+    }
+
+    pub fn presentation_request_msg(&self) -> VcxResult<RequestPresentationV2> {
+        let presentation_request = match self.state {
+            VerifierV2FullState::PresentationRequestSet(ref state) => Some(&state.presentation_request),
+            VerifierV2FullState::PresentationRequestSent(ref state) => Some(&state.presentation_request),
+            VerifierV2FullState::Finished(ref state) => state.presentation_request.as_ref(),
+            VerifierV2FullState::Initial(_) | VerifierV2FullState::PresentationProposalReceived(_) => None,
+        };
+        presentation_request.cloned().ok_or(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidState,
+            "No presentation request set",
+        ))
+    }
+
+    pub fn get_presentation_msg(&self) -> VcxResult<PresentationV2> {
+        match self.state {
+            VerifierV2FullState::Finished(ref state) => state.presentation.clone().ok_or(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "State machine is final state, but presentation is not available",
+            )),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Presentation not received yet",
+            )),
+        }
+    }
+
+    pub fn presentation_proposal(&self) -> VcxResult<ProposePresentationV2> {
+        match self.state {
+            VerifierV2FullState::PresentationProposalReceived(ref state) => Ok(state.presentation_proposal.clone()),
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "Presentation proposal not received yet",
+            )),
+        }
+    }
+}
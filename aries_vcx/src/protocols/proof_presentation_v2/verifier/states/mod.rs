@@ -0,0 +1,5 @@
+pub(super) mod finished;
+pub(super) mod initial;
+pub(super) mod presentation_proposal_received;
+pub(super) mod presentation_request_sent;
+pub(super) mod presentation_request_set;
@@ -0,0 +1,12 @@
+use messages::msg_fields::protocols::present_proof_v2::propose::ProposePresentationV2;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationProposalReceivedState {
+    pub presentation_proposal: ProposePresentationV2,
+}
+
+impl PresentationProposalReceivedState {
+    pub fn new(presentation_proposal: ProposePresentationV2) -> Self {
+        Self { presentation_proposal }
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use messages::msg_fields::protocols::present_proof_v2::present::{PresentationAttachmentFormatType, PresentationV2};
+use messages::msg_fields::protocols::present_proof_v2::request::{
+    RequestPresentationAttachmentFormatType, RequestPresentationV2,
+};
+use messages::msg_fields::protocols::report_problem::ProblemReport;
+
+use crate::common::proofs::verifier::verifier::validate_indy_proof;
+use crate::core::profile::profile::Profile;
+use crate::errors::error::prelude::*;
+use crate::handlers::util::Status;
+use crate::protocols::common::get_attach_for_format;
+use crate::protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus;
+use crate::protocols::proof_presentation_v2::verifier::states::finished::FinishedVerifierState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationRequestSentState {
+    pub presentation_request: RequestPresentationV2,
+}
+
+impl PresentationRequestSentState {
+    /// Validates the `hlindy/proof@v2.0` proof against the `hlindy/proof-req@v2.0` proof request.
+    pub async fn verify_presentation(
+        &self,
+        profile: &Arc<dyn Profile>,
+        presentation: &PresentationV2,
+    ) -> VcxResult<()> {
+        let proof_json = get_attach_for_format(
+            &presentation.content.formats,
+            &presentation.content.presentations_attach,
+            PresentationAttachmentFormatType::HyperledgerIndyProof2_0,
+        )?;
+        let proof_req_json = get_attach_for_format(
+            &self.presentation_request.content.formats,
+            &self.presentation_request.content.request_presentations_attach,
+            RequestPresentationAttachmentFormatType::HyperledgerIndyProofRequest2_0,
+        )?;
+
+        let valid = validate_indy_proof(profile, &proof_json, &proof_req_json).await?;
+
+        if !valid {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidProof,
+                "Presentation verification failed",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl
+    From<(
+        PresentationRequestSentState,
+        PresentationV2,
+        PresentationVerificationStatus,
+    )> for FinishedVerifierState
+{
+    fn from(
+        (state, presentation, verification_status): (
+            PresentationRequestSentState,
+            PresentationV2,
+            PresentationVerificationStatus,
+        ),
+    ) -> Self {
+        trace!("transit state from PresentationRequestSentState to FinishedVerifierState");
+        FinishedVerifierState {
+            presentation_request: Some(state.presentation_request),
+            presentation: Some(presentation),
+            status: Status::Success,
+            verification_status,
+        }
+    }
+}
+
+impl From<(PresentationRequestSentState, ProblemReport)> for FinishedVerifierState {
+    fn from((state, problem_report): (PresentationRequestSentState, ProblemReport)) -> Self {
+        trace!("transit state from PresentationRequestSentState to FinishedVerifierState");
+        FinishedVerifierState {
+            presentation_request: Some(state.presentation_request),
+            presentation: None,
+            status: Status::Failed(problem_report),
+            verification_status: PresentationVerificationStatus::Unavailable,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use messages::msg_fields::protocols::present_proof_v2::request::RequestPresentationV2;
+
+use crate::protocols::proof_presentation_v2::verifier::states::presentation_request_sent::PresentationRequestSentState;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PresentationRequestSetState {
+    pub presentation_request: RequestPresentationV2,
+}
+
+impl PresentationRequestSetState {
+    pub fn new(presentation_request: RequestPresentationV2) -> Self {
+        Self { presentation_request }
+    }
+}
+
+impl From<PresentationRequestSetState> for PresentationRequestSentState {
+    fn from(state: PresentationRequestSetState) -> Self {
+        trace!("transit state from PresentationRequestSetState to PresentationRequestSentState");
+        PresentationRequestSentState {
+            presentation_request: state.presentation_request,
+        }
+    }
+}
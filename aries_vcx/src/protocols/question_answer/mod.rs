@@ -0,0 +1,41 @@
+use ::uuid::Uuid;
+use chrono::{DateTime, Utc};
+use messages::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::question_answer::{
+        answer::{Answer, AnswerContent, AnswerDecorators},
+        question::{Question, QuestionContent, QuestionDecorators},
+    },
+};
+
+/// Builds a question which must be answered before `expires_time`, if provided.
+pub fn build_question(content: QuestionContent, expires_time: Option<DateTime<Utc>>) -> Question {
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+    timing.expires_time = expires_time;
+
+    let mut decorators = QuestionDecorators::default();
+    decorators.timing = Some(timing);
+
+    Question::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+pub fn build_answer(thread_id: &str, content: AnswerContent) -> Answer {
+    let mut timing = Timing::default();
+    timing.out_time = Some(Utc::now());
+
+    let mut decorators = AnswerDecorators::new(Thread::new(thread_id.to_owned()));
+    decorators.timing = Some(timing);
+
+    Answer::with_decorators(Uuid::new_v4().to_string(), content, decorators)
+}
+
+/// Returns the thread of a question, which is the thread its answer is sent on.
+pub fn get_question_thread_id(question: &Question) -> &str {
+    question
+        .decorators
+        .thread
+        .as_ref()
+        .map(|t| t.thid.as_str())
+        .unwrap_or(question.id.as_str())
+}
@@ -1,12 +1,23 @@
 use async_trait::async_trait;
 use url::Url;
 
-use crate::errors::error::VcxResult;
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
 
 /// Trait used for implementing a mechanism to send a message, used by [`crate::protocols::connection::Connection`].
 #[async_trait]
 pub trait Transport: Send + Sync {
     async fn send_message(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<()>;
+
+    /// Sends a message and returns the packed message the endpoint replied with in the same
+    /// session, as requested through the `~transport` return route decorator.
+    ///
+    /// Transports that cannot deliver responses (the default) return an error.
+    async fn send_message_with_response(&self, _msg: Vec<u8>, _service_endpoint: Url) -> VcxResult<Vec<u8>> {
+        Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::ActionNotSupported,
+            "Transport does not support return routing",
+        ))
+    }
 }
 
 // While in many cases the auto-dereferencing does the trick,
@@ -20,4 +31,8 @@ where
     async fn send_message(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<()> {
         self.send_message(msg, service_endpoint).await
     }
+
+    async fn send_message_with_response(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<Vec<u8>> {
+        self.send_message_with_response(msg, service_endpoint).await
+    }
 }
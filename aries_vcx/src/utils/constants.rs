@@ -16,11 +16,11 @@ pub static DEFAULT_SCHEMA_ATTRS: &str = r#"["address1","address2","zip","city","
 pub static DEFAULT_SCHEMA_ATTRS_5: &str = r#"["key1", "key2", "key3","key4", "key5"]"#;
 pub static DEFAULT_SCHEMA_ID: &str = "2hoqvcwupRTUNkXn6ArYzs:2:test-licence:4.4.4";
 pub static DEFAULT_SCHEMA_NAME: &str = "Test Schema Name";
-pub static CREDENTIAL_REQ_STRING: &str = r#"{"libindy_cred_req":"{\"prover_did\":\"2hoqvcwupRTUNkXn6ArYzs\",\"cred_def_id\":\"2hoqvcwupRTUNkXn6ArYzs:3:CL:1766\",\"blinded_ms\":{\"u\":\"8732071602357015307810566138808197234658312581785137109788113302982640059349967050965447489217593298616209988826723701562661343443517589847218013366407845073616266391756009264980040238952349445643778936575656535779015458023493903785780518101975701982901383514030208868847307622362696880263163343848494510595690307613204277848599695882210459126941797459019913953592724097855109613611647709745072773427626720401442235193011557232562555622244156336806151662441234847773393387649719209243455960347563274791229126202016215550120934775060992031280966045894859557271641817491943416048075445449722000591059568013176905304195\",\"ur\":null},\"blinded_ms_correctness_proof\":{\"c\":\"26530740026507431379491385424781000855170637402280225419270466226736067904512\",\"v_dash_cap\":\"143142764256221649591394190756594263575252787336888260277569702754606119430149731374696604981582865909586330696038557351486556018124278706293019764236792379930773289730781387402321307275066512629558473696520197393762713894449968058415758200647216768004242460019909604733610794104180629190082978779757591726666340720737832809779281945323437475154340615798778337960748836468199407007775031657682302038533398039806427675709453395148841959462470861915712789403465722659960342165041260269463103782446132475688821810775202828210979373826636650138063942962121467854349698464501455098258293105554402435773328031261630390919907379686173528652481917022556931483089035786146580024468924714494948737711000361399753716101561779590\",\"ms_cap\":\"6713785684292289748157544902063599004332363811033155861083956757033688921010462943169460951559595511857618896433311745591610892377735569122165958960965808330552472093346163460366\"},\"nonce\":\"1154549882365416803296713\"}","libindy_cred_req_meta":"{\"master_secret_blinding_data\":{\"v_prime\":\"19573554835481719662327485122688893711456991477879921695470731620175963787279917341526369852398210114401207141951797741891847253211319668203346462590568438671120943726162783813341598838616013039004762423956877028539225355867586807673681018234178116101643797916210905197387018359780257940149589162122784199178788814187547780152684853122014747482921656188183260370150999742557975345375106137123621426061675848590309427394874048446416740808489978625893734432529086470382099078632291038405367083882596203500659091849643476443635802557200596085378755820180062431900445542883509174786917819553164472263849777903881905876531213020487201635195790520\",\"vr_prime\":null},\"nonce\":\"143213049816807095013964\",\"master_secret_name\":\"main\"}","cred_def_id":"2hoqvcwupRTUNkXn6ArYzs:3:CL:1766","tid":"cCanHnpFAD","to_did":"BnRXf8yDMUwGyZVDkSENeq","from_did":"GxtnGN6ypZYgEqcftSQFnC","version":"0.1","mid":"","msg_ref_id":"123"}"#;
-pub static LIBINDY_CRED_OFFER: &str = r#"{"issuer_did":"2hoqvcwupRTUNkXn6ArYzs","schema_key":{"name":"Home Address","version":"1.4","did":"2hoqvcwupRTUNkXn6ArYzs"},"key_correctness_proof":{"c":"8555253541554245344305351079388313043821365069629297255640200538622329722556","xz_cap":"64818256731588984794575029881576438712171978148821994354569423109505883511370051539530363090404289097908646608544866367046312481771587336183036163818849360474523320055058050733772575227932313793985470881830147160471852946598089626822740951538444260248405680001410943962258653118246973446307071417314391910474888369634752642195173997916292806072016186810315308257756689251031806948447462801785007243395079942815166817065271733596477143189406957903952991335446968764832960906258373699575234207180135806072152726528786138816315911998387303385565913657745597433033756984505440643451253917452841385494947936404135348354895376751800590086535707370194450915965147666804363452357419799188104044508109","xr_cap":{"address1":"8236425893392219787423825014385198460820517586004442204287421088285469674020926840448786131806503567730307555837801319715555107413533966776756997088003362401505821396887204933829958258785093075846810980429322007441122948459832086015057507926262051365966017173045228232337530339680355717180291794733363148324101203340879842496879728996183974739507710337122557429529832639384077022317326079678153237524335334790193774589523155338216849532635731123476861074950940938322358853287805286272076498390452028019829082291826739453475976800681550225322996208089503815975750152834370138410964418644082923687817510140143620366818252076463572791466640135793621279863114074326681043782582123182032344081138","address2":"30414471804770994051376437296525278254597585112268783700020054398847238843189530750793146903722533375657200785297557019465948393596156534191847866989266176618709331559949972729939131388887244366321127743968836991526071402029914419405781596054783690896660703606768577825229647587998380728894419570361864769440309185637967429191914824558483741394914212983254247799137730101941670911547714088499696084822272226072237693975774997990116374449197382931059877141968595755981160846810650806105803130004361523114137045586548743326078945833123588843296375692506658736851641735658969617721427932961073974202337608798761064528676757519926255271724266286989825397405029723387126754299497661658557574216867","city":"159795636056543233530021344623621334175753173834199599499234503024224170089287815725788337040803537786795901100564559891075793321268703839671526386175533087941057761454903389990043254221508542663884105491028667931433093528378567035675241504608287341705758154859625863922110474313370021277749973041267871971965548396722681397958408458464210449202419266126608057284371794186889175339171087558861231355840830361110708993602208821778313069364112399404445977187422249127909803315019664537899385297653020295835898441614009217452024854561288538496889400595485884757791655246945196819845725103196695608534259378231125159518322706097470964698852674734436475238855630473478573401236177640541599034507313","state":"215512468490315112938301657833926278136808116594771729699898320102646611321724434471863048390556908138905125523936043735201882025532772433002153410083708215401917118972375534193847316461794285777665177963351136804949997738950645361626956052973425101611071191598827068821964513860723502996877635652196651818308886110840798493982976675792164313480547213301748933952971154819253513296456319475340952940914757162158069252461973054465657233683514169036627218211903327888618365019366708902832859985119776192696909319524217085945724819529037625577237504453097885607411726308520123962852327787736505753002450290943635652415448032216336431217538662448209579828135627648861641446385394343199453905348086","zip":"238413810148929820131063264189691178282858328114757399256193590161266006646670344870416481980522447923115217342582281807424862378687793299109363839238237538377362459559820681904274866049652851183765153471969318096511161665533190643665261284892951569998678113101193901664492159340828270692168345719923300987213287650281559452357368956472066676438018575401605560388568884399190765464134955117933339552804676602790359330495723485338924295339609987825045590507344961620812843451249916254642836938597183261177212672766675968705705261108413829152581548433386403050115216912797280460237259161451733151284615735871654022007177671460429253488906111387740833557450384941388970535365310270275589048348152"}},"nonce":"45815185447169282124747"}"#;
+pub static CREDENTIAL_REQ_STRING: &str = r#"{"prover_did":"2RjtVytftf9Psbh3E8jqyq","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","blinded_ms":{"u":"100027047566114662930857592792764412217721392275083369828882938655574695904084909254872598383374135462818157481579202347277961003470677127247697513248915070768964337200672247123080011833520943699367832053033304094927034469240651159293637839716784651051241104373984444379690075201193445259176133046547957952458929898225062912541441373493724006451480428570814460400887039849190969477499250718291571843884107872498111565592617570209630469169665200928632327643466698744118207300048944134796951152980530492185153018650124349633386639988967401936709045162893378689741335876430320096144414916612452997419176841748365773606484","ur":"1 011DD64801D4AB4C89D4656452BF7EF514966CE6CC554ED2573ED872E0708F26 1 2264648B75D7CB11CBB72841795E15EF3EAB78582FC17C2DD610C8A30489039D 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","hidden_attributes":["master_secret"],"committed_attributes":{}},"blinded_ms_correctness_proof":{"c":"42859294662460772808298435946417321061476430494246572399133120991903210109494","v_dash_cap":"1478619110782031402608405563787983201731901784804609146631815158222427729624585547558703984974630002825908701708332647420029468265107477296745177164705126834512105030146466919877322598183861210526376290879332453719303445348402276468524520038553102488153312773899465147281921239076618316757808923215466576260899789404828017046198822651337873272103124257332250516281041620482143110025386131181133476132476234272552040616399080363625825591015424807027478372552093751608537199491399316600682691844230586410091912487370544253884676753297187569361664585802861243207450995330792824397357597743885787143247543232848497365133388472251630812390248704221825526552721721638852309675197702870877730309672534696647015828790855052823","m_caps":{"master_secret":"31326986252462886685731795343818157373290006314121231271456817164687053104943493919210987299664317917279607162961117668460493725536706336994992071609632454800495834884095596105618"},"r_caps":{}},"nonce":"563819600274040972421495"}"#;
+pub static LIBINDY_CRED_OFFER: &str = r#"{"schema_id":"V4SGRU86Z58d6TV7PBUe6f:2:FaberVcx:83.23.62","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","key_correctness_proof":{"c":"92806215623656847195507753264862805543601611023161727875216841746800082952324","xz_cap":"1184794664507890417739289702065653183336422949034857746406318981237372008366739572132458041455603590869299047822010854643368688060847773436412336530573392519421720188821875500733382213518826993363848346449144711124380642338678022295128770718780272205658334794762841804833152294784316854066053479013608673369803926400090620978445638643180198217567889011876965602870770651382041546491073128417245891598325812508076372199192441164070116986251040897977015555768819902768328582183032790087372789721788419760748001593691718569580808816590564060806704685153498854717022713006478970444398050995814842936043750732722769423941033361671290078587379861280373238394834768931646176289084733491584475949342365","xr_cap":[["sex","412823972315692560988099866161149290812078589370218517910321277011667945798028689037866085957254135716950655662775063740974982616389246594503612096110237453885366574695544683890538306252434009086365139052963699083279574111173928306615542315267037448634438992555209769547326137068214559103420308494772797600081169571616043039789161778010004355522015273855984005891208704611582821538630887193775357918789106329680063143632160182117321700446641737682183756951641540461512842400902791174142590955538027236700455268559237166877645242272864261136523968274148536511016284677617453554711574517976348159036735997567464482761902386529579763758666657378223331235443132452313067792256691295614296888831828"],["master_secret","569193621319167251565201814513629422096871271866248451616222738493210651919992002136147128442507369442296599737698945365427000117699976816841571897294023900420127756422800419819934410150143472671370099437377716455714486279379423692245356311063685215302879375958217828781772221172515164367537112862694131845990180586928533922004320934485578616419548310384652714685301367280175045465781516585316235679757011635728476849054105079383137307231520969166948378921066336344585868959847017761234702018249234409533251773287647989258368573617312739836971166649341701505152455906713467430663949882392326601742718861788359247837906743739058578956347277921522261454533227674333242635771822197521354663979851"],["date","2079113296441335872211231076354310967689450464262366203639089008265313310345655570439273139167423104602344051261427875782575061667407366531230480208778933968552576226678498604346708236930831533577806319615904748391640632070797101126211884112553290509506142809493742165242588849506185190241339204905232362693186964309109871511351612477501318687686223971557247811524068532752693326889243400594936533007748037481694438596284445265199877432124232025330871359662363524726534406705268608741490342597188698225517593559718983330075071028111008918536604765125916521301456736315501530727620937967348823834013777068342747308650790001006390136036463570368298565041941901083106297774320053343621608985698347"],["last_name","381096954699892043681539033288579333432540991930521065907547976399614095714513422963718888398952455525813292819733371337192407211003398804715769410257305093978137797873396157950532205389650274929861691748008857020982508401262190521375816878781917736966688723258886878552067170243972118058168054356894056038702705573326267809048740156000735483000409697204947657871114353750126193782501900407910536556020089825603824615645337208490988372451202917010240905598908418383255008513186809250719650634345982135274268432154522896967907532079346768170648145887568172924918638865241502635999921593006350938638558088641415289726607801495305703819028784983169825646585280563060145257285235473066645634473583"],["degree","2069123634224411881681621207899897742446302592582047590872718006590048877478956145518451032655239440084151422431138558643487219279831733629649578798689199695694638700091016884067876570950324212956111543747177636440146310473815005426163465410986974881219843956674242635127731527686743665556355635668164761186911137180531595324852939374365014109394145812190075709435535814268200659206625132649048800334895782662216340299633563835094276996835620832550567198564168913353805569514211871644741492691890417639345374834343386886715764012294622018147813854720674132147129601646891459501372930835414836834695998590835051448285679096376569412303307485016832814361890848137790806870963696355567668362270417"],["name","1600031689463487467204541542485851975165304096193841850812439844880437142682415481429410938053445297821870411413041933563915119790308019259191550023526744105396239608040357503750517120278464681836962617160002308249174045049379842112218985134191226350592467361995023678796239141283005243479128750862510979326517306591629362377851002682923616950655692548812640696390442555183079705241384189524299077089727629312684467098932843918505696933390796681749963349099191454166815075127994983063099123745610421159441264833006310734261279112883866829414488922482118310347115451479261518333049931372114465088054719445234476747092471922108007424100339569585433622675166347595849140148244114211915034049042977"],["age","1795121452649230665412500739883816616666044201923539440366668234781049690340969528161090577261526929747118258124907160014337746738550775198231422738115244268779766336683664786307548305137530436356214720657705997702848368569336885739205036090388136345740665266859878523659489085267193755382160415117972530780802493364347338631060711784912320216361295792587094355879089353099224168577316515760412168605888380716116079391160653522988608141634697803002135197909921675595613308909330309243721044019224741392694823192116156181118754775677046101907929115115426790795732011447290587829012196509768648884213094529010235143736730253838247973813388828280298875727512881017895179078503200796349206609412988"]]},"nonce":"657547075909751525981807"}"#;
 pub static LIBINDY_CRED_DEF: &str = r#"{"data":{"primary":{"n":"114746515059260256085234678788587166148355243180280315102090645942751161606786511238733348638566416417303497105088451106899855630983593307364239632151590963537585250064542598188468391931410646982219473876772131150980262917568589457733589819957223264524201617141236009287666907817621809981593970342911797033651200317354944243448934984118385522763230083200500151663687733598612590779617976091488475790945488832306909284594586246370188320528257434368782767295927647652836090950436948787834961967536745377246052043584698163549299065527670856685769132775303570669824694852645445261928275720246006236672142506689977275868361","r":{"address1":"99691666140371070101358855135773568925647233196811391526286824382905688163990792578749168604103123248884382828153399602940509345627800317904075610052247641753179080938711038002760487730651355496233514312301108772924047804779106854832742682362631853415382718650290457593916293375742714388123906183692192298839372942898471052447331045137271144004625111415456710024852530838725807061493074186193830563122576703301370004391867534587752756127556778961151976176561132968144358067501677783620960920599769114567213278491640769805211738029349174624671317568270236523750186824708848670440189718797166160769005983338957645374651","address2":"50694627350272135995850227472773682653526785886273680025707432662274319132892635359629398510776487145897598764958307032635008747216007028774721753289104990954080718757271373082072271206903673750118720632612520934845984571658325100469119390059220958605738125231926799396876392119909204431936776959112879901832499818726172148126455962771797164231412142732169157096077449075112596659842193123517211788791802444854497416778006528187110765212794812816350644158221720598510512869974792744115485570047548700807040200469385145744569019135119452695958980311814554081191423436762488471083593240293107044795731173106225269075879","city":"102559182306527236449083287004741425738959787531929072403797989548015397214062434117297561478541945525533027326788960769556765871071638166299517271476445491827022651938273628586072010918899170935494269888904933582635309954710186662573013491930971322773769929465525735190214323632698521462642758610601865824221106289034715039425726673460562081648867092963498261193872377056119189184137195233286115023395930817931291731614731826876607790483194892570452915675015342381665340157231556766697416007335116526753816159747328974818962282332973001195014744573290346271712396063166983772336582770882568845075757379081928828333484","state":"15713842569245737467211725139597063575206193864299839064963322883000040008821879926466964994443780733540685507582503521942067619465901516066678686301847079899815506652234811980107432963385421543779350493494079718474286652279834034399265138653939510696807388435204386612790208603957327635965005102296774157640016748464771397682922017927919437902023107754480395084067810355347611382320882015302277874585199781243184210455762324907919521828011758577554657145366604111620312856210314976491491503679972802443973537563280871769127378645286351511023979727441703919415153280572332149194429598144248477123785366270918320355405","zip":"82439749096689797099365560351239753105378075571995009495583420256509858774982681509800195848127624487713779483877286273210752251434593846321791407852670597416682439130048751917529301094961639109787459476514215677667528190858195940523300129805780128872794677939891404232119474525809874425239287980834285388300426560924836810318681834730312715238871863866236582938180704668392242864562995155085487776333282873729950673055006682711045087024364613715008969916100231057009286368917288450047513042924053264179070247044364634863653978644955951623206916995954379611610172587909771777058114259553518287236931107941424476615146"},"rctxt":"12882106218364489852198756063472503925546736423511030980775724102309731782619944795270841688773712762811510510057735673384647149369683830553010311398919415875035419497039071344961855906260704429386009769391235310734274528765933895442598586760877605228504009278242556373802663874078663963134235128930919205138500224113611155809593610721019344088231155625768052107598044580568892989818589777033707706414333277298933289666110152325572085417003440591173816185535447148436461626970208749404809055575072540846992803414294365454191025320389124986744387913853078792368035519762273705341214769545763267017647118784802102609501","rms":"106028334352956120254190539677164874113260919335177645538985439215552009817414268001747934794792622586283368183992307658729682605636119929047669814138904443196076047435578798659633647074839223564300440710991823806423760442858751109813721943066300113338814805016319371724589997933204691059213904593223879782913786796897287377655313546120850344752880386937803109231596086401736775016957962252911603462988048614441232196851204796503935873881697942972984657550803208348039692816399383898170960697113742325598099504349794803545598259703412134354513305415733108643762987048364882676189469605110499746876278508590562888471309","s":"15678755827306057743760600028386502810123351546016461235476681362228825598831102199528795536128972467030169856793286910636446189899528748221680209430012552769741678665585748260276066556135386421782992251507127914167374251212176339062137692918334797451372750158620810700982064996262697961072684582507781695193053082112842190405411069106829867359524406960151388270743010774947305777397414657077050738998972524245537892741523091928637751005795657714227975900027201931853690576205714337633635803339006630861198097918548079506836227430559089772233951013727478631851053766855771442468532212938213839384294416442896733429506","z":"107844580696935267224685953710248721588061270382223646096349113942754030931632137466395766282891847634657182239048215576555350103258761763383903208677615631334632643004349863812873041076611716205401052812505603586763826866994143145234436298946833325296153637822126459684843587236075925037465050361093439139718472639710725193340606561954142895780104125189011472434268099373557377935176988976044145686558487862982478674750049266524295725097728797460210399377848983033250414174145298927648861452166644982315308937830863192556869355971023436985865987383144838977793063894497859875560718743368644008878035558886398529867651"}},"identifier":"GGBDg1j8bsKmr4h5T9XqYf","origin":"2hoqvcwupRTUNkXn6ArYzs","ref":1487,"reqId":1522866729726860308,"seqNo":1488,"signature_type":"CL","state_proof":{"multi_signature":{"participants":["Node4","Node3","Node1"],"signature":"R5cNGakn84PoGo4p9U6Y8CyYKGbRUE5mM78yA88KkvSDvWqN8jptp2hSTdzLgYgfWibbFcEv3iAxWH3LGxk9okwr38K6RR35ZbAttQHCafmWGwiUAMoKHoFrH8nMLWzb7A9ZXhb7uUYrSXp3zgZb2xFX83Q8kjmFTkvqcvZVy9uMt3","value":{"ledger_id":1,"pool_state_root_hash":"DuhjUiR6QDsT4X3KFTGHgPnaCCTTVMhmmA8uRwkkhDwA","state_root_hash":"3tses33E3t9z7W2gvHk8LizYcjWLTvGZwGZd3Q46SUUm","timestamp":1522824724,"txn_root_hash":"F3iggcw2svzk5uSynSAUQcsE6mKXBWRz5bXJoNvvBaRe"}},"proof_nodes":"+SGN4hOgjDfvaVyBfwtNlXiJD4lMZQeLMHzsAkP/pSKsfAZ6rkn40YCg3c/1kISB4pJHqTOxHXBSdzwl5iC5MX0C9G0ceD3xxNyAoPXB+8LMotY/0qas0C5UYHiGhufecleFu3QLM371MAxvgKDZwH646BRcMMlkhVUODumTpgpZ69XwNheU+IFumV4NJoCgcMNOI89GH1cmupClasMY/JnoUAGWxFZ5AFIi5wXGrB+g6RA7lLdOue0ZvjIyENBeU+pU/98GMRKNK9/IIinUAZSgQ3GwUvuVKlj6cA7ecDmtGYGi2y6dSLzBOR+TOH/qBYWAgICAgICA4hOgwT5AhKtLJWDWb9/tVNZGPbbMkON9RdyJMABtPUL8KSb4kYCg6dtQ/Fhndta7VHxiI1loRbC4OYsWrIs0SAFQTxrEJriAoEv/yTKO+/mf5czjRntRw3ZMlJJfssMABysXO4lDtnSogKDLv5krDrA+AU28QqWtoK+sB/U8B8HRtA7h3NbAxY1CHYCgo295NuH+4DKQWGMOxqVqoHLY9Q/BhHpst26feN8ZdMGAgICAgICAgID4UYCAoBcErMgDu3yu4RVRhrWVC16/M4hCS2yPWVcsP9QhFMe9oKqfH15wamU9ANR82jt6lMG4KzlFYyMVZ66FMFhiBdzwgICAgICAgICAgICAgPkBMaA6dGrtfugJlXVAH2h+78VOJ+Kp0GDot733GYQ5SaMif6BAYqh+Z/HaT0YciVe31CoEER1w7ug46oSTWmg8ideWdqCdmnil+INbwFtef9rJ23KWVOxLEGpekmdpD2szHdTxAqAPFpoPAHqIWS+0rn1cc+XpMyUikJ63oBfATZ+fGisu3KDRMff7h6mUjArlXOiLu9XvRVAAeqYkCxHXZB2Hku1Pa6AAtKzLK6OHX7LNHmRmN8hQCqL35cKZ5Mc57Tl4a9R0ZKA4e8VMr3+D4Tsv3cqRcyYS9lOQIx92KgSYg7dSVxHXlqC3iPf41ux9xUa3JN8qq8a4mjosz+Z4oD7Kc/RPhNkSCoCgY4NGjrlmlgUwgVKcuU2SoHbmGa1TIj39CX5j8Qm3MiCAgICAgICA54UTpDTDo6C4JADeo3ZbFk0Z1XN1mxwW0WQqgVRAbnEx5W7NUSithuIToP+QG77b7atogQGq9In+38W4M2XfioeXYmZ9BJ4TY4Nc+FGAgICAgICgo3awcJ8LKWBy2rfzyzJsuBTDLA7p9BPM7/L7xBdYZ8iAgICAgICAoD6rl+d6X/9kWMp9Y6fc9Jw7yTpB2SLv3D7LZkAJhRNmgID5GMIguRi++Ri7uRi4eyJsc24iOjE0ODgsImx1dCI6MTUyMjc2OTgxMiwidmFsIjp7InByaW1hcnkiOnsibiI6IjExNDc0NjUxNTA1OTI2MDI1NjA4NTIzNDY3ODc4ODU4NzE2NjE0ODM1NTI0MzE4MDI4MDMxNTEwMjA5MDY0NTk0Mjc1MTE2MTYwNjc4NjUxMTIzODczMzM0ODYzODU2NjQxNjQxNzMwMzQ5NzEwNTA4ODQ1MTEwNjg5OTg1NTYzMDk4MzU5MzMwNzM2NDIzOTYzMjE1MTU5MDk2MzUzNzU4NTI1MDA2NDU0MjU5ODE4ODQ2ODM5MTkzMTQxMDY0Njk4MjIxOTQ3Mzg3Njc3MjEzMTE1MDk4MDI2MjkxNzU2ODU4OTQ1NzczMzU4OTgxOTk1NzIyMzI2NDUyNDIwMTYxNzE0MTIzNjAwOTI4NzY2NjkwNzgxNzYyMTgwOTk4MTU5Mzk3MDM0MjkxMTc5NzAzMzY1MTIwMDMxNzM1NDk0NDI0MzQ0ODkzNDk4NDExODM4NTUyMjc2MzIzMDA4MzIwMDUwMDE1MTY2MzY4NzczMzU5ODYxMjU5MDc3OTYxNzk3NjA5MTQ4ODQ3NTc5MDk0NTQ4ODgzMjMwNjkwOTI4NDU5NDU4NjI0NjM3MDE4ODMyMDUyODI1NzQzNDM2ODc4Mjc2NzI5NTkyNzY0NzY1MjgzNjA5MDk1MDQzNjk0ODc4NzgzNDk2MTk2NzUzNjc0NTM3NzI0NjA1MjA0MzU4NDY5ODE2MzU0OTI5OTA2NTUyNzY3MDg1NjY4NTc2OTEzMjc3NTMwMzU3MDY2OTgyNDY5NDg1MjY0NTQ0NTI2MTkyODI3NTcyMDI0NjAwNjIzNjY3MjE0MjUwNjY4OTk3NzI3NTg2ODM2MSIsInIiOnsiYWRkcmVzczEiOiI5OTY5MTY2NjE0MDM3MTA3MDEwMTM1ODg1NTEzNTc3MzU2ODkyNTY0NzIzMzE5NjgxMTM5MTUyNjI4NjgyNDM4MjkwNTY4ODE2Mzk5MDc5MjU3ODc0OTE2ODYwNDEwMzEyMzI0ODg4NDM4MjgyODE1MzM5OTYwMjk0MDUwOTM0NTYyNzgwMDMxNzkwNDA3NTYxMDA1MjI0NzY0MTc1MzE3OTA4MDkzODcxMTAzODAwMjc2MDQ4NzczMDY1MTM1NTQ5NjIzMzUxNDMxMjMwMTEwODc3MjkyNDA0NzgwNDc3OTEwNjg1NDgzMjc0MjY4MjM2MjYzMTg1MzQxNTM4MjcxODY1MDI5MDQ1NzU5MzkxNjI5MzM3NTc0MjcxNDM4ODEyMzkwNjE4MzY5MjE5MjI5ODgzOTM3Mjk0Mjg5ODQ3MTA1MjQ0NzMzMTA0NTEzNzI3MTE0NDAwNDYyNTExMTQxNTQ1NjcxMDAyNDg1MjUzMDgzODcyNTgwNzA2MTQ5MzA3NDE4NjE5MzgzMDU2MzEyMjU3NjcwMzMwMTM3MDAwNDM5MTg2NzUzNDU4Nzc1Mjc1NjEyNzU1Njc3ODk2MTE1MTk3NjE3NjU2MTEzMjk2ODE0NDM1ODA2NzUwMTY3Nzc4MzYyMDk2MDkyMDU5OTc2OTExNDU2NzIxMzI3ODQ5MTY0MDc2OTgwNTIxMTczODAyOTM0OTE3NDYyNDY3MTMxNzU2ODI3MDIzNjUyMzc1MDE4NjgyNDcwODg0ODY3MDQ0MDE4OTcxODc5NzE2NjE2MDc2OTAwNTk4MzMzODk1NzY0NTM3NDY1MSIsImFkZHJlc3MyIjoiNTA2OTQ2MjczNTAyNzIxMzU5OTU4NTAyMjc0NzI3NzM2ODI2NTM1MjY3ODU4ODYyNzM2ODAwMjU3MDc0MzI2NjIyNzQzMTkxMzI4OTI2MzUzNTk2MjkzOTg1MTA3NzY0ODcxNDU4OTc1OTg3NjQ5NTgzMDcwMzI2MzUwMDg3NDcyMTYwMDcwMjg3NzQ3MjE3NTMyODkxMDQ5OTA5NTQwODA3MTg3NTcyNzEzNzMwODIwNzIyNzEyMDY5MDM2NzM3NTAxMTg3MjA2MzI2MTI1MjA5MzQ4NDU5ODQ1NzE2NTgzMjUxMDA0NjkxMTkzOTAwNTkyMjA5NTg2MDU3MzgxMjUyMzE5MjY3OTkzOTY4NzYzOTIxMTk5MDkyMDQ0MzE5MzY3NzY5NTkxMTI4Nzk5MDE4MzI0OTk4MTg3MjYxNzIxNDgxMjY0NTU5NjI3NzE3OTcxNjQyMzE0MTIxNDI3MzIxNjkxNTcwOTYwNzc0NDkwNzUxMTI1OTY2NTk4NDIxOTMxMjM1MTcyMTE3ODg3OTE4MDI0NDQ4NTQ0OTc0MTY3NzgwMDY1MjgxODcxMTA3NjUyMTI3OTQ4MTI4MTYzNTA2NDQxNTgyMjE3MjA1OTg1MTA1MTI4Njk5NzQ3OTI3NDQxMTU0ODU1NzAwNDc1NDg3MDA4MDcwNDAyMDA0NjkzODUxNDU3NDQ1NjkwMTkxMzUxMTk0NTI2OTU5NTg5ODAzMTE4MTQ1NTQwODExOTE0MjM0MzY3NjI0ODg0NzEwODM1OTMyNDAyOTMxMDcwNDQ3OTU3MzExNzMxMDYyMjUyNjkwNzU4NzkiLCJjaXR5IjoiMTAyNTU5MTgyMzA2NTI3MjM2NDQ5MDgzMjg3MDA0NzQxNDI1NzM4OTU5Nzg3NTMxOTI5MDcyNDAzNzk3OTg5NTQ4MDE1Mzk3MjE0MDYyNDM0MTE3Mjk3NTYxNDc4NTQxOTQ1NTI1NTMzMDI3MzI2Nzg4OTYwNzY5NTU2NzY1ODcxMDcxNjM4MTY2Mjk5NTE3MjcxNDc2NDQ1NDkxODI3MDIyNjUxOTM4MjczNjI4NTg2MDcyMDEwOTE4ODk5MTcwOTM1NDk0MjY5ODg4OTA0OTMzNTgyNjM1MzA5OTU0NzEwMTg2NjYyNTczMDEzNDkxOTMwOTcxMzIyNzczNzY5OTI5NDY1NTI1NzM1MTkwMjE0MzIzNjMyNjk4NTIxNDYyNjQyNzU4NjEwNjAxODY1ODI0MjIxMTA2Mjg5MDM0NzE1MDM5NDI1NzI2NjczNDYwNTYyMDgxNjQ4ODY3MDkyOTYzNDk4MjYxMTkzODcyMzc3MDU2MTE5MTg5MTg0MTM3MTk1MjMzMjg2MTE1MDIzMzk1OTMwODE3OTMxMjkxNzMxNjE0NzMxODI2ODc2NjA3NzkwNDgzMTk0ODkyNTcwNDUyOTE1Njc1MDE1MzQyMzgxNjY1MzQwMTU3MjMxNTU2NzY2Njk3NDE2MDA3MzM1MTE2NTI2NzUzODE2MTU5NzQ3MzI4OTc0ODE4OTYyMjgyMzMyOTczMDAxMTk1MDE0NzQ0NTczMjkwMzQ2MjcxNzEyMzk2MDYzMTY2OTgzNzcyMzM2NTgyNzcwODgyNTY4ODQ1MDc1NzU3Mzc5MDgxOTI4ODI4MzMzNDg0Iiwic3RhdGUiOiIxNTcxMzg0MjU2OTI0NTczNzQ2NzIxMTcyNTEzOTU5NzA2MzU3NTIwNjE5Mzg2NDI5OTgzOTA2NDk2MzMyMjg4MzAwMDA0MDAwODgyMTg3OTkyNjQ2Njk2NDk5NDQ0Mzc4MDczMzU0MDY4NTUwNzU4MjUwMzUyMTk0MjA2NzYxOTQ2NTkwMTUxNjA2NjY3ODY4NjMwMTg0NzA3OTg5OTgxNTUwNjY1MjIzNDgxMTk4MDEwNzQzMjk2MzM4NTQyMTU0Mzc3OTM1MDQ5MzQ5NDA3OTcxODQ3NDI4NjY1MjI3OTgzNDAzNDM5OTI2NTEzODY1MzkzOTUxMDY5NjgwNzM4ODQzNTIwNDM4NjYxMjc5MDIwODYwMzk1NzMyNzYzNTk2NTAwNTEwMjI5Njc3NDE1NzY0MDAxNjc0ODQ2NDc3MTM5NzY4MjkyMjAxNzkyNzkxOTQzNzkwMjAyMzEwNzc1NDQ4MDM5NTA4NDA2NzgxMDM1NTM0NzYxMTM4MjMyMDg4MjAxNTMwMjI3Nzg3NDU4NTE5OTc4MTI0MzE4NDIxMDQ1NTc2MjMyNDkwNzkxOTUyMTgyODAxMTc1ODU3NzU1NDY1NzE0NTM2NjYwNDExMTYyMDMxMjg1NjIxMDMxNDk3NjQ5MTQ5MTUwMzY3OTk3MjgwMjQ0Mzk3MzUzNzU2MzI4MDg3MTc2OTEyNzM3ODY0NTI4NjM1MTUxMTAyMzk3OTcyNzQ0MTcwMzkxOTQxNTE1MzI4MDU3MjMzMjE0OTE5NDQyOTU5ODE0NDI0ODQ3NzEyMzc4NTM2NjI3MDkxODMyMDM1NTQwNSIsInppcCI6IjgyNDM5NzQ5MDk2Njg5Nzk3MDk5MzY1NTYwMzUxMjM5NzUzMTA1Mzc4MDc1NTcxOTk1MDA5NDk1NTgzNDIwMjU2NTA5ODU4Nzc0OTgyNjgxNTA5ODAwMTk1ODQ4MTI3NjI0NDg3NzEzNzc5NDgzODc3Mjg2MjczMjEwNzUyMjUxNDM0NTkzODQ2MzIxNzkxNDA3ODUyNjcwNTk3NDE2NjgyNDM5MTMwMDQ4NzUxOTE3NTI5MzAxMDk0OTYxNjM5MTA5Nzg3NDU5NDc2NTE0MjE1Njc3NjY3NTI4MTkwODU4MTk1OTQwNTIzMzAwMTI5ODA1NzgwMTI4ODcyNzk0Njc3OTM5ODkxNDA0MjMyMTE5NDc0NTI1ODA5ODc0NDI1MjM5Mjg3OTgwODM0Mjg1Mzg4MzAwNDI2NTYwOTI0ODM2ODEwMzE4NjgxODM0NzMwMzEyNzE1MjM4ODcxODYzODY2MjM2NTgyOTM4MTgwNzA0NjY4MzkyMjQyODY0NTYyOTk1MTU1MDg1NDg3Nzc2MzMzMjgyODczNzI5OTUwNjczMDU1MDA2NjgyNzExMDQ1MDg3MDI0MzY0NjEzNzE1MDA4OTY5OTE2MTAwMjMxMDU3MDA5Mjg2MzY4OTE3Mjg4NDUwMDQ3NTEzMDQyOTI0MDUzMjY0MTc5MDcwMjQ3MDQ0MzY0NjM0ODYzNjUzOTc4NjQ0OTU1OTUxNjIzMjA2OTE2OTk1OTU0Mzc5NjExNjEwMTcyNTg3OTA5NzcxNzc3MDU4MTE0MjU5NTUzNTE4Mjg3MjM2OTMxMTA3OTQxNDI0NDc2NjE1MTQ2In0sInJjdHh0IjoiMTI4ODIxMDYyMTgzNjQ0ODk4NTIxOTg3NTYwNjM0NzI1MDM5MjU1NDY3MzY0MjM1MTEwMzA5ODA3NzU3MjQxMDIzMDk3MzE3ODI2MTk5NDQ3OTUyNzA4NDE2ODg3NzM3MTI3NjI4MTE1MTA1MTAwNTc3MzU2NzMzODQ2NDcxNDkzNjk2ODM4MzA1NTMwMTAzMTEzOTg5MTk0MTU4NzUwMzU0MTk0OTcwMzkwNzEzNDQ5NjE4NTU5MDYyNjA3MDQ0MjkzODYwMDk3NjkzOTEyMzUzMTA3MzQyNzQ1Mjg3NjU5MzM4OTU0NDI1OTg1ODY3NjA4Nzc2MDUyMjg1MDQwMDkyNzgyNDI1NTYzNzM4MDI2NjM4NzQwNzg2NjM5NjMxMzQyMzUxMjg5MzA5MTkyMDUxMzg1MDAyMjQxMTM2MTExNTU4MDk1OTM2MTA3MjEwMTkzNDQwODgyMzExNTU2MjU3NjgwNTIxMDc1OTgwNDQ1ODA1Njg4OTI5ODk4MTg1ODk3NzcwMzM3MDc3MDY0MTQzMzMyNzcyOTg5MzMyODk2NjYxMTAxNTIzMjU1NzIwODU0MTcwMDM0NDA1OTExNzM4MTYxODU1MzU0NDcxNDg0MzY0NjE2MjY5NzAyMDg3NDk0MDQ4MDkwNTU1NzUwNzI1NDA4NDY5OTI4MDM0MTQyOTQzNjU0NTQxOTEwMjUzMjAzODkxMjQ5ODY3NDQzODc5MTM4NTMwNzg3OTIzNjgwMzU1MTk3NjIyNzM3MDUzNDEyMTQ3Njk1NDU3NjMyNjcwMTc2NDcxMTg3ODQ4MDIxMDI2MDk1MDEiLCJybXMiOiIxMDYwMjgzMzQzNTI5NTYxMjAyNTQxOTA1Mzk2NzcxNjQ4NzQxMTMyNjA5MTkzMzUxNzc2NDU1Mzg5ODU0MzkyMTU1NTIwMDk4MTc0MTQyNjgwMDE3NDc5MzQ3OTQ3OTI2MjI1ODYyODMzNjgxODM5OTIzMDc2NTg3Mjk2ODI2MDU2MzYxMTk5MjkwNDc2Njk4MTQxMzg5MDQ0NDMxOTYwNzYwNDc0MzU1Nzg3OTg2NTk2MzM2NDcwNzQ4MzkyMjM1NjQzMDA0NDA3MTA5OTE4MjM4MDY0MjM3NjA0NDI4NTg3NTExMDk4MTM3MjE5NDMwNjYzMDAxMTMzMzg4MTQ4MDUwMTYzMTkzNzE3MjQ1ODk5OTc5MzMyMDQ2OTEwNTkyMTM5MDQ1OTMyMjM4Nzk3ODI5MTM3ODY3OTY4OTcyODczNzc2NTUzMTM1NDYxMjA4NTAzNDQ3NTI4ODAzODY5Mzc4MDMxMDkyMzE1OTYwODY0MDE3MzY3NzUwMTY5NTc5NjIyNTI5MTE2MDM0NjI5ODgwNDg2MTQ0NDEyMzIxOTY4NTEyMDQ3OTY1MDM5MzU4NzM4ODE2OTc5NDI5NzI5ODQ2NTc1NTA4MDMyMDgzNDgwMzk2OTI4MTYzOTkzODM4OTgxNzA5NjA2OTcxMTM3NDIzMjU1OTgwOTk1MDQzNDk3OTQ4MDM1NDU1OTgyNTk3MDM0MTIxMzQzNTQ1MTMzMDU0MTU3MzMxMDg2NDM3NjI5ODcwNDgzNjQ4ODI2NzYxODk0Njk2MDUxMTA0OTk3NDY4NzYyNzg1MDg1OTA1NjI4ODg0NzEzMDkiLCJzIjoiMTU2Nzg3NTU4MjczMDYwNTc3NDM3NjA2MDAwMjgzODY1MDI4MTAxMjMzNTE1NDYwMTY0NjEyMzU0NzY2ODEzNjIyMjg4MjU1OTg4MzExMDIxOTk1Mjg3OTU1MzYxMjg5NzI0NjcwMzAxNjk4NTY3OTMyODY5MTA2MzY0NDYxODk4OTk1Mjg3NDgyMjE2ODAyMDk0MzAwMTI1NTI3Njk3NDE2Nzg2NjU1ODU3NDgyNjAyNzYwNjY1NTYxMzUzODY0MjE3ODI5OTIyNTE1MDcxMjc5MTQxNjczNzQyNTEyMTIxNzYzMzkwNjIxMzc2OTI5MTgzMzQ3OTc0NTEzNzI3NTAxNTg2MjA4MTA3MDA5ODIwNjQ5OTYyNjI2OTc5NjEwNzI2ODQ1ODI1MDc3ODE2OTUxOTMwNTMwODIxMTI4NDIxOTA0MDU0MTEwNjkxMDY4Mjk4NjczNTk1MjQ0MDY5NjAxNTEzODgyNzA3NDMwMTA3NzQ5NDczMDU3NzczOTc0MTQ2NTcwNzcwNTA3Mzg5OTg5NzI1MjQyNDU1Mzc4OTI3NDE1MjMwOTE5Mjg2Mzc3NTEwMDU3OTU2NTc3MTQyMjc5NzU5MDAwMjcyMDE5MzE4NTM2OTA1NzYyMDU3MTQzMzc2MzM2MzU4MDMzMzkwMDY2MzA4NjExOTgwOTc5MTg1NDgwNzk1MDY4MzYyMjc0MzA1NTkwODk3NzIyMzM5NTEwMTM3Mjc0Nzg2MzE4NTEwNTM3NjY4NTU3NzE0NDI0Njg1MzIyMTI5MzgyMTM4MzkzODQyOTQ0MTY0NDI4OTY3MzM0Mjk1MDYiLCJ6IjoiMTA3ODQ0NTgwNjk2OTM1MjY3MjI0Njg1OTUzNzEwMjQ4NzIxNTg4MDYxMjcwMzgyMjIzNjQ2MDk2MzQ5MTEzOTQyNzU0MDMwOTMxNjMyMTM3NDY2Mzk1NzY2MjgyODkxODQ3NjM0NjU3MTgyMjM5MDQ4MjE1NTc2NTU1MzUwMTAzMjU4NzYxNzYzMzgzOTAzMjA4Njc3NjE1NjMxMzM0NjMyNjQzMDA0MzQ5ODYzODEyODczMDQxMDc2NjExNzE2MjA1NDAxMDUyODEyNTA1NjAzNTg2NzYzODI2ODY2OTk0MTQzMTQ1MjM0NDM2Mjk4OTQ2ODMzMzI1Mjk2MTUzNjM3ODIyMTI2NDU5Njg0ODQzNTg3MjM2MDc1OTI1MDM3NDY1MDUwMzYxMDkzNDM5MTM5NzE4NDcyNjM5NzEwNzI1MTkzMzQwNjA2NTYxOTU0MTQyODk1NzgwMTA0MTI1MTg5MDExNDcyNDM0MjY4MDk5MzczNTU3Mzc3OTM1MTc2OTg4OTc2MDQ0MTQ1Njg2NTU4NDg3ODYyOTgyNDc4Njc0NzUwMDQ5MjY2NTI0Mjk1NzI1MDk3NzI4Nzk3NDYwMjEwMzk5Mzc3ODQ4OTgzMDMzMjUwNDE0MTc0MTQ1Mjk4OTI3NjQ4ODYxNDUyMTY2NjQ0OTgyMzE1MzA4OTM3ODMwODYzMTkyNTU2ODY5MzU1OTcxMDIzNDM2OTg1ODY1OTg3MzgzMTQ0ODM4OTc3NzkzMDYzODk0NDk3ODU5ODc1NTYwNzE4NzQzMzY4NjQ0MDA4ODc4MDM1NTU4ODg2Mzk4NTI5ODY3NjUxIn19ffkBcaBPvWULqOwxMeXc9rVGPWeY/zrjenpinABGeavylxg9VKDSZgBk2repnWQF/NngiQn0k+hJj2w23mlKI/LVJ6yjLqAfaLK0d9PblIbRzlok6u21QjS4Dx7muCERAkmnKxIY0YCgRXB16H9S0x4fH5yFIemub7inDuOG67829/Y739jLMGWgo6VMJB4cemKJEio5Z/5mLKgTVHMYtjBi/9lP3ZuPlNSgNixAUxLZroi10PDukPsUxuEAXLnRaC+ku1zk3eRSg+egQWmwWHj+ec9D1ru4m47Vo4X/R4tlk9uTrUapmuG4fWCAoAoTTzqKqJFLJXTlBzO3RoFrsMHHRV7XiX/ZNGqkeZLdgKC/EIAumu/LD50YY3W1ErcKpTz+Ezhqz8mAuBQGLTfwPYCgR/+SU8YpYYvma5FK35JDtimGrOxiEFvh1HnAOjYNLB+AoIIe6s3NQv9J9TrOd5WW/SDVN98RV2lEhp/RPDSpBVKMgPg5lwCG9xdmN3dXBSVFVOa1huNkFyWXpzOgoL8NincrZ6kIy7QoYzPJhIp6iRUiUHmOAAj0d8Bxc3lI+QExgKAfJEVEiRmtfCUzf3UP9AYqzpaoudKZn5eIVoAQc+gyWKB3xACdspYL0Y6Jn2RPj5VFkbqYGwMEj+RHYS4pb+oSLqDJQTYScc5um90wXnIBziFOlONr9GXPRQGiBncfqA/LzqDVq2Aj26NN2nwQT5YDVPofc+xc1QPpl3XF4o/dtqwjrqBzJnB3BMBhzazaqgNcRRsTcEvHjRv1ZBVzDswD4tAK/KD4QbfFSniHCftdyvCLBbZhewAxCAMTH5kOUSzteq1PzaDfgdEGxnHHmSzniVnuBX9Bq9KNZF0IhAuCQ4b4UyCir6DQqeWiudjwNTKSrJZNbaa+k7fbTN6aaMGN7GAbWqbcD6D4GNYr9NMr/K5N572hzngAoGPpNBtsVF2c5wFGK/WV54CAgICAgID5AhGgZBqeju/DK9w6gsSRyeuChMfPiz3O5CJOt05Cwx6+Zq6g/qAj00WnUVvBv214PQY/n6Hz3ge+kdXc7bRq2zC31cCgK9Hxi/K6rqzGHb2hV1UhJwZV9RZh9kW/ROGpoNzP26qgWBd12S383lcW+7Kyb9gp/OyP5U+/eB3gkMTH/QzEhLag8yqxFoLeuvR5GBbAv96n6C5AFillVcGHJO1MCpH/nJugongKEEAA+Um2j9Oj6rBtKYKjeEb3zIv6vDwMB4ltGGWg1+BLf5OUANOczDp0kjz6BRqNExkadkzr5uKEhjae0i6g0kXS+Xw39zJ5DUOqKiMIKl3hcaVTIgnAEmke90PmIo+gjN41X4+PgYvGipSLeCRvP8LeGMm6Ot4iJbpNofTlRXqgqvwhtqLvuNKkBdPMSDAO28IarXiYXDhae80T+EiDK32gYJHzb9ZyTTcqeX/PSDKzNWm9SWT2MtEgxez0oIfijkmgMO//SplF4wKP6kvUTbLsUTi8LGdIzMJUL0q7t9AMom6gGtQvALeksBhOSUpKNXku8JP9WmQOhVSD9lk04NrphKCgdeaCcxJPJmGzB06WXeTH7Nr69ZLF5S5ahB/coqXdupygUKah9cJa5x9dtgVS6rymjOoNTbrSuw+AxvrqhZgYgI6gE+UVSRl8WVc4BZyM+zE9W5OEO58lpO97/pTn2PDkiB+A","root_hash":"3tses33E3t9z7W2gvHk8LizYcjWLTvGZwGZd3Q46SUUm"},"txnTime":1522769812,"type":"108"}"#;
 pub static CREATE_SCHEMA_RESULT: &str = r#"{"op":"REPLY","result":{"rootHash":"C98M4qjp4zzHw6APDWwGxTBHkEdAhjUQepi3Bxz2auna","type":"101","signature":"4iFhpLknpRiCU6Axrj8HcFxMaxGaMmnzwJ1WMKndK653k4B7LYGZD2PNHEEGZQEBVXwhgDxPFe1t9bSzdVcEQ3eL","reqId":1515795761424583710,"auditPath":["7hRA1eWgHDmqFfXQHmHLzCE1ZeXvvkq5VaJEpb6NWz74","4QvchQ6JGxvU57kyzHzKJvUV7rb12jpFX7FBP9LrN9qA","G14qswNCM1mxhRHPMLx4h5qmbLEDQkczjJUVUEedUGxQ","4B6hCrJc2TubiFE1rgxjM1Hj7zvTTjxkzo9Gikhy4MVZ"],"data":{"name":"name","version":"1.0","attr_names":["name","male"]},"seqNo":299,"identifier":"VsKV7grR1BUE29mG2Fm2kX","txnTime":1515795761}}"#;
-pub static CREDENTIAL_JSON: &str = r#"{"claim": { "address1": [ "123 Main St", "67178325706055440519333844001910714778187200326224626679030947715618922270834" ], "address2": [ "Suite 3", "14069420537488782212572815457694763597282389356553685124587846316416790412172" ], "city": [ "Draper", "12909874379699016994385580221532801536154776321388073746338293599464917909913" ], "state": [ "UT", "93856629670657830351991220989031130499313559332549427637940645777813964461231" ], "zip": [ "84000", "84000" ] }, "claim_offer_id": "n2vlytr", "from_did": "A5zycNieiKQQU6xQTtR3ie", "issuer_did": "2hoqvcwupRTUNkXn6ArYzs", "msg_type": "CRED", "schema_seq_no": 22, "signature": { "non_revocation_claim": null, "primary_claim": { "a": "67244059587509386261445845549403679184696388188135088719570347990957702854576331330763123108600111867246898203893942330532414276708236493565050051577255278475231198777619682053425511310805374629000032369988817946046983751759949589692002588340296869187196574372221898903115017422604977611873108946802618066478041981199841837601172757327153617669273883040639649832266729961077172477776787709368285904991774256514200981595163262342626363217452242070410058562010319101992520148282733995635010533432768876074037878998373839551569333559642205700332716013642805666606069808481186570157152956520080458539520791159072055699608", "e": "259344723055062059907025491480697571938277889515152306249728583105665800713306759149981690559193987143012367913206299323899696942213235956742929979955970602668519234522036604952927", "m2": "73598276571237653510985065573388298358260861260139843942970542810506069131247", "v": "7782040862611754129906364472072177993242400915902838921150044188825385426320744155674271999800409192400475215606427400576174714844912910889635915209442760601639503812483333710498966677032266043782534729495630058139079759294540063522862433832217680399967662575805572449362848616899654527283911479159798692836920609447117694604550229460246691843256094252578966531478355690870011917944269894281735089639287126305305853350364136525483737759256221150059629492378671054376973353349516063810767461334097264068335988545196188611499359009325998360279987424407962189883068449914753466665140753744386975512941589134281298094791056350565149274890478146205610581609463698788908501060583315271304706101286351923769293430142774178612205047744806182681425621706093102973045356561983323064302333475988590139227392601738347814619853533067" } }, "version": "0.1" }"#;
+pub static CREDENTIAL_JSON: &str = r#"{"schema_id":"V4SGRU86Z58d6TV7PBUe6f:2:FaberVcx:83.23.62","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","rev_reg_id":"V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1:CL_ACCUM:tag1","values":{"name":{"raw":"alice","encoded":"19831138297880367962895005496563562590284654704047651305948751287370224856720"},"age":{"raw":"25","encoded":"25"},"sex":{"raw":"female","encoded":"71957174156108022857985543806816820198680233386048843176560473245156249119752"},"date":{"raw":"05-2018","encoded":"101085817956371643310471822530712840836446570298192279302750234554843339322886"},"last_name":{"raw":"clark","encoded":"51192516729287562420368242940555165528396706187345387515033121164720912081028"},"degree":{"raw":"maths","encoded":"78137204873448776862705240258723141940757006710839733585634143215803847410018"}},"signature":{"p_credential":{"m_2":"34239492586603451132793432079627968880304030575812468226470636874751822858762","a":"3563131870571341562942447213215431793764331728811304513850423726584197868226953531211853518564185788421871086264703340351923854887295149243812720642580418187989823626988903301028859474352611893592620689431903289577500208193942359155514839092142415639545420995005344634496843154309379562341177883976932547420934334464659261988488683012480532976671889020837582586293529732428621563888453356419207774056583362932487901416696251200138928778719284124992702374614067490296832134122023385269276680417470517906015550348947567285667506407569680396891991575967982029847074598117891891522303427746502989090161328829469233540877","e":"259344723055062059907025491480697571938277889515152306249728583105665800713306759149981690559193987143012367913206299323899696942213235956742929829980207352210542787871744029957649","v":"5189282183832435863300765290818223025067469534318251742959519592331847887508203933635962125771260855185093545027477595844939404698982783628016776235695366260102138275373351273904130201717691903057434095185823117503663524326601494097274119604086316973344746599275286735089713311253444600164511949088907577080069435861224620218862152104267428843263052480679286948469204793959405508409387101565382789844595280641549760029760646688577467049437823519541743657954151593529704009735104561122628765293975667820624083117407972393575542353158748960486750772728245613246377354797137546851465874461135342276305028758637295673836305254475821204665871482118672068540011675974248109434460390394912373750254930136724230358537313974427177685036208302156289370488454015844354280625604252054853498665457468057423726387120729428080005427189"},"r_credential":{"sigma":"1 1FD3E477B5B2EF743C10024C7D5E7A53B005D774D324C8A709C1B70607943CD0 1 1B3DE6D2523D42823AADBA8C2CDE399A8E1AC570867D60EBBE379D2915574AAB 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","c":"0DA77597D2F83A5DBA58A32898246C95232F7BF3C8670E4DB54481C01A1171F1","vr_prime_prime":"0CD32F20701844F028CEBD87EF6E693B821A510F1826B1C28502AEB7C979765F","witness_signature":{"sigma_i":"1 1632DD67156E240A6E88111EDAB4E04AACC68516AD3A60E3B906103858C6E84B 1 136BDFC25131C56E27D778117E4D5FA0F8EF1E26B91FCC6567316D319D25733C 1 190071FFFD39CF96340C5982654E25DE9CDF3F2528ACBDD7B3687ABA0E49FC90 1 112D221855AAEDB4CD21206B2DAE88E22639FC35FF48A5381E434BE19500F30F 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","u_i":"1 20342585C1562CA3DFF2B1056DBC4F10B0B948B23C9980F11227E0A18FF72CB3 1 23293523262385202578753B5FE8F430E221435FE09B301A23424CF27F850CD4 1 021BBD73F1EEEAF70D657345EF44E05A9C22F83B4FB6715509237969DC9D966A 1 0F62D50D13D628DC8A5376E15BF5623F3F8C5EB422BEBE84D846D8E01C01A988 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","g_i":"1 10E29218D1B97E1129104DAD5C754F867F8DA391BB771A8A7CA406477B702561 1 09B9874680AE435F4646B80565CF680CD1A5DF0728F05642D8F225EEC9738224 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8"},"g_i":"1 10E29218D1B97E1129104DAD5C754F867F8DA391BB771A8A7CA406477B702561 1 09B9874680AE435F4646B80565CF680CD1A5DF0728F05642D8F225EEC9738224 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","i":1,"m2":"4BB2DDB4FC8891B42CB33E6E2F50A83A87D1A8DDEE43EF296ECB23A633A90A0A"}},"signature_correctness_proof":{"se":"4227770449172483014211029604945074281266657824743051071591223604130324662833046726980808434579738837283019678314689651232718408702312861484635194710822604584829535119864526998652227613977633912307594358378869841670816179325914556570886615075788082141289731083870623461925914625848700021614267747901807969887100790774908868563652268543118421299608286332396771906528020262242044098837686683452940471156001544051451734438543096891018533106236687173733055364372923974293931908331353110131779312841721522041869007072609268418395611945950800190773698572919140183021533368002059151050631094560385792972778504507860432572420","c":"77575687836064961489422049716490189621867956929024453155925031324499388041868"},"rev_reg":{"accum":"21 11AB07DB2CF0F7629108DA33C6593DE34321CE22463DB777A6243E62E4B797C27 21 12DC81E210AF59B6B00FDAEA6F043361953346533B2BC22BE91CDDAFFC9497B6F 6 654041C936480A5E4047AB33DDDFAC2193F4869D35D2887908247C0C9CB3F897 4 2E1E940139EA8C818DB1C20B7616166DF0F7FB1FEA2F049A57CA61FF7703DCC6 6 6088C090D37272D700E65DE80CF84F6F3EC97B07BE204FF8622BEAEDFD058C6F 4 170B22E61B4A333B2D6E476D6FCD7C8D9EBD4F6FBBC50BA002B929FA5821A16B"},"witness":{"omega":"21 12270D598F1894325C9A2537D18C9C558DE7634B7F18504387DE3D08D9505FC8E 21 13A72DC91CA9F4E53BA3CFFDB61A5068E6FC4B2405BB5B3E3E7F7056A5C9F3D7E 6 84922F7CB67BADCD83C180467B2B8104A0E5B50FCDD96730A4B7B66A6DDA5718 4 2ED45D49B0A1FB415CC3C6DC3CCFDF171A1BAE472091AA18D452812B7474FD88 6 549074BC70DB153F5B784A612E40C12522680ACE711D2F65835B2D494F1133FA 4 0E58979A327E309DE2434C21A8E207AFB9EB07E224B876BEA1846938B8370129"}}"#;
 pub static CREDENTIAL_DEF_JSON: &str = "{\"ref\":15,\"origin\":\"4fUDR9R7fjwELRvH9JT6HH\",\"signature_type\":\"CL\",\"data\":{\"primary\":{\"n\":\"99449721920983867980452115739521093388189250608430915795575398748566151502012057955217336948041746818624648053275113334155604245407421033484186764577590329252218803213710385538330949830880835456222156927519381192533477181168559597090903211569698203718554557823381883761860087225376417554213967789400707157435577397948922960969840213798705858913336148191647612221677004416370993117502298396552705806907336525000169245965202006856046970475897339344650241435322933475398755497340692589731882039048629953111315764664439197747127653908391803964209020943031145901121880186696634135213799380095891334900040826995060267263537\",\"s\":\"52590877349929458402745669938997330224603427897919289516472504658336073947597986754711349411295933233850442043919096523762956474385116663776933299521254568145737571361851646412816218484235060358608427484818588597647073942051615043151273581284204402154599631666405338818389557228027734458704518521611255287203718270100697861606505275619020792530781266153871820587526569418292472650093116208246276742522332785559092822461836233239581888009792012087146372166809852204987855459392454632060464844117306553267739112073643449927851543465995041511487726500091251690426093137534332191973467000184514579428985661944209133077728\",\"rms\":\"3863798656373785793935749747958535875663388377157839935233844441197336413322858012235344573088791932162150004391033365322332920570325348319445733679233577105495742525245332408136003345899530419035252838983755087657943157986816109506099404175381123410559434315705491754196301014945506186353345200841237348363445862875044619600879926832368129611818740543874678241543467673670144123963035864929486163671278398197208562415541584837631550047080842313215452363624776774746405641212961528221845143731822411057809042536128525606547708509406715753199193982770302795395802537549822339395497386819225783870203869096475789700949\",\"r\":{\"address2\":\"87924747217480355729680120615922279744123352192991070419319733072417195622138990914888434700745501793095706044245744899366662018196995726554367477385256435811290490290938060906476419255356270660484596896257926180743817578903728457757958147432105492356919384236436381620301280615827096453958029058393779077364410545504854103483562259116892971037715106066984635024850987108861257288500060283631172267765536217300733769280901826731623383054710862326530837715212055004002186461389335573553539780954854442200396018656278607056870817454642871554391987028541726830505285330495140923025659046149264428633323567546646484886994\",\"state\":\"67715657414107989662734923218016585533050213984650521495784905501443133691774534037357054136465158608274345933459595713337711481812316010403634951029161138797378564512133408318978574977952392205627277643652411174057950606709130011719824162407194067143291527135375739911842867045814005626184340866441679920397762036342807765850883191339951443346927865504940120474023081615547807362715943445534533625231976242935396140977498895763041035617893599610869440915472170905884544664556169757600455594009682011788318715706996246481227492663487047570305353111094366083583954567953045083913044209646668416051301699140888214161745\",\"address1\":\"73699451848208281985510810723869808101618113428017318947710878608843525466804203428546026016961440354928124846703224775275429538257422110897832787137807206481680532820264130495565540459341202627458234821667428124278528935821941370937495239638500739734972623136312125989808521644183342457467987728858274591301845562561162611305777791693799924132351171270960153338195754020059529489334656177881715196257740638287057843147254463344834365211481542808345750175559528646721692145946575571412568155771646302693272750546527080914617065067394357692653776759203604126162960315797534895333820802450487156722217838813697029163973\",\"city\":\"66712735432366954440904497397661324820601340477524203407774892580228378572543943717421654314796268117426709149979576579308186177393372151148826352047418470991764632101806312261417209104414059266877815349395547844546153521901291310475872496360285317279390569417279070572543807256660276655768419152074605219631609946442656767154637945089069665480415323519018749220170352848764352467680846297239296486998229032913747217052995366698773645084769849479609075271168065987806442699810724502271609202029150392116742733272421021374140659305070359685958282364392253423325830272699862347437643860144017387060353128548712309439090\",\"zip\":\"15207530124743897040474834994797558533057090242385963908844485166016027205867291779627329404523011887986764991856923560192158424084429688329706974093335651432447320761449491354403818586605742140441189134583474738905919629292982722539956218250782412638241250715371097535818659614332924049764852670918189916008644278105980557881349868501874991848330572971358864655996421231997804338980058737307532639635346476411997384387294897643425669917493499336539229789305441931965529197852866711407203483285073858843187152691445284489593185445847523138370412537111263340451891646223162235715432818589938642760570558964366975410129\"},\"rctxt\":\"78070372024309447409450163639646559600882975063331450322441472010003006347710470269797380345257430984658054667239542576223725583683554530323899814652158597621911391202295924393765597678082805655348225096771756033747685927725991254179428636274947944743221193667638650158062904429401196240915608278137792525844043283387071447777105921988124064756444729386486789363641889448947895397936852957400628883535029881975892519147784503751346136251711978232282626358672934177207471303236997590090062556761983823896294038252025349365882471021987022174975528277844546815276650088059742044724951755063437055030951995368624169814372\",\"z\":\"72461131092668633992103143913979324583216490230344205258218206973632529037599839989084666573398856158180649293267420293258980162708207788244432168928139862238975639170874575997043745319136136909624932007567347464414300854847526463237372944630010186125784205169037785443873061076116907293741929624122488639527155792944843038545677199029286471812416597508950437640786370585062042931847504471650544440901993573256297688676139450433654563473317976633910908392460678946228318691708983043238834595920812137470536673252950666846205254579616993892381542777376896229426424619654121187659137392105696856133820302787276969078538\"},\"revocation\":null}}";
 pub static STORE_CREDENTIAL_DEF_RESULT: &str = r#"{"result":{"ref":1,"origin":"NcYxiDXkpYi6ov5FcYDi1e","signature_type":"CL","data":{"primary":{"n":"9","s":"8","rms":"7","r":{"height":"6","sex":"5","age":"4","name":"3"},"rctxt":"2","z":"1"},"revocation":null}}}"#;
 pub static CREDENTIAL_DEF_DATA: &str = "{\"primary\":{\"n\":\"99449721920983867980452115739521093388189250608430915795575398748566151502012057955217336948041746818624648053275113334155604245407421033484186764577590329252218803213710385538330949830880835456222156927519381192533477181168559597090903211569698203718554557823381883761860087225376417554213967789400707157435577397948922960969840213798705858913336148191647612221677004416370993117502298396552705806907336525000169245965202006856046970475897339344650241435322933475398755497340692589731882039048629953111315764664439197747127653908391803964209020943031145901121880186696634135213799380095891334900040826995060267263537\",\"s\":\"52590877349929458402745669938997330224603427897919289516472504658336073947597986754711349411295933233850442043919096523762956474385116663776933299521254568145737571361851646412816218484235060358608427484818588597647073942051615043151273581284204402154599631666405338818389557228027734458704518521611255287203718270100697861606505275619020792530781266153871820587526569418292472650093116208246276742522332785559092822461836233239581888009792012087146372166809852204987855459392454632060464844117306553267739112073643449927851543465995041511487726500091251690426093137534332191973467000184514579428985661944209133077728\",\"rms\":\"3863798656373785793935749747958535875663388377157839935233844441197336413322858012235344573088791932162150004391033365322332920570325348319445733679233577105495742525245332408136003345899530419035252838983755087657943157986816109506099404175381123410559434315705491754196301014945506186353345200841237348363445862875044619600879926832368129611818740543874678241543467673670144123963035864929486163671278398197208562415541584837631550047080842313215452363624776774746405641212961528221845143731822411057809042536128525606547708509406715753199193982770302795395802537549822339395497386819225783870203869096475789700949\",\"r\":{\"address2\":\"87924747217480355729680120615922279744123352192991070419319733072417195622138990914888434700745501793095706044245744899366662018196995726554367477385256435811290490290938060906476419255356270660484596896257926180743817578903728457757958147432105492356919384236436381620301280615827096453958029058393779077364410545504854103483562259116892971037715106066984635024850987108861257288500060283631172267765536217300733769280901826731623383054710862326530837715212055004002186461389335573553539780954854442200396018656278607056870817454642871554391987028541726830505285330495140923025659046149264428633323567546646484886994\",\"state\":\"67715657414107989662734923218016585533050213984650521495784905501443133691774534037357054136465158608274345933459595713337711481812316010403634951029161138797378564512133408318978574977952392205627277643652411174057950606709130011719824162407194067143291527135375739911842867045814005626184340866441679920397762036342807765850883191339951443346927865504940120474023081615547807362715943445534533625231976242935396140977498895763041035617893599610869440915472170905884544664556169757600455594009682011788318715706996246481227492663487047570305353111094366083583954567953045083913044209646668416051301699140888214161745\",\"address1\":\"73699451848208281985510810723869808101618113428017318947710878608843525466804203428546026016961440354928124846703224775275429538257422110897832787137807206481680532820264130495565540459341202627458234821667428124278528935821941370937495239638500739734972623136312125989808521644183342457467987728858274591301845562561162611305777791693799924132351171270960153338195754020059529489334656177881715196257740638287057843147254463344834365211481542808345750175559528646721692145946575571412568155771646302693272750546527080914617065067394357692653776759203604126162960315797534895333820802450487156722217838813697029163973\",\"city\":\"66712735432366954440904497397661324820601340477524203407774892580228378572543943717421654314796268117426709149979576579308186177393372151148826352047418470991764632101806312261417209104414059266877815349395547844546153521901291310475872496360285317279390569417279070572543807256660276655768419152074605219631609946442656767154637945089069665480415323519018749220170352848764352467680846297239296486998229032913747217052995366698773645084769849479609075271168065987806442699810724502271609202029150392116742733272421021374140659305070359685958282364392253423325830272699862347437643860144017387060353128548712309439090\",\"zip\":\"15207530124743897040474834994797558533057090242385963908844485166016027205867291779627329404523011887986764991856923560192158424084429688329706974093335651432447320761449491354403818586605742140441189134583474738905919629292982722539956218250782412638241250715371097535818659614332924049764852670918189916008644278105980557881349868501874991848330572971358864655996421231997804338980058737307532639635346476411997384387294897643425669917493499336539229789305441931965529197852866711407203483285073858843187152691445284489593185445847523138370412537111263340451891646223162235715432818589938642760570558964366975410129\"},\"rctxt\":\"78070372024309447409450163639646559600882975063331450322441472010003006347710470269797380345257430984658054667239542576223725583683554530323899814652158597621911391202295924393765597678082805655348225096771756033747685927725991254179428636274947944743221193667638650158062904429401196240915608278137792525844043283387071447777105921988124064756444729386486789363641889448947895397936852957400628883535029881975892519147784503751346136251711978232282626358672934177207471303236997590090062556761983823896294038252025349365882471021987022174975528277844546815276650088059742044724951755063437055030951995368624169814372\",\"z\":\"72461131092668633992103143913979324583216490230344205258218206973632529037599839989084666573398856158180649293267420293258980162708207788244432168928139862238975639170874575997043745319136136909624932007567347464414300854847526463237372944630010186125784205169037785443873061076116907293741929624122488639527155792944843038545677199029286471812416597508950437640786370585062042931847504471650544440901993573256297688676139450433654563473317976633910908392460678946228318691708983043238834595920812137470536673252950666846205254579616993892381542777376896229426424619654121187659137392105696856133820302787276969078538\"},\"revocation\":null}";
@@ -227,7 +227,7 @@ pub static DEFAULT_REJECTED_PROOF: &str = r#"{"version":null,"to_did":null,"from
 
 pub static REV_REG_ID: &str = r#"V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1281:tag1:CL_ACCUM:tag1"#;
 pub static REV_REG_DELTA_JSON: &str = r#"{"ver":"1.0","value":{"accum":"2 0A0752AD393CCA8E840459E79BCF48F16ECEF17C00E9B639AC6CE2CCC93954C9 2 242D07E4AE3284C1E499D98E4EDF65ACFC0392E64C2BFF55192AC3AE51C3657C 2 165A2D44CAEE9717F1F52CC1BA6F72F39B21F969B3C4CDCA4FB501880F7AD297 2 1B08C9BB4876353F70E4A639F3B41593488B9964D4A56B61B0E1FF8B0FB0A1E7 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000"}}"#;
-pub static REV_STATE_JSON: &str = r#"{"V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:744:tag1:CL_ACCUM:tag1":{"1540930249":{"rev_reg":{"accum":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"},"timestamp":100,"witness":{"omega":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"}}}}"#;
+pub static REV_STATE_JSON: &str = r#"{"rev_reg":{"accum":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"},"timestamp":100,"witness":{"omega":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"}}"#;
 pub static REV_REG_JSON: &str = r#"{"ver":"1.0","value":{"accum":"2 0204F2D2B1F2B705A11AAFEEE73C9BA084C12AF1179294529AC4D14CA54E87F3 2 222BAE38FAF2673F7BCBB86D8DE1A327F5065BDC892E9A122164260C97BC0C63 2 1565105F8BA53037978B66E0CC9F53205F189DEEB6B7168744456DD98D2F4E88 2 1AC9E76B2868141A42329778831C14AEAAF7A9981209C1D96AECA4E69CAFB243 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000"}}"#;
 pub static TAILS_DIR: &str = r#"tails_file"#;
 pub static TEST_TAILS_LOCATION: &str = r#"/var/folders/kr/9gkxsj_s01b6fvx_72trl3mm0000gp/T/tails_file/5R6BWXL3vPrbJPKe9FsHAVG9hqKdDvVxonBuj3ETYuZh"#;
@@ -1,11 +1,23 @@
+use std::collections::HashMap;
+
+use aries_vcx_core::anoncreds::types::{
+    Credential, CredentialDefinitionConfig, CredentialInfo, CredentialOffer, CredentialRequest,
+    CredentialRequestMetadata, CredentialValues, IssuedCredential, Proof, ProofRequest, RequestedCredentials,
+    RetrievedCredentials, RevocationRegistries, RevocationState, RevocationStates,
+};
 use aries_vcx_core::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+use aries_vcx_core::ledger::types::{
+    CredentialDefinition, RevocationRegistryDefinition, RevocationRegistryDelta, Schema,
+};
 use async_trait::async_trait;
 
 use crate::{
     global::settings,
     utils::{
-        self,
-        constants::{LARGE_NONCE, LIBINDY_CRED_OFFER, REV_REG_DELTA_JSON, REV_STATE_JSON},
+        constants::{
+            CREDENTIAL_JSON, CREDENTIAL_REQ_STRING, CRED_REQ_META, LARGE_NONCE, LIBINDY_CRED_OFFER, PROOF_JSON,
+            REV_REG_DELTA_JSON, REV_STATE_JSON,
+        },
         mockdata::mock_settings::get_mock_creds_retrieved_for_proof_request,
     },
 };
@@ -20,12 +32,12 @@ pub(crate) struct MockAnoncreds;
 impl BaseAnonCreds for MockAnoncreds {
     async fn verifier_verify_proof(
         &self,
-        _proof_request_json: &str,
-        _proof_json: &str,
-        _schemas_json: &str,
-        _credential_defs_json: &str,
-        _rev_reg_defs_json: &str,
-        _rev_regs_json: &str,
+        _proof_request: &ProofRequest,
+        _proof: &Proof,
+        _schemas: &HashMap<String, Schema>,
+        _credential_defs: &HashMap<String, CredentialDefinition>,
+        _rev_reg_defs: &HashMap<String, RevocationRegistryDefinition>,
+        _rev_regs: &RevocationRegistries,
     ) -> VcxCoreResult<bool> {
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -40,7 +52,7 @@ impl BaseAnonCreds for MockAnoncreds {
         _tails_dir: &str,
         _max_creds: u32,
         _tag: &str,
-    ) -> VcxCoreResult<(String, String, String)> {
+    ) -> VcxCoreResult<(String, RevocationRegistryDefinition, RevocationRegistryDelta)> {
         // not needed yet
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -51,11 +63,11 @@ impl BaseAnonCreds for MockAnoncreds {
     async fn issuer_create_and_store_credential_def(
         &self,
         _issuer_did: &str,
-        _schema_json: &str,
+        _schema: &Schema,
         _tag: &str,
         _signature_type: Option<&str>,
-        _config_json: &str,
-    ) -> VcxCoreResult<(String, String)> {
+        _config: &CredentialDefinitionConfig,
+    ) -> VcxCoreResult<(String, CredentialDefinition)> {
         // not needed yet
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -63,34 +75,38 @@ impl BaseAnonCreds for MockAnoncreds {
         ))
     }
 
-    async fn issuer_create_credential_offer(&self, _cred_def_id: &str) -> VcxCoreResult<String> {
-        Ok(LIBINDY_CRED_OFFER.to_string())
+    async fn issuer_create_credential_offer(&self, _cred_def_id: &str) -> VcxCoreResult<CredentialOffer> {
+        Ok(serde_json::from_str(LIBINDY_CRED_OFFER)?)
     }
 
     async fn issuer_create_credential(
         &self,
-        _cred_offer_json: &str,
-        _cred_req_json: &str,
-        _cred_values_json: &str,
+        _cred_offer: &CredentialOffer,
+        _cred_request: &CredentialRequest,
+        _cred_values: &CredentialValues,
         _rev_reg_id: Option<String>,
         _tails_dir: Option<String>,
-    ) -> VcxCoreResult<(String, Option<String>, Option<String>)> {
-        Ok((utils::constants::CREDENTIAL_JSON.to_owned(), None, None))
+    ) -> VcxCoreResult<IssuedCredential> {
+        Ok(IssuedCredential {
+            credential: serde_json::from_str(CREDENTIAL_JSON)?,
+            cred_rev_id: None,
+            rev_reg_delta: None,
+        })
     }
 
     async fn prover_create_proof(
         &self,
-        _proof_req_json: &str,
-        _requested_credentials_json: &str,
+        _proof_req: &ProofRequest,
+        _requested_credentials: &RequestedCredentials,
         _master_secret_id: &str,
-        _schemas_json: &str,
-        _credential_defs_json: &str,
-        _revoc_states_json: Option<&str>,
-    ) -> VcxCoreResult<String> {
-        Ok(utils::constants::PROOF_JSON.to_owned())
+        _schemas: &HashMap<String, Schema>,
+        _credential_defs: &HashMap<String, CredentialDefinition>,
+        _revoc_states: Option<&RevocationStates>,
+    ) -> VcxCoreResult<Proof> {
+        Ok(serde_json::from_str(PROOF_JSON)?)
     }
 
-    async fn prover_get_credential(&self, _cred_id: &str) -> VcxCoreResult<String> {
+    async fn prover_get_credential(&self, _cred_id: &str) -> VcxCoreResult<CredentialInfo> {
         // not needed yet
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -98,7 +114,7 @@ impl BaseAnonCreds for MockAnoncreds {
         ))
     }
 
-    async fn prover_get_credentials(&self, _filter_json: Option<&str>) -> VcxCoreResult<String> {
+    async fn prover_get_credentials(&self, _filter_json: Option<&str>) -> VcxCoreResult<Vec<CredentialInfo>> {
         // not needed yet
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -106,7 +122,10 @@ impl BaseAnonCreds for MockAnoncreds {
         ))
     }
 
-    async fn prover_get_credentials_for_proof_req(&self, _proof_request_json: &str) -> VcxCoreResult<String> {
+    async fn prover_get_credentials_for_proof_req(
+        &self,
+        _proof_request: &ProofRequest,
+    ) -> VcxCoreResult<RetrievedCredentials> {
         match get_mock_creds_retrieved_for_proof_request() {
             None => Err(AriesVcxCoreError::from_msg(
                 AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -114,7 +133,7 @@ impl BaseAnonCreds for MockAnoncreds {
             )),
             Some(mocked_creds) => {
                 warn!("get_mock_creds_retrieved_for_proof_request  returning mocked response");
-                Ok(mocked_creds)
+                Ok(serde_json::from_str(&mocked_creds)?)
             }
         }
     }
@@ -122,31 +141,34 @@ impl BaseAnonCreds for MockAnoncreds {
     async fn prover_create_credential_req(
         &self,
         _prover_did: &str,
-        _cred_offer_json: &str,
-        _cred_def_json: &str,
+        _cred_offer: &CredentialOffer,
+        _cred_def: &CredentialDefinition,
         _master_secret_id: &str,
-    ) -> VcxCoreResult<(String, String)> {
-        Ok((utils::constants::CREDENTIAL_REQ_STRING.to_owned(), String::new()))
+    ) -> VcxCoreResult<(CredentialRequest, CredentialRequestMetadata)> {
+        Ok((
+            serde_json::from_str(CREDENTIAL_REQ_STRING)?,
+            serde_json::from_str(CRED_REQ_META)?,
+        ))
     }
 
     async fn create_revocation_state(
         &self,
         _tails_dir: &str,
-        _rev_reg_def_json: &str,
-        _rev_reg_delta_json: &str,
+        _rev_reg_def: &RevocationRegistryDefinition,
+        _rev_reg_delta: &RevocationRegistryDelta,
         _timestamp: u64,
         _cred_rev_id: &str,
-    ) -> VcxCoreResult<String> {
-        Ok(REV_STATE_JSON.to_string())
+    ) -> VcxCoreResult<RevocationState> {
+        Ok(serde_json::from_str(REV_STATE_JSON)?)
     }
 
     async fn prover_store_credential(
         &self,
         _cred_id: Option<&str>,
-        _cred_req_metadata_json: &str,
-        _cred_json: &str,
-        _cred_def_json: &str,
-        _rev_reg_def_json: Option<&str>,
+        _cred_req_metadata: &CredentialRequestMetadata,
+        _credential: &Credential,
+        _cred_def: &CredentialDefinition,
+        _rev_reg_def: Option<&RevocationRegistryDefinition>,
     ) -> VcxCoreResult<String> {
         Ok("cred_id".to_string())
     }
@@ -186,8 +208,8 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(())
     }
 
-    async fn get_rev_reg_delta(&self, _rev_reg_id: &str) -> VcxCoreResult<Option<String>> {
-        Ok(Some(REV_REG_DELTA_JSON.to_string()))
+    async fn get_rev_reg_delta(&self, _rev_reg_id: &str) -> VcxCoreResult<Option<RevocationRegistryDelta>> {
+        Ok(Some(serde_json::from_str(REV_REG_DELTA_JSON)?))
     }
 
     async fn clear_rev_reg_delta(&self, _rev_reg_id: &str) -> VcxCoreResult<()> {
@@ -202,6 +224,7 @@ impl BaseAnonCreds for MockAnoncreds {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod unit_tests {
+    use std::collections::HashMap;
 
     use aries_vcx_core::anoncreds::base_anoncreds::BaseAnonCreds;
     use aries_vcx_core::errors::error::{AriesVcxCoreErrorKind, VcxCoreResult};
@@ -218,16 +241,33 @@ mod unit_tests {
 
         let anoncreds: Box<dyn BaseAnonCreds> = Box::new(MockAnoncreds);
 
-        assert_unimplemented(anoncreds.verifier_verify_proof("", "", "", "", "", "").await);
+        let proof = serde_json::from_str(crate::utils::constants::PROOF_JSON).unwrap();
+        assert_unimplemented(
+            anoncreds
+                .verifier_verify_proof(
+                    &Default::default(),
+                    &proof,
+                    &HashMap::new(),
+                    &HashMap::new(),
+                    &HashMap::new(),
+                    &HashMap::new(),
+                )
+                .await,
+        );
         assert_unimplemented(anoncreds.issuer_create_and_store_revoc_reg("", "", "", 0, "").await);
+        let schema = serde_json::from_str(crate::utils::constants::SCHEMA_JSON).unwrap();
         assert_unimplemented(
             anoncreds
-                .issuer_create_and_store_credential_def("", "", "", None, "")
+                .issuer_create_and_store_credential_def("", &schema, "", None, &Default::default())
                 .await,
         );
         assert_unimplemented(anoncreds.prover_get_credential("").await);
         assert_unimplemented(anoncreds.prover_get_credentials(None).await);
-        assert_unimplemented(anoncreds.prover_get_credentials_for_proof_req("").await);
+        assert_unimplemented(
+            anoncreds
+                .prover_get_credentials_for_proof_req(&Default::default())
+                .await,
+        );
         assert_unimplemented(anoncreds.prover_delete_credential("").await);
         assert_unimplemented(anoncreds.issuer_create_schema("", "", "", "").await);
     }
@@ -1,5 +1,9 @@
 use aries_vcx_core::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
 use aries_vcx_core::ledger::base_ledger::BaseLedger;
+use aries_vcx_core::ledger::types::{
+    CredentialDefinition, NymData, NymResponse, RevocationRegistryDefinition, RevocationRegistryDelta, Schema,
+    TxnAuthorAgreement,
+};
 use async_trait::async_trait;
 
 use crate::utils::{
@@ -35,11 +39,11 @@ impl BaseLedger for MockLedger {
         Ok(utils::constants::REQUEST_WITH_ENDORSER.to_string())
     }
 
-    async fn get_txn_author_agreement(&self) -> VcxCoreResult<String> {
-        Ok(utils::constants::DEFAULT_AUTHOR_AGREEMENT.to_string())
+    async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<TxnAuthorAgreement>> {
+        Ok(Some(serde_json::from_str(utils::constants::DEFAULT_AUTHOR_AGREEMENT)?))
     }
 
-    async fn get_nym(&self, did: &str) -> VcxCoreResult<String> {
+    async fn get_nym(&self, did: &str) -> VcxCoreResult<NymResponse> {
         // not needed yet
         Err(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::UnimplementedFeature,
@@ -47,23 +51,40 @@ impl BaseLedger for MockLedger {
         ))
     }
 
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<NymResponse> {
+        // not needed yet
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: get_nym_version",
+        ))
+    }
+
     async fn publish_nym(
         &self,
         submitter_did: &str,
         target_did: &str,
         verkey: Option<&str>,
-        data: Option<&str>,
+        data: Option<&NymData>,
         role: Option<&str>,
     ) -> VcxCoreResult<String> {
         Ok(r#"{"rc":"success"}"#.to_string())
     }
 
-    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        Ok(SCHEMA_JSON.to_string())
+    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<Schema> {
+        Ok(serde_json::from_str(SCHEMA_JSON)?)
     }
 
-    async fn get_cred_def(&self, cred_def_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        Ok(CRED_DEF_JSON.to_string())
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &str,
+        submitter_did: Option<&str>,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        Ok(serde_json::from_str(CRED_DEF_JSON)?)
     }
 
     async fn get_attr(&self, target_did: &str, attr_name: &str) -> VcxCoreResult<String> {
@@ -74,17 +95,17 @@ impl BaseLedger for MockLedger {
         Ok(r#"{"rc":"success"}"#.to_string())
     }
 
-    async fn get_rev_reg_def_json(&self, rev_reg_id: &str) -> VcxCoreResult<String> {
-        Ok(rev_def_json())
+    async fn get_rev_reg_def(&self, rev_reg_id: &str) -> VcxCoreResult<RevocationRegistryDefinition> {
+        Ok(serde_json::from_str(&rev_def_json())?)
     }
 
-    async fn get_rev_reg_delta_json(
+    async fn get_rev_reg_delta(
         &self,
         rev_reg_id: &str,
         from: Option<u64>,
         to: Option<u64>,
-    ) -> VcxCoreResult<(String, String, u64)> {
-        Ok((REV_REG_ID.to_string(), REV_REG_DELTA_JSON.to_string(), 1))
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        Ok((serde_json::from_str(REV_REG_DELTA_JSON)?, 1))
     }
 
     async fn get_rev_reg(&self, rev_reg_id: &str, timestamp: u64) -> VcxCoreResult<(String, String, u64)> {
@@ -149,5 +170,6 @@ mod unit_tests {
 
         assert_unimplemented(ledger.submit_request("").await);
         assert_unimplemented(ledger.get_nym("").await);
+        assert_unimplemented(ledger.get_nym_version("", None, None).await);
     }
 }
@@ -11,7 +11,7 @@ mod integration_tests {
     use aries_vcx::agency_client::MessageStatusCode;
     use aries_vcx::common::ledger::transactions::into_did_doc;
     use aries_vcx::handlers::connection::mediated_connection::ConnectionState;
-    use aries_vcx::handlers::out_of_band::receiver::OutOfBandReceiver;
+    use aries_vcx::handlers::out_of_band::receiver::{OobConnection, OutOfBandReceiver};
     use aries_vcx::handlers::out_of_band::sender::OutOfBandSender;
     use aries_vcx::handlers::util::AnyInvitation;
     use aries_vcx::protocols::mediated_connection::invitee::state_machine::InviteeState;
@@ -88,10 +88,14 @@ mod integration_tests {
             let conns = vec![];
             let conn = oob_receiver.connection_exists(&consumer.profile, &conns).await.unwrap();
             assert!(conn.is_none());
-            let mut conn_receiver = oob_receiver
+            let mut conn_receiver = match oob_receiver
                 .build_connection(&consumer.profile, &consumer.agency_client, ddo, true)
                 .await
-                .unwrap();
+                .unwrap()
+            {
+                OobConnection::Connection(connection) => connection,
+                OobConnection::DidExchange(_) => panic!("Expected a connections protocol handshake"),
+            };
             conn_receiver
                 .connect(&consumer.profile, &consumer.agency_client, _send_message(sender))
                 .await
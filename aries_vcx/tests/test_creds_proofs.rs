@@ -42,7 +42,7 @@ mod integration_tests {
             .await;
             let (_, _, req, _) = create_indy_proof(&setup.profile, &holder_setup.profile, &setup.institution_did).await;
 
-            let pres_req_data: PresentationRequestData = serde_json::from_str(&req).unwrap();
+            let pres_req_data: PresentationRequestData = serde_json::from_value(json!(req)).unwrap();
             let id = "test_id".to_owned();
 
             let attach_type = messages::decorators::attachment::AttachmentType::Base64(base64::encode(
@@ -78,10 +78,10 @@ mod integration_tests {
             .await;
 
             let ledger = Arc::clone(&setup.profile).inject_ledger();
-            let r_cred_def_json = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
+            let r_cred_def = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
 
             let def1: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-            let def2: serde_json::Value = serde_json::from_str(&r_cred_def_json).unwrap();
+            let def2 = serde_json::to_value(&r_cred_def).unwrap();
             assert_eq!(def1, def2);
         })
         .await;
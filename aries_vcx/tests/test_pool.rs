@@ -43,10 +43,10 @@ mod integration_tests {
 
             let ledger = Arc::clone(&setup.profile).inject_ledger();
 
-            let r_cred_def_json = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
+            let r_cred_def = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
 
             let def1: serde_json::Value = serde_json::from_str(&cred_def_json).unwrap();
-            let def2: serde_json::Value = serde_json::from_str(&r_cred_def_json).unwrap();
+            let def2 = serde_json::to_value(&r_cred_def).unwrap();
             assert_eq!(def1, def2);
         })
         .await;
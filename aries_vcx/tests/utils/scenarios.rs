@@ -770,11 +770,11 @@ pub mod test_utils {
 
     pub async fn revoke_credential_local(faber: &mut Faber, issuer_credential: &Issuer, rev_reg_id: &str) {
         let ledger = Arc::clone(&faber.profile).inject_ledger();
-        let (_, delta, timestamp) = ledger.get_rev_reg_delta_json(&rev_reg_id, None, None).await.unwrap();
+        let (delta, timestamp) = ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
         info!("revoking credential locally");
         issuer_credential.revoke_credential_local(&faber.profile).await.unwrap();
-        let (_, delta_after_revoke, _) = ledger
-            .get_rev_reg_delta_json(rev_reg_id, Some(timestamp + 1), None)
+        let (delta_after_revoke, _) = ledger
+            .get_rev_reg_delta(rev_reg_id, Some(timestamp + 1), None)
             .await
             .unwrap();
         assert_ne!(delta, delta_after_revoke); // They will not equal as we have saved the delta in cache
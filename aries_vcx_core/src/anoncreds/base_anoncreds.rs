@@ -1,20 +1,32 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use crate::errors::error::VcxCoreResult;
+use crate::ledger::types::{CredentialDefinition, RevocationRegistryDefinition, RevocationRegistryDelta, Schema};
+
+use super::types::{
+    Credential, CredentialDefinitionConfig, CredentialInfo, CredentialOffer, CredentialRequest,
+    CredentialRequestMetadata, CredentialValues, IssuedCredential, Proof, ProofRequest, RequestedCredentials,
+    RetrievedCredentials, RevocationRegistries, RevocationState, RevocationStates,
+};
 
-/// Trait defining standard 'anoncreds' related functionality. The APIs, including
-/// input and output types are based off the indy Anoncreds API:
+/// Trait defining standard 'anoncreds' related functionality. The APIs are based off the indy Anoncreds API:
 /// see: <https://github.com/hyperledger/indy-sdk/blob/main/libindy/src/api/anoncreds.rs>
+///
+/// Offers, requests, credentials, proof requests, proofs and revocation states are passed as the typed
+/// structs of [`super::types`], ledger objects as the typed structs of [`crate::ledger::types`]. Schemas,
+/// credential definitions and revocation registries are keyed by their ledger id.
 #[async_trait]
 pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
     async fn verifier_verify_proof(
         &self,
-        proof_request_json: &str,
-        proof_json: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        rev_reg_defs_json: &str,
-        rev_regs_json: &str,
+        proof_request: &ProofRequest,
+        proof: &Proof,
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        rev_reg_defs: &HashMap<String, RevocationRegistryDefinition>,
+        rev_regs: &RevocationRegistries,
     ) -> VcxCoreResult<bool>;
 
     async fn issuer_create_and_store_revoc_reg(
@@ -24,68 +36,71 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         tails_dir: &str,
         max_creds: u32,
         tag: &str,
-    ) -> VcxCoreResult<(String, String, String)>;
+    ) -> VcxCoreResult<(String, RevocationRegistryDefinition, RevocationRegistryDelta)>;
 
     async fn issuer_create_and_store_credential_def(
         &self,
         issuer_did: &str,
-        schema_json: &str,
+        schema: &Schema,
         tag: &str,
         signature_type: Option<&str>,
-        config_json: &str,
-    ) -> VcxCoreResult<(String, String)>;
+        config: &CredentialDefinitionConfig,
+    ) -> VcxCoreResult<(String, CredentialDefinition)>;
 
-    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<String>;
+    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<CredentialOffer>;
 
     async fn issuer_create_credential(
         &self,
-        cred_offer_json: &str,
-        cred_req_json: &str,
-        cred_values_json: &str,
+        cred_offer: &CredentialOffer,
+        cred_req: &CredentialRequest,
+        cred_values: &CredentialValues,
         rev_reg_id: Option<String>,
         tails_dir: Option<String>,
-    ) -> VcxCoreResult<(String, Option<String>, Option<String>)>;
+    ) -> VcxCoreResult<IssuedCredential>;
 
     async fn prover_create_proof(
         &self,
-        proof_req_json: &str,
-        requested_credentials_json: &str,
+        proof_req: &ProofRequest,
+        requested_credentials: &RequestedCredentials,
         master_secret_id: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        revoc_states_json: Option<&str>,
-    ) -> VcxCoreResult<String>;
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        revoc_states: Option<&RevocationStates>,
+    ) -> VcxCoreResult<Proof>;
 
-    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<String>;
+    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<CredentialInfo>;
 
-    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<String>;
+    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<Vec<CredentialInfo>>;
 
-    async fn prover_get_credentials_for_proof_req(&self, proof_request_json: &str) -> VcxCoreResult<String>;
+    async fn prover_get_credentials_for_proof_req(
+        &self,
+        proof_req: &ProofRequest,
+    ) -> VcxCoreResult<RetrievedCredentials>;
 
     async fn prover_create_credential_req(
         &self,
         prover_did: &str,
-        cred_offer_json: &str,
-        cred_def_json: &str,
+        cred_offer: &CredentialOffer,
+        cred_def: &CredentialDefinition,
         master_secret_id: &str,
-    ) -> VcxCoreResult<(String, String)>;
+    ) -> VcxCoreResult<(CredentialRequest, CredentialRequestMetadata)>;
 
     async fn create_revocation_state(
         &self,
         tails_dir: &str,
-        rev_reg_def_json: &str,
-        rev_reg_delta_json: &str,
+        rev_reg_def: &RevocationRegistryDefinition,
+        rev_reg_delta: &RevocationRegistryDelta,
         timestamp: u64,
         cred_rev_id: &str,
-    ) -> VcxCoreResult<String>;
+    ) -> VcxCoreResult<RevocationState>;
 
     async fn prover_store_credential(
         &self,
         cred_id: Option<&str>,
-        cred_req_metadata_json: &str,
-        cred_json: &str,
-        cred_def_json: &str,
-        rev_reg_def_json: Option<&str>,
+        cred_req_metadata: &CredentialRequestMetadata,
+        credential: &Credential,
+        cred_def: &CredentialDefinition,
+        rev_reg_def: Option<&RevocationRegistryDefinition>,
     ) -> VcxCoreResult<String>;
 
     async fn prover_delete_credential(&self, cred_id: &str) -> VcxCoreResult<()>;
@@ -103,7 +118,7 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
     // TODO - FUTURE - think about moving this to somewhere else, as it aggregates other calls (not PURE Anoncreds)
     async fn revoke_credential_local(&self, tails_dir: &str, rev_reg_id: &str, cred_rev_id: &str) -> VcxCoreResult<()>;
 
-    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<String>>;
+    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<RevocationRegistryDelta>>;
 
     async fn clear_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<()>;
 
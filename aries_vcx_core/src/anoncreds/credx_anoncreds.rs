@@ -4,10 +4,7 @@ use std::{
     sync::Arc,
 };
 
-use crate::utils::{
-    constants::ATTRS,
-    json::{AsTypeOrDeserializationError, TryGetIndex},
-};
+use crate::utils::json::{AsTypeOrDeserializationError, TryGetIndex};
 use crate::wallet::base_wallet::AsyncFnIteratorCollect;
 use crate::{
     errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
@@ -16,26 +13,61 @@ use crate::{
 
 use async_trait::async_trait;
 use credx::{
+    tails::{TailsFileReader, TailsFileWriter},
     types::{
-        Credential as CredxCredential, CredentialDefinitionId, CredentialRequestMetadata, CredentialRevocationState,
-        DidValue, MasterSecret, PresentCredentials, Presentation, PresentationRequest, RevocationRegistry,
-        RevocationRegistryDefinition, RevocationRegistryDelta, RevocationRegistryId, Schema, SchemaId,
+        Credential as CredxCredential, CredentialDefinition as CredxCredentialDefinition,
+        CredentialDefinitionConfig as CredxCredentialDefinitionConfig, CredentialDefinitionId,
+        CredentialDefinitionPrivate, CredentialKeyCorrectnessProof, CredentialOffer as CredxCredentialOffer,
+        CredentialRequest as CredxCredentialRequest, CredentialRequestMetadata as CredxCredentialRequestMetadata,
+        CredentialRevocationConfig, CredentialRevocationState, CredentialValues as CredxCredentialValues, DidValue,
+        IssuanceType, MasterSecret, PresentCredentials, Presentation, PresentationRequest, RegistryType,
+        RevocationRegistry as CredxRevocationRegistry,
+        RevocationRegistryDefinition as CredxRevocationRegistryDefinition, RevocationRegistryDefinitionPrivate,
+        RevocationRegistryDelta as CredxRevocationRegistryDelta, RevocationRegistryId, Schema as CredxSchema, SchemaId,
+        SignatureType,
     },
-    ursa::bn::BigNumber,
-};
-use credx::{
-    types::{CredentialDefinition, CredentialOffer},
-    ursa::cl::MasterSecret as UrsaMasterSecret,
+    ursa::{bn::BigNumber, cl::MasterSecret as UrsaMasterSecret},
 };
 use indy_credx as credx;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
-use super::base_anoncreds::BaseAnonCreds;
+use super::{
+    base_anoncreds::BaseAnonCreds,
+    types::{
+        Credential, CredentialDefinitionConfig, CredentialInfo, CredentialOffer, CredentialRequest,
+        CredentialRequestMetadata, CredentialValues, IssuedCredential, NonRevokedInterval, Proof, ProofRequest,
+        RequestedCredentials, Restrictions, RetrievedCredentialForReferent, RetrievedCredentials, RevocationRegistries,
+        RevocationState, RevocationStates,
+    },
+};
+use crate::ledger::types::{CredentialDefinition, RevocationRegistryDefinition, RevocationRegistryDelta, Schema};
 
 const CATEGORY_CREDENTIAL: &str = "VCX_CREDENTIAL";
 const CATEGORY_LINK_SECRET: &str = "VCX_LINK_SECRET";
 
+const CATEGORY_CRED_DEF: &str = "VCX_CRED_DEF";
+const CATEGORY_CRED_KEY_CORRECTNESS_PROOF: &str = "VCX_CRED_KEY_CORRECTNESS_PROOF";
+const CATEGORY_CRED_DEF_PRIV: &str = "VCX_CRED_DEF_PRIV";
+const CATEGORY_CRED_MAP_SCHEMA_ID: &str = "VCX_CRED_MAP_SCHEMA_ID";
+
+const CATEGORY_REV_REG: &str = "VCX_REV_REG";
+const CATEGORY_REV_REG_INFO: &str = "VCX_REV_REG_INFO";
+const CATEGORY_REV_REG_DEF: &str = "VCX_REV_REG_DEF";
+const CATEGORY_REV_REG_DEF_PRIV: &str = "VCX_REV_REG_DEF_PRIV";
+const CATEGORY_REV_REG_DELTA: &str = "VCX_REV_REG_DELTA";
+
+/// Issuer-side bookkeeping of a revocation registry, mirroring the `RevocationRegistryInfo`
+/// record kept by libindy. `curr_id` is the last issued index, `used_ids` holds the indices whose
+/// state differs from the registry's default issuance state.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RevocationRegistryInfo {
+    id: RevocationRegistryId,
+    curr_id: u32,
+    used_ids: HashSet<u32>,
+}
+
 #[derive(Debug)]
 pub struct IndyCredxAnonCreds {
     wallet: Arc<dyn BaseWallet>,
@@ -67,6 +99,33 @@ impl IndyCredxAnonCreds {
         Ok(MasterSecret { value: ursa_ms })
     }
 
+    async fn get_wallet_record_value<T>(&self, category: &str, id: &str) -> VcxCoreResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let record = self.wallet.get_wallet_record(category, id, "{}").await?;
+        let record: Value = serde_json::from_str(&record)?;
+        let record_value = (&record).try_get("value")?;
+
+        Ok(serde_json::from_str(record_value.try_as_str()?)?)
+    }
+
+    async fn add_wallet_record_value<T>(&self, category: &str, id: &str, value: &T) -> VcxCoreResult<()>
+    where
+        T: Serialize,
+    {
+        let value = serde_json::to_string(value)?;
+        self.wallet.add_wallet_record(category, id, &value, None).await
+    }
+
+    async fn update_wallet_record_value<T>(&self, category: &str, id: &str, value: &T) -> VcxCoreResult<()>
+    where
+        T: Serialize,
+    {
+        let value = serde_json::to_string(value)?;
+        self.wallet.update_wallet_record_value(category, id, &value).await
+    }
+
     async fn _get_credential(&self, credential_id: &str) -> VcxCoreResult<CredxCredential> {
         let cred_record = self
             .wallet
@@ -144,37 +203,36 @@ impl IndyCredxAnonCreds {
 impl BaseAnonCreds for IndyCredxAnonCreds {
     async fn verifier_verify_proof(
         &self,
-        proof_req_json: &str,
-        proof_json: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        rev_reg_defs_json: &str,
-        rev_regs_json: &str,
+        proof_req: &ProofRequest,
+        proof: &Proof,
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        rev_reg_defs: &HashMap<String, RevocationRegistryDefinition>,
+        rev_regs: &RevocationRegistries,
     ) -> VcxCoreResult<bool> {
-        let _ = (
-            proof_req_json,
-            proof_json,
-            schemas_json,
-            credential_defs_json,
-            rev_reg_defs_json,
-            rev_regs_json,
-        );
+        let presentation: Presentation = convert(proof)?;
+        let pres_req: PresentationRequest = convert(proof_req)?;
 
-        let presentation: Presentation = serde_json::from_str(proof_json)?;
-        let pres_req: PresentationRequest = serde_json::from_str(proof_req_json)?;
+        let schemas: HashMap<SchemaId, CredxSchema> = convert(schemas)?;
+        let cred_defs: HashMap<CredentialDefinitionId, CredxCredentialDefinition> = convert(credential_defs)?;
 
-        let schemas: HashMap<SchemaId, Schema> = serde_json::from_str(schemas_json)?;
-        let cred_defs: HashMap<CredentialDefinitionId, CredentialDefinition> =
-            serde_json::from_str(credential_defs_json)?;
-
-        let rev_reg_defs: Option<HashMap<RevocationRegistryId, RevocationRegistryDefinition>> =
-            serde_json::from_str(rev_reg_defs_json)?;
+        let rev_reg_defs: Option<HashMap<RevocationRegistryId, CredxRevocationRegistryDefinition>> =
+            if rev_reg_defs.is_empty() {
+                None
+            } else {
+                Some(convert(rev_reg_defs)?)
+            };
 
-        let rev_regs: Option<HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistry>>> =
-            serde_json::from_str(rev_regs_json)?;
-        let rev_regs: Option<HashMap<RevocationRegistryId, HashMap<u64, &RevocationRegistry>>> =
+        let rev_regs: Option<HashMap<RevocationRegistryId, HashMap<u64, CredxRevocationRegistry>>> =
+            if rev_regs.is_empty() {
+                None
+            } else {
+                Some(convert(rev_regs)?)
+            };
+        let rev_regs: Option<HashMap<RevocationRegistryId, HashMap<u64, &CredxRevocationRegistry>>> =
             rev_regs.as_ref().map(|regs| {
-                let mut new_regs: HashMap<RevocationRegistryId, HashMap<u64, &RevocationRegistry>> = HashMap::new();
+                let mut new_regs: HashMap<RevocationRegistryId, HashMap<u64, &CredxRevocationRegistry>> =
+                    HashMap::new();
                 for (k, v) in regs {
                     new_regs.insert(k.clone(), hashmap_as_ref(v));
                 }
@@ -198,79 +256,239 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         tails_dir: &str,
         max_creds: u32,
         tag: &str,
-    ) -> VcxCoreResult<(String, String, String)> {
-        let _ = (issuer_did, cred_def_id, tails_dir, max_creds, tag);
-        Err(unimplemented_method_err("credx issuer_create_and_store_revoc_reg"))
+    ) -> VcxCoreResult<(String, RevocationRegistryDefinition, RevocationRegistryDelta)> {
+        let issuer_did = DidValue::new(issuer_did, None);
+        let cred_def: CredxCredentialDefinition = self.get_wallet_record_value(CATEGORY_CRED_DEF, cred_def_id).await?;
+
+        let mut tails_writer = TailsFileWriter::new(Some(tails_dir.to_string()));
+
+        let (rev_reg_def, rev_reg_def_priv, rev_reg, rev_reg_delta) = credx::issuer::create_revocation_registry(
+            &issuer_did,
+            &cred_def,
+            tag,
+            RegistryType::CL_ACCUM,
+            IssuanceType::ISSUANCE_BY_DEFAULT,
+            max_creds,
+            &mut tails_writer,
+        )?;
+
+        let rev_reg_id = rev_reg_def.id().to_owned();
+        let rev_reg_info = RevocationRegistryInfo {
+            id: rev_reg_id.clone(),
+            curr_id: 0,
+            used_ids: HashSet::new(),
+        };
+
+        self.add_wallet_record_value(CATEGORY_REV_REG_INFO, &rev_reg_id.0, &rev_reg_info)
+            .await?;
+        self.add_wallet_record_value(CATEGORY_REV_REG_DEF, &rev_reg_id.0, &rev_reg_def)
+            .await?;
+        self.add_wallet_record_value(CATEGORY_REV_REG_DEF_PRIV, &rev_reg_id.0, &rev_reg_def_priv)
+            .await?;
+        self.add_wallet_record_value(CATEGORY_REV_REG, &rev_reg_id.0, &rev_reg)
+            .await?;
+
+        Ok((rev_reg_id.0, convert(&rev_reg_def)?, convert(&rev_reg_delta)?))
     }
 
     async fn issuer_create_and_store_credential_def(
         &self,
         issuer_did: &str,
-        schema_json: &str,
+        schema: &Schema,
         tag: &str,
         sig_type: Option<&str>,
-        config_json: &str,
-    ) -> VcxCoreResult<(String, String)> {
-        let _ = (issuer_did, schema_json, tag, sig_type, config_json);
-        Err(unimplemented_method_err("credx issuer_create_and_store_credential_def"))
+        config: &CredentialDefinitionConfig,
+    ) -> VcxCoreResult<(String, CredentialDefinition)> {
+        let issuer_did = DidValue::new(issuer_did, None);
+        let schema: CredxSchema = convert(schema)?;
+
+        let signature_type = match sig_type {
+            None | Some("CL") => SignatureType::CL,
+            Some(other) => {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::InvalidInput,
+                    format!("Unsupported credential definition signature type: {}", other),
+                ))
+            }
+        };
+
+        let config = CredxCredentialDefinitionConfig {
+            support_revocation: config.support_revocation,
+        };
+
+        // generating the keys is expensive, so look for an existing cred def under the same id first
+        let cred_def_id = make_credential_definition_id(&issuer_did, &schema, signature_type, tag);
+
+        let existing_record = self
+            .wallet
+            .get_wallet_record(CATEGORY_CRED_DEF, &cred_def_id, "{}")
+            .await
+            .ok(); // ignore error, as we only care about whether it exists or not
+
+        if existing_record.is_some() {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::CredDefAlreadyCreated,
+                format!("Credential definition id: {} already exists in wallet.", cred_def_id),
+            ));
+        }
+
+        let (cred_def, cred_def_priv, key_correctness_proof) =
+            credx::issuer::create_credential_definition(&issuer_did, &schema, tag, signature_type, config)?;
+
+        self.add_wallet_record_value(CATEGORY_CRED_DEF, &cred_def_id, &cred_def)
+            .await?;
+        self.add_wallet_record_value(CATEGORY_CRED_DEF_PRIV, &cred_def_id, &cred_def_priv)
+            .await?;
+        self.add_wallet_record_value(
+            CATEGORY_CRED_KEY_CORRECTNESS_PROOF,
+            &cred_def_id,
+            &key_correctness_proof,
+        )
+        .await?;
+        self.add_wallet_record_value(CATEGORY_CRED_MAP_SCHEMA_ID, &cred_def_id, schema.id())
+            .await?;
+
+        Ok((cred_def_id, convert(&cred_def)?))
     }
 
-    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<String> {
-        let _ = cred_def_id;
-        Err(unimplemented_method_err("credx issuer_create_credential_offer"))
+    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<CredentialOffer> {
+        let cred_def: CredxCredentialDefinition = self.get_wallet_record_value(CATEGORY_CRED_DEF, cred_def_id).await?;
+        let key_correctness_proof: CredentialKeyCorrectnessProof = self
+            .get_wallet_record_value(CATEGORY_CRED_KEY_CORRECTNESS_PROOF, cred_def_id)
+            .await?;
+        let schema_id: SchemaId = self
+            .get_wallet_record_value(CATEGORY_CRED_MAP_SCHEMA_ID, cred_def_id)
+            .await?;
+
+        let cred_offer = credx::issuer::create_credential_offer(&schema_id, &cred_def, &key_correctness_proof)?;
+
+        convert(&cred_offer)
     }
 
     async fn issuer_create_credential(
         &self,
-        cred_offer_json: &str,
-        cred_req_json: &str,
-        cred_values_json: &str,
+        cred_offer: &CredentialOffer,
+        cred_request: &CredentialRequest,
+        cred_values: &CredentialValues,
         rev_reg_id: Option<String>,
         tails_dir: Option<String>,
-    ) -> VcxCoreResult<(String, Option<String>, Option<String>)> {
-        let _ = (cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, tails_dir);
-        Err(unimplemented_method_err("credx issuer_create_credential"))
-    }
-
-    /// * `requested_credentials_json`: either a credential or self-attested attribute for each requested attribute
-    ///     {
-    ///         "self_attested_attributes": {
-    ///             "self_attested_attribute_referent": string
-    ///         },
-    ///         "requested_attributes": {
-    ///             "requested_attribute_referent_1": {"cred_id": string, "timestamp": Optional<number>, revealed: <bool> }},
-    ///             "requested_attribute_referent_2": {"cred_id": string, "timestamp": Optional<number>, revealed: <bool> }}
-    ///         },
-    ///         "requested_predicates": {
-    ///             "requested_predicates_referent_1": {"cred_id": string, "timestamp": Optional<number> }},
-    ///         }
-    ///     }
-    async fn prover_create_proof(
-        &self,
-        proof_req_json: &str,
-        requested_credentials_json: &str,
-        link_secret_id: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        revoc_states_json: Option<&str>,
-    ) -> VcxCoreResult<String> {
-        let pres_req: PresentationRequest = serde_json::from_str(proof_req_json)?;
+    ) -> VcxCoreResult<IssuedCredential> {
+        let cred_offer: CredxCredentialOffer = convert(cred_offer)?;
+        let cred_request: CredxCredentialRequest = convert(cred_request)?;
+        let cred_values: CredxCredentialValues = convert(cred_values)?;
+
+        let cred_def_id = &cred_offer.cred_def_id.0;
+        let cred_def: CredxCredentialDefinition = self.get_wallet_record_value(CATEGORY_CRED_DEF, cred_def_id).await?;
+        let cred_def_priv: CredentialDefinitionPrivate = self
+            .get_wallet_record_value(CATEGORY_CRED_DEF_PRIV, cred_def_id)
+            .await?;
 
-        let requested_credentials: Value = serde_json::from_str(requested_credentials_json)?;
-        let requested_attributes = (&requested_credentials).try_get("requested_attributes")?;
+        let rev_reg_id = match rev_reg_id {
+            Some(rev_reg_id) => rev_reg_id,
+            None => {
+                let (credential, _, _) = credx::issuer::create_credential(
+                    &cred_def,
+                    &cred_def_priv,
+                    &cred_offer,
+                    &cred_request,
+                    cred_values,
+                    None,
+                )?;
+
+                return Ok(IssuedCredential {
+                    credential: convert(&credential)?,
+                    cred_rev_id: None,
+                    rev_reg_delta: None,
+                });
+            }
+        };
 
-        let requested_predicates = (&requested_credentials).try_get("requested_predicates")?;
-        let self_attested_attributes = requested_credentials.get("self_attested_attributes");
+        let tails_dir = tails_dir.ok_or(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidOption,
+            "Missing tails directory for issuance of a revocable credential",
+        ))?;
 
-        let rev_states: Option<Value> = if let Some(revoc_states_json) = revoc_states_json {
-            Some(serde_json::from_str(revoc_states_json)?)
-        } else {
-            None
+        let rev_reg_def: CredxRevocationRegistryDefinition =
+            self.get_wallet_record_value(CATEGORY_REV_REG_DEF, &rev_reg_id).await?;
+        let rev_reg_def_priv: RevocationRegistryDefinitionPrivate = self
+            .get_wallet_record_value(CATEGORY_REV_REG_DEF_PRIV, &rev_reg_id)
+            .await?;
+        let rev_reg: CredxRevocationRegistry = self.get_wallet_record_value(CATEGORY_REV_REG, &rev_reg_id).await?;
+        let mut rev_reg_info: RevocationRegistryInfo =
+            self.get_wallet_record_value(CATEGORY_REV_REG_INFO, &rev_reg_id).await?;
+
+        let (issuance_type, max_cred_num, tails_hash) = match &rev_reg_def {
+            CredxRevocationRegistryDefinition::RevocationRegistryDefinitionV1(r) => {
+                (r.value.issuance_type, r.value.max_cred_num, &r.value.tails_hash)
+            }
         };
 
-        let schemas: HashMap<SchemaId, Schema> = serde_json::from_str(schemas_json)?;
-        let cred_defs: HashMap<CredentialDefinitionId, CredentialDefinition> =
-            serde_json::from_str(credential_defs_json)?;
+        let cred_rev_id = rev_reg_info.curr_id + 1;
+        if cred_rev_id > max_cred_num {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidState,
+                format!("Revocation registry {} is full", rev_reg_id),
+            ));
+        }
+
+        let tails_file_path = format!("{}/{}", tails_dir, tails_hash);
+        let revocation_config = CredentialRevocationConfig {
+            reg_def: &rev_reg_def,
+            reg_def_private: &rev_reg_def_priv,
+            registry: &rev_reg,
+            registry_idx: cred_rev_id,
+            registry_used: &rev_reg_info.used_ids,
+            tails_reader: TailsFileReader::new(&tails_file_path),
+        };
+
+        let (credential, new_rev_reg, rev_reg_delta) = credx::issuer::create_credential(
+            &cred_def,
+            &cred_def_priv,
+            &cred_offer,
+            &cred_request,
+            cred_values,
+            Some(revocation_config),
+        )?;
+
+        rev_reg_info.curr_id = cred_rev_id;
+        match issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => {
+                rev_reg_info.used_ids.insert(cred_rev_id);
+            }
+            IssuanceType::ISSUANCE_BY_DEFAULT => {
+                rev_reg_info.used_ids.remove(&cred_rev_id);
+            }
+        };
+
+        if let Some(new_rev_reg) = new_rev_reg {
+            self.update_wallet_record_value(CATEGORY_REV_REG, &rev_reg_id, &new_rev_reg)
+                .await?;
+        }
+        self.update_wallet_record_value(CATEGORY_REV_REG_INFO, &rev_reg_id, &rev_reg_info)
+            .await?;
+
+        let rev_reg_delta: Option<RevocationRegistryDelta> = rev_reg_delta.as_ref().map(convert).transpose()?;
+
+        Ok(IssuedCredential {
+            credential: convert(&credential)?,
+            cred_rev_id: Some(cred_rev_id.to_string()),
+            rev_reg_delta,
+        })
+    }
+
+    async fn prover_create_proof(
+        &self,
+        proof_req: &ProofRequest,
+        requested_credentials: &RequestedCredentials,
+        link_secret_id: &str,
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        revoc_states: Option<&RevocationStates>,
+    ) -> VcxCoreResult<Proof> {
+        let pres_req: PresentationRequest = convert(proof_req)?;
+
+        let schemas: HashMap<SchemaId, CredxSchema> = convert(schemas)?;
+        let cred_defs: HashMap<CredentialDefinitionId, CredxCredentialDefinition> = convert(credential_defs)?;
 
         let mut present_credentials: PresentCredentials = PresentCredentials::new();
 
@@ -286,11 +504,9 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         > = HashMap::new();
 
         // add cred data and referent details for each requested attribute
-        for (reft, detail) in requested_attributes.try_as_object()?.iter() {
-            let _cred_id = detail.try_get("cred_id")?;
-            let cred_id = _cred_id.try_as_str()?;
-
-            let revealed = detail.try_get("revealed")?.try_as_bool()?;
+        for (reft, detail) in requested_credentials.requested_attributes.iter() {
+            let cred_id = detail.cred_id.as_str();
+            let revealed = detail.revealed;
 
             if let Some((_, _, _, req_attr_refts_revealed, _)) = proof_details_by_cred_id.get_mut(cred_id) {
                 // mapping made for this credential already, add reft and its revealed status
@@ -298,7 +514,7 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             } else {
                 let credential = self._get_credential(cred_id).await?;
 
-                let (timestamp, rev_state) = get_rev_state(cred_id, &credential, detail, rev_states.as_ref())?;
+                let (timestamp, rev_state) = get_rev_state(cred_id, &credential, detail.timestamp, revoc_states)?;
 
                 proof_details_by_cred_id.insert(
                     cred_id.to_string(),
@@ -314,9 +530,8 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         }
 
         // add cred data and referent details for each requested predicate
-        for (reft, detail) in requested_predicates.try_as_object()?.iter() {
-            let _cred_id = detail.try_get("cred_id")?;
-            let cred_id = _cred_id.try_as_str()?;
+        for (reft, detail) in requested_credentials.requested_predicates.iter() {
+            let cred_id = detail.cred_id.as_str();
 
             if let Some((_, _, _, _, req_preds_refts)) = proof_details_by_cred_id.get_mut(cred_id) {
                 // mapping made for this credential already, add reft
@@ -324,7 +539,7 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             } else {
                 let credential = self._get_credential(cred_id).await?;
 
-                let (timestamp, rev_state) = get_rev_state(cred_id, &credential, detail, rev_states.as_ref())?;
+                let (timestamp, rev_state) = get_rev_state(cred_id, &credential, detail.timestamp, revoc_states)?;
 
                 proof_details_by_cred_id.insert(
                     cred_id.to_string(),
@@ -348,22 +563,10 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             }
         }
 
-        // create self_attested by iterating thru self_attested_value
-        let self_attested = if let Some(self_attested_value) = self_attested_attributes {
-            let mut self_attested_map: HashMap<String, String> = HashMap::new();
-            let self_attested_obj = self_attested_value.try_as_object()?.clone();
-            let self_attested_iter = self_attested_obj.iter();
-            for (k, v) in self_attested_iter {
-                self_attested_map.insert(k.to_string(), v.try_as_str()?.to_string());
-            }
-
-            if self_attested_map.is_empty() {
-                None
-            } else {
-                Some(self_attested_map)
-            }
-        } else {
+        let self_attested = if requested_credentials.self_attested_attributes.is_empty() {
             None
+        } else {
+            Some(requested_credentials.self_attested_attributes.clone())
         };
 
         let link_secret = self.get_link_secret(link_secret_id).await?;
@@ -377,120 +580,85 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             &hashmap_as_ref(&cred_defs),
         )?;
 
-        Ok(serde_json::to_string(&presentation)?)
+        convert(&presentation)
     }
 
-    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<String> {
+    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<CredentialInfo> {
         let cred = self._get_credential(cred_id).await?;
 
-        let cred_info = _make_cred_info(cred_id, &cred)?;
-
-        Ok(serde_json::to_string(&cred_info)?)
+        _make_cred_info(cred_id, &cred)
     }
 
-    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<String> {
+    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<Vec<CredentialInfo>> {
         // filter_json should map to WQL query directly
         // TODO - future - may wish to validate the filter_json for more accurate error reporting
 
         let creds_wql = filter_json.map_or("{}", |x| x);
         let creds = self._get_credentials(creds_wql).await?;
 
-        let cred_info_list: VcxCoreResult<Vec<Value>> = creds
+        creds
             .iter()
             .map(|(credential_id, cred)| _make_cred_info(credential_id, cred))
-            .collect();
-
-        let cred_info_list = cred_info_list?;
-
-        Ok(serde_json::to_string(&cred_info_list)?)
+            .collect()
     }
 
-    async fn prover_get_credentials_for_proof_req(&self, proof_req: &str) -> VcxCoreResult<String> {
-        let proof_req_v: Value = serde_json::from_str(proof_req)
-            .map_err(|e| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidProofRequest, e))?;
-
-        let requested_attributes = proof_req_v.get("requested_attributes");
-        let requested_attributes = if let Some(requested_attributes) = requested_attributes {
-            Some(requested_attributes.try_as_object()?.clone())
-        } else {
-            None
-        };
-        let requested_predicates = proof_req_v.get("requested_predicates");
-        let requested_predicates = if let Some(requested_predicates) = requested_predicates {
-            Some(requested_predicates.try_as_object()?.clone())
-        } else {
-            None
-        };
-
-        // handle special case of "empty because json is bad" vs "empty because no attributes sepected"
-        if requested_attributes.is_none() && requested_predicates.is_none() {
-            return Err(AriesVcxCoreError::from_msg(
-                AriesVcxCoreErrorKind::InvalidAttributesStructure,
-                "Invalid Json Parsing of Requested Attributes Retrieved From Libindy",
-            ));
+    async fn prover_get_credentials_for_proof_req(
+        &self,
+        proof_req: &ProofRequest,
+    ) -> VcxCoreResult<RetrievedCredentials> {
+        let mut requested: Vec<(&String, &str, Option<&Restrictions>, Option<&NonRevokedInterval>)> = Vec::new();
+        for (referent, attr) in proof_req.requested_attributes.iter() {
+            let name = attr.name.as_deref().ok_or_else(|| {
+                AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::InvalidProofRequest,
+                    format!("Requested attribute {} has no name", referent),
+                )
+            })?;
+            requested.push((referent, name, attr.restrictions.as_ref(), attr.non_revoked.as_ref()));
         }
-
-        let mut referents: HashSet<String> = HashSet::new();
-        if let Some(requested_attributes) = &requested_attributes {
-            requested_attributes.iter().for_each(|(k, _)| {
-                referents.insert(k.to_string());
-            })
-        };
-        if let Some(requested_predicates) = &requested_predicates {
-            requested_predicates.iter().for_each(|(k, _)| {
-                referents.insert(k.to_string());
-            });
+        for (referent, predicate) in proof_req.requested_predicates.iter() {
+            requested.push((
+                referent,
+                &predicate.name,
+                predicate.restrictions.as_ref(),
+                predicate.non_revoked.as_ref(),
+            ));
         }
 
-        let mut cred_by_attr: Value = json!({});
-
-        for reft in referents {
-            let requested_val = requested_attributes
-                .as_ref()
-                .and_then(|req_attrs| req_attrs.get(&reft))
-                .or_else(|| requested_predicates.as_ref().and_then(|req_preds| req_preds.get(&reft)))
-                .ok_or(AriesVcxCoreError::from_msg(
-                    // should not happen
-                    AriesVcxCoreErrorKind::InvalidState,
-                    format!("Unknown referent: {}", reft),
-                ))?;
-
-            let _attr_name = requested_val.try_get("name")?;
-            let _attr_name = _attr_name.try_as_str()?;
-            let attr_name = _normalize_attr_name(_attr_name);
-
-            let non_revoked = requested_val.get("non_revoked"); // note that aca-py askar fetches from proof_req json
-            let restrictions = requested_val.get("restrictions");
-
+        // credentials for both attribute and predicate referents are listed under `attrs`
+        let mut retrieved_credentials = RetrievedCredentials::default();
+        for (referent, name, restrictions, non_revoked) in requested {
+            let restrictions = restrictions.map(serde_json::to_value).transpose()?;
             let credx_creds = self
-                ._get_credentials_for_proof_req_for_attr_name(restrictions, &attr_name)
+                ._get_credentials_for_proof_req_for_attr_name(restrictions.as_ref(), &_normalize_attr_name(name))
                 .await?;
 
-            let mut credentials_json = vec![];
-
-            for (cred_id, credx_cred) in credx_creds {
-                credentials_json.push(json!({
-                    "cred_info": _make_cred_info(&cred_id, &credx_cred)?,
-                    "interval": non_revoked
-                }))
-            }
-
-            cred_by_attr[ATTRS][reft] = Value::Array(credentials_json);
+            let credentials = credx_creds
+                .iter()
+                .map(|(cred_id, credx_cred)| {
+                    Ok(RetrievedCredentialForReferent {
+                        cred_info: _make_cred_info(cred_id, credx_cred)?,
+                        // note that aca-py askar fetches the interval from the proof request too
+                        interval: non_revoked.cloned(),
+                    })
+                })
+                .collect::<VcxCoreResult<Vec<_>>>()?;
+            retrieved_credentials.attrs.insert(referent.to_string(), credentials);
         }
 
-        Ok(serde_json::to_string(&cred_by_attr)?)
+        Ok(retrieved_credentials)
     }
 
     async fn prover_create_credential_req(
         &self,
         prover_did: &str,
-        credential_offer_json: &str,
-        credential_def_json: &str,
+        credential_offer: &CredentialOffer,
+        credential_def: &CredentialDefinition,
         link_secret_id: &str,
-    ) -> VcxCoreResult<(String, String)> {
+    ) -> VcxCoreResult<(CredentialRequest, CredentialRequestMetadata)> {
         let prover_did = DidValue::new(prover_did, None);
-        let cred_def: CredentialDefinition = serde_json::from_str(credential_def_json)?;
-        let credential_offer: CredentialOffer = serde_json::from_str(credential_offer_json)?;
+        let cred_def: CredxCredentialDefinition = convert(credential_def)?;
+        let credential_offer: CredxCredentialOffer = convert(credential_offer)?;
         let link_secret = self.get_link_secret(link_secret_id).await?;
 
         let (cred_req, cred_req_metadata) = credx::prover::create_credential_request(
@@ -501,27 +669,24 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             &credential_offer,
         )?;
 
-        Ok((
-            serde_json::to_string(&cred_req)?,
-            serde_json::to_string(&cred_req_metadata)?,
-        ))
+        Ok((convert(&cred_req)?, convert(&cred_req_metadata)?))
     }
 
     async fn create_revocation_state(
         &self,
         tails_dir: &str,
-        rev_reg_def_json: &str,
-        rev_reg_delta_json: &str,
+        rev_reg_def: &RevocationRegistryDefinition,
+        rev_reg_delta: &RevocationRegistryDelta,
         timestamp: u64,
         cred_rev_id: &str,
-    ) -> VcxCoreResult<String> {
-        let revoc_reg_def: RevocationRegistryDefinition = serde_json::from_str(rev_reg_def_json)?;
+    ) -> VcxCoreResult<RevocationState> {
+        let revoc_reg_def: CredxRevocationRegistryDefinition = convert(rev_reg_def)?;
         let tails_file_hash = match revoc_reg_def.borrow() {
-            RevocationRegistryDefinition::RevocationRegistryDefinitionV1(r) => &r.value.tails_hash,
+            CredxRevocationRegistryDefinition::RevocationRegistryDefinitionV1(r) => &r.value.tails_hash,
         };
         let tails_file_path = format!("{}/{}", tails_dir, tails_file_hash);
         let tails_reader: credx::tails::TailsReader = credx::tails::TailsFileReader::new(&tails_file_path);
-        let rev_reg_delta: RevocationRegistryDelta = serde_json::from_str(rev_reg_delta_json)?;
+        let rev_reg_delta: CredxRevocationRegistryDelta = convert(rev_reg_delta)?;
         let rev_reg_idx: u32 = cred_rev_id
             .parse()
             .map_err(|e| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::ParsingError, e))?;
@@ -535,27 +700,23 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
             None,
         )?;
 
-        Ok(serde_json::to_string(&rev_state)?)
+        convert(&rev_state)
     }
 
     async fn prover_store_credential(
         &self,
         cred_id: Option<&str>,
-        cred_req_meta: &str,
-        cred_json: &str,
-        cred_def_json: &str,
-        rev_reg_def_json: Option<&str>,
+        cred_req_meta: &CredentialRequestMetadata,
+        credential: &Credential,
+        cred_def: &CredentialDefinition,
+        rev_reg_def: Option<&RevocationRegistryDefinition>,
     ) -> VcxCoreResult<String> {
-        let mut credential: CredxCredential = serde_json::from_str(cred_json)?;
-        let cred_request_metadata: CredentialRequestMetadata = serde_json::from_str(cred_req_meta)?;
+        let mut credential: CredxCredential = convert(credential)?;
+        let cred_request_metadata: CredxCredentialRequestMetadata = convert(cred_req_meta)?;
         let link_secret_id = &cred_request_metadata.master_secret_name;
         let link_secret = self.get_link_secret(link_secret_id).await?;
-        let cred_def: CredentialDefinition = serde_json::from_str(cred_def_json)?;
-        let rev_reg_def: Option<RevocationRegistryDefinition> = if let Some(rev_reg_def_json) = rev_reg_def_json {
-            serde_json::from_str(rev_reg_def_json)?
-        } else {
-            None
-        };
+        let cred_def: CredxCredentialDefinition = convert(cred_def)?;
+        let rev_reg_def: Option<CredxRevocationRegistryDefinition> = rev_reg_def.map(convert).transpose()?;
 
         credx::prover::process_credential(
             &mut credential,
@@ -684,16 +845,106 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
     }
 
     async fn revoke_credential_local(&self, tails_dir: &str, rev_reg_id: &str, cred_rev_id: &str) -> VcxCoreResult<()> {
-        let _ = (tails_dir, rev_reg_id, cred_rev_id);
-        Err(unimplemented_method_err("credx revoke_credential_local"))
+        let cred_rev_id: u32 = cred_rev_id
+            .parse()
+            .map_err(|e| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::ParsingError, e))?;
+
+        let rev_reg_def: CredxRevocationRegistryDefinition =
+            self.get_wallet_record_value(CATEGORY_REV_REG_DEF, rev_reg_id).await?;
+        let rev_reg: CredxRevocationRegistry = self.get_wallet_record_value(CATEGORY_REV_REG, rev_reg_id).await?;
+        let mut rev_reg_info: RevocationRegistryInfo =
+            self.get_wallet_record_value(CATEGORY_REV_REG_INFO, rev_reg_id).await?;
+
+        let (issuance_type, tails_hash) = match &rev_reg_def {
+            CredxRevocationRegistryDefinition::RevocationRegistryDefinitionV1(r) => {
+                (r.value.issuance_type, &r.value.tails_hash)
+            }
+        };
+
+        if cred_rev_id == 0 || cred_rev_id > rev_reg_info.curr_id {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!(
+                    "Credential revocation id {} was not issued from revocation registry {}",
+                    cred_rev_id, rev_reg_id
+                ),
+            ));
+        }
+
+        let is_updated = match issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => rev_reg_info.used_ids.remove(&cred_rev_id),
+            IssuanceType::ISSUANCE_BY_DEFAULT => rev_reg_info.used_ids.insert(cred_rev_id),
+        };
+        if !is_updated {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!(
+                    "Credential revocation id {} in revocation registry {} is already revoked",
+                    cred_rev_id, rev_reg_id
+                ),
+            ));
+        }
+
+        let tails_file_path = format!("{}/{}", tails_dir, tails_hash);
+        let tails_reader = TailsFileReader::new(&tails_file_path);
+
+        let (new_rev_reg, new_rev_reg_delta) =
+            credx::issuer::revoke_credential(&rev_reg_def, &rev_reg, cred_rev_id, &tails_reader)?;
+
+        debug!(
+            "revoke_credential_local >>> new_rev_reg_delta: {}",
+            serde_json::to_string(&new_rev_reg_delta)?
+        );
+
+        let old_rev_reg_delta = self
+            .get_wallet_record_value::<CredxRevocationRegistryDelta>(CATEGORY_REV_REG_DELTA, rev_reg_id)
+            .await;
+
+        match old_rev_reg_delta {
+            Ok(old_rev_reg_delta) => {
+                let merged_rev_reg_delta =
+                    credx::issuer::merge_revocation_registry_deltas(&old_rev_reg_delta, &new_rev_reg_delta)?;
+                self.update_wallet_record_value(CATEGORY_REV_REG_DELTA, rev_reg_id, &merged_rev_reg_delta)
+                    .await?;
+            }
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => {
+                self.add_wallet_record_value(CATEGORY_REV_REG_DELTA, rev_reg_id, &new_rev_reg_delta)
+                    .await?;
+            }
+            Err(err) => return Err(err),
+        };
+
+        self.update_wallet_record_value(CATEGORY_REV_REG, rev_reg_id, &new_rev_reg)
+            .await?;
+        self.update_wallet_record_value(CATEGORY_REV_REG_INFO, rev_reg_id, &rev_reg_info)
+            .await?;
+
+        Ok(())
     }
 
-    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<String>> {
-        Err(unimplemented_method_err("credx get_rev_reg_delta"))
+    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<RevocationRegistryDelta>> {
+        let rev_reg_delta = self
+            .get_wallet_record_value::<CredxRevocationRegistryDelta>(CATEGORY_REV_REG_DELTA, rev_reg_id)
+            .await;
+
+        match rev_reg_delta {
+            Ok(rev_reg_delta) => Ok(Some(convert(&rev_reg_delta)?)),
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => Ok(None),
+            Err(err) => Err(err),
+        }
     }
 
     async fn clear_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<()> {
-        Err(unimplemented_method_err("credx clear_rev_reg_delta"))
+        if self.get_rev_reg_delta(rev_reg_id).await?.is_none() {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::IOError,
+                format!("Couldn't fetch delta for rev_reg_id {rev_reg_id} before deletion, deletion skipped"),
+            ));
+        }
+
+        self.wallet
+            .delete_wallet_record(CATEGORY_REV_REG_DELTA, rev_reg_id)
+            .await
     }
 
     async fn generate_nonce(&self) -> VcxCoreResult<String> {
@@ -705,15 +956,12 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
 fn get_rev_state(
     cred_id: &str,
     credential: &CredxCredential,
-    detail: &Value,
-    rev_states: Option<&Value>,
+    timestamp: Option<u64>,
+    rev_states: Option<&RevocationStates>,
 ) -> VcxCoreResult<(Option<u64>, Option<CredentialRevocationState>)> {
-    let timestamp = detail.get("timestamp").and_then(|timestamp| timestamp.as_u64());
     let cred_rev_reg_id = credential.rev_reg_id.as_ref().map(|id| id.0.to_string());
     let rev_state = if let (Some(timestamp), Some(cred_rev_reg_id)) = (timestamp, cred_rev_reg_id) {
-        let rev_state = rev_states
-            .as_ref()
-            .and_then(|_rev_states| _rev_states.get(cred_rev_reg_id.to_string()));
+        let rev_state = rev_states.and_then(|_rev_states| _rev_states.get(&cred_rev_reg_id));
         let rev_state = rev_state.ok_or(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::InvalidJson,
             format!(
@@ -722,7 +970,7 @@ fn get_rev_state(
             ),
         ))?;
 
-        let rev_state = rev_state.get(timestamp.to_string()).ok_or(AriesVcxCoreError::from_msg(
+        let rev_state = rev_state.get(&timestamp).ok_or(AriesVcxCoreError::from_msg(
             AriesVcxCoreErrorKind::InvalidJson,
             format!(
                 "No revocation states provided for credential '{}' with rev_reg_id '{}' at timestamp '{}'",
@@ -730,7 +978,7 @@ fn get_rev_state(
             ),
         ))?;
 
-        let rev_state: CredentialRevocationState = serde_json::from_value(rev_state.clone())?;
+        let rev_state: CredentialRevocationState = convert(rev_state)?;
         Some(rev_state)
     } else {
         None
@@ -739,18 +987,34 @@ fn get_rev_state(
     Ok((timestamp, rev_state))
 }
 
+// The id credx assigns to a cred def: schemas written on the ledger are referred to by their seq_no
+fn make_credential_definition_id(
+    issuer_did: &DidValue,
+    schema: &CredxSchema,
+    signature_type: SignatureType,
+    tag: &str,
+) -> String {
+    let schema_id = match schema {
+        CredxSchema::SchemaV1(schema) => schema
+            .seq_no
+            .map(|seq_no| SchemaId(seq_no.to_string()))
+            .unwrap_or_else(|| schema.id.clone()),
+    };
+    CredentialDefinitionId::new(issuer_did, &schema_id, signature_type.to_str(), tag).0
+}
+
 fn _normalize_attr_name(name: &str) -> String {
     // "name": string, // attribute name, (case insensitive and ignore spaces)
     name.replace(' ', "").to_lowercase()
 }
 
-fn _make_cred_info(credential_id: &str, cred: &CredxCredential) -> VcxCoreResult<Value> {
+fn _make_cred_info(credential_id: &str, cred: &CredxCredential) -> VcxCoreResult<CredentialInfo> {
     let cred_sig = serde_json::to_value(&cred.signature)?;
 
     let rev_info = cred_sig.get("r_credential");
 
-    let schema_id = &cred.schema_id.0;
-    let cred_def_id = &cred.cred_def_id.0;
+    let schema_id = cred.schema_id.0.to_string();
+    let cred_def_id = cred.cred_def_id.0.to_string();
     let rev_reg_id = cred.rev_reg_id.as_ref().map(|x| x.0.to_string());
     let cred_rev_id = rev_info.and_then(|x| x.get("i")).and_then(|i| {
         i.as_str()
@@ -758,21 +1022,21 @@ fn _make_cred_info(credential_id: &str, cred: &CredxCredential) -> VcxCoreResult
             .or(i.as_i64().map(|int_i| int_i.to_string()))
     });
 
-    let mut attrs = json!({});
-    for (x, y) in cred.values.0.iter() {
-        attrs[x] = Value::String(y.raw.to_string());
-    }
-
-    let val = json!({
-        "referent": credential_id,
-        "schema_id": schema_id,
-        "cred_def_id": cred_def_id,
-        "rev_reg_id": rev_reg_id,
-        "cred_rev_id": cred_rev_id,
-        "attrs": attrs
-    });
-
-    Ok(val)
+    let attrs = cred
+        .values
+        .0
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.raw.to_string()))
+        .collect();
+
+    Ok(CredentialInfo {
+        referent: credential_id.to_string(),
+        attrs,
+        schema_id,
+        cred_def_id,
+        rev_reg_id,
+        cred_rev_id,
+    })
 }
 
 fn _format_attribute_as_value_tag_name(attribute_name: &str) -> String {
@@ -783,11 +1047,13 @@ fn _format_attribute_as_marker_tag_name(attribute_name: &str) -> String {
     format!("attr::{attribute_name}::marker")
 }
 
-fn unimplemented_method_err(method_name: &str) -> AriesVcxCoreError {
-    AriesVcxCoreError::from_msg(
-        AriesVcxCoreErrorKind::UnimplementedFeature,
-        format!("method '{}' is not yet implemented in AriesVCX", method_name),
-    )
+// vcx anoncreds types and credx types share the same serialized form
+fn convert<T, U>(value: &T) -> VcxCoreResult<U>
+where
+    T: Serialize,
+    U: DeserializeOwned,
+{
+    Ok(serde_json::from_value(serde_json::to_value(value)?)?)
 }
 
 // common transformation requirement in credx
@@ -806,33 +1072,270 @@ where
 }
 
 #[cfg(test)]
-#[cfg(feature = "general_test")]
+#[cfg(feature = "vdrtools")]
 mod unit_tests {
-    use crate::errors::error::{AriesVcxCoreErrorKind, VcxCoreResult};
-    use crate::{common::test_utils::mock_profile, plugins::anoncreds::base_anoncreds::BaseAnonCreds};
+    use std::sync::Arc;
+
+    use serde_json::Value;
 
-    use super::IndyCredxAnonCreds;
+    use crate::anoncreds::base_anoncreds::BaseAnonCreds;
+    use crate::anoncreds::types::CredentialValues;
+    use crate::indy::utils::test_setup::{self, with_wallet};
+    use crate::ledger::types::Schema;
+    use crate::wallet::base_wallet::BaseWallet;
+    use crate::wallet::indy_wallet::IndySdkWallet;
+
+    use super::{
+        credx, make_credential_definition_id, CredentialDefinitionConfig, CredxCredentialDefinitionConfig, CredxSchema,
+        DidValue, IndyCredxAnonCreds, SignatureType,
+    };
+
+    const LINK_SECRET_ID: &str = "test_link_secret";
+
+    fn cred_values() -> CredentialValues {
+        serde_json::from_value(json!({
+            "name": {"raw": "Alice", "encoded": "27034640024117331033063128044004318218486816931520886405535659934417438781507"},
+            "age": {"raw": "28", "encoded": "28"}
+        }))
+        .unwrap()
+    }
+
+    fn tails_dir() -> String {
+        let mut path = std::env::temp_dir();
+        path.push(format!("credx_tails_{}", test_setup::generate_random_name()));
+        std::fs::create_dir_all(&path).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    async fn create_schema(anoncreds: &IndyCredxAnonCreds, issuer_did: &str) -> Schema {
+        let (_, schema_json) = anoncreds
+            .issuer_create_schema(issuer_did, "test_schema", "1.0", r#"["name","age"]"#)
+            .await
+            .unwrap();
+        serde_json::from_str(&schema_json).unwrap()
+    }
+
+    async fn issue_credential(
+        anoncreds: &IndyCredxAnonCreds,
+        issuer_did: &str,
+        prover_did: &str,
+        support_revocation: bool,
+    ) -> (String, Option<String>, Option<String>, String) {
+        let schema = create_schema(anoncreds, issuer_did).await;
+        let (cred_def_id, cred_def) = anoncreds
+            .issuer_create_and_store_credential_def(
+                issuer_did,
+                &schema,
+                "tag1",
+                None,
+                &CredentialDefinitionConfig { support_revocation },
+            )
+            .await
+            .unwrap();
+
+        let (rev_reg_id, rev_reg_def, tails_dir) = if support_revocation {
+            let tails_dir = tails_dir();
+            let (rev_reg_id, rev_reg_def, _) = anoncreds
+                .issuer_create_and_store_revoc_reg(issuer_did, &cred_def_id, &tails_dir, 10, "tag1")
+                .await
+                .unwrap();
+            (Some(rev_reg_id), Some(rev_reg_def), Some(tails_dir))
+        } else {
+            (None, None, None)
+        };
+
+        let cred_offer = anoncreds.issuer_create_credential_offer(&cred_def_id).await.unwrap();
+        anoncreds.prover_create_link_secret(LINK_SECRET_ID).await.unwrap();
+        let (cred_req, cred_req_meta) = anoncreds
+            .prover_create_credential_req(prover_did, &cred_offer, &cred_def, LINK_SECRET_ID)
+            .await
+            .unwrap();
+
+        let issued = anoncreds
+            .issuer_create_credential(&cred_offer, &cred_req, &cred_values(), rev_reg_id.clone(), tails_dir)
+            .await
+            .unwrap();
+
+        let cred_id = anoncreds
+            .prover_store_credential(
+                None,
+                &cred_req_meta,
+                &issued.credential,
+                &cred_def,
+                rev_reg_def.as_ref(),
+            )
+            .await
+            .unwrap();
+
+        (cred_id, rev_reg_id, issued.cred_rev_id, cred_def_id)
+    }
+
+    #[tokio::test]
+    async fn test_issue_and_store_nonrevocable_credential() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (issuer_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (prover_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(wallet);
+
+            let (cred_id, _, cred_rev_id, cred_def_id) =
+                issue_credential(&anoncreds, &issuer_did, &prover_did, false).await;
+            assert_eq!(cred_rev_id, None);
+
+            let cred_info = anoncreds.prover_get_credential(&cred_id).await.unwrap();
+            assert_eq!(cred_info.cred_def_id, cred_def_id);
+            assert_eq!(cred_info.attrs["name"], "Alice");
+            assert_eq!(cred_info.attrs["age"], "28");
+            assert_eq!(cred_info.rev_reg_id, None);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_issue_and_store_revocable_credential() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (issuer_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (prover_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(wallet);
+
+            let (cred_id, rev_reg_id, cred_rev_id, _) =
+                issue_credential(&anoncreds, &issuer_did, &prover_did, true).await;
+            assert_eq!(cred_rev_id.as_deref(), Some("1"));
+
+            let cred_info = anoncreds.prover_get_credential(&cred_id).await.unwrap();
+            assert_eq!(cred_info.rev_reg_id, rev_reg_id);
+            assert_eq!(cred_info.cred_rev_id.as_deref(), Some("1"));
+        })
+        .await;
+    }
 
     #[tokio::test]
-    async fn test_unimplemented_methods() {
-        // test used to assert which methods are unimplemented currently, can be removed after all methods implemented
+    async fn test_create_cred_def_twice_fails() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (issuer_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(wallet);
+
+            let schema = create_schema(&anoncreds, &issuer_did).await;
+            let config = CredentialDefinitionConfig::default();
+            anoncreds
+                .issuer_create_and_store_credential_def(&issuer_did, &schema, "tag1", None, &config)
+                .await
+                .unwrap();
+            let err = anoncreds
+                .issuer_create_and_store_credential_def(&issuer_did, &schema, "tag1", None, &config)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                err.kind(),
+                crate::errors::error::AriesVcxCoreErrorKind::CredDefAlreadyCreated
+            );
+        })
+        .await;
+    }
 
-        fn assert_unimplemented<T: std::fmt::Debug>(result: VcxCoreResult<T>) {
-            assert_eq!(result.unwrap_err().kind(), AriesVcxCoreErrorKind::UnimplementedFeature)
+    #[test]
+    fn test_make_credential_definition_id_matches_credx() {
+        let issuer_did = DidValue::new("V4SGRU86Z58d6TV7PBUe6f", None);
+        for seq_no in [json!(12), Value::Null] {
+            let schema: CredxSchema = serde_json::from_value(json!({
+                "ver": "1.0",
+                "id": "V4SGRU86Z58d6TV7PBUe6f:2:test_schema:1.0",
+                "name": "test_schema",
+                "version": "1.0",
+                "attrNames": ["name", "age"],
+                "seqNo": seq_no
+            }))
+            .unwrap();
+            let (cred_def, _, _) = credx::issuer::create_credential_definition(
+                &issuer_did,
+                &schema,
+                "tag1",
+                SignatureType::CL,
+                CredxCredentialDefinitionConfig {
+                    support_revocation: false,
+                },
+            )
+            .unwrap();
+            assert_eq!(
+                make_credential_definition_id(&issuer_did, &schema, SignatureType::CL, "tag1"),
+                cred_def.id().0
+            );
         }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_credentials_local_merges_deltas() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (issuer_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (prover_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(wallet);
+
+            let schema = create_schema(&anoncreds, &issuer_did).await;
+            let (cred_def_id, cred_def) = anoncreds
+                .issuer_create_and_store_credential_def(
+                    &issuer_did,
+                    &schema,
+                    "tag1",
+                    None,
+                    &CredentialDefinitionConfig {
+                        support_revocation: true,
+                    },
+                )
+                .await
+                .unwrap();
+            let tails_dir = tails_dir();
+            let (rev_reg_id, _, _) = anoncreds
+                .issuer_create_and_store_revoc_reg(&issuer_did, &cred_def_id, &tails_dir, 10, "tag1")
+                .await
+                .unwrap();
+            anoncreds.prover_create_link_secret(LINK_SECRET_ID).await.unwrap();
+
+            let mut cred_rev_ids = vec![];
+            for _ in 0..2 {
+                let cred_offer = anoncreds.issuer_create_credential_offer(&cred_def_id).await.unwrap();
+                let (cred_req, _) = anoncreds
+                    .prover_create_credential_req(&prover_did, &cred_offer, &cred_def, LINK_SECRET_ID)
+                    .await
+                    .unwrap();
+                let issued = anoncreds
+                    .issuer_create_credential(
+                        &cred_offer,
+                        &cred_req,
+                        &cred_values(),
+                        Some(rev_reg_id.clone()),
+                        Some(tails_dir.clone()),
+                    )
+                    .await
+                    .unwrap();
+                cred_rev_ids.push(issued.cred_rev_id.unwrap());
+            }
 
-        let profile = mock_profile();
-        let anoncreds: Box<dyn BaseAnonCreds> = Box::new(IndyCredxAnonCreds::new(profile.inject_wallet()));
+            assert_eq!(anoncreds.get_rev_reg_delta(&rev_reg_id).await.unwrap(), None);
 
-        assert_unimplemented(anoncreds.issuer_create_and_store_revoc_reg("", "", "", 0, "").await);
-        assert_unimplemented(
-            anoncreds
-                .issuer_create_and_store_credential_def("", "", "", None, "")
-                .await,
-        );
-        assert_unimplemented(anoncreds.issuer_create_credential_offer("").await);
-        assert_unimplemented(anoncreds.issuer_create_credential("", "", "", None, None).await);
-        assert_unimplemented(anoncreds.revoke_credential_local("", "", "").await);
-        assert_unimplemented(anoncreds.publish_local_revocations("", "").await);
+            for cred_rev_id in cred_rev_ids.iter() {
+                anoncreds
+                    .revoke_credential_local(&tails_dir, &rev_reg_id, cred_rev_id)
+                    .await
+                    .unwrap();
+            }
+
+            let delta = anoncreds.get_rev_reg_delta(&rev_reg_id).await.unwrap().unwrap();
+            let mut revoked = delta.revoked().to_vec();
+            revoked.sort();
+            assert_eq!(revoked, vec![1, 2]);
+
+            let err = anoncreds
+                .revoke_credential_local(&tails_dir, &rev_reg_id, &cred_rev_ids[0])
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), crate::errors::error::AriesVcxCoreErrorKind::InvalidInput);
+
+            anoncreds.clear_rev_reg_delta(&rev_reg_id).await.unwrap();
+            assert_eq!(anoncreds.get_rev_reg_delta(&rev_reg_id).await.unwrap(), None);
+        })
+        .await;
     }
 }
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use crate::errors::error::VcxCoreResult;
 use crate::indy::wallet_non_secrets::{clear_rev_reg_delta, get_rev_reg_delta};
+use crate::ledger::types::{CredentialDefinition, RevocationRegistryDefinition, RevocationRegistryDelta, Schema};
 use crate::{indy, PoolHandle, WalletHandle};
 
 use super::base_anoncreds::BaseAnonCreds;
+use super::types::{
+    Credential, CredentialDefinitionConfig, CredentialInfo, CredentialOffer, CredentialRequest,
+    CredentialRequestMetadata, CredentialValues, IssuedCredential, Proof, ProofRequest, RequestedCredentials,
+    RetrievedCredentials, RevocationRegistries, RevocationState, RevocationStates,
+};
 
 #[derive(Debug)]
 pub struct IndySdkAnonCreds {
@@ -21,20 +29,20 @@ impl IndySdkAnonCreds {
 impl BaseAnonCreds for IndySdkAnonCreds {
     async fn verifier_verify_proof(
         &self,
-        proof_req_json: &str,
-        proof_json: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        rev_reg_defs_json: &str,
-        rev_regs_json: &str,
+        proof_req: &ProofRequest,
+        proof: &Proof,
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        rev_reg_defs: &HashMap<String, RevocationRegistryDefinition>,
+        rev_regs: &RevocationRegistries,
     ) -> VcxCoreResult<bool> {
         indy::proofs::verifier::verifier::libindy_verifier_verify_proof(
-            proof_req_json,
-            proof_json,
-            schemas_json,
-            credential_defs_json,
-            rev_reg_defs_json,
-            rev_regs_json,
+            &serde_json::to_string(proof_req)?,
+            &serde_json::to_string(proof)?,
+            &serde_json::to_string(schemas)?,
+            &serde_json::to_string(credential_defs)?,
+            &serde_json::to_string(rev_reg_defs)?,
+            &serde_json::to_string(rev_regs)?,
         )
         .await
     }
@@ -46,144 +54,181 @@ impl BaseAnonCreds for IndySdkAnonCreds {
         tails_dir: &str,
         max_creds: u32,
         tag: &str,
-    ) -> VcxCoreResult<(String, String, String)> {
-        indy::primitives::revocation_registry::libindy_create_and_store_revoc_reg(
-            self.indy_wallet_handle,
-            issuer_did,
-            cred_def_id,
-            tails_dir,
-            max_creds,
-            tag,
-        )
-        .await
+    ) -> VcxCoreResult<(String, RevocationRegistryDefinition, RevocationRegistryDelta)> {
+        let (rev_reg_id, rev_reg_def_json, rev_reg_delta_json) =
+            indy::primitives::revocation_registry::libindy_create_and_store_revoc_reg(
+                self.indy_wallet_handle,
+                issuer_did,
+                cred_def_id,
+                tails_dir,
+                max_creds,
+                tag,
+            )
+            .await?;
+        Ok((
+            rev_reg_id,
+            serde_json::from_str(&rev_reg_def_json)?,
+            serde_json::from_str(&rev_reg_delta_json)?,
+        ))
     }
 
     async fn issuer_create_and_store_credential_def(
         &self,
         issuer_did: &str,
-        schema_json: &str,
+        schema: &Schema,
         tag: &str,
         sig_type: Option<&str>,
-        config_json: &str,
-    ) -> VcxCoreResult<(String, String)> {
-        indy::primitives::credential_definition::libindy_create_and_store_credential_def(
-            self.indy_wallet_handle,
-            issuer_did,
-            schema_json,
-            tag,
-            sig_type,
-            config_json,
-        )
-        .await
+        config: &CredentialDefinitionConfig,
+    ) -> VcxCoreResult<(String, CredentialDefinition)> {
+        let (cred_def_id, cred_def_json) =
+            indy::primitives::credential_definition::libindy_create_and_store_credential_def(
+                self.indy_wallet_handle,
+                issuer_did,
+                &serde_json::to_string(schema)?,
+                tag,
+                sig_type,
+                &serde_json::to_string(config)?,
+            )
+            .await?;
+        Ok((cred_def_id, serde_json::from_str(&cred_def_json)?))
     }
 
-    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<String> {
-        indy::credentials::issuer::libindy_issuer_create_credential_offer(self.indy_wallet_handle, cred_def_id).await
+    async fn issuer_create_credential_offer(&self, cred_def_id: &str) -> VcxCoreResult<CredentialOffer> {
+        let cred_offer_json =
+            indy::credentials::issuer::libindy_issuer_create_credential_offer(self.indy_wallet_handle, cred_def_id)
+                .await?;
+        Ok(serde_json::from_str(&cred_offer_json)?)
     }
 
     async fn issuer_create_credential(
         &self,
-        cred_offer_json: &str,
-        cred_req_json: &str,
-        cred_values_json: &str,
+        cred_offer: &CredentialOffer,
+        cred_req: &CredentialRequest,
+        cred_values: &CredentialValues,
         rev_reg_id: Option<String>,
         tails_dir: Option<String>,
-    ) -> VcxCoreResult<(String, Option<String>, Option<String>)> {
-        indy::credentials::issuer::libindy_issuer_create_credential(
+    ) -> VcxCoreResult<IssuedCredential> {
+        let (cred_json, cred_rev_id, rev_reg_delta_json) = indy::credentials::issuer::libindy_issuer_create_credential(
             self.indy_wallet_handle,
-            cred_offer_json,
-            cred_req_json,
-            cred_values_json,
+            &serde_json::to_string(cred_offer)?,
+            &serde_json::to_string(cred_req)?,
+            &serde_json::to_string(cred_values)?,
             rev_reg_id,
             tails_dir,
         )
-        .await
+        .await?;
+        Ok(IssuedCredential {
+            credential: serde_json::from_str(&cred_json)?,
+            cred_rev_id,
+            rev_reg_delta: rev_reg_delta_json
+                .map(|delta| serde_json::from_str(&delta))
+                .transpose()?,
+        })
     }
 
     async fn prover_create_proof(
         &self,
-        proof_req_json: &str,
-        requested_credentials_json: &str,
+        proof_req: &ProofRequest,
+        requested_credentials: &RequestedCredentials,
         master_secret_id: &str,
-        schemas_json: &str,
-        credential_defs_json: &str,
-        revoc_states_json: Option<&str>,
-    ) -> VcxCoreResult<String> {
-        indy::proofs::prover::prover::libindy_prover_create_proof(
+        schemas: &HashMap<String, Schema>,
+        credential_defs: &HashMap<String, CredentialDefinition>,
+        revoc_states: Option<&RevocationStates>,
+    ) -> VcxCoreResult<Proof> {
+        let revoc_states_json = revoc_states.map(serde_json::to_string).transpose()?;
+        let proof_json = indy::proofs::prover::prover::libindy_prover_create_proof(
             self.indy_wallet_handle,
-            proof_req_json,
-            requested_credentials_json,
+            &serde_json::to_string(proof_req)?,
+            &serde_json::to_string(requested_credentials)?,
             master_secret_id,
-            schemas_json,
-            credential_defs_json,
-            revoc_states_json,
+            &serde_json::to_string(schemas)?,
+            &serde_json::to_string(credential_defs)?,
+            revoc_states_json.as_deref(),
         )
-        .await
+        .await?;
+        Ok(serde_json::from_str(&proof_json)?)
     }
 
-    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<String> {
-        indy::credentials::holder::libindy_prover_get_credential(self.indy_wallet_handle, cred_id).await
+    async fn prover_get_credential(&self, cred_id: &str) -> VcxCoreResult<CredentialInfo> {
+        let cred_info_json =
+            indy::credentials::holder::libindy_prover_get_credential(self.indy_wallet_handle, cred_id).await?;
+        Ok(serde_json::from_str(&cred_info_json)?)
     }
 
-    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<String> {
-        indy::proofs::prover::prover::libindy_prover_get_credentials(self.indy_wallet_handle, filter_json).await
+    async fn prover_get_credentials(&self, filter_json: Option<&str>) -> VcxCoreResult<Vec<CredentialInfo>> {
+        let cred_infos_json =
+            indy::proofs::prover::prover::libindy_prover_get_credentials(self.indy_wallet_handle, filter_json).await?;
+        Ok(serde_json::from_str(&cred_infos_json)?)
     }
 
-    async fn prover_get_credentials_for_proof_req(&self, proof_req: &str) -> VcxCoreResult<String> {
-        indy::proofs::prover::prover::libindy_prover_get_credentials_for_proof_req(self.indy_wallet_handle, proof_req)
-            .await
+    async fn prover_get_credentials_for_proof_req(
+        &self,
+        proof_req: &ProofRequest,
+    ) -> VcxCoreResult<RetrievedCredentials> {
+        let retrieved_credentials_json = indy::proofs::prover::prover::libindy_prover_get_credentials_for_proof_req(
+            self.indy_wallet_handle,
+            &serde_json::to_string(proof_req)?,
+        )
+        .await?;
+        Ok(serde_json::from_str(&retrieved_credentials_json)?)
     }
 
     async fn prover_create_credential_req(
         &self,
         prover_did: &str,
-        credential_offer_json: &str,
-        credential_def_json: &str,
+        cred_offer: &CredentialOffer,
+        cred_def: &CredentialDefinition,
         master_secret_id: &str,
-    ) -> VcxCoreResult<(String, String)> {
-        indy::credentials::holder::libindy_prover_create_credential_req(
+    ) -> VcxCoreResult<(CredentialRequest, CredentialRequestMetadata)> {
+        let (cred_req_json, cred_req_meta_json) = indy::credentials::holder::libindy_prover_create_credential_req(
             self.indy_wallet_handle,
             prover_did,
-            credential_offer_json,
-            credential_def_json,
+            &serde_json::to_string(cred_offer)?,
+            &serde_json::to_string(cred_def)?,
             master_secret_id,
         )
-        .await
+        .await?;
+        Ok((
+            serde_json::from_str(&cred_req_json)?,
+            serde_json::from_str(&cred_req_meta_json)?,
+        ))
     }
 
     async fn create_revocation_state(
         &self,
         tails_dir: &str,
-        rev_reg_def_json: &str,
-        rev_reg_delta_json: &str,
+        rev_reg_def: &RevocationRegistryDefinition,
+        rev_reg_delta: &RevocationRegistryDelta,
         timestamp: u64,
         cred_rev_id: &str,
-    ) -> VcxCoreResult<String> {
-        indy::proofs::prover::libindy_prover_create_revocation_state(
+    ) -> VcxCoreResult<RevocationState> {
+        let rev_state_json = indy::proofs::prover::libindy_prover_create_revocation_state(
             tails_dir,
-            rev_reg_def_json,
-            rev_reg_delta_json,
+            &serde_json::to_string(rev_reg_def)?,
+            &serde_json::to_string(rev_reg_delta)?,
             timestamp,
             cred_rev_id,
         )
-        .await
+        .await?;
+        Ok(serde_json::from_str(&rev_state_json)?)
     }
 
     async fn prover_store_credential(
         &self,
         cred_id: Option<&str>,
-        cred_req_meta: &str,
-        cred_json: &str,
-        cred_def_json: &str,
-        rev_reg_def_json: Option<&str>,
+        cred_req_meta: &CredentialRequestMetadata,
+        credential: &Credential,
+        cred_def: &CredentialDefinition,
+        rev_reg_def: Option<&RevocationRegistryDefinition>,
     ) -> VcxCoreResult<String> {
+        let rev_reg_def_json = rev_reg_def.map(serde_json::to_string).transpose()?;
         indy::credentials::holder::libindy_prover_store_credential(
             self.indy_wallet_handle,
             cred_id,
-            cred_req_meta,
-            cred_json,
-            cred_def_json,
-            rev_reg_def_json,
+            &serde_json::to_string(cred_req_meta)?,
+            &serde_json::to_string(credential)?,
+            &serde_json::to_string(cred_def)?,
+            rev_reg_def_json.as_deref(),
         )
         .await
     }
@@ -216,8 +261,12 @@ impl BaseAnonCreds for IndySdkAnonCreds {
         .await
     }
 
-    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<String>> {
-        Ok(get_rev_reg_delta(self.indy_wallet_handle, rev_reg_id).await)
+    async fn get_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<Option<RevocationRegistryDelta>> {
+        get_rev_reg_delta(self.indy_wallet_handle, rev_reg_id)
+            .await
+            .map(|rev_reg_delta_json| serde_json::from_str(&rev_reg_delta_json))
+            .transpose()
+            .map_err(|err| err.into())
     }
 
     async fn clear_rev_reg_delta(&self, rev_reg_id: &str) -> VcxCoreResult<()> {
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::ledger::types::RevocationRegistryDelta;
+
+fn default_true() -> bool {
+    true
+}
+
+/// Credential offer, as created by
+/// [`BaseAnonCreds::issuer_create_credential_offer`](super::base_anoncreds::BaseAnonCreds::issuer_create_credential_offer).
+/// Serializes into the anoncreds credential offer JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialOffer {
+    pub schema_id: String,
+    pub cred_def_id: String,
+    pub key_correctness_proof: Value,
+    pub nonce: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method_name: Option<String>,
+}
+
+/// Credential request, as created by
+/// [`BaseAnonCreds::prover_create_credential_req`](super::base_anoncreds::BaseAnonCreds::prover_create_credential_req).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialRequest {
+    pub prover_did: String,
+    pub cred_def_id: String,
+    pub blinded_ms: Value,
+    pub blinded_ms_correctness_proof: Value,
+    pub nonce: String,
+}
+
+/// Private counterpart of a [`CredentialRequest`], kept by the prover until the credential is issued.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialRequestMetadata {
+    pub master_secret_blinding_data: Value,
+    pub nonce: String,
+    pub master_secret_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeValue {
+    pub raw: String,
+    pub encoded: String,
+}
+
+/// Raw and encoded values of the credential attributes, keyed by attribute name.
+pub type CredentialValues = HashMap<String, AttributeValue>;
+
+/// Anoncreds credential, as issued by
+/// [`BaseAnonCreds::issuer_create_credential`](super::base_anoncreds::BaseAnonCreds::issuer_create_credential).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Credential {
+    pub schema_id: String,
+    pub cred_def_id: String,
+    #[serde(default)]
+    pub rev_reg_id: Option<String>,
+    pub values: CredentialValues,
+    pub signature: Value,
+    pub signature_correctness_proof: Value,
+    #[serde(default)]
+    pub rev_reg: Option<Value>,
+    #[serde(default)]
+    pub witness: Option<Value>,
+}
+
+/// Result of [`BaseAnonCreds::issuer_create_credential`](super::base_anoncreds::BaseAnonCreds::issuer_create_credential).
+/// `cred_rev_id` and `rev_reg_delta` are only set when issuing from a revocation registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IssuedCredential {
+    pub credential: Credential,
+    pub cred_rev_id: Option<String>,
+    pub rev_reg_delta: Option<RevocationRegistryDelta>,
+}
+
+/// Summary of a credential stored in the prover's wallet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialInfo {
+    pub referent: String,
+    pub attrs: HashMap<String, String>,
+    pub schema_id: String,
+    pub cred_def_id: String,
+    #[serde(default)]
+    pub rev_reg_id: Option<String>,
+    #[serde(default)]
+    pub cred_rev_id: Option<String>,
+}
+
+/// Credential stored in the prover's wallet which can satisfy a referent of a [`ProofRequest`], along with the
+/// interval its non-revocation has to be proven for.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetrievedCredentialForReferent {
+    pub cred_info: CredentialInfo,
+    #[serde(default)]
+    pub interval: Option<NonRevokedInterval>,
+}
+
+/// Result of
+/// [`BaseAnonCreds::prover_get_credentials_for_proof_req`](super::base_anoncreds::BaseAnonCreds::prover_get_credentials_for_proof_req),
+/// the matching credentials keyed by referent of the proof request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetrievedCredentials {
+    #[serde(default)]
+    pub attrs: HashMap<String, Vec<RetrievedCredentialForReferent>>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub predicates: HashMap<String, Vec<RetrievedCredentialForReferent>>,
+}
+
+/// Options of a credential definition created by
+/// [`BaseAnonCreds::issuer_create_and_store_credential_def`](super::base_anoncreds::BaseAnonCreds::issuer_create_and_store_credential_def).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialDefinitionConfig {
+    #[serde(default)]
+    pub support_revocation: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Filter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_issuer_did: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer_did: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cred_def_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum Restrictions {
+    V1(Vec<Filter>),
+    V2(serde_json::Value),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct PredicateInfo {
+    pub name: String,
+    //Todo: Update p_type to use Enum
+    pub p_type: String,
+    pub p_value: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<Restrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_revoked: Option<NonRevokedInterval>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct AttrInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<Restrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_revoked: Option<NonRevokedInterval>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_attest_allowed: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct NonRevokedInterval {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+}
+
+/// Anoncreds proof request, as sent by the verifier.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRequest {
+    pub nonce: String,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub requested_attributes: HashMap<String, AttrInfo>,
+    #[serde(default)]
+    pub requested_predicates: HashMap<String, PredicateInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub non_revoked: Option<NonRevokedInterval>,
+    // anoncreds proof request format version, "1.0" if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ver: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestedAttribute {
+    pub cred_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    #[serde(default = "default_true")]
+    pub revealed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestedPredicate {
+    pub cred_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+/// Credentials (or self attested values) selected by the prover for each referent of a [`ProofRequest`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestedCredentials {
+    #[serde(default)]
+    pub self_attested_attributes: HashMap<String, String>,
+    #[serde(default)]
+    pub requested_attributes: HashMap<String, RequestedAttribute>,
+    #[serde(default)]
+    pub requested_predicates: HashMap<String, RequestedPredicate>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevealedAttributeInfo {
+    pub sub_proof_index: u32,
+    pub raw: String,
+    pub encoded: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevealedAttributeGroupInfo {
+    pub sub_proof_index: u32,
+    pub values: HashMap<String, AttributeValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubProofReferent {
+    pub sub_proof_index: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestedProof {
+    #[serde(default)]
+    pub revealed_attrs: HashMap<String, RevealedAttributeInfo>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub revealed_attr_groups: HashMap<String, RevealedAttributeGroupInfo>,
+    #[serde(default)]
+    pub self_attested_attrs: HashMap<String, String>,
+    #[serde(default)]
+    pub unrevealed_attrs: HashMap<String, SubProofReferent>,
+    #[serde(default)]
+    pub predicates: HashMap<String, SubProofReferent>,
+}
+
+/// Ledger objects a sub proof of a [`Proof`] was created against.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Identifier {
+    pub schema_id: String,
+    pub cred_def_id: String,
+    #[serde(default)]
+    pub rev_reg_id: Option<String>,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+}
+
+/// Anoncreds proof, as created by
+/// [`BaseAnonCreds::prover_create_proof`](super::base_anoncreds::BaseAnonCreds::prover_create_proof).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proof {
+    pub proof: Value,
+    pub requested_proof: RequestedProof,
+    pub identifiers: Vec<Identifier>,
+}
+
+/// Revocation state of a credential at `timestamp`, used by the prover to create a non-revocation proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationState {
+    pub witness: Value,
+    pub rev_reg: Value,
+    pub timestamp: u64,
+}
+
+/// Revocation states keyed by revocation registry id and timestamp.
+pub type RevocationStates = HashMap<String, HashMap<u64, RevocationState>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationRegistryValue {
+    pub accum: String,
+}
+
+/// Accumulator of a revocation registry at a point in time, as read from the ledger.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationRegistry {
+    pub ver: String,
+    pub value: RevocationRegistryValue,
+}
+
+/// Revocation registries keyed by revocation registry id and timestamp.
+pub type RevocationRegistries = HashMap<String, HashMap<u64, RevocationRegistry>>;
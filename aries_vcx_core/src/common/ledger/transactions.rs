@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Request {
@@ -50,3 +52,64 @@ pub struct ReplyV1 {
 pub struct ReplyDataV1 {
     pub result: serde_json::Value,
 }
+
+pub fn verify_transaction_can_be_endorsed(transaction_json: &str, did: &str) -> VcxCoreResult<()> {
+    let transaction: Request = serde_json::from_str(transaction_json)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidJson, format!("{err:?}")))?;
+
+    let transaction_endorser = transaction.endorser.ok_or(AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::InvalidJson,
+        "Transaction cannot be endorsed: endorser DID is not set.",
+    ))?;
+
+    if transaction_endorser != did {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidJson,
+            format!(
+                "Transaction cannot be endorsed: transaction endorser DID `{transaction_endorser}` and sender DID `{did}` are different"
+            ),
+        ));
+    }
+
+    let identifier = transaction.identifier.as_str();
+    if transaction.signature.is_none()
+        && !transaction
+            .signatures
+            .as_ref()
+            .map(|signatures| signatures.contains_key(identifier))
+            .unwrap_or(false)
+    {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidJson,
+            "Transaction cannot be endorsed: the author must sign the transaction.".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_transaction_can_be_endorsed() {
+        // success
+        let transaction = r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "signature": "gkVDhwe2", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
+        assert!(verify_transaction_can_be_endorsed(transaction, "NcYxiDXkpYi6ov5FcYDi1e").is_ok());
+
+        // success with multi signature of the author
+        let transaction = r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "signatures": {"EbP4aYNeTHL6q385GuVpRV": "gkVDhwe2"}, "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
+        assert!(verify_transaction_can_be_endorsed(transaction, "NcYxiDXkpYi6ov5FcYDi1e").is_ok());
+
+        // no author signature
+        let transaction =
+            r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
+        assert!(verify_transaction_can_be_endorsed(transaction, "NcYxiDXkpYi6ov5FcYDi1e").is_err());
+
+        // different endorser did
+        let transaction =
+            r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
+        assert!(verify_transaction_can_be_endorsed(transaction, "EbP4aYNeTHL6q385GuVpRV").is_err());
+    }
+}
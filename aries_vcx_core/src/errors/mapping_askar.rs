@@ -0,0 +1,20 @@
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind};
+use aries_askar::{Error as AskarError, ErrorKind as AskarErrorKind};
+
+impl From<AskarError> for AriesVcxCoreError {
+    fn from(err: AskarError) -> Self {
+        match err.kind() {
+            AskarErrorKind::Backend => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::IOError, err),
+            AskarErrorKind::Busy => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidState, err),
+            AskarErrorKind::Custom => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::UnknownError, err),
+            AskarErrorKind::Duplicate => {
+                AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::DuplicationWalletRecord, err)
+            }
+            AskarErrorKind::Encryption => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::WalletAccessFailed, err),
+            AskarErrorKind::Input => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, err),
+            AskarErrorKind::NotFound => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::WalletRecordNotFound, err),
+            AskarErrorKind::Unexpected => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::UnknownError, err),
+            AskarErrorKind::Unsupported => AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::ActionNotSupported, err),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 pub mod error;
 mod mapping_agency_client;
 #[cfg(feature = "modular_libs")]
+mod mapping_askar;
+#[cfg(feature = "modular_libs")]
 mod mapping_credx;
 #[cfg(feature = "modular_libs")]
 mod mapping_indyvdr;
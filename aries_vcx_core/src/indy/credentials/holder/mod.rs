@@ -107,7 +107,10 @@ pub async fn libindy_prover_create_credential_req(
     master_secret_name: &str,
 ) -> VcxCoreResult<(String, String)> {
     if settings::indy_mocks_enabled() {
-        return Ok((utils::constants::CREDENTIAL_REQ_STRING.to_owned(), String::new()));
+        return Ok((
+            utils::constants::CREDENTIAL_REQ_STRING.to_owned(),
+            utils::constants::CRED_REQ_META.to_owned(),
+        ));
     }
 
     let cred_offer = serde_json::from_str::<CredentialOffer>(credential_offer_json)?;
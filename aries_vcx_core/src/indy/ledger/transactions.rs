@@ -1,13 +1,14 @@
 use time::OffsetDateTime;
 use vdrtools::{DidValue, Locator};
 
-use crate::common::ledger::transactions::{Request, Response};
+use crate::common::ledger::transactions::{verify_transaction_can_be_endorsed, Response};
 use crate::errors::error::prelude::*;
 use crate::global::author_agreement::get_txn_author_agreement;
 use crate::global::settings;
 use crate::global::settings::get_sample_did;
 use crate::indy::utils::mocks::pool_mocks::PoolMocks;
 use crate::indy::utils::parse_and_validate;
+use crate::ledger::types::append_nym_version_to_request;
 use crate::utils::constants::{
     rev_def_json, CRED_DEF_ID, CRED_DEF_JSON, CRED_DEF_REQ, REVOC_REG_TYPE, REV_REG_DELTA_JSON, REV_REG_ID,
     REV_REG_JSON, SCHEMA_ID, SCHEMA_JSON, SCHEMA_TXN, SUBMIT_SCHEMA_RESPONSE,
@@ -189,7 +190,7 @@ pub async fn libindy_build_nym_request(
     submitter_did: &str,
     target_did: &str,
     verkey: Option<&str>,
-    data: Option<&str>,
+    alias: Option<&str>,
     role: Option<&str>,
 ) -> VcxCoreResult<String> {
     if PoolMocks::has_pool_mock_responses() {
@@ -203,7 +204,7 @@ pub async fn libindy_build_nym_request(
             submitter_did.into(),
             target_did.into(),
             verkey.map(|s| s.into()),
-            data.map(|s| s.into()),
+            alias.map(|s| s.into()),
             role.map(|s| s.into()),
         )
         .await?;
@@ -212,11 +213,22 @@ pub async fn libindy_build_nym_request(
 }
 
 pub async fn get_nym(pool_handle: PoolHandle, did: &str) -> VcxCoreResult<String> {
+    get_nym_version(pool_handle, did, None, None).await
+}
+
+pub async fn get_nym_version(
+    pool_handle: PoolHandle,
+    did: &str,
+    seq_no: Option<u64>,
+    timestamp: Option<u64>,
+) -> VcxCoreResult<String> {
     let submitter_did = get_sample_did();
 
     let get_nym_req = libindy_build_get_nym_request(Some(&submitter_did), did).await?;
+    let mut get_nym_req: serde_json::Value = serde_json::from_str(&get_nym_req)?;
+    append_nym_version_to_request(&mut get_nym_req, seq_no, timestamp)?;
 
-    libindy_submit_request(pool_handle, &get_nym_req).await
+    libindy_submit_request(pool_handle, &get_nym_req.to_string()).await
 }
 
 fn parse_response(response: &str) -> VcxCoreResult<Response> {
@@ -303,7 +315,7 @@ pub async fn endorse_transaction(
         return Ok(());
     }
 
-    _verify_transaction_can_be_endorsed(transaction_json, endorser_did)?;
+    verify_transaction_can_be_endorsed(transaction_json, endorser_did)?;
 
     let transaction = multisign_request(wallet_handle, endorser_did, transaction_json).await?;
     let response = libindy_submit_request(pool_handle, &transaction).await?;
@@ -317,41 +329,6 @@ pub async fn endorse_transaction(
     }
 }
 
-fn _verify_transaction_can_be_endorsed(transaction_json: &str, _did: &str) -> VcxCoreResult<()> {
-    let transaction: Request = serde_json::from_str(transaction_json)
-        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidJson, format!("{err:?}")))?;
-
-    let transaction_endorser = transaction.endorser.ok_or(AriesVcxCoreError::from_msg(
-        AriesVcxCoreErrorKind::InvalidJson,
-        "Transaction cannot be endorsed: endorser DID is not set.",
-    ))?;
-
-    if transaction_endorser != _did {
-        return Err(AriesVcxCoreError::from_msg(
-            AriesVcxCoreErrorKind::InvalidJson,
-            format!(
-                "Transaction cannot be endorsed: transaction endorser DID `{transaction_endorser}` and sender DID `{_did}` are different"
-            ),
-        ));
-    }
-
-    let identifier = transaction.identifier.as_str();
-    if transaction.signature.is_none()
-        && !transaction
-            .signatures
-            .as_ref()
-            .map(|signatures| signatures.contains_key(identifier))
-            .unwrap_or(false)
-    {
-        return Err(AriesVcxCoreError::from_msg(
-            AriesVcxCoreErrorKind::InvalidJson,
-            "Transaction cannot be endorsed: the author must sign the transaction.".to_string(),
-        ));
-    }
-
-    Ok(())
-}
-
 pub async fn build_attrib_request(
     submitter_did: &str,
     target_did: &str,
@@ -740,32 +717,6 @@ pub async fn get_cred_def_json(
     Ok((cred_def_id.to_string(), cred_def_json))
 }
 
-// #[cfg(test)]
-// mod test {
-//     use crate::utils::devsetup::*;
-
-//     use super::*;
-
-//     #[test]
-//     fn test_verify_transaction_can_be_endorsed() {
-//         let _setup = SetupDefaults::init();
-
-//         // success
-//         let transaction = r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "signature": "gkVDhwe2", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
-//         assert!(_verify_transaction_can_be_endorsed(transaction, "NcYxiDXkpYi6ov5FcYDi1e").is_ok());
-
-//         // no author signature
-//         let transaction =
-//             r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
-//         assert!(_verify_transaction_can_be_endorsed(transaction, "NcYxiDXkpYi6ov5FcYDi1e").is_err());
-
-//         // different endorser did
-//         let transaction =
-//             r#"{"reqId":1, "identifier": "EbP4aYNeTHL6q385GuVpRV", "endorser": "NcYxiDXkpYi6ov5FcYDi1e"}"#;
-//         assert!(_verify_transaction_can_be_endorsed(transaction, "EbP4aYNeTHL6q385GuVpRV").is_err());
-//     }
-// }
-
 // #[cfg(test)]
 // pub mod integration_tests {
 //     use crate::indy::ledger::transactions::get_ledger_txn;
@@ -1,6 +1,11 @@
 use async_trait::async_trait;
 
-use crate::errors::error::VcxCoreResult;
+use crate::errors::error::{AriesVcxCoreErrorKind, VcxCoreResult};
+
+use super::types::{
+    CredentialDefinition, NymData, NymResponse, RevocationRegistryDefinition, RevocationRegistryDelta, Schema,
+    TxnAuthorAgreement,
+};
 
 /// Trait defining standard 'ledger' related functionality.
 #[async_trait]
@@ -17,36 +22,57 @@ pub trait BaseLedger: std::fmt::Debug + Send + Sync {
     // adds endorser to request and signs with submitter_did, returns the transaction ready for endorser to take
     async fn set_endorser(&self, submitter_did: &str, request: &str, endorser: &str) -> VcxCoreResult<String>;
 
-    async fn get_txn_author_agreement(&self) -> VcxCoreResult<String>;
+    // returns None if the ledger does not require a transaction author agreement
+    async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<TxnAuthorAgreement>>;
 
-    // returns request result as JSON
-    async fn get_nym(&self, did: &str) -> VcxCoreResult<String>;
+    // fails with LedgerItemNotFound if the DID is not written on the ledger
+    async fn get_nym(&self, did: &str) -> VcxCoreResult<NymResponse>;
+
+    // like `get_nym`, but returns the NYM as written by the transaction `seq_no`, or as it was at
+    // `timestamp`; fails with LedgerItemNotFound if the DID has no such version
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<NymResponse>;
+
+    // returns the time the NYM of `did` was first written, given the `txn_time` of any of its
+    // versions; the ledger only keeps the state of a NYM, so this searches for the earliest time
+    // it can be read at, which takes a single request for NYMs which were never updated
+    async fn get_nym_created(&self, did: &str, txn_time: u64) -> VcxCoreResult<u64> {
+        // the NYM can be read at `created`, but not at `not_created`
+        let mut created = txn_time;
+        let mut not_created = 0;
+        let mut probe = txn_time.saturating_sub(1);
+        while created - not_created > 1 {
+            match self.get_nym_version(did, None, Some(probe)).await {
+                Ok(nym) => created = nym.txn_time.map_or(probe, |txn_time| txn_time.min(probe)),
+                Err(err) if err.kind() == AriesVcxCoreErrorKind::LedgerItemNotFound => not_created = probe,
+                Err(err) => return Err(err),
+            }
+            probe = not_created + (created - not_created) / 2;
+        }
+        Ok(created)
+    }
 
     // returns request result as JSON
+    // `data` carries the optional alias and diddocContent of the NYM
     async fn publish_nym(
         &self,
         submitter_did: &str,
         target_did: &str,
         verkey: Option<&str>,
-        data: Option<&str>,
+        data: Option<&NymData>,
         role: Option<&str>,
     ) -> VcxCoreResult<String>;
 
-    // Schema json.
-    // {
-    //     id: identifier of schema
-    //     attrNames: array of attribute name strings
-    //     name: Schema's name string
-    //     version: Schema's version string
-    //     ver: Version of the Schema json
-    // }
     // if submitter_did provided - use cache
-    // TO CONSIDER - do we need to return the schema ID in a tuple? is it ever different to the input?
-    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String>;
+    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<Schema>;
 
     // if submitter_did provided, try use cache
-    // TO CONSIDER - do we need to return the cred def ID in a tuple? is it ever different to the input?
-    async fn get_cred_def(&self, cred_def_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String>;
+    async fn get_cred_def(&self, cred_def_id: &str, submitter_did: Option<&str>)
+        -> VcxCoreResult<CredentialDefinition>;
 
     // returns request result as JSON
     async fn get_attr(&self, target_did: &str, attr_name: &str) -> VcxCoreResult<String>;
@@ -54,42 +80,17 @@ pub trait BaseLedger: std::fmt::Debug + Send + Sync {
     // returns request result as JSON
     async fn add_attr(&self, target_did: &str, attrib_json: &str) -> VcxCoreResult<String>;
 
-    // # Returns
-    // Revocation Registry Definition Id and Revocation Registry Definition json.
-    // {
-    //     "id": string - ID of the Revocation Registry,
-    //     "revocDefType": string - Revocation Registry type (only CL_ACCUM is supported for now),
-    //     "tag": string - Unique descriptive ID of the Registry,
-    //     "credDefId": string - ID of the corresponding CredentialDefinition,
-    //     "value": Registry-specific data {
-    //         "issuanceType": string - Type of Issuance(ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND),
-    //         "maxCredNum": number - Maximum number of credentials the Registry can serve.
-    //         "tailsHash": string - Hash of tails.
-    //         "tailsLocation": string - Location of tails file.
-    //         "publicKeys": <public_keys> - Registry's public key.
-    //     },
-    //     "ver": string - version of revocation registry definition json.
-    // }
-    // TO CONSIDER - do we need to return the rev reg id in a tuple? is it ever different to the input?
-    async fn get_rev_reg_def_json(&self, rev_reg_id: &str) -> VcxCoreResult<String>;
+    async fn get_rev_reg_def(&self, rev_reg_id: &str) -> VcxCoreResult<RevocationRegistryDefinition>;
 
     // # Returns
-    // Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp.
-    // {
-    //     "value": Registry-specific data {
-    //         prevAccum: string - previous accumulator value.
-    //         accum: string - current accumulator value.
-    //         issued: array<number> - an array of issued indices.
-    //         revoked: array<number> an array of revoked indices.
-    //     },
-    //     "ver": string - version revocation registry delta json
-    // }
-    async fn get_rev_reg_delta_json(
+    // Revocation Registry Delta accumulating the changes of the registry between `from` and `to`
+    // (defaults to now), and the timestamp of the delta
+    async fn get_rev_reg_delta(
         &self,
         rev_reg_id: &str,
         from: Option<u64>,
         to: Option<u64>,
-    ) -> VcxCoreResult<(String, String, u64)>;
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)>;
 
     // # Returns
     // Revocation Registry Definition Id, Revocation Registry json and Timestamp.
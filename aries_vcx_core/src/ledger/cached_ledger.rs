@@ -0,0 +1,794 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+
+use crate::errors::error::{AriesVcxCoreErrorKind, VcxCoreResult};
+use crate::wallet::base_wallet::{AsyncFnIteratorCollect, BaseWallet};
+
+use super::base_ledger::BaseLedger;
+use super::types::{
+    CredentialDefinition, NymData, NymResponse, RevocationRegistryDefinition, RevocationRegistryDelta, Schema,
+    TxnAuthorAgreement,
+};
+
+const CACHE_RECORD_TYPE: &str = "VCX_LEDGER_CACHE";
+
+// schemas, cred defs and rev reg defs can not be changed once written to the ledger
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+/// Time-to-live of cached ledger objects, per object type. `None` disables caching of the object type.
+///
+/// Revocation registries and deltas are only cached when requested for a point in time which already
+/// passed; requests for the current state are always forwarded to the ledger.
+///
+/// At most `max_entries` objects are kept in memory, the least recently used one is evicted first, along with
+/// its wallet record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerCacheConfig {
+    pub schema_ttl: Option<Duration>,
+    pub cred_def_ttl: Option<Duration>,
+    pub rev_reg_def_ttl: Option<Duration>,
+    pub rev_reg_ttl: Option<Duration>,
+    pub rev_reg_delta_ttl: Option<Duration>,
+    pub max_entries: usize,
+}
+
+impl Default for LedgerCacheConfig {
+    fn default() -> Self {
+        LedgerCacheConfig {
+            schema_ttl: Some(DEFAULT_TTL),
+            cred_def_ttl: Some(DEFAULT_TTL),
+            rev_reg_def_ttl: Some(DEFAULT_TTL),
+            rev_reg_ttl: Some(DEFAULT_TTL),
+            rev_reg_delta_ttl: Some(DEFAULT_TTL),
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    value: String,
+    // unix timestamp, in seconds
+    cached_at: i64,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, ttl: Duration, now: i64) -> bool {
+        now - self.cached_at < ttl.as_secs() as i64
+    }
+}
+
+// In memory entries, indexed by the tick of their last use to find the least recently used one
+#[derive(Default)]
+struct LruCache {
+    entries: HashMap<String, (CacheEntry, u64)>,
+    usage: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl LruCache {
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        let tick = self.next_tick();
+        let (entry, last_used) = self.entries.get_mut(key)?;
+        self.usage.remove(last_used);
+        self.usage.insert(tick, key.to_string());
+        *last_used = tick;
+        Some(entry.clone())
+    }
+
+    // returns the keys evicted to make room for the entry
+    fn insert(&mut self, key: &str, entry: CacheEntry, max_entries: usize) -> Vec<String> {
+        let mut evicted = Vec::new();
+        if max_entries == 0 {
+            return evicted;
+        }
+        let tick = self.next_tick();
+        if let Some((_, last_used)) = self.entries.insert(key.to_string(), (entry, tick)) {
+            self.usage.remove(&last_used);
+        }
+        self.usage.insert(tick, key.to_string());
+
+        while self.entries.len() > max_entries {
+            let lru_tick = match self.usage.keys().next() {
+                Some(tick) => *tick,
+                None => break,
+            };
+            if let Some(lru_key) = self.usage.remove(&lru_tick) {
+                self.entries.remove(&lru_key);
+                evicted.push(lru_key);
+            }
+        }
+        evicted
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((_, last_used)) = self.entries.remove(key) {
+            self.usage.remove(&last_used);
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// [`BaseLedger`] decorator caching ledger reads of the inner ledger. All other calls are passed through.
+///
+/// Cached objects are kept in memory and, if a wallet is provided, persisted into the wallet, so that the
+/// cache survives restarts. Expired records are deleted from the wallet when read, and the records left by
+/// previous runs are purged down to `max_entries` on first use of the wallet.
+pub struct CachedLedger {
+    inner: Arc<dyn BaseLedger>,
+    wallet: Option<Arc<dyn BaseWallet>>,
+    config: LedgerCacheConfig,
+    cache: Mutex<LruCache>,
+    // unix timestamp, in seconds, which freshness and past ledger states are judged by
+    clock: Arc<dyn Fn() -> i64 + Send + Sync>,
+    purged: AtomicBool,
+}
+
+impl CachedLedger {
+    pub fn new(inner: Arc<dyn BaseLedger>, wallet: Option<Arc<dyn BaseWallet>>, config: LedgerCacheConfig) -> Self {
+        CachedLedger {
+            inner,
+            wallet,
+            config,
+            cache: Mutex::new(LruCache::default()),
+            clock: Arc::new(current_epoch_time),
+            purged: AtomicBool::new(false),
+        }
+    }
+
+    #[cfg(test)]
+    fn set_clock(mut self, clock: impl Fn() -> i64 + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    fn now(&self) -> i64 {
+        (self.clock)()
+    }
+
+    // whether the ledger state at the given time can still change
+    fn is_past(&self, timestamp: u64) -> bool {
+        (timestamp as i64) < self.now()
+    }
+
+    fn ttl_of(&self, key: &str) -> Option<Duration> {
+        match key.split(':').next()? {
+            "schema" => self.config.schema_ttl,
+            "cred_def" => self.config.cred_def_ttl,
+            "rev_reg_def" => self.config.rev_reg_def_ttl,
+            "rev_reg" => self.config.rev_reg_ttl,
+            "rev_reg_delta" => self.config.rev_reg_delta_ttl,
+            _ => None,
+        }
+    }
+
+    async fn get_cached<T: DeserializeOwned>(&self, key: &str, ttl: Option<Duration>) -> Option<T> {
+        let ttl = ttl?;
+
+        let entry = self.get_cache_entry(key).await?;
+        if !entry.is_fresh(ttl, self.now()) {
+            self.cache.lock().ok()?.remove(key);
+            if let Some(wallet) = &self.wallet {
+                delete_cache_record(wallet, key).await;
+            }
+            return None;
+        }
+        match serde_json::from_str(&entry.value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                warn!(
+                    "CachedLedger::get_cached >>> failed to deserialize cached {}: {}",
+                    key, err
+                );
+                None
+            }
+        }
+    }
+
+    async fn get_cache_entry(&self, key: &str) -> Option<CacheEntry> {
+        if let Some(entry) = self.cache.lock().ok()?.get(key) {
+            return Some(entry);
+        }
+
+        let wallet = self.wallet.as_ref()?;
+        self.purge_once(wallet).await;
+        let record = wallet.get_wallet_record(CACHE_RECORD_TYPE, key, "{}").await.ok()?;
+        let record: Value = serde_json::from_str(&record).ok()?;
+        let entry: CacheEntry = serde_json::from_str(record["value"].as_str()?).ok()?;
+
+        let evicted = self
+            .cache
+            .lock()
+            .ok()?
+            .insert(key, entry.clone(), self.config.max_entries);
+        for key in evicted {
+            delete_cache_record(wallet, &key).await;
+        }
+        Some(entry)
+    }
+
+    async fn set_cached<T: Serialize>(&self, key: &str, ttl: Option<Duration>, value: &T) -> VcxCoreResult<()> {
+        if ttl.is_none() {
+            return Ok(());
+        }
+
+        let entry = CacheEntry {
+            value: serde_json::to_string(value)?,
+            cached_at: self.now(),
+        };
+        let evicted = self.cache.lock()?.insert(key, entry.clone(), self.config.max_entries);
+
+        if let Some(wallet) = &self.wallet {
+            self.purge_once(wallet).await;
+            // the cache is best effort, failing to persist it should not fail the ledger read
+            if let Err(err) = persist_cache_entry(wallet, key, &entry).await {
+                warn!(
+                    "CachedLedger::set_cached >>> failed to persist {} into wallet: {}",
+                    key, err
+                );
+            }
+            for key in evicted {
+                delete_cache_record(wallet, &key).await;
+            }
+        }
+        Ok(())
+    }
+
+    // records persisted by previous runs are not tracked in memory, so they are bounded once per instance
+    async fn purge_once(&self, wallet: &Arc<dyn BaseWallet>) {
+        if self.purged.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        if let Err(err) = self.purge_cache_records(wallet).await {
+            warn!(
+                "CachedLedger::purge_once >>> failed to purge cache records from wallet: {}",
+                err
+            );
+        }
+    }
+
+    // deletes the expired records, then the oldest ones beyond `max_entries`
+    async fn purge_cache_records(&self, wallet: &Arc<dyn BaseWallet>) -> VcxCoreResult<()> {
+        let mut record_iterator = wallet.iterate_wallet_records(CACHE_RECORD_TYPE, "{}", "{}").await?;
+        let records = record_iterator.collect().await?;
+
+        let now = self.now();
+        let mut fresh = Vec::new();
+        for record in records {
+            let record: Value = serde_json::from_str(&record)?;
+            let key = match record["id"].as_str() {
+                Some(key) => key.to_string(),
+                None => continue,
+            };
+            let entry = record["value"]
+                .as_str()
+                .and_then(|value| serde_json::from_str::<CacheEntry>(value).ok());
+            match (entry, self.ttl_of(&key)) {
+                (Some(entry), Some(ttl)) if entry.is_fresh(ttl, now) => fresh.push((entry.cached_at, key)),
+                _ => delete_cache_record(wallet, &key).await,
+            }
+        }
+
+        fresh.sort();
+        let excess = fresh.len().saturating_sub(self.config.max_entries);
+        for (_, key) in fresh.into_iter().take(excess) {
+            delete_cache_record(wallet, &key).await;
+        }
+        Ok(())
+    }
+}
+
+async fn persist_cache_entry(wallet: &Arc<dyn BaseWallet>, key: &str, entry: &CacheEntry) -> VcxCoreResult<()> {
+    let value = serde_json::to_string(entry)?;
+    match wallet.add_wallet_record(CACHE_RECORD_TYPE, key, &value, None).await {
+        Err(err) if err.kind() == AriesVcxCoreErrorKind::DuplicationWalletRecord => {
+            wallet.update_wallet_record_value(CACHE_RECORD_TYPE, key, &value).await
+        }
+        result => result,
+    }
+}
+
+async fn delete_cache_record(wallet: &Arc<dyn BaseWallet>, key: &str) {
+    match wallet.delete_wallet_record(CACHE_RECORD_TYPE, key).await {
+        Err(err) if err.kind() != AriesVcxCoreErrorKind::WalletRecordNotFound => {
+            warn!(
+                "CachedLedger::delete_cache_record >>> failed to delete {} from wallet: {}",
+                key, err
+            );
+        }
+        _ => {}
+    }
+}
+
+fn current_epoch_time() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+impl Debug for CachedLedger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedLedger")
+            .field("inner", &self.inner)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl BaseLedger for CachedLedger {
+    async fn sign_and_submit_request(&self, submitter_did: &str, request_json: &str) -> VcxCoreResult<String> {
+        self.inner.sign_and_submit_request(submitter_did, request_json).await
+    }
+
+    async fn submit_request(&self, request_json: &str) -> VcxCoreResult<String> {
+        self.inner.submit_request(request_json).await
+    }
+
+    async fn endorse_transaction(&self, endorser_did: &str, request_json: &str) -> VcxCoreResult<()> {
+        self.inner.endorse_transaction(endorser_did, request_json).await
+    }
+
+    async fn set_endorser(&self, submitter_did: &str, request: &str, endorser: &str) -> VcxCoreResult<String> {
+        self.inner.set_endorser(submitter_did, request, endorser).await
+    }
+
+    async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<TxnAuthorAgreement>> {
+        self.inner.get_txn_author_agreement().await
+    }
+
+    async fn get_nym(&self, did: &str) -> VcxCoreResult<NymResponse> {
+        self.inner.get_nym(did).await
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<NymResponse> {
+        self.inner.get_nym_version(did, seq_no, timestamp).await
+    }
+
+    async fn publish_nym(
+        &self,
+        submitter_did: &str,
+        target_did: &str,
+        verkey: Option<&str>,
+        data: Option<&NymData>,
+        role: Option<&str>,
+    ) -> VcxCoreResult<String> {
+        self.inner
+            .publish_nym(submitter_did, target_did, verkey, data, role)
+            .await
+    }
+
+    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<Schema> {
+        let key = format!("schema:{}", schema_id);
+        if let Some(schema) = self.get_cached(&key, self.config.schema_ttl).await {
+            return Ok(schema);
+        }
+
+        let schema = self.inner.get_schema(schema_id, submitter_did).await?;
+        self.set_cached(&key, self.config.schema_ttl, &schema).await?;
+        Ok(schema)
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &str,
+        submitter_did: Option<&str>,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        let key = format!("cred_def:{}", cred_def_id);
+        if let Some(cred_def) = self.get_cached(&key, self.config.cred_def_ttl).await {
+            return Ok(cred_def);
+        }
+
+        let cred_def = self.inner.get_cred_def(cred_def_id, submitter_did).await?;
+        self.set_cached(&key, self.config.cred_def_ttl, &cred_def).await?;
+        Ok(cred_def)
+    }
+
+    async fn get_attr(&self, target_did: &str, attr_name: &str) -> VcxCoreResult<String> {
+        self.inner.get_attr(target_did, attr_name).await
+    }
+
+    async fn add_attr(&self, target_did: &str, attrib_json: &str) -> VcxCoreResult<String> {
+        self.inner.add_attr(target_did, attrib_json).await
+    }
+
+    async fn get_rev_reg_def(&self, rev_reg_id: &str) -> VcxCoreResult<RevocationRegistryDefinition> {
+        let key = format!("rev_reg_def:{}", rev_reg_id);
+        if let Some(rev_reg_def) = self.get_cached(&key, self.config.rev_reg_def_ttl).await {
+            return Ok(rev_reg_def);
+        }
+
+        let rev_reg_def = self.inner.get_rev_reg_def(rev_reg_id).await?;
+        self.set_cached(&key, self.config.rev_reg_def_ttl, &rev_reg_def).await?;
+        Ok(rev_reg_def)
+    }
+
+    async fn get_rev_reg_delta(
+        &self,
+        rev_reg_id: &str,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        // a delta up to now keeps changing as revocations get published
+        let to = match to {
+            Some(to) if self.is_past(to) => to,
+            _ => return self.inner.get_rev_reg_delta(rev_reg_id, from, to).await,
+        };
+
+        let key = format!("rev_reg_delta:{}:{:?}:{}", rev_reg_id, from, to);
+        if let Some(rev_reg_delta) = self.get_cached(&key, self.config.rev_reg_delta_ttl).await {
+            return Ok(rev_reg_delta);
+        }
+
+        let rev_reg_delta = self.inner.get_rev_reg_delta(rev_reg_id, from, Some(to)).await?;
+        self.set_cached(&key, self.config.rev_reg_delta_ttl, &rev_reg_delta)
+            .await?;
+        Ok(rev_reg_delta)
+    }
+
+    async fn get_rev_reg(&self, rev_reg_id: &str, timestamp: u64) -> VcxCoreResult<(String, String, u64)> {
+        if !self.is_past(timestamp) {
+            return self.inner.get_rev_reg(rev_reg_id, timestamp).await;
+        }
+
+        let key = format!("rev_reg:{}:{}", rev_reg_id, timestamp);
+        if let Some(rev_reg) = self.get_cached(&key, self.config.rev_reg_ttl).await {
+            return Ok(rev_reg);
+        }
+
+        let rev_reg = self.inner.get_rev_reg(rev_reg_id, timestamp).await?;
+        self.set_cached(&key, self.config.rev_reg_ttl, &rev_reg).await?;
+        Ok(rev_reg)
+    }
+
+    async fn get_ledger_txn(&self, seq_no: i32, submitter_did: Option<&str>) -> VcxCoreResult<String> {
+        self.inner.get_ledger_txn(seq_no, submitter_did).await
+    }
+
+    async fn build_schema_request(&self, submitter_did: &str, schema_json: &str) -> VcxCoreResult<String> {
+        self.inner.build_schema_request(submitter_did, schema_json).await
+    }
+
+    async fn publish_schema(
+        &self,
+        schema_json: &str,
+        submitter_did: &str,
+        endorser_did: Option<String>,
+    ) -> VcxCoreResult<()> {
+        self.inner
+            .publish_schema(schema_json, submitter_did, endorser_did)
+            .await
+    }
+
+    async fn publish_cred_def(&self, cred_def_json: &str, submitter_did: &str) -> VcxCoreResult<()> {
+        self.inner.publish_cred_def(cred_def_json, submitter_did).await
+    }
+
+    async fn publish_rev_reg_def(&self, rev_reg_def: &str, submitter_did: &str) -> VcxCoreResult<()> {
+        self.inner.publish_rev_reg_def(rev_reg_def, submitter_did).await
+    }
+
+    async fn publish_rev_reg_delta(
+        &self,
+        rev_reg_id: &str,
+        rev_reg_entry_json: &str,
+        submitter_did: &str,
+    ) -> VcxCoreResult<()> {
+        self.inner
+            .publish_rev_reg_delta(rev_reg_id, rev_reg_entry_json, submitter_did)
+            .await
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "vdrtools", feature = "modular_libs"))]
+mod unit_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use indy_vdr::pool::PreparedRequest;
+
+    use crate::anoncreds::base_anoncreds::BaseAnonCreds;
+    use crate::anoncreds::credx_anoncreds::IndyCredxAnonCreds;
+    use crate::anoncreds::types::CredentialDefinitionConfig;
+    use crate::indy::utils::test_setup::with_wallet;
+    use crate::ledger::indy_vdr_ledger::IndyVdrLedger;
+    use crate::ledger::request_submitter::in_memory_ledger::InMemoryLedger;
+    use crate::ledger::request_submitter::RequestSubmitter;
+    use crate::wallet::indy_wallet::IndySdkWallet;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct CountingSubmitter {
+        ledger: InMemoryLedger,
+        submitted: AtomicUsize,
+    }
+
+    impl CountingSubmitter {
+        fn submitted(&self) -> usize {
+            self.submitted.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl RequestSubmitter for CountingSubmitter {
+        async fn submit(&self, request: PreparedRequest) -> VcxCoreResult<String> {
+            self.submitted.fetch_add(1, Ordering::SeqCst);
+            self.ledger.submit(request).await
+        }
+    }
+
+    // wall clock time when called, moving along with the paused tokio time
+    fn tokio_clock() -> impl Fn() -> i64 + Copy + Send + Sync + 'static {
+        let start = current_epoch_time();
+        let origin = tokio::time::Instant::now();
+        move || start + origin.elapsed().as_secs() as i64
+    }
+
+    async fn persisted_keys(wallet: &Arc<dyn BaseWallet>) -> Vec<String> {
+        let mut record_iterator = wallet
+            .iterate_wallet_records(CACHE_RECORD_TYPE, "{}", "{}")
+            .await
+            .unwrap();
+        let mut keys: Vec<String> = record_iterator
+            .collect()
+            .await
+            .unwrap()
+            .iter()
+            .map(|record| {
+                let record: Value = serde_json::from_str(record).unwrap();
+                record["id"].as_str().unwrap().to_string()
+            })
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    async fn publish_schema(wallet: &Arc<dyn BaseWallet>, ledger: &CachedLedger) -> String {
+        let (did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let anoncreds = IndyCredxAnonCreds::new(Arc::clone(wallet));
+        let (schema_id, schema_json) = anoncreds
+            .issuer_create_schema(&did, "test_schema", "1.0", r#"["name","age"]"#)
+            .await
+            .unwrap();
+        ledger.publish_schema(&schema_json, &did, None).await.unwrap();
+        schema_id
+    }
+
+    #[tokio::test]
+    async fn test_get_schema_is_served_from_cache() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+            let ledger = CachedLedger::new(inner, None, LedgerCacheConfig::default());
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let submitted = submitter.submitted();
+
+            let schema = ledger.get_schema(&schema_id, None).await.unwrap();
+            assert_eq!(ledger.get_schema(&schema_id, None).await.unwrap(), schema);
+            assert_eq!(submitter.submitted(), submitted + 1);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_disabled_entries_are_fetched_from_ledger() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+
+            let config = LedgerCacheConfig {
+                schema_ttl: Some(Duration::ZERO),
+                ..LedgerCacheConfig::default()
+            };
+            let ledger = CachedLedger::new(inner.clone(), None, config);
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let submitted = submitter.submitted();
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            assert_eq!(submitter.submitted(), submitted + 2);
+
+            let config = LedgerCacheConfig {
+                schema_ttl: None,
+                ..LedgerCacheConfig::default()
+            };
+            let ledger = CachedLedger::new(inner, None, config);
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            assert_eq!(submitter.submitted(), submitted + 4);
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_lru_cache_evicts_least_recently_used_entry() {
+        let entry = |value: &str| CacheEntry {
+            value: value.to_string(),
+            cached_at: current_epoch_time(),
+        };
+        let mut cache = LruCache::default();
+        cache.insert("a", entry("1"), 2);
+        cache.insert("b", entry("2"), 2);
+        assert_eq!(cache.get("a").unwrap().value, "1");
+
+        cache.insert("c", entry("3"), 2);
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.get("a").unwrap().value, "1");
+        assert_eq!(cache.get("c").unwrap().value, "3");
+
+        cache.insert("a", entry("4"), 2);
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.usage.len(), 2);
+        assert_eq!(cache.get("a").unwrap().value, "4");
+
+        cache.insert("d", entry("5"), 0);
+        assert!(cache.get("d").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cache_is_persisted_into_wallet() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner: Arc<dyn BaseLedger> = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+
+            let ledger = CachedLedger::new(
+                Arc::clone(&inner),
+                Some(Arc::clone(&wallet)),
+                LedgerCacheConfig::default(),
+            );
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let schema = ledger.get_schema(&schema_id, None).await.unwrap();
+            let submitted = submitter.submitted();
+
+            let ledger = CachedLedger::new(inner, Some(Arc::clone(&wallet)), LedgerCacheConfig::default());
+            assert_eq!(ledger.get_schema(&schema_id, None).await.unwrap(), schema);
+            assert_eq!(submitter.submitted(), submitted);
+        })
+        .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_persisted_cache_is_bounded() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner: Arc<dyn BaseLedger> = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+            let config = LedgerCacheConfig {
+                schema_ttl: Some(Duration::from_secs(60)),
+                max_entries: 1,
+                ..LedgerCacheConfig::default()
+            };
+
+            let ledger = CachedLedger::new(Arc::clone(&inner), Some(Arc::clone(&wallet)), config.clone())
+                .set_clock(tokio_clock());
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let other_schema_id = publish_schema(&wallet, &ledger).await;
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            ledger.get_schema(&other_schema_id, None).await.unwrap();
+            // the record of the evicted entry is deleted along with it
+            assert_eq!(
+                persisted_keys(&wallet).await,
+                vec![format!("schema:{}", other_schema_id)]
+            );
+
+            tokio::time::advance(Duration::from_secs(120)).await;
+            let key = format!("schema:{}", other_schema_id);
+            assert!(ledger.get_cached::<Schema>(&key, config.schema_ttl).await.is_none());
+            assert!(persisted_keys(&wallet).await.is_empty());
+        })
+        .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_expired_records_of_previous_runs_are_purged() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner: Arc<dyn BaseLedger> = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+            let config = LedgerCacheConfig {
+                schema_ttl: Some(Duration::from_secs(60)),
+                ..LedgerCacheConfig::default()
+            };
+
+            let clock = tokio_clock();
+            let ledger =
+                CachedLedger::new(Arc::clone(&inner), Some(Arc::clone(&wallet)), config.clone()).set_clock(clock);
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let other_schema_id = publish_schema(&wallet, &ledger).await;
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            ledger.get_schema(&other_schema_id, None).await.unwrap();
+            assert_eq!(persisted_keys(&wallet).await.len(), 2);
+
+            tokio::time::advance(Duration::from_secs(120)).await;
+            let ledger = CachedLedger::new(inner, Some(Arc::clone(&wallet)), config).set_clock(clock);
+            ledger.get_schema(&schema_id, None).await.unwrap();
+            assert_eq!(persisted_keys(&wallet).await, vec![format!("schema:{}", schema_id)]);
+        })
+        .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_current_rev_reg_delta_is_never_cached() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let submitter = Arc::new(CountingSubmitter::default());
+            let inner = Arc::new(IndyVdrLedger::new(Arc::clone(&wallet), Arc::clone(&submitter)));
+            let ledger = CachedLedger::new(inner, None, LedgerCacheConfig::default()).set_clock(tokio_clock());
+
+            let anoncreds = IndyCredxAnonCreds::new(Arc::clone(&wallet));
+            let schema_id = publish_schema(&wallet, &ledger).await;
+            let schema = ledger.get_schema(&schema_id, None).await.unwrap();
+            let (cred_def_id, cred_def) = anoncreds
+                .issuer_create_and_store_credential_def(
+                    &did,
+                    &schema,
+                    "tag1",
+                    None,
+                    &CredentialDefinitionConfig {
+                        support_revocation: true,
+                    },
+                )
+                .await
+                .unwrap();
+            ledger
+                .publish_cred_def(&serde_json::to_string(&cred_def).unwrap(), &did)
+                .await
+                .unwrap();
+            let tails_dir = std::env::temp_dir().to_str().unwrap().to_string();
+            let (rev_reg_id, rev_reg_def, rev_reg_entry) = anoncreds
+                .issuer_create_and_store_revoc_reg(&did, &cred_def_id, &tails_dir, 10, "tag1")
+                .await
+                .unwrap();
+            ledger
+                .publish_rev_reg_def(&serde_json::to_string(&rev_reg_def).unwrap(), &did)
+                .await
+                .unwrap();
+            ledger
+                .publish_rev_reg_delta(&rev_reg_id, &serde_json::to_string(&rev_reg_entry).unwrap(), &did)
+                .await
+                .unwrap();
+
+            let submitted = submitter.submitted();
+            let (_, timestamp) = ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
+            ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
+            assert_eq!(submitter.submitted(), submitted + 2);
+
+            // move the clock of the cache past the delta timestamp
+            tokio::time::advance(Duration::from_secs(60)).await;
+            let submitted = submitter.submitted();
+            let delta = ledger
+                .get_rev_reg_delta(&rev_reg_id, None, Some(timestamp))
+                .await
+                .unwrap();
+            assert_eq!(
+                ledger
+                    .get_rev_reg_delta(&rev_reg_id, None, Some(timestamp))
+                    .await
+                    .unwrap(),
+                delta
+            );
+            assert_eq!(submitter.submitted(), submitted + 1);
+        })
+        .await;
+    }
+}
@@ -4,6 +4,10 @@ use crate::errors::error::VcxCoreResult;
 use crate::{indy, PoolHandle, WalletHandle};
 
 use super::base_ledger::BaseLedger;
+use super::types::{
+    parse_ledger_json, CredentialDefinition, NymData, NymResponse, RevocationRegistryDefinition,
+    RevocationRegistryDelta, Schema, TxnAuthorAgreement,
+};
 
 #[derive(Debug)]
 pub struct IndySdkLedger {
@@ -50,12 +54,25 @@ impl BaseLedger for IndySdkLedger {
         indy::ledger::transactions::set_endorser(self.indy_wallet_handle, submitter_did, request_json, endorser).await
     }
 
-    async fn get_txn_author_agreement(&self) -> VcxCoreResult<String> {
-        indy::ledger::transactions::libindy_get_txn_author_agreement(self.indy_pool_handle).await
+    async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<TxnAuthorAgreement>> {
+        let taa = indy::ledger::transactions::libindy_get_txn_author_agreement(self.indy_pool_handle).await?;
+        TxnAuthorAgreement::from_author_agreement_data(&taa)
     }
 
-    async fn get_nym(&self, did: &str) -> VcxCoreResult<String> {
-        indy::ledger::transactions::get_nym(self.indy_pool_handle, did).await
+    async fn get_nym(&self, did: &str) -> VcxCoreResult<NymResponse> {
+        let response = indy::ledger::transactions::get_nym(self.indy_pool_handle, did).await?;
+        NymResponse::from_ledger_response(&response)
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<NymResponse> {
+        let response =
+            indy::ledger::transactions::get_nym_version(self.indy_pool_handle, did, seq_no, timestamp).await?;
+        NymResponse::from_ledger_response(&response)
     }
 
     // returns request result as JSON
@@ -64,12 +81,21 @@ impl BaseLedger for IndySdkLedger {
         submitter_did: &str,
         target_did: &str,
         verkey: Option<&str>,
-        data: Option<&str>,
+        data: Option<&NymData>,
         role: Option<&str>,
     ) -> VcxCoreResult<String> {
+        let alias = data.and_then(|data| data.alias.as_deref());
         let nym_request =
-            indy::ledger::transactions::libindy_build_nym_request(submitter_did, target_did, verkey, data, role)
+            indy::ledger::transactions::libindy_build_nym_request(submitter_did, target_did, verkey, alias, role)
                 .await?;
+        let nym_request = match data {
+            Some(data) if data.diddoc_content.is_some() => {
+                let mut nym_request: serde_json::Value = serde_json::from_str(&nym_request)?;
+                data.append_diddoc_content_to_request(&mut nym_request)?;
+                nym_request.to_string()
+            }
+            _ => nym_request,
+        };
         let nym_request = indy::ledger::transactions::append_txn_author_agreement_to_request(&nym_request).await?;
 
         indy::ledger::transactions::libindy_sign_and_submit_request(
@@ -81,8 +107,8 @@ impl BaseLedger for IndySdkLedger {
         .await
     }
 
-    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        if let Some(submitter_did) = submitter_did {
+    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<Schema> {
+        let schema_json = if let Some(submitter_did) = submitter_did {
             // with cache if possible
             indy::ledger::transactions::libindy_get_schema(
                 self.indy_wallet_handle,
@@ -90,19 +116,25 @@ impl BaseLedger for IndySdkLedger {
                 submitter_did,
                 schema_id,
             )
-            .await
+            .await?
         } else {
             // no cache
             indy::ledger::transactions::get_schema_json(self.indy_wallet_handle, self.indy_pool_handle, schema_id)
-                .await
-                .map(|(_, json)| json)
-        }
+                .await?
+                .1
+        };
+        parse_ledger_json(&schema_json)
     }
 
-    async fn get_cred_def(&self, cred_def_id: &str, _submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        indy::ledger::transactions::get_cred_def_json(self.indy_wallet_handle, self.indy_pool_handle, cred_def_id)
-            .await
-            .map(|(_id, json)| json)
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &str,
+        _submitter_did: Option<&str>,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        let (_, cred_def_json) =
+            indy::ledger::transactions::get_cred_def_json(self.indy_wallet_handle, self.indy_pool_handle, cred_def_id)
+                .await?;
+        parse_ledger_json(&cred_def_json)
     }
 
     async fn get_attr(&self, target_did: &str, attr_name: &str) -> VcxCoreResult<String> {
@@ -114,19 +146,21 @@ impl BaseLedger for IndySdkLedger {
             .await
     }
 
-    async fn get_rev_reg_def_json(&self, rev_reg_id: &str) -> VcxCoreResult<String> {
-        indy::ledger::transactions::get_rev_reg_def_json(self.indy_pool_handle, rev_reg_id)
-            .await
-            .map(|(_, json)| json)
+    async fn get_rev_reg_def(&self, rev_reg_id: &str) -> VcxCoreResult<RevocationRegistryDefinition> {
+        let (_, rev_reg_def_json) =
+            indy::ledger::transactions::get_rev_reg_def_json(self.indy_pool_handle, rev_reg_id).await?;
+        parse_ledger_json(&rev_reg_def_json)
     }
 
-    async fn get_rev_reg_delta_json(
+    async fn get_rev_reg_delta(
         &self,
         rev_reg_id: &str,
         from: Option<u64>,
         to: Option<u64>,
-    ) -> VcxCoreResult<(String, String, u64)> {
-        indy::ledger::transactions::get_rev_reg_delta_json(self.indy_pool_handle, rev_reg_id, from, to).await
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        let (_, rev_reg_delta_json, timestamp) =
+            indy::ledger::transactions::get_rev_reg_delta_json(self.indy_pool_handle, rev_reg_id, from, to).await?;
+        Ok((parse_ledger_json(&rev_reg_delta_json)?, timestamp))
     }
 
     async fn get_rev_reg(&self, rev_reg_id: &str, timestamp: u64) -> VcxCoreResult<(String, String, u64)> {
@@ -4,21 +4,26 @@ use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 use time::OffsetDateTime;
-use vdr::ledger::requests::schema::{AttributeNames, Schema, SchemaV1};
+use vdr::ledger::requests::schema::Schema as VdrSchema;
 
 use async_trait::async_trait;
 use serde_json::Value;
 use tokio::sync::oneshot;
 use vdr::common::error::VdrError;
 use vdr::config::PoolConfig as IndyVdrPoolConfig;
+use vdr::ledger::constants::LedgerType;
 use vdr::ledger::identifiers::{CredentialDefinitionId, RevocationRegistryId, SchemaId};
 use vdr::ledger::requests::author_agreement::TxnAuthrAgrmtAcceptanceData;
+use vdr::ledger::requests::cred_def::CredentialDefinition;
+use vdr::ledger::requests::rev_reg::RevocationRegistryDelta;
+use vdr::ledger::requests::rev_reg_def::{RegistryType, RevocationRegistryDefinition};
 use vdr::ledger::RequestBuilder;
 use vdr::pool::{PoolBuilder, PoolTransactions};
 use vdr::pool::{PoolRunner, PreparedRequest, ProtocolVersion, RequestResult};
 use vdr::utils::did::DidValue;
 use vdr::utils::Qualifiable;
 
+use crate::common::ledger::transactions::verify_transaction_can_be_endorsed;
 use crate::errors::error::VcxCoreResult;
 use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind};
 use crate::global::author_agreement::get_txn_author_agreement;
@@ -27,19 +32,24 @@ use crate::utils::json::{AsTypeOrDeserializationError, TryGetIndex};
 use crate::wallet::base_wallet::BaseWallet;
 
 use super::base_ledger::BaseLedger;
+use super::request_submitter::RequestSubmitter;
+use super::types::{
+    append_nym_version_to_request, CredentialDefinition as LedgerCredentialDefinition, NymData, NymResponse,
+    RevocationRegistryDefinition as LedgerRevocationRegistryDefinition,
+    RevocationRegistryDelta as LedgerRevocationRegistryDelta, RevocationRegistryDeltaValue, Schema, TxnAuthorAgreement,
+};
 
 pub struct LedgerPoolConfig {
     pub genesis_file_path: String,
 }
 
 pub struct IndyVdrLedgerPool {
-    // visibility strictly for internal unit testing
-    pub(self) runner: Option<PoolRunner>,
+    runner: PoolRunner,
 }
 
 impl IndyVdrLedgerPool {
     pub fn new_from_runner(runner: PoolRunner) -> Self {
-        IndyVdrLedgerPool { runner: Some(runner) }
+        IndyVdrLedgerPool { runner }
     }
 
     pub fn new(config: LedgerPoolConfig) -> VcxCoreResult<Self> {
@@ -48,7 +58,7 @@ impl IndyVdrLedgerPool {
 
         let runner = PoolBuilder::from(vdr_config).transactions(txns)?.into_runner()?;
 
-        Ok(IndyVdrLedgerPool { runner: Some(runner) })
+        Ok(IndyVdrLedgerPool { runner })
     }
 }
 
@@ -60,46 +70,21 @@ impl Debug for IndyVdrLedgerPool {
     }
 }
 
-pub struct IndyVdrLedger {
-    wallet: Arc<dyn BaseWallet>,
-    pool: Arc<IndyVdrLedgerPool>,
-}
-
-impl IndyVdrLedger {
-    pub fn new(wallet: Arc<dyn BaseWallet>, pool: Arc<IndyVdrLedgerPool>) -> Self {
-        IndyVdrLedger { wallet, pool }
-    }
-
-    pub fn request_builder(&self) -> VcxCoreResult<RequestBuilder> {
-        // TODO - confirm correct protocol version?
-        let v = settings::get_protocol_version();
-        let version = ProtocolVersion::from_id(v as u64)?;
-        Ok(RequestBuilder::new(version))
-    }
-
-    async fn _submit_request(&self, request: PreparedRequest) -> VcxCoreResult<String> {
+#[async_trait]
+impl RequestSubmitter for IndyVdrLedgerPool {
+    async fn submit(&self, request: PreparedRequest) -> VcxCoreResult<String> {
         // indyvdr send_request is Async via a callback.
         // Use oneshot channel to send result from callback, converting the fn to future.
         type VdrSendRequestResult =
             Result<(RequestResult<String>, Option<HashMap<String, f32, RandomState>>), VdrError>;
         let (sender, recv) = oneshot::channel::<VdrSendRequestResult>();
-        self.pool
-            .runner
-            .as_ref()
-            .ok_or(
-                // should not happen - strictly for unit testing
-                AriesVcxCoreError::from_msg(
-                    AriesVcxCoreErrorKind::NoPoolOpen,
-                    "IndyVdrLedgerPool runner was not provided",
-                ),
-            )?
-            .send_request(
-                request,
-                Box::new(move |result| {
-                    // unable to handle a failure from `send` here
-                    sender.send(result).ok();
-                }),
-            )?;
+        self.runner.send_request(
+            request,
+            Box::new(move |result| {
+                // unable to handle a failure from `send` here
+                sender.send(result).ok();
+            }),
+        )?;
 
         let send_req_result: VdrSendRequestResult = recv
             .await
@@ -113,6 +98,31 @@ impl IndyVdrLedger {
 
         Ok(reply?)
     }
+}
+
+pub struct IndyVdrLedger<T: RequestSubmitter = IndyVdrLedgerPool> {
+    wallet: Arc<dyn BaseWallet>,
+    request_submitter: Arc<T>,
+}
+
+impl<T: RequestSubmitter> IndyVdrLedger<T> {
+    pub fn new(wallet: Arc<dyn BaseWallet>, request_submitter: Arc<T>) -> Self {
+        IndyVdrLedger {
+            wallet,
+            request_submitter,
+        }
+    }
+
+    pub fn request_builder(&self) -> VcxCoreResult<RequestBuilder> {
+        // TODO - confirm correct protocol version?
+        let v = settings::get_protocol_version();
+        let version = ProtocolVersion::from_id(v as u64)?;
+        Ok(RequestBuilder::new(version))
+    }
+
+    async fn _submit_request(&self, request: PreparedRequest) -> VcxCoreResult<String> {
+        self.request_submitter.submit(request).await
+    }
 
     async fn _sign_and_submit_request(&self, submitter_did: &str, request: PreparedRequest) -> VcxCoreResult<String> {
         let mut request = request;
@@ -127,6 +137,19 @@ impl IndyVdrLedger {
         self._submit_request(request).await
     }
 
+    async fn _multi_sign_request(&self, did: &str, request: PreparedRequest) -> VcxCoreResult<PreparedRequest> {
+        let mut request = request;
+        let to_sign = request.get_signature_input()?;
+
+        let signer_verkey = self.wallet.key_for_local_did(did).await?;
+
+        let signature = self.wallet.sign(&signer_verkey, to_sign.as_bytes()).await?;
+
+        request.set_multi_signature(&DidValue::from_str(did)?, &signature)?;
+
+        Ok(request)
+    }
+
     async fn _build_get_cred_def_request(
         &self,
         submitter_did: Option<&str>,
@@ -164,6 +187,13 @@ impl IndyVdrLedger {
         )?)
     }
 
+    fn _build_schema_request(&self, submitter_did: &str, schema_json: &str) -> VcxCoreResult<PreparedRequest> {
+        let identifier = DidValue::from_str(submitter_did)?;
+        let schema: VdrSchema = serde_json::from_str(schema_json)?;
+
+        Ok(self.request_builder()?.build_schema_request(&identifier, schema)?)
+    }
+
     fn _build_attrib_request(
         &self,
         submitter_did: &str,
@@ -184,14 +214,14 @@ impl IndyVdrLedger {
     }
 }
 
-impl Debug for IndyVdrLedger {
+impl<T: RequestSubmitter> Debug for IndyVdrLedger<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "IndyVdrLedger instance")
     }
 }
 
 #[async_trait]
-impl BaseLedger for IndyVdrLedger {
+impl<T: RequestSubmitter> BaseLedger for IndyVdrLedger<T> {
     async fn sign_and_submit_request(&self, submitter_did: &str, request_json: &str) -> VcxCoreResult<String> {
         let request = PreparedRequest::from_request_json(request_json)?;
 
@@ -204,24 +234,58 @@ impl BaseLedger for IndyVdrLedger {
     }
 
     async fn endorse_transaction(&self, endorser_did: &str, request_json: &str) -> VcxCoreResult<()> {
-        let _ = (endorser_did, request_json);
-        Err(unimplemented_method_err("indy_vdr endorse_transaction"))
+        verify_transaction_can_be_endorsed(request_json, endorser_did)?;
+
+        let request = PreparedRequest::from_request_json(request_json)?;
+        let request = self._multi_sign_request(endorser_did, request).await?;
+
+        self._submit_request(request).await.map(|_| ())
     }
 
     async fn set_endorser(&self, submitter_did: &str, request_json: &str, endorser: &str) -> VcxCoreResult<String> {
-        let _ = (submitter_did, request_json, endorser);
-        Err(unimplemented_method_err("indy_vdr set_endorser"))
+        let mut request = PreparedRequest::from_request_json(request_json)?;
+        request.set_endorser(&DidValue::from_str(endorser)?)?;
+
+        let request = self._multi_sign_request(submitter_did, request).await?;
+
+        Ok(request.req_json.to_string())
     }
 
-    async fn get_txn_author_agreement(&self) -> VcxCoreResult<String> {
-        Err(unimplemented_method_err("indy_vdr get_txn_author_agreement"))
+    async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<TxnAuthorAgreement>> {
+        let taa_request = self
+            .request_builder()?
+            .build_get_txn_author_agreement_request(None, None)?;
+        let taa_response = self._submit_request(taa_request).await?;
+
+        let aml_request = self
+            .request_builder()?
+            .build_get_acceptance_mechanisms_request(None, None, None)?;
+        let aml_response = self._submit_request(aml_request).await?;
+
+        let taa = _merge_txn_author_agreement_responses(&taa_response, &aml_response)?;
+        TxnAuthorAgreement::from_author_agreement_data(&taa)
     }
 
-    async fn get_nym(&self, did: &str) -> VcxCoreResult<String> {
+    async fn get_nym(&self, did: &str) -> VcxCoreResult<NymResponse> {
         let dest = DidValue::from_str(did)?;
         let request = self.request_builder()?.build_get_nym_request(None, &dest)?;
 
-        self._submit_request(request).await
+        let response = self._submit_request(request).await?;
+        NymResponse::from_ledger_response(&response)
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<NymResponse> {
+        let dest = DidValue::from_str(did)?;
+        let mut request = self.request_builder()?.build_get_nym_request(None, &dest)?;
+        append_nym_version_to_request(&mut request.req_json, seq_no, timestamp)?;
+
+        let response = self._submit_request(request).await?;
+        NymResponse::from_ledger_response(&response)
     }
 
     async fn publish_nym(
@@ -229,29 +293,29 @@ impl BaseLedger for IndyVdrLedger {
         submitter_did: &str,
         target_did: &str,
         verkey: Option<&str>,
-        data: Option<&str>,
+        data: Option<&NymData>,
         role: Option<&str>,
     ) -> VcxCoreResult<String> {
-        // TODO - FUTURE: convert data into "alias" for indy vdr. for now throw unimplemented
-        if data.is_some() {
-            return Err(unimplemented_method_err("indy_vdr publish_nym with data"));
-        }
-        let alias = None;
+        let alias = data.and_then(|data| data.alias.clone());
 
         let identifier = DidValue::from_str(submitter_did)?;
         let dest = DidValue::from_str(target_did)?;
-        let request = self.request_builder()?.build_nym_request(
+        let mut request = self.request_builder()?.build_nym_request(
             &identifier,
             &dest,
             verkey.map(String::from),
             alias,
             role.map(String::from),
         )?;
+        if let Some(data) = data {
+            data.append_diddoc_content_to_request(&mut request.req_json)?;
+        }
+        let request = _append_txn_author_agreement_to_request(request).await?;
 
         self._sign_and_submit_request(submitter_did, request).await
     }
 
-    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String> {
+    async fn get_schema(&self, schema_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<Schema> {
         let _ = submitter_did;
         // TODO - future - try from cache first
         // TODO - future - do we need to handle someone submitting a schema request by seq number?
@@ -278,24 +342,23 @@ impl BaseLedger for IndyVdrLedger {
         let schema_id = SchemaId::new(&DidValue::from_str(dest)?, name, version);
 
         let attr_names = data_json.try_get("attr_names")?;
-        let attr_names: AttributeNames = serde_json::from_value(attr_names.to_owned())?;
+        let attr_names: Vec<String> = serde_json::from_value(attr_names.to_owned())?;
 
-        let schema = SchemaV1 {
-            id: schema_id,
+        Ok(Schema {
+            ver: "1.0".to_string(),
+            id: schema_id.0,
             name: name.to_string(),
             version: version.to_string(),
             attr_names,
             seq_no,
-        };
-
-        // TODO - future - store in cache if submitter_did provided
-
-        Ok(serde_json::to_string(&Schema::SchemaV1(schema))?)
+        })
     }
 
-    async fn get_cred_def(&self, cred_def_id: &str, submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        // todo - try from cache if submitter_did provided
-
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &str,
+        submitter_did: Option<&str>,
+    ) -> VcxCoreResult<LedgerCredentialDefinition> {
         let request = self._build_get_cred_def_request(submitter_did, cred_def_id).await?;
 
         let response = self._submit_request(request).await?;
@@ -329,11 +392,7 @@ impl BaseLedger for IndyVdrLedger {
             "value": data
         });
 
-        let cred_def_json = serde_json::to_string(&cred_def_value)?;
-
-        // todo - store in cache if submitter_did provided
-
-        Ok(cred_def_json)
+        Ok(serde_json::from_value(cred_def_value)?)
     }
 
     async fn get_attr(&self, target_did: &str, attr_name: &str) -> VcxCoreResult<String> {
@@ -349,7 +408,7 @@ impl BaseLedger for IndyVdrLedger {
         self._sign_and_submit_request(target_did, request).await
     }
 
-    async fn get_rev_reg_def_json(&self, rev_reg_id: &str) -> VcxCoreResult<String> {
+    async fn get_rev_reg_def(&self, rev_reg_id: &str) -> VcxCoreResult<LedgerRevocationRegistryDefinition> {
         let id = RevocationRegistryId::from_str(rev_reg_id)?;
         let request = self.request_builder()?.build_get_revoc_reg_def_request(None, &id)?;
         let res = self._submit_request(request).await?;
@@ -358,15 +417,15 @@ impl BaseLedger for IndyVdrLedger {
 
         data["ver"] = Value::String("1.0".to_string());
 
-        Ok(serde_json::to_string(&data)?)
+        Ok(serde_json::from_value(data)?)
     }
 
-    async fn get_rev_reg_delta_json(
+    async fn get_rev_reg_delta(
         &self,
         rev_reg_id: &str,
         from: Option<u64>,
         to: Option<u64>,
-    ) -> VcxCoreResult<(String, String, u64)> {
+    ) -> VcxCoreResult<(LedgerRevocationRegistryDelta, u64)> {
         let revoc_reg_def_id = RevocationRegistryId::from_str(rev_reg_id)?;
 
         let from = from.map(|x| x as i64);
@@ -381,24 +440,37 @@ impl BaseLedger for IndyVdrLedger {
         let res_data = _get_response_json_data_field(&res)?;
         let response_value = (&res_data).try_get("value")?;
 
-        let empty_json_list = json!([]);
-
-        let mut delta_value = json!({
-            "accum": response_value.try_get("accum_to")?.try_get("value")?.try_get("accum")?,
-            "issued": if let Some(v) = response_value.get("issued") { v } else { &empty_json_list },
-            "revoked": if let Some(v) = response_value.get("revoked") { v } else { &empty_json_list }
-        });
-
-        if let Some(accum_from) = response_value
+        let accum = response_value
+            .try_get("accum_to")?
+            .try_get("value")?
+            .try_get("accum")?
+            .try_as_str()?
+            .to_string();
+        let prev_accum = match response_value
             .get("accum_from")
             .and_then(|val| (!val.is_null()).then_some(val))
         {
-            let prev_accum = accum_from.try_get("value")?.try_get("accum")?;
-            // to check - should this be 'prevAccum'?
-            delta_value["prev_accum"] = prev_accum.to_owned();
-        }
+            Some(accum_from) => Some(accum_from.try_get("value")?.try_get("accum")?.try_as_str()?.to_string()),
+            None => None,
+        };
+        let issued: Vec<u32> = match response_value.get("issued").filter(|issued| !issued.is_null()) {
+            Some(issued) => serde_json::from_value(issued.to_owned())?,
+            None => vec![],
+        };
+        let revoked: Vec<u32> = match response_value.get("revoked").filter(|revoked| !revoked.is_null()) {
+            Some(revoked) => serde_json::from_value(revoked.to_owned())?,
+            None => vec![],
+        };
 
-        let reg_delta = json!({"ver": "1.0", "value": delta_value});
+        let reg_delta = LedgerRevocationRegistryDelta {
+            ver: "1.0".to_string(),
+            value: RevocationRegistryDeltaValue {
+                prev_accum,
+                accum,
+                issued,
+                revoked,
+            },
+        };
 
         let delta_timestamp =
             response_value
@@ -424,26 +496,64 @@ impl BaseLedger for IndyVdrLedger {
             ));
         }
 
-        Ok((
-            rev_reg_id.to_string(),
-            serde_json::to_string(&reg_delta)?,
-            delta_timestamp,
-        ))
+        Ok((reg_delta, delta_timestamp))
     }
 
     async fn get_rev_reg(&self, rev_reg_id: &str, timestamp: u64) -> VcxCoreResult<(String, String, u64)> {
-        let _ = (rev_reg_id, timestamp);
-        Err(unimplemented_method_err("indy_vdr get_rev_reg"))
+        let revoc_reg_def_id = RevocationRegistryId::from_str(rev_reg_id)?;
+
+        let request = self
+            .request_builder()?
+            .build_get_revoc_reg_request(None, &revoc_reg_def_id, timestamp as i64)?;
+        let res = self._submit_request(request).await?;
+
+        let res_data = _get_response_json_data_field(&res)?;
+        let rev_reg = json!({
+            "ver": "1.0",
+            "value": {
+                "accum": (&res_data).try_get("value")?.try_get("accum")?
+            }
+        });
+
+        let res: Value = serde_json::from_str(&res)?;
+        let rev_reg_timestamp =
+            (&res)
+                .try_get("result")?
+                .try_get("txnTime")?
+                .as_u64()
+                .ok_or(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::InvalidJson,
+                    "Error parsing txnTime value as u64",
+                ))?;
+
+        Ok((
+            rev_reg_id.to_string(),
+            serde_json::to_string(&rev_reg)?,
+            rev_reg_timestamp,
+        ))
     }
 
     async fn get_ledger_txn(&self, seq_no: i32, submitter_did: Option<&str>) -> VcxCoreResult<String> {
-        let _ = (seq_no, submitter_did);
-        Err(unimplemented_method_err("indy_vdr get_ledger_txn"))
+        let identifier = if let Some(did) = submitter_did {
+            Some(DidValue::from_str(did)?)
+        } else {
+            None
+        };
+        let request =
+            self.request_builder()?
+                .build_get_txn_request(identifier.as_ref(), LedgerType::DOMAIN as i32, seq_no)?;
+
+        match submitter_did {
+            Some(submitter_did) => self._sign_and_submit_request(submitter_did, request).await,
+            None => self._submit_request(request).await,
+        }
     }
 
     async fn build_schema_request(&self, submitter_did: &str, schema_json: &str) -> VcxCoreResult<String> {
-        let _ = (submitter_did, schema_json);
-        Err(unimplemented_method_err("indy_vdr build_schema_request"))
+        let request = self._build_schema_request(submitter_did, schema_json)?;
+        let request = _append_txn_author_agreement_to_request(request).await?;
+
+        Ok(request.req_json.to_string())
     }
 
     async fn publish_schema(
@@ -452,18 +562,40 @@ impl BaseLedger for IndyVdrLedger {
         submitter_did: &str,
         endorser_did: Option<String>,
     ) -> VcxCoreResult<()> {
-        let _ = (schema_json, submitter_did, endorser_did);
-        Err(unimplemented_method_err("indy_vdr publish_schema"))
+        let request = self._build_schema_request(submitter_did, schema_json)?;
+        let mut request = _append_txn_author_agreement_to_request(request).await?;
+
+        match endorser_did {
+            Some(endorser_did) => {
+                // only the author signs, the endorser's key is not held in the author's wallet
+                request.set_endorser(&DidValue::from_str(&endorser_did)?)?;
+                let request = self._multi_sign_request(submitter_did, request).await?;
+                self._submit_request(request).await.map(|_| ())
+            }
+            None => self._sign_and_submit_request(submitter_did, request).await.map(|_| ()),
+        }
     }
 
     async fn publish_cred_def(&self, cred_def_json: &str, submitter_did: &str) -> VcxCoreResult<()> {
-        let _ = (cred_def_json, submitter_did);
-        Err(unimplemented_method_err("indy_vdr publish_cred_def"))
+        let identifier = DidValue::from_str(submitter_did)?;
+        let cred_def: CredentialDefinition = serde_json::from_str(cred_def_json)?;
+
+        let request = self.request_builder()?.build_cred_def_request(&identifier, cred_def)?;
+        let request = _append_txn_author_agreement_to_request(request).await?;
+
+        self._sign_and_submit_request(submitter_did, request).await.map(|_| ())
     }
 
     async fn publish_rev_reg_def(&self, rev_reg_def: &str, submitter_did: &str) -> VcxCoreResult<()> {
-        let _ = (rev_reg_def, submitter_did);
-        Err(unimplemented_method_err("indy_vdr publish_rev_reg_def"))
+        let identifier = DidValue::from_str(submitter_did)?;
+        let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(rev_reg_def)?;
+
+        let request = self
+            .request_builder()?
+            .build_revoc_reg_def_request(&identifier, rev_reg_def)?;
+        let request = _append_txn_author_agreement_to_request(request).await?;
+
+        self._sign_and_submit_request(submitter_did, request).await.map(|_| ())
     }
 
     async fn publish_rev_reg_delta(
@@ -472,16 +604,20 @@ impl BaseLedger for IndyVdrLedger {
         rev_reg_entry_json: &str,
         submitter_did: &str,
     ) -> VcxCoreResult<()> {
-        let _ = (rev_reg_entry_json, rev_reg_id, submitter_did);
-        Err(unimplemented_method_err("indy_vdr publish_rev_reg_delta"))
-    }
-}
+        let identifier = DidValue::from_str(submitter_did)?;
+        let revoc_reg_def_id = RevocationRegistryId::from_str(rev_reg_id)?;
+        let rev_reg_entry: RevocationRegistryDelta = serde_json::from_str(rev_reg_entry_json)?;
+
+        let request = self.request_builder()?.build_revoc_reg_entry_request(
+            &identifier,
+            &revoc_reg_def_id,
+            &RegistryType::CL_ACCUM,
+            rev_reg_entry,
+        )?;
+        let request = _append_txn_author_agreement_to_request(request).await?;
 
-fn unimplemented_method_err(method_name: &str) -> AriesVcxCoreError {
-    AriesVcxCoreError::from_msg(
-        AriesVcxCoreErrorKind::UnimplementedFeature,
-        format!("method called '{}' is not yet implemented in AriesVCX", method_name),
-    )
+        self._sign_and_submit_request(submitter_did, request).await.map(|_| ())
+    }
 }
 
 fn current_epoch_time() -> i64 {
@@ -505,6 +641,25 @@ async fn _append_txn_author_agreement_to_request(request: PreparedRequest) -> Vc
     }
 }
 
+// Combines the GET_TXN_AUTHR_AGRMT and GET_TXN_AUTHR_AGRMT_AML replies into the same shape returned by the
+// vdrtools ledger: the TAA data object, extended with the active acceptance mechanisms under "aml"
+fn _merge_txn_author_agreement_responses(taa_response: &str, aml_response: &str) -> VcxCoreResult<String> {
+    let taa_response: Value = serde_json::from_str(taa_response)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidLedgerResponse, format!("{err:?}")))?;
+    let aml_response: Value = serde_json::from_str(aml_response)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidLedgerResponse, format!("{err:?}")))?;
+
+    let mut author_agreement_data = taa_response["result"]["data"]
+        .as_object()
+        .map_or(json!({}), |data| json!(data));
+
+    if let Some(aml) = aml_response["result"]["data"]["aml"].as_object() {
+        author_agreement_data["aml"] = json!(aml);
+    }
+
+    Ok(author_agreement_data.to_string())
+}
+
 fn _get_response_json_data_field(response_json: &str) -> VcxCoreResult<Value> {
     let res: Value = serde_json::from_str(response_json)?;
     let result = (&res).try_get("result")?;
@@ -512,38 +667,292 @@ fn _get_response_json_data_field(response_json: &str) -> VcxCoreResult<Value> {
 }
 
 #[cfg(test)]
+#[cfg(feature = "vdrtools")]
 mod unit_tests {
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
-    use crate::errors::error::{AriesVcxCoreErrorKind, VcxCoreResult};
-    use crate::{
-        common::test_utils::mock_profile,
-        plugins::ledger::{base_ledger::BaseLedger, indy_vdr_ledger::IndyVdrLedgerPool},
-    };
+    use serde_json::Value;
 
-    use super::IndyVdrLedger;
+    use crate::indy::utils::test_setup::with_wallet;
+    use crate::ledger::base_ledger::BaseLedger;
+    use crate::ledger::request_submitter::in_memory_ledger::InMemoryLedger;
+    use crate::wallet::base_wallet::BaseWallet;
+    use crate::wallet::indy_wallet::IndySdkWallet;
 
-    #[tokio::test]
-    #[ignore]
-    async fn test_pool_unimplemented_methods() {
-        // test used to assert which methods are unimplemented currently, can be removed after all methods implemented
+    use super::*;
+
+    fn offline_ledger(wallet: Arc<dyn BaseWallet>) -> IndyVdrLedger<InMemoryLedger> {
+        IndyVdrLedger::new(wallet, Arc::new(InMemoryLedger::new()))
+    }
+
+    // Records the submitted requests and replies with the response registered for their transaction type
+    #[derive(Default)]
+    struct MockRequestSubmitter {
+        responses: HashMap<String, String>,
+        requests: Mutex<Vec<Value>>,
+    }
 
-        fn assert_unimplemented<T: std::fmt::Debug>(result: VcxCoreResult<T>) {
-            assert_eq!(result.unwrap_err().kind(), AriesVcxCoreErrorKind::UnimplementedFeature)
+    impl MockRequestSubmitter {
+        fn with_response(mut self, txn_type: &str, data: Value) -> Self {
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": txn_type, "data": data, "seqNo": 7, "txnTime": 1600000000}
+            });
+            self.responses.insert(txn_type.to_string(), response.to_string());
+            self
+        }
+
+        fn requests(&self) -> Vec<Value> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl RequestSubmitter for MockRequestSubmitter {
+        async fn submit(&self, request: PreparedRequest) -> VcxCoreResult<String> {
+            let txn_type = request.req_json["operation"]["type"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            self.requests.lock().unwrap().push(request.req_json);
+            self.responses.get(&txn_type).cloned().ok_or_else(|| {
+                AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::InvalidLedgerResponse,
+                    format!("No response registered for transaction type {txn_type}"),
+                )
+            })
         }
+    }
+
+    async fn build_nym_request<T: RequestSubmitter>(
+        ledger: &IndyVdrLedger<T>,
+        submitter_did: &str,
+        target_did: &str,
+    ) -> String {
+        let request = ledger
+            .request_builder()
+            .unwrap()
+            .build_nym_request(
+                &DidValue::from_str(submitter_did).unwrap(),
+                &DidValue::from_str(target_did).unwrap(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        request.req_json.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_set_endorser_signs_request_for_endorsement() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (author_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (endorser_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (target_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let ledger = offline_ledger(wallet);
+
+            let request = build_nym_request(&ledger, &author_did, &target_did).await;
+            let request = ledger.set_endorser(&author_did, &request, &endorser_did).await.unwrap();
+
+            let request: Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["endorser"], endorser_did);
+            assert!(request["signatures"][&author_did].is_string());
+            verify_transaction_can_be_endorsed(&request.to_string(), &endorser_did).unwrap();
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_endorse_transaction() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (author_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (endorser_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (other_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let ledger = offline_ledger(wallet);
+
+            let request = build_nym_request(&ledger, &author_did, &other_did).await;
+            let err = ledger.endorse_transaction(&endorser_did, &request).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::InvalidJson);
+
+            let request = ledger.set_endorser(&author_did, &request, &endorser_did).await.unwrap();
+            let err = ledger.endorse_transaction(&other_did, &request).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::InvalidJson);
+
+            ledger.endorse_transaction(&endorser_did, &request).await.unwrap();
+            assert_eq!(ledger.get_nym(&other_did).await.unwrap().did, other_did);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_txn_author_agreement_requests_taa_and_aml() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(
+                MockRequestSubmitter::default()
+                    .with_response(
+                        "6",
+                        json!({"text": "Default agreement", "version": "1.0.0", "digest": "abcd"}),
+                    )
+                    .with_response("7", json!({"aml": {"at_submission": "Accepted at submission"}})),
+            );
+            let ledger = IndyVdrLedger::new(wallet, Arc::clone(&submitter));
+
+            let taa = ledger.get_txn_author_agreement().await.unwrap().unwrap();
+            assert_eq!(taa.text, "Default agreement");
+            assert_eq!(taa.version, "1.0.0");
+            assert_eq!(taa.digest.as_deref(), Some("abcd"));
+            assert_eq!(taa.aml["at_submission"], "Accepted at submission");
+
+            let requests = submitter.requests();
+            assert_eq!(requests.len(), 2);
+            assert_eq!(requests[0]["operation"]["type"], "6");
+            assert_eq!(requests[1]["operation"]["type"], "7");
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_txn_author_agreement_not_set() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let submitter = Arc::new(
+                MockRequestSubmitter::default()
+                    .with_response("6", Value::Null)
+                    .with_response("7", Value::Null),
+            );
+            let ledger = IndyVdrLedger::new(wallet, submitter);
+
+            assert_eq!(ledger.get_txn_author_agreement().await.unwrap(), None);
+        })
+        .await;
+    }
 
-        let profile = mock_profile();
-        let pool = Arc::new(IndyVdrLedgerPool { runner: None });
-        let ledger: Box<dyn BaseLedger> = Box::new(IndyVdrLedger::new(profile.inject_wallet(), pool));
-
-        assert_unimplemented(ledger.endorse_transaction("", "").await);
-        assert_unimplemented(ledger.set_endorser("", "", "").await);
-        assert_unimplemented(ledger.get_txn_author_agreement().await);
-        assert_unimplemented(ledger.get_rev_reg("", 0).await);
-        assert_unimplemented(ledger.get_ledger_txn(0, None).await);
-        assert_unimplemented(ledger.build_schema_request("", "").await);
-        assert_unimplemented(ledger.publish_schema("", "", None).await);
-        assert_unimplemented(ledger.publish_cred_def("", "").await);
-        assert_unimplemented(ledger.publish_rev_reg_def("", "").await);
+    #[tokio::test]
+    async fn test_get_nym_builds_request_and_parses_reply() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (did, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let data = json!({"dest": did, "verkey": verkey, "role": "101", "seqNo": 7, "txnTime": 1600000000});
+            let submitter = Arc::new(MockRequestSubmitter::default().with_response("105", json!(data.to_string())));
+            let ledger = IndyVdrLedger::new(wallet, Arc::clone(&submitter));
+
+            let nym = ledger.get_nym(&did).await.unwrap();
+            assert_eq!(nym.did, did);
+            assert_eq!(nym.verkey, Some(verkey));
+            assert_eq!(nym.role.as_deref(), Some("101"));
+            assert_eq!(nym.seq_no, Some(7));
+
+            let requests = submitter.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0]["operation"]["type"], "105");
+            assert_eq!(requests[0]["operation"]["dest"], did);
+            assert!(requests[0].get("signature").is_none());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_nym_not_found() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let submitter = Arc::new(MockRequestSubmitter::default().with_response("105", Value::Null));
+            let ledger = IndyVdrLedger::new(wallet, submitter);
+
+            let err = ledger.get_nym(&did).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::LedgerItemNotFound);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_endorse_transaction_submits_both_signatures() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (author_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (endorser_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (target_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let submitter = Arc::new(MockRequestSubmitter::default().with_response("1", Value::Null));
+            let ledger = IndyVdrLedger::new(wallet, Arc::clone(&submitter));
+
+            let request = build_nym_request(&ledger, &author_did, &target_did).await;
+            let request = ledger.set_endorser(&author_did, &request, &endorser_did).await.unwrap();
+            assert!(submitter.requests().is_empty());
+
+            ledger.endorse_transaction(&endorser_did, &request).await.unwrap();
+
+            let requests = submitter.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0]["operation"]["type"], "1");
+            assert_eq!(requests[0]["operation"]["dest"], target_did);
+            assert_eq!(requests[0]["endorser"], endorser_did);
+            assert!(requests[0]["signatures"][&author_did].is_string());
+            assert!(requests[0]["signatures"][&endorser_did].is_string());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_publish_schema_with_endorser_signs_as_author_only() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (author_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            // the endorser's key is not held in the author's wallet
+            let endorser_did = "V4SGRU86Z58d6TV7PBUe6f".to_string();
+            let submitter = Arc::new(MockRequestSubmitter::default().with_response("101", Value::Null));
+            let ledger = IndyVdrLedger::new(wallet, Arc::clone(&submitter));
+
+            let schema_json = json!({
+                "ver": "1.0",
+                "id": format!("{author_did}:2:test-schema:1.0"),
+                "name": "test-schema",
+                "version": "1.0",
+                "attrNames": ["name", "age"],
+                "seqNo": null
+            })
+            .to_string();
+            ledger
+                .publish_schema(&schema_json, &author_did, Some(endorser_did.clone()))
+                .await
+                .unwrap();
+
+            let requests = submitter.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0]["operation"]["type"], "101");
+            assert_eq!(requests[0]["endorser"], endorser_did);
+            assert!(requests[0]["signatures"][&author_did].is_string());
+            assert!(requests[0]["signatures"].get(&endorser_did).is_none());
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_merge_txn_author_agreement_responses() {
+        let taa_response = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "6",
+                "data": {"text": "Default agreement", "version": "1.0.0", "digest": "abcd", "ratification_ts": 1}
+            }
+        })
+        .to_string();
+        let aml_response = json!({
+            "op": "REPLY",
+            "result": {
+                "type": "7",
+                "data": {"aml": {"at_submission": "The agreement was reviewed by the user"}, "version": "1.0"}
+            }
+        })
+        .to_string();
+
+        let taa: Value =
+            serde_json::from_str(&_merge_txn_author_agreement_responses(&taa_response, &aml_response).unwrap())
+                .unwrap();
+        assert_eq!(taa["text"], "Default agreement");
+        assert_eq!(taa["version"], "1.0.0");
+        assert_eq!(taa["aml"]["at_submission"], "The agreement was reviewed by the user");
     }
 }
@@ -1,5 +1,9 @@
 pub mod base_ledger;
+pub mod cached_ledger;
 #[cfg(feature = "vdrtools")]
 pub mod indy_ledger;
 #[cfg(feature = "modular_libs")]
 pub mod indy_vdr_ledger;
+#[cfg(feature = "modular_libs")]
+pub mod request_submitter;
+pub mod types;
@@ -0,0 +1,714 @@
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use indy_vdr::pool::PreparedRequest;
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
+use super::RequestSubmitter;
+
+// indy-node transaction types
+const NYM: &str = "1";
+const GET_TXN: &str = "3";
+const GET_TXN_AUTHR_AGRMT: &str = "6";
+const GET_TXN_AUTHR_AGRMT_AML: &str = "7";
+const ATTRIB: &str = "100";
+const SCHEMA: &str = "101";
+const CLAIM_DEF: &str = "102";
+const GET_ATTR: &str = "104";
+const GET_NYM: &str = "105";
+const GET_SCHEMA: &str = "107";
+const GET_CLAIM_DEF: &str = "108";
+const REVOC_REG_DEF: &str = "113";
+const REVOC_REG_ENTRY: &str = "114";
+const GET_REVOC_REG_DEF: &str = "115";
+const GET_REVOC_REG: &str = "116";
+const GET_REVOC_REG_DELTA: &str = "117";
+
+#[derive(Clone, Debug)]
+struct LedgerTxn {
+    seq_no: u64,
+    txn_time: u64,
+    txn_type: String,
+    submitter_did: String,
+    // request operation without the "type" field, as stored by indy-node in `txn.data`
+    data: Value,
+}
+
+/// Ledger fake keeping the domain ledger in memory, so that code using
+/// [`IndyVdrLedger`](crate::ledger::indy_vdr_ledger::IndyVdrLedger) can be exercised without a pool.
+///
+/// Supports writing and reading NYM, ATTRIB, SCHEMA, CLAIM_DEF, REVOC_REG_DEF and REVOC_REG_ENTRY
+/// transactions, plus GET_TXN by sequence number. Replies mimic the shape of indy-node replies. The ledger
+/// requires write requests to carry a signature, but neither verifies signatures nor enforces roles.
+#[derive(Debug, Default)]
+pub struct InMemoryLedger {
+    txns: Mutex<Vec<LedgerTxn>>,
+}
+
+impl InMemoryLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write(&self, request: &Value, txn_type: &str, operation: &Value) -> VcxCoreResult<Value> {
+        if request.get("signature").is_none() && request.get("signatures").is_none() {
+            return Err(reject("Write request is missing a signature"));
+        }
+        let submitter_did = request["identifier"]
+            .as_str()
+            .ok_or_else(|| reject("Write request is missing the identifier"))?
+            .to_string();
+
+        let mut data = operation.clone();
+        if let Some(data) = data.as_object_mut() {
+            data.remove("type");
+        }
+
+        let mut txns = self.txns.lock()?;
+        validate_write(&txns, txn_type, &submitter_did, &data)?;
+
+        let txn = LedgerTxn {
+            seq_no: txns.len() as u64 + 1,
+            txn_time: OffsetDateTime::now_utc().unix_timestamp() as u64,
+            txn_type: txn_type.to_string(),
+            submitter_did,
+            data,
+        };
+        let result = txn_json(&txn);
+        txns.push(txn);
+
+        Ok(result)
+    }
+
+    fn read(&self, txn_type: &str, operation: &Value) -> VcxCoreResult<Value> {
+        let txns = self.txns.lock()?;
+        let mut result = match txn_type {
+            GET_NYM => get_nym(&txns, operation),
+            GET_ATTR => get_attr(&txns, operation),
+            GET_SCHEMA => get_schema(&txns, operation),
+            GET_CLAIM_DEF => get_cred_def(&txns, operation),
+            GET_REVOC_REG_DEF => get_rev_reg_def(&txns, operation),
+            GET_REVOC_REG => get_rev_reg(&txns, operation),
+            GET_REVOC_REG_DELTA => get_rev_reg_delta(&txns, operation),
+            GET_TXN => get_txn(&txns, operation),
+            // no transaction author agreement is required by this ledger
+            GET_TXN_AUTHR_AGRMT | GET_TXN_AUTHR_AGRMT_AML => json!({ "data": null }),
+            _ => {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::ActionNotSupported,
+                    format!("Transaction type {} is not supported by the in-memory ledger", txn_type),
+                ))
+            }
+        };
+        result["type"] = json!(txn_type);
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl RequestSubmitter for InMemoryLedger {
+    async fn submit(&self, request: PreparedRequest) -> VcxCoreResult<String> {
+        let request = request.req_json;
+        let operation = &request["operation"];
+        let txn_type = operation["type"].as_str().ok_or(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidJson,
+            "Request operation is missing the transaction type",
+        ))?;
+
+        let mut result = match txn_type {
+            NYM | ATTRIB | SCHEMA | CLAIM_DEF | REVOC_REG_DEF | REVOC_REG_ENTRY => {
+                self.write(&request, txn_type, operation)?
+            }
+            _ => self.read(txn_type, operation)?,
+        };
+        result["reqId"] = request["reqId"].clone();
+        result["identifier"] = request["identifier"].clone();
+
+        Ok(json!({ "op": "REPLY", "result": result }).to_string())
+    }
+}
+
+fn reject(reason: &str) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidLedgerResponse, reason)
+}
+
+fn validate_write(txns: &[LedgerTxn], txn_type: &str, submitter_did: &str, data: &Value) -> VcxCoreResult<()> {
+    let duplicate = match txn_type {
+        SCHEMA => find_schema(txns, submitter_did, &data["data"]["name"], &data["data"]["version"]).is_some(),
+        CLAIM_DEF => find_cred_def(txns, submitter_did, &data["ref"], &data["signature_type"], &data["tag"]).is_some(),
+        REVOC_REG_DEF => find_rev_reg_def(txns, &data["id"]).is_some(),
+        REVOC_REG_ENTRY => {
+            if find_rev_reg_def(txns, &data["revocRegDefId"]).is_none() {
+                return Err(reject(
+                    "Revocation registry definition of the entry is not on the ledger",
+                ));
+            }
+            false
+        }
+        _ => false,
+    };
+    if duplicate {
+        return Err(reject("Ledger already contains the transaction"));
+    }
+    Ok(())
+}
+
+fn txn_json(txn: &LedgerTxn) -> Value {
+    json!({
+        "txn": {
+            "type": txn.txn_type,
+            "data": txn.data,
+            "metadata": { "from": txn.submitter_did }
+        },
+        "txnMetadata": {
+            "seqNo": txn.seq_no,
+            "txnTime": txn.txn_time
+        },
+        "reqSignature": {},
+        "ver": "1"
+    })
+}
+
+fn txns_of_type<'a>(txns: &'a [LedgerTxn], txn_type: &'a str) -> impl DoubleEndedIterator<Item = &'a LedgerTxn> {
+    txns.iter().filter(move |txn| txn.txn_type == txn_type)
+}
+
+fn find_schema<'a>(txns: &'a [LedgerTxn], dest: &str, name: &Value, version: &Value) -> Option<&'a LedgerTxn> {
+    txns_of_type(txns, SCHEMA).find(|txn| {
+        txn.submitter_did == dest && &txn.data["data"]["name"] == name && &txn.data["data"]["version"] == version
+    })
+}
+
+fn find_cred_def<'a>(
+    txns: &'a [LedgerTxn],
+    origin: &str,
+    schema_ref: &Value,
+    signature_type: &Value,
+    tag: &Value,
+) -> Option<&'a LedgerTxn> {
+    txns_of_type(txns, CLAIM_DEF).find(|txn| {
+        txn.submitter_did == origin
+            && &txn.data["ref"] == schema_ref
+            && &txn.data["signature_type"] == signature_type
+            && &txn.data["tag"] == tag
+    })
+}
+
+fn find_rev_reg_def<'a>(txns: &'a [LedgerTxn], id: &Value) -> Option<&'a LedgerTxn> {
+    txns_of_type(txns, REVOC_REG_DEF).find(|txn| &txn.data["id"] == id)
+}
+
+fn rev_reg_entries<'a>(txns: &'a [LedgerTxn], rev_reg_id: &'a Value) -> impl DoubleEndedIterator<Item = &'a LedgerTxn> {
+    txns_of_type(txns, REVOC_REG_ENTRY).filter(move |txn| &txn.data["revocRegDefId"] == rev_reg_id)
+}
+
+fn get_nym(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let dest = &operation["dest"];
+    // a past version of the NYM is selected by the transaction which wrote it, or by time
+    let seq_no = operation["seqNo"].as_u64();
+    let timestamp = operation["timestamp"].as_u64();
+    let nym_txns: Vec<&LedgerTxn> = txns_of_type(txns, NYM)
+        .filter(|txn| &txn.data["dest"] == dest)
+        .filter(|txn| seq_no.map_or(true, |seq_no| txn.seq_no <= seq_no))
+        .filter(|txn| timestamp.map_or(true, |timestamp| txn.txn_time <= timestamp))
+        .collect();
+    let last_txn = match nym_txns.last() {
+        Some(txn) if seq_no.map_or(true, |seq_no| txn.seq_no == seq_no) => txn,
+        _ => return json!({ "dest": dest, "data": null, "seqNo": null, "txnTime": null }),
+    };
+
+    // NYM updates only overwrite the fields they contain
+    let mut nym = Map::new();
+    for txn in nym_txns.iter() {
+        if let Some(fields) = txn.data.as_object() {
+            nym.extend(fields.clone());
+        }
+    }
+    nym.insert("identifier".to_string(), json!(nym_txns[0].submitter_did));
+    nym.insert("seqNo".to_string(), json!(last_txn.seq_no));
+    nym.insert("txnTime".to_string(), json!(last_txn.txn_time));
+
+    json!({
+        "dest": dest,
+        "data": Value::Object(nym).to_string(),
+        "seqNo": last_txn.seq_no,
+        "txnTime": last_txn.txn_time
+    })
+}
+
+fn get_attr(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let dest = &operation["dest"];
+    let attr_name = operation["raw"].as_str().unwrap_or_default();
+    let attr_txn = txns_of_type(txns, ATTRIB).rev().find(|txn| {
+        &txn.data["dest"] == dest
+            && txn.data["raw"]
+                .as_str()
+                .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+                .map_or(false, |raw| raw.get(attr_name).is_some())
+    });
+
+    match attr_txn {
+        Some(txn) => json!({
+            "dest": dest,
+            "raw": attr_name,
+            "data": txn.data["raw"],
+            "seqNo": txn.seq_no,
+            "txnTime": txn.txn_time
+        }),
+        None => json!({ "dest": dest, "raw": attr_name, "data": null, "seqNo": null, "txnTime": null }),
+    }
+}
+
+fn get_schema(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let dest = &operation["dest"];
+    let schema_txn = find_schema(
+        txns,
+        dest.as_str().unwrap_or_default(),
+        &operation["data"]["name"],
+        &operation["data"]["version"],
+    );
+
+    match schema_txn {
+        Some(txn) => json!({
+            "dest": dest,
+            "data": txn.data["data"],
+            "seqNo": txn.seq_no,
+            "txnTime": txn.txn_time
+        }),
+        None => json!({ "dest": dest, "data": null, "seqNo": null, "txnTime": null }),
+    }
+}
+
+fn get_cred_def(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let origin = &operation["origin"];
+    let cred_def_txn = find_cred_def(
+        txns,
+        origin.as_str().unwrap_or_default(),
+        &operation["ref"],
+        &operation["signature_type"],
+        &operation["tag"],
+    );
+
+    let mut result = json!({
+        "origin": origin,
+        "ref": operation["ref"],
+        "signature_type": operation["signature_type"],
+        "tag": operation["tag"],
+        "data": null,
+        "seqNo": null,
+        "txnTime": null
+    });
+    if let Some(txn) = cred_def_txn {
+        result["data"] = txn.data["data"].clone();
+        result["seqNo"] = json!(txn.seq_no);
+        result["txnTime"] = json!(txn.txn_time);
+    }
+    result
+}
+
+fn get_rev_reg_def(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let id = &operation["id"];
+
+    match find_rev_reg_def(txns, id) {
+        Some(txn) => json!({
+            "id": id,
+            "data": txn.data,
+            "seqNo": txn.seq_no,
+            "txnTime": txn.txn_time
+        }),
+        None => json!({ "id": id, "data": null, "seqNo": null, "txnTime": null }),
+    }
+}
+
+fn accum_json(txn: &LedgerTxn) -> Value {
+    json!({
+        "revocDefType": txn.data["revocDefType"],
+        "revocRegDefId": txn.data["revocRegDefId"],
+        "value": { "accum": txn.data["value"]["accum"] },
+        "seqNo": txn.seq_no,
+        "txnTime": txn.txn_time
+    })
+}
+
+// latest revocation registry entry written at or before the given time
+fn rev_reg_entry_at<'a>(txns: &'a [LedgerTxn], rev_reg_id: &'a Value, timestamp: u64) -> Option<&'a LedgerTxn> {
+    rev_reg_entries(txns, rev_reg_id)
+        .rev()
+        .find(|txn| txn.txn_time <= timestamp)
+}
+
+fn rev_reg_entry_indices<'a>(txn: &'a LedgerTxn, field: &str) -> impl Iterator<Item = u64> + 'a {
+    txn.data["value"][field]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_u64)
+}
+
+fn get_rev_reg(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let rev_reg_id = &operation["revocRegDefId"];
+    let timestamp = operation["timestamp"].as_u64().unwrap_or_default();
+
+    match rev_reg_entry_at(txns, rev_reg_id, timestamp) {
+        Some(txn) => json!({
+            "revocRegDefId": rev_reg_id,
+            "data": accum_json(txn),
+            "seqNo": txn.seq_no,
+            "txnTime": txn.txn_time
+        }),
+        None => json!({ "revocRegDefId": rev_reg_id, "data": null, "seqNo": null, "txnTime": null }),
+    }
+}
+
+fn get_rev_reg_delta(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let rev_reg_id = &operation["revocRegDefId"];
+    let from = operation["from"].as_u64();
+    let to = operation["to"].as_u64().unwrap_or_default();
+
+    let accum_to = match rev_reg_entry_at(txns, rev_reg_id, to) {
+        Some(txn) => txn,
+        None => return json!({ "revocRegDefId": rev_reg_id, "data": null, "seqNo": null, "txnTime": null }),
+    };
+    let accum_from = from.and_then(|from| rev_reg_entry_at(txns, rev_reg_id, from));
+
+    let mut issued = BTreeSet::new();
+    let mut revoked = BTreeSet::new();
+    let entries = rev_reg_entries(txns, rev_reg_id)
+        .filter(|txn| from.map_or(true, |from| txn.txn_time > from) && txn.txn_time <= to);
+    for txn in entries {
+        for idx in rev_reg_entry_indices(txn, "issued") {
+            revoked.remove(&idx);
+            issued.insert(idx);
+        }
+        for idx in rev_reg_entry_indices(txn, "revoked") {
+            issued.remove(&idx);
+            revoked.insert(idx);
+        }
+    }
+
+    json!({
+        "revocRegDefId": rev_reg_id,
+        "data": {
+            "revocDefType": accum_to.data["revocDefType"],
+            "revocRegDefId": rev_reg_id,
+            "value": {
+                "accum_to": accum_json(accum_to),
+                "accum_from": accum_from.map(accum_json),
+                "issued": issued,
+                "revoked": revoked
+            }
+        },
+        "seqNo": accum_to.seq_no,
+        "txnTime": accum_to.txn_time
+    })
+}
+
+fn get_txn(txns: &[LedgerTxn], operation: &Value) -> Value {
+    let seq_no = operation["data"].as_u64().unwrap_or_default();
+    let txn = txns.iter().find(|txn| txn.seq_no == seq_no);
+
+    json!({
+        "seqNo": seq_no,
+        "data": txn.map(txn_json)
+    })
+}
+
+#[cfg(test)]
+#[cfg(feature = "vdrtools")]
+mod unit_tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use serde_json::Value;
+
+    use crate::anoncreds::base_anoncreds::BaseAnonCreds;
+    use crate::anoncreds::credx_anoncreds::IndyCredxAnonCreds;
+    use crate::anoncreds::types::{
+        CredentialDefinitionConfig, CredentialValues, ProofRequest, RequestedCredentials, RevocationRegistry,
+    };
+    use crate::errors::error::AriesVcxCoreErrorKind;
+    use crate::indy::utils::test_setup::{self, with_wallet};
+    use crate::ledger::base_ledger::BaseLedger;
+    use crate::ledger::indy_vdr_ledger::IndyVdrLedger;
+    use crate::ledger::types::NymData;
+    use crate::wallet::base_wallet::BaseWallet;
+    use crate::wallet::indy_wallet::IndySdkWallet;
+
+    use super::InMemoryLedger;
+
+    const LINK_SECRET_ID: &str = "test_link_secret";
+
+    fn tails_dir() -> String {
+        let mut path = std::env::temp_dir();
+        path.push(format!("in_memory_ledger_tails_{}", test_setup::generate_random_name()));
+        std::fs::create_dir_all(&path).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_nym_and_attrib_round_trip() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (trustee_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (did, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let ledger = IndyVdrLedger::new(Arc::clone(&wallet), Arc::new(InMemoryLedger::new()));
+
+            let err = ledger.get_nym(&did).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::LedgerItemNotFound);
+
+            ledger
+                .publish_nym(
+                    &trustee_did,
+                    &did,
+                    Some(&verkey),
+                    Some(&NymData::new().alias("alice")),
+                    None,
+                )
+                .await
+                .unwrap();
+            ledger
+                .add_attr(
+                    &did,
+                    &json!({"endpoint": {"endpoint": "http://localhost:8080"}}).to_string(),
+                )
+                .await
+                .unwrap();
+
+            let nym = ledger.get_nym(&did).await.unwrap();
+            assert_eq!(nym.did, did);
+            assert_eq!(nym.verkey, Some(verkey));
+            assert_eq!(nym.alias, Some("alice".to_string()));
+
+            let attr = ledger.get_attr(&did, "endpoint").await.unwrap();
+            let attr: Value = serde_json::from_str(&attr).unwrap();
+            let attr_data: Value = serde_json::from_str(attr["result"]["data"].as_str().unwrap()).unwrap();
+            assert_eq!(attr_data["endpoint"]["endpoint"], "http://localhost:8080");
+
+            let txn = ledger.get_ledger_txn(1, None).await.unwrap();
+            let txn: Value = serde_json::from_str(&txn).unwrap();
+            assert_eq!(txn["result"]["data"]["txn"]["data"]["dest"], did);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_nym_version() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (trustee_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (did, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let ledger = IndyVdrLedger::new(Arc::clone(&wallet), Arc::new(InMemoryLedger::new()));
+
+            ledger
+                .publish_nym(
+                    &trustee_did,
+                    &did,
+                    Some(&verkey),
+                    Some(&NymData::new().alias("alice")),
+                    None,
+                )
+                .await
+                .unwrap();
+            let first = ledger.get_nym(&did).await.unwrap();
+            ledger
+                .publish_nym(&trustee_did, &did, None, Some(&NymData::new().alias("bob")), None)
+                .await
+                .unwrap();
+            let second = ledger.get_nym(&did).await.unwrap();
+
+            let nym = ledger.get_nym_version(&did, first.seq_no, None).await.unwrap();
+            assert_eq!(nym.alias, Some("alice".to_string()));
+            assert_eq!(nym.verkey, Some(verkey));
+            let nym = ledger.get_nym_version(&did, None, second.txn_time).await.unwrap();
+            assert_eq!(nym.alias, Some("bob".to_string()));
+            let created = ledger.get_nym_created(&did, second.txn_time.unwrap()).await.unwrap();
+            assert_eq!(Some(created), first.txn_time);
+
+            let err = ledger.get_nym_version(&did, None, Some(0)).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::LedgerItemNotFound);
+            let err = ledger.get_nym_version(&did, Some(1000), None).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::LedgerItemNotFound);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_write_is_rejected() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(Arc::clone(&wallet));
+            let ledger = IndyVdrLedger::new(Arc::clone(&wallet), Arc::new(InMemoryLedger::new()));
+
+            let (_, schema_json) = anoncreds
+                .issuer_create_schema(&did, "test_schema", "1.0", r#"["name"]"#)
+                .await
+                .unwrap();
+            let request = ledger.build_schema_request(&did, &schema_json).await.unwrap();
+
+            let err = ledger.submit_request(&request).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::InvalidLedgerResponse);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_issue_revoke_and_verify_credential_against_in_memory_ledger() {
+        with_wallet(|wallet_handle| async move {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (issuer_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (prover_did, _) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            let anoncreds = IndyCredxAnonCreds::new(Arc::clone(&wallet));
+            let ledger = IndyVdrLedger::new(Arc::clone(&wallet), Arc::new(InMemoryLedger::new()));
+            let tails_dir = tails_dir();
+
+            // issuer publishes the schema, cred def and revocation registry
+            let (schema_id, schema_json) = anoncreds
+                .issuer_create_schema(&issuer_did, "test_schema", "1.0", r#"["name","age"]"#)
+                .await
+                .unwrap();
+            ledger.publish_schema(&schema_json, &issuer_did, None).await.unwrap();
+            let err = ledger.publish_schema(&schema_json, &issuer_did, None).await.unwrap_err();
+            assert_eq!(err.kind(), AriesVcxCoreErrorKind::InvalidLedgerResponse);
+            let schema = ledger.get_schema(&schema_id, None).await.unwrap();
+            assert_eq!(schema.attr_names, vec!["name", "age"]);
+
+            let (cred_def_id, cred_def) = anoncreds
+                .issuer_create_and_store_credential_def(
+                    &issuer_did,
+                    &schema,
+                    "tag1",
+                    None,
+                    &CredentialDefinitionConfig {
+                        support_revocation: true,
+                    },
+                )
+                .await
+                .unwrap();
+            ledger
+                .publish_cred_def(&serde_json::to_string(&cred_def).unwrap(), &issuer_did)
+                .await
+                .unwrap();
+            let ledger_cred_def = ledger.get_cred_def(&cred_def_id, None).await.unwrap();
+            assert_eq!(ledger_cred_def, cred_def);
+
+            let (rev_reg_id, rev_reg_def, rev_reg_entry) = anoncreds
+                .issuer_create_and_store_revoc_reg(&issuer_did, &cred_def_id, &tails_dir, 10, "tag1")
+                .await
+                .unwrap();
+            ledger
+                .publish_rev_reg_def(&serde_json::to_string(&rev_reg_def).unwrap(), &issuer_did)
+                .await
+                .unwrap();
+            ledger
+                .publish_rev_reg_delta(&rev_reg_id, &serde_json::to_string(&rev_reg_entry).unwrap(), &issuer_did)
+                .await
+                .unwrap();
+            let rev_reg_def = ledger.get_rev_reg_def(&rev_reg_id).await.unwrap();
+
+            // holder obtains a credential
+            let cred_offer = anoncreds.issuer_create_credential_offer(&cred_def_id).await.unwrap();
+            anoncreds.prover_create_link_secret(LINK_SECRET_ID).await.unwrap();
+            let (cred_req, cred_req_meta) = anoncreds
+                .prover_create_credential_req(&prover_did, &cred_offer, &ledger_cred_def, LINK_SECRET_ID)
+                .await
+                .unwrap();
+            let cred_values: CredentialValues = serde_json::from_value(json!({
+                "name": {"raw": "Alice", "encoded": "27034640024117331033063128044004318218486816931520886405535659934417438781507"},
+                "age": {"raw": "28", "encoded": "28"}
+            }))
+            .unwrap();
+            let issued = anoncreds
+                .issuer_create_credential(
+                    &cred_offer,
+                    &cred_req,
+                    &cred_values,
+                    Some(rev_reg_id.clone()),
+                    Some(tails_dir.clone()),
+                )
+                .await
+                .unwrap();
+            let cred_rev_id = issued.cred_rev_id.unwrap();
+            let cred_id = anoncreds
+                .prover_store_credential(
+                    None,
+                    &cred_req_meta,
+                    &issued.credential,
+                    &ledger_cred_def,
+                    Some(&rev_reg_def),
+                )
+                .await
+                .unwrap();
+
+            // holder proves non-revocation of the credential to a verifier
+            let (rev_reg_delta, timestamp) = ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
+            let rev_state = anoncreds
+                .create_revocation_state(&tails_dir, &rev_reg_def, &rev_reg_delta, timestamp, &cred_rev_id)
+                .await
+                .unwrap();
+            let proof_req: ProofRequest = serde_json::from_value(json!({
+                "nonce": anoncreds.generate_nonce().await.unwrap(),
+                "name": "proof",
+                "version": "1.0",
+                "requested_attributes": {
+                    "attr_1": { "name": "name", "restrictions": [{ "cred_def_id": cred_def_id }] }
+                },
+                "requested_predicates": {},
+                "non_revoked": { "to": timestamp }
+            }))
+            .unwrap();
+            let requested_credentials: RequestedCredentials = serde_json::from_value(json!({
+                "self_attested_attributes": {},
+                "requested_attributes": {
+                    "attr_1": { "cred_id": cred_id, "revealed": true, "timestamp": timestamp }
+                },
+                "requested_predicates": {}
+            }))
+            .unwrap();
+            let schemas = HashMap::from([(schema_id, schema)]);
+            let cred_defs = HashMap::from([(cred_def_id.clone(), ledger_cred_def)]);
+            let rev_states = HashMap::from([(rev_reg_id.clone(), HashMap::from([(timestamp, rev_state)]))]);
+            let proof = anoncreds
+                .prover_create_proof(
+                    &proof_req,
+                    &requested_credentials,
+                    LINK_SECRET_ID,
+                    &schemas,
+                    &cred_defs,
+                    Some(&rev_states),
+                )
+                .await
+                .unwrap();
+
+            let (_, rev_reg_json, rev_reg_timestamp) = ledger.get_rev_reg(&rev_reg_id, timestamp).await.unwrap();
+            assert_eq!(rev_reg_timestamp, timestamp);
+            let rev_reg: RevocationRegistry = serde_json::from_str(&rev_reg_json).unwrap();
+            let rev_reg_defs = HashMap::from([(rev_reg_id.clone(), rev_reg_def)]);
+            let rev_regs = HashMap::from([(rev_reg_id.clone(), HashMap::from([(timestamp, rev_reg)]))]);
+            assert!(anoncreds
+                .verifier_verify_proof(&proof_req, &proof, &schemas, &cred_defs, &rev_reg_defs, &rev_regs)
+                .await
+                .unwrap());
+
+            // issuer revokes the credential, the ledger delta reflects the revocation
+            anoncreds
+                .revoke_credential_local(&tails_dir, &rev_reg_id, &cred_rev_id)
+                .await
+                .unwrap();
+            let delta = anoncreds.get_rev_reg_delta(&rev_reg_id).await.unwrap().unwrap();
+            ledger
+                .publish_rev_reg_delta(&rev_reg_id, &serde_json::to_string(&delta).unwrap(), &issuer_did)
+                .await
+                .unwrap();
+
+            let (rev_reg_delta, _) = ledger.get_rev_reg_delta(&rev_reg_id, None, None).await.unwrap();
+            assert_eq!(rev_reg_delta.revoked(), [cred_rev_id.parse::<u32>().unwrap()]);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use indy_vdr::pool::PreparedRequest;
+
+use crate::errors::error::VcxCoreResult;
+
+pub mod in_memory_ledger;
+
+/// Transport used by [`IndyVdrLedger`](super::indy_vdr_ledger::IndyVdrLedger) to deliver prepared (and, where
+/// required, signed) requests to a ledger. Implementations return the raw JSON reply of the ledger.
+#[async_trait]
+pub trait RequestSubmitter: Send + Sync {
+    async fn submit(&self, request: PreparedRequest) -> VcxCoreResult<String>;
+}
@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
+/// Optional NYM transaction fields, besides the verkey and role.
+/// See: <https://hyperledger.github.io/indy-did-method/#nym-transaction-version>
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NymData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    // did:indy style DID document content, merged by resolvers into the DID document derived from the NYM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diddoc_content: Option<Value>,
+}
+
+impl NymData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn diddoc_content(mut self, diddoc_content: Value) -> Self {
+        self.diddoc_content = Some(diddoc_content);
+        self
+    }
+
+    // Writes the diddocContent into the `operation` of a built NYM request. The ledger expects the
+    // content as a serialized JSON object.
+    pub(crate) fn append_diddoc_content_to_request(&self, request: &mut Value) -> VcxCoreResult<()> {
+        let diddoc_content = match &self.diddoc_content {
+            Some(diddoc_content) => diddoc_content,
+            None => return Ok(()),
+        };
+        if !diddoc_content.is_object() {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!("NYM diddocContent must be a JSON object, found: {}", diddoc_content),
+            ));
+        }
+        let operation = request.get_mut("operation").ok_or(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidJson,
+            "NYM request is missing the operation field",
+        ))?;
+        operation["diddocContent"] = Value::String(diddoc_content.to_string());
+        Ok(())
+    }
+}
+
+fn default_ver() -> String {
+    "1.0".to_string()
+}
+
+/// Schema, as returned by [`BaseLedger::get_schema`](super::base_ledger::BaseLedger::get_schema). Serializes
+/// into the anoncreds schema JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    #[serde(default = "default_ver")]
+    pub ver: String,
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub attr_names: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u32>,
+}
+
+/// Credential definition, as returned by [`BaseLedger::get_cred_def`](super::base_ledger::BaseLedger::get_cred_def).
+/// Serializes into the anoncreds credential definition JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialDefinition {
+    #[serde(default = "default_ver")]
+    pub ver: String,
+    pub id: String,
+    // seq no of the schema on the ledger
+    pub schema_id: String,
+    #[serde(rename = "type")]
+    pub signature_type: String,
+    pub tag: String,
+    // public keys of the credential definition
+    pub value: Value,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationRegistryDefinitionValue {
+    pub issuance_type: String,
+    pub max_cred_num: u32,
+    pub public_keys: Value,
+    pub tails_hash: String,
+    pub tails_location: String,
+}
+
+/// Revocation registry definition, as returned by
+/// [`BaseLedger::get_rev_reg_def`](super::base_ledger::BaseLedger::get_rev_reg_def).
+/// Serializes into the anoncreds revocation registry definition JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationRegistryDefinition {
+    pub id: String,
+    pub revoc_def_type: String,
+    pub tag: String,
+    pub cred_def_id: String,
+    pub value: RevocationRegistryDefinitionValue,
+    #[serde(default = "default_ver")]
+    pub ver: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationRegistryDeltaValue {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_accum: Option<String>,
+    pub accum: String,
+    #[serde(default)]
+    pub issued: Vec<u32>,
+    #[serde(default)]
+    pub revoked: Vec<u32>,
+}
+
+/// Revocation registry delta, as returned by
+/// [`BaseLedger::get_rev_reg_delta`](super::base_ledger::BaseLedger::get_rev_reg_delta).
+/// Serializes into the anoncreds revocation registry delta JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationRegistryDelta {
+    #[serde(default = "default_ver")]
+    pub ver: String,
+    pub value: RevocationRegistryDeltaValue,
+}
+
+impl RevocationRegistryDelta {
+    pub fn issued(&self) -> &[u32] {
+        self.value.issued.as_ref()
+    }
+
+    pub fn revoked(&self) -> &[u32] {
+        self.value.revoked.as_ref()
+    }
+}
+
+// Selects a past version of the NYM in the `operation` of a built GET_NYM request, by the sequence number of
+// the transaction which wrote it or by the time at which it was current.
+pub(crate) fn append_nym_version_to_request(
+    request: &mut Value,
+    seq_no: Option<u64>,
+    timestamp: Option<u64>,
+) -> VcxCoreResult<()> {
+    let operation = request.get_mut("operation").ok_or(AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::InvalidJson,
+        "GET_NYM request is missing the operation field",
+    ))?;
+    if let Some(seq_no) = seq_no {
+        operation["seqNo"] = Value::from(seq_no);
+    }
+    if let Some(timestamp) = timestamp {
+        operation["timestamp"] = Value::from(timestamp);
+    }
+    Ok(())
+}
+
+/// Current state of a NYM, as returned by [`BaseLedger::get_nym`](super::base_ledger::BaseLedger::get_nym).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NymResponse {
+    #[serde(rename = "dest")]
+    pub did: String,
+    pub verkey: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diddoc_content: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+}
+
+impl NymResponse {
+    // Parses the reply to a GET_NYM request. The NYM state is carried as a JSON string in `result.data`.
+    pub(crate) fn from_ledger_response(response: &str) -> VcxCoreResult<Self> {
+        let response: Value = serde_json::from_str(response).map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidLedgerResponse,
+                format!("Cannot deserialize GET_NYM response: {err}"),
+            )
+        })?;
+        let data = match response["result"]["data"].as_str() {
+            Some(data) => data,
+            None => {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::LedgerItemNotFound,
+                    format!("NYM {} not found on the ledger", response["result"]["dest"]),
+                ))
+            }
+        };
+        let mut nym_response: NymResponse = serde_json::from_str(data).map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidLedgerResponse,
+                format!("Cannot deserialize NYM data {data}: {err}"),
+            )
+        })?;
+        // the ledger serializes diddocContent as a JSON string
+        if let Some(Value::String(diddoc_content)) = &nym_response.diddoc_content {
+            nym_response.diddoc_content = Some(serde_json::from_str(diddoc_content)?);
+        }
+        Ok(nym_response)
+    }
+}
+
+/// Active transaction author agreement of the ledger, along with its acceptance mechanisms, as returned by
+/// [`BaseLedger::get_txn_author_agreement`](super::base_ledger::BaseLedger::get_txn_author_agreement).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxnAuthorAgreement {
+    pub text: String,
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratification_ts: Option<u64>,
+    // acceptance mechanism label -> description
+    #[serde(default)]
+    pub aml: HashMap<String, String>,
+}
+
+impl TxnAuthorAgreement {
+    // Parses the TAA data object extended with the acceptance mechanisms under "aml", as assembled by the
+    // ledger implementations. The data object is empty if the ledger has no agreement set.
+    pub(crate) fn from_author_agreement_data(data: &str) -> VcxCoreResult<Option<Self>> {
+        let data: Value = parse_ledger_json(data)?;
+        if data.get("text").map_or(true, Value::is_null) {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_value(data)?))
+    }
+}
+
+pub(crate) fn parse_ledger_json<T: DeserializeOwned>(json: &str) -> VcxCoreResult<T> {
+    serde_json::from_str(json).map_err(|err| {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidLedgerResponse,
+            format!("Cannot deserialize {json} from ledger: {err}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_append_diddoc_content_to_request() {
+        let mut request = json!({"operation": {"type": "1", "dest": "V4SGRU86Z58d6TV7PBUe6f"}});
+        let diddoc_content = json!({"@context": ["https://identity.foundation/didcomm-messaging/service-endpoint/v1"]});
+
+        NymData::new()
+            .alias("alice")
+            .diddoc_content(diddoc_content.clone())
+            .append_diddoc_content_to_request(&mut request)
+            .unwrap();
+
+        let appended: Value = serde_json::from_str(request["operation"]["diddocContent"].as_str().unwrap()).unwrap();
+        assert_eq!(appended, diddoc_content);
+    }
+
+    #[test]
+    fn test_append_diddoc_content_fails_for_non_object() {
+        let mut request = json!({"operation": {"type": "1"}});
+
+        let err = NymData::new()
+            .diddoc_content(json!("not an object"))
+            .append_diddoc_content_to_request(&mut request)
+            .unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_append_without_diddoc_content_is_noop() {
+        let mut request = json!({"operation": {"type": "1"}});
+        NymData::new()
+            .alias("alice")
+            .append_diddoc_content_to_request(&mut request)
+            .unwrap();
+        assert_eq!(request, json!({"operation": {"type": "1"}}));
+    }
+
+    #[test]
+    fn test_nym_response_from_ledger_response() {
+        let data = json!({
+            "dest": "V4SGRU86Z58d6TV7PBUe6f",
+            "identifier": "V4SGRU86Z58d6TV7PBUe6f",
+            "verkey": "~CoRER63DVYnWZtK8uAzNbx",
+            "role": "0",
+            "diddocContent": json!({"service": []}).to_string(),
+            "seqNo": 10,
+            "txnTime": 1600000000
+        });
+        let response = json!({"op": "REPLY", "result": {"type": "105", "data": data.to_string()}}).to_string();
+
+        let nym = NymResponse::from_ledger_response(&response).unwrap();
+        assert_eq!(nym.did, "V4SGRU86Z58d6TV7PBUe6f");
+        assert_eq!(nym.verkey.as_deref(), Some("~CoRER63DVYnWZtK8uAzNbx"));
+        assert_eq!(nym.role.as_deref(), Some("0"));
+        assert_eq!(nym.diddoc_content, Some(json!({"service": []})));
+        assert_eq!(nym.seq_no, Some(10));
+    }
+
+    #[test]
+    fn test_nym_response_from_ledger_response_not_found() {
+        let response =
+            json!({"op": "REPLY", "result": {"type": "105", "dest": "V4SGRU86Z58d6TV7PBUe6f", "data": null}});
+
+        let err = NymResponse::from_ledger_response(&response.to_string()).unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::LedgerItemNotFound);
+    }
+
+    #[test]
+    fn test_txn_author_agreement_from_author_agreement_data() {
+        let data = json!({
+            "text": "Default agreement",
+            "version": "1.0.0",
+            "digest": "abcd",
+            "ratification_ts": 1,
+            "aml": {"at_submission": "The agreement was reviewed by the user"}
+        });
+
+        let taa = TxnAuthorAgreement::from_author_agreement_data(&data.to_string())
+            .unwrap()
+            .unwrap();
+        assert_eq!(taa.text, "Default agreement");
+        assert_eq!(taa.ratification_ts, Some(1));
+        assert_eq!(taa.aml["at_submission"], "The agreement was reviewed by the user");
+
+        assert_eq!(TxnAuthorAgreement::from_author_agreement_data("{}").unwrap(), None);
+    }
+
+    #[test]
+    fn test_rev_reg_def_ver_defaults() {
+        let rev_reg_def: RevocationRegistryDefinition = serde_json::from_value(json!({
+            "id": "V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag1:CL_ACCUM:tag1",
+            "revocDefType": "CL_ACCUM",
+            "tag": "tag1",
+            "credDefId": "V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag1",
+            "value": {
+                "issuanceType": "ISSUANCE_BY_DEFAULT",
+                "maxCredNum": 10,
+                "publicKeys": {},
+                "tailsHash": "hash",
+                "tailsLocation": "/tmp/tails"
+            }
+        }))
+        .unwrap();
+        assert_eq!(rev_reg_def.ver, "1.0");
+    }
+}
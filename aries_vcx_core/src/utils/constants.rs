@@ -1,16 +1,17 @@
 pub static ATTRS: &str = "attrs";
-pub static LIBINDY_CRED_OFFER: &str = r#"{"issuer_did":"2hoqvcwupRTUNkXn6ArYzs","schema_key":{"name":"Home Address","version":"1.4","did":"2hoqvcwupRTUNkXn6ArYzs"},"key_correctness_proof":{"c":"8555253541554245344305351079388313043821365069629297255640200538622329722556","xz_cap":"64818256731588984794575029881576438712171978148821994354569423109505883511370051539530363090404289097908646608544866367046312481771587336183036163818849360474523320055058050733772575227932313793985470881830147160471852946598089626822740951538444260248405680001410943962258653118246973446307071417314391910474888369634752642195173997916292806072016186810315308257756689251031806948447462801785007243395079942815166817065271733596477143189406957903952991335446968764832960906258373699575234207180135806072152726528786138816315911998387303385565913657745597433033756984505440643451253917452841385494947936404135348354895376751800590086535707370194450915965147666804363452357419799188104044508109","xr_cap":{"address1":"8236425893392219787423825014385198460820517586004442204287421088285469674020926840448786131806503567730307555837801319715555107413533966776756997088003362401505821396887204933829958258785093075846810980429322007441122948459832086015057507926262051365966017173045228232337530339680355717180291794733363148324101203340879842496879728996183974739507710337122557429529832639384077022317326079678153237524335334790193774589523155338216849532635731123476861074950940938322358853287805286272076498390452028019829082291826739453475976800681550225322996208089503815975750152834370138410964418644082923687817510140143620366818252076463572791466640135793621279863114074326681043782582123182032344081138","address2":"30414471804770994051376437296525278254597585112268783700020054398847238843189530750793146903722533375657200785297557019465948393596156534191847866989266176618709331559949972729939131388887244366321127743968836991526071402029914419405781596054783690896660703606768577825229647587998380728894419570361864769440309185637967429191914824558483741394914212983254247799137730101941670911547714088499696084822272226072237693975774997990116374449197382931059877141968595755981160846810650806105803130004361523114137045586548743326078945833123588843296375692506658736851641735658969617721427932961073974202337608798761064528676757519926255271724266286989825397405029723387126754299497661658557574216867","city":"159795636056543233530021344623621334175753173834199599499234503024224170089287815725788337040803537786795901100564559891075793321268703839671526386175533087941057761454903389990043254221508542663884105491028667931433093528378567035675241504608287341705758154859625863922110474313370021277749973041267871971965548396722681397958408458464210449202419266126608057284371794186889175339171087558861231355840830361110708993602208821778313069364112399404445977187422249127909803315019664537899385297653020295835898441614009217452024854561288538496889400595485884757791655246945196819845725103196695608534259378231125159518322706097470964698852674734436475238855630473478573401236177640541599034507313","state":"215512468490315112938301657833926278136808116594771729699898320102646611321724434471863048390556908138905125523936043735201882025532772433002153410083708215401917118972375534193847316461794285777665177963351136804949997738950645361626956052973425101611071191598827068821964513860723502996877635652196651818308886110840798493982976675792164313480547213301748933952971154819253513296456319475340952940914757162158069252461973054465657233683514169036627218211903327888618365019366708902832859985119776192696909319524217085945724819529037625577237504453097885607411726308520123962852327787736505753002450290943635652415448032216336431217538662448209579828135627648861641446385394343199453905348086","zip":"238413810148929820131063264189691178282858328114757399256193590161266006646670344870416481980522447923115217342582281807424862378687793299109363839238237538377362459559820681904274866049652851183765153471969318096511161665533190643665261284892951569998678113101193901664492159340828270692168345719923300987213287650281559452357368956472066676438018575401605560388568884399190765464134955117933339552804676602790359330495723485338924295339609987825045590507344961620812843451249916254642836938597183261177212672766675968705705261108413829152581548433386403050115216912797280460237259161451733151284615735871654022007177671460429253488906111387740833557450384941388970535365310270275589048348152"}},"nonce":"45815185447169282124747"}"#;
+pub static LIBINDY_CRED_OFFER: &str = r#"{"schema_id":"V4SGRU86Z58d6TV7PBUe6f:2:FaberVcx:83.23.62","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","key_correctness_proof":{"c":"92806215623656847195507753264862805543601611023161727875216841746800082952324","xz_cap":"1184794664507890417739289702065653183336422949034857746406318981237372008366739572132458041455603590869299047822010854643368688060847773436412336530573392519421720188821875500733382213518826993363848346449144711124380642338678022295128770718780272205658334794762841804833152294784316854066053479013608673369803926400090620978445638643180198217567889011876965602870770651382041546491073128417245891598325812508076372199192441164070116986251040897977015555768819902768328582183032790087372789721788419760748001593691718569580808816590564060806704685153498854717022713006478970444398050995814842936043750732722769423941033361671290078587379861280373238394834768931646176289084733491584475949342365","xr_cap":[["sex","412823972315692560988099866161149290812078589370218517910321277011667945798028689037866085957254135716950655662775063740974982616389246594503612096110237453885366574695544683890538306252434009086365139052963699083279574111173928306615542315267037448634438992555209769547326137068214559103420308494772797600081169571616043039789161778010004355522015273855984005891208704611582821538630887193775357918789106329680063143632160182117321700446641737682183756951641540461512842400902791174142590955538027236700455268559237166877645242272864261136523968274148536511016284677617453554711574517976348159036735997567464482761902386529579763758666657378223331235443132452313067792256691295614296888831828"],["master_secret","569193621319167251565201814513629422096871271866248451616222738493210651919992002136147128442507369442296599737698945365427000117699976816841571897294023900420127756422800419819934410150143472671370099437377716455714486279379423692245356311063685215302879375958217828781772221172515164367537112862694131845990180586928533922004320934485578616419548310384652714685301367280175045465781516585316235679757011635728476849054105079383137307231520969166948378921066336344585868959847017761234702018249234409533251773287647989258368573617312739836971166649341701505152455906713467430663949882392326601742718861788359247837906743739058578956347277921522261454533227674333242635771822197521354663979851"],["date","2079113296441335872211231076354310967689450464262366203639089008265313310345655570439273139167423104602344051261427875782575061667407366531230480208778933968552576226678498604346708236930831533577806319615904748391640632070797101126211884112553290509506142809493742165242588849506185190241339204905232362693186964309109871511351612477501318687686223971557247811524068532752693326889243400594936533007748037481694438596284445265199877432124232025330871359662363524726534406705268608741490342597188698225517593559718983330075071028111008918536604765125916521301456736315501530727620937967348823834013777068342747308650790001006390136036463570368298565041941901083106297774320053343621608985698347"],["last_name","381096954699892043681539033288579333432540991930521065907547976399614095714513422963718888398952455525813292819733371337192407211003398804715769410257305093978137797873396157950532205389650274929861691748008857020982508401262190521375816878781917736966688723258886878552067170243972118058168054356894056038702705573326267809048740156000735483000409697204947657871114353750126193782501900407910536556020089825603824615645337208490988372451202917010240905598908418383255008513186809250719650634345982135274268432154522896967907532079346768170648145887568172924918638865241502635999921593006350938638558088641415289726607801495305703819028784983169825646585280563060145257285235473066645634473583"],["degree","2069123634224411881681621207899897742446302592582047590872718006590048877478956145518451032655239440084151422431138558643487219279831733629649578798689199695694638700091016884067876570950324212956111543747177636440146310473815005426163465410986974881219843956674242635127731527686743665556355635668164761186911137180531595324852939374365014109394145812190075709435535814268200659206625132649048800334895782662216340299633563835094276996835620832550567198564168913353805569514211871644741492691890417639345374834343386886715764012294622018147813854720674132147129601646891459501372930835414836834695998590835051448285679096376569412303307485016832814361890848137790806870963696355567668362270417"],["name","1600031689463487467204541542485851975165304096193841850812439844880437142682415481429410938053445297821870411413041933563915119790308019259191550023526744105396239608040357503750517120278464681836962617160002308249174045049379842112218985134191226350592467361995023678796239141283005243479128750862510979326517306591629362377851002682923616950655692548812640696390442555183079705241384189524299077089727629312684467098932843918505696933390796681749963349099191454166815075127994983063099123745610421159441264833006310734261279112883866829414488922482118310347115451479261518333049931372114465088054719445234476747092471922108007424100339569585433622675166347595849140148244114211915034049042977"],["age","1795121452649230665412500739883816616666044201923539440366668234781049690340969528161090577261526929747118258124907160014337746738550775198231422738115244268779766336683664786307548305137530436356214720657705997702848368569336885739205036090388136345740665266859878523659489085267193755382160415117972530780802493364347338631060711784912320216361295792587094355879089353099224168577316515760412168605888380716116079391160653522988608141634697803002135197909921675595613308909330309243721044019224741392694823192116156181118754775677046101907929115115426790795732011447290587829012196509768648884213094529010235143736730253838247973813388828280298875727512881017895179078503200796349206609412988"]]},"nonce":"657547075909751525981807"}"#;
 pub static PROOF_REQUESTED_PREDICATES: &str = "requested_predicates";
 pub static REQUESTED_ATTRIBUTES: &str = "requested_attributes";
-pub static CREDENTIAL_REQ_STRING: &str = r#"{"libindy_cred_req":"{\"prover_did\":\"2hoqvcwupRTUNkXn6ArYzs\",\"cred_def_id\":\"2hoqvcwupRTUNkXn6ArYzs:3:CL:1766\",\"blinded_ms\":{\"u\":\"8732071602357015307810566138808197234658312581785137109788113302982640059349967050965447489217593298616209988826723701562661343443517589847218013366407845073616266391756009264980040238952349445643778936575656535779015458023493903785780518101975701982901383514030208868847307622362696880263163343848494510595690307613204277848599695882210459126941797459019913953592724097855109613611647709745072773427626720401442235193011557232562555622244156336806151662441234847773393387649719209243455960347563274791229126202016215550120934775060992031280966045894859557271641817491943416048075445449722000591059568013176905304195\",\"ur\":null},\"blinded_ms_correctness_proof\":{\"c\":\"26530740026507431379491385424781000855170637402280225419270466226736067904512\",\"v_dash_cap\":\"143142764256221649591394190756594263575252787336888260277569702754606119430149731374696604981582865909586330696038557351486556018124278706293019764236792379930773289730781387402321307275066512629558473696520197393762713894449968058415758200647216768004242460019909604733610794104180629190082978779757591726666340720737832809779281945323437475154340615798778337960748836468199407007775031657682302038533398039806427675709453395148841959462470861915712789403465722659960342165041260269463103782446132475688821810775202828210979373826636650138063942962121467854349698464501455098258293105554402435773328031261630390919907379686173528652481917022556931483089035786146580024468924714494948737711000361399753716101561779590\",\"ms_cap\":\"6713785684292289748157544902063599004332363811033155861083956757033688921010462943169460951559595511857618896433311745591610892377735569122165958960965808330552472093346163460366\"},\"nonce\":\"1154549882365416803296713\"}","libindy_cred_req_meta":"{\"master_secret_blinding_data\":{\"v_prime\":\"19573554835481719662327485122688893711456991477879921695470731620175963787279917341526369852398210114401207141951797741891847253211319668203346462590568438671120943726162783813341598838616013039004762423956877028539225355867586807673681018234178116101643797916210905197387018359780257940149589162122784199178788814187547780152684853122014747482921656188183260370150999742557975345375106137123621426061675848590309427394874048446416740808489978625893734432529086470382099078632291038405367083882596203500659091849643476443635802557200596085378755820180062431900445542883509174786917819553164472263849777903881905876531213020487201635195790520\",\"vr_prime\":null},\"nonce\":\"143213049816807095013964\",\"master_secret_name\":\"main\"}","cred_def_id":"2hoqvcwupRTUNkXn6ArYzs:3:CL:1766","tid":"cCanHnpFAD","to_did":"BnRXf8yDMUwGyZVDkSENeq","from_did":"GxtnGN6ypZYgEqcftSQFnC","version":"0.1","mid":"","msg_ref_id":"123"}"#;
-pub static CREDENTIAL_JSON: &str = r#"{"claim": { "address1": [ "123 Main St", "67178325706055440519333844001910714778187200326224626679030947715618922270834" ], "address2": [ "Suite 3", "14069420537488782212572815457694763597282389356553685124587846316416790412172" ], "city": [ "Draper", "12909874379699016994385580221532801536154776321388073746338293599464917909913" ], "state": [ "UT", "93856629670657830351991220989031130499313559332549427637940645777813964461231" ], "zip": [ "84000", "84000" ] }, "claim_offer_id": "n2vlytr", "from_did": "A5zycNieiKQQU6xQTtR3ie", "issuer_did": "2hoqvcwupRTUNkXn6ArYzs", "msg_type": "CRED", "schema_seq_no": 22, "signature": { "non_revocation_claim": null, "primary_claim": { "a": "67244059587509386261445845549403679184696388188135088719570347990957702854576331330763123108600111867246898203893942330532414276708236493565050051577255278475231198777619682053425511310805374629000032369988817946046983751759949589692002588340296869187196574372221898903115017422604977611873108946802618066478041981199841837601172757327153617669273883040639649832266729961077172477776787709368285904991774256514200981595163262342626363217452242070410058562010319101992520148282733995635010533432768876074037878998373839551569333559642205700332716013642805666606069808481186570157152956520080458539520791159072055699608", "e": "259344723055062059907025491480697571938277889515152306249728583105665800713306759149981690559193987143012367913206299323899696942213235956742929979955970602668519234522036604952927", "m2": "73598276571237653510985065573388298358260861260139843942970542810506069131247", "v": "7782040862611754129906364472072177993242400915902838921150044188825385426320744155674271999800409192400475215606427400576174714844912910889635915209442760601639503812483333710498966677032266043782534729495630058139079759294540063522862433832217680399967662575805572449362848616899654527283911479159798692836920609447117694604550229460246691843256094252578966531478355690870011917944269894281735089639287126305305853350364136525483737759256221150059629492378671054376973353349516063810767461334097264068335988545196188611499359009325998360279987424407962189883068449914753466665140753744386975512941589134281298094791056350565149274890478146205610581609463698788908501060583315271304706101286351923769293430142774178612205047744806182681425621706093102973045356561983323064302333475988590139227392601738347814619853533067" } }, "version": "0.1" }"#;
+pub static CREDENTIAL_REQ_STRING: &str = r#"{"prover_did":"2RjtVytftf9Psbh3E8jqyq","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","blinded_ms":{"u":"100027047566114662930857592792764412217721392275083369828882938655574695904084909254872598383374135462818157481579202347277961003470677127247697513248915070768964337200672247123080011833520943699367832053033304094927034469240651159293637839716784651051241104373984444379690075201193445259176133046547957952458929898225062912541441373493724006451480428570814460400887039849190969477499250718291571843884107872498111565592617570209630469169665200928632327643466698744118207300048944134796951152980530492185153018650124349633386639988967401936709045162893378689741335876430320096144414916612452997419176841748365773606484","ur":"1 011DD64801D4AB4C89D4656452BF7EF514966CE6CC554ED2573ED872E0708F26 1 2264648B75D7CB11CBB72841795E15EF3EAB78582FC17C2DD610C8A30489039D 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","hidden_attributes":["master_secret"],"committed_attributes":{}},"blinded_ms_correctness_proof":{"c":"42859294662460772808298435946417321061476430494246572399133120991903210109494","v_dash_cap":"1478619110782031402608405563787983201731901784804609146631815158222427729624585547558703984974630002825908701708332647420029468265107477296745177164705126834512105030146466919877322598183861210526376290879332453719303445348402276468524520038553102488153312773899465147281921239076618316757808923215466576260899789404828017046198822651337873272103124257332250516281041620482143110025386131181133476132476234272552040616399080363625825591015424807027478372552093751608537199491399316600682691844230586410091912487370544253884676753297187569361664585802861243207450995330792824397357597743885787143247543232848497365133388472251630812390248704221825526552721721638852309675197702870877730309672534696647015828790855052823","m_caps":{"master_secret":"31326986252462886685731795343818157373290006314121231271456817164687053104943493919210987299664317917279607162961117668460493725536706336994992071609632454800495834884095596105618"},"r_caps":{}},"nonce":"563819600274040972421495"}"#;
+pub static CRED_REQ_META: &str = r#"{"master_secret_blinding_data":{"v_prime":"19573554835481719662327485122688893711456991477879921695470731620175963787279917341526369852398210114401207141951797741891847253211319668203346462590568438671120943726162783813341598838616013039004762423956877028539225355867586807673681018234178116101643797916210905197387018359780257940149589162122784199178788814187547780152684853122014747482921656188183260370150999742557975345375106137123621426061675848590309427394874048446416740808489978625893734432529086470382099078632291038405367083882596203500659091849643476443635802557200596085378755820180062431900445542883509174786917819553164472263849777903881905876531213020487201635195790520","vr_prime":null},"nonce":"143213049816807095013964","master_secret_name":"main"}"#;
+pub static CREDENTIAL_JSON: &str = r#"{"schema_id":"V4SGRU86Z58d6TV7PBUe6f:2:FaberVcx:83.23.62","cred_def_id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1","rev_reg_id":"V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:31:tag1:CL_ACCUM:tag1","values":{"name":{"raw":"alice","encoded":"19831138297880367962895005496563562590284654704047651305948751287370224856720"},"age":{"raw":"25","encoded":"25"},"sex":{"raw":"female","encoded":"71957174156108022857985543806816820198680233386048843176560473245156249119752"},"date":{"raw":"05-2018","encoded":"101085817956371643310471822530712840836446570298192279302750234554843339322886"},"last_name":{"raw":"clark","encoded":"51192516729287562420368242940555165528396706187345387515033121164720912081028"},"degree":{"raw":"maths","encoded":"78137204873448776862705240258723141940757006710839733585634143215803847410018"}},"signature":{"p_credential":{"m_2":"34239492586603451132793432079627968880304030575812468226470636874751822858762","a":"3563131870571341562942447213215431793764331728811304513850423726584197868226953531211853518564185788421871086264703340351923854887295149243812720642580418187989823626988903301028859474352611893592620689431903289577500208193942359155514839092142415639545420995005344634496843154309379562341177883976932547420934334464659261988488683012480532976671889020837582586293529732428621563888453356419207774056583362932487901416696251200138928778719284124992702374614067490296832134122023385269276680417470517906015550348947567285667506407569680396891991575967982029847074598117891891522303427746502989090161328829469233540877","e":"259344723055062059907025491480697571938277889515152306249728583105665800713306759149981690559193987143012367913206299323899696942213235956742929829980207352210542787871744029957649","v":"5189282183832435863300765290818223025067469534318251742959519592331847887508203933635962125771260855185093545027477595844939404698982783628016776235695366260102138275373351273904130201717691903057434095185823117503663524326601494097274119604086316973344746599275286735089713311253444600164511949088907577080069435861224620218862152104267428843263052480679286948469204793959405508409387101565382789844595280641549760029760646688577467049437823519541743657954151593529704009735104561122628765293975667820624083117407972393575542353158748960486750772728245613246377354797137546851465874461135342276305028758637295673836305254475821204665871482118672068540011675974248109434460390394912373750254930136724230358537313974427177685036208302156289370488454015844354280625604252054853498665457468057423726387120729428080005427189"},"r_credential":{"sigma":"1 1FD3E477B5B2EF743C10024C7D5E7A53B005D774D324C8A709C1B70607943CD0 1 1B3DE6D2523D42823AADBA8C2CDE399A8E1AC570867D60EBBE379D2915574AAB 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","c":"0DA77597D2F83A5DBA58A32898246C95232F7BF3C8670E4DB54481C01A1171F1","vr_prime_prime":"0CD32F20701844F028CEBD87EF6E693B821A510F1826B1C28502AEB7C979765F","witness_signature":{"sigma_i":"1 1632DD67156E240A6E88111EDAB4E04AACC68516AD3A60E3B906103858C6E84B 1 136BDFC25131C56E27D778117E4D5FA0F8EF1E26B91FCC6567316D319D25733C 1 190071FFFD39CF96340C5982654E25DE9CDF3F2528ACBDD7B3687ABA0E49FC90 1 112D221855AAEDB4CD21206B2DAE88E22639FC35FF48A5381E434BE19500F30F 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","u_i":"1 20342585C1562CA3DFF2B1056DBC4F10B0B948B23C9980F11227E0A18FF72CB3 1 23293523262385202578753B5FE8F430E221435FE09B301A23424CF27F850CD4 1 021BBD73F1EEEAF70D657345EF44E05A9C22F83B4FB6715509237969DC9D966A 1 0F62D50D13D628DC8A5376E15BF5623F3F8C5EB422BEBE84D846D8E01C01A988 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","g_i":"1 10E29218D1B97E1129104DAD5C754F867F8DA391BB771A8A7CA406477B702561 1 09B9874680AE435F4646B80565CF680CD1A5DF0728F05642D8F225EEC9738224 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8"},"g_i":"1 10E29218D1B97E1129104DAD5C754F867F8DA391BB771A8A7CA406477B702561 1 09B9874680AE435F4646B80565CF680CD1A5DF0728F05642D8F225EEC9738224 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","i":1,"m2":"4BB2DDB4FC8891B42CB33E6E2F50A83A87D1A8DDEE43EF296ECB23A633A90A0A"}},"signature_correctness_proof":{"se":"4227770449172483014211029604945074281266657824743051071591223604130324662833046726980808434579738837283019678314689651232718408702312861484635194710822604584829535119864526998652227613977633912307594358378869841670816179325914556570886615075788082141289731083870623461925914625848700021614267747901807969887100790774908868563652268543118421299608286332396771906528020262242044098837686683452940471156001544051451734438543096891018533106236687173733055364372923974293931908331353110131779312841721522041869007072609268418395611945950800190773698572919140183021533368002059151050631094560385792972778504507860432572420","c":"77575687836064961489422049716490189621867956929024453155925031324499388041868"},"rev_reg":{"accum":"21 11AB07DB2CF0F7629108DA33C6593DE34321CE22463DB777A6243E62E4B797C27 21 12DC81E210AF59B6B00FDAEA6F043361953346533B2BC22BE91CDDAFFC9497B6F 6 654041C936480A5E4047AB33DDDFAC2193F4869D35D2887908247C0C9CB3F897 4 2E1E940139EA8C818DB1C20B7616166DF0F7FB1FEA2F049A57CA61FF7703DCC6 6 6088C090D37272D700E65DE80CF84F6F3EC97B07BE204FF8622BEAEDFD058C6F 4 170B22E61B4A333B2D6E476D6FCD7C8D9EBD4F6FBBC50BA002B929FA5821A16B"},"witness":{"omega":"21 12270D598F1894325C9A2537D18C9C558DE7634B7F18504387DE3D08D9505FC8E 21 13A72DC91CA9F4E53BA3CFFDB61A5068E6FC4B2405BB5B3E3E7F7056A5C9F3D7E 6 84922F7CB67BADCD83C180467B2B8104A0E5B50FCDD96730A4B7B66A6DDA5718 4 2ED45D49B0A1FB415CC3C6DC3CCFDF171A1BAE472091AA18D452812B7474FD88 6 549074BC70DB153F5B784A612E40C12522680ACE711D2F65835B2D494F1133FA 4 0E58979A327E309DE2434C21A8E207AFB9EB07E224B876BEA1846938B8370129"}}"#;
 pub const DID: &str = "FhrSrYtQcw3p9xwf7NYemf";
 pub const VERKEY: &str = "91qMFrZjXDoi2Vc8Mm14Ys112tEZdDegBZZoembFEATE";
 pub static DEFAULT_AUTHOR_AGREEMENT: &str =
     r#"{"text":"Default indy agreement", "version":"1.0.0", "aml": {"acceptance mechanism label1": "description"}}"#;
 pub static REQUEST_WITH_ENDORSER: &str = r#"{"seqNo":344,"reqId":1522866729726860308,"identifier":"VsKV7grR1BUE29mG2Fm2kX","txnTime":1516284381,"type":"101","endorser":"V4SGRU86Z58d6TV7PBUe6f"}"#;
 pub static PROOF_JSON: &str = r#"{"proof":{"proofs":[{"primary_proof":{"eq_proof":{"revealed_attrs":{"zip":"87121"},"a_prime":"11385117131660364111896731574172401420546708937324515452576064042574669257712348081839601624888211708194648786566821283532355289865761814401811668762111208667245892385556461650028028744704055636249982477585075400271187055151098908619974783653495410195833182515968533296209260588771695756312205872111048687853205561923237816546964283713422785468941977105067099251171604215251944317080214747243003427107934144216501594603687728398269433535428273623697427762663203253444115676455803364347007933198640450232624037803599295212149889810488912469008187381827942935958271186223675811120227514991467360019909918168868871310826","e":"106416440968489307688477111652744547884453520724896945262209396020249946843791285682305811237667052208528018943270519839389621717609114567","v":"693333598587517819941352847365742544380142765574139951146061816085618155189452020698413593997300585596324426252632314355582268506939303400407496417606249958981732261864957022478367092130677367027387866707732300909963513115315021003243992979679627258775140835220412197296938533762295871549731992640856382276196325998522049034267838563447330191852382971065392091768243880823490111370897368424139935395298937408562086499759656814447622881298133126653711399509169280794404022383866082757555249917245259236759269242823638516805150417229733278877402638802562166688388004908477637031034437462247846682697897200600573773942810246023809243280772183646809858924033191135463766964917504748987154189779729311869624825798007137022205256426446605413391018202025830072404371317952188965637832374449641811319605909172576074628353298939272848787572418960647922046317299399246159851863001665989283575050230911587927811099747218793149167944","m":{"address1":"14383563842714000336694832849665594946445962739184223209071037007452629790699757287294972813779623472744803572582328437911448950215408915191131611402522120804333222273489132084886","city":"6335807846068782600189687454462436112078812829004884052293133660597927372142081774735780601789659420890039727382522297770947316366004790684358611659096944452253560380208620107157","address2":"14091589660350349984354459530985480819537335363987179151561762220365706941537938554719525480994821015753311971694921036448081399741287968054078818802947721994040284668811562798267","state":"3374323397845656383843625203828356603754667474607034126894741033080120461511932284334653947059306129056440817463723283235350691497796837255669792651105389390523206636040269228223"},"m1":"3855360575146368990379338878340437444760062086797499842306857866860002517711237747809252335775444064433909759766687399940398272328893322787415703512272117087096813054972554563968","m2":"829849307958330576060854040909379039690330674646288918248299874016648994971333520820812771094141855582854026260700068274261655287053826960354567228953144532668188875028174450750"},"ge_proofs":[]},"non_revoc_proof":null},{"primary_proof":{"eq_proof":{"revealed_attrs":{"height":"25730877424947290072821310314181366395232879096832067784637233452620527354832"},"a_prime":"19606523040238141145717779531277522663224725021451964954928837778213780167806130611283656444876346090324217222925322088211429761142256636233006676888209824950866753616039040159779454392004510214888982466524158456130352445810766077072116343308981883033612830486699333857960241899822662839721374841002668163948377275672875472144246128931473522911282089521505706783701020863536724574924376299714351850171426752924728784363669376534908759391351622381020289812523865512890582404247961101220209552503431785599610046609564493669749669261122419593784559612666376852938604194178973814343952986182892452430023827671762557476463","e":"175708676962718808956603450728506825597108526784886396727551101010180796035520261116334855856024649318365254638362385900327196683643205289","v":"259807837216236716040272227118473612683891972149132456558837821820045873391789606085689340379415983105482747915525402053563208559407596040401913772550177102524653456118110816872909394038065296113425708018233090717854181564661116753447052574450590706580409613994542437680615027500439312381591222697969343626799892433263884943265734866198270469445076231573689904631155795742013664414011861042682562430778840652138660305373152231267819984539617728384058547342678165579739778850312059485316741860467804248135416832291520168882232179984203367316142165755286256284689566686572380792365871911853060464498285167571443335788402725298771375480014651263894303375604516169554253834245685148604580043733043610372908803488088857487861826680931152125459941500902596987258417619816375861312684684210554655250852226060290197739094390988802816264926238846473088662123137199115232833530286814846710257219453988118104609524238769962408378467","m":{"age":"7439137780003632501472198844883943985851982687398948370748383175062107337760437828394880407813732765383124554153414377359168765440779597987864714528157062929739375601687952057680","name":"478729181402353180485622154597836495776273289714890210090026576459958696376861534830836701841321719749324218544889558530446121758555251583232291309241803053682268055213713398699","sex":"3475750043210272427962584518900024357879025980293153402384602896340153685772192528112892451091903360990446424944281039234223910308784253682458410982729333916158905947811280570097"},"m1":"3855360575146368990379338878340437444760062086797499842306857866860002517711237747809252335775444064433909759766687399940398272328893322787415703512272117087096813054972554563968","m2":"11250551913673338582984048217906733348080383121220187035974642609915309422920937161820959912087373627684234183422675877388269370474604625896553325837450381771318770783786394439756"},"ge_proofs":[]},"non_revoc_proof":null}],"aggregated_proof":{"c_hash":"98289858372176739486785426465663295860443703464650074572637147012182597972573","c_list":[[90,48,0,0,86,54,61,179,29,65,240,170,216,13,100,217,170,179,191,79,71,2,133,94,84,120,87,90,167,161,166,221,56,137,73,156,8,139,61,114,10,131,241,112,150,110,102,170,132,231,229,250,177,131,6,33,158,228,233,190,125,211,244,238,143,232,12,25,154,52,34,54,194,237,16,20,239,207,37,3,211,34,39,70,253,226,25,2,235,164,40,227,87,103,108,40,93,125,174,74,139,95,111,175,124,1,26,204,120,172,3,125,18,149,107,209,82,168,46,185,96,173,161,80,33,165,32,40,166,106,141,41,55,143,50,219,37,210,114,90,104,64,249,193,195,157,51,34,174,127,83,251,254,102,199,221,199,239,204,13,221,216,8,254,107,100,14,31,49,38,187,243,247,12,116,110,169,98,85,202,127,237,149,126,10,115,200,229,74,131,202,195,188,176,253,132,25,117,104,197,73,252,211,192,235,136,7,83,136,235,152,98,99,208,232,50,165,117,245,89,138,255,143,241,113,14,130,94,125,253,252,74,45,73,67,49,87,12,106,218,197,238,82,134,231,210,33,228,189,181,71,168,252,217,141,234],[155,80,70,194,115,251,48,64,107,148,172,180,25,185,82,75,129,60,47,228,47,102,33,64,158,83,190,172,149,37,66,189,237,26,120,207,50,163,29,130,142,226,11,83,96,172,63,161,188,131,75,138,14,180,56,139,244,111,163,136,89,239,13,161,204,20,204,227,236,219,156,33,217,141,114,76,47,221,129,186,123,46,16,6,158,238,205,66,208,252,108,184,252,72,229,52,89,54,68,23,39,130,57,231,96,236,130,157,65,41,42,21,8,247,206,179,36,244,164,132,158,56,191,73,212,192,234,95,143,141,194,71,197,58,172,122,170,220,53,59,64,201,234,28,74,198,125,206,10,240,192,145,14,207,68,227,132,185,85,112,173,156,71,73,80,60,152,100,252,45,249,201,153,5,159,61,27,105,97,57,61,35,233,190,23,158,46,141,42,41,225,141,26,41,123,60,243,10,81,251,234,32,192,181,135,122,167,231,106,8,123,92,196,197,191,104,119,235,205,181,178,62,248,146,71,221,250,251,193,217,14,213,13,121,37,76,209,150,22,216,155,84,212,58,58,38,148,170,178,86,185,107,84,152,30,111]]}},"requested_proof":{"revealed_attrs":{"zip_2":{"sub_proof_index":0,"raw":"87121","encoded":"87121"},"height_1":{"sub_proof_index":1,"raw":"4'11","encoded":"25730877424947290072821310314181366395232879096832067784637233452620527354832"}},"self_attested_attrs":{"self_attest_3":"my_self_attested_val"},"unrevealed_attrs":{},"predicates":{}},"identifiers":[{"schema_id":"2hoqvcwupRTUNkXn6ArYzs:2:Home Address:5.5.5","cred_def_id":"2hoqvcwupRTUNkXn6ArYzs:3:CL:2479","rev_reg_id":null,"timestamp":null},{"schema_id":"2hoqvcwupRTUNkXn6ArYzs:2:test-licence:4.4.4","cred_def_id":"2hoqvcwupRTUNkXn6ArYzs:3:CL:2471","rev_reg_id":null,"timestamp":null}]}"#;
-pub static REV_STATE_JSON: &str = r#"{"V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:744:tag1:CL_ACCUM:tag1":{"1540930249":{"rev_reg":{"accum":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"},"timestamp":100,"witness":{"omega":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"}}}}"#;
+pub static REV_STATE_JSON: &str = r#"{"rev_reg":{"accum":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"},"timestamp":100,"witness":{"omega":"1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"}}"#;
 pub static CRED_DEF_ID: &str = r#"2hoqvcwupRTUNkXn6ArYzs:3:CL:2471"#;
 pub static CRED_DEF_JSON: &str = r#"{"ver":"1.0","id":"V4SGRU86Z58d6TV7PBUe6f:3:CL:47:tag1","schemaId":"47","type":"CL","tag":"tag1","value":{"primary":{"n":"84315068910733942941538809865498212264459549000792879639194636554256493686411316918356364554212859153177297904254803226691261360163017568975126644984686667408908554649062870983454913573717132685671583694031717814141080679830350673819424952039249083306114176943232365022146274429759329160659813641353420711789491657407711667921406386914492317126931232096716361835185621871614892278526459017279649489759883446875710292582916862323180484745724279188511139859961558899492420881308319897939707149225665665750089691347075452237689762438550797003970061912118059748053097698671091262939106899007559343210815224380894488995113","s":"59945496099091529683010866609186265795684084593915879677365915314463441987443154552461559065352420479185095999059693986296256981985940684025427266913415378231498151267007158809467729823827861060943249985214295565954619712884813475078876243461567805408186530126430117091175878433493470477405243290350006589531746383498912735958116529589160259765518623375993634632483497463706818065357079852866706817198531892216731574724567574484572625164896490617904130456390333434781688685711531389963108606060826034467848677030185954836450146848456029587966315982491265726825962242361193382187200762212532433623355158921675478443655","r":{"date":"4530952538742033870264476947349521164360569832293598526413037147348444667070532579011380195228953804587892910083371419619205239227176615305655903664544843027114068926732016634797988559479373173420379032359085866159812396874183493337593923986352373367443489125306598512961937690050408637316178808308468698130916843726350286470498112647012795872536655274223930769557053638433983185163528151238000191689201476902211980373037910868763273992732059619082351273391415938130689371576452787378432526477108387174464183508295202268819673712009207498503854885022563424081101176507778361451123464434452487512804710607807387159128","age":"15503562420190113779412573443648670692955804417106499721072073114914016752313409982751974380400397358007517489487316569306402072592113120841927939614225596950574350717260042735712458756920802142037678828871321635736672163943850608100354921834523463955560137056697629609048487954091228011758465160630168342803951659774432090950114225268433943738713511960512519720523823132697152235977167573478225681125743108316237901888395175219199986619980202460002105538052202194307901021813863765169429019328213794814861353730831662815923471654084390063142965516688500592978949402225958824367010905689069418109693434050714606537583","master_secret":"14941959991861844203640254789113219741444241402376608919648803136983822447657869566295932734574583636024573117873598134469823095273660720727015649700465955361130129864938450014649937111357170711555934174503723640116145690157792143484339964157425981213977310483344564302214614951542098664609872435210449645226834832312148045998264085006307562873923691290268448463268834055489643805348568651181211925383052438130996893167066397253030164424601706641019876113890399331369874738474583032456630131756536716380809815371596958967704560484978381009830921031570414600773593753852611696648360844881738828836829723309887344258937","degree":"67311842668657630299931187112088054454211766880915366228670112262543717421860411017794917555864962789983330613927578732076546462151555711446970436129702520726176833537897538147071443776547628756352432432835899834656529545556299956904072273738406120215054506535933414063527222201017224487746551625599741110865905908376807007226016794285559868443574843566769079505217003255193711592800832900528743423878219697996298053773029816576222817010079313631823474213756143038164999157352990720891769630050634864109412199796030812795554794690666251581638175258059399898533412136730015599390155694930639754604259112806273788686507","name":"40219920836667226846980595968749841377080664270433982561850197901597771608457272395564987365558822976616985553706043669221173208652485993987057610703687751081160705282278075965587059598006922553243119778614657332904443210949022832267152413602769950017196241603290054807405460837019722951966510572795690156337466164027728801433988397914462240536123226066351251559900540164628048512242681757179461134506191038056117474598282470153495944204106741140156797129717000379741035610651103388047392025454843123388667794650989667119908702980140953817053781064048661940525040691050449058181790166453523432033421772638164421440685"},"rctxt":"82211384518226913414705883468527183492640036595481131419387459490531641219140075943570487048949702495964452189878216535351106872901004288800618738558792099255633906919332859771231971995964089510050225371749467514963769062274472125808020747444043470829002911273221886787637565057381007986495929400181408859710441856354570835196999403016572481694010972193591132652075787983469256801030377358546763251500115728919042442285672299538383497028946509014399210152856456151424115321673276206158701693427269192935107281015760959764697283756967072538997471422184132520456634304294669685041869025373789168601981777301930702142717","z":"597630352445077967631363625241310742422278192985165396805410934637833378871905757950406233560527227666603560941363695352019306999881059381981398160064772044355803827657704204341958693819520622597159046224481967205282164000142449330594352761829061889177257816365267888924702641013893644925126158726833651215929952524830180400844722456288706452674387695005404773156271263201912761375013255052598817079064887267420056304601888709527771529363399401864219168554878211931445812982845627741042319366707757767391990264005995464401655063121998656818808854159318505715730088491626077711079755220554530286586303737320102790443"},"revocation":{"g":"1 163FAAD4EB9AA0DF02C19EAC9E91DAFF5C9EEC50B13D59613BB03AC57A864724 1 091121B3A92D2C48A12FB2B6904AFDA9708EAC9CBDF4E9BF988C9071BB4CFEC2 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","g_dash":"1 165E30BED89E08D23FC61685E5F38A65A74342EDF75283BE2E3D7A84D036AC1F 1 07D4894035E05420317656B39B0104E2E6CF372024C8DA9B5E69C05D073DEE17 1 2408458D7F1B790AC27A05055FB7DB562BD51E2597BC3CA5713589716A128647 1 1965D421EA07B38C9C287348BC6AAC53B7FF6E44DE6AC3202F4E62B147019FB3 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","h":"1 1EC742963B128F781DEC49BF60E9D7D627BE75EE6DB6FC7EC0A4388EB6EDDD5E 1 0A98F72733982BF22B83E40FB03AA339C990424498DFF7D227B75F442F089E71 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","h0":"1 1EBE1D3B82473D8435B13E1B22B9B8A3FFD8251F3FACF423CE3CF0A63AF81D6B 1 10890876E36CCB96308ED4C284CDC4B2B014AE67404207E73F287EC86ACFE809 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","h1":"1 21F6A9DA5A812DB4840340A817788CC84EB3C079E07C9908E9637C0A00F2DD56 1 1B1A0005E895B479500E818FC2280B6D06C088788CCF44C07E94B55941EE85F6 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","h2":"1 180ADD04BFF577BCC49D09F97A9C11347C7A0359A0347DE9F138393CAF5F1F93 1 1044FFDF4AC72BBD8B6CC38D918A7C64A441E53D4561A7F5799B68D48E355294 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","htilde":"1 031D6DDE2A7B05F29EFB574973E6D54AE36B79EBDD0599CD5AD2DF93BDBD0661 1 23A358FEC4883CE1EF44EEC1640B4D4C27FF5C7D64E9798BBF2C5A0D414D1AB5 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","h_cap":"1 1536F787B8F6676E31B769543085CC12E484D6B9A136A29E05723DE993E52C78 1 05EF3C2E5AC1F62132E1F62AC715588203902BCBA8D40203606E6834F9065BB5 1 09878859092CA40C7D5AB4D42F6AFC16987CC90C361F161F9383BCD70F0BD7F0 1 2472E732278D393032B33DEDD2F38F84C3D05E109819E97D462D55822FD14DAA 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","u":"1 11523B940E760339BBDA36AE6B2DDA570E9CCC2E9314744FCB6C767DF022C5CF 1 1DADE6A6EBFFB2D329A691DB51C3A862F5FBD7D6BD5E594216E613BE882DBC02 1 0E4DE16A4C7514B7F1E09D1253F79B1D3127FD45AB2E535717BA2912F048D587 1 14A1436619A0C1B02302D66D78CE66027A1AAF44FC6FA0BA605E045526A76B76 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000","pk":"1 0CBF9F57DD1607305F2D6297E85CA9B1D71BCBDCE26E10329224C4EC3C0299D6 1 01EE49B4D07D933E518E9647105408758B1D7E977E66E976E4FE4A2E66F8E734 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8","y":"1 1D2618B8EA3B4E1C5C8D0382940E34DA19425E3CE69C2F6A55F10352ABDF7BD9 1 1F45619B4247A65FDFE577B6AE40474F53E94A83551622859795E71B44920FA0 1 21324A71042C04555C2C89881F297E6E4FB10BA3949B0C3C345B4E5EE4C48100 1 0FAF04961F119E50C72FF39E7E7198EBE46C2217A87A47C6A6A5BFAB6D39E1EE 2 095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 0000000000000000000000000000000000000000000000000000000000000000"}}}"#;
 pub static CRED_DEF_REQ: &str = r#"{"reqId":1523973501515533537,"identifier":"2hoqvcwupRTUNkXn6ArYzs","operation":{"ref":1697,"data":{"primary":{"n":"105439650298099224472719164376596161045251617303168281910159687390977210338964410885966961049370963844109577113880208775752790942154227411982272072507400975072160645232526715327797164824882560229420816283794257946916740324879507082186216013343695279033274312621248936890473037663661888132238733207284797797060688270822706628589089845988721591601541996988579232618488160302787750229030765767569290666862257482182922041829167922591858800108229336185234275773072436922194471127622834341457625330335523970056008991104547541087181274952806206555492380014454388457015641855319853631730897968076440148482735658679509195973229","s":"60953486107303437254858098347112147855961082010529831543925121348034850203115179124876298317648430279679361478189647964535496561070909197001914193094619169504223685018738630978862147708598750398578637328530459556539705596731435008410479736235986865330309807581578290511666662695997158909186371964511139813592297961190597658676380863143274259740580857329319695392485506412357170157397251363465407140774676065910188698127696232880383421764518063443167071100572409790068476991316727051224978393235952176964765376235500533058252877890769219600806170662345836407755729775125521367096071093946270223902491749179598598240007","rms":"74927454739683193439836053944526071131189312874633507963810001217818493539308966842764274972289273602083051643813618047300322726400114830941907959912717178969765593604740488276899078109381885609788262983797782928349065607840985318164148517237153559638617138176498396271661087369503798847309432340923099252340037814242208995379813666930434450480744618281930932894264779695378264474657623220969389369551953686671141939280549610978755889072299795725034713880500651539126902925590569165941662431316992627881973207959883233408720167917915773917493866543309246115826765013674414949313549319840364505706466381318770512814513","r":{"age":"72390727859526948598769839682288390417473671455179114560673764547964449836342363303058321492471438760541948864022003709321744455120254768175223293596449996826595273724760729320481096733331991948430678036676757625299731983178650561399678877389754749619112078949918941391493061822465137113510854600441267452572649498335187207492469387033617053431889624608855193996184402156803341574574511582253212976919203777637748764743645048893306642529788023632615356942928857470649044023895302779761685893773882231358057913710209964999765674027089182383791272684258727765842629428098443892104875788252562359687604843567250668466205","height":"70543652063920845859154008892668197469045463230526467788992453250348274988780226904184587428157474155841194019499287047784141303197449207734445797115252509103173309018363853666184086317902822370905889636406504062785864995352868401033791533530804265504197284651856689740677707908492077183476817196045018228102285116176439517159284338817174978088675004826034802582049787189053101500675154143481246788606272330820806000588773724331753554396126402363549248687990840343827936617495186162626312787826658827586407676203229548227961504603200983549319856619144110464382075502272335203569685652507088214819921524626565054602963","name":"103527417395412238888732428105082823940624567978540514467897305199021605169073958599584563894272332281816005031812861208644363591031241317456947092556057770193455192272881863813069073457043008807897251607948372819249506183283490033835716773361393443808593327403475966545819207102769478475664467796886363393530041838279722696582611568824236467218032392449201020447076530962681867315415363010583982718641987445364085004038446070026640396028415200813760916865414789141811236715013530115289187565164408806242055105072455180019610563498271520111485109375480738234744593620273745298286777388987749644151152269778723686076551","sex":"17617387932224727732199032309657311959653464327684904565665262116333485725099438740880498162786199759421174664300234192351685303611995960534494608414353666969417211685468384065816318438447271709427463460177681252105735773652693767982232188452219217616374492905901821779824332438132561291598134824044704078907285050025183391837108116981759639815190415115487913965779048079428260169329853396984184424485339773406425546899648016014736200060341710952899752230800885097739382453047106269305723853221158955761756922247572130749739616875269361527280465929778539688076462942946174197729389230963102291452423078070870962973619"},"rctxt":"4404315251773590316656822787297959622496576836552374156390520232552296453794129216754026817555078815021049615728085929219015364921450431226188774309944820398259891709060280718051557490803398313760947065810439719714123819895408512016106880827892821233111458571519330166816136132697559648590250134807031359214726816199106700734839255716500676798699117354732874112511742243712694381626446626419345668001579277348432636527446529529969165947242879634940857763624341205912060086708313947345578092110014431367213490048611612229350045080032675964138831029782870097773300884700106789138689225969924786204294929718640563353955","z":"9968790886085769451934843209308305336514488713326446790495438688479920764117604659751410885142587285217733003414264977016448602413241027962458674221915402914676994791840660414497108187556909054907402395620183134598633811934865000705151053208456858874619150881386069361745000551586712840940266532760237223420286143567858962716749251821165461773542587677264987691089537728906914092795339930821335526641158054600048907638649153305825526849538353282672732507140412280831764364628783212076771504299767486342751820345866765313763582577366603726245983948166163461384231297824883993328529043873547398892685961181132345769239"}},"type":"102","signature_type":"CL"},"protocolVersion":1}"#;
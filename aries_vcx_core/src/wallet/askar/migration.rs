@@ -0,0 +1,122 @@
+//! Migration of the content of an indy (vdrtools) wallet into an [AskarWallet].
+
+use aries_askar::kms::{KeyAlg, LocalKey};
+use serde::Deserialize;
+use vdrtools::Locator;
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+use crate::WalletHandle;
+
+use super::{tags_from_map, AskarWallet};
+
+const INDY_KEY_CATEGORY: &str = "Indy::Key";
+
+#[derive(Deserialize)]
+struct IndyKey {
+    verkey: String,
+    signkey: String,
+}
+
+/// Copies every record of the opened indy wallet into `askar_wallet`, in a single transaction.
+///
+/// Indy keys are converted into askar keys, stored under their verkey. All other records,
+/// including the `Indy::Did` records, keep their type, id, value and tags, which is how
+/// [AskarWallet] stores them too. Anoncreds objects of the indy wallet (`Indy::Credential`,
+/// `Indy::MasterSecret`, ...) are copied as they are, but are not converted for use by credx.
+///
+/// Returns the number of migrated records.
+pub async fn migrate_indy_wallet(indy_wallet_handle: WalletHandle, askar_wallet: &AskarWallet) -> VcxCoreResult<u32> {
+    let mut search = Locator::instance()
+        .wallet_controller
+        .search_all_records(indy_wallet_handle)
+        .await?;
+    let mut session = askar_wallet.transaction().await?;
+    let mut migrated = 0;
+
+    while let Some(record) = search.fetch_next_record().await? {
+        let (category, value) = match (record.get_type(), record.get_value()) {
+            (Some(category), Some(value)) => (category, value),
+            _ => {
+                warn!(
+                    "migrate_indy_wallet >> skipping record without type or value: {}",
+                    record.get_id()
+                );
+                continue;
+            }
+        };
+
+        if category == INDY_KEY_CATEGORY {
+            let key: IndyKey = serde_json::from_str(value)?;
+            let local_key = indy_signkey_to_local_key(&key.signkey)?;
+            session.insert_key(&key.verkey, &local_key, None, None, None).await?;
+        } else {
+            let tags = tags_from_map(record.get_tags().cloned().unwrap_or_default());
+            session
+                .insert(category, record.get_id(), value.as_bytes(), Some(&tags), None)
+                .await?;
+        }
+        migrated += 1;
+    }
+    session.commit().await?;
+
+    info!("migrate_indy_wallet >> migrated {} records", migrated);
+    Ok(migrated)
+}
+
+// indy signkeys are the base58 encoded 64 bytes libsodium secret keys: the seed followed by the public key
+fn indy_signkey_to_local_key(signkey: &str) -> VcxCoreResult<LocalKey> {
+    let signkey = bs58::decode(signkey).into_vec().map_err(|err| {
+        AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::NotBase58, format!("Invalid indy signkey: {err}"))
+    })?;
+    let seed = signkey
+        .get(..32)
+        .ok_or_else(|| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, "Indy signkey is too short"))?;
+    Ok(LocalKey::from_secret_bytes(KeyAlg::Ed25519, seed)?)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::Arc;
+
+    use serde_json::Value;
+
+    use crate::indy::utils::test_setup::with_wallet;
+    use crate::wallet::askar::{AskarWallet, AskarWalletConfigBuilder};
+    use crate::wallet::base_wallet::BaseWallet;
+    use crate::wallet::indy_wallet::IndySdkWallet;
+
+    use super::migrate_indy_wallet;
+
+    #[tokio::test]
+    async fn test_migrate_indy_wallet_to_askar() {
+        with_wallet(|wallet_handle| async move {
+            let indy_wallet: Arc<dyn BaseWallet> = Arc::new(IndySdkWallet::new(wallet_handle));
+            let (did, verkey) = indy_wallet.create_and_store_my_did(None, None).await.unwrap();
+            indy_wallet
+                .add_wallet_record("type", "id", "value", Some(r#"{"a":"1","~b":"2"}"#))
+                .await
+                .unwrap();
+
+            let config = AskarWalletConfigBuilder::default()
+                .db_url("sqlite://:memory:")
+                .wallet_key("8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY")
+                .wallet_key_derivation("RAW")
+                .build()
+                .unwrap();
+            let askar_wallet = AskarWallet::create(&config).await.unwrap();
+            let migrated = migrate_indy_wallet(wallet_handle, &askar_wallet).await.unwrap();
+            assert!(migrated >= 3);
+
+            assert_eq!(askar_wallet.key_for_local_did(&did).await.unwrap(), verkey);
+            let signature = askar_wallet.sign(&verkey, b"hello").await.unwrap();
+            assert!(indy_wallet.verify(&verkey, b"hello", &signature).await.unwrap());
+
+            let options = r#"{"retrieveValue": true, "retrieveTags": true}"#;
+            let record: Value =
+                serde_json::from_str(&askar_wallet.get_wallet_record("type", "id", options).await.unwrap()).unwrap();
+            assert_eq!(record["value"], "value");
+            assert_eq!(record["tags"], json!({"a": "1", "~b": "2"}));
+        })
+        .await;
+    }
+}
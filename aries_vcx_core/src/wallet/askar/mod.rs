@@ -0,0 +1,558 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aries_askar::kms::{KeyAlg, LocalKey};
+use aries_askar::{Entry, EntryTag, PassKey, Session, Store, StoreKeyMethod, TagFilter};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+use crate::utils::async_fn_iterator::AsyncFnIterator;
+
+use super::base_wallet::BaseWallet;
+use super::pack;
+use super::utils::{
+    decode_seed, did_from_verkey, parse_tags, record_to_json, verkey_to_local_key, DidRecord, RecordOptions,
+};
+
+#[cfg(feature = "vdrtools")]
+pub mod migration;
+
+// record categories shared with indy wallets, so that migrated DIDs are readable as they are
+const DID_CATEGORY: &str = "Indy::Did";
+const TEMPORARY_DID_CATEGORY: &str = "Indy::TemporaryDid";
+
+#[derive(Clone, Debug, Default, Builder, Serialize, Deserialize)]
+#[builder(setter(into, strip_option), default)]
+pub struct AskarWalletConfig {
+    /// SQLite database url, e.g. `sqlite://path/to/wallet.db` or `sqlite://:memory:`
+    pub db_url: String,
+    pub wallet_key: String,
+    /// Same values as for indy wallets: `ARGON2I_MOD` (used if empty), `ARGON2I_INT` or `RAW`
+    pub wallet_key_derivation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+impl AskarWalletConfig {
+    fn key_method(&self) -> VcxCoreResult<StoreKeyMethod> {
+        let uri = match self.wallet_key_derivation.as_str() {
+            "" | "ARGON2I_MOD" => "kdf:argon2i:mod",
+            "ARGON2I_INT" => "kdf:argon2i:int",
+            "RAW" => "raw",
+            other => {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::InvalidConfiguration,
+                    format!("Unsupported wallet key derivation method: {other}"),
+                ))
+            }
+        };
+        Ok(StoreKeyMethod::parse_uri(uri)?)
+    }
+}
+
+/// [BaseWallet] implementation backed by an aries-askar store.
+///
+/// Records, tags and queries follow the indy wallet conventions: tag names prefixed with `~` are
+/// stored unencrypted, and queries are WQL.
+pub struct AskarWallet {
+    store: Store,
+    profile: Option<String>,
+}
+
+impl fmt::Debug for AskarWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AskarWallet").field("profile", &self.profile).finish()
+    }
+}
+
+impl AskarWallet {
+    /// Provisions a new store as described by `config`, failing if it already exists.
+    pub async fn create(config: &AskarWalletConfig) -> VcxCoreResult<Self> {
+        let store = Store::provision(
+            &config.db_url,
+            config.key_method()?,
+            PassKey::from(config.wallet_key.as_str()),
+            config.profile.clone(),
+            false,
+        )
+        .await?;
+
+        Ok(AskarWallet {
+            store,
+            profile: config.profile.clone(),
+        })
+    }
+
+    pub async fn open(config: &AskarWalletConfig) -> VcxCoreResult<Self> {
+        let store = Store::open(
+            &config.db_url,
+            Some(config.key_method()?),
+            PassKey::from(config.wallet_key.as_str()),
+            config.profile.clone(),
+        )
+        .await?;
+
+        Ok(AskarWallet {
+            store,
+            profile: config.profile.clone(),
+        })
+    }
+
+    pub async fn close(self) -> VcxCoreResult<()> {
+        Ok(self.store.close().await?)
+    }
+
+    async fn session(&self) -> VcxCoreResult<Session> {
+        Ok(self.store.session(self.profile.clone()).await?)
+    }
+
+    async fn transaction(&self) -> VcxCoreResult<Session> {
+        Ok(self.store.transaction(self.profile.clone()).await?)
+    }
+
+    async fn fetch_record(session: &mut Session, category: &str, name: &str, for_update: bool) -> VcxCoreResult<Entry> {
+        session.fetch(category, name, for_update).await?.ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletRecordNotFound,
+                format!("Wallet record not found, type: {category}, id: {name}"),
+            )
+        })
+    }
+
+    async fn fetch_did(session: &mut Session, category: &str, did: &str) -> VcxCoreResult<DidRecord> {
+        let entry = Self::fetch_record(session, category, did, false).await?;
+        Ok(serde_json::from_slice(&entry.value)?)
+    }
+
+    async fn upsert_did(session: &mut Session, category: &str, did: &DidRecord) -> VcxCoreResult<()> {
+        let value = serde_json::to_vec(did)?;
+        if session.fetch(category, &did.did, true).await?.is_some() {
+            session.replace(category, &did.did, &value, None, None).await?;
+        } else {
+            session.insert(category, &did.did, &value, None, None).await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_local_key(session: &mut Session, verkey: &str) -> VcxCoreResult<LocalKey> {
+        let key_entry = session.fetch_key(verkey, false).await?.ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletRecordNotFound,
+                format!("Key not found in wallet: {verkey}"),
+            )
+        })?;
+        Ok(key_entry.load_local_key()?)
+    }
+
+    /// Stores a new ed25519 key under its verkey, returns the verkey along with the key.
+    async fn insert_new_key(session: &mut Session, seed: Option<&str>) -> VcxCoreResult<(String, LocalKey)> {
+        let key = match seed {
+            Some(seed) => LocalKey::from_secret_bytes(KeyAlg::Ed25519, &decode_seed(seed)?)?,
+            None => LocalKey::generate(KeyAlg::Ed25519, false)?,
+        };
+        let verkey = bs58::encode(&key.to_public_bytes()?[..]).into_string();
+        if session.fetch_key(&verkey, false).await?.is_none() {
+            session.insert_key(&verkey, &key, None, None, None).await?;
+        }
+        Ok((verkey, key))
+    }
+
+    async fn update_record_tags(
+        &self,
+        xtype: &str,
+        id: &str,
+        update: impl FnOnce(Vec<EntryTag>) -> VcxCoreResult<Vec<EntryTag>> + Send,
+    ) -> VcxCoreResult<()> {
+        let mut session = self.transaction().await?;
+        let entry = Self::fetch_record(&mut session, xtype, id, true).await?;
+        let tags = update(entry.tags)?;
+        session.replace(xtype, id, &entry.value, Some(&tags), None).await?;
+        Ok(session.commit().await?)
+    }
+}
+
+pub(crate) fn tags_from_map(tags: HashMap<String, String>) -> Vec<EntryTag> {
+    tags.into_iter()
+        .map(|(name, value)| match name.strip_prefix('~') {
+            Some(name) => EntryTag::Plaintext(name.to_string(), value),
+            None => EntryTag::Encrypted(name, value),
+        })
+        .collect()
+}
+
+fn tags_to_map(tags: &[EntryTag]) -> HashMap<String, String> {
+    tags.iter()
+        .map(|tag| match tag {
+            EntryTag::Plaintext(name, value) => (format!("~{name}"), value.clone()),
+            EntryTag::Encrypted(name, value) => (name.clone(), value.clone()),
+        })
+        .collect()
+}
+
+fn tag_name(tag: &EntryTag) -> String {
+    match tag {
+        EntryTag::Plaintext(name, _) => format!("~{name}"),
+        EntryTag::Encrypted(name, _) => name.clone(),
+    }
+}
+
+fn entry_to_json(xtype: &str, entry: &Entry, options: &RecordOptions) -> VcxCoreResult<String> {
+    record_to_json(xtype, &entry.name, &entry.value, tags_to_map(&entry.tags), options)
+}
+
+#[async_trait]
+impl BaseWallet for AskarWallet {
+    async fn create_and_store_my_did(
+        &self,
+        seed: Option<&str>,
+        method_name: Option<&str>,
+    ) -> VcxCoreResult<(String, String)> {
+        let mut session = self.transaction().await?;
+        let (verkey, key) = Self::insert_new_key(&mut session, seed).await?;
+
+        let did = did_from_verkey(&key.to_public_bytes()?, method_name);
+
+        if let Some(entry) = session.fetch(DID_CATEGORY, &did, false).await? {
+            let existing: DidRecord = serde_json::from_slice(&entry.value)?;
+            if existing.verkey != verkey {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::DuplicationDid,
+                    format!("DID {did} already exists in the wallet with a different verkey"),
+                ));
+            }
+        } else {
+            let record = DidRecord {
+                did: did.clone(),
+                verkey: verkey.clone(),
+            };
+            session
+                .insert(DID_CATEGORY, &did, &serde_json::to_vec(&record)?, None, None)
+                .await?;
+        }
+        session.commit().await?;
+
+        Ok((did, verkey))
+    }
+
+    async fn key_for_local_did(&self, did: &str) -> VcxCoreResult<String> {
+        let mut session = self.session().await?;
+        Ok(Self::fetch_did(&mut session, DID_CATEGORY, did).await?.verkey)
+    }
+
+    async fn replace_did_keys_start(&self, target_did: &str) -> VcxCoreResult<String> {
+        let mut session = self.transaction().await?;
+        Self::fetch_did(&mut session, DID_CATEGORY, target_did).await?;
+
+        let (verkey, _) = Self::insert_new_key(&mut session, None).await?;
+        let temporary_did = DidRecord {
+            did: target_did.to_string(),
+            verkey: verkey.clone(),
+        };
+        Self::upsert_did(&mut session, TEMPORARY_DID_CATEGORY, &temporary_did).await?;
+        session.commit().await?;
+
+        Ok(verkey)
+    }
+
+    async fn replace_did_keys_apply(&self, target_did: &str) -> VcxCoreResult<()> {
+        let mut session = self.transaction().await?;
+        let temporary_did = Self::fetch_did(&mut session, TEMPORARY_DID_CATEGORY, target_did).await?;
+
+        Self::upsert_did(&mut session, DID_CATEGORY, &temporary_did).await?;
+        session.remove(TEMPORARY_DID_CATEGORY, target_did).await?;
+
+        Ok(session.commit().await?)
+    }
+
+    async fn add_wallet_record(
+        &self,
+        xtype: &str,
+        id: &str,
+        value: &str,
+        tags_json: Option<&str>,
+    ) -> VcxCoreResult<()> {
+        let tags = tags_json.map(parse_tags).transpose()?.map(tags_from_map);
+        let mut session = self.session().await?;
+        Ok(session
+            .insert(xtype, id, value.as_bytes(), tags.as_deref(), None)
+            .await?)
+    }
+
+    async fn get_wallet_record(&self, xtype: &str, id: &str, options_json: &str) -> VcxCoreResult<String> {
+        let options: RecordOptions = serde_json::from_str(options_json)?;
+        let mut session = self.session().await?;
+        let entry = Self::fetch_record(&mut session, xtype, id, false).await?;
+
+        entry_to_json(xtype, &entry, &options)
+    }
+
+    async fn delete_wallet_record(&self, xtype: &str, id: &str) -> VcxCoreResult<()> {
+        let mut session = self.session().await?;
+        Ok(session.remove(xtype, id).await?)
+    }
+
+    async fn update_wallet_record_value(&self, xtype: &str, id: &str, value: &str) -> VcxCoreResult<()> {
+        let mut session = self.transaction().await?;
+        let entry = Self::fetch_record(&mut session, xtype, id, true).await?;
+        session
+            .replace(xtype, id, value.as_bytes(), Some(&entry.tags), None)
+            .await?;
+        Ok(session.commit().await?)
+    }
+
+    async fn add_wallet_record_tags(&self, xtype: &str, id: &str, tags_json: &str) -> VcxCoreResult<()> {
+        let new_tags = tags_from_map(parse_tags(tags_json)?);
+        self.update_record_tags(xtype, id, |tags| {
+            let new_names: Vec<String> = new_tags.iter().map(tag_name).collect();
+            let mut tags: Vec<EntryTag> = tags
+                .into_iter()
+                .filter(|tag| !new_names.contains(&tag_name(tag)))
+                .collect();
+            tags.extend(new_tags);
+            Ok(tags)
+        })
+        .await
+    }
+
+    async fn update_wallet_record_tags(&self, xtype: &str, id: &str, tags_json: &str) -> VcxCoreResult<()> {
+        let new_tags = tags_from_map(parse_tags(tags_json)?);
+        self.update_record_tags(xtype, id, |_| Ok(new_tags)).await
+    }
+
+    async fn delete_wallet_record_tags(&self, xtype: &str, id: &str, tag_names: &str) -> VcxCoreResult<()> {
+        let tag_names: Vec<String> = serde_json::from_str(tag_names)?;
+        self.update_record_tags(xtype, id, |tags| {
+            Ok(tags
+                .into_iter()
+                .filter(|tag| !tag_names.contains(&tag_name(tag)))
+                .collect())
+        })
+        .await
+    }
+
+    async fn iterate_wallet_records(
+        &self,
+        xtype: &str,
+        query: &str,
+        options: &str,
+    ) -> VcxCoreResult<Box<dyn AsyncFnIterator<Item = VcxCoreResult<String>>>> {
+        let options: RecordOptions = serde_json::from_str(options)?;
+        if !options.retrieve_records {
+            return Ok(Box::new(AskarRecordIterator::new(vec![])));
+        }
+
+        let tag_filter = TagFilter::from_str(query)?;
+        let mut session = self.session().await?;
+        let records = session
+            .fetch_all(xtype, Some(tag_filter), None, false)
+            .await?
+            .iter()
+            .map(|entry| entry_to_json(xtype, entry, &options))
+            .collect::<VcxCoreResult<Vec<_>>>()?;
+
+        Ok(Box::new(AskarRecordIterator::new(records)))
+    }
+
+    async fn sign(&self, my_vk: &str, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let mut session = self.session().await?;
+        let key = Self::fetch_local_key(&mut session, my_vk).await?;
+        Ok(key.sign_message(msg, None)?)
+    }
+
+    async fn verify(&self, vk: &str, msg: &[u8], signature: &[u8]) -> VcxCoreResult<bool> {
+        Ok(verkey_to_local_key(vk)?.verify_signature(msg, signature, None)?)
+    }
+
+    async fn pack_message(&self, sender_vk: Option<&str>, receiver_keys: &str, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let receiver_keys: Vec<String> = serde_json::from_str(receiver_keys).map_err(|_| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidJson,
+                "Invalid RecipientKeys has been passed",
+            )
+        })?;
+        if receiver_keys.is_empty() {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidLibindyParam,
+                "Empty RecipientKeys has been passed",
+            ));
+        }
+
+        let sender = match sender_vk {
+            Some(sender_vk) => {
+                let mut session = self.session().await?;
+                Some((sender_vk, Self::fetch_local_key(&mut session, sender_vk).await?))
+            }
+            None => None,
+        };
+
+        pack::pack_message(sender.as_ref().map(|(vk, key)| (*vk, key)), &receiver_keys, msg)
+    }
+
+    async fn unpack_message(&self, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let jwe: pack::Jwe = serde_json::from_slice(msg)?;
+        let protected = jwe.decode_protected()?;
+
+        let mut session = self.session().await?;
+        for recipient in protected.recipients.iter() {
+            if let Some(key_entry) = session.fetch_key(&recipient.header.kid, false).await? {
+                let recipient_key = key_entry.load_local_key()?;
+                return pack::unpack_message(&jwe, &protected, recipient, &recipient_key);
+            }
+        }
+
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::WalletRecordNotFound,
+            "None of the message recipient keys is stored in the wallet",
+        ))
+    }
+}
+
+struct AskarRecordIterator {
+    records: std::vec::IntoIter<String>,
+}
+
+impl AskarRecordIterator {
+    fn new(records: Vec<String>) -> Self {
+        AskarRecordIterator {
+            records: records.into_iter(),
+        }
+    }
+}
+
+/// Records are fetched from the store upfront, so this only hands them out one by one.
+#[async_trait]
+impl AsyncFnIterator for AskarRecordIterator {
+    type Item = VcxCoreResult<String>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.records.next().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use serde_json::Value;
+
+    use crate::errors::error::AriesVcxCoreErrorKind;
+    use crate::wallet::base_wallet::{AsyncFnIteratorCollect, BaseWallet};
+
+    use super::{AskarWallet, AskarWalletConfigBuilder};
+
+    async fn create_wallet() -> AskarWallet {
+        let config = AskarWalletConfigBuilder::default()
+            .db_url("sqlite://:memory:")
+            .wallet_key("8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY")
+            .wallet_key_derivation("RAW")
+            .build()
+            .unwrap();
+        AskarWallet::create(&config).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_did_sign_and_verify() {
+        let wallet = create_wallet().await;
+
+        let (did, verkey) = wallet
+            .create_and_store_my_did(Some("000000000000000000000000Trustee1"), None)
+            .await
+            .unwrap();
+        assert_eq!(did, "V4SGRU86Z58d6TV7PBUe6f");
+        assert_eq!(verkey, "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL");
+        assert_eq!(wallet.key_for_local_did(&did).await.unwrap(), verkey);
+
+        let signature = wallet.sign(&verkey, b"hello").await.unwrap();
+        assert!(wallet.verify(&verkey, b"hello", &signature).await.unwrap());
+        assert!(!wallet.verify(&verkey, b"other", &signature).await.unwrap());
+
+        let new_verkey = wallet.replace_did_keys_start(&did).await.unwrap();
+        assert_eq!(wallet.key_for_local_did(&did).await.unwrap(), verkey);
+        wallet.replace_did_keys_apply(&did).await.unwrap();
+        assert_eq!(wallet.key_for_local_did(&did).await.unwrap(), new_verkey);
+    }
+
+    #[tokio::test]
+    async fn test_wallet_records() {
+        let wallet = create_wallet().await;
+        let options = r#"{"retrieveType": true, "retrieveValue": true, "retrieveTags": true}"#;
+
+        wallet
+            .add_wallet_record("type", "id1", "value1", Some(r#"{"a":"1","~b":"2"}"#))
+            .await
+            .unwrap();
+        wallet
+            .add_wallet_record("type", "id2", "value2", Some(r#"{"a":"3"}"#))
+            .await
+            .unwrap();
+        let err = wallet
+            .add_wallet_record("type", "id1", "value", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::DuplicationWalletRecord);
+
+        wallet
+            .update_wallet_record_value("type", "id1", "value3")
+            .await
+            .unwrap();
+        wallet
+            .add_wallet_record_tags("type", "id1", r#"{"a":"4","c":"5"}"#)
+            .await
+            .unwrap();
+        wallet
+            .delete_wallet_record_tags("type", "id1", r#"["c"]"#)
+            .await
+            .unwrap();
+        let record: Value =
+            serde_json::from_str(&wallet.get_wallet_record("type", "id1", options).await.unwrap()).unwrap();
+        assert_eq!(
+            record,
+            json!({"type": "type", "id": "id1", "value": "value3", "tags": {"a": "4", "~b": "2"}})
+        );
+
+        let records = wallet
+            .iterate_wallet_records("type", r#"{"~b":"2"}"#, "{}")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        let record: Value = serde_json::from_str(&records[0]).unwrap();
+        assert_eq!(record["id"], "id1");
+        assert_eq!(record["tags"], Value::Null);
+
+        wallet.delete_wallet_record("type", "id1").await.unwrap();
+        let err = wallet.get_wallet_record("type", "id1", options).await.unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::WalletRecordNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_pack_and_unpack_message() {
+        let sender = create_wallet().await;
+        let recipient = create_wallet().await;
+        let (_, sender_vk) = sender.create_and_store_my_did(None, None).await.unwrap();
+        let (_, recipient_vk) = recipient.create_and_store_my_did(None, None).await.unwrap();
+        let receiver_keys = json!([recipient_vk]).to_string();
+
+        let packed = sender
+            .pack_message(Some(&sender_vk), &receiver_keys, b"authcrypted")
+            .await
+            .unwrap();
+        let unpacked: Value = serde_json::from_slice(&recipient.unpack_message(&packed).await.unwrap()).unwrap();
+        assert_eq!(
+            unpacked,
+            json!({"message": "authcrypted", "recipient_verkey": recipient_vk, "sender_verkey": sender_vk})
+        );
+
+        let packed = sender.pack_message(None, &receiver_keys, b"anoncrypted").await.unwrap();
+        let unpacked: Value = serde_json::from_slice(&recipient.unpack_message(&packed).await.unwrap()).unwrap();
+        assert_eq!(
+            unpacked,
+            json!({"message": "anoncrypted", "recipient_verkey": recipient_vk})
+        );
+
+        let err = sender.unpack_message(&packed).await.unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::WalletRecordNotFound);
+    }
+}
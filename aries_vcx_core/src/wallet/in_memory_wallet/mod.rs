@@ -0,0 +1,477 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::RwLock;
+
+use aries_askar::kms::{KeyAlg, LocalKey};
+use async_trait::async_trait;
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+use crate::utils::async_fn_iterator::AsyncFnIterator;
+
+use self::wql::Query;
+use super::base_wallet::BaseWallet;
+use super::pack;
+use super::utils::{
+    decode_seed, did_from_verkey, parse_tags, record_to_json, verkey_to_local_key, DidRecord, RecordOptions,
+};
+
+mod wql;
+
+#[derive(Clone, Debug)]
+struct Record {
+    value: String,
+    tags: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct InMemoryWalletState {
+    dids: HashMap<String, DidRecord>,
+    temporary_dids: HashMap<String, DidRecord>,
+    // secret bytes of the ed25519 keys, by verkey
+    keys: HashMap<String, Vec<u8>>,
+    // records by type, then by id
+    records: HashMap<String, HashMap<String, Record>>,
+}
+
+// Ed25519 key, which is only kept in the wallet once stored
+struct GeneratedKey {
+    verkey: String,
+    public_key: Vec<u8>,
+    secret: Vec<u8>,
+}
+
+impl GeneratedKey {
+    fn new(seed: Option<&str>) -> VcxCoreResult<Self> {
+        let key = match seed {
+            Some(seed) => LocalKey::from_secret_bytes(KeyAlg::Ed25519, &decode_seed(seed)?)?,
+            None => LocalKey::generate(KeyAlg::Ed25519, false)?,
+        };
+        let public_key = key.to_public_bytes()?.to_vec();
+        Ok(Self {
+            verkey: bs58::encode(&public_key).into_string(),
+            public_key,
+            secret: key.to_secret_bytes()?.to_vec(),
+        })
+    }
+}
+
+impl InMemoryWalletState {
+    fn store_key(&mut self, key: GeneratedKey) -> String {
+        self.keys.insert(key.verkey.clone(), key.secret);
+        key.verkey
+    }
+
+    fn local_key(&self, verkey: &str) -> VcxCoreResult<Option<LocalKey>> {
+        self.keys
+            .get(verkey)
+            .map(|secret| Ok(LocalKey::from_secret_bytes(KeyAlg::Ed25519, secret)?))
+            .transpose()
+    }
+
+    fn record_mut(&mut self, xtype: &str, id: &str) -> VcxCoreResult<&mut Record> {
+        self.records
+            .get_mut(xtype)
+            .and_then(|records| records.get_mut(id))
+            .ok_or_else(|| record_not_found(xtype, id))
+    }
+}
+
+fn record_not_found(xtype: &str, id: &str) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::WalletRecordNotFound,
+        format!("Wallet record not found, type: {xtype}, id: {id}"),
+    )
+}
+
+fn did_not_found(did: &str) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::WalletRecordNotFound,
+        format!("DID not found in wallet: {did}"),
+    )
+}
+
+/// [BaseWallet] implementation keeping DIDs, keys and records in memory, for tests and ephemeral
+/// agents. Keys are real ed25519 keys, and packed messages are interoperable with indy wallets.
+///
+/// The content of the wallet is lost when it is dropped.
+#[derive(Default)]
+pub struct InMemoryWallet {
+    state: RwLock<InMemoryWalletState>,
+}
+
+impl InMemoryWallet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for InMemoryWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryWallet").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl BaseWallet for InMemoryWallet {
+    async fn create_and_store_my_did(
+        &self,
+        seed: Option<&str>,
+        method_name: Option<&str>,
+    ) -> VcxCoreResult<(String, String)> {
+        let key = GeneratedKey::new(seed)?;
+        let did = did_from_verkey(&key.public_key, method_name);
+
+        let mut state = self.state.write()?;
+        if let Some(existing) = state.dids.get(&did) {
+            if existing.verkey != key.verkey {
+                return Err(AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::DuplicationDid,
+                    format!("DID {did} already exists in the wallet with a different verkey"),
+                ));
+            }
+        }
+        let verkey = state.store_key(key);
+        state.dids.insert(
+            did.clone(),
+            DidRecord {
+                did: did.clone(),
+                verkey: verkey.clone(),
+            },
+        );
+
+        Ok((did, verkey))
+    }
+
+    async fn key_for_local_did(&self, did: &str) -> VcxCoreResult<String> {
+        let state = self.state.read()?;
+        state
+            .dids
+            .get(did)
+            .map(|did| did.verkey.clone())
+            .ok_or_else(|| did_not_found(did))
+    }
+
+    async fn replace_did_keys_start(&self, target_did: &str) -> VcxCoreResult<String> {
+        let mut state = self.state.write()?;
+        if !state.dids.contains_key(target_did) {
+            return Err(did_not_found(target_did));
+        }
+
+        let verkey = state.store_key(GeneratedKey::new(None)?);
+        state.temporary_dids.insert(
+            target_did.to_string(),
+            DidRecord {
+                did: target_did.to_string(),
+                verkey: verkey.clone(),
+            },
+        );
+
+        Ok(verkey)
+    }
+
+    async fn replace_did_keys_apply(&self, target_did: &str) -> VcxCoreResult<()> {
+        let mut state = self.state.write()?;
+        let temporary_did = state
+            .temporary_dids
+            .remove(target_did)
+            .ok_or_else(|| did_not_found(target_did))?;
+        state.dids.insert(target_did.to_string(), temporary_did);
+
+        Ok(())
+    }
+
+    async fn add_wallet_record(
+        &self,
+        xtype: &str,
+        id: &str,
+        value: &str,
+        tags_json: Option<&str>,
+    ) -> VcxCoreResult<()> {
+        let tags = tags_json.map(parse_tags).transpose()?.unwrap_or_default();
+        let mut state = self.state.write()?;
+        let records = state.records.entry(xtype.to_string()).or_default();
+        if records.contains_key(id) {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::DuplicationWalletRecord,
+                format!("Wallet record already exists, type: {xtype}, id: {id}"),
+            ));
+        }
+        records.insert(
+            id.to_string(),
+            Record {
+                value: value.to_string(),
+                tags,
+            },
+        );
+
+        Ok(())
+    }
+
+    async fn get_wallet_record(&self, xtype: &str, id: &str, options_json: &str) -> VcxCoreResult<String> {
+        let options: RecordOptions = serde_json::from_str(options_json)?;
+        let state = self.state.read()?;
+        let record = state
+            .records
+            .get(xtype)
+            .and_then(|records| records.get(id))
+            .ok_or_else(|| record_not_found(xtype, id))?;
+
+        record_to_json(xtype, id, record.value.as_bytes(), record.tags.clone(), &options)
+    }
+
+    async fn delete_wallet_record(&self, xtype: &str, id: &str) -> VcxCoreResult<()> {
+        let mut state = self.state.write()?;
+        state
+            .records
+            .get_mut(xtype)
+            .and_then(|records| records.remove(id))
+            .map(|_| ())
+            .ok_or_else(|| record_not_found(xtype, id))
+    }
+
+    async fn update_wallet_record_value(&self, xtype: &str, id: &str, value: &str) -> VcxCoreResult<()> {
+        let mut state = self.state.write()?;
+        state.record_mut(xtype, id)?.value = value.to_string();
+        Ok(())
+    }
+
+    async fn add_wallet_record_tags(&self, xtype: &str, id: &str, tags_json: &str) -> VcxCoreResult<()> {
+        let tags = parse_tags(tags_json)?;
+        let mut state = self.state.write()?;
+        state.record_mut(xtype, id)?.tags.extend(tags);
+        Ok(())
+    }
+
+    async fn update_wallet_record_tags(&self, xtype: &str, id: &str, tags_json: &str) -> VcxCoreResult<()> {
+        let tags = parse_tags(tags_json)?;
+        let mut state = self.state.write()?;
+        state.record_mut(xtype, id)?.tags = tags;
+        Ok(())
+    }
+
+    async fn delete_wallet_record_tags(&self, xtype: &str, id: &str, tag_names: &str) -> VcxCoreResult<()> {
+        let tag_names: Vec<String> = serde_json::from_str(tag_names)?;
+        let mut state = self.state.write()?;
+        let record = state.record_mut(xtype, id)?;
+        for tag_name in tag_names.iter() {
+            record.tags.remove(tag_name);
+        }
+        Ok(())
+    }
+
+    async fn iterate_wallet_records(
+        &self,
+        xtype: &str,
+        query: &str,
+        options: &str,
+    ) -> VcxCoreResult<Box<dyn AsyncFnIterator<Item = VcxCoreResult<String>>>> {
+        let options: RecordOptions = serde_json::from_str(options)?;
+        let query = Query::parse(query)?;
+        let state = self.state.read()?;
+
+        let records = match (options.retrieve_records, state.records.get(xtype)) {
+            (true, Some(records)) => records
+                .iter()
+                .filter(|(_, record)| query.matches(&record.tags))
+                .map(|(id, record)| record_to_json(xtype, id, record.value.as_bytes(), record.tags.clone(), &options))
+                .collect::<VcxCoreResult<Vec<_>>>()?,
+            _ => vec![],
+        };
+
+        Ok(Box::new(InMemoryRecordIterator {
+            records: records.into_iter(),
+        }))
+    }
+
+    async fn sign(&self, my_vk: &str, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let key = self.state.read()?.local_key(my_vk)?.ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletRecordNotFound,
+                format!("Key not found in wallet: {my_vk}"),
+            )
+        })?;
+        Ok(key.sign_message(msg, None)?)
+    }
+
+    async fn verify(&self, vk: &str, msg: &[u8], signature: &[u8]) -> VcxCoreResult<bool> {
+        Ok(verkey_to_local_key(vk)?.verify_signature(msg, signature, None)?)
+    }
+
+    async fn pack_message(&self, sender_vk: Option<&str>, receiver_keys: &str, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let receiver_keys: Vec<String> = serde_json::from_str(receiver_keys).map_err(|_| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidJson,
+                "Invalid RecipientKeys has been passed",
+            )
+        })?;
+        if receiver_keys.is_empty() {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidLibindyParam,
+                "Empty RecipientKeys has been passed",
+            ));
+        }
+
+        let sender = match sender_vk {
+            Some(sender_vk) => {
+                let sender_key = self.state.read()?.local_key(sender_vk)?.ok_or_else(|| {
+                    AriesVcxCoreError::from_msg(
+                        AriesVcxCoreErrorKind::WalletRecordNotFound,
+                        format!("Key not found in wallet: {sender_vk}"),
+                    )
+                })?;
+                Some((sender_vk, sender_key))
+            }
+            None => None,
+        };
+
+        pack::pack_message(sender.as_ref().map(|(vk, key)| (*vk, key)), &receiver_keys, msg)
+    }
+
+    async fn unpack_message(&self, msg: &[u8]) -> VcxCoreResult<Vec<u8>> {
+        let jwe: pack::Jwe = serde_json::from_slice(msg)?;
+        let protected = jwe.decode_protected()?;
+
+        let state = self.state.read()?;
+        for recipient in protected.recipients.iter() {
+            if let Some(recipient_key) = state.local_key(&recipient.header.kid)? {
+                return pack::unpack_message(&jwe, &protected, recipient, &recipient_key);
+            }
+        }
+
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::WalletRecordNotFound,
+            "None of the message recipient keys is stored in the wallet",
+        ))
+    }
+}
+
+struct InMemoryRecordIterator {
+    records: std::vec::IntoIter<String>,
+}
+
+#[async_trait]
+impl AsyncFnIterator for InMemoryRecordIterator {
+    type Item = VcxCoreResult<String>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.records.next().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use serde_json::Value;
+
+    use crate::errors::error::AriesVcxCoreErrorKind;
+    use crate::wallet::base_wallet::{AsyncFnIteratorCollect, BaseWallet};
+
+    use super::InMemoryWallet;
+
+    #[tokio::test]
+    async fn test_create_did_sign_and_verify() {
+        let wallet = InMemoryWallet::new();
+
+        let (did, verkey) = wallet
+            .create_and_store_my_did(Some("000000000000000000000000Trustee1"), None)
+            .await
+            .unwrap();
+        assert_eq!(did, "V4SGRU86Z58d6TV7PBUe6f");
+        assert_eq!(verkey, "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL");
+
+        let signature = wallet.sign(&verkey, b"hello").await.unwrap();
+        assert!(wallet.verify(&verkey, b"hello", &signature).await.unwrap());
+        assert!(!wallet.verify(&verkey, b"other", &signature).await.unwrap());
+
+        let new_verkey = wallet.replace_did_keys_start(&did).await.unwrap();
+        wallet.replace_did_keys_apply(&did).await.unwrap();
+        assert_eq!(wallet.key_for_local_did(&did).await.unwrap(), new_verkey);
+    }
+
+    #[tokio::test]
+    async fn test_search_wallet_records() {
+        let wallet = InMemoryWallet::new();
+        wallet
+            .add_wallet_record("type", "id1", "value1", Some(r#"{"name":"alice","~age":"28"}"#))
+            .await
+            .unwrap();
+        wallet
+            .add_wallet_record("type", "id2", "value2", Some(r#"{"name":"bob","~age":"35"}"#))
+            .await
+            .unwrap();
+        wallet.add_wallet_record("other", "id3", "value3", None).await.unwrap();
+
+        let records = wallet
+            .iterate_wallet_records("type", r#"{"~age": {"$gt": "30"}}"#, r#"{"retrieveTags": true}"#)
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        let records: Vec<Value> = records.iter().map(|r| serde_json::from_str(r).unwrap()).collect();
+        assert_eq!(
+            records,
+            vec![json!({"type": null, "id": "id2", "value": "value2", "tags": {"name": "bob", "~age": "35"}})]
+        );
+
+        wallet.delete_wallet_record("type", "id2").await.unwrap();
+        let err = wallet
+            .update_wallet_record_value("type", "id2", "value")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), AriesVcxCoreErrorKind::WalletRecordNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_pack_and_unpack_message() {
+        let sender = InMemoryWallet::new();
+        let recipient = InMemoryWallet::new();
+        let (_, sender_vk) = sender.create_and_store_my_did(None, None).await.unwrap();
+        let (_, recipient_vk) = recipient.create_and_store_my_did(None, None).await.unwrap();
+        let receiver_keys = json!([recipient_vk]).to_string();
+
+        let packed = sender
+            .pack_message(Some(&sender_vk), &receiver_keys, b"authcrypted")
+            .await
+            .unwrap();
+        let unpacked: Value = serde_json::from_slice(&recipient.unpack_message(&packed).await.unwrap()).unwrap();
+        assert_eq!(unpacked["message"], "authcrypted");
+        assert_eq!(unpacked["sender_verkey"], sender_vk);
+
+        let packed = sender.pack_message(None, &receiver_keys, b"anoncrypted").await.unwrap();
+        let unpacked: Value = serde_json::from_slice(&recipient.unpack_message(&packed).await.unwrap()).unwrap();
+        assert_eq!(unpacked["message"], "anoncrypted");
+        assert_eq!(unpacked.get("sender_verkey"), None);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "vdrtools")]
+    async fn test_pack_message_interoperates_with_indy_wallet() {
+        use crate::indy::utils::test_setup::with_wallet;
+        use crate::wallet::indy_wallet::IndySdkWallet;
+
+        with_wallet(|wallet_handle| async move {
+            let indy_wallet = IndySdkWallet::new(wallet_handle);
+            let in_memory_wallet = InMemoryWallet::new();
+            let (_, indy_vk) = indy_wallet.create_and_store_my_did(None, None).await.unwrap();
+            let (_, in_memory_vk) = in_memory_wallet.create_and_store_my_did(None, None).await.unwrap();
+
+            let packed = indy_wallet
+                .pack_message(Some(&indy_vk), &json!([in_memory_vk]).to_string(), b"from indy")
+                .await
+                .unwrap();
+            let unpacked: Value =
+                serde_json::from_slice(&in_memory_wallet.unpack_message(&packed).await.unwrap()).unwrap();
+            assert_eq!(unpacked["message"], "from indy");
+            assert_eq!(unpacked["sender_verkey"], indy_vk);
+
+            let packed = in_memory_wallet
+                .pack_message(Some(&in_memory_vk), &json!([indy_vk]).to_string(), b"from memory")
+                .await
+                .unwrap();
+            let unpacked: Value = serde_json::from_slice(&indy_wallet.unpack_message(&packed).await.unwrap()).unwrap();
+            assert_eq!(unpacked["message"], "from memory");
+            assert_eq!(unpacked["sender_verkey"], in_memory_vk);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,175 @@
+//! Evaluation of the WQL (wallet query language) queries of indy wallets against record tags.
+//!
+//! Supported: `$and`, `$or`, `$not`, tag equality and the `$neq`, `$gt`, `$gte`, `$lt`, `$lte`,
+//! `$like` and `$in` operators. Comparisons are done on the string values, as for indy wallets.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Query {
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+    Eq(String, String),
+    Neq(String, String),
+    Gt(String, String),
+    Gte(String, String),
+    Lt(String, String),
+    Lte(String, String),
+    Like(String, String),
+    In(String, Vec<String>),
+}
+
+fn invalid_query(msg: String) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, msg)
+}
+
+impl Query {
+    pub fn parse(query: &str) -> VcxCoreResult<Self> {
+        match serde_json::from_str(query)? {
+            Value::Object(map) => Self::parse_object(map),
+            _ => Err(invalid_query(format!("WQL query must be a JSON object: {query}"))),
+        }
+    }
+
+    fn parse_object(map: Map<String, Value>) -> VcxCoreResult<Self> {
+        let mut queries = map
+            .into_iter()
+            .map(|(key, value)| Self::parse_operator(key, value))
+            .collect::<VcxCoreResult<Vec<_>>>()?;
+
+        if queries.len() == 1 {
+            Ok(queries.remove(0))
+        } else {
+            Ok(Query::And(queries))
+        }
+    }
+
+    fn parse_operator(key: String, value: Value) -> VcxCoreResult<Self> {
+        match (key.as_str(), value) {
+            ("$and", Value::Array(values)) => Ok(Query::And(Self::parse_list(values)?)),
+            ("$or", Value::Array(values)) => Ok(Query::Or(Self::parse_list(values)?)),
+            ("$not", Value::Object(map)) => Ok(Query::Not(Box::new(Self::parse_object(map)?))),
+            (_, Value::String(value)) if !key.starts_with('$') => Ok(Query::Eq(key, value)),
+            (_, Value::Object(map)) if !key.starts_with('$') => Self::parse_tag_operator(key, map),
+            (_, value) => Err(invalid_query(format!("Unsupported WQL operator: {key}: {value}"))),
+        }
+    }
+
+    fn parse_list(values: Vec<Value>) -> VcxCoreResult<Vec<Self>> {
+        values
+            .into_iter()
+            .map(|value| match value {
+                Value::Object(map) => Self::parse_object(map),
+                value => Err(invalid_query(format!("WQL subquery must be a JSON object: {value}"))),
+            })
+            .collect()
+    }
+
+    fn parse_tag_operator(name: String, map: Map<String, Value>) -> VcxCoreResult<Self> {
+        let mut operators = map.into_iter();
+        let (operator, value) = match (operators.next(), operators.next()) {
+            (Some(operator), None) => operator,
+            _ => return Err(invalid_query(format!("WQL tag {name} must have exactly one operator"))),
+        };
+
+        let query = match (operator.as_str(), value) {
+            ("$neq", Value::String(value)) => Query::Neq(name, value),
+            ("$gt", Value::String(value)) => Query::Gt(name, value),
+            ("$gte", Value::String(value)) => Query::Gte(name, value),
+            ("$lt", Value::String(value)) => Query::Lt(name, value),
+            ("$lte", Value::String(value)) => Query::Lte(name, value),
+            ("$like", Value::String(value)) => Query::Like(name, value),
+            ("$in", Value::Array(values)) => Query::In(
+                name,
+                values
+                    .into_iter()
+                    .map(|value| match value {
+                        Value::String(value) => Ok(value),
+                        value => Err(invalid_query(format!("WQL $in values must be strings: {value}"))),
+                    })
+                    .collect::<VcxCoreResult<_>>()?,
+            ),
+            (operator, value) => {
+                return Err(invalid_query(format!(
+                    "Unsupported WQL tag operator: {operator}: {value}"
+                )))
+            }
+        };
+        Ok(query)
+    }
+
+    /// Whether a record with `tags` matches the query. Tag operators never match missing tags.
+    pub fn matches(&self, tags: &HashMap<String, String>) -> bool {
+        let tag = |name: &String| tags.get(name);
+        match self {
+            Query::And(queries) => queries.iter().all(|query| query.matches(tags)),
+            Query::Or(queries) => queries.iter().any(|query| query.matches(tags)),
+            Query::Not(query) => !query.matches(tags),
+            Query::Eq(name, value) => tag(name) == Some(value),
+            Query::Neq(name, value) => tag(name).map_or(false, |tag| tag != value),
+            Query::Gt(name, value) => tag(name).map_or(false, |tag| tag > value),
+            Query::Gte(name, value) => tag(name).map_or(false, |tag| tag >= value),
+            Query::Lt(name, value) => tag(name).map_or(false, |tag| tag < value),
+            Query::Lte(name, value) => tag(name).map_or(false, |tag| tag <= value),
+            Query::Like(name, pattern) => tag(name).map_or(false, |tag| like(tag.as_bytes(), pattern.as_bytes())),
+            Query::In(name, values) => tag(name).map_or(false, |tag| values.contains(tag)),
+        }
+    }
+}
+
+// SQL LIKE: `%` matches any sequence of characters, `_` any single character
+fn like(value: &[u8], pattern: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => value.is_empty(),
+        Some((b'%', rest)) => (0..=value.len()).any(|skip| like(&value[skip..], rest)),
+        Some((b'_', rest)) => !value.is_empty() && like(&value[1..], rest),
+        Some((expected, rest)) => value.first() == Some(expected) && like(&value[1..], rest),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::collections::HashMap;
+
+    use super::Query;
+
+    fn tags() -> HashMap<String, String> {
+        HashMap::from([
+            ("name".to_string(), "alice".to_string()),
+            ("~age".to_string(), "28".to_string()),
+        ])
+    }
+
+    fn matches(query: &str) -> bool {
+        Query::parse(query).unwrap().matches(&tags())
+    }
+
+    #[test]
+    fn test_wql_matches_tags() {
+        assert!(matches("{}"));
+        assert!(matches(r#"{"name": "alice"}"#));
+        assert!(!matches(r#"{"name": "bob"}"#));
+        assert!(matches(r#"{"name": "alice", "~age": {"$gte": "28"}}"#));
+        assert!(!matches(r#"{"name": "alice", "~age": {"$gt": "28"}}"#));
+        assert!(matches(r#"{"$or": [{"name": "bob"}, {"~age": {"$lt": "30"}}]}"#));
+        assert!(matches(r#"{"$not": {"name": "bob"}}"#));
+        assert!(matches(r#"{"name": {"$in": ["bob", "alice"]}}"#));
+        assert!(matches(r#"{"name": {"$like": "a%c_"}}"#));
+        assert!(!matches(r#"{"name": {"$like": "a_"}}"#));
+        assert!(!matches(r#"{"missing": {"$neq": "alice"}}"#));
+    }
+
+    #[test]
+    fn test_wql_rejects_invalid_queries() {
+        assert!(Query::parse("[]").is_err());
+        assert!(Query::parse(r#"{"$and": {"name": "alice"}}"#).is_err());
+        assert!(Query::parse(r#"{"name": {"$neq": "a", "$gt": "b"}}"#).is_err());
+        assert!(Query::parse(r#"{"name": {"$regex": "a"}}"#).is_err());
+        assert!(Query::parse(r#"{"name": 1}"#).is_err());
+    }
+}
@@ -1,4 +1,12 @@
 pub mod agency_client_wallet;
+#[cfg(feature = "modular_libs")]
+pub mod askar;
 pub mod base_wallet;
+#[cfg(feature = "modular_libs")]
+pub mod in_memory_wallet;
 #[cfg(feature = "vdrtools")]
 pub mod indy_wallet;
+#[cfg(feature = "modular_libs")]
+pub(crate) mod pack;
+#[cfg(feature = "modular_libs")]
+pub(crate) mod utils;
@@ -0,0 +1,207 @@
+//! DIDComm v1 message packing (Aries RFC 0019 encryption envelope), interoperable with
+//! the envelopes produced by indy wallets.
+
+use aries_askar::crypto::alg::Chacha20Types;
+use aries_askar::kms::{
+    crypto_box, crypto_box_open, crypto_box_random_nonce, crypto_box_seal, crypto_box_seal_open, KeyAlg, LocalKey,
+};
+use base64::{
+    engine::general_purpose::{URL_SAFE, URL_SAFE_NO_PAD},
+    Engine,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
+use super::utils::verkey_to_local_key;
+
+// indy labels the content encryption as xchacha20poly1305, but uses the 12 bytes nonce variant
+const PROTECTED_HEADER_ENC: &str = "xchacha20poly1305_ietf";
+const PROTECTED_HEADER_TYP: &str = "JWM/1.0";
+const PROTECTED_HEADER_ALG_AUTH: &str = "Authcrypt";
+const PROTECTED_HEADER_ALG_ANON: &str = "Anoncrypt";
+
+const CEK_ALG: KeyAlg = KeyAlg::Chacha20(Chacha20Types::C20P);
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Jwe {
+    pub protected: String,
+    pub iv: String,
+    pub ciphertext: String,
+    pub tag: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Recipient {
+    pub encrypted_key: String,
+    pub header: Header,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Header {
+    pub kid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iv: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct Protected {
+    pub enc: String,
+    pub typ: String,
+    pub alg: String,
+    pub recipients: Vec<Recipient>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct UnpackMessage {
+    message: String,
+    recipient_verkey: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sender_verkey: Option<String>,
+}
+
+impl Jwe {
+    pub fn decode_protected(&self) -> VcxCoreResult<Protected> {
+        Ok(serde_json::from_slice(&b64_decode(&self.protected)?)?)
+    }
+}
+
+fn b64_encode(bytes: &[u8]) -> String {
+    URL_SAFE.encode(bytes)
+}
+
+// padding is optional for the envelopes of other agents
+fn b64_decode(value: &str) -> VcxCoreResult<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(value.trim_end_matches('=')).map_err(|err| {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidMessageFormat,
+            format!("Invalid base64url value in packed message: {err}"),
+        )
+    })
+}
+
+fn invalid_message(msg: &str) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidMessageFormat, msg.to_string())
+}
+
+/// Packs `msg` for `recipient_verkeys`, authcrypted if `sender` (verkey and its key) is set,
+/// anoncrypted otherwise.
+pub(crate) fn pack_message(
+    sender: Option<(&str, &LocalKey)>,
+    recipient_verkeys: &[String],
+    msg: &[u8],
+) -> VcxCoreResult<Vec<u8>> {
+    let cek = LocalKey::generate(CEK_ALG, true)?;
+    let cek_bytes = cek.to_secret_bytes()?;
+
+    let recipients = recipient_verkeys
+        .iter()
+        .map(|recipient_verkey| {
+            let recipient_key = verkey_to_local_key(recipient_verkey)?.convert_key(KeyAlg::X25519)?;
+            let recipient = match sender {
+                Some((sender_verkey, sender_key)) => {
+                    let nonce = crypto_box_random_nonce()?;
+                    let sender_key = sender_key.convert_key(KeyAlg::X25519)?;
+                    let encrypted_key = crypto_box(&recipient_key, &sender_key, &cek_bytes, &nonce)?;
+                    let encrypted_sender = crypto_box_seal(&recipient_key, sender_verkey.as_bytes())?;
+                    Recipient {
+                        encrypted_key: b64_encode(&encrypted_key),
+                        header: Header {
+                            kid: recipient_verkey.to_string(),
+                            iv: Some(b64_encode(&nonce)),
+                            sender: Some(b64_encode(&encrypted_sender)),
+                        },
+                    }
+                }
+                None => Recipient {
+                    encrypted_key: b64_encode(&crypto_box_seal(&recipient_key, &cek_bytes)?),
+                    header: Header {
+                        kid: recipient_verkey.to_string(),
+                        iv: None,
+                        sender: None,
+                    },
+                },
+            };
+            Ok(recipient)
+        })
+        .collect::<VcxCoreResult<Vec<_>>>()?;
+
+    let protected = Protected {
+        enc: PROTECTED_HEADER_ENC.to_string(),
+        typ: PROTECTED_HEADER_TYP.to_string(),
+        alg: match sender {
+            Some(_) => PROTECTED_HEADER_ALG_AUTH.to_string(),
+            None => PROTECTED_HEADER_ALG_ANON.to_string(),
+        },
+        recipients,
+    };
+    let protected = b64_encode(&serde_json::to_vec(&protected)?);
+
+    let nonce = cek.aead_random_nonce()?;
+    let encrypted = cek.aead_encrypt(msg, &nonce, protected.as_bytes())?;
+
+    Ok(serde_json::to_vec(&Jwe {
+        protected,
+        iv: b64_encode(encrypted.nonce()),
+        ciphertext: b64_encode(encrypted.ciphertext()),
+        tag: b64_encode(encrypted.tag()),
+    })?)
+}
+
+/// Unpacks a message addressed to `recipient`, whose key is `recipient_key`, into the indy unpack
+/// output: `{"message": .., "recipient_verkey": .., "sender_verkey": ..}`.
+pub(crate) fn unpack_message(
+    jwe: &Jwe,
+    protected: &Protected,
+    recipient: &Recipient,
+    recipient_key: &LocalKey,
+) -> VcxCoreResult<Vec<u8>> {
+    let recipient_key = recipient_key.convert_key(KeyAlg::X25519)?;
+    let encrypted_key = b64_decode(&recipient.encrypted_key)?;
+
+    let (sender_verkey, cek) = match protected.alg.as_str() {
+        PROTECTED_HEADER_ALG_AUTH => {
+            let encrypted_sender = recipient
+                .header
+                .sender
+                .as_deref()
+                .ok_or_else(|| invalid_message("Authcrypted message is missing the sender"))?;
+            let nonce = recipient
+                .header
+                .iv
+                .as_deref()
+                .ok_or_else(|| invalid_message("Authcrypted message is missing the iv"))?;
+
+            let sender_verkey = crypto_box_seal_open(&recipient_key, &b64_decode(encrypted_sender)?)?;
+            let sender_verkey = String::from_utf8(sender_verkey.to_vec())
+                .map_err(|_| invalid_message("Sender verkey is not valid utf8"))?;
+            let sender_key = verkey_to_local_key(&sender_verkey)?.convert_key(KeyAlg::X25519)?;
+            let cek = crypto_box_open(&recipient_key, &sender_key, &encrypted_key, &b64_decode(nonce)?)?;
+            (Some(sender_verkey), cek)
+        }
+        PROTECTED_HEADER_ALG_ANON => (None, crypto_box_seal_open(&recipient_key, &encrypted_key)?),
+        alg => {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidMessageFormat,
+                format!("Unsupported packed message alg: {alg}"),
+            ))
+        }
+    };
+
+    let cek = LocalKey::from_secret_bytes(CEK_ALG, &cek)?;
+    let ciphertext = b64_decode(&jwe.ciphertext)?;
+    let tag = b64_decode(&jwe.tag)?;
+    let message = cek.aead_decrypt(
+        (ciphertext.as_slice(), tag.as_slice()),
+        &b64_decode(&jwe.iv)?,
+        jwe.protected.as_bytes(),
+    )?;
+
+    Ok(serde_json::to_vec(&UnpackMessage {
+        message: String::from_utf8(message.to_vec()).map_err(|_| invalid_message("Message is not valid utf8"))?,
+        recipient_verkey: recipient.header.kid.clone(),
+        sender_verkey,
+    })?)
+}
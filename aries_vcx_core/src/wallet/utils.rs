@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use aries_askar::kms::{KeyAlg, LocalKey};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
+
+const SEED_LENGTH: usize = 32;
+
+/// DID of the wallet, stored the same way indy wallets store their `Indy::Did` records.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct DidRecord {
+    pub did: String,
+    pub verkey: String,
+}
+
+/// Options of the indy wallet record retrieval and search APIs.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordOptions {
+    #[serde(default = "default_true")]
+    pub retrieve_records: bool,
+    #[serde(default)]
+    pub retrieve_type: bool,
+    #[serde(default = "default_true")]
+    pub retrieve_value: bool,
+    #[serde(default)]
+    pub retrieve_tags: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Accepts the seed formats of indy wallets: a 32 bytes string or a base64 encoded 32 bytes value.
+pub(crate) fn decode_seed(seed: &str) -> VcxCoreResult<Vec<u8>> {
+    let bytes = if seed.len() == SEED_LENGTH {
+        seed.as_bytes().to_vec()
+    } else if seed.ends_with('=') {
+        STANDARD.decode(seed).map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!("Can't decode base64 seed: {err}"),
+            )
+        })?
+    } else {
+        vec![]
+    };
+
+    if bytes.len() != SEED_LENGTH {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!("Seed must be a {SEED_LENGTH} bytes string or a base64 encoded {SEED_LENGTH} bytes value"),
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Unqualified DIDs are the base58 encoded first 16 bytes of the verkey, as in indy wallets.
+pub(crate) fn did_from_verkey(verkey: &[u8], method_name: Option<&str>) -> String {
+    let did = bs58::encode(&verkey[0..16]).into_string();
+    match method_name {
+        Some(method_name) => format!("did:{method_name}:{did}"),
+        None => did,
+    }
+}
+
+pub(crate) fn verkey_to_local_key(verkey: &str) -> VcxCoreResult<LocalKey> {
+    let bytes = bs58::decode(verkey).into_vec().map_err(|err| {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::NotBase58,
+            format!("Invalid verkey {verkey}: {err}"),
+        )
+    })?;
+    Ok(LocalKey::from_public_bytes(KeyAlg::Ed25519, &bytes)?)
+}
+
+pub(crate) fn parse_tags(tags_json: &str) -> VcxCoreResult<HashMap<String, String>> {
+    serde_json::from_str(tags_json).map_err(|err| {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidJson,
+            format!("Wallet record tags must be a JSON object of strings: {err}"),
+        )
+    })
+}
+
+/// Serializes a record the way indy wallets return it, leaving out what `options` does not retrieve.
+pub(crate) fn record_to_json(
+    xtype: &str,
+    id: &str,
+    value: &[u8],
+    tags: HashMap<String, String>,
+    options: &RecordOptions,
+) -> VcxCoreResult<String> {
+    let value = if options.retrieve_value {
+        Some(String::from_utf8(value.to_vec()).map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidState,
+                format!("Wallet record value is not valid utf8: {err}"),
+            )
+        })?)
+    } else {
+        None
+    };
+
+    Ok(json!({
+        "type": options.retrieve_type.then_some(xtype),
+        "id": id,
+        "value": value,
+        "tags": options.retrieve_tags.then_some(tags),
+    })
+    .to_string())
+}
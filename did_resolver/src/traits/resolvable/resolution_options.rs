@@ -1,13 +1,23 @@
+use chrono::{DateTime, Utc};
+
 use crate::shared_types::media_type::MediaType;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct DidResolutionOptions {
     accept: Option<MediaType>,
+    version_id: Option<String>,
+    version_time: Option<DateTime<Utc>>,
+    no_cache: bool,
 }
 
 impl DidResolutionOptions {
     pub fn new() -> Self {
-        Self { accept: None }
+        Self {
+            accept: None,
+            version_id: None,
+            version_time: None,
+            no_cache: false,
+        }
     }
 
     pub fn set_accept(mut self, accept: MediaType) -> Self {
@@ -15,7 +25,37 @@ impl DidResolutionOptions {
         self
     }
 
+    /// Requests the version of the DID document identified by the method specific `version_id`
+    pub fn set_version_id(mut self, version_id: String) -> Self {
+        self.version_id = Some(version_id);
+        self
+    }
+
+    /// Requests the version of the DID document which was current at `version_time`
+    pub fn set_version_time(mut self, version_time: DateTime<Utc>) -> Self {
+        self.version_time = Some(version_time);
+        self
+    }
+
+    /// Requests a fresh resolution, bypassing any cached results
+    pub fn set_no_cache(mut self, no_cache: bool) -> Self {
+        self.no_cache = no_cache;
+        self
+    }
+
     pub fn accept(&self) -> Option<&MediaType> {
         self.accept.as_ref()
     }
+
+    pub fn version_id(&self) -> Option<&String> {
+        self.version_id.as_ref()
+    }
+
+    pub fn version_time(&self) -> Option<DateTime<Utc>> {
+        self.version_time
+    }
+
+    pub fn no_cache(&self) -> bool {
+        self.no_cache
+    }
 }
@@ -0,0 +1,43 @@
+pub mod parsing;
+
+use aries_vcx_core::errors::error::AriesVcxCoreError;
+use did_resolver::did_doc_builder::error::DidDocumentBuilderError;
+use did_resolver_sov::error::DidSovError;
+use thiserror::Error;
+
+use self::parsing::ParsingErrorSource;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum DidIndyError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("DID method not supported: {0}")]
+    MethodNotSupported(String),
+    #[error("Representation not supported: {0}")]
+    RepresentationNotSupported(String),
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("Invalid resolution options: {0}")]
+    InvalidOptions(String),
+    #[error("AriesVCX Core error: {0}")]
+    AriesVcxCoreError(#[from] AriesVcxCoreError),
+    #[error("DID Document Builder Error: {0}")]
+    DidDocumentBuilderError(#[from] DidDocumentBuilderError),
+    #[error("Parsing error: {0}")]
+    ParsingError(#[from] ParsingErrorSource),
+    #[error(transparent)]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+// NYMs are read through the did:sov helpers, whose errors are mapped to the matching variants
+impl From<DidSovError> for DidIndyError {
+    fn from(error: DidSovError) -> Self {
+        match error {
+            DidSovError::NotFound(msg) => DidIndyError::NotFound(msg),
+            DidSovError::InvalidOptions(msg) => DidIndyError::InvalidOptions(msg),
+            DidSovError::AriesVcxCoreError(err) => DidIndyError::AriesVcxCoreError(err),
+            err => DidIndyError::Other(Box::new(err)),
+        }
+    }
+}
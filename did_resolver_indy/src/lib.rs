@@ -0,0 +1,4 @@
+pub extern crate did_resolver;
+
+pub mod error;
+pub mod resolution;
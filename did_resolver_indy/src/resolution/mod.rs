@@ -0,0 +1,4 @@
+mod resolver;
+mod utils;
+
+pub use resolver::DidIndyResolver;
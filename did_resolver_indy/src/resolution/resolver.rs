@@ -0,0 +1,142 @@
+use std::{collections::HashMap, sync::Arc};
+
+use aries_vcx_core::{errors::error::AriesVcxCoreErrorKind, ledger::base_ledger::BaseLedger};
+use async_trait::async_trait;
+use did_resolver::{
+    did_parser::Did,
+    error::GenericError,
+    shared_types::media_type::MediaType,
+    traits::resolvable::{
+        resolution_error::DidResolutionError, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput, DidResolvable,
+    },
+};
+
+use did_resolver_sov::{
+    error::DidSovError,
+    reader::{AttrReader, ConcreteAttrReader},
+    resolution::{get_data_from_response, get_nym, is_versioned, NymCreatedCache},
+    service::EndpointDidSov,
+};
+
+use crate::error::DidIndyError;
+
+use super::utils::{nym_to_ddo, parse_indy_did_id};
+
+// NYMs are read as for did:sov, which is backed by the same ledger transactions
+struct NamespaceLedger {
+    reader: ConcreteAttrReader,
+    created: NymCreatedCache,
+}
+
+/// Resolves `did:indy:<namespace>:<id>` DIDs against the ledger registered for their namespace.
+/// Sub-namespaces, such as `sovrin:staging`, are registered as a whole.
+#[derive(Default)]
+pub struct DidIndyResolver {
+    ledgers: HashMap<String, NamespaceLedger>,
+}
+
+impl DidIndyResolver {
+    /// Creates a resolver without any namespace, see [`DidIndyResolver::register_namespace`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves DIDs of `namespace` against `ledger`, replacing any ledger previously
+    /// registered for it.
+    pub fn register_namespace(&mut self, namespace: String, ledger: Arc<dyn BaseLedger>) {
+        let ledger = NamespaceLedger {
+            reader: ledger.into(),
+            created: NymCreatedCache::default(),
+        };
+        self.ledgers.insert(namespace, ledger);
+    }
+
+    async fn resolve_did(
+        &self,
+        parsed_did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        if let Some(accept) = options.accept() {
+            if accept != &MediaType::DidJson {
+                return Err(Box::new(DidIndyError::RepresentationNotSupported(
+                    accept.to_string(),
+                )));
+            }
+        }
+        if parsed_did.method() != "indy" {
+            return Err(Box::new(DidIndyError::MethodNotSupported(
+                parsed_did.method().to_string(),
+            )));
+        }
+        let (namespace, nsid) = parse_indy_did_id(parsed_did.id())?;
+        // An unregistered namespace is a gap in the configuration, not a missing DID
+        let ledger = self.ledgers.get(namespace).ok_or_else(|| {
+            DidIndyError::MethodNotSupported(format!("indy:{namespace}, no ledger is registered"))
+        })?;
+
+        let nym = get_nym(&ledger.reader, nsid, options)
+            .await
+            .map_err(DidIndyError::from)?;
+        let created = match nym.txn_time {
+            Some(txn_time) => Some(
+                ledger
+                    .created
+                    .get_nym_created(&ledger.reader, nsid, txn_time)
+                    .await
+                    .map_err(DidIndyError::from)?,
+            ),
+            None => None,
+        };
+        // The legacy endpoint ATTRIB is only consulted for the current version of NYMs written
+        // without diddocContent
+        let endpoint = if nym.diddoc_content.is_none() && !is_versioned(options) {
+            get_endpoint(&ledger.reader, nsid).await?
+        } else {
+            None
+        };
+        nym_to_ddo(parsed_did, nsid, &nym, endpoint, created).map_err(|err| err.into())
+    }
+}
+
+#[async_trait]
+impl DidResolvable for DidIndyResolver {
+    async fn resolve(
+        &self,
+        parsed_did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        self.resolve_did(parsed_did, options)
+            .await
+            .map_err(not_found_to_resolution_error)
+    }
+}
+
+// DIDs missing from the namespace ledger are reported as `DidResolutionError::NotFound`, like
+// those of the other methods
+fn not_found_to_resolution_error(err: GenericError) -> GenericError {
+    match err.downcast_ref::<DidIndyError>() {
+        Some(DidIndyError::NotFound(_)) => Box::new(DidResolutionError::NotFound),
+        _ => err,
+    }
+}
+
+async fn get_endpoint(
+    reader: &dyn AttrReader,
+    nsid: &str,
+) -> Result<Option<EndpointDidSov>, DidIndyError> {
+    let response = match reader.get_attr(nsid, "endpoint").await {
+        Ok(response) => response,
+        Err(DidSovError::AriesVcxCoreError(err))
+            if err.kind() == AriesVcxCoreErrorKind::LedgerItemNotFound =>
+        {
+            return Ok(None)
+        }
+        Err(err) => return Err(err.into()),
+    };
+    match get_data_from_response(&response) {
+        Ok(data) => Ok(Some(serde_json::from_value(data["endpoint"].clone())?)),
+        Err(DidSovError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
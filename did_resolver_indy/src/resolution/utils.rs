@@ -0,0 +1,318 @@
+use aries_vcx_core::ledger::types::NymResponse;
+use did_resolver::{
+    did_doc_builder::schema::{
+        did_doc::DidDocument, service::Service, types::uri::Uri,
+        verification_method::VerificationMethod,
+    },
+    did_parser::{Did, DidUrl},
+    shared_types::did_document_metadata::DidDocumentMetadata,
+    traits::resolvable::{
+        resolution_metadata::DidResolutionMetadata, resolution_output::DidResolutionOutput,
+    },
+};
+use did_resolver_sov::{resolution::unix_to_datetime, service::EndpointDidSov};
+use serde_json::{json, Value};
+
+use crate::error::{parsing::ParsingErrorSource, DidIndyError};
+
+// https://hyperledger.github.io/indy-did-method/#did-syntax
+pub(super) fn parse_indy_did_id(id: &str) -> Result<(&str, &str), DidIndyError> {
+    let (namespace, nsid) = id
+        .rsplit_once(':')
+        .ok_or_else(|| DidIndyError::InvalidDid(format!("{id} is missing the namespace")))?;
+    let is_valid_namespace = !namespace.is_empty()
+        && namespace.split(':').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        });
+    if !is_valid_namespace {
+        return Err(DidIndyError::InvalidDid(format!(
+            "{namespace} is not a valid namespace"
+        )));
+    }
+    match bs58::decode(nsid).into_vec() {
+        Ok(bytes) if bytes.len() == 16 || bytes.len() == 32 => Ok((namespace, nsid)),
+        _ => Err(DidIndyError::InvalidDid(format!(
+            "{nsid} is not a valid namespace identifier"
+        ))),
+    }
+}
+
+// Abbreviated verkeys (prefixed with `~`) hold the second half of the key, the first half being
+// the namespace identifier itself
+pub(super) fn expand_verkey(nsid: &str, verkey: &str) -> Result<String, DidIndyError> {
+    let abbreviated = match verkey.strip_prefix('~') {
+        Some(abbreviated) => abbreviated,
+        None => return Ok(verkey.to_string()),
+    };
+    let invalid_verkey = |_| {
+        DidIndyError::ParsingError(ParsingErrorSource::LedgerResponseParsingError(format!(
+            "Failed to expand abbreviated verkey {verkey}"
+        )))
+    };
+    let mut key = bs58::decode(nsid).into_vec().map_err(invalid_verkey)?;
+    key.extend(
+        bs58::decode(abbreviated)
+            .into_vec()
+            .map_err(invalid_verkey)?,
+    );
+    Ok(bs58::encode(key).into_string())
+}
+
+// Merges the `diddocContent` of a NYM into the base document: arrays are appended to and absent
+// properties are added, while any other overlap is rejected
+fn merge_diddoc_content(base: &mut Value, content: &Value) -> Result<(), DidIndyError> {
+    let invalid_content = |msg: String| {
+        DidIndyError::ParsingError(ParsingErrorSource::LedgerResponseParsingError(msg))
+    };
+    let content = content
+        .as_object()
+        .ok_or_else(|| invalid_content("diddocContent is not a JSON object".to_string()))?;
+    let base = base
+        .as_object_mut()
+        .ok_or_else(|| invalid_content("Base DID document is not a JSON object".to_string()))?;
+    for (key, value) in content {
+        match (base.get_mut(key), value) {
+            (None, value) => {
+                base.insert(key.to_string(), value.clone());
+            }
+            (Some(Value::Array(existing)), Value::Array(values)) => {
+                existing.extend(values.iter().cloned());
+            }
+            _ => {
+                return Err(invalid_content(format!(
+                    "diddocContent must not override the {key} property"
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn did_communication_service(did: &Did, endpoint: EndpointDidSov) -> Result<Service, DidIndyError> {
+    let service_id = Uri::new(&format!("{}#did-communication", did.did()))?;
+    Ok(
+        Service::builder(service_id, endpoint.endpoint.as_str().try_into()?)?
+            .add_service_type("did-communication".to_string())?
+            .add_extra_field("priority".to_string(), json!(0))
+            .add_extra_field(
+                "recipientKeys".to_string(),
+                json!([format!("{}#verkey", did.did())]),
+            )
+            .add_extra_field("routingKeys".to_string(), json!(endpoint.routing_keys))
+            .add_extra_field("accept".to_string(), json!(["didcomm/aip2;env=rfc19"]))
+            .build()?,
+    )
+}
+
+fn deactivated_output(did: &Did, nym: &NymResponse, created: Option<u64>) -> DidResolutionOutput {
+    DidResolutionOutput::builder(DidDocument::builder(did.clone()).build())
+        .did_document_metadata(nym_metadata(nym, true, created))
+        .did_resolution_metadata(resolution_metadata())
+        .build()
+}
+
+// `created` is the time of the first NYM transaction of the DID
+fn nym_metadata(nym: &NymResponse, deactivated: bool, created: Option<u64>) -> DidDocumentMetadata {
+    let mut metadata_builder = DidDocumentMetadata::builder().deactivated(deactivated);
+    if let Some(datetime) = created.and_then(|created| unix_to_datetime(created as i64)) {
+        metadata_builder = metadata_builder.created(datetime);
+    }
+    if let Some(seq_no) = nym.seq_no {
+        metadata_builder = metadata_builder.version_id(seq_no.to_string());
+    }
+    if let Some(datetime) = nym
+        .txn_time
+        .and_then(|txn_time| unix_to_datetime(txn_time as i64))
+    {
+        metadata_builder = metadata_builder.updated(datetime);
+    }
+    metadata_builder.build()
+}
+
+fn resolution_metadata() -> DidResolutionMetadata {
+    DidResolutionMetadata::builder()
+        .content_type("application/did+json".to_string())
+        .build()
+}
+
+// https://hyperledger.github.io/indy-did-method/#diddoc-assembly-steps
+pub(super) fn nym_to_ddo(
+    did: &Did,
+    nsid: &str,
+    nym: &NymResponse,
+    endpoint: Option<EndpointDidSov>,
+    created: Option<u64>,
+) -> Result<DidResolutionOutput, DidIndyError> {
+    // A NYM whose verkey has been removed is deactivated
+    let verkey = match &nym.verkey {
+        Some(verkey) => expand_verkey(nsid, verkey)?,
+        None => return Ok(deactivated_output(did, nym, created)),
+    };
+
+    let verkey_id = DidUrl::parse(format!("{}#verkey", did.did()))?;
+    let verification_method = VerificationMethod::builder(
+        verkey_id.clone(),
+        did.clone(),
+        "Ed25519VerificationKey2018".to_string(),
+    )
+    .add_extra_field("publicKeyBase58".to_string(), Value::String(verkey))
+    .build();
+
+    let mut builder = DidDocument::builder(did.clone())
+        .add_verification_method(verification_method)
+        .add_authentication_reference(verkey_id);
+
+    let ddo = match (&nym.diddoc_content, endpoint) {
+        (Some(content), _) => {
+            let mut ddo = serde_json::to_value(builder.build())?;
+            merge_diddoc_content(&mut ddo, content)?;
+            serde_json::from_value(ddo)?
+        }
+        (None, Some(endpoint)) => {
+            builder = builder.add_service(did_communication_service(did, endpoint)?);
+            builder.build()
+        }
+        (None, None) => builder.build(),
+    };
+
+    Ok(DidResolutionOutput::builder(ddo)
+        .did_document_metadata(nym_metadata(nym, false, created))
+        .did_resolution_metadata(resolution_metadata())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "did:indy:sovrin:staging:6cgbu8ZPoWTnR5Rv5JcSMB";
+    const NSID: &str = "6cgbu8ZPoWTnR5Rv5JcSMB";
+    const VERKEY: &str = "Ha8gpYzL9GEkLzkNdzBCh4Ac3nmDz7ASRs6xnRW5XqJw";
+
+    fn nym(verkey: Option<&str>, diddoc_content: Option<Value>) -> NymResponse {
+        NymResponse {
+            did: NSID.to_string(),
+            verkey: verkey.map(|verkey| verkey.to_string()),
+            diddoc_content,
+            seq_no: Some(42),
+            txn_time: Some(1683291540),
+            ..Default::default()
+        }
+    }
+
+    fn endpoint() -> EndpointDidSov {
+        serde_json::from_value(json!({
+            "endpoint": "https://example.com",
+            "routingKeys": ["key1"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_indy_did_id() {
+        assert_eq!(
+            parse_indy_did_id("sovrin:staging:6cgbu8ZPoWTnR5Rv5JcSMB").unwrap(),
+            ("sovrin:staging", NSID)
+        );
+        assert_eq!(
+            parse_indy_did_id("idunion:BDrEcHc8Tb4Lb2VyQZWEDE").unwrap(),
+            ("idunion", "BDrEcHc8Tb4Lb2VyQZWEDE")
+        );
+    }
+
+    #[test]
+    fn test_parse_indy_did_id_invalid() {
+        assert!(parse_indy_did_id(NSID).is_err());
+        assert!(parse_indy_did_id("Sovrin:6cgbu8ZPoWTnR5Rv5JcSMB").is_err());
+        assert!(parse_indy_did_id("sovrin::6cgbu8ZPoWTnR5Rv5JcSMB").is_err());
+        assert!(parse_indy_did_id("sovrin:6cgbu8ZPoWTnR5Rv5Jc").is_err());
+        assert!(parse_indy_did_id("sovrin:6cgbu8ZPoWTnR5Rv5JcSM0").is_err());
+    }
+
+    #[test]
+    fn test_expand_verkey() {
+        assert_eq!(expand_verkey(NSID, VERKEY).unwrap(), VERKEY);
+        let expanded = expand_verkey("V4SGRU86Z58d6TV7PBUe6f", "~CoRER63DVYnWZtK8uAzNbx").unwrap();
+        assert_eq!(expanded, "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL");
+    }
+
+    #[test]
+    fn test_nym_to_ddo() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let output = nym_to_ddo(
+            &did,
+            NSID,
+            &nym(Some(VERKEY), None),
+            Some(endpoint()),
+            Some(1683290000),
+        )
+        .unwrap();
+        let ddo = output.did_document();
+        assert_eq!(ddo.id(), &did);
+        assert_eq!(ddo.verification_method().len(), 1);
+        assert_eq!(
+            ddo.verification_method()[0].id().to_string(),
+            format!("{DID}#verkey")
+        );
+        assert_eq!(ddo.authentication().len(), 1);
+        assert_eq!(ddo.service().len(), 1);
+        assert_eq!(
+            ddo.service()[0].id().to_string(),
+            format!("{DID}#did-communication")
+        );
+        assert_eq!(
+            ddo.service()[0].extra_field("routingKeys"),
+            Some(&json!(["key1"]))
+        );
+
+        let metadata = output.did_document_metadata();
+        assert_eq!(metadata.version_id(), Some(&"42".to_string()));
+        assert_eq!(metadata.updated(), unix_to_datetime(1683291540));
+        assert_eq!(metadata.created(), unix_to_datetime(1683290000));
+        assert_eq!(metadata.deactivated(), Some(false));
+    }
+
+    #[test]
+    fn test_nym_to_ddo_with_diddoc_content() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let content = json!({
+            "@context": ["https://www.w3.org/ns/did/v1"],
+            "verificationMethod": [{
+                "id": format!("{DID}#key-agreement-1"),
+                "type": "X25519KeyAgreementKey2019",
+                "controller": DID,
+                "publicKeyBase58": "FxfdY3DCQxVZddKGAtSjZdFW9bCCW7oRwZn1NFJ2Tbg2"
+            }],
+            "keyAgreement": [format!("{DID}#key-agreement-1")]
+        });
+        let nym = nym(Some(VERKEY), Some(content));
+        // The legacy endpoint is ignored when the NYM carries diddocContent
+        let output = nym_to_ddo(&did, NSID, &nym, Some(endpoint()), None).unwrap();
+        let ddo = output.did_document();
+        assert_eq!(ddo.verification_method().len(), 2);
+        assert_eq!(ddo.key_agreement().len(), 1);
+        assert_eq!(ddo.authentication().len(), 1);
+        assert!(ddo.service().is_empty());
+    }
+
+    #[test]
+    fn test_nym_to_ddo_with_conflicting_diddoc_content() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let nym = nym(Some(VERKEY), Some(json!({ "id": "did:indy:sovrin:other" })));
+        assert!(matches!(
+            nym_to_ddo(&did, NSID, &nym, None, None),
+            Err(DidIndyError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn test_nym_to_ddo_deactivated() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let output = nym_to_ddo(&did, NSID, &nym(None, None), None, None).unwrap();
+        assert!(output.did_document().verification_method().is_empty());
+        assert_eq!(output.did_document_metadata().deactivated(), Some(true));
+    }
+}
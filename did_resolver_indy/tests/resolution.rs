@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aries_vcx::core::profile::profile::Profile;
+use aries_vcx::{
+    common::ledger::{
+        service_didsov::{DidSovServiceType, EndpointDidSov},
+        transactions::write_endpoint,
+    },
+    utils::devsetup::SetupProfile,
+};
+use did_resolver::did_parser::Did;
+use did_resolver::traits::resolvable::{resolution_options::DidResolutionOptions, DidResolvable};
+use did_resolver_indy::{error::DidIndyError, resolution::DidIndyResolver};
+
+async fn write_test_endpoint(profile: &Arc<dyn Profile>, did: &str) {
+    let endpoint = EndpointDidSov::create()
+        .set_service_endpoint("http://localhost:8080".parse().unwrap())
+        .set_routing_keys(Some(vec!["key1".to_string(), "key2".to_string()]))
+        .set_types(Some(vec![DidSovServiceType::Endpoint]));
+    write_endpoint(profile, did, &endpoint).await.unwrap();
+    thread::sleep(Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn write_service_on_ledger_and_resolve_did_indy_doc() {
+    SetupProfile::run(|init| async move {
+        let did = format!("did:indy:test:{}", init.institution_did);
+        write_test_endpoint(&init.profile, &init.institution_did).await;
+        let mut resolver = DidIndyResolver::new();
+        resolver.register_namespace("test".to_string(), init.profile.inject_ledger());
+        let output = resolver
+            .resolve(
+                &Did::parse(did.clone()).unwrap(),
+                &DidResolutionOptions::default(),
+            )
+            .await
+            .unwrap();
+        let did_doc = output.did_document();
+        assert_eq!(did_doc.id().to_string(), did);
+        assert_eq!(did_doc.verification_method().len(), 1);
+        assert_eq!(did_doc.service().len(), 1);
+        assert!(output.did_document_metadata().version_id().is_some());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_did_indy_unknown_namespace() {
+    SetupProfile::run(|init| async move {
+        let did = format!("did:indy:unknown:{}", init.institution_did);
+        let mut resolver = DidIndyResolver::new();
+        resolver.register_namespace("test".to_string(), init.profile.inject_ledger());
+        let err = resolver
+            .resolve(
+                &Did::parse(did.clone()).unwrap(),
+                &DidResolutionOptions::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DidIndyError>(),
+            Some(DidIndyError::MethodNotSupported(_))
+        ));
+    })
+    .await;
+}
@@ -0,0 +1,27 @@
+pub mod parsing;
+
+use did_resolver::did_doc_builder::error::DidDocumentBuilderError;
+use thiserror::Error;
+
+use self::parsing::ParsingErrorSource;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum DidKeyError {
+    #[error("DID method not supported: {0}")]
+    MethodNotSupported(String),
+    #[error("Representation not supported: {0}")]
+    RepresentationNotSupported(String),
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("Key type not supported: {0}")]
+    KeyTypeNotSupported(String),
+    #[error("Invalid public key: {0}")]
+    InvalidKey(String),
+    #[error("DID Document Builder Error: {0}")]
+    DidDocumentBuilderError(#[from] DidDocumentBuilderError),
+    #[error("Parsing error: {0}")]
+    ParsingError(#[from] ParsingErrorSource),
+    #[error(transparent)]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
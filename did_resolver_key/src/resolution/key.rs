@@ -0,0 +1,192 @@
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use did_resolver::{
+    did_doc_builder::schema::{types::multibase::Multibase, verification_method::VerificationMethod},
+    did_parser::{Did, DidUrl},
+};
+use multibase::Base;
+
+use crate::error::DidKeyError;
+
+// https://github.com/multiformats/multicodec/blob/master/table.csv
+const ED25519_PREFIX: [u8; 2] = [0xed, 0x01];
+const X25519_PREFIX: [u8; 2] = [0xec, 0x01];
+const P256_PREFIX: [u8; 2] = [0x80, 0x24];
+
+/// Key types supported in did:key, identified by their multicodec.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyType {
+    Ed25519,
+    X25519,
+    P256,
+}
+
+impl KeyType {
+    fn multicodec_prefix(&self) -> &'static [u8] {
+        match self {
+            KeyType::Ed25519 => &ED25519_PREFIX,
+            KeyType::X25519 => &X25519_PREFIX,
+            KeyType::P256 => &P256_PREFIX,
+        }
+    }
+
+    fn key_len(&self) -> usize {
+        match self {
+            KeyType::Ed25519 | KeyType::X25519 => 32,
+            // Compressed SEC1 point
+            KeyType::P256 => 33,
+        }
+    }
+
+    pub fn verification_method_type(&self) -> &'static str {
+        match self {
+            KeyType::Ed25519 => "Ed25519VerificationKey2020",
+            KeyType::X25519 => "X25519KeyAgreementKey2020",
+            KeyType::P256 => "P256Key2021",
+        }
+    }
+}
+
+/// A public key tagged with its type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicKey {
+    key_type: KeyType,
+    bytes: Vec<u8>,
+}
+
+impl PublicKey {
+    pub fn new(key_type: KeyType, key: Vec<u8>) -> Result<Self, DidKeyError> {
+        if key.len() != key_type.key_len() {
+            return Err(DidKeyError::InvalidKey(format!(
+                "Expected {:?} key of {} bytes, got {}",
+                key_type,
+                key_type.key_len(),
+                key.len()
+            )));
+        }
+        Ok(Self { key_type, bytes: key })
+    }
+
+    /// Decodes the multicodec encoded key of a did:key method-specific id, which must be
+    /// base58btc multibase encoded.
+    pub fn from_fingerprint(fingerprint: &str) -> Result<Self, DidKeyError> {
+        let (base, decoded) = multibase::decode(fingerprint)?;
+        if base != Base::Base58Btc {
+            return Err(DidKeyError::InvalidDid(format!(
+                "Expected base58btc encoded key, got {:?}",
+                base
+            )));
+        }
+
+        let key_type = [KeyType::Ed25519, KeyType::X25519, KeyType::P256]
+            .into_iter()
+            .find(|key_type| decoded.starts_with(key_type.multicodec_prefix()))
+            .ok_or_else(|| {
+                DidKeyError::KeyTypeNotSupported(format!("Unknown multicodec prefix of key {}", fingerprint))
+            })?;
+
+        Self::new(key_type, decoded[key_type.multicodec_prefix().len()..].to_vec())
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    pub fn key(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The multibase encoded key prefixed with its multicodec, as used in the method-specific
+    /// id and the verification method fragments.
+    pub fn fingerprint(&self) -> String {
+        let mut prefixed = self.key_type.multicodec_prefix().to_vec();
+        prefixed.extend_from_slice(&self.bytes);
+        multibase::encode(Base::Base58Btc, prefixed)
+    }
+
+    /// Derives the X25519 key agreement key from an Ed25519 key. Returns `None` for other key
+    /// types.
+    pub fn derive_x25519(&self) -> Result<Option<PublicKey>, DidKeyError> {
+        if self.key_type != KeyType::Ed25519 {
+            return Ok(None);
+        }
+        let montgomery = CompressedEdwardsY::from_slice(&self.bytes)
+            .decompress()
+            .ok_or_else(|| DidKeyError::InvalidKey("Ed25519 key is not a valid curve point".to_string()))?
+            .to_montgomery();
+
+        Ok(Some(PublicKey {
+            key_type: KeyType::X25519,
+            bytes: montgomery.to_bytes().to_vec(),
+        }))
+    }
+
+    /// Builds a verification method `id` holding the key multibase encoded.
+    pub fn verification_method(&self, id: DidUrl, controller: Did) -> Result<VerificationMethod, DidKeyError> {
+        Ok(
+            VerificationMethod::builder(id, controller, self.key_type.verification_method_type().to_string())
+                .add_public_key_multibase(Multibase::new(self.fingerprint())?)
+                .build(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED25519_FINGERPRINT: &str = "z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+    const X25519_FINGERPRINT: &str = "z6LSj72tK8brWgZja8NLRwPigth2T9QRiG1uH9oKZuKjdh9p";
+    const P256_FINGERPRINT: &str = "zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+
+    #[test]
+    fn test_decode_key_types() {
+        let ed25519 = PublicKey::from_fingerprint(ED25519_FINGERPRINT).unwrap();
+        assert_eq!(ed25519.key_type(), KeyType::Ed25519);
+        assert_eq!(ed25519.fingerprint(), ED25519_FINGERPRINT);
+
+        let x25519 = PublicKey::from_fingerprint(X25519_FINGERPRINT).unwrap();
+        assert_eq!(x25519.key_type(), KeyType::X25519);
+        assert_eq!(x25519.fingerprint(), X25519_FINGERPRINT);
+
+        let p256 = PublicKey::from_fingerprint(P256_FINGERPRINT).unwrap();
+        assert_eq!(p256.key_type(), KeyType::P256);
+        assert_eq!(p256.fingerprint(), P256_FINGERPRINT);
+    }
+
+    #[test]
+    fn test_derive_x25519() {
+        let ed25519 = PublicKey::from_fingerprint(ED25519_FINGERPRINT).unwrap();
+        let x25519 = ed25519.derive_x25519().unwrap().unwrap();
+        assert_eq!(x25519.fingerprint(), X25519_FINGERPRINT);
+
+        let p256 = PublicKey::from_fingerprint(P256_FINGERPRINT).unwrap();
+        assert!(p256.derive_x25519().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_non_base58btc_key() {
+        let key = multibase::encode(Base::Base64Url, [0xed, 0x01]);
+        assert!(matches!(
+            PublicKey::from_fingerprint(&key),
+            Err(DidKeyError::InvalidDid(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_unsupported_key_type() {
+        let key = multibase::encode(Base::Base58Btc, [0xe7, 0x01, 0x00]);
+        assert!(matches!(
+            PublicKey::from_fingerprint(&key),
+            Err(DidKeyError::KeyTypeNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_invalid_key_length() {
+        let key = multibase::encode(Base::Base58Btc, [0xed, 0x01, 0x00]);
+        assert!(matches!(
+            PublicKey::from_fingerprint(&key),
+            Err(DidKeyError::InvalidKey(_))
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use did_resolver::{
+    did_doc_builder::schema::{did_doc::DidDocument, verification_method::VerificationMethod},
+    did_parser::{Did, DidUrl},
+    error::GenericError,
+    shared_types::{did_document_metadata::DidDocumentMetadata, media_type::MediaType},
+    traits::resolvable::{
+        resolution_metadata::DidResolutionMetadata, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput, DidResolvable,
+    },
+};
+
+use super::key::{KeyType, PublicKey};
+use crate::error::DidKeyError;
+
+/// Resolves did:key DIDs by expanding the key encoded in the method-specific id into a DID
+/// document. Resolution is purely local, no network access is needed.
+#[derive(Default)]
+pub struct DidKeyResolver;
+
+impl DidKeyResolver {
+    pub fn new() -> Self {
+        DidKeyResolver
+    }
+}
+
+fn verification_method(did: &Did, key: &PublicKey) -> Result<VerificationMethod, DidKeyError> {
+    let id = DidUrl::parse(format!("{}#{}", did.did(), key.fingerprint()))?;
+    key.verification_method(id, did.clone())
+}
+
+/// Builds the DID document of `did` controlled by the single `key`, the way a did:key document
+/// is expanded. Also used by methods derived from did:key, such as did:peer numalgo 0.
+pub fn build_did_document(did: &Did, key: &PublicKey) -> Result<DidDocument, DidKeyError> {
+    let method = verification_method(did, key)?;
+    let builder = DidDocument::builder(did.clone());
+
+    // An X25519 key can only be used for key agreement
+    if key.key_type() == KeyType::X25519 {
+        return Ok(builder.add_key_agreement(method).build());
+    }
+
+    let method_id = method.id().clone();
+    let mut builder = builder
+        .add_verification_method(method)
+        .add_authentication_reference(method_id.clone())
+        .add_assertion_method_reference(method_id.clone())
+        .add_capability_invocation_refrence(method_id.clone())
+        .add_capability_delegation_refrence(method_id);
+
+    if let Some(x25519) = key.derive_x25519()? {
+        builder = builder.add_key_agreement(verification_method(did, &x25519)?);
+    }
+
+    Ok(builder.build())
+}
+
+#[async_trait]
+impl DidResolvable for DidKeyResolver {
+    async fn resolve(&self, did: &Did, options: &DidResolutionOptions) -> Result<DidResolutionOutput, GenericError> {
+        if did.method() != "key" {
+            return Err(Box::new(DidKeyError::MethodNotSupported(did.method().to_string())));
+        }
+
+        if let Some(accept) = options.accept() {
+            if accept != &MediaType::DidJson {
+                return Err(Box::new(DidKeyError::RepresentationNotSupported(accept.to_string())));
+            }
+        }
+
+        let key = PublicKey::from_fingerprint(did.id())?;
+        let did_document = build_did_document(did, &key)?;
+
+        let did_resolution_output = DidResolutionOutput::builder(did_document)
+            .did_resolution_metadata(DidResolutionMetadata::default())
+            .did_document_metadata(DidDocumentMetadata::default())
+            .build();
+
+        Ok(did_resolution_output)
+    }
+}
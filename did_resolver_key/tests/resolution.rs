@@ -0,0 +1,134 @@
+use did_resolver::did_doc_builder::schema::did_doc::DidDocument;
+use did_resolver::did_doc_builder::schema::types::multibase::Multibase;
+use did_resolver::did_doc_builder::schema::verification_method::VerificationMethodKind;
+use did_resolver::did_parser::{Did, DidUrl};
+use did_resolver::shared_types::media_type::MediaType;
+use did_resolver::traits::resolvable::{resolution_options::DidResolutionOptions, DidResolvable};
+use did_resolver_key::error::DidKeyError;
+use did_resolver_key::resolution::resolver::DidKeyResolver;
+use did_resolver_registry::ResolverRegistry;
+
+const DID_KEY_ED25519: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+const DERIVED_X25519: &str = "z6LSj72tK8brWgZja8NLRwPigth2T9QRiG1uH9oKZuKjdh9p";
+const DID_KEY_X25519: &str = "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F";
+const DID_KEY_P256: &str = "did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+
+async fn resolve(did: &str) -> DidDocument {
+    let did = Did::parse(did.to_string()).unwrap();
+    DidKeyResolver::new()
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap()
+        .did_document()
+        .clone()
+}
+
+fn fragment(did: &str) -> &str {
+    did.strip_prefix("did:key:").unwrap()
+}
+
+fn assert_reference(kinds: &[VerificationMethodKind], id: &DidUrl) {
+    assert_eq!(kinds.len(), 1);
+    match &kinds[0] {
+        VerificationMethodKind::Resolvable(reference) => assert_eq!(reference, id),
+        VerificationMethodKind::Resolved(_) => panic!("Expected a reference to {}", id),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_ed25519() {
+    let did_document = resolve(DID_KEY_ED25519).await;
+    assert_eq!(did_document.id().to_string(), DID_KEY_ED25519);
+
+    let method_id = DidUrl::parse(format!("{}#{}", DID_KEY_ED25519, fragment(DID_KEY_ED25519))).unwrap();
+    assert_eq!(did_document.verification_method().len(), 1);
+    let method = &did_document.verification_method()[0];
+    assert_eq!(method.id(), &method_id);
+    assert_eq!(method.controller().to_string(), DID_KEY_ED25519);
+    assert_eq!(method.verification_method_type(), "Ed25519VerificationKey2020");
+    assert_eq!(
+        method.public_key_multibase(),
+        Some(&Multibase::new(fragment(DID_KEY_ED25519).to_string()).unwrap())
+    );
+
+    assert_reference(did_document.authentication(), &method_id);
+    assert_reference(did_document.assertion_method(), &method_id);
+    assert_reference(did_document.capability_invocation(), &method_id);
+    assert_reference(did_document.capability_delegation(), &method_id);
+
+    assert_eq!(did_document.key_agreement().len(), 1);
+    match &did_document.key_agreement()[0] {
+        VerificationMethodKind::Resolved(key_agreement) => {
+            assert_eq!(
+                key_agreement.id().to_string(),
+                format!("{}#{}", DID_KEY_ED25519, DERIVED_X25519)
+            );
+            assert_eq!(key_agreement.verification_method_type(), "X25519KeyAgreementKey2020");
+            assert_eq!(
+                key_agreement.public_key_multibase(),
+                Some(&Multibase::new(DERIVED_X25519.to_string()).unwrap())
+            );
+        }
+        VerificationMethodKind::Resolvable(_) => panic!("Expected an embedded key agreement method"),
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_x25519() {
+    let did_document = resolve(DID_KEY_X25519).await;
+
+    assert!(did_document.verification_method().is_empty());
+    assert!(did_document.authentication().is_empty());
+    assert_eq!(did_document.key_agreement().len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_p256() {
+    let did_document = resolve(DID_KEY_P256).await;
+
+    assert_eq!(did_document.verification_method().len(), 1);
+    assert_eq!(
+        did_document.verification_method()[0].verification_method_type(),
+        "P256Key2021"
+    );
+    assert_eq!(did_document.authentication().len(), 1);
+    assert!(did_document.key_agreement().is_empty());
+}
+
+#[tokio::test]
+async fn test_resolve_invalid_did() {
+    let resolver = DidKeyResolver::new();
+    let options = DidResolutionOptions::default();
+
+    let did = Did::parse("did:web:example.com".to_string()).unwrap();
+    let err = resolver.resolve(&did, &options).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidKeyError>(),
+        Some(DidKeyError::MethodNotSupported(_))
+    ));
+
+    let did = Did::parse("did:key:zAq9r99PUfP1Xyitb5n8V9sxht".to_string()).unwrap();
+    let err = resolver.resolve(&did, &options).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidKeyError>(),
+        Some(DidKeyError::InvalidKey(_))
+    ));
+
+    let did = Did::parse(DID_KEY_ED25519.to_string()).unwrap();
+    let options = DidResolutionOptions::new().set_accept(MediaType::DidLdJson);
+    let err = resolver.resolve(&did, &options).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidKeyError>(),
+        Some(DidKeyError::RepresentationNotSupported(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_resolve_through_registry() {
+    let mut registry = ResolverRegistry::new();
+    registry.register_resolver("key".to_string(), Box::new(DidKeyResolver::new()));
+
+    let did = Did::parse(DID_KEY_ED25519.to_string()).unwrap();
+    let output = registry.resolve(&did, &DidResolutionOptions::default()).await.unwrap();
+    assert_eq!(output.did_document().id(), &did);
+}
@@ -0,0 +1,34 @@
+pub mod parsing;
+
+use did_resolver::did_doc_builder::error::DidDocumentBuilderError;
+use did_resolver_key::error::DidKeyError;
+use thiserror::Error;
+
+use self::parsing::ParsingErrorSource;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum DidPeerError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("DID method not supported: {0}")]
+    MethodNotSupported(String),
+    #[error("Representation not supported: {0}")]
+    RepresentationNotSupported(String),
+    #[error("Numalgo not supported: {0}")]
+    NumalgoNotSupported(String),
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("Invalid service: {0}")]
+    InvalidService(String),
+    #[error("Invalid input document: {0}")]
+    InvalidInputDocument(String),
+    #[error("Key error: {0}")]
+    DidKeyError(#[from] DidKeyError),
+    #[error("DID Document Builder Error: {0}")]
+    DidDocumentBuilderError(#[from] DidDocumentBuilderError),
+    #[error("Parsing error: {0}")]
+    ParsingError(#[from] ParsingErrorSource),
+    #[error(transparent)]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
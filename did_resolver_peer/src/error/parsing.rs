@@ -0,0 +1,40 @@
+use did_resolver::did_parser;
+use thiserror::Error;
+
+use super::DidPeerError;
+
+#[derive(Error, Debug)]
+pub enum ParsingErrorSource {
+    #[error("DID parsing error: {0}")]
+    DidParsingError(#[from] did_parser::ParseError),
+    #[error("Serde error: {0}")]
+    SerdeError(#[from] serde_json::Error),
+    #[error("Multibase decoding error: {0}")]
+    MultibaseError(#[from] multibase::Error),
+    #[error("Base64 decoding error: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+}
+
+impl From<did_parser::ParseError> for DidPeerError {
+    fn from(error: did_parser::ParseError) -> Self {
+        DidPeerError::ParsingError(ParsingErrorSource::DidParsingError(error))
+    }
+}
+
+impl From<serde_json::Error> for DidPeerError {
+    fn from(error: serde_json::Error) -> Self {
+        DidPeerError::ParsingError(ParsingErrorSource::SerdeError(error))
+    }
+}
+
+impl From<multibase::Error> for DidPeerError {
+    fn from(error: multibase::Error) -> Self {
+        DidPeerError::ParsingError(ParsingErrorSource::MultibaseError(error))
+    }
+}
+
+impl From<base64::DecodeError> for DidPeerError {
+    fn from(error: base64::DecodeError) -> Self {
+        DidPeerError::ParsingError(ParsingErrorSource::Base64Error(error))
+    }
+}
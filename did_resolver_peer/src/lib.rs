@@ -0,0 +1,3 @@
+pub mod error;
+pub mod numalgos;
+pub mod resolution;
@@ -0,0 +1,5 @@
+pub mod numalgo0;
+pub mod numalgo2;
+pub mod numalgo4;
+
+const DID_PEER_PREFIX: &str = "did:peer:";
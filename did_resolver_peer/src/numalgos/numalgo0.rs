@@ -0,0 +1,55 @@
+use did_resolver::{did_doc_builder::schema::did_doc::DidDocument, did_parser::Did};
+use did_resolver_key::resolution::{key::PublicKey, resolver::build_did_document};
+
+use super::DID_PEER_PREFIX;
+use crate::error::DidPeerError;
+
+/// Creates a did:peer with numalgo 0, which wraps a single inception key the way did:key does.
+pub fn generate(key: &PublicKey) -> Result<Did, DidPeerError> {
+    Ok(Did::parse(format!("{}0{}", DID_PEER_PREFIX, key.fingerprint()))?)
+}
+
+pub(crate) fn resolve(did: &Did) -> Result<DidDocument, DidPeerError> {
+    let key = PublicKey::from_fingerprint(&did.id()[1..])?;
+    Ok(build_did_document(did, &key)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use did_resolver::did_doc_builder::schema::verification_method::VerificationMethodKind;
+    use did_resolver_key::resolution::key::KeyType;
+
+    use super::*;
+
+    const DID_PEER_0: &str = "did:peer:0z6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V";
+
+    #[test]
+    fn test_generate() {
+        let key = PublicKey::from_fingerprint("z6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V").unwrap();
+        assert_eq!(generate(&key).unwrap().did(), DID_PEER_0);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let did = Did::parse(DID_PEER_0.to_string()).unwrap();
+        let did_document = resolve(&did).unwrap();
+
+        assert_eq!(did_document.id(), &did);
+        assert_eq!(did_document.verification_method().len(), 1);
+        assert_eq!(
+            did_document.verification_method()[0].verification_method_type(),
+            KeyType::Ed25519.verification_method_type()
+        );
+        assert_eq!(did_document.authentication().len(), 1);
+        assert!(matches!(
+            did_document.key_agreement(),
+            [VerificationMethodKind::Resolved(_)]
+        ));
+    }
+
+    #[test]
+    fn test_resolve_invalid_key() {
+        let did = Did::parse("did:peer:0z6Mk".to_string()).unwrap();
+        assert!(resolve(&did).is_err());
+    }
+}
@@ -0,0 +1,308 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use did_resolver::{
+    did_doc_builder::schema::{did_doc::DidDocument, service::Service},
+    did_parser::{Did, DidUrl},
+};
+use did_resolver_key::resolution::key::PublicKey;
+use serde_json::{Map, Value};
+
+use super::DID_PEER_PREFIX;
+use crate::error::DidPeerError;
+
+const SERVICE_CODE: char = 'S';
+
+// https://identity.foundation/peer-did-method-spec/#generating-a-didpeer2
+const KEY_ABBREVIATIONS: [(&str, &str); 4] = [
+    ("type", "t"),
+    ("serviceEndpoint", "s"),
+    ("routingKeys", "r"),
+    ("accept", "a"),
+];
+const TYPE_ABBREVIATIONS: [(&str, &str); 1] = [("DIDCommMessaging", "dm")];
+
+/// The verification relationship of a key encoded in a numalgo 2 did:peer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Purpose {
+    Assertion,
+    Encryption,
+    Verification,
+    CapabilityInvocation,
+    CapabilityDelegation,
+}
+
+impl Purpose {
+    fn code(&self) -> char {
+        match self {
+            Purpose::Assertion => 'A',
+            Purpose::Encryption => 'E',
+            Purpose::Verification => 'V',
+            Purpose::CapabilityInvocation => 'I',
+            Purpose::CapabilityDelegation => 'D',
+        }
+    }
+
+    fn from_code(code: char) -> Option<Self> {
+        match code {
+            'A' => Some(Purpose::Assertion),
+            'E' => Some(Purpose::Encryption),
+            'V' => Some(Purpose::Verification),
+            'I' => Some(Purpose::CapabilityInvocation),
+            'D' => Some(Purpose::CapabilityDelegation),
+            _ => None,
+        }
+    }
+}
+
+fn lookup<'a>(table: &[(&'a str, &'a str)], value: &str, abbreviate: bool) -> Option<&'a str> {
+    table.iter().find_map(|&(full, short)| {
+        let (from, to) = if abbreviate { (full, short) } else { (short, full) };
+        (from == value).then_some(to)
+    })
+}
+
+fn convert_type(value: Value, abbreviate: bool) -> Value {
+    match value {
+        Value::String(s) => Value::String(lookup(&TYPE_ABBREVIATIONS, &s, abbreviate).map_or(s, str::to_string)),
+        Value::Array(values) => Value::Array(values.into_iter().map(|v| convert_type(v, abbreviate)).collect()),
+        value => value,
+    }
+}
+
+/// Abbreviates (or expands) the keys of `value` and the values of its `type` field, recursively.
+fn convert(value: Value, abbreviate: bool) -> Value {
+    match value {
+        Value::Object(map) => {
+            let type_key = if abbreviate { "type" } else { "t" };
+            let converted = map.into_iter().map(|(key, value)| {
+                let value = if key == type_key {
+                    convert_type(value, abbreviate)
+                } else {
+                    convert(value, abbreviate)
+                };
+                let key = lookup(&KEY_ABBREVIATIONS, &key, abbreviate).map_or(key, str::to_string);
+                (key, value)
+            });
+            Value::Object(converted.collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(|value| convert(value, abbreviate)).collect()),
+        value => value,
+    }
+}
+
+fn encode_service(service: &Service) -> Result<String, DidPeerError> {
+    let mut value = serde_json::to_value(service)?;
+    let map = value
+        .as_object_mut()
+        .ok_or_else(|| DidPeerError::InvalidService("Service is not a JSON object".to_string()))?;
+    // Service ids are assigned by position when resolving
+    map.remove("id");
+    if let Some(Value::Array(types)) = map.get("type") {
+        if let [service_type] = types.as_slice() {
+            let service_type = service_type.clone();
+            map.insert("type".to_string(), service_type);
+        }
+    }
+    Ok(URL_SAFE_NO_PAD.encode(serde_json::to_vec(&convert(value, true))?))
+}
+
+fn decode_services(encoded: &str) -> Result<Vec<Map<String, Value>>, DidPeerError> {
+    let decoded = URL_SAFE_NO_PAD.decode(encoded.trim_end_matches('='))?;
+    let services = match convert(serde_json::from_slice(&decoded)?, false) {
+        Value::Array(services) => services,
+        service => vec![service],
+    };
+    services
+        .into_iter()
+        .map(|service| match service {
+            Value::Object(mut service) => {
+                // DIDComm v2 endpoints are objects holding the uri along with the routing keys and
+                // accepted media types, which are kept as extra fields of the service
+                if let Some(Value::Object(endpoint)) = service.remove("serviceEndpoint") {
+                    for (key, value) in endpoint {
+                        let key = if key == "uri" {
+                            "serviceEndpoint".to_string()
+                        } else {
+                            key
+                        };
+                        service.insert(key, value);
+                    }
+                }
+                Ok(service)
+            }
+            _ => Err(DidPeerError::InvalidService(format!(
+                "Encoded service {} is not a JSON object",
+                encoded
+            ))),
+        })
+        .collect()
+}
+
+/// Creates a did:peer with numalgo 2, encoding each key along with its purpose, followed by the
+/// services with their property names abbreviated.
+pub fn generate(keys: &[(Purpose, PublicKey)], services: &[Service]) -> Result<Did, DidPeerError> {
+    let mut did = format!("{}2", DID_PEER_PREFIX);
+    for (purpose, key) in keys {
+        did.push('.');
+        did.push(purpose.code());
+        did.push_str(&key.fingerprint());
+    }
+    for service in services {
+        did.push('.');
+        did.push(SERVICE_CODE);
+        did.push_str(&encode_service(service)?);
+    }
+    Ok(Did::parse(did)?)
+}
+
+pub(crate) fn resolve(did: &Did) -> Result<DidDocument, DidPeerError> {
+    let elements = did.id()[1..]
+        .strip_prefix('.')
+        .ok_or_else(|| DidPeerError::InvalidDid(did.did().to_string()))?;
+
+    let mut builder = DidDocument::builder(did.clone());
+    let mut key_count = 0;
+    let mut service_count = 0;
+
+    for element in elements.split('.') {
+        let mut chars = element.chars();
+        let code = chars
+            .next()
+            .ok_or_else(|| DidPeerError::InvalidDid(format!("Empty element in {}", did.did())))?;
+
+        if code == SERVICE_CODE {
+            for mut service in decode_services(chars.as_str())? {
+                let id = match service_count {
+                    0 => format!("{}#service", did.did()),
+                    n => format!("{}#service-{}", did.did(), n),
+                };
+                service_count += 1;
+                service.insert("id".to_string(), Value::String(id));
+                let service: Service = serde_json::from_value(Value::Object(service))?;
+                builder = builder.add_service(service);
+            }
+            continue;
+        }
+
+        let purpose = Purpose::from_code(code)
+            .ok_or_else(|| DidPeerError::InvalidDid(format!("Unknown purpose code {} in {}", code, did.did())))?;
+        let key = PublicKey::from_fingerprint(chars.as_str())?;
+        key_count += 1;
+        let id = DidUrl::parse(format!("{}#key-{}", did.did(), key_count))?;
+        builder = builder.add_verification_method(key.verification_method(id.clone(), did.clone())?);
+        builder = match purpose {
+            Purpose::Assertion => builder.add_assertion_method_reference(id),
+            Purpose::Encryption => builder.add_key_agreement_refrence(id),
+            Purpose::Verification => builder.add_authentication_reference(id),
+            Purpose::CapabilityInvocation => builder.add_capability_invocation_refrence(id),
+            Purpose::CapabilityDelegation => builder.add_capability_delegation_refrence(id),
+        };
+    }
+
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use did_resolver::did_doc_builder::schema::types::{uri::Uri, url::Url};
+    use serde_json::json;
+
+    use super::*;
+
+    const VERIFICATION_KEY: &str = "z6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V";
+    const ENCRYPTION_KEY: &str = "z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc";
+
+    fn service() -> Service {
+        Service::builder(
+            Uri::new("http://example.com").unwrap(),
+            Url::new("https://example.com/endpoint").unwrap(),
+        )
+        .unwrap()
+        .add_service_type("DIDCommMessaging".to_string())
+        .unwrap()
+        .add_extra_field("routingKeys".to_string(), json!(["did:example:somemediator#somekey"]))
+        .build()
+        .unwrap()
+    }
+
+    fn keys() -> Vec<(Purpose, PublicKey)> {
+        vec![
+            (
+                Purpose::Encryption,
+                PublicKey::from_fingerprint(ENCRYPTION_KEY).unwrap(),
+            ),
+            (
+                Purpose::Verification,
+                PublicKey::from_fingerprint(VERIFICATION_KEY).unwrap(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_service_abbreviations() {
+        let encoded = encode_service(&service()).unwrap();
+        let decoded: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(encoded).unwrap()).unwrap();
+        assert_eq!(
+            decoded,
+            json!({
+                "t": "dm",
+                "s": "https://example.com/endpoint",
+                "r": ["did:example:somemediator#somekey"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_generate_and_resolve() {
+        let did = generate(&keys(), &[service()]).unwrap();
+        assert!(did
+            .did()
+            .starts_with(&format!("did:peer:2.E{}.V{}.S", ENCRYPTION_KEY, VERIFICATION_KEY)));
+
+        let did_document = resolve(&did).unwrap();
+        assert_eq!(did_document.id(), &did);
+        assert_eq!(did_document.verification_method().len(), 2);
+        assert_eq!(
+            did_document.verification_method()[0].id().to_string(),
+            format!("{}#key-1", did)
+        );
+        assert_eq!(did_document.key_agreement().len(), 1);
+        assert_eq!(did_document.authentication().len(), 1);
+
+        assert_eq!(did_document.service().len(), 1);
+        let service = &did_document.service()[0];
+        assert_eq!(service.id().to_string(), format!("{}#service", did));
+        assert_eq!(service.service_endpoint(), "https://example.com/endpoint");
+        assert_eq!(
+            service.extra_field("routingKeys"),
+            Some(&json!(["did:example:somemediator#somekey"]))
+        );
+    }
+
+    #[test]
+    fn test_resolve_didcomm_v2_service() {
+        let service = json!({
+            "t": "dm",
+            "s": {
+                "uri": "https://example.com/endpoint",
+                "a": ["didcomm/v2"]
+            }
+        });
+        let did = Did::parse(format!(
+            "did:peer:2.V{}.S{}",
+            VERIFICATION_KEY,
+            URL_SAFE_NO_PAD.encode(service.to_string())
+        ))
+        .unwrap();
+
+        let did_document = resolve(&did).unwrap();
+        let service = &did_document.service()[0];
+        assert_eq!(service.service_endpoint(), "https://example.com/endpoint");
+        assert_eq!(service.extra_field("accept"), Some(&json!(["didcomm/v2"])));
+    }
+
+    #[test]
+    fn test_resolve_invalid_purpose() {
+        let did = Did::parse(format!("did:peer:2.X{}", VERIFICATION_KEY)).unwrap();
+        assert!(matches!(resolve(&did), Err(DidPeerError::InvalidDid(_))));
+    }
+}
@@ -0,0 +1,180 @@
+use did_resolver::{did_doc_builder::schema::did_doc::DidDocument, did_parser::Did};
+use multibase::Base;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::DID_PEER_PREFIX;
+use crate::error::DidPeerError;
+
+// Varint encoded multicodec of json (0x0200) and multihash header of sha2-256 (0x12, 32 bytes)
+const JSON_MULTICODEC_PREFIX: [u8; 2] = [0x80, 0x04];
+const SHA2_256_MULTIHASH_PREFIX: [u8; 2] = [0x12, 0x20];
+
+const VERIFICATION_METHOD_PROPERTIES: [&str; 6] = [
+    "verificationMethod",
+    "authentication",
+    "assertionMethod",
+    "keyAgreement",
+    "capabilityInvocation",
+    "capabilityDelegation",
+];
+
+fn hash(encoded_document: &str) -> String {
+    let mut multihash = SHA2_256_MULTIHASH_PREFIX.to_vec();
+    multihash.extend_from_slice(&Sha256::digest(encoded_document.as_bytes()));
+    multibase::encode(Base::Base58Btc, multihash)
+}
+
+/// Creates the long form of a did:peer with numalgo 4 from `input_document`, a DID document
+/// without `id`, using relative references such as `#key-1` for its verification methods.
+pub fn generate(input_document: &Value) -> Result<Did, DidPeerError> {
+    let document = input_document
+        .as_object()
+        .ok_or_else(|| DidPeerError::InvalidInputDocument("Input document is not a JSON object".to_string()))?;
+    if document.contains_key("id") {
+        return Err(DidPeerError::InvalidInputDocument(
+            "Input document must not contain an id".to_string(),
+        ));
+    }
+
+    let mut encoded = JSON_MULTICODEC_PREFIX.to_vec();
+    encoded.extend(serde_json::to_vec(document)?);
+    let encoded = multibase::encode(Base::Base58Btc, encoded);
+
+    Ok(Did::parse(format!(
+        "{}4{}:{}",
+        DID_PEER_PREFIX,
+        hash(&encoded),
+        encoded
+    ))?)
+}
+
+/// Returns the short form of a numalgo 4 did:peer, which only holds the hash of the document.
+pub fn short_form(did: &Did) -> Result<Did, DidPeerError> {
+    match did.id().split_once(':') {
+        Some((short, _)) if short.starts_with('4') => Ok(Did::parse(format!("{}{}", DID_PEER_PREFIX, short))?),
+        _ => Err(DidPeerError::InvalidDid(format!(
+            "{} is not a long form numalgo 4 did:peer",
+            did.did()
+        ))),
+    }
+}
+
+/// Sets the id of the decoded document and the controller of its verification methods to the
+/// long form DID, and lists the short form in `alsoKnownAs`.
+fn contextualize(mut document: Value, did: &Did, short_form: &Did) -> Result<DidDocument, DidPeerError> {
+    let map = document
+        .as_object_mut()
+        .ok_or_else(|| DidPeerError::InvalidDid(format!("Encoded document of {} is not a JSON object", did)))?;
+
+    map.insert("id".to_string(), Value::String(did.did().to_string()));
+    let also_known_as = map.entry("alsoKnownAs").or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(also_known_as) = also_known_as {
+        also_known_as.push(Value::String(short_form.did().to_string()));
+    }
+
+    for property in VERIFICATION_METHOD_PROPERTIES {
+        if let Some(Value::Array(methods)) = map.get_mut(property) {
+            for method in methods.iter_mut().filter_map(Value::as_object_mut) {
+                method
+                    .entry("controller")
+                    .or_insert_with(|| Value::String(did.did().to_string()));
+            }
+        }
+    }
+
+    Ok(serde_json::from_value(document)?)
+}
+
+pub(crate) fn resolve(did: &Did) -> Result<DidDocument, DidPeerError> {
+    let (hashed, encoded) = did.id()[1..].split_once(':').ok_or_else(|| {
+        DidPeerError::NotFound(format!(
+            "The short form {} can not be resolved without its long form",
+            did.did()
+        ))
+    })?;
+    if hashed != hash(encoded) {
+        return Err(DidPeerError::InvalidDid(format!(
+            "Hash of {} does not match its encoded document",
+            did.did()
+        )));
+    }
+
+    let (base, decoded) = multibase::decode(encoded)?;
+    let document = match decoded.strip_prefix(&JSON_MULTICODEC_PREFIX) {
+        Some(document) if base == Base::Base58Btc => document,
+        _ => {
+            return Err(DidPeerError::InvalidDid(format!(
+                "Encoded document of {} is not base58btc encoded JSON",
+                did.did()
+            )))
+        }
+    };
+
+    contextualize(serde_json::from_slice(document)?, did, &short_form(did)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn input_document() -> Value {
+        json!({
+            "@context": ["https://www.w3.org/ns/did/v1"],
+            "verificationMethod": [
+                {
+                    "id": "#key-1",
+                    "type": "Ed25519VerificationKey2020",
+                    "publicKeyMultibase": "z6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V"
+                }
+            ],
+            "authentication": ["#key-1"]
+        })
+    }
+
+    #[test]
+    fn test_generate_and_resolve() {
+        let did = generate(&input_document()).unwrap();
+        let short = short_form(&did).unwrap();
+        assert!(did.did().starts_with(&format!("{}:", short.did())));
+
+        let did_document = resolve(&did).unwrap();
+        assert_eq!(did_document.id(), &did);
+        assert_eq!(did_document.also_known_as()[0].to_string(), short.did());
+        assert_eq!(did_document.verification_method().len(), 1);
+        assert_eq!(did_document.verification_method()[0].controller(), &did);
+        assert_eq!(did_document.authentication().len(), 1);
+    }
+
+    #[test]
+    fn test_generate_with_id() {
+        let mut document = input_document();
+        document["id"] = json!("did:example:123");
+        assert!(matches!(
+            generate(&document),
+            Err(DidPeerError::InvalidInputDocument(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_short_form() {
+        let did = generate(&input_document()).unwrap();
+        assert!(matches!(
+            resolve(&short_form(&did).unwrap()),
+            Err(DidPeerError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_tampered_document() {
+        let did = generate(&input_document()).unwrap();
+        let other = generate(&json!({ "authentication": [] })).unwrap();
+        let (hashed, _) = did.id().split_once(':').unwrap();
+        let (_, encoded) = other.id().split_once(':').unwrap();
+
+        let tampered = Did::parse(format!("did:peer:{}:{}", hashed, encoded)).unwrap();
+        assert!(matches!(resolve(&tampered), Err(DidPeerError::InvalidDid(_))));
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use did_resolver::{
+    did_parser::Did,
+    error::GenericError,
+    shared_types::{did_document_metadata::DidDocumentMetadata, media_type::MediaType},
+    traits::resolvable::{
+        resolution_error::DidResolutionError, resolution_metadata::DidResolutionMetadata,
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput, DidResolvable,
+    },
+};
+
+use crate::{
+    error::DidPeerError,
+    numalgos::{numalgo0, numalgo2, numalgo4},
+};
+
+/// Resolves did:peer DIDs with numalgo 0, 2 and 4, whose DID documents are derived from the DID
+/// itself. A numalgo 4 did:peer can only be resolved in its long form.
+#[derive(Default)]
+pub struct DidPeerResolver;
+
+impl DidPeerResolver {
+    pub fn new() -> Self {
+        DidPeerResolver
+    }
+
+    async fn resolve_did(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        if did.method() != "peer" {
+            return Err(Box::new(DidPeerError::MethodNotSupported(did.method().to_string())));
+        }
+
+        if let Some(accept) = options.accept() {
+            if accept != &MediaType::DidJson {
+                return Err(Box::new(DidPeerError::RepresentationNotSupported(accept.to_string())));
+            }
+        }
+
+        let did_document = match did.id().chars().next() {
+            Some('0') => numalgo0::resolve(did)?,
+            Some('2') => numalgo2::resolve(did)?,
+            Some('4') => numalgo4::resolve(did)?,
+            _ => return Err(Box::new(DidPeerError::NumalgoNotSupported(did.did().to_string()))),
+        };
+
+        let did_resolution_output = DidResolutionOutput::builder(did_document)
+            .did_resolution_metadata(DidResolutionMetadata::default())
+            .did_document_metadata(DidDocumentMetadata::default())
+            .build();
+
+        Ok(did_resolution_output)
+    }
+}
+
+#[async_trait]
+impl DidResolvable for DidPeerResolver {
+    async fn resolve(&self, did: &Did, options: &DidResolutionOptions) -> Result<DidResolutionOutput, GenericError> {
+        self.resolve_did(did, options)
+            .await
+            .map_err(not_found_to_resolution_error)
+    }
+}
+
+// A short form DID without its long form can not be resolved, which is reported as the method
+// independent `DidResolutionError::NotFound`
+fn not_found_to_resolution_error(err: GenericError) -> GenericError {
+    match err.downcast_ref::<DidPeerError>() {
+        Some(DidPeerError::NotFound(_)) => Box::new(DidResolutionError::NotFound),
+        _ => err,
+    }
+}
@@ -0,0 +1,133 @@
+use did_resolver::did_doc_builder::schema::service::Service;
+use did_resolver::did_doc_builder::schema::types::{uri::Uri, url::Url};
+use did_resolver::did_parser::Did;
+use did_resolver::traits::resolvable::{
+    resolution_error::DidResolutionError, resolution_options::DidResolutionOptions, DidResolvable,
+};
+use did_resolver_key::resolution::key::PublicKey;
+use did_resolver_peer::error::DidPeerError;
+use did_resolver_peer::numalgos::numalgo2::Purpose;
+use did_resolver_peer::numalgos::{numalgo0, numalgo2, numalgo4};
+use did_resolver_peer::resolution::resolver::DidPeerResolver;
+use did_resolver_registry::{cache::CachedResolver, ResolverRegistry};
+use serde_json::json;
+
+const VERIFICATION_KEY: &str = "z6MkqRYqQiSgvZQdnBytw86Qbs2ZWUkGv22od935YF4s8M7V";
+const ENCRYPTION_KEY: &str = "z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc";
+
+fn registry() -> ResolverRegistry {
+    let mut registry = ResolverRegistry::new();
+    registry.register_resolver("peer".to_string(), Box::new(DidPeerResolver::new()));
+    registry
+}
+
+#[tokio::test]
+async fn test_resolve_numalgo0() {
+    let did = numalgo0::generate(&PublicKey::from_fingerprint(VERIFICATION_KEY).unwrap()).unwrap();
+
+    let output = registry()
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(output.did_document().id(), &did);
+    assert_eq!(output.did_document().verification_method().len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_numalgo2() {
+    let service = Service::builder(
+        Uri::new("http://example.com").unwrap(),
+        Url::new("https://example.com/endpoint").unwrap(),
+    )
+    .unwrap()
+    .add_service_type("DIDCommMessaging".to_string())
+    .unwrap()
+    .build()
+    .unwrap();
+    let keys = [
+        (
+            Purpose::Encryption,
+            PublicKey::from_fingerprint(ENCRYPTION_KEY).unwrap(),
+        ),
+        (
+            Purpose::Verification,
+            PublicKey::from_fingerprint(VERIFICATION_KEY).unwrap(),
+        ),
+    ];
+    let did = numalgo2::generate(&keys, &[service]).unwrap();
+
+    let output = registry()
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap();
+    let did_document = output.did_document();
+    assert_eq!(did_document.verification_method().len(), 2);
+    assert_eq!(did_document.key_agreement().len(), 1);
+    assert_eq!(did_document.authentication().len(), 1);
+    assert_eq!(did_document.service().len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_numalgo4() {
+    let input_document = json!({
+        "verificationMethod": [
+            {
+                "id": "#key-1",
+                "type": "X25519KeyAgreementKey2020",
+                "publicKeyMultibase": ENCRYPTION_KEY
+            }
+        ],
+        "keyAgreement": ["#key-1"]
+    });
+    let did = numalgo4::generate(&input_document).unwrap();
+
+    let output = registry()
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(output.did_document().id(), &did);
+    assert_eq!(output.did_document().key_agreement().len(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_numalgo4_short_form_cached_as_not_found() {
+    let input_document = json!({
+        "verificationMethod": [
+            {
+                "id": "#key-1",
+                "type": "X25519KeyAgreementKey2020",
+                "publicKeyMultibase": ENCRYPTION_KEY
+            }
+        ],
+        "keyAgreement": ["#key-1"]
+    });
+    let did = numalgo4::short_form(&numalgo4::generate(&input_document).unwrap()).unwrap();
+
+    let cached_registry = CachedResolver::new(registry());
+    for _ in 0..2 {
+        let err = cached_registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DidResolutionError>(),
+            Some(DidResolutionError::NotFound)
+        ));
+    }
+    assert_eq!(cached_registry.hits(), 1);
+    assert_eq!(cached_registry.misses(), 1);
+}
+
+#[tokio::test]
+async fn test_resolve_unsupported_numalgo() {
+    let did = Did::parse(format!("did:peer:1{}", VERIFICATION_KEY)).unwrap();
+
+    let err = DidPeerResolver::new()
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidPeerError>(),
+        Some(DidPeerError::NumalgoNotSupported(_))
+    ));
+}
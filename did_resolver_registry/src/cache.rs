@@ -0,0 +1,451 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use did_resolver::{
+    did_parser::Did,
+    error::GenericError,
+    traits::resolvable::{
+        resolution_error::DidResolutionError, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput, DidResolvable,
+    },
+};
+use tokio::time::Instant;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(10);
+const DEFAULT_MAX_SIZE: usize = 1000;
+
+// Everything in the options which may change the resolution result, except `noCache`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CacheKey {
+    did: String,
+    accept: Option<String>,
+    version_id: Option<String>,
+    version_time: Option<i64>,
+}
+
+impl CacheKey {
+    fn new(did: &Did, options: &DidResolutionOptions) -> Self {
+        Self {
+            did: did.did().to_string(),
+            accept: options.accept().map(|accept| accept.to_string()),
+            version_id: options.version_id().cloned(),
+            version_time: options
+                .version_time()
+                .map(|version_time| version_time.timestamp()),
+        }
+    }
+}
+
+// Resolver errors can not be cloned, so only the not found outcome is kept for failures
+#[derive(Clone)]
+enum CachedResult {
+    Resolved(DidResolutionOutput),
+    NotFound,
+}
+
+impl CachedResult {
+    fn into_result(self) -> Result<DidResolutionOutput, GenericError> {
+        match self {
+            CachedResult::Resolved(output) => Ok(output),
+            CachedResult::NotFound => Err(Box::new(DidResolutionError::NotFound)),
+        }
+    }
+}
+
+// Entries are indexed by expiry, the tick telling apart entries expiring at the same instant
+type ExpiryKey = (Instant, u64);
+
+struct CacheEntry {
+    result: CachedResult,
+    expiry: ExpiryKey,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    entries: HashMap<CacheKey, CacheEntry>,
+    expiries: BTreeMap<ExpiryKey, CacheKey>,
+    next_tick: u64,
+}
+
+impl CacheEntries {
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.expiries.remove(&entry.expiry);
+        }
+    }
+
+    fn evict_first_expiring(&mut self) {
+        let first = self.expiries.keys().next().copied();
+        if let Some(key) = first.and_then(|expiry| self.expiries.remove(&expiry)) {
+            self.entries.remove(&key);
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, result: CachedResult, expires_at: Instant) {
+        let expiry = (expires_at, self.next_tick);
+        self.next_tick += 1;
+        self.expiries.insert(expiry, key.clone());
+        if let Some(previous) = self.entries.insert(key, CacheEntry { result, expiry }) {
+            self.expiries.remove(&previous.expiry);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.expiries.clear();
+    }
+}
+
+fn is_resolution_not_found(err: &GenericError) -> bool {
+    matches!(
+        err.downcast_ref::<DidResolutionError>(),
+        Some(DidResolutionError::NotFound)
+    )
+}
+
+/// Wraps a resolver, such as a single method resolver or a whole
+/// [`ResolverRegistry`](crate::ResolverRegistry), keeping its results for a limited time.
+///
+/// Successful resolutions are kept for `ttl`. Failures recognized as not found, by default the
+/// [`DidResolutionError::NotFound`] which the sov, indy, web and peer resolvers report for missing
+/// DIDs, are kept for the shorter `negative_ttl` and always reported as
+/// [`DidResolutionError::NotFound`], whether cached or not. Other failures are passed through
+/// and never cached. Once `max_size` entries are cached, the entry expiring first is dropped.
+/// Resolving with the `noCache` option always calls the wrapped resolver and refreshes the
+/// cached result.
+pub struct CachedResolver<R> {
+    resolver: R,
+    ttl: Duration,
+    negative_ttl: Duration,
+    max_size: usize,
+    is_not_found: fn(&GenericError) -> bool,
+    entries: Mutex<CacheEntries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<R> CachedResolver<R>
+where
+    R: DidResolvable + Send + Sync,
+{
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            ttl: DEFAULT_TTL,
+            negative_ttl: DEFAULT_NEGATIVE_TTL,
+            max_size: DEFAULT_MAX_SIZE,
+            is_not_found: is_resolution_not_found,
+            entries: Mutex::new(CacheEntries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn set_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn set_negative_ttl(mut self, negative_ttl: Duration) -> Self {
+        self.negative_ttl = negative_ttl;
+        self
+    }
+
+    pub fn set_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets how failures of the wrapped resolver are recognized as not found, e.g. by
+    /// downcasting to the error of a resolver which does not report
+    /// [`DidResolutionError::NotFound`].
+    pub fn set_is_not_found(mut self, is_not_found: fn(&GenericError) -> bool) -> Self {
+        self.is_not_found = is_not_found;
+        self
+    }
+
+    pub fn resolver(&self) -> &R {
+        &self.resolver
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<CachedResult> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.entries.get(key) {
+            Some(entry) if entry.expiry.0 > Instant::now() => Some(entry.result.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: CacheKey, result: CachedResult) {
+        if self.max_size == 0 {
+            return;
+        }
+        let ttl = match result {
+            CachedResult::Resolved(_) => self.ttl,
+            CachedResult::NotFound => self.negative_ttl,
+        };
+        let mut entries = self.entries.lock().unwrap();
+        if !entries.entries.contains_key(&key) && entries.entries.len() >= self.max_size {
+            entries.evict_first_expiring();
+        }
+        entries.insert(key, result, Instant::now() + ttl);
+    }
+}
+
+#[async_trait]
+impl<R> DidResolvable for CachedResolver<R>
+where
+    R: DidResolvable + Send + Sync,
+{
+    async fn resolve(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        let key = CacheKey::new(did, options);
+        if !options.no_cache() {
+            if let Some(result) = self.lookup(&key) {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return result.into_result();
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let result = match self.resolver.resolve(did, options).await {
+            Ok(output) => CachedResult::Resolved(output),
+            Err(err) if (self.is_not_found)(&err) => CachedResult::NotFound,
+            Err(err) => return Err(err),
+        };
+        self.store(key, result.clone());
+        result.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{atomic::AtomicUsize, Arc};
+
+    use did_resolver::did_doc_builder::schema::did_doc::DidDocumentBuilder;
+
+    use super::*;
+    use crate::error::DidResolverRegistryError;
+
+    // Counts the calls which reach it, failing for `did:example:missing` and `did:example:broken`
+    #[derive(Default)]
+    struct CountingResolver {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl DidResolvable for CountingResolver {
+        async fn resolve(
+            &self,
+            did: &Did,
+            _options: &DidResolutionOptions,
+        ) -> Result<DidResolutionOutput, GenericError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match did.id() {
+                "missing" => return Err(Box::new(DidResolutionError::NotFound)),
+                "broken" => return Err(Box::new(DidResolverRegistryError::UnsupportedMethod)),
+                _ => {}
+            }
+            Ok(DidResolutionOutput::builder(DidDocumentBuilder::new(did.clone()).build()).build())
+        }
+    }
+
+    fn did(id: &str) -> Did {
+        Did::parse(format!("did:example:{}", id)).unwrap()
+    }
+
+    fn cached_resolver() -> (CachedResolver<CountingResolver>, Arc<AtomicUsize>) {
+        let resolver = CountingResolver::default();
+        let calls = resolver.calls.clone();
+        (CachedResolver::new(resolver), calls)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_cached() {
+        let (cache, calls) = cached_resolver();
+        let options = DidResolutionOptions::default();
+
+        let first = cache.resolve(&did("1234"), &options).await.unwrap();
+        let second = cache.resolve(&did("1234"), &options).await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.misses(), 1);
+
+        cache.resolve(&did("5678"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_options_are_part_of_the_key() {
+        let (cache, calls) = cached_resolver();
+
+        cache
+            .resolve(&did("1234"), &DidResolutionOptions::default())
+            .await
+            .unwrap();
+        let options = DidResolutionOptions::default().set_version_id("1".to_string());
+        cache.resolve(&did("1234"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_no_cache() {
+        let (cache, calls) = cached_resolver();
+
+        cache
+            .resolve(&did("1234"), &DidResolutionOptions::default())
+            .await
+            .unwrap();
+        let options = DidResolutionOptions::default().set_no_cache(true);
+        cache.resolve(&did("1234"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.hits(), 0);
+        assert_eq!(cache.misses(), 2);
+    }
+
+    fn is_not_found(err: &GenericError) -> bool {
+        matches!(
+            err.downcast_ref::<DidResolutionError>(),
+            Some(DidResolutionError::NotFound)
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_resolve_expired() {
+        let (cache, calls) = cached_resolver();
+        let cache = cache.set_ttl(Duration::from_millis(20));
+        let options = DidResolutionOptions::default();
+
+        cache.resolve(&did("1234"), &options).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        cache.resolve(&did("1234"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_resolve_not_found_cached_briefly() {
+        let (cache, calls) = cached_resolver();
+        let cache = cache.set_negative_ttl(Duration::from_millis(20));
+        let options = DidResolutionOptions::default();
+
+        let first = cache.resolve(&did("missing"), &options).await.unwrap_err();
+        let cached = cache.resolve(&did("missing"), &options).await.unwrap_err();
+        assert!(is_not_found(&first));
+        assert!(is_not_found(&cached));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.hits(), 1);
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(cache.resolve(&did("missing"), &options).await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_other_failure_not_cached() {
+        let (cache, calls) = cached_resolver();
+        let options = DidResolutionOptions::default();
+
+        for _ in 0..2 {
+            let err = cache.resolve(&did("broken"), &options).await.unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DidResolverRegistryError>(),
+                Some(DidResolverRegistryError::UnsupportedMethod)
+            ));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_custom_not_found() {
+        let (cache, calls) = cached_resolver();
+        let cache = cache.set_is_not_found(|err| {
+            matches!(
+                err.downcast_ref::<DidResolverRegistryError>(),
+                Some(DidResolverRegistryError::UnsupportedMethod)
+            )
+        });
+        let options = DidResolutionOptions::default();
+
+        for _ in 0..2 {
+            let err = cache.resolve(&did("broken"), &options).await.unwrap_err();
+            assert!(is_not_found(&err));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_max_size() {
+        let (cache, calls) = cached_resolver();
+        let cache = cache.set_max_size(2);
+        let options = DidResolutionOptions::default();
+
+        for id in ["1", "2", "3"] {
+            cache.resolve(&did(id), &options).await.unwrap();
+        }
+        assert_eq!(cache.len(), 2);
+
+        // The first expiring entry was evicted to make room for the third one
+        cache.resolve(&did("3"), &options).await.unwrap();
+        cache.resolve(&did("1"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_resolve_max_size_evicts_first_expiring() {
+        let (cache, calls) = cached_resolver();
+        let cache = cache
+            .set_max_size(2)
+            .set_negative_ttl(Duration::from_millis(20));
+        let options = DidResolutionOptions::default();
+
+        cache.resolve(&did("1"), &options).await.unwrap();
+        assert!(cache.resolve(&did("missing"), &options).await.is_err());
+        cache.resolve(&did("2"), &options).await.unwrap();
+        assert_eq!(cache.len(), 2);
+
+        // The not found result expires before the older successful resolution
+        cache.resolve(&did("1"), &options).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(cache.hits(), 1);
+    }
+}
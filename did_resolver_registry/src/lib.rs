@@ -1,7 +1,9 @@
+pub mod cache;
 pub mod error;
 
 use std::collections::HashMap;
 
+use async_trait::async_trait;
 use did_resolver::{
     did_parser::Did,
     error::GenericError,
@@ -13,7 +15,7 @@ use did_resolver::{
 use error::DidResolverRegistryError;
 
 pub struct ResolverRegistry {
-    resolvers: HashMap<String, Box<dyn DidResolvable>>,
+    resolvers: HashMap<String, Box<dyn DidResolvable + Send + Sync>>,
 }
 
 impl ResolverRegistry {
@@ -23,7 +25,11 @@ impl ResolverRegistry {
         }
     }
 
-    pub fn register_resolver(&mut self, method: String, resolver: Box<dyn DidResolvable>) {
+    pub fn register_resolver(
+        &mut self,
+        method: String,
+        resolver: Box<dyn DidResolvable + Send + Sync>,
+    ) {
         self.resolvers.insert(method, resolver);
     }
 
@@ -44,10 +50,21 @@ impl ResolverRegistry {
     }
 }
 
+// Lets the registry as a whole be wrapped, e.g. by a `CachedResolver`
+#[async_trait]
+impl DidResolvable for ResolverRegistry {
+    async fn resolve(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        ResolverRegistry::resolve(self, did, options).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
     use did_resolver::did_doc_builder::schema::did_doc::DidDocumentBuilder;
     use mockall::{automock, predicate::eq};
     use std::{error::Error, pin::Pin};
@@ -223,4 +240,41 @@ mod tests {
             .await;
         assert!(result_after.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_resolve_through_cached_registry() {
+        let did = "did:example:1234";
+        let parsed_did = Did::parse(did.to_string()).unwrap();
+        let method = parsed_did.method().to_string();
+
+        let mut mock_resolver = MockDummyDidResolver::new();
+        mock_resolver
+            .expect_resolve()
+            .with(eq(parsed_did.clone()), eq(DidResolutionOptions::default()))
+            .times(1)
+            .return_once(move |_, _| {
+                let future = async move {
+                    Ok::<DidResolutionOutput, GenericError>(
+                        DidResolutionOutput::builder(
+                            DidDocumentBuilder::new(Did::parse(did.to_string()).unwrap()).build(),
+                        )
+                        .build(),
+                    )
+                };
+                Pin::from(Box::new(future))
+            });
+
+        let mut registry = ResolverRegistry::new();
+        registry.register_resolver(method, Box::new(mock_resolver));
+        let cached_registry = cache::CachedResolver::new(registry);
+
+        for _ in 0..2 {
+            let result = cached_registry
+                .resolve(&parsed_did, &DidResolutionOptions::default())
+                .await;
+            assert!(result.is_ok());
+        }
+        assert_eq!(cached_registry.hits(), 1);
+        assert_eq!(cached_registry.misses(), 1);
+    }
 }
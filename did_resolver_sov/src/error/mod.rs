@@ -23,6 +23,8 @@ pub enum DidSovError {
     InternalError,
     #[error("Invalid DID: {0}")]
     InvalidDid(String),
+    #[error("Invalid resolution options: {0}")]
+    InvalidOptions(String),
     #[error("AriesVCX Core error: {0}")]
     AriesVcxCoreError(#[from] AriesVcxCoreError),
     #[error("DID Document Builder Error: {0}")]
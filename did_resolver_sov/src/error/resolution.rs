@@ -8,6 +8,7 @@ impl From<&DidSovError> for DidResolutionError {
     fn from(err: &DidSovError) -> Self {
         match err {
             DidSovError::NotFound(_) => DidResolutionError::NotFound,
+            DidSovError::InvalidDid(_) => DidResolutionError::InvalidDid,
             DidSovError::MethodNotSupported(_) => DidResolutionError::MethodNotSupported,
             _ => DidResolutionError::InternalError,
         }
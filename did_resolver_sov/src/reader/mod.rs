@@ -5,7 +5,10 @@ pub mod vdr_reader;
 
 use std::sync::Arc;
 
-use aries_vcx_core::ledger::base_ledger::BaseLedger;
+use aries_vcx_core::{
+    errors::error::AriesVcxCoreErrorKind,
+    ledger::{base_ledger::BaseLedger, types::NymResponse},
+};
 use async_trait::async_trait;
 
 use crate::error::DidSovError;
@@ -14,7 +17,14 @@ use crate::error::DidSovError;
 #[cfg_attr(test, mockall::automock)]
 pub trait AttrReader: Send + Sync {
     async fn get_attr(&self, target_did: &str, attr_name: &str) -> Result<String, DidSovError>;
-    async fn get_nym(&self, did: &str) -> Result<String, DidSovError>;
+    async fn get_nym(&self, did: &str) -> Result<NymResponse, DidSovError>;
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> Result<NymResponse, DidSovError>;
+    async fn get_nym_created(&self, did: &str, txn_time: u64) -> Result<u64, DidSovError>;
 }
 
 pub struct ConcreteAttrReader {
@@ -30,8 +40,40 @@ impl AttrReader for ConcreteAttrReader {
             .map_err(|err| err.into())
     }
 
-    async fn get_nym(&self, did: &str) -> Result<String, DidSovError> {
-        self.ledger.get_nym(did).await.map_err(|err| err.into())
+    async fn get_nym(&self, did: &str) -> Result<NymResponse, DidSovError> {
+        self.ledger
+            .get_nym(did)
+            .await
+            .map_err(|err| match err.kind() {
+                AriesVcxCoreErrorKind::LedgerItemNotFound => {
+                    DidSovError::NotFound(format!("DID {did} not found"))
+                }
+                _ => err.into(),
+            })
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &str,
+        seq_no: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> Result<NymResponse, DidSovError> {
+        self.ledger
+            .get_nym_version(did, seq_no, timestamp)
+            .await
+            .map_err(|err| match err.kind() {
+                AriesVcxCoreErrorKind::LedgerItemNotFound => {
+                    DidSovError::NotFound(format!("No such version of DID {did}"))
+                }
+                _ => err.into(),
+            })
+    }
+
+    async fn get_nym_created(&self, did: &str, txn_time: u64) -> Result<u64, DidSovError> {
+        self.ledger
+            .get_nym_created(did, txn_time)
+            .await
+            .map_err(|err| err.into())
     }
 }
 
@@ -2,3 +2,4 @@ mod resolver;
 mod utils;
 
 pub use resolver::DidSovResolver;
+pub use utils::{get_data_from_response, get_nym, is_versioned, unix_to_datetime, NymCreatedCache};
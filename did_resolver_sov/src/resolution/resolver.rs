@@ -6,32 +6,31 @@ use did_resolver::{
     error::GenericError,
     shared_types::media_type::MediaType,
     traits::resolvable::{
-        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
-        DidResolvable,
+        resolution_error::DidResolutionError, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput, DidResolvable,
     },
 };
-use serde_json::Value;
 
-use crate::{
-    error::{parsing::ParsingErrorSource, DidSovError},
-    reader::AttrReader,
-};
+use crate::{error::DidSovError, reader::AttrReader};
 
-use super::utils::{is_valid_sovrin_did_id, ledger_response_to_ddo};
+use super::utils::{
+    get_nym, is_valid_sovrin_did_id, is_versioned, ledger_response_to_ddo, NymCreatedCache,
+};
 
 pub struct DidSovResolver {
     ledger: Arc<dyn AttrReader>,
+    created: NymCreatedCache,
 }
 
 impl DidSovResolver {
     pub fn new(ledger: Arc<dyn AttrReader>) -> Self {
-        DidSovResolver { ledger }
+        DidSovResolver {
+            ledger,
+            created: NymCreatedCache::default(),
+        }
     }
-}
 
-#[async_trait]
-impl DidResolvable for DidSovResolver {
-    async fn resolve(
+    async fn resolve_did(
         &self,
         parsed_did: &Did,
         options: &DidResolutionOptions,
@@ -54,33 +53,46 @@ impl DidResolvable for DidSovResolver {
             )));
         }
         let did = parsed_did.did();
-        let ledger_response = self.ledger.get_attr(did, "endpoint").await?;
-        let verkey = self.get_verkey(did).await?;
-        ledger_response_to_ddo(did, &ledger_response, verkey)
+        let nym_response = get_nym(self.ledger.as_ref(), did, options).await?;
+        let created = match nym_response.txn_time {
+            Some(txn_time) => Some(
+                self.created
+                    .get_nym_created(self.ledger.as_ref(), did, txn_time)
+                    .await?,
+            ),
+            None => None,
+        };
+        // Only the current endpoint ATTRIB can be read from the ledger, so past versions of the
+        // document are served without their service
+        let ledger_response = if is_versioned(options) {
+            None
+        } else {
+            Some(self.ledger.get_attr(did, "endpoint").await?)
+        };
+        let resolution_output =
+            ledger_response_to_ddo(did, ledger_response.as_deref(), &nym_response, created).await?;
+        Ok(resolution_output)
+    }
+}
+
+#[async_trait]
+impl DidResolvable for DidSovResolver {
+    async fn resolve(
+        &self,
+        parsed_did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
+        self.resolve_did(parsed_did, options)
             .await
-            .map_err(|err| err.into())
+            .map_err(not_found_to_resolution_error)
     }
 }
 
-impl DidSovResolver {
-    async fn get_verkey(&self, did: &str) -> Result<String, DidSovError> {
-        let nym_response = self.ledger.get_nym(did).await?;
-        let nym_json: Value = serde_json::from_str(&nym_response)?;
-        let nym_data = nym_json["result"]["data"]
-            .as_str()
-            .ok_or(DidSovError::ParsingError(
-                ParsingErrorSource::LedgerResponseParsingError(
-                    "Failed to parse nym data".to_string(),
-                ),
-            ))?;
-        let nym_data: Value = serde_json::from_str(nym_data)?;
-        let verkey = nym_data["verkey"]
-            .as_str()
-            .ok_or(DidSovError::ParsingError(
-                ParsingErrorSource::LedgerResponseParsingError(
-                    "Failed to parse verkey from nym data".to_string(),
-                ),
-            ))?;
-        Ok(verkey.to_string())
+// Ledger lookups of DIDs which were never written fail with `DidResolutionError::NotFound`,
+// shared by all methods, so that wrappers such as a resolution cache can recognize them
+fn not_found_to_resolution_error(err: GenericError) -> GenericError {
+    match err.downcast_ref::<DidSovError>() {
+        Some(DidSovError::NotFound(_)) => Box::new(DidResolutionError::NotFound),
+        _ => err,
     }
 }
@@ -1,3 +1,6 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use aries_vcx_core::ledger::types::NymResponse;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use did_resolver::{
     did_doc_builder::schema::{
@@ -7,13 +10,15 @@ use did_resolver::{
     did_parser::Did,
     shared_types::did_document_metadata::DidDocumentMetadata,
     traits::resolvable::{
-        resolution_metadata::DidResolutionMetadata, resolution_output::DidResolutionOutput,
+        resolution_metadata::DidResolutionMetadata, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput,
     },
 };
 use serde_json::Value;
 
 use crate::{
     error::{parsing::ParsingErrorSource, DidSovError},
+    reader::AttrReader,
     service::{DidSovServiceType, EndpointDidSov},
 };
 
@@ -23,7 +28,7 @@ fn prepare_ids(did: &str) -> Result<(Uri, Did), DidSovError> {
     Ok((service_id, ddo_id))
 }
 
-fn get_data_from_response(resp: &str) -> Result<Value, DidSovError> {
+pub fn get_data_from_response(resp: &str) -> Result<Value, DidSovError> {
     let resp: serde_json::Value = serde_json::from_str(resp)?;
     match &resp["result"]["data"] {
         Value::String(ref data) => serde_json::from_str(data).map_err(|err| err.into()),
@@ -46,11 +51,80 @@ fn get_txn_time_from_response(resp: &str) -> Result<i64, DidSovError> {
     Ok(txn_time)
 }
 
-fn unix_to_datetime(posix_timestamp: i64) -> Option<DateTime<Utc>> {
+pub fn unix_to_datetime(posix_timestamp: i64) -> Option<DateTime<Utc>> {
     NaiveDateTime::from_timestamp_opt(posix_timestamp, 0)
         .map(|date_time| DateTime::<Utc>::from_utc(date_time, Utc))
 }
 
+pub fn is_versioned(options: &DidResolutionOptions) -> bool {
+    options.version_id().is_some() || options.version_time().is_some()
+}
+
+// The versionId of a did:sov document is the sequence number of the NYM transaction, as for
+// did:indy, so that both methods identify the same ledger state by the same version
+pub async fn get_nym(
+    ledger: &dyn AttrReader,
+    did: &str,
+    options: &DidResolutionOptions,
+) -> Result<NymResponse, DidSovError> {
+    if !is_versioned(options) {
+        return ledger.get_nym(did).await;
+    }
+    let seq_no = options
+        .version_id()
+        .map(|version_id| {
+            version_id.parse::<u64>().map_err(|_| {
+                DidSovError::InvalidOptions(format!(
+                    "versionId {version_id} is not a sequence number"
+                ))
+            })
+        })
+        .transpose()?;
+    let timestamp = options
+        .version_time()
+        .map(|version_time| {
+            u64::try_from(version_time.timestamp()).map_err(|_| {
+                DidSovError::InvalidOptions(format!(
+                    "versionTime {} is out of range",
+                    version_time.timestamp()
+                ))
+            })
+        })
+        .transpose()?;
+    ledger.get_nym_version(did, seq_no, timestamp).await
+}
+
+// Bounds the memory held by a long lived resolver, as NYMs are resolved by external input
+const MAX_CACHED_NYMS: usize = 10_000;
+
+/// Remembers when each NYM was first written. That time never changes, but finding it takes a
+/// search over the versions of the NYM, see [`AttrReader::get_nym_created`].
+#[derive(Default)]
+pub struct NymCreatedCache {
+    created: Mutex<HashMap<String, u64>>,
+}
+
+impl NymCreatedCache {
+    /// Returns the creation time of the NYM of `did`, searching `ledger` only the first time.
+    pub async fn get_nym_created(
+        &self,
+        ledger: &dyn AttrReader,
+        did: &str,
+        txn_time: u64,
+    ) -> Result<u64, DidSovError> {
+        if let Some(created) = self.created.lock().unwrap().get(did) {
+            return Ok(*created);
+        }
+        let created = ledger.get_nym_created(did, txn_time).await?;
+        let mut cached = self.created.lock().unwrap();
+        if cached.len() >= MAX_CACHED_NYMS {
+            cached.clear();
+        }
+        cached.insert(did.to_string(), created);
+        Ok(created)
+    }
+}
+
 pub(super) fn is_valid_sovrin_did_id(id: &str) -> bool {
     if id.len() < 21 || id.len() > 22 {
         return false;
@@ -59,29 +133,33 @@ pub(super) fn is_valid_sovrin_did_id(id: &str) -> bool {
     id.chars().all(|c| base58_chars.contains(c))
 }
 
+fn endpoint_service(service_id: Uri, resp: &str) -> Result<Service, DidSovError> {
+    let service_data = get_data_from_response(resp)?;
+    let endpoint: EndpointDidSov = serde_json::from_value(service_data["endpoint"].clone())?;
+    let mut service_builder = Service::builder(service_id, endpoint.endpoint.as_str().try_into()?)?;
+    for t in endpoint.types {
+        if t != DidSovServiceType::Unknown {
+            service_builder = service_builder.add_service_type(t.to_string())?;
+        };
+    }
+    Ok(service_builder.build()?)
+}
+
+// The document is derived from the NYM and, for its current version, from the endpoint ATTRIB in
+// `resp`. It was therefore last updated by whichever of the two was written last, and created by
+// the first NYM transaction at `created`.
 pub(super) async fn ledger_response_to_ddo(
     did: &str,
-    resp: &str,
-    verkey: String,
+    resp: Option<&str>,
+    nym: &NymResponse,
+    created: Option<u64>,
 ) -> Result<DidResolutionOutput, DidSovError> {
     let (service_id, ddo_id) = prepare_ids(did)?;
-
-    let service_data = get_data_from_response(resp)?;
-    let endpoint: EndpointDidSov = serde_json::from_value(service_data["endpoint"].clone())?;
-
-    let txn_time = get_txn_time_from_response(resp)?;
-    let datetime = unix_to_datetime(txn_time);
-
-    let service = {
-        let mut service_builder =
-            Service::builder(service_id, endpoint.endpoint.as_str().try_into()?)?;
-        for t in endpoint.types {
-            if t != DidSovServiceType::Unknown {
-                service_builder = service_builder.add_service_type(t.to_string())?;
-            };
-        }
-        service_builder.build()?
-    };
+    let verkey = nym.verkey.clone().ok_or(DidSovError::ParsingError(
+        ParsingErrorSource::LedgerResponseParsingError(
+            "Failed to parse verkey from nym data".to_string(),
+        ),
+    ))?;
 
     // TODO: Use multibase instead of base58
     let verification_method = VerificationMethod::builder(
@@ -89,22 +167,28 @@ pub(super) async fn ledger_response_to_ddo(
         did.to_string().try_into()?,
         "Ed25519VerificationKey2018".to_string(),
     )
-    .add_extra_field(
-        "publicKeyBase58".to_string(),
-        Value::String(verkey.to_string()),
-    )
+    .add_extra_field("publicKeyBase58".to_string(), Value::String(verkey))
     .build();
 
-    let ddo = DidDocument::builder(ddo_id)
-        .add_service(service)
-        .add_verification_method(verification_method)
-        .build();
+    let mut ddo_builder = DidDocument::builder(ddo_id).add_verification_method(verification_method);
+    let mut updated = nym.txn_time.map(|txn_time| txn_time as i64);
+    if let Some(resp) = resp {
+        ddo_builder = ddo_builder.add_service(endpoint_service(service_id, resp)?);
+        let attrib_txn_time = get_txn_time_from_response(resp)?;
+        updated = Some(updated.map_or(attrib_txn_time, |updated| updated.max(attrib_txn_time)));
+    }
 
     let ddo_metadata = {
         let mut metadata_builder = DidDocumentMetadata::builder().deactivated(false);
-        if let Some(datetime) = datetime {
+        if let Some(datetime) = updated.and_then(unix_to_datetime) {
             metadata_builder = metadata_builder.updated(datetime);
         };
+        if let Some(datetime) = created.and_then(|created| unix_to_datetime(created as i64)) {
+            metadata_builder = metadata_builder.created(datetime);
+        };
+        if let Some(seq_no) = nym.seq_no {
+            metadata_builder = metadata_builder.version_id(seq_no.to_string());
+        };
         metadata_builder.build()
     };
 
@@ -112,7 +196,7 @@ pub(super) async fn ledger_response_to_ddo(
         .content_type("application/did+json".to_string())
         .build();
 
-    Ok(DidResolutionOutput::builder(ddo)
+    Ok(DidResolutionOutput::builder(ddo_builder.build())
         .did_document_metadata(ddo_metadata)
         .did_resolution_metadata(resolution_metadata)
         .build())
@@ -121,8 +205,56 @@ pub(super) async fn ledger_response_to_ddo(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use async_trait::async_trait;
     use chrono::TimeZone;
 
+    // Echoes the requested NYM version
+    #[derive(Default)]
+    struct VersionReader;
+
+    #[async_trait]
+    impl AttrReader for VersionReader {
+        async fn get_attr(
+            &self,
+            _target_did: &str,
+            _attr_name: &str,
+        ) -> Result<String, DidSovError> {
+            Err(DidSovError::NotFound("No ATTRIB".to_string()))
+        }
+
+        async fn get_nym(&self, did: &str) -> Result<NymResponse, DidSovError> {
+            self.get_nym_version(did, None, None).await
+        }
+
+        async fn get_nym_version(
+            &self,
+            did: &str,
+            seq_no: Option<u64>,
+            timestamp: Option<u64>,
+        ) -> Result<NymResponse, DidSovError> {
+            Ok(NymResponse {
+                did: did.to_string(),
+                seq_no,
+                txn_time: timestamp,
+                ..Default::default()
+            })
+        }
+
+        async fn get_nym_created(&self, _did: &str, txn_time: u64) -> Result<u64, DidSovError> {
+            Ok(txn_time)
+        }
+    }
+
+    fn nym(seq_no: Option<u64>, txn_time: Option<u64>) -> NymResponse {
+        NymResponse {
+            did: "1234567890".to_string(),
+            verkey: Some("9wvq2i4xUa5umXoThe83CDgx1e5bsjZKJL4DEWvTP9qe".to_string()),
+            seq_no,
+            txn_time,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_prepare_ids() {
         let did = "did:example:1234567890".to_string();
@@ -175,8 +307,9 @@ mod tests {
                 "txnTime": 1629272938
             }
         }"#;
-        let verkey = "9wvq2i4xUa5umXoThe83CDgx1e5bsjZKJL4DEWvTP9qe".to_string();
-        let resolution_output = ledger_response_to_ddo(did, resp, verkey).await.unwrap();
+        let resolution_output = ledger_response_to_ddo(did, Some(resp), &nym(None, None), None)
+            .await
+            .unwrap();
         let ddo = resolution_output.did_document();
         assert_eq!(ddo.id().to_string(), "did:example:1234567890");
         assert_eq!(ddo.service()[0].id().to_string(), "did:example:1234567890");
@@ -203,4 +336,87 @@ mod tests {
             "application/did+json"
         );
     }
+
+    #[tokio::test]
+    async fn test_resolve_ddo_metadata() {
+        let did = "did:example:1234567890";
+        let resp = r#"{
+            "result": {
+                "data": "{\"endpoint\":{\"endpoint\":\"https://example.com\"}}",
+                "seqNo": 15,
+                "txnTime": 1629272938
+            }
+        }"#;
+        let resolution_output = ledger_response_to_ddo(
+            did,
+            Some(resp),
+            &nym(Some(12), Some(1629273000)),
+            Some(1629270000),
+        )
+        .await
+        .unwrap();
+        let metadata = resolution_output.did_document_metadata();
+        // The version is identified by the NYM, not by the endpoint ATTRIB
+        assert_eq!(metadata.version_id().unwrap(), "12");
+        assert_eq!(
+            metadata.updated().unwrap(),
+            chrono::Utc.timestamp_opt(1629273000, 0).unwrap()
+        );
+        assert_eq!(
+            metadata.created().unwrap(),
+            chrono::Utc.timestamp_opt(1629270000, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_ddo_without_endpoint() {
+        let did = "did:example:1234567890";
+        let resolution_output =
+            ledger_response_to_ddo(did, None, &nym(Some(12), Some(1629273000)), None)
+                .await
+                .unwrap();
+        assert!(resolution_output.did_document().service().is_empty());
+        assert_eq!(
+            resolution_output.did_document_metadata().updated().unwrap(),
+            chrono::Utc.timestamp_opt(1629273000, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_nym_version() {
+        let reader = VersionReader::default();
+        let nym = get_nym(&reader, "did", &DidResolutionOptions::new())
+            .await
+            .unwrap();
+        assert_eq!(nym.seq_no, None);
+
+        let options = DidResolutionOptions::new().set_version_id("12".to_string());
+        let nym = get_nym(&reader, "did", &options).await.unwrap();
+        assert_eq!(nym.seq_no, Some(12));
+
+        let options = DidResolutionOptions::new()
+            .set_version_time(chrono::Utc.timestamp_opt(1629272938, 0).unwrap());
+        let nym = get_nym(&reader, "did", &options).await.unwrap();
+        assert_eq!(nym.txn_time, Some(1629272938));
+
+        let options = DidResolutionOptions::new().set_version_id("latest".to_string());
+        assert!(matches!(
+            get_nym(&reader, "did", &options).await,
+            Err(DidSovError::InvalidOptions(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_nym_created_cached() {
+        let reader = VersionReader::default();
+        let cache = NymCreatedCache::default();
+        let created = cache.get_nym_created(&reader, "did", 1629270000).await;
+        assert_eq!(created.unwrap(), 1629270000);
+
+        // The ledger is not searched again, even when a later version is resolved
+        let created = cache.get_nym_created(&reader, "did", 1629273000).await;
+        assert_eq!(created.unwrap(), 1629270000);
+        let created = cache.get_nym_created(&reader, "other", 1629273000).await;
+        assert_eq!(created.unwrap(), 1629273000);
+    }
 }
@@ -8,6 +8,8 @@ use self::parsing::ParsingErrorSource;
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum DidWebError {
+    #[error("Not found: {0}")]
+    NotFound(String),
     #[error("DID method not supported: {0}")]
     MethodNotSupported(String),
     #[error("Representation not supported: {0}")]
@@ -1,17 +1,19 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use did_resolver::{
     did_parser::Did,
     error::GenericError,
     shared_types::{did_document_metadata::DidDocumentMetadata, media_type::MediaType},
     traits::resolvable::{
-        resolution_metadata::DidResolutionMetadata, resolution_options::DidResolutionOptions,
-        resolution_output::DidResolutionOutput, DidResolvable,
+        resolution_error::DidResolutionError, resolution_metadata::DidResolutionMetadata,
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput, DidResolvable,
     },
 };
 use hyper::{
     client::{connect::Connect, HttpConnector},
+    header::{CACHE_CONTROL, LAST_MODIFIED},
     http::uri::{self, Scheme},
-    Body, Client, Uri,
+    Body, Client, Request, StatusCode, Uri,
 };
 use hyper_tls::HttpsConnector;
 
@@ -47,25 +49,45 @@ impl<C> DidWebResolver<C>
 where
     C: Connect + Send + Sync + Clone + 'static,
 {
-    async fn fetch_did_document(&self, url: Uri) -> Result<String, DidWebError> {
-        let res = self.client.get(url).await?;
+    // Returns the DID document along with the time it was last modified, if the server tells
+    async fn fetch_did_document(
+        &self,
+        url: Uri,
+        no_cache: bool,
+    ) -> Result<(String, Option<DateTime<Utc>>), DidWebError> {
+        let mut req = Request::get(url);
+        if no_cache {
+            req = req.header(CACHE_CONTROL, "no-cache");
+        }
+        let res = self
+            .client
+            .request(
+                req.body(Body::empty())
+                    .map_err(|err| DidWebError::Other(Box::new(err)))?,
+            )
+            .await?;
 
         if !res.status().is_success() {
             return Err(DidWebError::NonSuccessResponse(res.status()));
         }
 
+        let last_modified = res
+            .headers()
+            .get(LAST_MODIFIED)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .map(|last_modified| last_modified.with_timezone(&Utc));
+
         let body = hyper::body::to_bytes(res.into_body()).await?;
 
-        String::from_utf8(body.to_vec()).map_err(|err| err.into())
+        Ok((String::from_utf8(body.to_vec())?, last_modified))
     }
-}
 
-#[async_trait]
-impl<C> DidResolvable for DidWebResolver<C>
-where
-    C: Connect + Send + Sync + Clone + 'static,
-{
-    async fn resolve(&self, did: &Did, options: &DidResolutionOptions) -> Result<DidResolutionOutput, GenericError> {
+    async fn resolve_did(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions,
+    ) -> Result<DidResolutionOutput, GenericError> {
         if did.method() != "web" {
             return Err(Box::new(DidWebError::MethodNotSupported(did.method().to_string())));
         }
@@ -76,6 +98,14 @@ where
             }
         }
 
+        // did:web documents are not versioned, only the one currently hosted can be resolved
+        if let Some(version_id) = options.version_id() {
+            return Err(Box::new(DidWebError::NotFound(format!(
+                "did:web does not support versions, requested {}",
+                version_id
+            ))));
+        }
+
         let did_parts: Vec<&str> = did.id().split(':').collect();
 
         if did_parts.is_empty() {
@@ -97,13 +127,55 @@ where
             .path_and_query(path_and_query.as_str())
             .build()?;
 
-        let did_document = serde_json::from_str(&self.fetch_did_document(url).await?)?;
+        let (did_document, last_modified) = self.fetch_did_document(url, options.no_cache()).await?;
+        let did_document = serde_json::from_str(&did_document)?;
+
+        if let Some(version_time) = options.version_time() {
+            if last_modified.map_or(true, |last_modified| last_modified > version_time) {
+                return Err(Box::new(DidWebError::NotFound(format!(
+                    "The hosted DID document is not known to have been valid at {}",
+                    version_time
+                ))));
+            }
+        }
+
+        let mut did_document_metadata = DidDocumentMetadata::builder();
+        if let Some(last_modified) = last_modified {
+            did_document_metadata = did_document_metadata.updated(last_modified);
+        }
 
         let did_resolution_output = DidResolutionOutput::builder(did_document)
-            .did_resolution_metadata(DidResolutionMetadata::default())
-            .did_document_metadata(DidDocumentMetadata::default())
+            .did_resolution_metadata(
+                DidResolutionMetadata::builder()
+                    .content_type("application/did+json".to_string())
+                    .build(),
+            )
+            .did_document_metadata(did_document_metadata.build())
             .build();
 
         Ok(did_resolution_output)
     }
 }
+
+#[async_trait]
+impl<C> DidResolvable for DidWebResolver<C>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    async fn resolve(&self, did: &Did, options: &DidResolutionOptions) -> Result<DidResolutionOutput, GenericError> {
+        self.resolve_did(did, options)
+            .await
+            .map_err(not_found_to_resolution_error)
+    }
+}
+
+// Documents which are not hosted, or not in the requested version, are reported as the method
+// independent `DidResolutionError::NotFound`
+fn not_found_to_resolution_error(err: GenericError) -> GenericError {
+    match err.downcast_ref::<DidWebError>() {
+        Some(DidWebError::NotFound(_)) | Some(DidWebError::NonSuccessResponse(StatusCode::NOT_FOUND)) => {
+            Box::new(DidResolutionError::NotFound)
+        }
+        _ => err,
+    }
+}
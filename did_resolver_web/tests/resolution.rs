@@ -1,8 +1,12 @@
+use chrono::{DateTime, Utc};
 use did_resolver::did_doc_builder::schema::did_doc::DidDocument;
 use did_resolver::did_parser::Did;
-use did_resolver::traits::resolvable::{resolution_options::DidResolutionOptions, DidResolvable};
+use did_resolver::traits::resolvable::{
+    resolution_error::DidResolutionError, resolution_options::DidResolutionOptions, DidResolvable,
+};
 use did_resolver_web::resolution::resolver::DidWebResolver;
 use hyper::{
+    header::LAST_MODIFIED,
     service::{make_service_fn, service_fn},
     Body, Request, Response, Server,
 };
@@ -64,9 +68,14 @@ const DID_DOCUMENT: &'static str = r##"
   ]
 }"##;
 
+const LAST_MODIFIED_DATE: &str = "Wed, 21 Oct 2015 07:28:00 GMT";
+
 async fn mock_server_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
     let response = match req.uri().path() {
-        "/.well-known/did.json" | "/user/alice/did.json" => Response::new(Body::from(DID_DOCUMENT)),
+        "/.well-known/did.json" | "/user/alice/did.json" => Response::builder()
+            .header(LAST_MODIFIED, LAST_MODIFIED_DATE)
+            .body(Body::from(DID_DOCUMENT))
+            .unwrap(),
         _ => Response::builder().status(404).body(Body::from("Not Found")).unwrap(),
     };
 
@@ -118,3 +127,39 @@ async fn test_did_web_resolver() {
     );
     verify_did_document(result_2.did_document());
 }
+
+#[tokio::test]
+async fn test_did_web_resolver_metadata_and_versions() {
+    let port = 3001;
+    let host = create_mock_server(port).await;
+
+    let did_web_resolver = DidWebResolver::http();
+    let did = Did::parse(format!("did:web:{}%3A{}", host, port)).unwrap();
+    let last_modified = DateTime::parse_from_rfc2822(LAST_MODIFIED_DATE)
+        .unwrap()
+        .with_timezone(&Utc);
+
+    let result = assert_ok!(
+        did_web_resolver
+            .resolve(&did, &DidResolutionOptions::default().set_no_cache(true))
+            .await
+    );
+    assert_eq!(result.did_document_metadata().updated(), Some(last_modified));
+
+    let options = DidResolutionOptions::default().set_version_time(last_modified);
+    assert_ok!(did_web_resolver.resolve(&did, &options).await);
+
+    let options = DidResolutionOptions::default().set_version_time(last_modified - chrono::Duration::seconds(1));
+    let err = did_web_resolver.resolve(&did, &options).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidResolutionError>(),
+        Some(DidResolutionError::NotFound)
+    ));
+
+    let options = DidResolutionOptions::default().set_version_id("1".to_string());
+    let err = did_web_resolver.resolve(&did, &options).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<DidResolutionError>(),
+        Some(DidResolutionError::NotFound)
+    ));
+}
@@ -95,7 +95,12 @@ pub async fn ledger_clear_attr(target_did: &str, attr: &str) -> LibvcxResult<Str
 pub async fn ledger_get_txn_author_agreement() -> LibvcxResult<String> {
     let profile = get_main_profile()?;
     let ledger = profile.inject_ledger();
-    map_ariesvcx_core_result(ledger.get_txn_author_agreement().await)
+    let agreement = map_ariesvcx_core_result(ledger.get_txn_author_agreement().await)?;
+    // callers expect an empty object if the ledger has no author agreement set
+    match agreement {
+        Some(agreement) => Ok(serde_json::to_string(&agreement)?),
+        None => Ok("{}".to_string()),
+    }
 }
 
 pub fn ledger_set_txn_author_agreement(
@@ -164,6 +169,9 @@ pub mod tests {
         let _setup = SetupMocks::init();
 
         let agreement = ledger_get_txn_author_agreement().await.unwrap();
-        assert_eq!(aries_vcx::utils::constants::DEFAULT_AUTHOR_AGREEMENT, agreement);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(aries_vcx::utils::constants::DEFAULT_AUTHOR_AGREEMENT).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&agreement).unwrap()
+        );
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use aries_vcx::common::ledger::transactions::into_did_doc;
-use aries_vcx::handlers::out_of_band::receiver::OutOfBandReceiver;
+use aries_vcx::handlers::out_of_band::receiver::{HandshakeProtocol, OobConnection, OutOfBandReceiver};
 use aries_vcx::handlers::out_of_band::sender::OutOfBandSender;
 use aries_vcx::handlers::util::AnyInvitation;
 use aries_vcx::messages::msg_fields::protocols::out_of_band::invitation::OobService;
@@ -204,10 +204,23 @@ pub async fn build_connection(handle: u32) -> LibvcxResult<String> {
     let invitation = AnyInvitation::Oob(oob.oob.clone());
     let profile = get_main_profile()?;
     let ddo = into_did_doc(&profile, &invitation).await?;
-    oob.build_connection(&profile, &get_main_agency_client()?, ddo, false)
-        .await?
-        .to_string()
-        .map_err(|err| err.into())
+    // libvcx only drives mediated connections/1.0 connections
+    let connection = oob
+        .build_connection_with_protocols(
+            &profile,
+            &get_main_agency_client()?,
+            ddo,
+            false,
+            &[HandshakeProtocol::Connections],
+        )
+        .await?;
+    match connection {
+        OobConnection::Connection(connection) => connection.to_string().map_err(|err| err.into()),
+        OobConnection::DidExchange(_) => Err(LibvcxError::from_msg(
+            LibvcxErrorKind::ActionNotSupported,
+            "DID exchange handshake is not supported for mediated connections",
+        )),
+    }
 }
 
 pub fn get_thread_id_sender(handle: u32) -> LibvcxResult<String> {
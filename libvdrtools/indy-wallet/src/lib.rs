@@ -649,13 +649,12 @@ impl WalletService {
         .await
     }
 
-    #[allow(dead_code)] // TODO: Should we implement getting all records or delete everywhere?
-    pub fn search_all_records(&self, _wallet_handle: WalletHandle) -> IndyResult<WalletSearch> {
-        //        match self.wallets.lock().await.get(&wallet_handle) {
-        //            Some(wallet) => wallet.search_all_records(),
-        //            None => Err(IndyError::InvalidHandle(wallet_handle.to_string()))
-        //        }
-        unimplemented!()
+    pub async fn search_all_records(&self, wallet_handle: WalletHandle) -> IndyResult<WalletSearch> {
+        let wallet = self.get_wallet(wallet_handle).await?;
+
+        Ok(WalletSearch {
+            iter: wallet.get_all().await?,
+        })
     }
 
     pub async fn upsert_indy_object<T>(
@@ -13,7 +13,7 @@ use indy_utils::crypto::{
 };
 
 use crate::utils::crypto::base58::ToBase58;
-use indy_wallet::{KeyDerivationData, WalletService};
+use indy_wallet::{KeyDerivationData, WalletSearch, WalletService};
 
 use crate::services::CryptoService;
 
@@ -428,6 +428,35 @@ impl WalletController {
         Ok(res)
     }
 
+    /// Opens a search over all records stored in the wallet, including the records
+    /// holding the indy DIDs, keys and anoncreds objects (types prefixed with `Indy::`).
+    ///
+    /// Used to migrate the content of the wallet into a different storage.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handle returned by indy_open_wallet.
+    ///
+    /// #Returns
+    /// Search yielding the records with their type, value and tags
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    pub async fn search_all_records(
+        &self,
+        wallet_handle: WalletHandle,
+    ) -> IndyResult<WalletSearch> {
+        trace!("search_all_records > handle: {:?}", wallet_handle);
+
+        let res = self
+            .wallet_service
+            .search_all_records(wallet_handle)
+            .await?;
+
+        trace!("search_all_records < search opened");
+        Ok(res)
+    }
+
     async fn _derive_key(key_data: &KeyDerivationData) -> IndyResult<MasterKey> {
         key_data.calc_master_key()
         // let res = spawn_blocking(move || key_data.calc_master_key()).await?;
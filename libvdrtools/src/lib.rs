@@ -74,6 +74,8 @@ pub use indy_api_types::{
 
 pub use services::AnoncredsHelpers;
 
+pub use indy_wallet::{WalletRecord, WalletSearch};
+
 // Global (lazy inited) instance of Locator
 lazy_static! {
     static ref LOCATOR: Locator = Locator::new();
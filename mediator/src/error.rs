@@ -0,0 +1,21 @@
+use aries_vcx_core::errors::error::AriesVcxCoreError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum MediatorError {
+    #[error("Wallet error: {0}")]
+    WalletError(#[from] AriesVcxCoreError),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Invalid encoding: {0}")]
+    Utf8Error(#[from] std::string::FromUtf8Error),
+    #[error("Invalid wallet record: {0}")]
+    InvalidRecord(String),
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+    #[error("Message type not supported by the mediator: {0}")]
+    UnsupportedMessage(String),
+}
+
+pub type MediatorResult<T> = Result<T, MediatorError>;
@@ -0,0 +1,9 @@
+//! Library implementing the mediator role: it accepts inbound packed messages, queues the
+//! content of forward messages per recipient key in a wallet, and lets clients manage their
+//! keys through coordinate-mediation and retrieve their messages through message pickup.
+
+pub mod error;
+pub mod mediator;
+pub mod storage;
+
+pub use mediator::Mediator;
@@ -0,0 +1,594 @@
+use std::sync::Arc;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use chrono::Utc;
+use diddoc::aries::diddoc::AriesDidDoc;
+use messages::decorators::attachment::{Attachment, AttachmentData, AttachmentType};
+use messages::decorators::thread::Thread;
+use messages::msg_fields::protocols::coordinate_mediation::keylist::{
+    Keylist, KeylistContent, KeylistDecorators, KeylistItem, KeylistPagination,
+};
+use messages::msg_fields::protocols::coordinate_mediation::keylist_query::KeylistQuery;
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update::{
+    KeylistUpdate, KeylistUpdateItem, KeylistUpdateItemAction,
+};
+use messages::msg_fields::protocols::coordinate_mediation::keylist_update_response::{
+    KeylistUpdateItemResult, KeylistUpdateResponse, KeylistUpdateResponseContent, KeylistUpdateResponseDecorators,
+    KeylistUpdateResponseItem,
+};
+use messages::msg_fields::protocols::coordinate_mediation::mediate_grant::{
+    MediateGrant, MediateGrantContent, MediateGrantDecorators,
+};
+use messages::msg_fields::protocols::coordinate_mediation::CoordinateMediation;
+use messages::msg_fields::protocols::pickup::delivery::{Delivery, DeliveryContent};
+use messages::msg_fields::protocols::pickup::status::{Status, StatusContent};
+use messages::msg_fields::protocols::pickup::{Pickup, PickupDecorators};
+use messages::msg_fields::protocols::report_problem::{ProblemReport, ProblemReportContent, ProblemReportDecorators};
+use messages::msg_fields::protocols::routing::Forward;
+use messages::AriesMessage;
+use serde::Deserialize;
+use serde_json::json;
+use url::Url;
+use uuid::Uuid;
+
+use crate::error::{MediatorError, MediatorResult};
+use crate::storage::MediatorStore;
+
+/// Problem code sent to clients using pickup or keylist messages before mediation was granted.
+pub const PROBLEM_CODE_MEDIATION_NOT_GRANTED: &str = "e.p.mediation-not-granted";
+/// Problem code sent to clients requesting live delivery, which the mediator does not support.
+pub const PROBLEM_CODE_LIVE_MODE_NOT_SUPPORTED: &str = "e.m.live-mode-not-supported";
+
+#[derive(Deserialize)]
+struct UnpackedMessage {
+    message: String,
+    sender_verkey: Option<String>,
+}
+
+/// A mediator, as described by the [mediators and relays](<https://github.com/hyperledger/aries-rfcs/blob/main/concepts/0046-mediators-and-relays/README.md>) RFC.
+///
+/// Clients obtain mediation through [coordinate-mediation 1.0](<https://github.com/hyperledger/aries-rfcs/blob/main/features/0211-route-coordination/README.md>)
+/// and register the recipient keys they want messages to be routed for. Forward messages addressed
+/// to those keys are queued in a [`MediatorStore`] until the client retrieves them through
+/// [message pickup 2.0](<https://github.com/hyperledger/aries-rfcs/blob/main/features/0685-pickup-v2/README.md>).
+///
+/// The mediator does not do any transport itself: the hosting service passes every inbound
+/// payload to [`Mediator::receive`] and sends the returned reply back over the same connection,
+/// as if `return_route` was always set to `all`.
+#[derive(Clone, Debug)]
+pub struct Mediator {
+    wallet: Arc<dyn BaseWallet>,
+    store: MediatorStore,
+    endpoint: Url,
+    verkey: String,
+}
+
+impl Mediator {
+    pub fn new(wallet: Arc<dyn BaseWallet>, endpoint: Url, verkey: String) -> Self {
+        Self {
+            store: MediatorStore::new(wallet.clone()),
+            wallet,
+            endpoint,
+            verkey,
+        }
+    }
+
+    /// Creates a mediator reachable at `endpoint`, generating a new key in `wallet`.
+    pub async fn create(wallet: Arc<dyn BaseWallet>, endpoint: Url) -> MediatorResult<Self> {
+        let (_, verkey) = wallet.create_and_store_my_did(None, None).await?;
+        Ok(Self::new(wallet, endpoint, verkey))
+    }
+
+    pub fn verkey(&self) -> &str {
+        &self.verkey
+    }
+
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    pub fn store(&self) -> &MediatorStore {
+        &self.store
+    }
+
+    /// DIDDoc clients use to reach the mediator, e.g. when setting up a pickup client.
+    pub fn did_doc(&self) -> AriesDidDoc {
+        let mut did_doc = AriesDidDoc::default();
+        did_doc.set_service_endpoint(self.endpoint.clone());
+        did_doc.set_recipient_keys(vec![self.verkey.clone()]);
+        did_doc
+    }
+
+    /// Processes a packed inbound message, returning the packed reply to send back, if any.
+    ///
+    /// Forward messages may be anoncrypted and never get a reply. Every other message must be
+    /// authcrypted, as the sender key identifies the client.
+    pub async fn receive(&self, packed: &[u8]) -> MediatorResult<Option<Vec<u8>>> {
+        let unpacked = self.wallet.unpack_message(packed).await?;
+        let unpacked: UnpackedMessage = serde_json::from_slice(&unpacked)?;
+        let message: AriesMessage = serde_json::from_str(&unpacked.message)?;
+
+        match (
+            self.handle_message(message, unpacked.sender_verkey.as_deref()).await?,
+            unpacked.sender_verkey,
+        ) {
+            (Some(reply), Some(sender_vk)) => Ok(Some(self.pack_reply(&reply, &sender_vk).await?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Processes an already unpacked message sent by `sender_vk`, returning the reply, if any.
+    pub async fn handle_message(
+        &self,
+        message: AriesMessage,
+        sender_vk: Option<&str>,
+    ) -> MediatorResult<Option<AriesMessage>> {
+        if let AriesMessage::Routing(forward) = message {
+            self.handle_forward(forward).await?;
+            return Ok(None);
+        }
+
+        let client_vk = sender_vk
+            .ok_or_else(|| MediatorError::InvalidMessage("Only forward messages may be anoncrypted".to_owned()))?;
+
+        match message {
+            AriesMessage::CoordinateMediation(message) => {
+                self.handle_coordinate_mediation(message, client_vk).await.map(Some)
+            }
+            AriesMessage::Pickup(message) => self.handle_pickup(message, client_vk).await.map(Some),
+            message => Err(unsupported_message(&message)),
+        }
+    }
+
+    async fn handle_forward(&self, forward: Forward) -> MediatorResult<()> {
+        let recipient_key = forward.content.to;
+        if self.store.client_for_recipient_key(&recipient_key).await?.is_none() {
+            return Err(MediatorError::InvalidMessage(format!(
+                "No client registered recipient key {}",
+                recipient_key
+            )));
+        }
+        let message = serde_json::to_vec(&forward.content.msg)?;
+        self.store.enqueue_message(&recipient_key, &message).await?;
+        Ok(())
+    }
+
+    async fn handle_coordinate_mediation(
+        &self,
+        message: CoordinateMediation,
+        client_vk: &str,
+    ) -> MediatorResult<AriesMessage> {
+        match message {
+            CoordinateMediation::MediateRequest(request) => {
+                self.store.grant_mediation(client_vk).await?;
+                let content = MediateGrantContent::new(self.endpoint.clone(), vec![self.verkey.clone()]);
+                let decorators = MediateGrantDecorators::new(reply_thread(&request.id, request.decorators.thread));
+                Ok(MediateGrant::with_decorators(Uuid::new_v4().to_string(), content, decorators).into())
+            }
+            CoordinateMediation::KeylistUpdate(update) => {
+                let thread = reply_thread(&update.id, update.decorators.thread.clone());
+                if !self.store.is_mediation_granted(client_vk).await? {
+                    return Ok(problem_report(thread, PROBLEM_CODE_MEDIATION_NOT_GRANTED));
+                }
+                self.handle_keylist_update(update, client_vk, thread).await
+            }
+            CoordinateMediation::KeylistQuery(query) => {
+                let thread = reply_thread(&query.id, query.decorators.thread.clone());
+                if !self.store.is_mediation_granted(client_vk).await? {
+                    return Ok(problem_report(thread, PROBLEM_CODE_MEDIATION_NOT_GRANTED));
+                }
+                self.handle_keylist_query(query, client_vk, thread).await
+            }
+            message => Err(unsupported_message(&message.into())),
+        }
+    }
+
+    async fn handle_keylist_update(
+        &self,
+        update: KeylistUpdate,
+        client_vk: &str,
+        thread: Thread,
+    ) -> MediatorResult<AriesMessage> {
+        let mut updated = Vec::with_capacity(update.content.updates.len());
+        for item in update.content.updates {
+            let result = self.update_recipient_key(&item, client_vk).await?;
+            updated.push(KeylistUpdateResponseItem::new(item.recipient_key, item.action, result));
+        }
+
+        let content = KeylistUpdateResponseContent::new(updated);
+        let decorators = KeylistUpdateResponseDecorators::new(thread);
+        Ok(KeylistUpdateResponse::with_decorators(Uuid::new_v4().to_string(), content, decorators).into())
+    }
+
+    async fn update_recipient_key(
+        &self,
+        item: &KeylistUpdateItem,
+        client_vk: &str,
+    ) -> MediatorResult<KeylistUpdateItemResult> {
+        let owner = self.store.client_for_recipient_key(&item.recipient_key).await?;
+
+        let result = match (item.action, owner) {
+            (KeylistUpdateItemAction::Add, None) => {
+                self.store.add_recipient_key(client_vk, &item.recipient_key).await?;
+                KeylistUpdateItemResult::Success
+            }
+            (KeylistUpdateItemAction::Remove, Some(owner)) if owner == client_vk => {
+                self.store.remove_recipient_key(&item.recipient_key).await?;
+                KeylistUpdateItemResult::Success
+            }
+            (KeylistUpdateItemAction::Add, Some(owner)) if owner == client_vk => KeylistUpdateItemResult::NoChange,
+            (KeylistUpdateItemAction::Remove, None) => KeylistUpdateItemResult::NoChange,
+            // The key is registered by another client.
+            (_, Some(_)) => KeylistUpdateItemResult::ClientError,
+        };
+        Ok(result)
+    }
+
+    async fn handle_keylist_query(
+        &self,
+        query: KeylistQuery,
+        client_vk: &str,
+        thread: Thread,
+    ) -> MediatorResult<AriesMessage> {
+        let mut keys = self.store.recipient_keys(client_vk).await?;
+        if let Some(filter) = query.content.filter.as_ref().and_then(|f| f.get("recipient_key")) {
+            keys.retain(|key| filter.contains(key));
+        }
+
+        let total = keys.len();
+        let (offset, limit) = query
+            .content
+            .paginate
+            .as_ref()
+            .map(|paginate| (paginate.offset as usize, paginate.limit as usize))
+            .unwrap_or((0, total));
+        let page: Vec<_> = keys
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(KeylistItem::new)
+            .collect();
+
+        let remaining = total.saturating_sub(offset).saturating_sub(page.len());
+        let pagination = query
+            .content
+            .paginate
+            .map(|paginate| KeylistPagination::new(page.len() as u32, paginate.offset, remaining as u32));
+
+        let mut content = KeylistContent::new(page);
+        content.pagination = pagination;
+        let decorators = KeylistDecorators::new(thread);
+        Ok(Keylist::with_decorators(Uuid::new_v4().to_string(), content, decorators).into())
+    }
+
+    async fn handle_pickup(&self, message: Pickup, client_vk: &str) -> MediatorResult<AriesMessage> {
+        let (id, thread) = match &message {
+            Pickup::StatusRequest(msg) => (&msg.id, &msg.decorators.thread),
+            Pickup::DeliveryRequest(msg) => (&msg.id, &msg.decorators.thread),
+            Pickup::MessagesReceived(msg) => (&msg.id, &msg.decorators.thread),
+            Pickup::LiveDeliveryChange(msg) => (&msg.id, &msg.decorators.thread),
+            Pickup::Status(_) | Pickup::Delivery(_) => return Err(unsupported_message(&message.into())),
+        };
+        let thread = reply_thread(id, thread.clone());
+
+        if !self.store.is_mediation_granted(client_vk).await? {
+            return Ok(problem_report(thread, PROBLEM_CODE_MEDIATION_NOT_GRANTED));
+        }
+
+        match message {
+            Pickup::StatusRequest(request) => {
+                let recipient_key = request.content.recipient_key;
+                let keys = self.client_keys(client_vk, recipient_key.as_deref()).await?;
+                self.status(&keys, recipient_key, thread).await.map(From::from)
+            }
+            Pickup::DeliveryRequest(request) => {
+                let recipient_key = request.content.recipient_key;
+                let keys = self.client_keys(client_vk, recipient_key.as_deref()).await?;
+
+                let messages = self.store.queued_messages(&keys).await?;
+                if messages.is_empty() {
+                    return self.status(&keys, recipient_key, thread).await.map(From::from);
+                }
+
+                let attach = messages
+                    .into_iter()
+                    .take(request.content.limit as usize)
+                    .map(|message| {
+                        let data = AttachmentData::new(AttachmentType::Base64(base64::encode(message.message)));
+                        let mut attachment = Attachment::new(data);
+                        attachment.id = Some(message.id);
+                        attachment
+                    })
+                    .collect();
+
+                let mut content = DeliveryContent::new(attach);
+                content.recipient_key = recipient_key;
+                let decorators = pickup_decorators(thread);
+                Ok(Delivery::with_decorators(Uuid::new_v4().to_string(), content, decorators).into())
+            }
+            Pickup::MessagesReceived(received) => {
+                let keys = self.client_keys(client_vk, None).await?;
+                self.store
+                    .remove_messages(&keys, &received.content.message_id_list)
+                    .await?;
+                self.status(&keys, None, thread).await.map(From::from)
+            }
+            Pickup::LiveDeliveryChange(change) => {
+                if change.content.live_delivery {
+                    return Ok(problem_report(thread, PROBLEM_CODE_LIVE_MODE_NOT_SUPPORTED));
+                }
+                let keys = self.client_keys(client_vk, None).await?;
+                self.status(&keys, None, thread).await.map(From::from)
+            }
+            message => Err(unsupported_message(&message.into())),
+        }
+    }
+
+    /// Recipient keys of the client, narrowed down to `recipient_key` when given. A key not
+    /// registered by the client yields no keys, so that it never sees the queues of others.
+    async fn client_keys(&self, client_vk: &str, recipient_key: Option<&str>) -> MediatorResult<Vec<String>> {
+        let mut keys = self.store.recipient_keys(client_vk).await?;
+        if let Some(recipient_key) = recipient_key {
+            keys.retain(|key| key == recipient_key);
+        }
+        Ok(keys)
+    }
+
+    async fn status(&self, keys: &[String], recipient_key: Option<String>, thread: Thread) -> MediatorResult<Status> {
+        let messages = self.store.queued_messages(keys).await?;
+
+        let mut content = StatusContent::new(messages.len() as u32);
+        content.recipient_key = recipient_key;
+        content.total_bytes = Some(messages.iter().map(|message| message.message.len() as u64).sum());
+        content.live_delivery = Some(false);
+        if let (Some(oldest), Some(newest)) = (messages.first(), messages.last()) {
+            let waited = Utc::now().signed_duration_since(oldest.received_at).num_seconds();
+            content.longest_waited_seconds = Some(waited.max(0) as u32);
+            content.oldest_received_time = Some(oldest.received_at);
+            content.newest_received_time = Some(newest.received_at);
+        }
+
+        Ok(Status::with_decorators(
+            Uuid::new_v4().to_string(),
+            content,
+            pickup_decorators(thread),
+        ))
+    }
+
+    async fn pack_reply(&self, reply: &AriesMessage, receiver_vk: &str) -> MediatorResult<Vec<u8>> {
+        let receiver_keys = json!([receiver_vk]).to_string();
+        let packed = self
+            .wallet
+            .pack_message(Some(&self.verkey), &receiver_keys, json!(reply).to_string().as_bytes())
+            .await?;
+        Ok(packed)
+    }
+}
+
+fn reply_thread(request_id: &str, thread: Option<Thread>) -> Thread {
+    thread.unwrap_or_else(|| Thread::new(request_id.to_owned()))
+}
+
+fn pickup_decorators(thread: Thread) -> PickupDecorators {
+    let mut decorators = PickupDecorators::default();
+    decorators.thread = Some(thread);
+    decorators
+}
+
+fn problem_report(thread: Thread, code: &str) -> AriesMessage {
+    let content = ProblemReportContent::new(code.to_owned());
+    let mut decorators = ProblemReportDecorators::default();
+    decorators.thread = Some(thread);
+    ProblemReport::with_decorators(Uuid::new_v4().to_string(), content, decorators).into()
+}
+
+fn unsupported_message(message: &AriesMessage) -> MediatorError {
+    let msg_type = json!(message)["@type"].as_str().unwrap_or_default().to_owned();
+    MediatorError::UnsupportedMessage(msg_type)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+    use messages::msg_fields::protocols::coordinate_mediation::keylist_update::{
+        KeylistUpdateContent, KeylistUpdateDecorators,
+    };
+    use messages::msg_fields::protocols::coordinate_mediation::mediate_request::{
+        MediateRequest, MediateRequestContent, MediateRequestDecorators,
+    };
+    use messages::msg_fields::protocols::pickup::delivery_request::{DeliveryRequest, DeliveryRequestContent};
+    use messages::msg_fields::protocols::pickup::messages_received::{MessagesReceived, MessagesReceivedContent};
+    use messages::msg_fields::protocols::pickup::status_request::{StatusRequest, StatusRequestContent};
+    use messages::msg_fields::protocols::routing::ForwardContent;
+    use messages::msg_fields::protocols::trust_ping::ping::{Ping, PingContent, PingDecorators};
+
+    use super::*;
+
+    struct Client {
+        wallet: Arc<dyn BaseWallet>,
+        verkey: String,
+    }
+
+    impl Client {
+        async fn new() -> Self {
+            let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+            let (_, verkey) = wallet.create_and_store_my_did(None, None).await.unwrap();
+            Self { wallet, verkey }
+        }
+
+        async fn pack(&self, msg: &AriesMessage, recipient_vk: &str, authcrypt: bool) -> Vec<u8> {
+            let sender_vk = authcrypt.then_some(self.verkey.as_str());
+            self.wallet
+                .pack_message(
+                    sender_vk,
+                    &json!([recipient_vk]).to_string(),
+                    json!(msg).to_string().as_bytes(),
+                )
+                .await
+                .unwrap()
+        }
+
+        async fn unpack(&self, packed: &[u8]) -> AriesMessage {
+            let unpacked = self.wallet.unpack_message(packed).await.unwrap();
+            let unpacked: UnpackedMessage = serde_json::from_slice(&unpacked).unwrap();
+            serde_json::from_str(&unpacked.message).unwrap()
+        }
+
+        async fn send(&self, mediator: &Mediator, msg: AriesMessage) -> AriesMessage {
+            let packed = self.pack(&msg, mediator.verkey(), true).await;
+            let reply = mediator.receive(&packed).await.unwrap().unwrap();
+            self.unpack(&reply).await
+        }
+    }
+
+    async fn create_mediator() -> Mediator {
+        let wallet: Arc<dyn BaseWallet> = Arc::new(InMemoryWallet::new());
+        Mediator::create(wallet, "https://mediator.example.org".parse().unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn mediate_request() -> AriesMessage {
+        MediateRequest::with_decorators(
+            Uuid::new_v4().to_string(),
+            MediateRequestContent::default(),
+            MediateRequestDecorators::default(),
+        )
+        .into()
+    }
+
+    fn keylist_update(recipient_key: &str, action: KeylistUpdateItemAction) -> AriesMessage {
+        let content = KeylistUpdateContent::new(vec![KeylistUpdateItem::new(recipient_key.to_owned(), action)]);
+        KeylistUpdate::with_decorators(Uuid::new_v4().to_string(), content, KeylistUpdateDecorators::default()).into()
+    }
+
+    fn status_request() -> AriesMessage {
+        StatusRequest::with_decorators(
+            Uuid::new_v4().to_string(),
+            StatusRequestContent::default(),
+            PickupDecorators::default(),
+        )
+        .into()
+    }
+
+    fn message_count(reply: AriesMessage) -> u32 {
+        match reply {
+            AriesMessage::Pickup(Pickup::Status(status)) => status.content.message_count,
+            msg => panic!("Expected status, got {:?}", msg),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mediation_forward_and_pickup() {
+        let mediator = create_mediator().await;
+        let client = Client::new().await;
+        let sender = Client::new().await;
+        let (_, recipient_key) = client.wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        match client.send(&mediator, mediate_request()).await {
+            AriesMessage::CoordinateMediation(CoordinateMediation::MediateGrant(grant)) => {
+                assert_eq!(mediator.endpoint(), &grant.content.endpoint);
+                assert_eq!(vec![mediator.verkey().to_owned()], grant.content.routing_keys);
+            }
+            msg => panic!("Expected mediate grant, got {:?}", msg),
+        }
+
+        let update = keylist_update(&recipient_key, KeylistUpdateItemAction::Add);
+        match client.send(&mediator, update).await {
+            AriesMessage::CoordinateMediation(CoordinateMediation::KeylistUpdateResponse(response)) => {
+                assert_eq!(KeylistUpdateItemResult::Success, response.content.updated[0].result);
+            }
+            msg => panic!("Expected keylist update response, got {:?}", msg),
+        }
+
+        let ping: AriesMessage = Ping::with_decorators(
+            Uuid::new_v4().to_string(),
+            PingContent::default(),
+            PingDecorators::default(),
+        )
+        .into();
+        let packed_ping = sender.pack(&ping, &recipient_key, true).await;
+        let content = ForwardContent::new(recipient_key.clone(), serde_json::from_slice(&packed_ping).unwrap());
+        let forward: AriesMessage = Forward::new(Uuid::new_v4().to_string(), content).into();
+        let packed_forward = sender.pack(&forward, mediator.verkey(), false).await;
+        assert_eq!(None, mediator.receive(&packed_forward).await.unwrap());
+
+        assert_eq!(1, message_count(client.send(&mediator, status_request()).await));
+
+        let request = DeliveryRequest::with_decorators(
+            Uuid::new_v4().to_string(),
+            DeliveryRequestContent::new(10),
+            PickupDecorators::default(),
+        );
+        let attachment = match client.send(&mediator, request.into()).await {
+            AriesMessage::Pickup(Pickup::Delivery(delivery)) => delivery.content.attach.into_iter().next().unwrap(),
+            msg => panic!("Expected delivery, got {:?}", msg),
+        };
+        let delivered = match attachment.data.content {
+            AttachmentType::Base64(encoded) => base64::decode(encoded).unwrap(),
+            content => panic!("Expected base64 attachment, got {:?}", content),
+        };
+        assert_eq!(ping, client.unpack(&delivered).await);
+
+        let content = MessagesReceivedContent::new(vec![attachment.id.unwrap()]);
+        let received =
+            MessagesReceived::with_decorators(Uuid::new_v4().to_string(), content, PickupDecorators::default());
+        assert_eq!(0, message_count(client.send(&mediator, received.into()).await));
+    }
+
+    #[tokio::test]
+    async fn test_keylist_update_requires_mediation() {
+        let mediator = create_mediator().await;
+        let client = Client::new().await;
+
+        match client
+            .send(&mediator, keylist_update("recipient_key", KeylistUpdateItemAction::Add))
+            .await
+        {
+            AriesMessage::ReportProblem(report) => {
+                assert_eq!(PROBLEM_CODE_MEDIATION_NOT_GRANTED, report.content.description.code);
+            }
+            msg => panic!("Expected problem report, got {:?}", msg),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recipient_key_of_other_client() {
+        let mediator = create_mediator().await;
+        let client = Client::new().await;
+        let other_client = Client::new().await;
+
+        client.send(&mediator, mediate_request()).await;
+        other_client.send(&mediator, mediate_request()).await;
+        client
+            .send(&mediator, keylist_update("recipient_key", KeylistUpdateItemAction::Add))
+            .await;
+
+        let update = keylist_update("recipient_key", KeylistUpdateItemAction::Remove);
+        match other_client.send(&mediator, update).await {
+            AriesMessage::CoordinateMediation(CoordinateMediation::KeylistUpdateResponse(response)) => {
+                assert_eq!(KeylistUpdateItemResult::ClientError, response.content.updated[0].result);
+            }
+            msg => panic!("Expected keylist update response, got {:?}", msg),
+        }
+        assert_eq!(
+            Some(client.verkey.clone()),
+            mediator
+                .store()
+                .client_for_recipient_key("recipient_key")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_to_unknown_recipient_key_fails() {
+        let mediator = create_mediator().await;
+        let content = ForwardContent::new("unknown_key".to_owned(), json!({}));
+        let forward = Forward::new(Uuid::new_v4().to_string(), content);
+
+        let result = mediator.handle_message(forward.into(), None).await;
+        assert!(matches!(result, Err(MediatorError::InvalidMessage(_))));
+    }
+}
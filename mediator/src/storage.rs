@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use aries_vcx_core::errors::error::AriesVcxCoreErrorKind;
+use aries_vcx_core::wallet::base_wallet::{AsyncFnIteratorCollect, BaseWallet};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::error::{MediatorError, MediatorResult};
+
+const CLIENT_RECORD_TYPE: &str = "mediator_client";
+const RECIPIENT_KEY_RECORD_TYPE: &str = "mediator_recipient_key";
+const QUEUED_MESSAGE_RECORD_TYPE: &str = "mediator_queued_message";
+
+const TAG_CLIENT_VK: &str = "client_vk";
+const TAG_RECIPIENT_KEY: &str = "recipient_key";
+const TAG_RECEIVED_AT: &str = "received_at";
+
+const SEARCH_OPTIONS: &str = r#"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"#;
+
+/// A packed message waiting in the queue of a recipient key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedMessage {
+    pub id: String,
+    pub recipient_key: String,
+    pub received_at: DateTime<Utc>,
+    /// The packed message, as it was received.
+    pub message: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct WalletRecord {
+    id: String,
+    value: Option<String>,
+    #[serde(default)]
+    tags: Option<HashMap<String, String>>,
+}
+
+impl WalletRecord {
+    fn tag(&self, name: &str) -> MediatorResult<&str> {
+        self.tags
+            .as_ref()
+            .and_then(|tags| tags.get(name))
+            .map(String::as_str)
+            .ok_or_else(|| MediatorError::InvalidRecord(format!("Wallet record {} is missing tag {}", self.id, name)))
+    }
+}
+
+/// Mediator state kept in a [`BaseWallet`]: the clients mediation was granted to, the recipient
+/// keys each of them registered and the queue of messages forwarded to every recipient key.
+#[derive(Clone, Debug)]
+pub struct MediatorStore {
+    wallet: Arc<dyn BaseWallet>,
+}
+
+impl MediatorStore {
+    pub fn new(wallet: Arc<dyn BaseWallet>) -> Self {
+        Self { wallet }
+    }
+
+    pub async fn grant_mediation(&self, client_vk: &str) -> MediatorResult<()> {
+        if self.is_mediation_granted(client_vk).await? {
+            return Ok(());
+        }
+        self.wallet
+            .add_wallet_record(CLIENT_RECORD_TYPE, client_vk, client_vk, None)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn is_mediation_granted(&self, client_vk: &str) -> MediatorResult<bool> {
+        match self.wallet.get_wallet_record(CLIENT_RECORD_TYPE, client_vk, "{}").await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub async fn add_recipient_key(&self, client_vk: &str, recipient_key: &str) -> MediatorResult<()> {
+        let tags = json!({ TAG_CLIENT_VK: client_vk }).to_string();
+        self.wallet
+            .add_wallet_record(RECIPIENT_KEY_RECORD_TYPE, recipient_key, client_vk, Some(&tags))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_recipient_key(&self, recipient_key: &str) -> MediatorResult<()> {
+        self.wallet
+            .delete_wallet_record(RECIPIENT_KEY_RECORD_TYPE, recipient_key)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the client which registered `recipient_key`, if any.
+    pub async fn client_for_recipient_key(&self, recipient_key: &str) -> MediatorResult<Option<String>> {
+        let record = match self
+            .wallet
+            .get_wallet_record(RECIPIENT_KEY_RECORD_TYPE, recipient_key, SEARCH_OPTIONS)
+            .await
+        {
+            Ok(record) => record,
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let record: WalletRecord = serde_json::from_str(&record)?;
+        Ok(record.value)
+    }
+
+    /// Returns the recipient keys registered by `client_vk`, sorted.
+    pub async fn recipient_keys(&self, client_vk: &str) -> MediatorResult<Vec<String>> {
+        let query = json!({ TAG_CLIENT_VK: client_vk }).to_string();
+        let mut keys: Vec<String> = self
+            .search(RECIPIENT_KEY_RECORD_TYPE, &query)
+            .await?
+            .into_iter()
+            .map(|record| record.id)
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// Queues a packed message for `recipient_key`, returning the id of the queued message.
+    pub async fn enqueue_message(&self, recipient_key: &str, message: &[u8]) -> MediatorResult<String> {
+        let id = Uuid::new_v4().to_string();
+        let tags = json!({
+            TAG_RECIPIENT_KEY: recipient_key,
+            TAG_RECEIVED_AT: format!("{:020}", Utc::now().timestamp_millis()),
+        })
+        .to_string();
+        self.wallet
+            .add_wallet_record(QUEUED_MESSAGE_RECORD_TYPE, &id, &base64::encode(message), Some(&tags))
+            .await?;
+        Ok(id)
+    }
+
+    /// Returns the messages queued for any of `recipient_keys`, oldest first.
+    pub async fn queued_messages(&self, recipient_keys: &[String]) -> MediatorResult<Vec<QueuedMessage>> {
+        if recipient_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = json!({ TAG_RECIPIENT_KEY: { "$in": recipient_keys } }).to_string();
+
+        let mut messages = self
+            .search(QUEUED_MESSAGE_RECORD_TYPE, &query)
+            .await?
+            .into_iter()
+            .map(|record| {
+                let received_at = record
+                    .tag(TAG_RECEIVED_AT)?
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+                    .ok_or_else(|| {
+                        MediatorError::InvalidRecord(format!("Invalid timestamp of queued message {}", record.id))
+                    })?;
+                let message = base64::decode(record.value.as_deref().unwrap_or_default()).map_err(|err| {
+                    MediatorError::InvalidRecord(format!("Invalid encoding of queued message {}: {}", record.id, err))
+                })?;
+                Ok(QueuedMessage {
+                    recipient_key: record.tag(TAG_RECIPIENT_KEY)?.to_owned(),
+                    received_at,
+                    message,
+                    id: record.id,
+                })
+            })
+            .collect::<MediatorResult<Vec<_>>>()?;
+
+        messages.sort_by(|a, b| a.received_at.cmp(&b.received_at).then_with(|| a.id.cmp(&b.id)));
+        Ok(messages)
+    }
+
+    /// Removes the given queued messages. Ids not belonging to `recipient_keys` are ignored, so
+    /// a client can only remove messages from its own queues.
+    pub async fn remove_messages(&self, recipient_keys: &[String], message_ids: &[String]) -> MediatorResult<()> {
+        for message in self.queued_messages(recipient_keys).await? {
+            if message_ids.contains(&message.id) {
+                self.wallet
+                    .delete_wallet_record(QUEUED_MESSAGE_RECORD_TYPE, &message.id)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn search(&self, xtype: &str, query: &str) -> MediatorResult<Vec<WalletRecord>> {
+        let mut iterator = self.wallet.iterate_wallet_records(xtype, query, SEARCH_OPTIONS).await?;
+        iterator
+            .collect()
+            .await?
+            .iter()
+            .map(|record| serde_json::from_str(record).map_err(From::from))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod unit_tests {
+    use aries_vcx_core::wallet::in_memory_wallet::InMemoryWallet;
+
+    use super::*;
+
+    fn store() -> MediatorStore {
+        MediatorStore::new(Arc::new(InMemoryWallet::new()))
+    }
+
+    #[tokio::test]
+    async fn test_grant_mediation() {
+        let store = store();
+        assert!(!store.is_mediation_granted("client_vk").await.unwrap());
+
+        store.grant_mediation("client_vk").await.unwrap();
+        store.grant_mediation("client_vk").await.unwrap();
+        assert!(store.is_mediation_granted("client_vk").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_recipient_keys() {
+        let store = store();
+        store.add_recipient_key("client_vk", "key_2").await.unwrap();
+        store.add_recipient_key("client_vk", "key_1").await.unwrap();
+        store.add_recipient_key("other_client_vk", "key_3").await.unwrap();
+
+        assert_eq!(vec!["key_1", "key_2"], store.recipient_keys("client_vk").await.unwrap());
+        assert_eq!(
+            Some("other_client_vk".to_owned()),
+            store.client_for_recipient_key("key_3").await.unwrap()
+        );
+
+        store.remove_recipient_key("key_3").await.unwrap();
+        assert_eq!(None, store.client_for_recipient_key("key_3").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_message_queue() {
+        let store = store();
+        let keys = vec!["key_1".to_owned(), "key_2".to_owned()];
+        let id_1 = store.enqueue_message("key_1", b"message_1").await.unwrap();
+        let id_2 = store.enqueue_message("key_2", b"message_2").await.unwrap();
+        store.enqueue_message("key_3", b"message_3").await.unwrap();
+
+        let queued = store.queued_messages(&keys).await.unwrap();
+        let mut ids: Vec<_> = queued.iter().map(|m| m.id.clone()).collect();
+        ids.sort();
+        let mut expected = vec![id_1.clone(), id_2.clone()];
+        expected.sort();
+        assert_eq!(expected, ids);
+
+        let message = queued.iter().find(|m| m.id == id_1).unwrap();
+        assert_eq!("key_1", message.recipient_key);
+        assert_eq!(b"message_1".to_vec(), message.message);
+
+        store.remove_messages(&keys[..1], &[id_1, id_2.clone()]).await.unwrap();
+        let remaining: Vec<_> = store
+            .queued_messages(&keys)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(vec![id_2], remaining);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod attachment;
 pub mod localization;
 pub mod please_ack;
+pub mod service;
 pub mod thread;
 pub mod timing;
+pub mod transport;
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Struct representing the `~service` decorator from its [RFC](<https://github.com/hyperledger/aries-rfcs/blob/main/features/0056-service-decorator/README.md>).
+///
+/// Lets a message be answered without a connection, by telling the recipient where and
+/// to which keys the reply must be sent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub recipient_keys: Vec<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    pub service_endpoint: Url,
+}
+
+impl Service {
+    pub fn new(recipient_keys: Vec<String>, routing_keys: Vec<String>, service_endpoint: Url) -> Self {
+        Self {
+            recipient_keys,
+            routing_keys,
+            service_endpoint,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+pub mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::misc::test_utils;
+
+    pub fn make_minimal_service() -> Service {
+        let recipient_keys = vec!["test_recipient_key".to_owned()];
+        let service_endpoint = "https://dummy.dummy/dummy".parse().unwrap();
+        Service::new(recipient_keys, Vec::new(), service_endpoint)
+    }
+
+    pub fn make_extended_service() -> Service {
+        let mut service = make_minimal_service();
+        service.routing_keys = vec!["test_routing_key".to_owned()];
+        service
+    }
+
+    #[test]
+    fn test_minimal_service() {
+        let service = make_minimal_service();
+        let expected = json!({
+            "recipientKeys": service.recipient_keys,
+            "routingKeys": [],
+            "serviceEndpoint": service.service_endpoint
+        });
+
+        test_utils::test_serde(service, expected);
+    }
+
+    #[test]
+    fn test_extended_service() {
+        let service = make_extended_service();
+        let expected = json!({
+            "recipientKeys": service.recipient_keys,
+            "routingKeys": service.routing_keys,
+            "serviceEndpoint": service.service_endpoint
+        });
+
+        test_utils::test_serde(service, expected);
+    }
+
+    #[test]
+    fn test_service_without_routing_keys() {
+        let json = json!({
+            "recipientKeys": ["test_recipient_key"],
+            "serviceEndpoint": "https://dummy.dummy/dummy"
+        });
+
+        let service: Service = serde_json::from_value(json).unwrap();
+        assert_eq!(make_minimal_service(), service);
+    }
+}
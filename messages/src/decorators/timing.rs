@@ -24,6 +24,19 @@ pub struct Timing {
     pub wait_until_time: Option<DateTime<Utc>>,
 }
 
+impl Timing {
+    /// Returns whether the message carrying this decorator has expired at `time`,
+    /// according to its `expires_time`. Messages without an `expires_time` never expire.
+    pub fn is_expired_at(&self, time: DateTime<Utc>) -> bool {
+        self.expires_time.map_or(false, |expires_time| expires_time <= time)
+    }
+
+    /// Returns whether the message carrying this decorator has already expired.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 #[allow(clippy::field_reassign_with_default)]
@@ -75,4 +88,17 @@ pub mod tests {
 
         test_utils::test_serde(timing, expected);
     }
+
+    #[test]
+    fn test_timing_expiry() {
+        let expires_time = DateTime::default() + chrono::Duration::days(1);
+
+        let mut timing = Timing::default();
+        assert!(!timing.is_expired_at(expires_time));
+
+        timing.expires_time = Some(expires_time);
+        assert!(!timing.is_expired_at(expires_time - chrono::Duration::seconds(1)));
+        assert!(timing.is_expired_at(expires_time));
+        assert!(timing.is_expired());
+    }
 }
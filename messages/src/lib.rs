@@ -23,10 +23,12 @@ use crate::{
     misc::utils::MSG_TYPE,
     msg_fields::{
         protocols::{
-            basic_message::BasicMessage, connection::Connection, cred_issuance::CredentialIssuance,
-            discover_features::DiscoverFeatures, notification::Notification, out_of_band::OutOfBand,
-            present_proof::PresentProof, report_problem::ProblemReport, revocation::Revocation, routing::Forward,
-            trust_ping::TrustPing,
+            action_menu::ActionMenu, basic_message::BasicMessage, connection::Connection,
+            coordinate_mediation::CoordinateMediation, cred_issuance::CredentialIssuance,
+            cred_issuance_v2::CredentialIssuanceV2, did_exchange::DidExchange, discover_features::DiscoverFeatures,
+            notification::Notification, out_of_band::OutOfBand, pickup::Pickup, present_proof::PresentProof,
+            present_proof_v2::PresentProofV2, question_answer::QuestionAnswer, report_problem::ProblemReport,
+            revocation::Revocation, routing::Forward, trust_ping::TrustPing,
         },
         traits::DelayedSerde,
     },
@@ -34,6 +36,8 @@ use crate::{
         basic_message::BasicMessageTypeV1_0,
         protocols::{
             basic_message::{BasicMessageType, BasicMessageTypeV1},
+            cred_issuance::CredentialIssuanceType,
+            present_proof::PresentProofType,
             report_problem::{ReportProblemType, ReportProblemTypeV1},
             routing::{RoutingType, RoutingTypeV1},
         },
@@ -54,13 +58,20 @@ pub enum AriesMessage {
     Connection(Connection),
     Revocation(Revocation),
     CredentialIssuance(CredentialIssuance),
+    CredentialIssuanceV2(CredentialIssuanceV2),
     ReportProblem(ProblemReport),
     PresentProof(PresentProof),
+    PresentProofV2(PresentProofV2),
     TrustPing(TrustPing),
     DiscoverFeatures(DiscoverFeatures),
     BasicMessage(BasicMessage),
     OutOfBand(OutOfBand),
     Notification(Notification),
+    DidExchange(DidExchange),
+    CoordinateMediation(CoordinateMediation),
+    Pickup(Pickup),
+    ActionMenu(ActionMenu),
+    QuestionAnswer(QuestionAnswer),
 }
 
 impl DelayedSerde for AriesMessage {
@@ -99,9 +110,12 @@ impl DelayedSerde for AriesMessage {
             Protocol::RevocationType(msg_type) => {
                 Revocation::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
             }
-            Protocol::CredentialIssuanceType(msg_type) => {
+            Protocol::CredentialIssuanceType(CredentialIssuanceType::V1(msg_type)) => {
                 CredentialIssuance::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
             }
+            Protocol::CredentialIssuanceType(CredentialIssuanceType::V2(msg_type)) => {
+                CredentialIssuanceV2::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
             Protocol::ReportProblemType(msg_type) => {
                 let kind = match msg_type {
                     ReportProblemType::V1(ReportProblemTypeV1::V1_0(kind)) => kind.kind_from_str(kind_str),
@@ -111,9 +125,12 @@ impl DelayedSerde for AriesMessage {
                     ReportProblemTypeV1_0::ProblemReport => ProblemReport::deserialize(deserializer).map(From::from),
                 }
             }
-            Protocol::PresentProofType(msg_type) => {
+            Protocol::PresentProofType(PresentProofType::V1(msg_type)) => {
                 PresentProof::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
             }
+            Protocol::PresentProofType(PresentProofType::V2(msg_type)) => {
+                PresentProofV2::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
             Protocol::TrustPingType(msg_type) => {
                 TrustPing::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
             }
@@ -135,6 +152,21 @@ impl DelayedSerde for AriesMessage {
             Protocol::NotificationType(msg_type) => {
                 Notification::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
             }
+            Protocol::DidExchangeType(msg_type) => {
+                DidExchange::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
+            Protocol::CoordinateMediationType(msg_type) => {
+                CoordinateMediation::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
+            Protocol::PickupType(msg_type) => {
+                Pickup::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
+            Protocol::ActionMenuType(msg_type) => {
+                ActionMenu::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
+            Protocol::QuestionAnswerType(msg_type) => {
+                QuestionAnswer::delayed_deserialize((msg_type, kind_str), deserializer).map(From::from)
+            }
         }
     }
 
@@ -147,13 +179,20 @@ impl DelayedSerde for AriesMessage {
             Self::Connection(v) => v.delayed_serialize(serializer),
             Self::Revocation(v) => v.delayed_serialize(serializer),
             Self::CredentialIssuance(v) => v.delayed_serialize(serializer),
+            Self::CredentialIssuanceV2(v) => v.delayed_serialize(serializer),
             Self::ReportProblem(v) => MsgWithType::from(v).serialize(serializer),
             Self::PresentProof(v) => v.delayed_serialize(serializer),
+            Self::PresentProofV2(v) => v.delayed_serialize(serializer),
             Self::TrustPing(v) => v.delayed_serialize(serializer),
             Self::DiscoverFeatures(v) => v.delayed_serialize(serializer),
             Self::BasicMessage(v) => MsgWithType::from(v).serialize(serializer),
             Self::OutOfBand(v) => v.delayed_serialize(serializer),
             Self::Notification(v) => v.delayed_serialize(serializer),
+            Self::DidExchange(v) => v.delayed_serialize(serializer),
+            Self::CoordinateMediation(v) => v.delayed_serialize(serializer),
+            Self::Pickup(v) => v.delayed_serialize(serializer),
+            Self::ActionMenu(v) => v.delayed_serialize(serializer),
+            Self::QuestionAnswer(v) => v.delayed_serialize(serializer),
         }
     }
 }
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type Menu = MsgParts<MenuContent, MenuDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MenuContent {
+    pub title: String,
+    pub description: String,
+    /// Error to display to the user, usually after a failed `perform`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errormsg: Option<String>,
+    pub options: Vec<MenuOption>,
+}
+
+impl MenuContent {
+    pub fn new(title: String, description: String, options: Vec<MenuOption>) -> Self {
+        Self {
+            title,
+            description,
+            errormsg: None,
+            options,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MenuOption {
+    /// Identifier of the option, sent back in the `perform` message.
+    pub name: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form: Option<MenuForm>,
+}
+
+impl MenuOption {
+    pub fn new(name: String, title: String, description: String) -> Self {
+        Self {
+            name,
+            title,
+            description,
+            disabled: false,
+            form: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MenuForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub params: Vec<MenuFormParam>,
+    #[serde(rename = "submit-label")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_label: Option<String>,
+}
+
+impl MenuForm {
+    pub fn new(params: Vec<MenuFormParam>) -> Self {
+        Self {
+            description: None,
+            params,
+            submit_label: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MenuFormParam {
+    pub name: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub param_type: MenuFormParamType,
+}
+
+impl MenuFormParam {
+    pub fn new(name: String, title: String) -> Self {
+        Self {
+            name,
+            title,
+            default: None,
+            description: None,
+            required: false,
+            param_type: MenuFormParamType::default(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MenuFormParamType {
+    #[default]
+    Text,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct MenuDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::action_menu::ActionMenuTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_menu() {
+        let option = MenuOption::new(
+            "obtain-email-cred".to_owned(),
+            "Obtain a verified email credential".to_owned(),
+            "Connect with the BC email verification service".to_owned(),
+        );
+        let content = MenuContent::new(
+            "Welcome to IIWBook".to_owned(),
+            "IIWBook facilitates connections between attendees".to_owned(),
+            vec![option],
+        );
+
+        let decorators = MenuDecorators::default();
+
+        let expected = json!({
+            "title": content.title,
+            "description": content.description,
+            "options": [{
+                "name": "obtain-email-cred",
+                "title": "Obtain a verified email credential",
+                "description": "Connect with the BC email verification service",
+                "disabled": false
+            }]
+        });
+
+        test_utils::test_msg(content, decorators, ActionMenuTypeV1_0::Menu, expected);
+    }
+
+    #[test]
+    fn test_extended_menu() {
+        let mut param = MenuFormParam::new("query".to_owned(), "Attendee name".to_owned());
+        param.default = Some("Alice".to_owned());
+        param.description = Some("Name of the attendee to search for".to_owned());
+        param.required = true;
+
+        let mut form = MenuForm::new(vec![param]);
+        form.description = Some("Enter a name to find other attendees".to_owned());
+        form.submit_label = Some("Search".to_owned());
+
+        let mut option = MenuOption::new(
+            "search-connections".to_owned(),
+            "Search attendees".to_owned(),
+            "Find other attendees".to_owned(),
+        );
+        option.disabled = true;
+        option.form = Some(form);
+
+        let mut content = MenuContent::new(
+            "Welcome to IIWBook".to_owned(),
+            "IIWBook facilitates connections between attendees".to_owned(),
+            vec![option],
+        );
+        content.errormsg = Some("No IIWBook names were found".to_owned());
+
+        let mut decorators = MenuDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "title": content.title,
+            "description": content.description,
+            "errormsg": content.errormsg,
+            "options": [{
+                "name": "search-connections",
+                "title": "Search attendees",
+                "description": "Find other attendees",
+                "disabled": true,
+                "form": {
+                    "description": "Enter a name to find other attendees",
+                    "params": [{
+                        "name": "query",
+                        "title": "Attendee name",
+                        "default": "Alice",
+                        "description": "Name of the attendee to search for",
+                        "required": true,
+                        "type": "text"
+                    }],
+                    "submit-label": "Search"
+                }
+            }],
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(content, decorators, ActionMenuTypeV1_0::Menu, expected);
+    }
+}
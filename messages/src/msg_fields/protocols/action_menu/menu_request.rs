@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+// Bind `shared_vcx::misc::serde_ignored::SerdeIgnored` type as `NoContent`.
+use shared_vcx::misc::serde_ignored::SerdeIgnored as NoContent;
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type MenuRequest = MsgParts<MenuRequestContent, MenuRequestDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+#[serde(transparent)]
+pub struct MenuRequestContent(NoContent);
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct MenuRequestDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::action_menu::ActionMenuTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_menu_request() {
+        let content = MenuRequestContent::default();
+
+        let decorators = MenuRequestDecorators::default();
+
+        let expected = json!({});
+
+        test_utils::test_msg(content, decorators, ActionMenuTypeV1_0::MenuRequest, expected);
+    }
+
+    #[test]
+    fn test_extended_menu_request() {
+        let content = MenuRequestContent::default();
+
+        let mut decorators = MenuRequestDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(content, decorators, ActionMenuTypeV1_0::MenuRequest, expected);
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use shared_vcx::maybe_known::MaybeKnown;
+
+/// Entry of the `formats` field of the messages of protocols with format-tagged attachments,
+/// such as `issue-credential/2.0` and `present-proof/2.0`.
+///
+/// Binds the attachment with the id `attach_id` to its `format`, which is one of the
+/// attachment formats registered for the message, represented by `F`.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct AttachmentFormatSpecifier<F> {
+    pub attach_id: String,
+    pub format: MaybeKnown<F>,
+}
+
+impl<F> AttachmentFormatSpecifier<F> {
+    pub fn new(attach_id: String, format: MaybeKnown<F>) -> Self {
+        Self { attach_id, format }
+    }
+}
@@ -72,7 +72,7 @@ impl ResponseDecorators {
 /// It is not a message on it's own.
 #[derive(Copy, Clone, Debug, Deserialize, Default, PartialEq)]
 #[serde(try_from = "CowStr")]
-struct SigEd25519Sha512Single;
+pub(crate) struct SigEd25519Sha512Single;
 
 impl<'a> From<&'a SigEd25519Sha512Single> for SignatureTypeV1_0 {
     fn from(_value: &'a SigEd25519Sha512Single) -> Self {
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type KeylistQuery = MsgParts<KeylistQueryContent, KeylistQueryDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct KeylistQueryContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<HashMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paginate: Option<KeylistQueryPaginateParams>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct KeylistQueryPaginateParams {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl KeylistQueryPaginateParams {
+    pub fn new(limit: u32, offset: u32) -> Self {
+        Self { limit, offset }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct KeylistQueryDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::coordinate_mediation::CoordinateMediationTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_keylist_query() {
+        let content = KeylistQueryContent::default();
+
+        let decorators = KeylistQueryDecorators::default();
+
+        let expected = json!({});
+
+        test_utils::test_msg(content, decorators, CoordinateMediationTypeV1_0::KeylistQuery, expected);
+    }
+
+    #[test]
+    fn test_extended_keylist_query() {
+        let mut content = KeylistQueryContent::default();
+        content.filter = Some(HashMap::from([(
+            "routing_key".to_owned(),
+            vec!["did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_owned()],
+        )]));
+        content.paginate = Some(KeylistQueryPaginateParams::new(30, 0));
+
+        let mut decorators = KeylistQueryDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "filter": {
+                "routing_key": ["did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH"]
+            },
+            "paginate": {
+                "limit": 30,
+                "offset": 0
+            },
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(content, decorators, CoordinateMediationTypeV1_0::KeylistQuery, expected);
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type KeylistUpdate = MsgParts<KeylistUpdateContent, KeylistUpdateDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct KeylistUpdateContent {
+    pub updates: Vec<KeylistUpdateItem>,
+}
+
+impl KeylistUpdateContent {
+    pub fn new(updates: Vec<KeylistUpdateItem>) -> Self {
+        Self { updates }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct KeylistUpdateItem {
+    pub recipient_key: String,
+    pub action: KeylistUpdateItemAction,
+}
+
+impl KeylistUpdateItem {
+    pub fn new(recipient_key: String, action: KeylistUpdateItemAction) -> Self {
+        Self { recipient_key, action }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeylistUpdateItemAction {
+    Add,
+    Remove,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct KeylistUpdateDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::coordinate_mediation::CoordinateMediationTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_keylist_update() {
+        let content = KeylistUpdateContent::new(vec![KeylistUpdateItem::new(
+            "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_owned(),
+            KeylistUpdateItemAction::Add,
+        )]);
+
+        let decorators = KeylistUpdateDecorators::default();
+
+        let expected = json!({
+            "updates": [
+                {
+                    "recipient_key": "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH",
+                    "action": "add"
+                }
+            ]
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CoordinateMediationTypeV1_0::KeylistUpdate,
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_extended_keylist_update() {
+        let content = KeylistUpdateContent::new(vec![KeylistUpdateItem::new(
+            "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH".to_owned(),
+            KeylistUpdateItemAction::Remove,
+        )]);
+
+        let mut decorators = KeylistUpdateDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "updates": [
+                {
+                    "recipient_key": "did:key:z6MkpTHR8VNsBxYAAWHut2Geadd9jSwuBV8xRoAnwWsdvktH",
+                    "action": "remove"
+                }
+            ],
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CoordinateMediationTypeV1_0::KeylistUpdate,
+            expected,
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+// Bind `shared_vcx::misc::serde_ignored::SerdeIgnored` type as `NoContent`.
+use shared_vcx::misc::serde_ignored::SerdeIgnored as NoContent;
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type MediateRequest = MsgParts<MediateRequestContent, MediateRequestDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+#[serde(transparent)]
+pub struct MediateRequestContent(NoContent);
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct MediateRequestDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::coordinate_mediation::CoordinateMediationTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_mediate_request() {
+        let content = MediateRequestContent::default();
+
+        let decorators = MediateRequestDecorators::default();
+
+        let expected = json!({});
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CoordinateMediationTypeV1_0::MediateRequest,
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_extended_mediate_request() {
+        let content = MediateRequestContent::default();
+
+        let mut decorators = MediateRequestDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CoordinateMediationTypeV1_0::MediateRequest,
+            expected,
+        );
+    }
+}
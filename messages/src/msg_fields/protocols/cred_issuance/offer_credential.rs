@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::CredentialPreview;
 use crate::{
-    decorators::{attachment::Attachment, thread::Thread, timing::Timing},
+    decorators::{attachment::Attachment, service::Service, thread::Thread, timing::Timing},
     msg_parts::MsgParts,
 };
 
@@ -35,6 +35,9 @@ pub struct OfferCredentialDecorators {
     #[serde(rename = "~timing")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<Timing>,
+    #[serde(rename = "~service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
 }
 
 #[cfg(test)]
@@ -46,8 +49,8 @@ mod tests {
     use super::*;
     use crate::{
         decorators::{
-            attachment::tests::make_extended_attachment, thread::tests::make_extended_thread,
-            timing::tests::make_extended_timing,
+            attachment::tests::make_extended_attachment, service::tests::make_extended_service,
+            thread::tests::make_extended_thread, timing::tests::make_extended_timing,
         },
         misc::test_utils,
         msg_fields::protocols::cred_issuance::CredentialAttr,
@@ -85,13 +88,15 @@ mod tests {
         let mut decorators = OfferCredentialDecorators::default();
         decorators.thread = Some(make_extended_thread());
         decorators.timing = Some(make_extended_timing());
+        decorators.service = Some(make_extended_service());
 
         let expected = json!({
             "offers~attach": content.offers_attach,
             "credential_preview": content.credential_preview,
             "comment": content.comment,
             "~thread": decorators.thread,
-            "~timing": decorators.timing
+            "~timing": decorators.timing,
+            "~service": decorators.service
         });
 
         test_utils::test_msg(
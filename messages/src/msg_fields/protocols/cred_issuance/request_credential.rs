@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    decorators::{attachment::Attachment, thread::Thread, timing::Timing},
+    decorators::{attachment::Attachment, service::Service, thread::Thread, timing::Timing},
     msg_parts::MsgParts,
 };
 
@@ -32,6 +32,9 @@ pub struct RequestCredentialDecorators {
     #[serde(rename = "~timing")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<Timing>,
+    #[serde(rename = "~service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
 }
 
 #[cfg(test)]
@@ -42,7 +45,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        decorators::{attachment::tests::make_extended_attachment, thread::tests::make_extended_thread},
+        decorators::{
+            attachment::tests::make_extended_attachment, service::tests::make_extended_service,
+            thread::tests::make_extended_thread,
+        },
         misc::test_utils,
         msg_types::cred_issuance::CredentialIssuanceTypeV1_0,
     };
@@ -72,11 +78,13 @@ mod tests {
 
         let mut decorators = RequestCredentialDecorators::default();
         decorators.thread = Some(make_extended_thread());
+        decorators.service = Some(make_extended_service());
 
         let expected = json!({
             "requests~attach": content.requests_attach,
             "comment": content.comment,
-            "~thread": decorators.thread
+            "~thread": decorators.thread,
+            "~service": decorators.service
         });
 
         test_utils::test_msg(
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{attachment::Attachment, please_ack::PleaseAck, thread::Thread, timing::Timing},
+    msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier,
+    msg_parts::MsgParts,
+};
+
+pub type IssueCredentialV2 = MsgParts<IssueCredentialV2Content, IssueCredentialV2Decorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct IssueCredentialV2Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormatSpecifier<IssueCredentialAttachmentFormatType>>,
+    #[serde(rename = "credentials~attach")]
+    pub credentials_attach: Vec<Attachment>,
+}
+
+impl IssueCredentialV2Content {
+    pub fn new(
+        formats: Vec<AttachmentFormatSpecifier<IssueCredentialAttachmentFormatType>>,
+        credentials_attach: Vec<Attachment>,
+    ) -> Self {
+        Self {
+            goal_code: None,
+            replacement_id: None,
+            comment: None,
+            formats,
+            credentials_attach,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct IssueCredentialV2Decorators {
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    #[serde(rename = "~please_ack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub please_ack: Option<PleaseAck>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+impl IssueCredentialV2Decorators {
+    pub fn new(thread: Thread) -> Self {
+        Self {
+            thread,
+            please_ack: None,
+            timing: None,
+        }
+    }
+}
+
+/// Attachment formats of the `credentials~attach` of an issued credential.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum IssueCredentialAttachmentFormatType {
+    #[serde(rename = "hlindy/cred@v2.0")]
+    HyperledgerIndyCredential2_0,
+    #[serde(rename = "aries/ld-proof-vc@v1.0")]
+    AriesLdProofVc1_0,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+    use shared_vcx::maybe_known::MaybeKnown;
+
+    use super::*;
+    use crate::{
+        decorators::{
+            attachment::tests::make_extended_attachment, please_ack::tests::make_minimal_please_ack,
+            thread::tests::make_extended_thread, timing::tests::make_extended_timing,
+        },
+        misc::test_utils,
+        msg_types::cred_issuance::CredentialIssuanceTypeV2_0,
+    };
+
+    fn make_formats() -> Vec<AttachmentFormatSpecifier<IssueCredentialAttachmentFormatType>> {
+        vec![AttachmentFormatSpecifier::new(
+            "test_id".to_owned(),
+            MaybeKnown::Known(IssueCredentialAttachmentFormatType::AriesLdProofVc1_0),
+        )]
+    }
+
+    #[test]
+    fn test_minimal_issue_cred_v2() {
+        let content = IssueCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+
+        let decorators = IssueCredentialV2Decorators::new(make_extended_thread());
+
+        let expected = json!({
+            "formats": [{"attach_id": "test_id", "format": "aries/ld-proof-vc@v1.0"}],
+            "credentials~attach": content.credentials_attach,
+            "~thread": decorators.thread
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::IssueCredential,
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_extended_issue_cred_v2() {
+        let mut content = IssueCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+        content.goal_code = Some("test_goal_code".to_owned());
+        content.replacement_id = Some("test_replacement_id".to_owned());
+        content.comment = Some("test_comment".to_owned());
+
+        let mut decorators = IssueCredentialV2Decorators::new(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+        decorators.please_ack = Some(make_minimal_please_ack());
+
+        let expected = json!({
+            "formats": content.formats,
+            "credentials~attach": content.credentials_attach,
+            "goal_code": content.goal_code,
+            "replacement_id": content.replacement_id,
+            "comment": content.comment,
+            "~thread": decorators.thread,
+            "~timing": decorators.timing,
+            "~please_ack": decorators.please_ack
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::IssueCredential,
+            expected,
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use super::CredentialPreviewV2;
+use crate::{
+    decorators::{attachment::Attachment, thread::Thread, timing::Timing},
+    msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier,
+    msg_parts::MsgParts,
+};
+
+pub type ProposeCredentialV2 = MsgParts<ProposeCredentialV2Content, ProposeCredentialV2Decorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ProposeCredentialV2Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_preview: Option<CredentialPreviewV2>,
+    pub formats: Vec<AttachmentFormatSpecifier<ProposeCredentialAttachmentFormatType>>,
+    #[serde(rename = "filters~attach")]
+    pub filters_attach: Vec<Attachment>,
+}
+
+impl ProposeCredentialV2Content {
+    pub fn new(
+        formats: Vec<AttachmentFormatSpecifier<ProposeCredentialAttachmentFormatType>>,
+        filters_attach: Vec<Attachment>,
+    ) -> Self {
+        Self {
+            goal_code: None,
+            comment: None,
+            credential_preview: None,
+            formats,
+            filters_attach,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct ProposeCredentialV2Decorators {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "~thread")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+/// Attachment formats of the `filters~attach` of a credential proposal.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum ProposeCredentialAttachmentFormatType {
+    #[serde(rename = "hlindy/cred-filter@v2.0")]
+    HyperledgerIndyCredentialFilter2_0,
+    #[serde(rename = "aries/ld-proof-vc-detail@v1.0")]
+    AriesLdProofVcDetail1_0,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+    use shared_vcx::maybe_known::MaybeKnown;
+
+    use super::*;
+    use crate::{
+        decorators::{
+            attachment::tests::make_extended_attachment, thread::tests::make_extended_thread,
+            timing::tests::make_extended_timing,
+        },
+        misc::test_utils,
+        msg_fields::protocols::cred_issuance::CredentialAttr,
+        msg_types::cred_issuance::CredentialIssuanceTypeV2_0,
+    };
+
+    fn make_formats() -> Vec<AttachmentFormatSpecifier<ProposeCredentialAttachmentFormatType>> {
+        vec![AttachmentFormatSpecifier::new(
+            "test_id".to_owned(),
+            MaybeKnown::Known(ProposeCredentialAttachmentFormatType::HyperledgerIndyCredentialFilter2_0),
+        )]
+    }
+
+    #[test]
+    fn test_minimal_propose_cred_v2() {
+        let content = ProposeCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+
+        let decorators = ProposeCredentialV2Decorators::default();
+
+        let expected = json!({
+            "formats": [{"attach_id": "test_id", "format": "hlindy/cred-filter@v2.0"}],
+            "filters~attach": content.filters_attach,
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::ProposeCredential,
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_extended_propose_cred_v2() {
+        let attribute = CredentialAttr::new("test_attribute_name".to_owned(), "test_attribute_value".to_owned());
+        let mut content = ProposeCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+        content.goal_code = Some("test_goal_code".to_owned());
+        content.comment = Some("test_comment".to_owned());
+        content.credential_preview = Some(CredentialPreviewV2::new(vec![attribute]));
+
+        let mut decorators = ProposeCredentialV2Decorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "formats": content.formats,
+            "filters~attach": content.filters_attach,
+            "goal_code": content.goal_code,
+            "comment": content.comment,
+            "credential_preview": content.credential_preview,
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::ProposeCredential,
+            expected,
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{attachment::Attachment, thread::Thread, timing::Timing},
+    msg_fields::protocols::common::attachment_format_id::AttachmentFormatSpecifier,
+    msg_parts::MsgParts,
+};
+
+pub type RequestCredentialV2 = MsgParts<RequestCredentialV2Content, RequestCredentialV2Decorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RequestCredentialV2Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormatSpecifier<RequestCredentialAttachmentFormatType>>,
+    #[serde(rename = "requests~attach")]
+    pub requests_attach: Vec<Attachment>,
+}
+
+impl RequestCredentialV2Content {
+    pub fn new(
+        formats: Vec<AttachmentFormatSpecifier<RequestCredentialAttachmentFormatType>>,
+        requests_attach: Vec<Attachment>,
+    ) -> Self {
+        Self {
+            goal_code: None,
+            comment: None,
+            formats,
+            requests_attach,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct RequestCredentialV2Decorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+/// Attachment formats of the `requests~attach` of a credential request.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum RequestCredentialAttachmentFormatType {
+    #[serde(rename = "hlindy/cred-req@v2.0")]
+    HyperledgerIndyCredentialRequest2_0,
+    #[serde(rename = "aries/ld-proof-vc-detail@v1.0")]
+    AriesLdProofVcDetail1_0,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+    use shared_vcx::maybe_known::MaybeKnown;
+
+    use super::*;
+    use crate::{
+        decorators::{attachment::tests::make_extended_attachment, thread::tests::make_extended_thread},
+        misc::test_utils,
+        msg_types::cred_issuance::CredentialIssuanceTypeV2_0,
+    };
+
+    fn make_formats() -> Vec<AttachmentFormatSpecifier<RequestCredentialAttachmentFormatType>> {
+        vec![AttachmentFormatSpecifier::new(
+            "test_id".to_owned(),
+            MaybeKnown::Known(RequestCredentialAttachmentFormatType::HyperledgerIndyCredentialRequest2_0),
+        )]
+    }
+
+    #[test]
+    fn test_minimal_request_cred_v2() {
+        let content = RequestCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+
+        let decorators = RequestCredentialV2Decorators::default();
+
+        let expected = json!({
+            "formats": [{"attach_id": "test_id", "format": "hlindy/cred-req@v2.0"}],
+            "requests~attach": content.requests_attach,
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::RequestCredential,
+            expected,
+        );
+    }
+
+    #[test]
+    fn test_extended_request_cred_v2() {
+        let mut content = RequestCredentialV2Content::new(make_formats(), vec![make_extended_attachment()]);
+        content.goal_code = Some("test_goal_code".to_owned());
+        content.comment = Some("test_comment".to_owned());
+
+        let mut decorators = RequestCredentialV2Decorators::default();
+        decorators.thread = Some(make_extended_thread());
+
+        let expected = json!({
+            "formats": content.formats,
+            "requests~attach": content.requests_attach,
+            "goal_code": content.goal_code,
+            "comment": content.comment,
+            "~thread": decorators.thread
+        });
+
+        test_utils::test_msg(
+            content,
+            decorators,
+            CredentialIssuanceTypeV2_0::RequestCredential,
+            expected,
+        );
+    }
+}
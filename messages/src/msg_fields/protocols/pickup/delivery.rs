@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use super::PickupDecorators;
+use crate::{decorators::attachment::Attachment, msg_parts::MsgParts};
+
+pub type Delivery = MsgParts<DeliveryContent, PickupDecorators>;
+
+/// Batch of queued messages. Each attachment holds one packed message,
+/// with the attachment id being the message id used for acknowledging it.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct DeliveryContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_key: Option<String>,
+    #[serde(rename = "~attach")]
+    pub attach: Vec<Attachment>,
+}
+
+impl DeliveryContent {
+    pub fn new(attach: Vec<Attachment>) -> Self {
+        Self {
+            recipient_key: None,
+            attach,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{attachment::tests::make_extended_attachment, thread::tests::make_extended_thread},
+        misc::test_utils,
+        msg_types::pickup::PickupTypeV2_0,
+    };
+
+    #[test]
+    fn test_minimal_delivery() {
+        let content = DeliveryContent::new(vec![make_extended_attachment()]);
+
+        let decorators = PickupDecorators::default();
+
+        let expected = json!({
+            "~attach": content.attach
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::Delivery, expected);
+    }
+
+    #[test]
+    fn test_extended_delivery() {
+        let mut content = DeliveryContent::new(vec![make_extended_attachment()]);
+        content.recipient_key = Some("test_recipient_key".to_owned());
+
+        let mut decorators = PickupDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+
+        let expected = json!({
+            "recipient_key": content.recipient_key,
+            "~attach": content.attach,
+            "~thread": decorators.thread
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::Delivery, expected);
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use super::PickupDecorators;
+use crate::msg_parts::MsgParts;
+
+pub type MessagesReceived = MsgParts<MessagesReceivedContent, PickupDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct MessagesReceivedContent {
+    pub message_id_list: Vec<String>,
+}
+
+impl MessagesReceivedContent {
+    pub fn new(message_id_list: Vec<String>) -> Self {
+        Self { message_id_list }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, transport::tests::make_minimal_transport},
+        misc::test_utils,
+        msg_types::pickup::PickupTypeV2_0,
+    };
+
+    #[test]
+    fn test_minimal_messages_received() {
+        let content = MessagesReceivedContent::new(vec!["123".to_owned(), "456".to_owned()]);
+
+        let decorators = PickupDecorators::default();
+
+        let expected = json!({
+            "message_id_list": content.message_id_list
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::MessagesReceived, expected);
+    }
+
+    #[test]
+    fn test_extended_messages_received() {
+        let content = MessagesReceivedContent::new(vec!["123".to_owned(), "456".to_owned()]);
+
+        let mut decorators = PickupDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.transport = Some(make_minimal_transport());
+
+        let expected = json!({
+            "message_id_list": content.message_id_list,
+            "~thread": decorators.thread,
+            "~transport": decorators.transport
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::MessagesReceived, expected);
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::PickupDecorators;
+use crate::{misc::utils, msg_parts::MsgParts};
+
+pub type Status = MsgParts<StatusContent, PickupDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct StatusContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_key: Option<String>,
+    pub message_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_waited_seconds: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "utils::serialize_opt_datetime")]
+    pub newest_received_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "utils::serialize_opt_datetime")]
+    pub oldest_received_time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_delivery: Option<bool>,
+}
+
+impl StatusContent {
+    pub fn new(message_count: u32) -> Self {
+        Self {
+            recipient_key: None,
+            message_count,
+            longest_waited_seconds: None,
+            newest_received_time: None,
+            oldest_received_time: None,
+            total_bytes: None,
+            live_delivery: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::thread::tests::make_extended_thread,
+        misc::test_utils::{self, OptDateTimeRfc3339},
+        msg_types::pickup::PickupTypeV2_0,
+    };
+
+    #[test]
+    fn test_minimal_status() {
+        let content = StatusContent::new(10);
+
+        let decorators = PickupDecorators::default();
+
+        let expected = json!({
+            "message_count": 10
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::Status, expected);
+    }
+
+    #[test]
+    fn test_extended_status() {
+        let mut content = StatusContent::new(10);
+        content.recipient_key = Some("test_recipient_key".to_owned());
+        content.longest_waited_seconds = Some(3600);
+        content.newest_received_time = Some(DateTime::default());
+        content.oldest_received_time = Some(DateTime::default());
+        content.total_bytes = Some(8096);
+        content.live_delivery = Some(false);
+
+        let mut decorators = PickupDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+
+        let expected = json!({
+            "recipient_key": content.recipient_key,
+            "message_count": 10,
+            "longest_waited_seconds": 3600,
+            "newest_received_time": OptDateTimeRfc3339(&content.newest_received_time),
+            "oldest_received_time": OptDateTimeRfc3339(&content.oldest_received_time),
+            "total_bytes": 8096,
+            "live_delivery": false,
+            "~thread": decorators.thread
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::Status, expected);
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::PickupDecorators;
+use crate::msg_parts::MsgParts;
+
+pub type StatusRequest = MsgParts<StatusRequestContent, PickupDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct StatusRequestContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_key: Option<String>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, transport::tests::make_minimal_transport},
+        misc::test_utils,
+        msg_types::pickup::PickupTypeV2_0,
+    };
+
+    #[test]
+    fn test_minimal_status_request() {
+        let content = StatusRequestContent::default();
+
+        let decorators = PickupDecorators::default();
+
+        let expected = json!({});
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::StatusRequest, expected);
+    }
+
+    #[test]
+    fn test_extended_status_request() {
+        let mut content = StatusRequestContent::default();
+        content.recipient_key = Some("test_recipient_key".to_owned());
+
+        let mut decorators = PickupDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.transport = Some(make_minimal_transport());
+
+        let expected = json!({
+            "recipient_key": content.recipient_key,
+            "~thread": decorators.thread,
+            "~transport": decorators.transport
+        });
+
+        test_utils::test_msg(content, decorators, PickupTypeV2_0::StatusRequest, expected);
+    }
+}
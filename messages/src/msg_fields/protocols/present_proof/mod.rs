@@ -21,7 +21,7 @@ use crate::{
     misc::utils::{self, into_msg_with_type, transit_to_aries_msg},
     msg_fields::traits::DelayedSerde,
     msg_types::{
-        protocols::present_proof::{PresentProofType, PresentProofTypeV1, PresentProofTypeV1_0},
+        protocols::present_proof::{PresentProofTypeV1, PresentProofTypeV1_0},
         MsgWithType,
     },
 };
@@ -36,7 +36,7 @@ pub enum PresentProof {
 }
 
 impl DelayedSerde for PresentProof {
-    type MsgType<'a> = (PresentProofType, &'a str);
+    type MsgType<'a> = (PresentProofTypeV1, &'a str);
 
     fn delayed_deserialize<'de, D>(msg_type: Self::MsgType<'de>, deserializer: D) -> Result<Self, D::Error>
     where
@@ -45,7 +45,7 @@ impl DelayedSerde for PresentProof {
         let (protocol, kind_str) = msg_type;
 
         let kind = match protocol {
-            PresentProofType::V1(PresentProofTypeV1::V1_0(kind)) => kind.kind_from_str(kind_str),
+            PresentProofTypeV1::V1_0(kind) => kind.kind_from_str(kind_str),
         };
 
         match kind.map_err(D::Error::custom)? {
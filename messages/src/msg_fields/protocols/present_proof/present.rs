@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    decorators::{attachment::Attachment, please_ack::PleaseAck, thread::Thread, timing::Timing},
+    decorators::{attachment::Attachment, please_ack::PleaseAck, service::Service, thread::Thread, timing::Timing},
     msg_parts::MsgParts,
 };
 
@@ -34,6 +34,9 @@ pub struct PresentationDecorators {
     #[serde(rename = "~timing")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<Timing>,
+    #[serde(rename = "~service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
 }
 
 impl PresentationDecorators {
@@ -42,6 +45,7 @@ impl PresentationDecorators {
             thread,
             please_ack: None,
             timing: None,
+            service: None,
         }
     }
 }
@@ -56,7 +60,8 @@ mod tests {
     use crate::{
         decorators::{
             attachment::tests::make_extended_attachment, please_ack::tests::make_minimal_please_ack,
-            thread::tests::make_extended_thread, timing::tests::make_extended_timing,
+            service::tests::make_extended_service, thread::tests::make_extended_thread,
+            timing::tests::make_extended_timing,
         },
         misc::test_utils,
         msg_types::present_proof::PresentProofTypeV1_0,
@@ -84,13 +89,15 @@ mod tests {
         let mut decorators = PresentationDecorators::new(make_extended_thread());
         decorators.timing = Some(make_extended_timing());
         decorators.please_ack = Some(make_minimal_please_ack());
+        decorators.service = Some(make_extended_service());
 
         let expected = json!({
             "comment": content.comment,
             "presentations~attach": content.presentations_attach,
             "~thread": decorators.thread,
             "~timing": decorators.timing,
-            "~please_ack": decorators.please_ack
+            "~please_ack": decorators.please_ack,
+            "~service": decorators.service
         });
 
         test_utils::test_msg(content, decorators, PresentProofTypeV1_0::Presentation, expected);
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    decorators::{attachment::Attachment, thread::Thread, timing::Timing},
+    decorators::{attachment::Attachment, service::Service, thread::Thread, timing::Timing},
     msg_parts::MsgParts,
 };
 
@@ -32,6 +32,9 @@ pub struct RequestPresentationDecorators {
     #[serde(rename = "~timing")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<Timing>,
+    #[serde(rename = "~service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
 }
 
 #[cfg(test)]
@@ -43,8 +46,8 @@ mod tests {
     use super::*;
     use crate::{
         decorators::{
-            attachment::tests::make_extended_attachment, thread::tests::make_extended_thread,
-            timing::tests::make_extended_timing,
+            attachment::tests::make_extended_attachment, service::tests::make_extended_service,
+            thread::tests::make_extended_thread, timing::tests::make_extended_timing,
         },
         misc::test_utils,
         msg_types::present_proof::PresentProofTypeV1_0,
@@ -71,12 +74,14 @@ mod tests {
         let mut decorators = RequestPresentationDecorators::default();
         decorators.thread = Some(make_extended_thread());
         decorators.timing = Some(make_extended_timing());
+        decorators.service = Some(make_extended_service());
 
         let expected = json!({
             "request_presentations~attach": content.request_presentations_attach,
             "comment": content.comment,
             "~thread": decorators.thread,
-            "~timing": decorators.timing
+            "~timing": decorators.timing,
+            "~service": decorators.service
         });
 
         test_utils::test_msg(content, decorators, PresentProofTypeV1_0::RequestPresentation, expected);
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_fields::protocols::connection::response::SigEd25519Sha512Single,
+    msg_parts::MsgParts,
+};
+
+pub type Answer = MsgParts<AnswerContent, AnswerDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct AnswerContent {
+    /// The text of the selected valid response of the question.
+    pub response: String,
+    #[serde(rename = "response~sig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_sig: Option<ResponseSignature>,
+}
+
+impl AnswerContent {
+    pub fn new(response: String) -> Self {
+        Self {
+            response,
+            response_sig: None,
+        }
+    }
+}
+
+/// Signature over the question text, the selected response and the nonce of the question.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ResponseSignature {
+    #[serde(rename = "@type")]
+    msg_type: SigEd25519Sha512Single,
+    pub signature: String,
+    pub sig_data: String,
+    pub signer: String,
+}
+
+impl ResponseSignature {
+    pub fn new(signature: String, sig_data: String, signer: String) -> Self {
+        Self {
+            msg_type: SigEd25519Sha512Single,
+            signature,
+            sig_data,
+            signer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct AnswerDecorators {
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+impl AnswerDecorators {
+    pub fn new(thread: Thread) -> Self {
+        Self { thread, timing: None }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::question_answer::QuestionAnswerTypeV1_0,
+    };
+
+    #[test]
+    fn test_minimal_answer() {
+        let content = AnswerContent::new("Yes, it's me".to_owned());
+
+        let decorators = AnswerDecorators::new(make_extended_thread());
+
+        let expected = json!({
+            "response": content.response,
+            "~thread": decorators.thread
+        });
+
+        test_utils::test_msg(content, decorators, QuestionAnswerTypeV1_0::Answer, expected);
+    }
+
+    #[test]
+    fn test_extended_answer() {
+        let mut content = AnswerContent::new("Yes, it's me".to_owned());
+        content.response_sig = Some(ResponseSignature::new(
+            "test_signature".to_owned(),
+            "test_sig_data".to_owned(),
+            "test_signer".to_owned(),
+        ));
+
+        let mut decorators = AnswerDecorators::new(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "response": content.response,
+            "response~sig": {
+                "@type": "https://didcomm.org/signature/1.0/ed25519Sha512_single",
+                "signature": "test_signature",
+                "sig_data": "test_sig_data",
+                "signer": "test_signer"
+            },
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(content, decorators, QuestionAnswerTypeV1_0::Answer, expected);
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    decorators::{thread::Thread, timing::Timing},
+    msg_parts::MsgParts,
+};
+
+pub type Question = MsgParts<QuestionContent, QuestionDecorators>;
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct QuestionContent {
+    pub question_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_detail: Option<String>,
+    /// Included in the signed data of the answer, so that a signature can't be replayed for
+    /// another question.
+    pub nonce: String,
+    #[serde(default)]
+    pub signature_required: bool,
+    pub valid_responses: Vec<QuestionResponse>,
+}
+
+impl QuestionContent {
+    pub fn new(question_text: String, nonce: String, valid_responses: Vec<QuestionResponse>) -> Self {
+        Self {
+            question_text,
+            question_detail: None,
+            nonce,
+            signature_required: false,
+            valid_responses,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct QuestionResponse {
+    pub text: String,
+}
+
+impl QuestionResponse {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+}
+
+/// The `expires_time` of the `~timing` decorator, if present, is the time after which
+/// the question must no longer be answered.
+#[derive(Clone, Debug, Deserialize, Serialize, Default, PartialEq)]
+pub struct QuestionDecorators {
+    #[serde(rename = "~thread")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+    #[serde(rename = "~timing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
+}
+
+impl QuestionDecorators {
+    /// Returns whether the question has expired at `time`, according to its `~timing` decorator.
+    pub fn is_expired_at(&self, time: DateTime<Utc>) -> bool {
+        self.timing.as_ref().map_or(false, |timing| timing.is_expired_at(time))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use chrono::Duration;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        decorators::{thread::tests::make_extended_thread, timing::tests::make_extended_timing},
+        misc::test_utils,
+        msg_types::question_answer::QuestionAnswerTypeV1_0,
+    };
+
+    fn make_valid_responses() -> Vec<QuestionResponse> {
+        vec![
+            QuestionResponse::new("Yes, it's me".to_owned()),
+            QuestionResponse::new("No, that's not me!".to_owned()),
+        ]
+    }
+
+    #[test]
+    fn test_minimal_question() {
+        let content = QuestionContent::new(
+            "Alice, are you on the phone with Bob from Faber Bank right now?".to_owned(),
+            "test_nonce".to_owned(),
+            make_valid_responses(),
+        );
+
+        let decorators = QuestionDecorators::default();
+
+        let expected = json!({
+            "question_text": content.question_text,
+            "nonce": content.nonce,
+            "signature_required": false,
+            "valid_responses": [
+                { "text": "Yes, it's me" },
+                { "text": "No, that's not me!" }
+            ]
+        });
+
+        test_utils::test_msg(content, decorators, QuestionAnswerTypeV1_0::Question, expected);
+    }
+
+    #[test]
+    fn test_extended_question() {
+        let mut content = QuestionContent::new(
+            "Alice, are you on the phone with Bob from Faber Bank right now?".to_owned(),
+            "test_nonce".to_owned(),
+            make_valid_responses(),
+        );
+        content.question_detail = Some("This is optional fine-print giving context to the question".to_owned());
+        content.signature_required = true;
+
+        let mut decorators = QuestionDecorators::default();
+        decorators.thread = Some(make_extended_thread());
+        decorators.timing = Some(make_extended_timing());
+
+        let expected = json!({
+            "question_text": content.question_text,
+            "question_detail": content.question_detail,
+            "nonce": content.nonce,
+            "signature_required": true,
+            "valid_responses": content.valid_responses,
+            "~thread": decorators.thread,
+            "~timing": decorators.timing
+        });
+
+        test_utils::test_msg(content, decorators, QuestionAnswerTypeV1_0::Question, expected);
+    }
+
+    #[test]
+    fn test_question_expiry() {
+        let now = DateTime::default() + Duration::days(1);
+
+        let mut decorators = QuestionDecorators::default();
+        assert!(!decorators.is_expired_at(now));
+
+        let mut timing = Timing::default();
+        timing.expires_time = Some(now + Duration::minutes(1));
+        decorators.timing = Some(timing.clone());
+        assert!(!decorators.is_expired_at(now));
+
+        timing.expires_time = Some(now - Duration::minutes(1));
+        decorators.timing = Some(timing);
+        assert!(decorators.is_expired_at(now));
+    }
+}
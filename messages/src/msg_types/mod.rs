@@ -14,8 +14,9 @@ use std::{marker::PhantomData, str::FromStr};
 use serde::Serialize;
 
 pub use protocols::{
-    basic_message, connection, cred_issuance, discover_features, notification, out_of_band, present_proof,
-    report_problem, revocation, routing, trust_ping, Protocol,
+    action_menu, basic_message, connection, coordinate_mediation, cred_issuance, did_exchange, discover_features,
+    notification, out_of_band, pickup, present_proof, question_answer, report_problem, revocation, routing, trust_ping,
+    Protocol,
 };
 pub use role::Role;
 
@@ -5,9 +5,11 @@ use serde::{Deserialize, Serialize};
 use shared_vcx::misc::utils::CowStr;
 
 use self::{
-    basic_message::BasicMessageType, connection::ConnectionType, cred_issuance::CredentialIssuanceType,
-    discover_features::DiscoverFeaturesType, notification::NotificationType, out_of_band::OutOfBandType,
-    present_proof::PresentProofType, report_problem::ReportProblemType, revocation::RevocationType,
+    action_menu::ActionMenuType, basic_message::BasicMessageType, connection::ConnectionType,
+    coordinate_mediation::CoordinateMediationType, cred_issuance::CredentialIssuanceType,
+    did_exchange::DidExchangeType, discover_features::DiscoverFeaturesType, notification::NotificationType,
+    out_of_band::OutOfBandType, pickup::PickupType, present_proof::PresentProofType,
+    question_answer::QuestionAnswerType, report_problem::ReportProblemType, revocation::RevocationType,
     routing::RoutingType, signature::SignatureType, trust_ping::TrustPingType,
 };
 use crate::{
@@ -15,13 +17,18 @@ use crate::{
     msg_types::traits::ProtocolName,
 };
 
+pub mod action_menu;
 pub mod basic_message;
 pub mod connection;
+pub mod coordinate_mediation;
 pub mod cred_issuance;
+pub mod did_exchange;
 pub mod discover_features;
 pub mod notification;
 pub mod out_of_band;
+pub mod pickup;
 pub mod present_proof;
+pub mod question_answer;
 pub mod report_problem;
 pub mod revocation;
 pub mod routing;
@@ -57,6 +64,11 @@ pub enum Protocol {
     BasicMessageType(BasicMessageType),
     OutOfBandType(OutOfBandType),
     NotificationType(NotificationType),
+    DidExchangeType(DidExchangeType),
+    CoordinateMediationType(CoordinateMediationType),
+    PickupType(PickupType),
+    ActionMenuType(ActionMenuType),
+    QuestionAnswerType(QuestionAnswerType),
 }
 
 /// Utility macro to avoid harder to read and error prone calling
@@ -92,6 +104,11 @@ impl Protocol {
         match_protocol!(BasicMessageType, protocol, major, minor);
         match_protocol!(OutOfBandType, protocol, major, minor);
         match_protocol!(NotificationType, protocol, major, minor);
+        match_protocol!(DidExchangeType, protocol, major, minor);
+        match_protocol!(CoordinateMediationType, protocol, major, minor);
+        match_protocol!(PickupType, protocol, major, minor);
+        match_protocol!(ActionMenuType, protocol, major, minor);
+        match_protocol!(QuestionAnswerType, protocol, major, minor);
 
         Err(MsgTypeError::unknown_protocol(protocol.to_owned()))
     }
@@ -111,6 +128,11 @@ impl Protocol {
             Self::BasicMessageType(v) => v.as_protocol_parts(),
             Self::OutOfBandType(v) => v.as_protocol_parts(),
             Self::NotificationType(v) => v.as_protocol_parts(),
+            Self::DidExchangeType(v) => v.as_protocol_parts(),
+            Self::CoordinateMediationType(v) => v.as_protocol_parts(),
+            Self::PickupType(v) => v.as_protocol_parts(),
+            Self::ActionMenuType(v) => v.as_protocol_parts(),
+            Self::QuestionAnswerType(v) => v.as_protocol_parts(),
         }
     }
 
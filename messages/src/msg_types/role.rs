@@ -18,4 +18,6 @@ pub enum Role {
     Notified,
     Notifier,
     Mediator,
+    Recipient,
+    Questioner,
 }
@@ -10,4 +10,8 @@ impl Transport for HttpClient {
         post_message(msg, service_endpoint).await?;
         Ok(())
     }
+
+    async fn send_message_with_response(&self, msg: Vec<u8>, service_endpoint: Url) -> VcxResult<Vec<u8>> {
+        Ok(post_message(msg, service_endpoint).await?)
+    }
 }